@@ -1,7 +1,7 @@
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use ragu_arithmetic::{
     Domain,
-    ff::Field,
+    ff::{Field, PrimeField},
     pasta_curves::Fp,
     rand::{SeedableRng, rngs::StdRng},
 };
@@ -46,5 +46,81 @@ fn ifft_bench(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, fft_bench, ifft_bench);
+fn coset_fft_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("coset_fft");
+
+    for log2_n in [10, 14, 18] {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let domain = Domain::<Fp>::new(log2_n);
+        let data: Vec<Fp> = (0..domain.n()).map(|_| Fp::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(log2_n), &log2_n, |b, _| {
+            b.iter_batched(
+                || data.clone(),
+                |mut buf| domain.coset_fft(&mut buf, Fp::MULTIPLICATIVE_GENERATOR),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+fn batch_fft_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_fft");
+
+    let log2_n = 12;
+    for count in [4, 16, 64] {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let domain = Domain::<Fp>::new(log2_n);
+        let data: Vec<Vec<Fp>> = (0..count)
+            .map(|_| (0..domain.n()).map(|_| Fp::random(&mut rng)).collect())
+            .collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter_batched(
+                || data.clone(),
+                |mut polys| domain.batch_fft(&mut polys),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+fn divide_by_vanishing_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("divide_by_vanishing_on_coset");
+
+    for log2_n in [10, 14, 18] {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let domain = Domain::<Fp>::new(log2_n);
+        let data: Vec<Fp> = (0..domain.n()).map(|_| Fp::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(log2_n), &log2_n, |b, _| {
+            b.iter_batched(
+                || data.clone(),
+                |mut buf| {
+                    domain.divide_by_vanishing_on_coset(
+                        &mut buf,
+                        Fp::MULTIPLICATIVE_GENERATOR,
+                        log2_n - 2,
+                    )
+                },
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    fft_bench,
+    ifft_bench,
+    coset_fft_bench,
+    batch_fft_bench,
+    divide_by_vanishing_bench
+);
 criterion_main!(benches);
@@ -1,27 +1,67 @@
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use ragu_arithmetic::{
+    FixedBaseTable, TableConfig,
     ff::Field,
-    mul,
+    mul, mul_glv, mul_windowed,
     pasta_curves::{EpAffine, Fq, group::CurveAffine},
     rand::{SeedableRng, rngs::StdRng},
 };
 
 fn msm_bench(c: &mut Criterion) {
-    let mut group = c.benchmark_group("msm");
-
-    for size in [64, 256, 1024, 4096, 8192] {
-        let mut rng = StdRng::seed_from_u64(1234);
-        let coeffs: Vec<Fq> = (0..size).map(|_| Fq::random(&mut rng)).collect();
-        let bases: Vec<EpAffine> = (0..size)
-            .map(|_| (EpAffine::generator() * Fq::random(&mut rng)).into())
-            .collect();
+    let inputs: Vec<(usize, Vec<Fq>, Vec<EpAffine>)> = [64, 256, 1024, 4096, 8192]
+        .into_iter()
+        .map(|size| {
+            let mut rng = StdRng::seed_from_u64(1234);
+            let coeffs: Vec<Fq> = (0..size).map(|_| Fq::random(&mut rng)).collect();
+            let bases: Vec<EpAffine> = (0..size)
+                .map(|_| (EpAffine::generator() * Fq::random(&mut rng)).into())
+                .collect();
+            assert_eq!(
+                mul_glv(coeffs.iter(), bases.iter()),
+                mul_windowed(coeffs.iter(), bases.iter())
+            );
+            (size, coeffs, bases)
+        })
+        .collect();
 
-        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+    let mut group = c.benchmark_group("msm");
+    for (size, coeffs, bases) in &inputs {
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
             b.iter(|| mul(coeffs.iter(), bases.iter()));
         });
     }
+    group.finish();
+
+    let mut group = c.benchmark_group("msm_windowed");
+    for (size, coeffs, bases) in &inputs {
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
+            b.iter(|| mul_windowed(coeffs.iter(), bases.iter()));
+        });
+    }
+    group.finish();
 
+    let mut group = c.benchmark_group("msm_glv");
+    for (size, coeffs, bases) in &inputs {
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
+            b.iter(|| mul_glv(coeffs.iter(), bases.iter()));
+        });
+    }
     group.finish();
+
+    for spacing in [1, 2, 4] {
+        let config = TableConfig {
+            spacing,
+            ..TableConfig::default()
+        };
+        let mut group = c.benchmark_group(format!("msm_table_spacing{spacing}"));
+        for (size, coeffs, bases) in &inputs {
+            let table = FixedBaseTable::new(bases, config);
+            group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
+                b.iter(|| table.mul(coeffs.iter().enumerate()));
+            });
+        }
+        group.finish();
+    }
 }
 
 criterion_group!(benches, msm_bench);
@@ -1,6 +1,10 @@
 use alloc::{vec, vec::Vec};
 
-use crate::ff::PrimeField;
+use crate::{
+    ff::PrimeField,
+    fft::{FFTField, FOUR_STEP_THRESHOLD, Ring, fft, four_step_fft},
+    multicore::*,
+};
 
 /// Radix-2 evaluation domain of size $n = 2^k$ generated by powers of a
 /// primitive $n$-th root of unity $\omega$, consisting of the set
@@ -95,11 +99,15 @@ impl<F: PrimeField> Domain<F> {
     /// Computes the radix2 discrete Fourier transform (DFT) of a slice of
     /// generic ring elements using the Cooley-Tukey FFT algorithm.
     ///
+    /// Domains of $2^{16}$ or more elements use Bailey's four-step variant of
+    /// the algorithm, which splits the transform into many smaller ones that
+    /// fit in cache.
+    ///
     /// # Panics
     ///
     /// Panics if `input.len()` does not equal `self.n()`.
-    pub fn ring_fft<R: crate::fft::Ring<F = F>>(&self, input: &mut [R::R]) {
-        crate::fft::fft::<R>(input, self.omega, self.log2_n);
+    pub fn ring_fft<R: Ring<F = F>>(&self, input: &mut [R::R]) {
+        self.transform::<R>(input, self.omega);
     }
 
     /// Performs the inverse operation of [`Self::ring_fft`].
@@ -107,8 +115,8 @@ impl<F: PrimeField> Domain<F> {
     /// # Panics
     ///
     /// Panics if `input.len()` does not equal `self.n()`.
-    pub fn ring_ifft<R: crate::fft::Ring<F = F>>(&self, input: &mut [R::R]) {
-        crate::fft::fft::<R>(input, self.omega_inv, self.log2_n);
+    pub fn ring_ifft<R: Ring<F = F>>(&self, input: &mut [R::R]) {
+        self.transform::<R>(input, self.omega_inv);
 
         for input in input.iter_mut() {
             R::scale_assign(input, self.n_inv);
@@ -122,7 +130,7 @@ impl<F: PrimeField> Domain<F> {
     ///
     /// Panics if `input.len()` does not equal `self.n()`.
     pub fn fft(&self, input: &mut [F]) {
-        self.ring_fft::<crate::fft::FFTField<F>>(input);
+        self.ring_fft::<FFTField<F>>(input);
     }
 
     /// Performs the inverse operation of [`Self::fft`].
@@ -131,7 +139,140 @@ impl<F: PrimeField> Domain<F> {
     ///
     /// Panics if `input.len()` does not equal `self.n()`.
     pub fn ifft(&self, input: &mut [F]) {
-        self.ring_ifft::<crate::fft::FFTField<F>>(input);
+        self.ring_ifft::<FFTField<F>>(input);
+    }
+
+    /// Evaluates the polynomial with coefficients `input` over the coset
+    /// $gD = \{g, g\omega, \ldots, g\omega^{n-1}\}$ of this domain $D$.
+    ///
+    /// This is the FFT of the coefficients scaled by $g^i$, since
+    /// $p(g\omega^j) = \sum_i (p_i g^i) \omega^{ij}$. Any $g \notin D$, such
+    /// as [`PrimeField::MULTIPLICATIVE_GENERATOR`], gives a coset that is
+    /// disjoint from $D$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input.len()` does not equal `self.n()`.
+    pub fn coset_fft(&self, input: &mut [F], g: F) {
+        distribute_powers(input, g);
+        self.fft(input);
+    }
+
+    /// Performs the inverse operation of [`Self::coset_fft`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input.len()` does not equal `self.n()` or if `g` is zero.
+    pub fn coset_ifft(&self, input: &mut [F], g: F) {
+        self.ifft(input);
+        distribute_powers(input, g.invert().expect("coset generator must be nonzero"));
+    }
+
+    /// Computes [`Self::fft`] of each polynomial in `polys`.
+    ///
+    /// When the `multicore` feature is enabled, the polynomials are
+    /// transformed in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any polynomial does not have length `self.n()`.
+    pub fn batch_fft<P: AsMut<[F]> + Send>(&self, polys: &mut [P]) {
+        for_each_poly(polys, |poly| self.fft(poly));
+    }
+
+    /// Computes [`Self::ifft`] of each polynomial in `polys`.
+    ///
+    /// When the `multicore` feature is enabled, the polynomials are
+    /// transformed in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any polynomial does not have length `self.n()`.
+    pub fn batch_ifft<P: AsMut<[F]> + Send>(&self, polys: &mut [P]) {
+        for_each_poly(polys, |poly| self.ifft(poly));
+    }
+
+    /// Computes [`Self::coset_fft`] of each polynomial in `polys` over the
+    /// same coset $gD$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any polynomial does not have length `self.n()`.
+    pub fn batch_coset_fft<P: AsMut<[F]> + Send>(&self, polys: &mut [P], g: F) {
+        for_each_poly(polys, |poly| self.coset_fft(poly, g));
+    }
+
+    /// Computes [`Self::coset_ifft`] of each polynomial in `polys` over the
+    /// same coset $gD$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any polynomial does not have length `self.n()` or if `g` is
+    /// zero.
+    pub fn batch_coset_ifft<P: AsMut<[F]> + Send>(&self, polys: &mut [P], g: F) {
+        for_each_poly(polys, |poly| self.coset_ifft(poly, g));
+    }
+
+    /// Divides the evaluations `evals` of a polynomial $p(X)$ over the coset
+    /// $gD$ by the vanishing polynomial $X^m - 1$ of the subdomain of size
+    /// $m = 2^{\text{log2\_m}}$, in place.
+    ///
+    /// If $p(X)$ is divisible by $X^m - 1$, the result is the evaluations of
+    /// the quotient over $gD$, which [`Self::coset_ifft`] turns back into
+    /// coefficients. This is how a quotient of degree less than $n - m$ is
+    /// computed without polynomial long division.
+    ///
+    /// # Implementation
+    ///
+    /// The vanishing polynomial takes the values
+    /// $(g\omega^j)^m - 1 = g^m (\omega^m)^j - 1$ over the coset, and since
+    /// $\omega^m$ has order $n / m$ there are only $n / m$ distinct values to
+    /// invert.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `evals.len()` does not equal `self.n()`, if `log2_m` exceeds
+    /// `self.log2_n()`, or if the coset intersects the subdomain, in which
+    /// case the vanishing polynomial has zeroes on it.
+    pub fn divide_by_vanishing_on_coset(&self, evals: &mut [F], g: F, log2_m: u32) {
+        assert_eq!(evals.len(), self.n);
+        assert!(
+            log2_m <= self.log2_n,
+            "subdomain of size 2^{} exceeds domain of size 2^{}",
+            log2_m,
+            self.log2_n
+        );
+
+        let m = 1u64 << log2_m;
+        let omega_m = self.omega.pow_vartime([m]);
+        let mut denominators: Vec<F> = (0..self.n >> log2_m)
+            .scan(g.pow_vartime([m]), |acc, _| {
+                let tmp = *acc - F::ONE;
+                *acc *= omega_m;
+                Some(tmp)
+            })
+            .collect();
+        assert!(
+            denominators.iter().all(|d| !bool::from(d.is_zero())),
+            "coset intersects the vanishing subdomain"
+        );
+        {
+            let mut scratch = vec![F::ZERO; denominators.len()];
+            crate::ff::BatchInverter::invert_with_external_scratch(&mut denominators, &mut scratch);
+        }
+
+        for (eval, denominator) in evals.iter_mut().zip(denominators.iter().cycle()) {
+            *eval *= denominator;
+        }
+    }
+
+    /// Dispatches to the cache-friendly four-step FFT for large domains.
+    fn transform<R: Ring<F = F>>(&self, input: &mut [R::R], omega: F) {
+        if self.log2_n >= FOUR_STEP_THRESHOLD {
+            four_step_fft::<R>(input, omega, self.log2_n);
+        } else {
+            fft::<R>(input, omega, self.log2_n);
+        }
     }
 
     /// This function produces the evaluations of Lagrange basis polynomials
@@ -211,6 +352,27 @@ impl<F: PrimeField> Domain<F> {
     }
 }
 
+/// Multiplies the $i$-th element of `input` by $g^i$.
+fn distribute_powers<F: PrimeField>(input: &mut [F], g: F) {
+    let mut power = F::ONE;
+    for x in input.iter_mut() {
+        *x *= power;
+        power *= g;
+    }
+}
+
+/// Calls `f` on each polynomial of a batch, in parallel when possible.
+fn for_each_poly<F: PrimeField, P: AsMut<[F]> + Send>(
+    polys: &mut [P],
+    f: impl Fn(&mut [F]) + Sync,
+) {
+    polys
+        .iter_mut()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .for_each(|poly| f(poly.as_mut()));
+}
+
 #[test]
 fn test_fft() {
     use crate::{eval, ff::Field, pasta_curves::Fp as F};
@@ -289,6 +451,130 @@ fn test_contains() {
     assert!(!domain.contains(F::DELTA));
 }
 
+#[test]
+fn test_coset_fft() {
+    use crate::{eval, ff::Field, pasta_curves::Fp as F};
+
+    let g = F::MULTIPLICATIVE_GENERATOR;
+    for log2_n in 0..=8 {
+        let domain = Domain::<F>::new(log2_n);
+
+        let coeffs = (0..domain.n)
+            .map(|i| F::DELTA.pow([(i + 3) as u64]))
+            .collect::<Vec<_>>();
+        let mut evals = coeffs.clone();
+        domain.coset_fft(&mut evals, g);
+
+        let mut x = g;
+        for e in &evals {
+            assert_eq!(*e, eval(&coeffs, x));
+            x *= domain.omega;
+        }
+
+        domain.coset_ifft(&mut evals, g);
+        assert_eq!(evals, coeffs);
+    }
+}
+
+#[test]
+fn test_batch_fft() {
+    use crate::pasta_curves::Fp as F;
+
+    let domain = Domain::<F>::new(6);
+    let g = F::DELTA;
+    let polys: Vec<Vec<F>> = (0..5u64)
+        .map(|j| {
+            (0..domain.n)
+                .map(|i| F::from(j * 1000 + i as u64).square())
+                .collect()
+        })
+        .collect();
+
+    let mut batch = polys.clone();
+    domain.batch_fft(&mut batch);
+    for (poly, batched) in polys.iter().zip(&batch) {
+        let mut expected = poly.clone();
+        domain.fft(&mut expected);
+        assert_eq!(*batched, expected);
+    }
+    domain.batch_ifft(&mut batch);
+    assert_eq!(batch, polys);
+
+    domain.batch_coset_fft(&mut batch, g);
+    for (poly, batched) in polys.iter().zip(&batch) {
+        let mut expected = poly.clone();
+        domain.coset_fft(&mut expected, g);
+        assert_eq!(*batched, expected);
+    }
+    domain.batch_coset_ifft(&mut batch, g);
+    assert_eq!(batch, polys);
+}
+
+#[test]
+fn test_large_fft_roundtrip() {
+    use crate::pasta_curves::Fp as F;
+
+    // Large enough to take the four-step path.
+    let domain = Domain::<F>::new(FOUR_STEP_THRESHOLD);
+    let coeffs = (0..domain.n)
+        .map(|i| F::from(i as u64).square() + F::DELTA)
+        .collect::<Vec<_>>();
+
+    let mut evals = coeffs.clone();
+    domain.fft(&mut evals);
+    let mut expected = coeffs.clone();
+    fft::<FFTField<F>>(&mut expected, domain.omega, domain.log2_n);
+    assert_eq!(evals, expected);
+
+    domain.ifft(&mut evals);
+    assert_eq!(evals, coeffs);
+}
+
+#[test]
+fn test_divide_by_vanishing_on_coset() {
+    use crate::{eval, ff::Field, pasta_curves::Fp as F};
+
+    let g = F::MULTIPLICATIVE_GENERATOR;
+    for log2_m in 0..=4 {
+        // p(X) = q(X) (X^m - 1) with deg q < n - m, evaluated over a coset of
+        // a domain large enough to hold p.
+        let domain = Domain::<F>::new(5);
+        let m = 1 << log2_m;
+        let q = (0..domain.n - m)
+            .map(|i| F::DELTA.pow([(i + 1) as u64]))
+            .collect::<Vec<_>>();
+        let mut p = vec![F::ZERO; domain.n];
+        for (i, q) in q.iter().enumerate() {
+            p[i + m] += q;
+            p[i] -= q;
+        }
+
+        let mut evals = p;
+        domain.coset_fft(&mut evals, g);
+        domain.divide_by_vanishing_on_coset(&mut evals, g, log2_m);
+
+        let mut x = g;
+        for e in &evals {
+            assert_eq!(*e, eval(&q, x));
+            x *= domain.omega;
+        }
+
+        domain.coset_ifft(&mut evals, g);
+        assert_eq!(&evals[..q.len()], &q[..]);
+        assert!(evals[q.len()..].iter().all(|c| *c == F::ZERO));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_divide_by_vanishing_rejects_domain() {
+    use crate::{ff::Field, pasta_curves::Fp as F};
+
+    let domain = Domain::<F>::new(4);
+    let mut evals = vec![F::ONE; domain.n];
+    domain.divide_by_vanishing_on_coset(&mut evals, F::ONE, 2);
+}
+
 #[cfg(test)]
 mod proptests {
     use proptest::prelude::*;
@@ -1,8 +1,14 @@
+use alloc::vec::Vec;
+
 use crate::{
     ff::{Field, PrimeField},
-    multicore,
+    multicore::{self, *},
 };
 
+/// Transforms of at least $2^{16}$ elements no longer fit in cache, and are
+/// computed by [`four_step_fft`] instead of [`fft`].
+pub(crate) const FOUR_STEP_THRESHOLD: u32 = 16;
+
 /// A ring that can be used for FFTs.
 pub trait Ring {
     /// Elements of the ring.
@@ -147,6 +153,77 @@ pub(crate) fn recursive_butterfly_arithmetic<R: Ring>(
     }
 }
 
+/// Computes the same transform as [`fft`] with Bailey's four-step algorithm,
+/// which only ever performs FFTs of about $\sqrt{n}$ contiguous elements.
+///
+/// Writing $n = n_1 n_2$, input index $j = j_1 + n_1 j_2$ and output index
+/// $k = k_2 + n_2 k_1$, the transform splits into $n_1$ FFTs of size $n_2$
+/// over $j_2$, a multiplication by the twiddle factors $\omega^{j_1 k_2}$,
+/// and $n_2$ FFTs of size $n_1$ over $j_1$. Transposing between the steps
+/// keeps each of these FFTs on a contiguous row, and the rows are
+/// transformed in parallel.
+pub(crate) fn four_step_fft<R: Ring>(a: &mut [R::R], omega: R::F, log_n: u32) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+
+    let log_n1 = log_n / 2;
+    let log_n2 = log_n - log_n1;
+    let (n1, n2) = (1 << log_n1, 1 << log_n2);
+
+    // Row j_1 holds the inputs x_{j_1 + n_1 j_2}.
+    let mut rows = transpose(a, n2, n1);
+    let omega_n1 = omega.pow_vartime([n1 as u64]);
+    for_each_row(&mut rows, n2, |j1, row| {
+        fft::<R>(row, omega_n1, log_n2);
+
+        let step = omega.pow_vartime([j1 as u64]);
+        let mut twiddle = R::F::ONE;
+        for x in row.iter_mut().skip(1) {
+            twiddle *= step;
+            R::scale_assign(x, twiddle);
+        }
+    });
+
+    // Row k_2 holds the twiddled values for every j_1.
+    let mut columns = transpose(&rows, n1, n2);
+    let omega_n2 = omega.pow_vartime([n2 as u64]);
+    for_each_row(&mut columns, n1, |_, row| fft::<R>(row, omega_n2, log_n1));
+
+    // Column k_2 of row k_1 is the output y_{k_2 + n_2 k_1}.
+    for (k1, out) in a.chunks_mut(n2).enumerate() {
+        for (k2, out) in out.iter_mut().enumerate() {
+            *out = columns[k2 * n1 + k1].clone();
+        }
+    }
+}
+
+/// Returns the transpose of the `rows` by `cols` row-major matrix `src`,
+/// copying it in square tiles to stay within cache.
+fn transpose<T: Default + Clone>(src: &[T], rows: usize, cols: usize) -> Vec<T> {
+    const TILE: usize = 16;
+
+    let mut out = alloc::vec![T::default(); src.len()];
+    for r0 in (0..rows).step_by(TILE) {
+        for c0 in (0..cols).step_by(TILE) {
+            for r in r0..rows.min(r0 + TILE) {
+                for c in c0..cols.min(c0 + TILE) {
+                    out[c * rows + r] = src[r * cols + c].clone();
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Calls `f` with the index and contents of each row of width `width`.
+fn for_each_row<T: Send>(data: &mut [T], width: usize, f: impl Fn(usize, &mut [T]) + Send + Sync) {
+    data.chunks_mut(width)
+        .enumerate()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .for_each(|(i, row)| f(i, row));
+}
+
 fn log2_floor(num: usize) -> u32 {
     assert!(num > 0);
     let mut pow = 0;
@@ -199,6 +276,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_four_step_fft_matches_fft() {
+        for log2_n in 0..=11 {
+            let domain = Domain::<Fp>::new(log2_n);
+            let input: Vec<Fp> = (0..domain.n())
+                .map(|i| Fp::from((3 * i * i + 5) as u64))
+                .collect();
+
+            let mut expected = input.clone();
+            fft::<FFTField<Fp>>(&mut expected, domain.omega(), log2_n);
+            let mut actual = input;
+            four_step_fft::<FFTField<Fp>>(&mut actual, domain.omega(), log2_n);
+
+            assert_eq!(actual, expected, "size 2^{}", log2_n);
+        }
+    }
+
+    #[test]
+    fn test_transpose() {
+        let src: Vec<u32> = (0..6 * 35).collect();
+        let out = transpose(&src, 6, 35);
+        for r in 0..6 {
+            for c in 0..35 {
+                assert_eq!(out[c * 6 + r], src[r * 35 + c]);
+            }
+        }
+    }
+
     #[test]
     fn test_fft_single_element() {
         let domain = Domain::<Fp>::new(0);
@@ -0,0 +1,277 @@
+//! Fixed-base multiscalar multiplication with precomputed window tables.
+//!
+//! Commitments are multiscalar multiplications against the same vector of
+//! generators $\mathbf{G}$, so the doublings that a variable-base MSM spends
+//! between windows can be precomputed once. A [`FixedBaseTable`] stores the
+//! multiples $2^{jtc} G_i$ for every generator, where $c$ is the window size
+//! and $t$ is the spacing between stored windows. A scalar recoded into signed
+//! $c$-bit digits $k = \sum_w d_w 2^{wc}$ then contributes $d_{jt + r}$ copies
+//! of $2^{jtc} G_i$ to the $r$-th of $t$ bucket sums, which are combined with
+//! $c$ doublings each.
+//!
+//! With a spacing of one, every window is stored and a commitment is a single
+//! bucket sum with no doublings at all. Larger spacings divide the size of the
+//! table by $t$ at the cost of $t$ bucket sums and $(t - 1) c$ doublings.
+
+use alloc::vec::Vec;
+
+use crate::{
+    ff::PrimeField,
+    msm::{U512, bucket_sum, signed},
+    multicore::*,
+    pasta_curves::{
+        arithmetic::CurveAffine,
+        group::{Curve, Group},
+    },
+};
+
+/// Configures the memory/speed tradeoff of a [`FixedBaseTable`].
+///
+/// A table over $n$ generators for a scalar field of $b$ bits stores
+/// $n \lceil \lceil (b + 1) / c \rceil / t \rceil$ affine points, where $c$ is
+/// [`window_bits`](Self::window_bits) and $t$ is [`spacing`](Self::spacing).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableConfig {
+    /// The size $c$ of the signed-digit windows, in bits. Each bucket sum uses
+    /// $2^{c - 1}$ buckets.
+    pub window_bits: usize,
+
+    /// The spacing $t$ between stored windows. Only every $t$-th power
+    /// $2^{jtc}$ of each generator is stored.
+    pub spacing: usize,
+}
+
+impl Default for TableConfig {
+    /// Stores every window of 12 bits, which for the 255-bit Pasta scalar
+    /// fields amounts to 22 points per generator.
+    fn default() -> Self {
+        TableConfig {
+            window_bits: 12,
+            spacing: 1,
+        }
+    }
+}
+
+/// Precomputed multiples of a fixed vector of bases, used to compute
+/// multiscalar multiplications against them without doublings.
+///
+/// See [`TableConfig`] for the memory/speed tradeoff. The results are
+/// identical to those of [`mul`](crate::mul) over the same bases.
+pub struct FixedBaseTable<C: CurveAffine> {
+    config: TableConfig,
+    /// The number of signed-digit windows of a scalar.
+    windows: usize,
+    /// The number of bases.
+    n: usize,
+    /// The affine coordinates of $2^{jtc} G_i$ at index $jn + i$.
+    rows: Vec<(C::Base, C::Base)>,
+}
+
+impl<C: CurveAffine> FixedBaseTable<C> {
+    /// Precomputes the table for `bases` with the given configuration.
+    ///
+    /// When the `multicore` feature is enabled, the rows of the table are
+    /// computed in parallel over the bases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bases is the identity, or if
+    /// [`window_bits`](TableConfig::window_bits) is not in `2..=20` or
+    /// [`spacing`](TableConfig::spacing) is zero.
+    pub fn new(bases: &[C], config: TableConfig) -> Self {
+        assert!(
+            (2..=20).contains(&config.window_bits),
+            "window size must be between 2 and 20 bits"
+        );
+        assert!(config.spacing > 0, "spacing must be nonzero");
+
+        // Signed magnitudes have at most NUM_BITS - 1 bits; two spare bits
+        // keep the recoded scalar within the last window.
+        let windows = (C::Scalar::NUM_BITS as usize + 1).div_ceil(config.window_bits);
+        let num_rows = windows.div_ceil(config.spacing);
+        let shift = config.window_bits * config.spacing;
+
+        let n = bases.len();
+        let mut current: Vec<C::Curve> = bases.iter().map(|base| base.to_curve()).collect();
+        let mut affine = alloc::vec![C::identity(); n];
+        let mut rows = Vec::with_capacity(num_rows * n);
+        for row in 0..num_rows {
+            if row > 0 {
+                current = current
+                    .into_par_iter()
+                    .map(|mut point| {
+                        for _ in 0..shift {
+                            point = point.double();
+                        }
+                        point
+                    })
+                    .collect();
+            }
+            C::Curve::batch_normalize(&current, &mut affine);
+            rows.extend(affine.iter().map(|point| {
+                let coordinates = point
+                    .coordinates()
+                    .expect("fixed bases must not be the identity");
+                (*coordinates.x(), *coordinates.y())
+            }));
+        }
+
+        FixedBaseTable {
+            config,
+            windows,
+            n,
+            rows,
+        }
+    }
+
+    /// Returns the configuration this table was built with.
+    pub fn config(&self) -> TableConfig {
+        self.config
+    }
+
+    /// Returns the number of bases in the table.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Returns true if the table holds no bases.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Computes $\sum_i a_i G_{j_i}$ over `(j_i, a_i)` pairs, where $G_j$ is
+    /// the $j$-th base of the table.
+    ///
+    /// When the `multicore` feature is enabled, the bucket sums are split
+    /// across threads by rows of the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of range.
+    pub fn mul<'a>(&self, terms: impl IntoIterator<Item = (usize, &'a C::Scalar)>) -> C::Curve {
+        let TableConfig {
+            window_bits: c,
+            spacing,
+        } = self.config;
+
+        // Recode into signed digits by adding 2^{c-1} to every window, as
+        // in `mul_terms`.
+        let mut offset = U512::ZERO;
+        for window in 0..self.windows {
+            offset.set_bit(window * c + c - 1);
+        }
+        let terms: Vec<(usize, U512, bool)> = terms
+            .into_iter()
+            .filter_map(|(index, coeff)| {
+                assert!(index < self.n, "base index {} out of range", index);
+                let (magnitude, negative) = signed(coeff);
+                (magnitude.bits() != 0).then(|| (index, magnitude.add(&offset), negative))
+            })
+            .collect();
+        if terms.is_empty() {
+            return C::Curve::identity();
+        }
+
+        let num_rows = self.rows.len() / self.n;
+        let jobs = current_num_threads().min(num_rows);
+        let rows_per_job = num_rows.div_ceil(jobs);
+        let half = 1 << (c - 1);
+
+        // Combine the t bucket sums from most significant to least.
+        let mut acc = C::Curve::identity();
+        for r in (0..spacing).rev() {
+            let sums: Vec<C::Curve> = (0..jobs)
+                .into_par_iter()
+                .map(|job| {
+                    let rows = (job * rows_per_job..num_rows.min((job + 1) * rows_per_job))
+                        .filter(|row| row * spacing + r < self.windows);
+                    let mut digits = Vec::new();
+                    let mut points = Vec::new();
+                    for row in rows {
+                        let window = row * spacing + r;
+                        for (index, scalar, negative) in &terms {
+                            let digit = scalar.window(window * c, c) as isize - half as isize;
+                            digits.push(if *negative { -digit } else { digit });
+                            points.push(row * self.n + index);
+                        }
+                    }
+                    bucket_sum::<C>(c, &digits, |i| self.rows[points[i]])
+                })
+                .collect();
+
+            for _ in 0..c {
+                acc = acc.double();
+            }
+            for sum in sums {
+                acc += &sum;
+            }
+        }
+
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ff::Field,
+        mul,
+        pasta_curves::{EpAffine, Fq, group::prime::PrimeCurveAffine},
+    };
+
+    fn check_table(config: TableConfig) {
+        let bases: Vec<EpAffine> = (1..=40u64)
+            .map(|i| (EpAffine::generator() * Fq::from(i * 7919)).to_affine())
+            .collect();
+        let table = FixedBaseTable::new(&bases, config);
+
+        let coeffs: Vec<Fq> = (0..bases.len())
+            .map(|i| match i % 5 {
+                0 => Fq::ZERO,
+                1 => Fq::ONE,
+                2 => -Fq::ONE,
+                3 => -Fq::from(i as u64),
+                _ => Fq::DELTA.pow([i as u64]),
+            })
+            .collect();
+
+        assert_eq!(table.mul(coeffs.iter().enumerate()), mul(&coeffs, &bases));
+
+        // Repeated and unordered indices.
+        let indices = [3, 3, 17, 0, 39, 17];
+        assert_eq!(
+            table.mul(indices.iter().copied().zip(&coeffs)),
+            mul(&coeffs[..indices.len()], indices.iter().map(|i| &bases[*i]))
+        );
+        assert_eq!(
+            table.mul(core::iter::empty()),
+            <EpAffine as CurveAffine>::CurveExt::identity()
+        );
+    }
+
+    #[test]
+    fn test_table_matches_mul() {
+        check_table(TableConfig::default());
+        for window_bits in [2, 3, 7, 16] {
+            for spacing in [1, 2, 5, 200] {
+                check_table(TableConfig {
+                    window_bits,
+                    spacing,
+                });
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_table_rejects_zero_spacing() {
+        FixedBaseTable::new(
+            &[EpAffine::generator()],
+            TableConfig {
+                window_bits: 4,
+                spacing: 0,
+            },
+        );
+    }
+}
@@ -75,11 +75,15 @@ compile_error!("`modern-deps` and `legacy-deps` are mutually exclusive.");
 #[cfg(not(any(feature = "modern-deps", feature = "legacy-deps")))]
 compile_error!("`ragu_arithmetic` requires either `modern-deps` or `legacy-deps`.");
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod coeff;
 mod deferred;
 mod domain;
 mod fft;
+mod fixed;
+mod msm;
 mod multicore;
 mod util;
 
@@ -112,14 +116,16 @@ pub use coeff::Coeff;
 pub use deferred::DeferredField;
 pub use domain::Domain;
 pub use fft::{Ring, bitreverse};
+pub use fixed::{FixedBaseTable, TableConfig};
+pub use msm::{mul, mul_glv};
 /// Converts a 256-bit integer literal into the little endian `[u64; 4]`
 /// representation that e.g. [`Fp::from_raw`](crate::pasta_curves::Fp::from_raw) or
 /// [`Fp::pow`](crate::pasta_curves::Fp::pow) need as input. This makes constants
 /// more readable, but is not intended for use in other contexts.
 pub use ragu_macros::repr256;
 pub use util::{
-    batch_to_affine, decomp_product_poly, dot, eval, factor, factor_iter, geosum, low_u64, mul,
-    poly_mul, poly_with_roots,
+    batch_to_affine, decomp_product_poly, dot, eval, factor, factor_iter, geosum, low_u64,
+    mul_windowed, poly_mul, poly_with_roots,
 };
 
 use crate::ff::{Field, FromUniformBytes, WithSmallOrderMulGroup};
@@ -152,6 +158,17 @@ impl<RNG: crate::rand::CryptoRng + crate::rand::RngCore + ?Sized> CryptoRngCore
 /// The trait is designed as a zero-sized marker type, with runtime parameters
 /// (generators, Poseidon constants) stored in the associated
 /// [`Params`](Cycle::Params) type as necessary.
+///
+/// # Requirements
+///
+/// Circuits are indexed by an evaluation [`Domain`] over the field of their
+/// registry, so **both** [`CircuitField`](Cycle::CircuitField) and
+/// [`ScalarField`](Cycle::ScalarField) must have a 2-adicity
+/// ([`S`](ff::PrimeField::S)) at least the base-2 logarithm of the number of
+/// circuits in the corresponding registry. Cycles where one field has a low
+/// 2-adicity cannot currently be supported. For example, BN254/Grumpkin
+/// cannot: the BN254 base field has a 2-adicity of one, while the nested
+/// registry holds more than two internal circuits.
 pub trait Cycle: Copy + Clone + Default + Send + Sync + 'static {
     /// The field that circuit developers will primarily work with, and the
     /// scalar field of the [`HostCurve`](Cycle::HostCurve).
@@ -205,6 +222,15 @@ pub trait Cycle: Copy + Clone + Default + Send + Sync + 'static {
 
     /// Generate the runtime parameters for this cycle.
     fn generate() -> Self::Params;
+
+    /// Generate the runtime parameters for this cycle with at least $2^k$
+    /// generators for each curve, enough to commit to polynomials with $2^k$
+    /// coefficients.
+    ///
+    /// Applications reject parameters with fewer generators than their rank
+    /// needs, so implementations must honor `k` rather than fall back to
+    /// [`generate`](Cycle::generate).
+    fn generate_for(k: u32) -> Self::Params;
 }
 
 /// Contains various fixed generators for elliptic curves, all of which have
@@ -217,6 +243,29 @@ pub trait FixedGenerators<C: CurveAffine>: Send + Sync + 'static {
     /// Generator used as a blinding factor or randomization.
     fn h(&self) -> &C;
 
+    /// Returns the precomputed [`FixedBaseTable`] for [`g`](Self::g), if
+    /// there is one.
+    fn table(&self) -> Option<&FixedBaseTable<C>> {
+        None
+    }
+
+    /// Computes $\sum_i a_i G_{j_i}$ over `(j_i, a_i)` pairs, where $G_j$ is
+    /// the $j$-th generator of [`g`](Self::g).
+    ///
+    /// This uses the [`table`](Self::table) when one is available and falls
+    /// back to [`mul`] otherwise. Both produce the same result.
+    fn commit<'a>(&self, terms: impl IntoIterator<Item = (usize, &'a C::ScalarExt)>) -> C::Curve {
+        match self.table() {
+            Some(table) => table.mul(terms),
+            None => {
+                let g = self.g();
+                let (coeffs, bases): (alloc::vec::Vec<_>, alloc::vec::Vec<_>) =
+                    terms.into_iter().map(|(j, a)| (a, &g[j])).unzip();
+                mul(coeffs, bases)
+            }
+        }
+    }
+
     /// Compute a commitment to a single value.
     fn short_commit(&self, value: C::ScalarExt, blind: C::ScalarExt) -> C {
         (self.g()[0] * value + *self.h() * blind).into()
@@ -0,0 +1,887 @@
+//! Multiscalar multiplication with signed digits, batch-affine buckets and GLV
+//! scalar decomposition.
+//!
+//! Curves with an efficiently computable endomorphism
+//! $\phi(x, y) = (\zeta x, y)$ satisfy $\phi(P) = [\lambda] P$ for a cube root
+//! of unity $\lambda$ in the scalar field. Every scalar $k$ can then be
+//! rewritten as $k \equiv k_1 + k_2 \lambda \pmod{r}$ with
+//! $|k_1|, |k_2| \approx \sqrt{r}$, so an MSM of size $n$ over full-width
+//! scalars becomes an MSM of size $2n$ over half-width scalars, halving the
+//! number of windows.
+//!
+//! The half-width scalars are recoded into signed digits in
+//! $[-2^{c-1}, 2^{c-1})$. Negating an affine point is free, so each window
+//! only needs $2^{c-1}$ buckets. Points are accumulated into buckets in affine
+//! coordinates, where the additions of a round share a single field inversion
+//! through Montgomery's trick.
+//!
+//! Scalars that are already small, such as the zeros, ones and boolean or limb
+//! values that dominate circuit traces, skip the decomposition. [`mul`] drops
+//! zeros and sums the bases of $\pm 1$ scalars directly in batched affine
+//! rounds, without any windowing. The remaining small scalars enter the
+//! pipeline as-is; when they are narrower than a window they occupy a single
+//! window sized to their width, and otherwise only the low windows.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::{
+    ff::{BatchInverter, Field, PrimeField, WithSmallOrderMulGroup},
+    multicore::*,
+    pasta_curves::{
+        arithmetic::CurveAffine,
+        group::{Curve, Group},
+    },
+    util::{bucket_lookup, mul_windowed},
+};
+
+/// Full-width scalars are decomposed with [`mul_glv`] by [`mul`] when there are
+/// at least this many of them; below it the decomposition and batching
+/// overheads outweigh the savings, and [`mul_windowed`] is used instead.
+const GLV_THRESHOLD: usize = 1 << 10;
+
+/// Scalars whose signed magnitude has at most this many bits are considered
+/// small by [`mul`], matching the width of the halves produced by GLV
+/// decomposition.
+const SMALL_BITS: usize = 128;
+
+/// Compute the multiscalar multiplication $\langle \mathbf{a}, \mathbf{G} \rangle$ where
+/// $\mathbf{a} \in \mathbb{F}^n$ is a vector of scalars and $\mathbf{G} \in \mathbb{G}^n$
+/// is a vector of bases.
+///
+/// Scalars are classified before any windowing. Zero scalars and bases at
+/// infinity are dropped, and the bases of $\pm 1$ scalars are added together
+/// with one batched inversion per round. Other scalars $k$ with
+/// $|k| < 2^{128}$ (including small negative values) enter the signed-digit
+/// pipeline of [`mul_glv`] as-is, so they only cost the windows they occupy,
+/// sized to their actual width. The remaining
+/// full-width scalars are handled by [`mul_glv`] or, when there are few of
+/// them, [`mul_windowed`]. All of these produce the same result.
+///
+/// # Correctness
+///
+/// The caller must ensure that `coeffs` and `bases` yield the same number of
+/// elements.
+pub fn mul<
+    'a,
+    C: CurveAffine,
+    A: IntoIterator<Item = &'a C::Scalar>,
+    B: IntoIterator<Item = &'a C>,
+>(
+    coeffs: A,
+    bases: B,
+) -> C::Curve {
+    let mut units = Vec::new();
+    let mut terms = Vec::new();
+    let mut full_coeffs = Vec::new();
+    let mut full_bases = Vec::new();
+    for (coeff, base) in coeffs.into_iter().zip(bases) {
+        let Some(coordinates) = base.coordinates().into_option() else {
+            continue;
+        };
+        let (magnitude, negative) = signed(coeff);
+        match magnitude.bits() {
+            0 => {}
+            1 => units.push((*coordinates.x(), *coordinates.y(), negative)),
+            bits if bits <= SMALL_BITS => {
+                let (x, y) = (*coordinates.x(), *coordinates.y());
+                terms.push(Term {
+                    scalar: magnitude,
+                    x,
+                    y: if negative { -y } else { y },
+                });
+            }
+            _ => {
+                full_coeffs.push(*coeff);
+                full_bases.push(*base);
+            }
+        }
+    }
+
+    if full_coeffs.len() >= GLV_THRESHOLD
+        && let Some(glv) = glv::<C>()
+    {
+        terms.extend(glv_terms(&glv, &full_coeffs, &full_bases));
+        full_coeffs.clear();
+    }
+
+    let digits: Vec<isize> = units
+        .iter()
+        .map(|&(_, _, negative)| if negative { -1 } else { 1 })
+        .collect();
+    let mut acc = bucket_sum::<C>(1, &digits, |i| (units[i].0, units[i].1));
+    acc += mul_terms(terms);
+    if !full_coeffs.is_empty() {
+        acc += mul_windowed(full_coeffs.iter(), full_bases.iter());
+    }
+    acc
+}
+
+/// Compute the multiscalar multiplication $\langle \mathbf{a}, \mathbf{G} \rangle$
+/// using GLV decomposition, signed-digit windows and batch-affine bucket
+/// accumulation.
+///
+/// This produces the same result as [`mul_windowed`] and is faster for large
+/// inputs of full-width scalars; [`mul`] selects between the two. The curve
+/// must satisfy $(\zeta_{\mathbb{F}_p} x, y) = [\zeta_{\mathbb{F}_q}] (x, y)$,
+/// where $\zeta$ is the [`ZETA`](WithSmallOrderMulGroup::ZETA) constant of the
+/// base and scalar fields respectively. Curves for which this does not hold,
+/// and scalar fields whose representation is not 32 bytes, fall back to
+/// [`mul_windowed`].
+///
+/// When the `multicore` feature is enabled, scalar decomposition and window
+/// computation are parallelized using rayon.
+///
+/// # Correctness
+///
+/// The caller must ensure that `coeffs` and `bases` yield the same number of
+/// elements.
+pub fn mul_glv<
+    'a,
+    C: CurveAffine,
+    A: IntoIterator<Item = &'a C::Scalar>,
+    B: IntoIterator<Item = &'a C>,
+>(
+    coeffs: A,
+    bases: B,
+) -> C::Curve {
+    let coeffs: Vec<C::Scalar> = coeffs.into_iter().copied().collect();
+    let bases: Vec<C> = bases.into_iter().copied().collect();
+
+    match glv::<C>() {
+        Some(glv) => mul_terms(glv_terms(&glv, &coeffs, &bases)),
+        None => mul_windowed(coeffs.iter(), bases.iter()),
+    }
+}
+
+/// Splits every (scalar, base) pair into two half-width terms, skipping bases
+/// at infinity.
+fn glv_terms<C: CurveAffine>(
+    glv: &Glv<C::Scalar>,
+    coeffs: &[C::Scalar],
+    bases: &[C],
+) -> Vec<Term<C>> {
+    let pairs: Vec<Option<[Term<C>; 2]>> = (0..coeffs.len())
+        .into_par_iter()
+        .map(|i| {
+            let coordinates = bases[i].coordinates().into_option()?;
+            let (x, y) = (*coordinates.x(), *coordinates.y());
+            let [(k1, neg1), (k2, neg2)] = glv.decompose(&coeffs[i]);
+
+            Some([
+                Term {
+                    scalar: k1,
+                    x,
+                    y: if neg1 { -y } else { y },
+                },
+                Term {
+                    scalar: k2,
+                    x: x * C::Base::ZETA,
+                    y: if neg2 { -y } else { y },
+                },
+            ])
+        })
+        .collect();
+
+    pairs.into_iter().flatten().flatten().collect()
+}
+
+/// Returns the GLV decomposition parameters for the scalar field of `C`, or
+/// `None` if [`mul_glv`] must fall back to [`mul_windowed`] for this curve.
+///
+/// With the `std` feature the parameters are computed once per curve and
+/// cached for the lifetime of the process; otherwise they are recomputed on
+/// every call.
+fn glv<C: CurveAffine>() -> Option<Glv<C::Scalar>> {
+    #[cfg(feature = "std")]
+    {
+        use std::{
+            any::{Any, TypeId},
+            boxed::Box,
+            collections::BTreeMap,
+            sync::RwLock,
+        };
+
+        type Cache = BTreeMap<TypeId, &'static (dyn Any + Send + Sync)>;
+        static CACHE: RwLock<Cache> = RwLock::new(BTreeMap::new());
+
+        let lookup = |cache: &Cache| {
+            cache.get(&TypeId::of::<C>()).map(|params| {
+                *params
+                    .downcast_ref::<Option<Glv<C::Scalar>>>()
+                    .expect("cached under the curve's type")
+            })
+        };
+        if let Some(params) = lookup(&CACHE.read().unwrap_or_else(|e| e.into_inner())) {
+            return params;
+        }
+        let mut cache = CACHE.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(params) = lookup(&cache) {
+            return params;
+        }
+        let params = compute_glv::<C>();
+        cache.insert(TypeId::of::<C>(), Box::leak(Box::new(params)));
+        params
+    }
+
+    #[cfg(not(feature = "std"))]
+    compute_glv::<C>()
+}
+
+/// Computes the GLV decomposition parameters for the scalar field of `C` if
+/// the curve endomorphism $(x, y) \mapsto (\zeta_{\mathbb{F}_p} x, y)$ acts
+/// as multiplication by $\zeta_{\mathbb{F}_q}$.
+fn compute_glv<C: CurveAffine>() -> Option<Glv<C::Scalar>> {
+    let generator = C::generator().coordinates().into_option()?;
+    let image = C::from_xy(*generator.x() * C::Base::ZETA, *generator.y()).into_option()?;
+    if image != (C::generator() * C::Scalar::ZETA).to_affine() {
+        return None;
+    }
+
+    Glv::new()
+}
+
+/// A scalar magnitude paired with the affine coordinates of its base, which
+/// has already been negated if the scalar was negative.
+struct Term<C: CurveAffine> {
+    scalar: U512,
+    x: C::Base,
+    y: C::Base,
+}
+
+/// Computes $\sum_i [k_i] P_i$ over the terms with signed-digit windows.
+fn mul_terms<C: CurveAffine>(mut terms: Vec<Term<C>>) -> C::Curve {
+    let Some(max_bits) = terms.iter().map(|term| term.scalar.bits()).max() else {
+        return C::Curve::identity();
+    };
+
+    let c = bucket_lookup(terms.len()) + 1;
+
+    // Scalars narrower than a window are their own unsigned digits, so a
+    // single window of 2^{max_bits} buckets covers them without recoding.
+    if max_bits < c {
+        let digits: Vec<isize> = terms
+            .iter()
+            .map(|term| term.scalar.window(0, max_bits) as isize)
+            .collect();
+        return bucket_sum::<C>(max_bits + 1, &digits, |i| (terms[i].x, terms[i].y));
+    }
+
+    // Recode into signed digits by adding 2^{c-1} to every window, so that
+    // each window of the shifted scalar, minus 2^{c-1}, is a digit in
+    // [-2^{c-1}, 2^{c-1}). Two spare bits keep the shifted scalar within
+    // the last window.
+    let windows = (max_bits + 2).div_ceil(c);
+    let mut offset = U512::ZERO;
+    for window in 0..windows {
+        offset.set_bit(window * c + c - 1);
+    }
+    for term in terms.iter_mut() {
+        term.scalar = term.scalar.add(&offset);
+    }
+
+    let window_sums: Vec<C::Curve> = (0..windows)
+        .into_par_iter()
+        .map(|window| window_sum(&terms, window * c, c))
+        .collect();
+
+    // Combine window sums sequentially, from most significant to least.
+    let mut acc = C::Curve::identity();
+    for sum in window_sums.into_iter().rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+        acc += &sum;
+    }
+
+    acc
+}
+
+/// Compute the bucket sum for the window of `c` bits starting at bit `offset`.
+fn window_sum<C: CurveAffine>(terms: &[Term<C>], offset: usize, c: usize) -> C::Curve {
+    let half = 1 << (c - 1);
+    let digits: Vec<isize> = terms
+        .iter()
+        .map(|term| term.scalar.window(offset, c) as isize - half as isize)
+        .collect();
+    bucket_sum::<C>(c, &digits, |i| (terms[i].x, terms[i].y))
+}
+
+/// Computes $\sum_i d_i P_i$ for signed digits $|d_i| \le 2^{c-1}$, where
+/// `point(i)` returns the affine coordinates of $P_i$.
+pub(crate) fn bucket_sum<C: CurveAffine>(
+    c: usize,
+    digits: &[isize],
+    point: impl Fn(usize) -> (C::Base, C::Base),
+) -> C::Curve {
+    let half = 1 << (c - 1);
+
+    // Counting sort the points with nonzero digits by bucket; bucket i holds
+    // the points with digit ±(i + 1).
+    let mut starts = vec![0; half];
+    for digit in digits.iter().filter(|digit| **digit != 0) {
+        starts[digit.unsigned_abs() - 1] += 1;
+    }
+    let mut total = 0;
+    for start in starts.iter_mut() {
+        let count = *start;
+        *start = total;
+        total += count;
+    }
+    let mut points = vec![(0, C::Base::ZERO, C::Base::ZERO); total];
+    for (i, digit) in digits.iter().enumerate() {
+        let bucket = match digit.cmp(&0) {
+            Ordering::Equal => continue,
+            Ordering::Greater => {
+                let (x, y) = point(i);
+                (digit.unsigned_abs() - 1, x, y)
+            }
+            Ordering::Less => {
+                let (x, y) = point(i);
+                (digit.unsigned_abs() - 1, x, -y)
+            }
+        };
+        points[starts[bucket.0]] = bucket;
+        starts[bucket.0] += 1;
+    }
+
+    // Repeatedly add adjacent points that share a bucket, halving every
+    // bucket's population per round, until each bucket holds at most one
+    // point.
+    let mut next = Vec::with_capacity(points.len());
+    let mut denominators = Vec::new();
+    let mut scratch = Vec::new();
+    loop {
+        denominators.clear();
+        let mut i = 0;
+        while i + 1 < points.len() {
+            if points[i].0 == points[i + 1].0 {
+                denominators.push(slope_denominator(&points[i], &points[i + 1]));
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+        if denominators.is_empty() {
+            break;
+        }
+
+        scratch.clear();
+        scratch.resize(denominators.len(), C::Base::ZERO);
+        BatchInverter::invert_with_external_scratch(&mut denominators, &mut scratch);
+
+        let mut inverses = denominators.iter();
+        let mut i = 0;
+        while i < points.len() {
+            if i + 1 < points.len() && points[i].0 == points[i + 1].0 {
+                let inverse = inverses.next().expect("one inverse per pair");
+                next.extend(add_affine::<C>(&points[i], &points[i + 1], inverse));
+                i += 2;
+            } else {
+                next.push(points[i]);
+                i += 1;
+            }
+        }
+        core::mem::swap(&mut points, &mut next);
+        next.clear();
+    }
+
+    // Summation by parts, as in `mul_windowed`.
+    let mut points = points.into_iter().rev().peekable();
+    let mut running_sum = C::Curve::identity();
+    let mut sum = C::Curve::identity();
+    for bucket in (0..half).rev() {
+        if let Some((_, x, y)) = points.next_if(|point| point.0 == bucket) {
+            running_sum += C::from_xy(x, y).unwrap();
+        }
+        sum += &running_sum;
+    }
+    sum
+}
+
+/// Returns the denominator of the slope of the line through `p` and `q`, or
+/// of the tangent at `p` when the two share an $x$-coordinate.
+fn slope_denominator<F: Field>(p: &(usize, F, F), q: &(usize, F, F)) -> F {
+    if p.1 != q.1 { q.1 - p.1 } else { p.2.double() }
+}
+
+/// Adds two affine points given the inverse of their
+/// [`slope_denominator`], returning `None` for $P + (-P)$. The bucket of `p`
+/// is carried over to the sum.
+fn add_affine<C: CurveAffine>(
+    &(bucket, x1, y1): &(usize, C::Base, C::Base),
+    &(_, x2, y2): &(usize, C::Base, C::Base),
+    inverse: &C::Base,
+) -> Option<(usize, C::Base, C::Base)> {
+    let slope = if x1 != x2 {
+        (y2 - y1) * inverse
+    } else if y1 == y2 && !bool::from(y1.is_zero()) {
+        let x1_squared = x1.square();
+        (x1_squared.double() + x1_squared + C::a()) * inverse
+    } else {
+        return None;
+    };
+
+    let x3 = slope.square() - x1 - x2;
+    let y3 = slope * (x1 - x3) - y1;
+    Some((bucket, x3, y3))
+}
+
+/// Interprets `k` as an integer in $(-r/2, r/2)$, returning its magnitude and
+/// whether it is negative.
+pub(crate) fn signed<F: PrimeField>(k: &F) -> (U512, bool) {
+    let value = U512::from_le_bytes(k.to_repr().as_ref());
+    let negated = U512::from_le_bytes((-*k).to_repr().as_ref());
+    if negated < value {
+        (negated, true)
+    } else {
+        (value, false)
+    }
+}
+
+/// Decomposes scalars $k \equiv k_1 + k_2 \lambda \pmod{r}$, where
+/// $\lambda = \zeta$, into halves of roughly $\log_2(r) / 2$ bits.
+///
+/// A reduced basis $(a_1, b_1), (a_2, b_2)$ of the lattice
+/// $\{(a, b) : a + b \lambda \equiv 0 \pmod{r}\}$ is obtained from the
+/// extended Euclidean algorithm on $(r, \lambda)$ (Algorithm 3.74 of the
+/// _Guide to Elliptic Curve Cryptography_). A scalar is then decomposed by
+/// rounding its coordinates $(b_2 k / r, -b_1 k / r)$ in that basis to
+/// integers $(c_1, c_2)$, using precomputed fixed-point approximations of
+/// $|b_2| / r$ and $|b_1| / r$.
+#[derive(Clone, Copy)]
+struct Glv<F> {
+    /// $b_1$ as a field element.
+    b1: F,
+    /// $b_2$ as a field element.
+    b2: F,
+    /// $\lfloor 2^{256} |b_2| / r \rfloor$.
+    g1: U512,
+    /// $\lfloor 2^{256} |b_1| / r \rfloor$.
+    g2: U512,
+    /// Whether $c_1$ and $c_2$ must be negated, as determined by the signs of
+    /// $b_2$ and $b_1$.
+    negate: bool,
+}
+
+impl<F: WithSmallOrderMulGroup<3>> Glv<F> {
+    /// Computes the decomposition parameters, or returns `None` if the field's
+    /// representation is not 32 bytes.
+    fn new() -> Option<Self> {
+        if F::Repr::default().as_ref().len() != 32 {
+            return None;
+        }
+
+        let modulus = U512::from_le_bytes((-F::ONE).to_repr().as_ref()).add(&U512::ONE);
+        let lambda = U512::from_le_bytes(F::ZETA.to_repr().as_ref());
+
+        // Run the extended Euclidean algorithm on (r, λ), tracking the
+        // remainders r_i and the magnitudes of the coefficients t_i, which
+        // alternate in sign: t_i is positive for odd i and negative for even
+        // i > 0. Stop at the first remainder r_{l+1} below sqrt(r).
+        let step = |prev: &(U512, U512), cur: &(U512, U512)| {
+            let (quotient, remainder) = prev.0.div_rem(&cur.0);
+            (remainder, prev.1.add(&quotient.mul(&cur.1)))
+        };
+        let mut prev = (modulus, U512::ZERO);
+        let mut cur = (lambda, U512::ONE);
+        let mut index = 1;
+        while cur.0.mul(&cur.0) >= modulus {
+            let next = step(&prev, &cur);
+            prev = core::mem::replace(&mut cur, next);
+            index += 1;
+        }
+        let next = step(&prev, &cur);
+
+        // (a_1, b_1) = (r_{l+1}, -t_{l+1}), and (a_2, b_2) is the shorter of
+        // (r_l, -t_l) and (r_{l+2}, -t_{l+2}).
+        let norm = |v: &(U512, U512)| v.0.mul(&v.0).add(&v.1.mul(&v.1));
+        let b1 = cur.1;
+        let b2 = if norm(&prev) <= norm(&next) {
+            prev.1
+        } else {
+            next.1
+        };
+
+        // cur has index l + 1, so l is odd exactly when b_1 > 0 and b_2 < 0.
+        let negate = index % 2 == 0;
+        let g1 = b2.shl(256).div_rem(&modulus).0;
+        let g2 = b1.shl(256).div_rem(&modulus).0;
+
+        // Keep the rounded coordinates below 2^{NUM_BITS - 2} < r so that they
+        // can be converted into field elements directly.
+        let bound = F::NUM_BITS as usize - 2;
+        if g1.bits() > bound || g2.bits() > bound {
+            return None;
+        }
+
+        let (b1, b2) = (Self::to_field(&b1), Self::to_field(&b2));
+        Some(Glv {
+            b1: if negate { b1 } else { -b1 },
+            b2: if negate { -b2 } else { b2 },
+            g1,
+            g2,
+            negate,
+        })
+    }
+
+    /// Returns the magnitudes and signs of $k_1$ and $k_2$ for the scalar
+    /// $k$.
+    fn decompose(&self, k: &F) -> [(U512, bool); 2] {
+        let rounding = U512::ONE.shl(255);
+        let scalar = U512::from_le_bytes(k.to_repr().as_ref());
+        let c1 = Self::to_field(&scalar.mul(&self.g1).add(&rounding).shr(256));
+        let c2 = Self::to_field(&scalar.mul(&self.g2).add(&rounding).shr(256));
+        let (c1, c2) = if self.negate { (-c1, -c2) } else { (c1, c2) };
+
+        let k2 = -(c1 * self.b1 + c2 * self.b2);
+        let k1 = *k - k2 * F::ZETA;
+        [signed(&k1), signed(&k2)]
+    }
+
+    /// Converts an integer smaller than the modulus into a field element.
+    fn to_field(value: &U512) -> F {
+        let mut repr = F::Repr::default();
+        value.to_le_bytes(repr.as_mut());
+        F::from_repr(repr).unwrap()
+    }
+}
+
+/// A little-endian 512-bit unsigned integer, wide enough for products of the
+/// 256-bit integers that arise while decomposing scalars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct U512([u64; 8]);
+
+impl U512 {
+    pub(crate) const ZERO: Self = U512([0; 8]);
+    const ONE: Self = U512([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        let mut limbs = [0; 8];
+        for (i, byte) in bytes.iter().enumerate() {
+            limbs[i / 8] |= u64::from(*byte) << ((i % 8) * 8);
+        }
+        U512(limbs)
+    }
+
+    fn to_le_bytes(self, bytes: &mut [u8]) {
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (self.0[i / 8] >> ((i % 8) * 8)) as u8;
+        }
+    }
+
+    /// Returns the number of significant bits.
+    pub(crate) fn bits(&self) -> usize {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            Some(i) => 64 * (i + 1) - self.0[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub(crate) fn set_bit(&mut self, i: usize) {
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    /// Returns the `c` bits starting at bit `offset`, for `c < 64`.
+    pub(crate) fn window(&self, offset: usize, c: usize) -> usize {
+        let (limb, shift) = (offset / 64, offset % 64);
+        let mut window = self.0[limb] >> shift;
+        if shift + c > 64 && limb + 1 < 8 {
+            window |= self.0[limb + 1] << (64 - shift);
+        }
+        (window & ((1 << c) - 1)) as usize
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        let mut limbs = [0; 8];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = c1 | c2;
+        }
+        debug_assert!(!carry, "U512 addition overflowed");
+        U512(limbs)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        let mut limbs = [0; 8];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+            *limb = diff;
+            borrow = b1 | b2;
+        }
+        debug_assert!(!borrow, "U512 subtraction underflowed");
+        U512(limbs)
+    }
+
+    /// Returns the low 512 bits of the product.
+    fn mul(&self, other: &Self) -> Self {
+        let mut limbs = [0; 8];
+        for (i, a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b) in other.0.iter().take(8 - i).enumerate() {
+                let t = u128::from(*a) * u128::from(*b) + u128::from(limbs[i + j]) + carry;
+                limbs[i + j] = t as u64;
+                carry = t >> 64;
+            }
+        }
+        U512(limbs)
+    }
+
+    fn shl(&self, n: usize) -> Self {
+        let (limbs, bits) = (n / 64, n % 64);
+        let mut out = [0; 8];
+        for (i, limb) in out.iter_mut().enumerate().skip(limbs) {
+            *limb = self.0[i - limbs] << bits;
+            if bits > 0 && i > limbs {
+                *limb |= self.0[i - limbs - 1] >> (64 - bits);
+            }
+        }
+        U512(out)
+    }
+
+    fn shr(&self, n: usize) -> Self {
+        let (limbs, bits) = (n / 64, n % 64);
+        let mut out = [0; 8];
+        for (i, limb) in out.iter_mut().enumerate().take(8 - limbs) {
+            *limb = self.0[i + limbs] >> bits;
+            if bits > 0 && i + limbs + 1 < 8 {
+                *limb |= self.0[i + limbs + 1] << (64 - bits);
+            }
+        }
+        U512(out)
+    }
+
+    /// Returns the quotient and remainder of division by a nonzero `divisor`.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert_ne!(*divisor, Self::ZERO, "division by zero");
+
+        let mut quotient = Self::ZERO;
+        let mut remainder = *self;
+        if remainder >= *divisor {
+            let shift = remainder.bits() - divisor.bits();
+            let mut shifted = divisor.shl(shift);
+            for i in (0..=shift).rev() {
+                if remainder >= shifted {
+                    remainder = remainder.sub(&shifted);
+                    quotient.set_bit(i);
+                }
+                shifted = shifted.shr(1);
+            }
+        }
+        (quotient, remainder)
+    }
+}
+
+impl PartialOrd for U512 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U512 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{
+        ff::FromUniformBytes,
+        pasta_curves::{EpAffine, EqAffine, Fp, Fq, group::prime::PrimeCurveAffine},
+    };
+
+    fn scalar<F: FromUniformBytes<64>>(bytes: ([u8; 32], [u8; 32])) -> F {
+        let mut wide = [0; 64];
+        wide[..32].copy_from_slice(&bytes.0);
+        wide[32..].copy_from_slice(&bytes.1);
+        F::from_uniform_bytes(&wide)
+    }
+
+    fn check_decompose<F: WithSmallOrderMulGroup<3>>(k: F) {
+        let glv = Glv::<F>::new().unwrap();
+        let [(k1, neg1), (k2, neg2)] = glv.decompose(&k);
+        assert!(k1.bits() <= 128 && k2.bits() <= 128);
+
+        let k1 = Glv::<F>::to_field(&k1);
+        let k2 = Glv::<F>::to_field(&k2);
+        let k1 = if neg1 { -k1 } else { k1 };
+        let k2 = if neg2 { -k2 } else { k2 };
+        assert_eq!(k1 + k2 * F::ZETA, k);
+    }
+
+    fn check_mul<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) {
+        let expected = coeffs
+            .iter()
+            .zip(bases.iter())
+            .fold(C::Curve::identity(), |acc, (scalar, base)| {
+                acc + *base * *scalar
+            });
+
+        assert_eq!(mul(coeffs.iter(), bases.iter()), expected);
+        assert_eq!(mul_glv(coeffs.iter(), bases.iter()), expected);
+        assert_eq!(mul_windowed(coeffs.iter(), bases.iter()), expected);
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut coeffs = vec![];
+        for i in 0..1000 {
+            coeffs.push(Fp::from(i) * Fp::MULTIPLICATIVE_GENERATOR);
+        }
+
+        let mut bases = vec![];
+        for i in 0..1000 {
+            bases.push((EqAffine::generator() * Fp::from(i)).to_affine());
+        }
+
+        check_mul(&coeffs, &bases);
+    }
+
+    #[test]
+    fn test_mul_classified() {
+        // A trace-like mix of zeros, units, small signed values and enough
+        // full-width scalars to take the GLV path, over bases that repeat so
+        // that buckets collect many equal and opposite points.
+        let generator = EqAffine::generator();
+        let mut base = generator.to_curve();
+        let mut coeffs = vec![];
+        let mut bases = vec![];
+        for i in 0..(3 * GLV_THRESHOLD as u64) {
+            coeffs.push(match i % 8 {
+                0 => Fp::ZERO,
+                1 => Fp::ONE,
+                2 => -Fp::ONE,
+                3 => Fp::from(i),
+                4 => -Fp::from(i << 40),
+                _ => Fp::from(i).invert().unwrap(),
+            });
+            base += generator;
+            bases.push(if i % 16 < 4 {
+                generator
+            } else {
+                base.to_affine()
+            });
+        }
+        let expected = mul_windowed(coeffs.iter(), bases.iter());
+
+        assert_eq!(mul(coeffs.iter(), bases.iter()), expected);
+        assert_eq!(mul_glv(coeffs.iter(), bases.iter()), expected);
+        let (coeffs, bases) = (&coeffs[..64], &bases[..64]);
+        assert_eq!(
+            mul(coeffs.iter(), bases.iter()),
+            mul_windowed(coeffs.iter(), bases.iter())
+        );
+    }
+
+    #[test]
+    fn test_mul_units_and_narrow() {
+        // Only ±1 scalars, which never reach the windowed pipeline, and only
+        // boolean and byte-sized scalars, which fit a single window.
+        let generator = EqAffine::generator();
+        let bases: Vec<EqAffine> = (1..=200u64)
+            .map(|i| (generator * Fp::from(i % 37)).to_affine())
+            .collect();
+        let units: Vec<Fp> = (0..200)
+            .map(|i| if i % 3 == 0 { -Fp::ONE } else { Fp::ONE })
+            .collect();
+        let bools: Vec<Fp> = (0..200u64).map(|i| Fp::from(i % 2)).collect();
+        let bytes: Vec<Fp> = (0..200u64)
+            .map(|i| {
+                let k = Fp::from(i * 97 % 256);
+                if i % 5 == 0 { -k } else { k }
+            })
+            .collect();
+        for coeffs in [&units, &bools, &bytes] {
+            assert_eq!(
+                mul(coeffs.iter(), bases.iter()),
+                mul_windowed(coeffs.iter(), bases.iter())
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompose_edge_cases() {
+        for k in [
+            Fq::ZERO,
+            Fq::ONE,
+            -Fq::ONE,
+            Fq::ZETA,
+            -Fq::ZETA,
+            Fq::TWO_INV,
+            -Fq::TWO_INV,
+        ] {
+            check_decompose(k);
+        }
+        for k in [Fp::ZERO, Fp::ONE, -Fp::ONE, Fp::ZETA, Fp::TWO_INV] {
+            check_decompose(k);
+        }
+    }
+
+    #[test]
+    fn test_glv_endomorphism() {
+        assert!(compute_glv::<EpAffine>().is_some());
+        assert!(compute_glv::<EqAffine>().is_some());
+        // Repeated lookups agree with the parameters computed initially.
+        for _ in 0..2 {
+            assert_eq!(
+                glv::<EpAffine>().map(|glv| glv.g1),
+                Glv::<Fq>::new().map(|glv| glv.g1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_glv_edge_cases() {
+        let g = EpAffine::generator();
+        let p: EpAffine = (g * Fq::from(7)).into();
+        let q: EpAffine = (g * Fq::ZETA).into();
+
+        // Empty, identity bases, zero and extreme scalars, repeated bases
+        // (bucket doublings), opposite bases (bucket cancellations) and a base
+        // whose endomorphism image is already present.
+        check_mul::<EpAffine>(&[], &[]);
+        check_mul(&[Fq::from(3)], &[EpAffine::identity()]);
+        check_mul(&[Fq::ZERO, -Fq::ONE], &[p, g]);
+        check_mul(&[Fq::from(5), Fq::from(5), Fq::from(5)], &[p, p, p]);
+        check_mul(&[Fq::from(5), Fq::from(5)], &[p, -p]);
+        check_mul(&[-Fq::ONE, Fq::ONE, Fq::ZETA], &[g, q, g]);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn proptest_decompose(a in any::<[u8; 32]>(), b in any::<[u8; 32]>()) {
+            check_decompose(scalar::<Fp>((a, b)));
+            check_decompose(scalar::<Fq>((a, b)));
+        }
+
+        #[test]
+        fn proptest_mul_glv(
+            seeds in proptest::collection::vec((any::<[u8; 32]>(), any::<[u8; 32]>()), 0..48),
+        ) {
+            let coeffs: Vec<Fq> = seeds.iter().copied().map(scalar).collect();
+            let bases: Vec<EpAffine> = seeds
+                .iter()
+                .map(|&(a, b)| (EpAffine::generator() * scalar::<Fq>((b, a))).into())
+                .collect();
+            check_mul(&coeffs, &bases);
+
+            let coeffs: Vec<Fp> = seeds.iter().copied().map(scalar).collect();
+            let bases: Vec<EqAffine> = seeds
+                .iter()
+                .map(|&(a, b)| (EqAffine::generator() * scalar::<Fp>((b, a))).into())
+                .collect();
+            check_mul(&coeffs, &bases);
+        }
+    }
+}
@@ -125,7 +125,7 @@ where
 /// Given a number of scalars, returns the ideal bucket size (in bits) for
 /// multiexp, obtained through experimentation. This could probably be optimized
 /// further and for particular compilation targets.
-fn bucket_lookup(n: usize) -> usize {
+pub(crate) fn bucket_lookup(n: usize) -> usize {
     // Approximates ceil(ln(n)) without floating-point. See test_bucket_lookup_thresholds.
     const LN_THRESHOLDS: [usize; 15] = [
         4, 4, 32, 55, 149, 404, 1097, 2981, 8104, 22027, 59875, 162755, 442414, 1202605, 3269018,
@@ -174,9 +174,9 @@ pub fn batch_to_affine<C: CurveAffine, const N: usize>(projectives: [C::Curve; N
     affines
 }
 
-/// Compute the multiscalar multiplication $\langle \mathbf{a}, \mathbf{G} \rangle$ where
-/// $\mathbf{a} \in \mathbb{F}^n$ is a vector of scalars and $\mathbf{G} \in \mathbb{G}^n$
-/// is a vector of bases.
+/// Compute the multiscalar multiplication $\langle \mathbf{a}, \mathbf{G} \rangle$
+/// using Pippenger's algorithm with unsigned windows and mixed-addition
+/// buckets.
 ///
 /// When the `multicore` feature is enabled, window computation is parallelized
 /// using rayon.
@@ -185,7 +185,7 @@ pub fn batch_to_affine<C: CurveAffine, const N: usize>(projectives: [C::Curve; N
 ///
 /// The caller must ensure that `coeffs` and `bases` yield the same number of
 /// elements.
-pub fn mul<
+pub fn mul_windowed<
     'a,
     C: CurveAffine,
     A: IntoIterator<Item = &'a C::Scalar>,
@@ -741,33 +741,6 @@ mod proptests {
     }
 }
 
-#[test]
-fn test_mul() {
-    use crate::pasta_curves::group::{Curve, CurveAffine};
-
-    let mut coeffs = vec![];
-    for i in 0..1000 {
-        coeffs.push(
-            crate::pasta_curves::Fp::from(i) * crate::pasta_curves::Fp::MULTIPLICATIVE_GENERATOR,
-        );
-    }
-
-    let mut bases = vec![];
-    for i in 0..1000 {
-        bases.push(
-            (crate::pasta_curves::EqAffine::generator() * crate::pasta_curves::Fp::from(i))
-                .to_affine(),
-        );
-    }
-
-    let expected = coeffs.iter().zip(bases.iter()).fold(
-        crate::pasta_curves::Eq::identity(),
-        |acc, (scalar, point)| acc + point * scalar,
-    );
-
-    assert_eq!(mul(coeffs.iter(), bases.iter()), expected);
-}
-
 #[test]
 fn test_dot() {
     use crate::pasta_curves::Fp as F;
@@ -0,0 +1,73 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use ff::Field;
+use ragu_arithmetic::{Cycle, FixedGenerators};
+use ragu_circuits::{
+    Circuit, CircuitExt,
+    polynomials::{ProductionRank, sparse},
+    registry::{CircuitIndex, RegistryBuilder},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_testing::circuits::{BitsCircuit, MySimpleCircuit, SquareCircuit};
+use rand::{SeedableRng, rngs::StdRng};
+
+/// Traces `circuit` on `witness` and assembles its trace polynomial.
+fn assemble<C: Circuit<Fp>>(
+    circuit: C,
+    witness: C::Witness<'_>,
+    rng: &mut StdRng,
+) -> sparse::Polynomial<Fp, ProductionRank> {
+    let trace = circuit.trace(witness).unwrap().into_output();
+    RegistryBuilder::<Fp, ProductionRank>::new()
+        .register_circuit(circuit)
+        .unwrap()
+        .finalize()
+        .unwrap()
+        .assemble(&trace, CircuitIndex::new(0), rng)
+        .unwrap()
+}
+
+fn commit_bench(c: &mut Criterion) {
+    let generators = Pasta::host_generators(Pasta::baked());
+    let mut rng = StdRng::seed_from_u64(1234);
+    let x = Fp::random(&mut rng);
+
+    let polys = [
+        (
+            "square",
+            assemble(SquareCircuit { times: 1000 }, x, &mut rng),
+        ),
+        (
+            "simple",
+            assemble(MySimpleCircuit, (x.square(), x.pow([5])), &mut rng),
+        ),
+        ("bits", assemble(BitsCircuit { bits: 254 }, x, &mut rng)),
+    ];
+
+    for (_, poly) in &polys {
+        let coeffs = poly.iter_coeffs().collect::<Vec<_>>();
+        assert_eq!(
+            poly.commit(generators),
+            ragu_arithmetic::mul_windowed(coeffs.iter(), generators.g())
+        );
+    }
+
+    let mut group = c.benchmark_group("commit_trace");
+    for (name, poly) in &polys {
+        group.bench_with_input(BenchmarkId::from_parameter(name), poly, |b, poly| {
+            b.iter(|| poly.commit(generators));
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("commit_trace_windowed");
+    for (name, poly) in &polys {
+        let coeffs = poly.iter_coeffs().collect::<Vec<_>>();
+        group.bench_with_input(BenchmarkId::from_parameter(name), &coeffs, |b, coeffs| {
+            b.iter(|| ragu_arithmetic::mul_windowed(coeffs.iter(), generators.g()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, commit_bench);
+criterion_main!(benches);
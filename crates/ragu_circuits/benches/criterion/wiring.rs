@@ -0,0 +1,142 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use ff::Field;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{
+    Circuit, WithAux,
+    polynomials::ProductionRank,
+    registry::{CircuitIndex, RegistryBuilder},
+};
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    gadgets::{Bound, Kind},
+    routines::{Prediction, Routine},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_primitives::{Element, allocator::Standard, poseidon::Sponge};
+use rand::{SeedableRng, rngs::StdRng};
+
+/// A routine that chains `permutations` Poseidon permutations over its input.
+///
+/// Each permutation is itself a (memoized) leaf routine, so this routine is a
+/// non-leaf segment that the `multicore` evaluators defer to a parallel job.
+#[derive(Clone)]
+struct PoseidonChain {
+    permutations: usize,
+}
+
+impl Routine<Fp> for PoseidonChain {
+    type Input = Kind![Fp; Element<'_, _>];
+    type Output = Kind![Fp; Element<'_, _>];
+    type Aux<'dr> = ();
+
+    fn execute<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        input: Bound<'dr, D, Self::Input>,
+        _aux: DriverValue<D, Self::Aux<'dr>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let mut sponge = Sponge::new(dr, Pasta::circuit_poseidon(Pasta::baked()));
+        let mut value = input;
+        for _ in 0..self.permutations {
+            sponge.absorb(dr, &value)?;
+            value = sponge.squeeze(dr)?;
+        }
+        Ok(value)
+    }
+
+    fn predict<'dr, D: Driver<'dr, F = Fp, Wire = ()>>(
+        &self,
+        _dr: &mut D,
+        _input: &Bound<'dr, D, Self::Input>,
+    ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>> {
+        Ok(Prediction::Unknown(D::unit()))
+    }
+}
+
+/// Circuit that calls `PoseidonChain` `calls` times in sequence.
+struct PoseidonCircuit {
+    calls: usize,
+    permutations: usize,
+}
+
+impl Circuit<Fp> for PoseidonCircuit {
+    type Instance<'instance> = Fp;
+    type Output = Kind![Fp; Element<'_, _>];
+    type Witness<'witness> = Fp;
+    type Aux<'witness> = ();
+
+    fn instance<'dr, 'instance: 'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        instance: DriverValue<D, Self::Instance<'instance>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let allocator = &mut Standard::new();
+        Element::alloc(dr, allocator, instance)
+    }
+
+    fn witness<'dr, 'witness: 'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'witness>>,
+    ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'witness>>>> {
+        let allocator = &mut Standard::new();
+        let mut result = Element::alloc(dr, allocator, witness)?;
+        let routine = PoseidonChain {
+            permutations: self.permutations,
+        };
+
+        for _ in 0..self.calls {
+            result = dr.routine(routine.clone(), result)?;
+        }
+
+        Ok(WithAux::new(result, D::unit()))
+    }
+}
+
+fn wiring_bench(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let x = Fp::random(&mut rng);
+    let y = Fp::random(&mut rng);
+    let w = CircuitIndex::new(0).omega_j::<Fp>();
+
+    let permutations = 2;
+    let registries = [1, 2, 3].map(|calls| {
+        let registry = RegistryBuilder::<Fp, ProductionRank>::new()
+            .register_circuit(PoseidonCircuit {
+                calls,
+                permutations,
+            })
+            .unwrap()
+            .finalize()
+            .unwrap();
+        (calls, registry)
+    });
+
+    let mut group = c.benchmark_group("wiring_poseidon_sxy");
+    for (calls, registry) in &registries {
+        group.bench_with_input(BenchmarkId::from_parameter(calls), calls, |b, _| {
+            b.iter(|| registry.circuit_xy(CircuitIndex::new(0), x, y));
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("wiring_poseidon_sx");
+    for (calls, registry) in &registries {
+        group.bench_with_input(BenchmarkId::from_parameter(calls), calls, |b, _| {
+            b.iter(|| registry.wx(w, x));
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("wiring_poseidon_sy");
+    for (calls, registry) in &registries {
+        group.bench_with_input(BenchmarkId::from_parameter(calls), calls, |b, _| {
+            b.iter(|| registry.wy(w, y));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, wiring_bench);
+criterion_main!(benches);
@@ -0,0 +1,712 @@
+//! Export of a circuit's constraint system for external tooling.
+//!
+//! The `sx`, `sy` and `sxy` evaluators only ever see the wiring polynomial at
+//! concrete points. This module instead synthesizes a circuit on a driver whose
+//! wires are *symbolic*: every wire is a reference to the $a$, $b$, $c$
+//! or $d$ wire of some gate, and every virtual wire is the linear combination
+//! of such references that defines it. The result is a [`ConstraintSystem`]
+//! that can be rendered as JSON ([`to_json`](ConstraintSystem::to_json)) or as
+//! line-based text ([`to_text`](ConstraintSystem::to_text)) for diffing,
+//! visualization or auditing outside of Ragu.
+//!
+//! # Layout
+//!
+//! The exported constraint system is laid out exactly as the wiring
+//! polynomial is, according to a floor plan:
+//!
+//! - Every gate $i$ imposes $a\_i \cdot b\_i = c\_i$ and $c\_i \cdot d\_i = 0$.
+//!   Gates are implicit, so only their number is exported. Gate $0$ is the
+//!   SYSTEM gate, whose $d\_0$ wire is [`Driver::ONE`].
+//! - Every linear constraint is exported with its *position*, the power of $Y$
+//!   it occupies in $s(X, Y)$. Within each segment the first emitted
+//!   constraint takes the highest position, matching the order in which
+//!   Horner's rule assigns powers of $Y$.
+//! - Every segment is exported with its floor plan offsets, so routine
+//!   boundaries can be recovered from gate indices and positions.
+//! - The public outputs are the constraints that bind the circuit's output
+//!   wires to the coefficients of $k(Y)$. Output $i$ sits at position
+//!   $p - i$, where $p$ is the number of outputs; position $0$ holds the ONE
+//!   constraint.
+//!
+//! The registry key constraint at $Y^{4n-1}$ is not part of any circuit's
+//! constraint system and is not exported.
+//!
+//! # Digest
+//!
+//! [`ConstraintSystem::digest`] hashes everything above (but not the
+//! diagnostic segment names) with a domain-separated BLAKE2b, so that two
+//! builds can be compared for unintended constraint changes without diffing
+//! the full export. Exports taken from a [`Registry`](crate::registry::Registry)
+//! additionally carry the registry digest, which changes whenever *any*
+//! registered circuit does.
+//!
+//! [`Driver::ONE`]: ragu_core::drivers::Driver::ONE
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Write as _};
+
+use ragu_arithmetic::{
+    Coeff,
+    ff::{Field, PrimeField},
+};
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverTypes, LinearExpression, emulator::Emulator},
+    gadgets::Bound,
+    maybe::Empty,
+    routines::Routine,
+};
+
+use crate::{DriverScope, SegmentRecord, floor_planner::ConstraintSegment, raw::RawCircuit};
+
+/// The version of the exported format.
+///
+/// This is bumped whenever the JSON or text rendering, or the encoding hashed
+/// by [`ConstraintSystem::digest`], changes.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// One of the four wires of a gate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WireKind {
+    /// The left input of the gate's multiplication.
+    A,
+    /// The right input of the gate's multiplication.
+    B,
+    /// The product of the gate's multiplication.
+    C,
+    /// The auxiliary wire, constrained by $c \cdot d = 0$.
+    D,
+}
+
+/// A reference to an allocated wire, identified by its gate's absolute index
+/// in the floor plan.
+///
+/// Displayed as the lowercase wire kind followed by the gate index, such as
+/// `a3` or `d0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WireRef {
+    gate: usize,
+    kind: WireKind,
+}
+
+impl WireRef {
+    /// The [`Driver::ONE`] wire, which is the $d$ wire of the SYSTEM gate.
+    pub const ONE: Self = WireRef {
+        gate: 0,
+        kind: WireKind::D,
+    };
+
+    /// The absolute index of the wire's gate.
+    pub fn gate(&self) -> usize {
+        self.gate
+    }
+
+    /// Which of the gate's wires this is.
+    pub fn kind(&self) -> WireKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for WireRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            WireKind::A => 'a',
+            WireKind::B => 'b',
+            WireKind::C => 'c',
+            WireKind::D => 'd',
+        };
+        write!(f, "{kind}{}", self.gate)
+    }
+}
+
+/// A segment of the circuit and its placement in the floor plan.
+///
+/// See [`SegmentRecord`] for how a circuit is divided into segments.
+#[derive(Clone, Debug)]
+pub struct Segment {
+    name: &'static str,
+    parent: Option<usize>,
+    gate_start: usize,
+    num_gates: usize,
+    constraint_start: usize,
+    num_constraints: usize,
+}
+
+impl Segment {
+    /// The type name of the routine that produced this segment, or of the
+    /// circuit for the root segment. See [`SegmentRecord::name`].
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The index of the segment whose scope invoked this routine, or `None`
+    /// for the root segment.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    /// The absolute index of this segment's first gate.
+    pub fn gate_start(&self) -> usize {
+        self.gate_start
+    }
+
+    /// The number of gates in this segment.
+    pub fn num_gates(&self) -> usize {
+        self.num_gates
+    }
+
+    /// The lowest position of this segment's constraints.
+    pub fn constraint_start(&self) -> usize {
+        self.constraint_start
+    }
+
+    /// The number of constraints in this segment.
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+}
+
+/// A constraint requiring a linear combination of wires to equal zero.
+#[derive(Clone, Debug)]
+pub struct LinearConstraint<F> {
+    position: usize,
+    segment: usize,
+    terms: Vec<(WireRef, F)>,
+}
+
+impl<F> LinearConstraint<F> {
+    /// The power of $Y$ this constraint occupies in $s(X, Y)$.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The index of the segment that emitted this constraint.
+    pub fn segment(&self) -> usize {
+        self.segment
+    }
+
+    /// The nonzero terms of the linear combination, ordered by wire.
+    pub fn terms(&self) -> &[(WireRef, F)] {
+        &self.terms
+    }
+}
+
+/// The constraint system of a circuit, as laid out by a floor plan.
+///
+/// Obtained through [`CircuitExt::export`](crate::CircuitExt::export) or
+/// [`Registry::export`](crate::registry::Registry::export). See the
+/// [module documentation](self) for the layout.
+#[derive(Clone, Debug)]
+pub struct ConstraintSystem<F> {
+    num_gates: usize,
+    degree_ky: usize,
+    segments: Vec<Segment>,
+    constraints: Vec<LinearConstraint<F>>,
+    registry_digest: Option<F>,
+}
+
+impl<F> ConstraintSystem<F> {
+    /// The number of gates, including the SYSTEM gate.
+    pub fn num_gates(&self) -> usize {
+        self.num_gates
+    }
+
+    /// The number of linear constraints, including the ONE constraint and
+    /// those binding the public outputs.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// The segments of the circuit in DFS synthesis order.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The linear constraints, ordered by position.
+    pub fn constraints(&self) -> &[LinearConstraint<F>] {
+        &self.constraints
+    }
+
+    /// The constraints binding each public output of the circuit, in the
+    /// order the outputs are written.
+    pub fn outputs(&self) -> impl Iterator<Item = &LinearConstraint<F>> {
+        (0..self.degree_ky).map(|i| &self.constraints[self.degree_ky - i])
+    }
+
+    /// The digest of the registry this constraint system was exported from,
+    /// if any.
+    pub fn registry_digest(&self) -> Option<&F> {
+        self.registry_digest.as_ref()
+    }
+
+    pub(crate) fn with_registry_digest(mut self, digest: F) -> Self {
+        self.registry_digest = Some(digest);
+        self
+    }
+
+    /// Removes the terms of the ONE constraint, for wiring objects that strip
+    /// its contribution.
+    pub(crate) fn strip_one(mut self) -> Self {
+        self.constraints[0].terms.clear();
+        self
+    }
+}
+
+impl<F: PrimeField> ConstraintSystem<F> {
+    /// Returns a digest of this constraint system.
+    ///
+    /// The digest covers the format version, the gates, the segments and
+    /// their placement, and every linear constraint. It does not cover the
+    /// segment names, which are for diagnostics only, nor the registry
+    /// digest.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut state = blake2b_simd::Params::new()
+            .hash_length(32)
+            .personal(b"ragu_cs_export__")
+            .to_state();
+        let write_usize = |state: &mut blake2b_simd::State, value: usize| {
+            state.update(&(value as u64).to_le_bytes());
+        };
+
+        state.update(&FORMAT_VERSION.to_le_bytes());
+        write_usize(&mut state, self.num_gates);
+        write_usize(&mut state, self.degree_ky);
+
+        write_usize(&mut state, self.segments.len());
+        for segment in &self.segments {
+            write_usize(&mut state, segment.parent.map_or(0, |parent| parent + 1));
+            write_usize(&mut state, segment.gate_start);
+            write_usize(&mut state, segment.num_gates);
+            write_usize(&mut state, segment.constraint_start);
+            write_usize(&mut state, segment.num_constraints);
+        }
+
+        write_usize(&mut state, self.constraints.len());
+        for constraint in &self.constraints {
+            write_usize(&mut state, constraint.segment);
+            write_usize(&mut state, constraint.terms.len());
+            for (wire, coeff) in &constraint.terms {
+                write_usize(&mut state, wire.gate);
+                state.update(&[wire.kind as u8]);
+                state.update(coeff.to_repr().as_ref());
+            }
+        }
+
+        state
+            .finalize()
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32")
+    }
+
+    /// Renders this constraint system as JSON.
+    ///
+    /// Field elements are rendered as big-endian hexadecimal strings, and
+    /// each term as a `[wire, coefficient]` pair. Public outputs are rendered
+    /// as the positions of their constraints.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out)
+            .expect("writing to a string succeeds");
+        out
+    }
+
+    /// Renders this constraint system as line-based text, with one segment,
+    /// constraint or output per line, suitable for diffing.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out)
+            .expect("writing to a string succeeds");
+        out
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "{{")?;
+        writeln!(out, "  \"version\": {FORMAT_VERSION},")?;
+        writeln!(out, "  \"digest\": \"{}\",", Hex(&self.digest()))?;
+        match &self.registry_digest {
+            Some(digest) => writeln!(out, "  \"registry_digest\": \"{}\",", FieldHex(digest))?,
+            None => writeln!(out, "  \"registry_digest\": null,")?,
+        }
+        writeln!(out, "  \"num_gates\": {},", self.num_gates)?;
+        writeln!(out, "  \"num_constraints\": {},", self.num_constraints())?;
+
+        writeln!(out, "  \"segments\": [")?;
+        for (i, segment) in self.segments.iter().enumerate() {
+            write!(out, "    {{\"name\": \"")?;
+            for c in segment.name.chars() {
+                match c {
+                    '"' | '\\' => write!(out, "\\{c}")?,
+                    c => write!(out, "{c}")?,
+                }
+            }
+            write!(out, "\", \"parent\": ")?;
+            match segment.parent {
+                Some(parent) => write!(out, "{parent}")?,
+                None => write!(out, "null")?,
+            }
+            write!(
+                out,
+                ", \"gate_start\": {}, \"num_gates\": {}, \"constraint_start\": {}, \"num_constraints\": {}}}",
+                segment.gate_start,
+                segment.num_gates,
+                segment.constraint_start,
+                segment.num_constraints
+            )?;
+            writeln!(out, "{}", separator(i, self.segments.len()))?;
+        }
+        writeln!(out, "  ],")?;
+
+        writeln!(out, "  \"constraints\": [")?;
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            write!(
+                out,
+                "    {{\"position\": {}, \"segment\": {}, \"terms\": [",
+                constraint.position, constraint.segment
+            )?;
+            for (j, (wire, coeff)) in constraint.terms.iter().enumerate() {
+                if j > 0 {
+                    write!(out, ", ")?;
+                }
+                write!(out, "[\"{wire}\", \"{}\"]", FieldHex(coeff))?;
+            }
+            writeln!(out, "]}}{}", separator(i, self.constraints.len()))?;
+        }
+        writeln!(out, "  ],")?;
+
+        write!(out, "  \"outputs\": [")?;
+        for (i, output) in self.outputs().enumerate() {
+            if i > 0 {
+                write!(out, ", ")?;
+            }
+            write!(out, "{}", output.position)?;
+        }
+        writeln!(out, "]")?;
+        writeln!(out, "}}")
+    }
+
+    fn write_text(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "ragu-constraint-system {FORMAT_VERSION}")?;
+        writeln!(out, "digest {}", Hex(&self.digest()))?;
+        if let Some(digest) = &self.registry_digest {
+            writeln!(out, "registry {}", FieldHex(digest))?;
+        }
+        writeln!(out, "gates {}", self.num_gates)?;
+        writeln!(out, "constraints {}", self.num_constraints())?;
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            write!(out, "segment {i} parent ")?;
+            match segment.parent {
+                Some(parent) => write!(out, "{parent}")?,
+                None => write!(out, "-")?,
+            }
+            writeln!(
+                out,
+                " gates {}+{} constraints {}+{} {}",
+                segment.gate_start,
+                segment.num_gates,
+                segment.constraint_start,
+                segment.num_constraints,
+                segment.name
+            )?;
+        }
+
+        for constraint in &self.constraints {
+            write!(
+                out,
+                "constraint {} segment {}:",
+                constraint.position, constraint.segment
+            )?;
+            if constraint.terms.is_empty() {
+                write!(out, " 0")?;
+            }
+            for (j, (wire, coeff)) in constraint.terms.iter().enumerate() {
+                if j > 0 {
+                    write!(out, " +")?;
+                }
+                write!(out, " {}*{wire}", FieldHex(coeff))?;
+            }
+            writeln!(out)?;
+        }
+
+        for (i, output) in self.outputs().enumerate() {
+            writeln!(out, "output {i} {}", output.position)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the separator following the `i`-th of `len` JSON array elements.
+fn separator(i: usize, len: usize) -> &'static str {
+    if i + 1 < len { "," } else { "" }
+}
+
+/// Displays bytes as lowercase hexadecimal.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Displays a field element as a big-endian hexadecimal literal without
+/// leading zeros.
+struct FieldHex<'a, F>(&'a F);
+
+impl<F: PrimeField> fmt::Display for FieldHex<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = self.0.to_repr();
+        let mut digits = String::new();
+        for byte in repr.as_ref().iter().rev() {
+            write!(digits, "{byte:02x}")?;
+        }
+        let digits = digits.trim_start_matches('0');
+        write!(f, "0x{}", if digits.is_empty() { "0" } else { digits })
+    }
+}
+
+/// A wire of the [`Exporter`]: either an allocated wire or a virtual wire
+/// defined by a linear combination of allocated wires.
+#[derive(Clone)]
+enum Wire<F> {
+    Allocated(WireRef),
+    Virtual(Vec<(WireRef, F)>),
+}
+
+/// A linear combination of allocated wires, with virtual wires expanded.
+struct Terms<F: Field> {
+    terms: BTreeMap<WireRef, F>,
+    gain: Coeff<F>,
+}
+
+impl<F: Field> Default for Terms<F> {
+    fn default() -> Self {
+        Terms {
+            terms: BTreeMap::new(),
+            gain: Coeff::One,
+        }
+    }
+}
+
+impl<F: Field> Terms<F> {
+    fn push(&mut self, wire: WireRef, coeff: F) {
+        *self.terms.entry(wire).or_insert(F::ZERO) += coeff;
+    }
+
+    /// Returns the nonzero terms, ordered by wire.
+    fn into_terms(self) -> Vec<(WireRef, F)> {
+        self.terms
+            .into_iter()
+            .filter(|(_, coeff)| !bool::from(coeff.is_zero()))
+            .collect()
+    }
+}
+
+impl<F: Field> LinearExpression<Wire<F>, F> for Terms<F> {
+    fn add_term(mut self, wire: &Wire<F>, coeff: Coeff<F>) -> Self {
+        let coeff = (coeff * self.gain).value();
+        match wire {
+            Wire::Allocated(wire) => self.push(*wire, coeff),
+            Wire::Virtual(terms) => {
+                for (wire, inner) in terms {
+                    self.push(*wire, *inner * coeff);
+                }
+            }
+        }
+        self
+    }
+
+    fn gain(mut self, coeff: Coeff<F>) -> Self {
+        self.gain = self.gain * coeff;
+        self
+    }
+}
+
+/// Per-routine state saved and restored across routine boundaries.
+struct ExportScope {
+    /// DFS index of the current segment.
+    segment: usize,
+    /// Absolute index of the next gate to be allocated.
+    /// Initialized to `segment.gate_start` on routine entry.
+    gates: usize,
+    /// Number of constraints emitted so far in the current segment.
+    constraints: usize,
+}
+
+/// A [`Driver`] that records a circuit's constraints over symbolic wires.
+struct Exporter<'fp, F> {
+    /// Per-routine scoped state.
+    scope: ExportScope,
+
+    /// Floor plan mapping DFS segment index to absolute offsets.
+    floor_plan: &'fp [ConstraintSegment],
+
+    /// Global monotonic DFS counter for routine entries.
+    current_routine: usize,
+
+    /// Recorded constraints as `(segment, local index, terms)`, in synthesis
+    /// order.
+    constraints: Vec<(usize, usize, Vec<(WireRef, F)>)>,
+}
+
+impl<F> DriverScope<ExportScope> for Exporter<'_, F> {
+    fn scope(&mut self) -> &mut ExportScope {
+        &mut self.scope
+    }
+}
+
+impl<F: Field> DriverTypes for Exporter<'_, F> {
+    type MaybeKind = Empty;
+    type LCadd = Terms<F>;
+    type LCenforce = Terms<F>;
+    type ImplField = F;
+    type ImplWire = Wire<F>;
+    type Extra = usize;
+
+    /// Allocates the next gate of the current segment, returning references
+    /// to its $a$, $b$ and $c$ wires and its index as the
+    /// [`Extra`](DriverTypes::Extra) token.
+    fn gate(
+        &mut self,
+        _: impl Fn() -> Result<(Coeff<F>, Coeff<F>, Coeff<F>)>,
+    ) -> Result<(Wire<F>, Wire<F>, Wire<F>, usize)> {
+        let gate = self.scope.gates;
+        self.scope.gates += 1;
+
+        let wire = |kind| Wire::Allocated(WireRef { gate, kind });
+        Ok((
+            wire(WireKind::A),
+            wire(WireKind::B),
+            wire(WireKind::C),
+            gate,
+        ))
+    }
+
+    fn assign_extra(&mut self, gate: usize, _: impl Fn() -> Result<Coeff<F>>) -> Result<Wire<F>> {
+        Ok(Wire::Allocated(WireRef {
+            gate,
+            kind: WireKind::D,
+        }))
+    }
+}
+
+impl<'fp, F: Field> Driver<'fp> for Exporter<'fp, F> {
+    type F = F;
+    type Wire = Wire<F>;
+
+    const ONE: Self::Wire = Wire::Allocated(WireRef::ONE);
+
+    /// Expands a linear combination into a virtual wire.
+    fn add(&mut self, lc: impl Fn(Self::LCadd) -> Self::LCadd) -> Self::Wire {
+        Wire::Virtual(lc(Terms::default()).into_terms())
+    }
+
+    /// Records a linear constraint in the current segment.
+    fn enforce_zero(&mut self, lc: impl Fn(Self::LCenforce) -> Self::LCenforce) -> Result<()> {
+        let terms = lc(Terms::default()).into_terms();
+        self.constraints
+            .push((self.scope.segment, self.scope.constraints, terms));
+        self.scope.constraints += 1;
+        Ok(())
+    }
+
+    /// Records a routine invocation at its floor plan segment.
+    fn routine<Ro: Routine<Self::F> + 'fp>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        self.current_routine += 1;
+        let segment = self.current_routine;
+        let init = ExportScope {
+            segment,
+            gates: self.floor_plan[segment].gate_start,
+            constraints: 0,
+        };
+
+        self.with_scope(init, |this| {
+            let aux = Emulator::predict(&routine, &input)?.into_aux();
+            routine.execute(this, input, aux)
+        })
+    }
+}
+
+/// Exports the constraint system of a circuit laid out by `floor_plan`.
+///
+/// # Arguments
+///
+/// - `circuit`: The circuit whose constraint system to export.
+/// - `segment_records`: The circuit's segment records, from which `floor_plan`
+///   was computed.
+/// - `floor_plan`: Per-segment absolute offsets, computed by a
+///   [`FloorPlanner`](crate::floor_planner::FloorPlanner).
+pub(crate) fn eval<F: Field, RC: RawCircuit<F>>(
+    circuit: &RC,
+    segment_records: &[SegmentRecord],
+    floor_plan: &[ConstraintSegment],
+) -> Result<ConstraintSystem<F>> {
+    let mut exporter = Exporter {
+        scope: ExportScope {
+            segment: 0,
+            gates: 0,
+            constraints: 0,
+        },
+        floor_plan,
+        current_routine: 0,
+        constraints: Vec::new(),
+    };
+
+    let orchestrated = crate::raw::orchestrate(&mut exporter, circuit, Empty)?;
+
+    assert_eq!(
+        exporter.current_routine + 1,
+        floor_plan.len(),
+        "floor plan routine count must match synthesis"
+    );
+
+    // Place each constraint at its position; see "Layout" in the module
+    // documentation.
+    let mut constraints: Vec<_> = exporter
+        .constraints
+        .into_iter()
+        .map(|(segment, index, terms)| {
+            let seg = &floor_plan[segment];
+            LinearConstraint {
+                position: seg.constraint_start + seg.num_constraints - 1 - index,
+                segment,
+                terms,
+            }
+        })
+        .collect();
+    constraints.sort_by_key(|constraint| constraint.position);
+    assert!(
+        constraints
+            .iter()
+            .enumerate()
+            .all(|(position, constraint)| constraint.position == position),
+        "constraint positions must match floor plan"
+    );
+
+    let segments = segment_records
+        .iter()
+        .zip(floor_plan)
+        .map(|(record, seg)| Segment {
+            name: record.name(),
+            parent: record.parent(),
+            gate_start: seg.gate_start,
+            num_gates: seg.num_gates,
+            constraint_start: seg.constraint_start,
+            num_constraints: seg.num_constraints,
+        })
+        .collect();
+
+    Ok(ConstraintSystem {
+        num_gates: floor_plan.iter().map(|seg| seg.num_gates).sum(),
+        degree_ky: orchestrated.degree_ky,
+        segments,
+        constraints,
+        registry_digest: None,
+    })
+}
@@ -12,8 +12,8 @@
 //! **indices**. All consumers — the three `s(X, Y)` evaluators, the `rx`
 //! evaluator, and `assemble` — depend on this convention.
 //!
-//! The root segment (index 0) is always pinned at offset 0; see the
-//! [`floor_plan`] function for details.
+//! The root segment (index 0) is always pinned at offset 0; see
+//! [`FloorPlanner::plan`] for details.
 //!
 //! ```text
 //!  Synthesis trace              Seg
@@ -37,10 +37,22 @@
 //! ```
 //!
 //! See [`SegmentRecord`] for a fully worked example with concrete numbers.
+//!
+//! # Floor planners
+//!
+//! A [`FloorPlanner`] only chooses the order in which the non-root segments
+//! are laid out after the root; offsets are then assigned by a prefix sum in
+//! that order. This keeps both invariants above by construction and never
+//! leaves gaps, so every floor plan of a circuit occupies exactly as many
+//! gates and constraints as the circuit uses.
+//!
+//! - [`DfsPlanner`] (the default) lays segments out in synthesis order.
+//! - [`PackingPlanner`] groups segments with the same [`RoutineFingerprint`]
+//!   so that repeated routines are placed back to back at a fixed stride.
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
-use super::metrics::SegmentRecord;
+use super::metrics::{RoutineFingerprint, RoutineIdentity, SegmentRecord};
 
 /// A segment's placement in a constraint system.
 ///
@@ -56,10 +68,8 @@ use super::metrics::SegmentRecord;
 /// correspondence. The root segment (index 0) must always be placed at
 /// the polynomial origin (both offsets zero).
 ///
-/// Currently, segments keep their synthesis (DFS) order and positions are
-/// computed by a trivial prefix sum over per-segment constraint counts. A
-/// future floor planner could reorder segments for alignment or packing, but
-/// the current implementation does not.
+/// Positions are computed by a prefix sum over per-segment gate and
+/// constraint counts, in the order chosen by a [`FloorPlanner`].
 ///
 /// [`Routine`]: ragu_core::routines::Routine
 pub struct ConstraintSegment {
@@ -71,33 +81,139 @@ pub struct ConstraintSegment {
     pub(crate) num_gates: usize,
     /// Number of constraints in this segment.
     pub(crate) num_constraints: usize,
+    /// Fingerprint under which the wiring evaluators may memoize this
+    /// segment's contribution, or `None` if it must be synthesized in place.
+    ///
+    /// Only leaf routine segments (those that invoke no nested routines) are
+    /// eligible, since a nested call would jump to an unrelated offset.
+    pub(crate) memo_key: Option<RoutineFingerprint>,
 }
 
-/// Computes a floor plan from per-segment constraint records.
+/// A strategy for laying out a circuit's segments in the polynomial.
 ///
-/// Converts per-segment constraint counts into absolute offsets via prefix
-/// sum, preserving synthesis (DFS) order.
-pub fn floor_plan(segment_records: &[SegmentRecord]) -> Vec<ConstraintSegment> {
-    let mut result = Vec::with_capacity(segment_records.len());
-    let mut gate_start = 0usize;
-    let mut constraint_start = 0usize;
-    for record in segment_records {
-        result.push(ConstraintSegment {
-            gate_start,
-            constraint_start,
-            num_gates: record.num_gates(),
-            num_constraints: record.num_constraints(),
-        });
-        gate_start += record.num_gates();
-        constraint_start += record.num_constraints();
-    }
+/// Implementations choose the order of the non-root segments; the provided
+/// [`plan`](FloorPlanner::plan) method assigns the offsets. See the
+/// [module documentation](self) for the invariants this preserves.
+pub trait FloorPlanner: Send + Sync {
+    /// Returns the DFS indices of all non-root segments (`1..len`) in the
+    /// order they should be laid out after the root segment.
+    fn order(&self, segment_records: &[SegmentRecord]) -> Vec<usize>;
+
+    /// Computes a floor plan from per-segment constraint records.
+    ///
+    /// Offsets are assigned by prefix sum in the order returned by
+    /// [`order`](FloorPlanner::order), starting with the root segment at the
+    /// polynomial origin. Leaf routine segments are tagged with their
+    /// fingerprint so that the wiring evaluators can memoize them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`order`](FloorPlanner::order) does not return a permutation
+    /// of the non-root segment indices.
+    fn plan(&self, segment_records: &[SegmentRecord]) -> Vec<ConstraintSegment> {
+        let mut has_children = alloc::vec![false; segment_records.len()];
+        for record in segment_records {
+            if let Some(parent) = record.parent() {
+                has_children[parent] = true;
+            }
+        }
+
+        let mut result: Vec<_> = segment_records
+            .iter()
+            .zip(has_children)
+            .map(|(record, has_children)| ConstraintSegment {
+                gate_start: 0,
+                constraint_start: 0,
+                num_gates: record.num_gates(),
+                num_constraints: record.num_constraints(),
+                memo_key: match *record.identity() {
+                    RoutineIdentity::Routine(fingerprint) if !has_children => Some(fingerprint),
+                    _ => None,
+                },
+            })
+            .collect();
+        if result.is_empty() {
+            return result;
+        }
+
+        let order = self.order(segment_records);
+        assert_eq!(
+            order.len() + 1,
+            result.len(),
+            "floor planner must order every non-root segment"
+        );
+
+        let mut placed = alloc::vec![false; result.len()];
+        let mut gate_start = 0usize;
+        let mut constraint_start = 0usize;
+        for index in core::iter::once(0).chain(order) {
+            assert!(
+                !core::mem::replace(&mut placed[index], true),
+                "floor planner must place each segment exactly once"
+            );
+            let segment = &mut result[index];
+            segment.gate_start = gate_start;
+            segment.constraint_start = constraint_start;
+            gate_start += segment.num_gates;
+            constraint_start += segment.num_constraints;
+        }
 
-    assert!(
         result
-            .first()
-            .is_none_or(|r| r.gate_start == 0 && r.constraint_start == 0),
-        "root segment must be placed at the polynomial origin"
-    );
+    }
+}
+
+/// The default [`FloorPlanner`], which lays segments out in synthesis (DFS)
+/// order.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DfsPlanner;
 
-    result
+impl FloorPlanner for DfsPlanner {
+    fn order(&self, segment_records: &[SegmentRecord]) -> Vec<usize> {
+        (1..segment_records.len()).collect()
+    }
+}
+
+/// A [`FloorPlanner`] that places segments with the same
+/// [`RoutineFingerprint`] next to each other.
+///
+/// Each group of identical routine segments is laid out contiguously, at the
+/// position of its first invocation in synthesis order, so that successive
+/// invocations sit at a fixed stride of gates and constraints. Segments
+/// within a group, and segments with no identical counterpart, keep their
+/// synthesis order.
+///
+/// Like every floor planner, it assigns offsets without gaps, so a packed
+/// circuit uses exactly as many gates and constraints as it does under
+/// [`DfsPlanner`] and needs the same [`Rank`](crate::polynomials::Rank).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PackingPlanner;
+
+impl FloorPlanner for PackingPlanner {
+    fn order(&self, segment_records: &[SegmentRecord]) -> Vec<usize> {
+        let mut first_seen = BTreeMap::new();
+        let group: Vec<usize> = segment_records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| match *record.identity() {
+                RoutineIdentity::Routine(fingerprint) => {
+                    *first_seen.entry(fingerprint).or_insert(index)
+                }
+                RoutineIdentity::Root => index,
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (1..segment_records.len()).collect();
+        order.sort_by_key(|&index| group[index]);
+        order
+    }
+}
+
+/// Computes a floor plan from per-segment constraint records using the
+/// default [`DfsPlanner`].
+///
+/// Converts per-segment constraint counts into absolute offsets via prefix
+/// sum, preserving synthesis (DFS) order. Leaf routine segments are tagged
+/// with their fingerprint so that the wiring evaluators can memoize them.
+pub fn floor_plan(segment_records: &[SegmentRecord]) -> Vec<ConstraintSegment> {
+    DfsPlanner.plan(segment_records)
 }
@@ -20,11 +20,13 @@ extern crate alloc;
 #[cfg(any(feature = "std", test))]
 extern crate std;
 
+pub mod export;
 pub mod floor_planner;
 pub mod horner;
 mod ky;
 mod metrics;
 pub mod polynomials;
+pub mod r1cs;
 mod raw;
 pub mod registry;
 pub mod staging;
@@ -32,7 +34,9 @@ mod trace;
 mod trivial;
 mod wiring;
 
-pub use metrics::{RoutineFingerprint, RoutineIdentity, SegmentRecord};
+pub use metrics::{
+    CircuitMetrics, RoutineFingerprint, RoutineIdentity, RoutineMetrics, SegmentRecord,
+};
 pub use trace::Trace;
 
 #[cfg(test)]
@@ -173,6 +177,37 @@ pub trait CircuitExt<F: Field>: Circuit<F> {
     fn ky(&self, instance: Self::Instance<'_>, y: F) -> Result<F> {
         ky::eval(self, instance, y)
     }
+
+    /// Computes the gate and constraint counts of this circuit without a
+    /// witness.
+    ///
+    /// The returned [`CircuitMetrics`] can be folded into a per-routine cost
+    /// breakdown via [`CircuitMetrics::routines`]. The totals are the values
+    /// checked against a [`Rank`] when the circuit is registered.
+    fn metrics(&self) -> Result<CircuitMetrics>
+    where
+        F: FromUniformBytes<64>,
+    {
+        metrics::eval(self)
+    }
+
+    /// Exports the constraint system of this circuit, laid out by the default
+    /// [`DfsPlanner`](floor_planner::DfsPlanner).
+    ///
+    /// Use [`Registry::export`](registry::Registry::export) to export a
+    /// circuit as it is laid out in a registry.
+    fn export(&self) -> Result<export::ConstraintSystem<F>>
+    where
+        F: FromUniformBytes<64>,
+    {
+        let metrics = metrics::eval(self)?;
+        let floor_plan = floor_planner::floor_plan(metrics.segments());
+        export::eval(
+            &raw::CircuitAdapterRef(self),
+            metrics.segments(),
+            &floor_plan,
+        )
+    }
 }
 
 impl<F: Field, C: Circuit<F>> CircuitExt<F> for C {}
@@ -216,6 +251,21 @@ pub(crate) trait WiringObject<F: Field, R: Rank>: Send + Sync {
     fn is_mask(&self) -> bool {
         false
     }
+
+    /// Exports the constraint system of this circuit, or returns `None` if it
+    /// is not synthesized from a circuit.
+    fn export(
+        &self,
+        _floor_plan: &[floor_planner::ConstraintSegment],
+    ) -> Option<export::ConstraintSystem<F>> {
+        None
+    }
+
+    /// Returns the metrics computed when this circuit was wrapped, or `None`
+    /// if it is not synthesized from a circuit.
+    fn metrics(&self) -> Option<&CircuitMetrics> {
+        None
+    }
 }
 
 /// Wraps a circuit into a boxed [`WiringObject`] that can evaluate the
@@ -288,6 +338,17 @@ where
         fn segment_records(&self) -> &[SegmentRecord] {
             &self.metrics.segments
         }
+        fn export(
+            &self,
+            floor_plan: &[floor_planner::ConstraintSegment],
+        ) -> Option<export::ConstraintSystem<F>> {
+            let cs = export::eval(&self.circuit, &self.metrics.segments, floor_plan)
+                .expect("should succeed if metrics succeeded");
+            Some(cs)
+        }
+        fn metrics(&self) -> Option<&metrics::CircuitMetrics> {
+            Some(&self.metrics)
+        }
     }
 
     Ok(Box::new(Processed { circuit, metrics }))
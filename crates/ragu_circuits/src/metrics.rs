@@ -11,10 +11,12 @@
 //! # Fingerprinting
 //!
 //! A routine's fingerprint is the tuple `(TypeId(Input), TypeId(Output),
-//! eval, num_mul, num_lc)`. The [`TypeId`] pairs cheaply narrow equivalence
-//! candidates by type; the constraint counts further partition by shape; the
-//! scalar confirms structural equivalence via random evaluation
-//! (Schwartz–Zippel).
+//! eval, output_eval, num_mul, num_lc)`. The [`TypeId`] pairs cheaply narrow
+//! equivalence candidates by type; the constraint counts further partition by
+//! shape; the scalars confirm structural equivalence via random evaluation
+//! (Schwartz–Zippel). `output_eval` folds the routine's output wires with the
+//! same Horner rule, so that two routines with identical constraints but
+//! differently wired outputs are not considered equivalent.
 //!
 //! The fingerprint is wrapped in [`RoutineIdentity`], an enum that
 //! distinguishes the root circuit body ([`Root`](RoutineIdentity::Root)) from
@@ -31,10 +33,23 @@
 //! the same fingerprint, they are structurally equivalent with overwhelming
 //! probability.
 //!
+//! # Cost breakdown
+//!
+//! Each segment also records the [`type_name`] of the routine that produced it
+//! and the index of its enclosing segment, so that the flat DFS record list
+//! can be folded into a [`RoutineMetrics`] tree via
+//! [`CircuitMetrics::routines`]. This is the structure surfaced publicly by
+//! [`CircuitExt::metrics`](crate::CircuitExt::metrics) for budgeting circuit
+//! size against a [`Rank`](crate::polynomials::Rank).
+//!
 //! [`TypeId`]: core::any::TypeId
+//! [`type_name`]: core::any::type_name
 
 use alloc::vec::Vec;
-use core::any::TypeId;
+use core::{
+    any::{TypeId, type_name},
+    marker::PhantomData,
+};
 
 use ragu_arithmetic::{
     Coeff,
@@ -44,7 +59,7 @@ use ragu_core::{
     Result,
     convert::WireMap,
     drivers::{DirectSum, Driver, DriverTypes, emulator::Emulator},
-    gadgets::{Bound, GadgetKind as _},
+    gadgets::{Bound, Gadget, GadgetKind as _},
     maybe::Empty,
     routines::Routine,
 };
@@ -73,14 +88,16 @@ pub enum RoutineIdentity {
 /// structure.
 ///
 /// Two routines share a fingerprint when they have matching [`TypeId`] pairs,
-/// matching evaluation scalars, and matching constraint counts. The scalar is
-/// the low 64 bits of the field element produced by running the routine's
-/// synthesis on the `Counter` driver.
+/// matching evaluation scalars, and matching constraint counts. The scalars
+/// are the field elements produced by running the routine's synthesis on the
+/// `Counter` driver: one for its constraints and one for its output wires.
 ///
-/// The 64-bit truncation gives ~2^{-64} collision probability per pair,
-/// adequate for floor-planner equivalence classes. If fingerprints are
-/// ever used for security-critical decisions, store the full field
-/// representation (`[u8; 32]`) instead — the cost is negligible.
+/// Because the fingerprint covers everything a routine contributes to
+/// $s(X, Y)$ other than its placement, the wiring evaluators use it to
+/// memoize leaf routines. That makes it security-critical, since a collision
+/// would place one routine's constraints where another's belong, so the
+/// scalars are stored as their full canonical representation (`[u8; 32]`)
+/// rather than truncated.
 ///
 /// The constraint counts duplicate the values in the enclosing
 /// [`SegmentRecord`]. This is intentional: it makes the fingerprint a
@@ -88,39 +105,60 @@ pub enum RoutineIdentity {
 /// equivalence maps without also comparing the segment record.
 ///
 /// [`TypeId`]: core::any::TypeId
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RoutineFingerprint {
     input_kind: TypeId,
     output_kind: TypeId,
-    eval: u64,
+    eval: [u8; 32],
+    output_eval: [u8; 32],
     local_num_gates: usize,
     local_num_constraints: usize,
 }
 
 impl RoutineFingerprint {
     /// Constructs a [`RoutineFingerprint`] from a routine's `Input`/`Output`
-    /// type ids, a field element evaluation, and local constraint counts.
+    /// type ids, field element evaluations of its constraints and outputs,
+    /// and local constraint counts.
     fn of<F: PrimeField, Ro: Routine<F>>(
         eval: F,
+        output_eval: F,
         local_num_gates: usize,
         local_num_constraints: usize,
     ) -> Self {
         Self {
             input_kind: TypeId::of::<Ro::Input>(),
             output_kind: TypeId::of::<Ro::Output>(),
-            eval: ragu_arithmetic::low_u64(&eval),
+            eval: full_repr(&eval),
+            output_eval: full_repr(&output_eval),
             local_num_gates,
             local_num_constraints,
         }
     }
 
-    /// Returns the raw evaluation scalar.
+    /// Returns the low 64 bits of the evaluation scalar.
     #[cfg(test)]
     pub(crate) fn eval(&self) -> u64 {
-        self.eval
+        u64::from_le_bytes(self.eval[..8].try_into().unwrap())
     }
 }
 
+/// Returns the canonical representation of `value`, zero-padded to 32 bytes.
+///
+/// # Panics
+///
+/// Panics if the field's canonical representation is longer than 32 bytes.
+fn full_repr<F: PrimeField>(value: &F) -> [u8; 32] {
+    let repr = value.to_repr();
+    let bytes = repr.as_ref();
+    assert!(
+        bytes.len() <= 32,
+        "fingerprinted field elements must fit in 32 bytes"
+    );
+    let mut full = [0; 32];
+    full[..bytes.len()].copy_from_slice(bytes);
+    full
+}
+
 /// Constraint counts for one segment of the circuit, collected during synthesis.
 ///
 /// Each record captures the gates and constraints contributed
@@ -162,6 +200,8 @@ pub struct SegmentRecord {
     num_gates: usize,
     num_constraints: usize,
     identity: RoutineIdentity,
+    name: &'static str,
+    parent: Option<usize>,
 }
 
 impl SegmentRecord {
@@ -177,17 +217,31 @@ impl SegmentRecord {
     }
 
     /// The structural identity of this routine invocation.
-    // TODO: consumed by the floor planner (not yet implemented)
-    #[allow(dead_code)]
-    pub(crate) fn identity(&self) -> &RoutineIdentity {
+    pub fn identity(&self) -> &RoutineIdentity {
         &self.identity
     }
+
+    /// The type name of the [`Routine`] that produced this segment, or of the
+    /// circuit itself for the root segment.
+    ///
+    /// This is the output of [`core::any::type_name`] and is intended for
+    /// diagnostics only; its exact contents are not stable.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The DFS index of the segment whose scope invoked this routine, or
+    /// `None` for the root segment.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
 }
 
 /// A summary of a circuit's constraint topology.
 ///
 /// Captures constraint counts and per-routine records by simulating circuit
-/// execution without computing actual values.
+/// execution without computing actual values. Obtained publicly through
+/// [`CircuitExt::metrics`](crate::CircuitExt::metrics).
 pub struct CircuitMetrics {
     /// The number of constraints, including those for instance enforcement.
     pub(crate) num_constraints: usize,
@@ -206,6 +260,140 @@ pub struct CircuitMetrics {
     pub(crate) segments: Vec<SegmentRecord>,
 }
 
+impl CircuitMetrics {
+    /// The total number of gates, including the SYSTEM gate and those used
+    /// for allocations.
+    pub fn num_gates(&self) -> usize {
+        self.num_gates
+    }
+
+    /// The total number of constraints, including those for instance
+    /// enforcement and the ONE constraint.
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// The degree of the instance polynomial $k(Y)$.
+    pub fn degree_ky(&self) -> usize {
+        self.degree_ky
+    }
+
+    /// Per-segment constraint records in DFS synthesis order.
+    pub fn segments(&self) -> &[SegmentRecord] {
+        &self.segments
+    }
+
+    /// Folds the per-segment records into a tree of routine invocations
+    /// rooted at the circuit body.
+    ///
+    /// The root node's inclusive counts equal [`num_gates`](Self::num_gates)
+    /// and [`num_constraints`](Self::num_constraints).
+    pub fn routines(&self) -> RoutineMetrics {
+        let mut nodes: Vec<Option<RoutineMetrics>> = self
+            .segments
+            .iter()
+            .map(|record| {
+                Some(RoutineMetrics {
+                    name: record.name,
+                    identity: record.identity,
+                    num_gates: record.num_gates,
+                    num_constraints: record.num_constraints,
+                    local_num_gates: record.num_gates,
+                    local_num_constraints: record.num_constraints,
+                    children: Vec::new(),
+                })
+            })
+            .collect();
+
+        // Children always follow their parent in DFS order, so visiting the
+        // records in reverse completes every subtree before it is attached.
+        for index in (1..nodes.len()).rev() {
+            let mut node = nodes[index].take().expect("each node is taken once");
+            node.children.reverse();
+
+            let parent = self.segments[index]
+                .parent
+                .expect("only the root segment lacks a parent");
+            let parent = nodes[parent]
+                .as_mut()
+                .expect("parent precedes child in DFS order");
+            parent.num_gates += node.num_gates;
+            parent.num_constraints += node.num_constraints;
+            parent.children.push(node);
+        }
+
+        let mut root = nodes[0].take().expect("root segment always exists");
+        root.children.reverse();
+        root
+    }
+}
+
+/// The cost of one routine invocation (or the root circuit body), together
+/// with the invocations nested inside it.
+///
+/// Produced by [`CircuitMetrics::routines`]. "Local" counts cover only the
+/// constraints emitted directly in this invocation's scope (the same values as
+/// its [`SegmentRecord`]), while the unqualified counts are inclusive of all
+/// nested routine calls.
+#[derive(Clone, Debug)]
+pub struct RoutineMetrics {
+    name: &'static str,
+    identity: RoutineIdentity,
+    num_gates: usize,
+    num_constraints: usize,
+    local_num_gates: usize,
+    local_num_constraints: usize,
+    children: Vec<RoutineMetrics>,
+}
+
+impl RoutineMetrics {
+    /// The type name of the routine, or of the circuit for the root node. See
+    /// [`SegmentRecord::name`].
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The structural identity of this invocation.
+    pub fn identity(&self) -> &RoutineIdentity {
+        &self.identity
+    }
+
+    /// The fingerprint of this invocation, or `None` for the root node.
+    pub fn fingerprint(&self) -> Option<RoutineFingerprint> {
+        match self.identity {
+            RoutineIdentity::Root => None,
+            RoutineIdentity::Routine(fingerprint) => Some(fingerprint),
+        }
+    }
+
+    /// The number of gates in this invocation, including nested routines.
+    pub fn num_gates(&self) -> usize {
+        self.num_gates
+    }
+
+    /// The number of constraints in this invocation, including nested
+    /// routines.
+    pub fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// The number of gates emitted directly in this invocation's scope.
+    pub fn local_num_gates(&self) -> usize {
+        self.local_num_gates
+    }
+
+    /// The number of constraints emitted directly in this invocation's scope.
+    pub fn local_num_constraints(&self) -> usize {
+        self.local_num_constraints
+    }
+
+    /// The routine invocations made directly from this scope, in synthesis
+    /// order.
+    pub fn children(&self) -> &[RoutineMetrics] {
+        &self.children
+    }
+}
+
 /// Per-routine state that is saved and restored across routine boundaries.
 ///
 /// Contains both the constraint counting record index and the identity
@@ -302,6 +490,36 @@ impl<F: FromUniformBytes<64>> Counter<F> {
         v
     }
 
+    /// Folds the wires of a routine's output gadget into a single scalar
+    /// using the same seeded Horner rule as constraint accumulation.
+    ///
+    /// Must be called before the routine's scope is popped, so that output
+    /// wires still carry the routine's own geometric-sequence values.
+    fn output_eval<'dr, G: Gadget<'dr, Self>>(&self, output: &G) -> Result<F> {
+        struct OutputFold<F> {
+            y: F,
+            result: F,
+        }
+
+        impl<F: FromUniformBytes<64>> WireMap<F> for OutputFold<F> {
+            type Src = Counter<F>;
+            type Dst = PhantomData<F>;
+
+            fn convert_wire(&mut self, wire: &F) -> Result<()> {
+                self.result *= self.y;
+                self.result += *wire;
+                Ok(())
+            }
+        }
+
+        let mut fold = OutputFold {
+            y: self.y,
+            result: self.h,
+        };
+        output.map(&mut fold)?;
+        Ok(fold.result)
+    }
+
     fn new() -> Self {
         let base_state = blake2b_simd::Params::new()
             .personal(b"ragu_counter____")
@@ -334,6 +552,8 @@ impl<F: FromUniformBytes<64>> Counter<F> {
                 num_gates: 0,
                 num_constraints: 0,
                 identity: RoutineIdentity::Root,
+                name: "",
+                parent: None,
             }],
             x0,
             x1,
@@ -412,6 +632,8 @@ impl<'dr, F: FromUniformBytes<64>> Driver<'dr> for Counter<F> {
             num_gates: 0,
             num_constraints: 0,
             identity: RoutineIdentity::Root,
+            name: type_name::<Ro>(),
+            parent: Some(self.scope.current_segment),
         });
         let segment_idx = self.segments.len() - 1;
 
@@ -440,11 +662,13 @@ impl<'dr, F: FromUniformBytes<64>> Driver<'dr> for Counter<F> {
         let aux = Emulator::predict(&routine, &new_input)?.into_aux();
         let output = routine.execute(self, new_input, aux)?;
 
-        // Extract fingerprint from the child's Horner accumulator and counts.
+        // Extract fingerprint from the child's Horner accumulators and counts.
+        let output_eval = self.output_eval(&output)?;
         let seg = &self.segments[segment_idx];
         self.segments[segment_idx].identity =
             RoutineIdentity::Routine(RoutineFingerprint::of::<F, Ro>(
                 self.scope.result,
+                output_eval,
                 seg.num_gates,
                 seg.num_constraints,
             ));
@@ -479,12 +703,22 @@ impl<F: FromUniformBytes<64>> WireMap<F> for Counter<F> {
 
 /// Evaluates the constraint topology of a circuit.
 pub fn eval<F: FromUniformBytes<64>, C: Circuit<F>>(circuit: &C) -> Result<CircuitMetrics> {
-    eval_raw(&super::raw::CircuitAdapterRef(circuit))
+    eval_named(&super::raw::CircuitAdapterRef(circuit), type_name::<C>())
 }
 
 /// Evaluates the constraint topology of a [`RawCircuit`].
+#[cfg(test)]
 pub(crate) fn eval_raw<F: FromUniformBytes<64>, RC: RawCircuit<F>>(
     circuit: &RC,
+) -> Result<CircuitMetrics> {
+    eval_named(circuit, type_name::<RC>())
+}
+
+/// Evaluates the constraint topology of a [`RawCircuit`], naming its root
+/// segment `name`.
+fn eval_named<F: FromUniformBytes<64>, RC: RawCircuit<F>>(
+    circuit: &RC,
+    name: &'static str,
 ) -> Result<CircuitMetrics> {
     let mut collector = Counter::<F>::new();
 
@@ -509,6 +743,8 @@ pub(crate) fn eval_raw<F: FromUniformBytes<64>, RC: RawCircuit<F>>(
         "exactly one segment must be Root"
     );
 
+    collector.segments[0].name = name;
+
     Ok(CircuitMetrics {
         num_constraints: collector.num_constraints,
         num_gates: collector.num_gates,
@@ -519,8 +755,6 @@ pub(crate) fn eval_raw<F: FromUniformBytes<64>, RC: RawCircuit<F>>(
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use core::marker::PhantomData;
-
     use ragu_core::{
         drivers::{Driver, DriverValue},
         gadgets::Bound,
@@ -587,13 +821,15 @@ pub(crate) mod tests {
 
         // Predict (on a wireless emulator) then execute on the counter.
         let aux = Emulator::predict(routine, &new_input)?.into_aux();
-        routine.execute(&mut counter, new_input, aux)?;
+        let output = routine.execute(&mut counter, new_input, aux)?;
+        let output_eval = counter.output_eval(&output)?;
 
         // Segment 0 holds only this routine's own constraints; nested
         // routine constraints live in their own segments.
         let seg = &counter.segments[0];
         Ok(RoutineIdentity::Routine(RoutineFingerprint::of::<F, Ro>(
             counter.scope.result,
+            output_eval,
             seg.num_gates,
             seg.num_constraints,
         )))
@@ -661,4 +897,95 @@ pub(crate) mod tests {
     fn single_alloc_in_routine() {
         super::eval::<Fp, _>(&SingleAllocCircuit).expect("metrics eval should succeed");
     }
+
+    #[derive(Clone)]
+    struct TwoAllocRoutine;
+
+    impl Routine<Fp> for TwoAllocRoutine {
+        type Input = ();
+        type Output = ();
+        type Aux<'dr> = ();
+
+        fn execute<'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            dr: &mut D,
+            _input: Bound<'dr, D, Self::Input>,
+            _aux: DriverValue<D, Self::Aux<'dr>>,
+        ) -> Result<Bound<'dr, D, Self::Output>> {
+            dr.routine(SingleAllocRoutine, ())?;
+            dr.routine(SingleAllocRoutine, ())?;
+            dr.enforce_zero(|lc| lc)?;
+            Ok(())
+        }
+
+        fn predict<'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            _dr: &mut D,
+            _input: &Bound<'dr, D, Self::Input>,
+        ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>>
+        {
+            Ok(Prediction::Unknown(D::unit()))
+        }
+    }
+
+    struct NestedAllocCircuit;
+
+    impl Circuit<Fp> for NestedAllocCircuit {
+        type Instance<'source> = ();
+        type Witness<'source> = ();
+        type Output = ();
+        type Aux<'source> = ();
+
+        fn instance<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            _dr: &mut D,
+            _instance: DriverValue<D, Self::Instance<'source>>,
+        ) -> Result<Bound<'dr, D, Self::Output>> {
+            Ok(())
+        }
+
+        fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            dr: &mut D,
+            _witness: DriverValue<D, Self::Witness<'source>>,
+        ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'source>>>>
+        {
+            dr.routine(TwoAllocRoutine, ())?;
+            dr.routine(SingleAllocRoutine, ())?;
+            Ok(WithAux::new((), D::unit()))
+        }
+    }
+
+    #[test]
+    fn routine_tree_matches_segments() {
+        let metrics = super::eval::<Fp, _>(&NestedAllocCircuit).unwrap();
+        let root = metrics.routines();
+
+        assert!(root.name().ends_with("NestedAllocCircuit"));
+        assert!(root.fingerprint().is_none());
+        assert_eq!(root.num_gates(), metrics.num_gates());
+        assert_eq!(root.num_constraints(), metrics.num_constraints());
+        assert_eq!(root.local_num_gates(), metrics.segments()[0].num_gates());
+
+        let [outer, single] = root.children() else {
+            panic!("root should invoke exactly two routines");
+        };
+        assert!(outer.name().ends_with("TwoAllocRoutine"));
+        assert!(single.name().ends_with("SingleAllocRoutine"));
+        assert_eq!(outer.children().len(), 2);
+        assert_eq!(outer.local_num_constraints(), 1);
+        assert_eq!(
+            outer.num_gates(),
+            outer.local_num_gates() + 2 * single.num_gates()
+        );
+        assert_eq!(
+            outer.children()[0].fingerprint(),
+            single.fingerprint(),
+            "identical invocations share a fingerprint"
+        );
+
+        // Parent links follow DFS order: outer = 1, its children = 2, 3.
+        let parents: Vec<_> = metrics.segments().iter().map(|s| s.parent()).collect();
+        assert_eq!(parents, [None, Some(0), Some(1), Some(1), Some(0)]);
+    }
 }
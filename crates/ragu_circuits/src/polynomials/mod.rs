@@ -17,8 +17,10 @@ pub trait Rank:
     private::Sealed + Clone + Send + Sync + 'static + PartialEq + Eq + core::fmt::Debug + Default
 {
     /// The rank can range from $2$ to $28$ (to avoid overflows on 32-bit
-    /// architectures), but only [`ProductionRank`] and [`TestRank`] are
-    /// currently implemented.
+    /// architectures), but only `R<7>` ([`TestRank`]) through `R<13>`
+    /// ([`ProductionRank`]) are currently implemented; see [`with_rank!`].
+    ///
+    /// [`with_rank!`]: crate::with_rank
     const RANK: u32;
 
     /// Returns the $2^\text{RANK}$ number of coefficients in the polynomials
@@ -41,6 +43,12 @@ pub trait Rank:
         Self::RANK - 2
     }
 
+    /// Generates runtime parameters for the cycle `C` with enough generators
+    /// to commit to polynomials of this rank.
+    fn generate_params<C: ragu_arithmetic::Cycle>() -> C::Params {
+        C::generate_for(Self::RANK)
+    }
+
     /// Computes the coefficients of
     /// $$t(X, z) = -\sum_{i=0}^{n - 1} X^{4n - 1 - i} (z^{2n - 1 - i} + z^{2n + i})$$
     /// for some $z \in \mathbb{F}$.
@@ -115,7 +123,8 @@ pub trait Rank:
 
 /// `R<N>` implements [`Rank`] for supported values of $N$. The type aliases
 /// [`ProductionRank`] ($N = 13$) and [`TestRank`] ($N = 7$) are provided for
-/// convenience. Additional implementations can be added to `impl_rank_for_R!` as needed.
+/// convenience. Additional implementations can be added to `impl_rank_for_R!`
+/// (and [`with_rank!`](crate::with_rank)) as needed.
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
 pub struct R<const RANK: u32>;
 
@@ -143,7 +152,74 @@ macro_rules! impl_rank_for_R {
     };
 }
 
-impl_rank_for_R! {7, 13}
+impl_rank_for_R! {7, 8, 9, 10, 11, 12, 13}
+
+/// Returns the smallest `RANK` whose bounds admit a circuit with the given
+/// number of gates and constraints.
+///
+/// A circuit fits in a rank when it has at most [`Rank::n()`] gates and fewer
+/// than [`Rank::num_coeffs()`] constraints (the last coefficient is reserved
+/// for the registry key). The result may be smaller than the smallest
+/// implemented rank.
+pub fn min_rank(num_gates: usize, num_constraints: usize) -> u32 {
+    // num_constraints < 2^RANK
+    let for_constraints = usize::BITS - num_constraints.leading_zeros();
+    // num_gates <= 2^(RANK - 2)
+    let for_gates = num_gates.next_power_of_two().trailing_zeros() + 2;
+
+    for_constraints.max(for_gates)
+}
+
+/// Evaluates an expression with a type alias bound to `R<N>` for a `RANK`
+/// chosen at runtime.
+///
+/// Expands to `Some(expr)` when the rank is implemented (see [`Rank::RANK`])
+/// and `None` otherwise. This is useful together with [`min_rank`] to
+/// instantiate the smallest rank that fits a set of circuits:
+///
+/// ```
+/// use ragu_circuits::{polynomials::Rank, with_rank};
+///
+/// let n = with_rank!(9, MyRank => MyRank::n());
+/// assert_eq!(n, Some(128));
+/// assert_eq!(with_rank!(40, MyRank => MyRank::n()), None);
+/// ```
+#[macro_export]
+macro_rules! with_rank {
+    ($rank:expr, $alias:ident => $body:expr) => {
+        match $rank {
+            7 => Some({
+                type $alias = $crate::polynomials::R<7>;
+                $body
+            }),
+            8 => Some({
+                type $alias = $crate::polynomials::R<8>;
+                $body
+            }),
+            9 => Some({
+                type $alias = $crate::polynomials::R<9>;
+                $body
+            }),
+            10 => Some({
+                type $alias = $crate::polynomials::R<10>;
+                $body
+            }),
+            11 => Some({
+                type $alias = $crate::polynomials::R<11>;
+                $body
+            }),
+            12 => Some({
+                type $alias = $crate::polynomials::R<12>;
+                $body
+            }),
+            13 => Some({
+                type $alias = $crate::polynomials::R<13>;
+                $body
+            }),
+            _ => None,
+        }
+    };
+}
 
 #[test]
 fn test_tz() {
@@ -176,6 +252,21 @@ fn test_tz() {
     assert_eq!(expected_tz, DemoR::tz::<Fp>(z).to_dense());
 }
 
+#[test]
+fn test_min_rank() {
+    assert_eq!(min_rank(0, 0), 2);
+    assert_eq!(
+        min_rank(TestRank::n(), TestRank::num_coeffs() - 1),
+        TestRank::RANK
+    );
+    assert_eq!(min_rank(TestRank::n() + 1, 0), TestRank::RANK + 1);
+    assert_eq!(min_rank(0, TestRank::num_coeffs()), TestRank::RANK + 1);
+    assert_eq!(
+        min_rank(ProductionRank::n(), ProductionRank::num_coeffs() - 1),
+        ProductionRank::RANK
+    );
+}
+
 #[test]
 fn test_txz_consistency() {
     use ragu_pasta::Fp;
@@ -194,3 +285,19 @@ fn test_txz_consistency() {
     assert_eq!(t00, DemoR::tz::<Fp>(Fp::ZERO).eval(Fp::ZERO));
     assert_eq!(t00, DemoR::tx::<Fp>(Fp::ZERO).eval(Fp::ZERO));
 }
+
+#[test]
+fn test_generate_params() {
+    use ragu_arithmetic::{Cycle, FixedGenerators};
+    use ragu_pasta::Pasta;
+
+    let params = TestRank::generate_params::<Pasta>();
+    assert_eq!(
+        Pasta::host_generators(&params).g().len(),
+        TestRank::num_coeffs()
+    );
+    assert_eq!(
+        Pasta::nested_generators(&params).g().len(),
+        TestRank::num_coeffs()
+    );
+}
@@ -454,18 +454,28 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
     /// [`batch_to_affine`](ragu_arithmetic::batch_to_affine) to efficiently
     /// convert multiple projective commitments to affine with a single
     /// field inversion.
+    ///
+    /// The stored coefficients are passed to
+    /// [`FixedGenerators::commit`](ragu_arithmetic::FixedGenerators::commit),
+    /// which uses the generators' precomputed tables when they have them.
+    /// Otherwise the coefficients are classified by [`ragu_arithmetic::mul`],
+    /// so the zeros, units and small values that dominate trace polynomials
+    /// are cheaper to commit to than full-width coefficients.
     pub fn commit<C: CurveAffine<ScalarExt = F>>(
         &self,
         generators: &impl ragu_arithmetic::FixedGenerators<C>,
     ) -> C::Curve {
-        assert!(generators.g().len() >= R::num_coeffs());
+        assert!(
+            generators.g().len() >= R::num_coeffs(),
+            "{} generators cannot commit to polynomials of rank {}",
+            generators.g().len(),
+            R::RANK
+        );
 
-        let g = generators.g();
-        ragu_arithmetic::mul(
-            self.blocks.iter().flat_map(|(_, data)| data.iter()),
+        generators.commit(
             self.blocks
                 .iter()
-                .flat_map(|(start, data)| &g[*start..*start + data.len()]),
+                .flat_map(|(start, data)| (*start..).zip(data.iter())),
         )
     }
 
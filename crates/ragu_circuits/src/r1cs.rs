@@ -0,0 +1,426 @@
+//! Import of R1CS circuits compiled by [circom](https://docs.circom.io/).
+//!
+//! [`R1cs::read`] loads a circom `.r1cs` constraint file and [`read_witness`]
+//! loads the matching `.wtns` witness file. Each R1CS constraint
+//! $\langle A, w \rangle \cdot \langle B, w \rangle = \langle C, w \rangle$ is
+//! synthesized as a single multiplication gate whose output is constrained to
+//! equal $\langle C, w \rangle$, except that constraints which are linear in
+//! $w$ cost no gate at all. [`R1csCircuit`] exposes the public signals of an
+//! R1CS circuit as the output of a [`Circuit`].
+//!
+//! Circom numbers signals (which it calls wires) so that signal $0$ is the
+//! constant $1$, followed by the public outputs, the public inputs, the private
+//! inputs and finally the intermediate signals. The circuit must be compiled
+//! for the field it is synthesized over, such as with `circom --prime pallas`
+//! for [`Fp`](https://docs.rs/pasta_curves). Field elements are decoded from
+//! the little-endian encoding circom uses, which must match
+//! [`PrimeField::Repr`].
+
+use alloc::vec::Vec;
+
+use ragu_arithmetic::{
+    Coeff,
+    ff::{Field, PrimeField},
+};
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue},
+    gadgets::{Bound, Kind},
+    maybe::Maybe,
+};
+use ragu_primitives::{
+    Element,
+    allocator::Standard,
+    multiadd,
+    vec::{ConstLen, FixedVec},
+};
+
+use crate::{Circuit, WithAux};
+
+/// A linear combination of signals, as `(signal, coefficient)` terms.
+pub type LinearCombination<F> = Vec<(usize, F)>;
+
+/// A single R1CS constraint $\langle A, w \rangle \cdot \langle B, w \rangle =
+/// \langle C, w \rangle$ over the signals $w$.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint<F> {
+    a: LinearCombination<F>,
+    b: LinearCombination<F>,
+    c: LinearCombination<F>,
+}
+
+impl<F> Constraint<F> {
+    /// Returns the terms of $A$.
+    pub fn a(&self) -> &[(usize, F)] {
+        &self.a
+    }
+
+    /// Returns the terms of $B$.
+    pub fn b(&self) -> &[(usize, F)] {
+        &self.b
+    }
+
+    /// Returns the terms of $C$.
+    pub fn c(&self) -> &[(usize, F)] {
+        &self.c
+    }
+}
+
+/// An R1CS constraint system loaded from a circom `.r1cs` file.
+#[derive(Clone, Debug)]
+pub struct R1cs<F> {
+    num_signals: usize,
+    num_public_outputs: usize,
+    num_public_inputs: usize,
+    num_private_inputs: usize,
+    constraints: Vec<Constraint<F>>,
+}
+
+impl<F: PrimeField> R1cs<F> {
+    /// Parses the contents of a circom `.r1cs` file.
+    ///
+    /// Returns [`Error::MalformedEncoding`] if the file is malformed, was
+    /// compiled for a different field, or uses custom gates.
+    pub fn read(bytes: &[u8]) -> Result<Self> {
+        let sections = sections(bytes, b"r1cs", 1)?;
+
+        if sections.iter().any(|(ty, _)| matches!(ty, 4 | 5)) {
+            return Err(malformed("custom gates are not supported"));
+        }
+
+        let mut header = Reader::new(section(&sections, 1)?);
+        let n8 = header.u32()? as usize;
+        check_prime::<F>(header.take(n8)?)?;
+        let num_signals = header.u32()? as usize;
+        let num_public_outputs = header.u32()? as usize;
+        let num_public_inputs = header.u32()? as usize;
+        let num_private_inputs = header.u32()? as usize;
+        let _num_labels = header.u64()?;
+        let num_constraints = header.u32()? as usize;
+
+        if num_signals == 0
+            || 1 + num_public_outputs + num_public_inputs + num_private_inputs > num_signals
+        {
+            return Err(malformed("inconsistent signal counts"));
+        }
+
+        let mut reader = Reader::new(section(&sections, 2)?);
+        let lc = |reader: &mut Reader<'_>| -> Result<LinearCombination<F>> {
+            (0..reader.u32()?)
+                .map(|_| {
+                    let signal = reader.u32()? as usize;
+                    if signal >= num_signals {
+                        return Err(malformed("constraint refers to an unknown signal"));
+                    }
+                    Ok((signal, field(reader.take(n8)?)?))
+                })
+                .collect()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| {
+                Ok(Constraint {
+                    a: lc(&mut reader)?,
+                    b: lc(&mut reader)?,
+                    c: lc(&mut reader)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        reader.finish()?;
+
+        Ok(R1cs {
+            num_signals,
+            num_public_outputs,
+            num_public_inputs,
+            num_private_inputs,
+            constraints,
+        })
+    }
+
+    /// Returns the number of signals, including the constant signal $0$.
+    pub fn num_signals(&self) -> usize {
+        self.num_signals
+    }
+
+    /// Returns the number of public output signals.
+    pub fn num_public_outputs(&self) -> usize {
+        self.num_public_outputs
+    }
+
+    /// Returns the number of public input signals.
+    pub fn num_public_inputs(&self) -> usize {
+        self.num_public_inputs
+    }
+
+    /// Returns the number of private input signals.
+    pub fn num_private_inputs(&self) -> usize {
+        self.num_private_inputs
+    }
+
+    /// Returns the number of public signals, which are the signals
+    /// `1..=num_public()`.
+    pub fn num_public(&self) -> usize {
+        self.num_public_outputs + self.num_public_inputs
+    }
+
+    /// Returns the constraints of this system.
+    pub fn constraints(&self) -> &[Constraint<F>] {
+        &self.constraints
+    }
+
+    /// Synthesizes this constraint system given an assignment of every signal,
+    /// such as one loaded with [`read_witness`], and returns the elements that
+    /// each signal is assigned to.
+    ///
+    /// The constant signal $0$ is always [`Element::one`], regardless of its
+    /// assignment in `signals`.
+    pub fn synthesize<'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        signals: DriverValue<D, &[F]>,
+    ) -> Result<Vec<Element<'dr, D>>> {
+        D::try_just(|| {
+            let actual = signals.snag().len();
+            if actual != self.num_signals {
+                return Err(Error::VectorLengthMismatch {
+                    expected: self.num_signals,
+                    actual,
+                });
+            }
+            Ok(())
+        })?;
+
+        let allocator = &mut Standard::new();
+        let mut elements = Vec::with_capacity(self.num_signals);
+        elements.push(Element::one());
+        for i in 1..self.num_signals {
+            elements.push(Element::alloc(
+                dr,
+                allocator,
+                signals.as_ref().map(|signals| signals[i]),
+            )?);
+        }
+
+        for constraint in &self.constraints {
+            let combine = |dr: &mut D, lc: &[(usize, F)]| -> Element<'dr, D> {
+                let (values, coeffs): (Vec<_>, Vec<_>) = lc
+                    .iter()
+                    .map(|&(signal, coeff)| (elements[signal].clone(), coeff))
+                    .unzip();
+                multiadd(dr, &values, &coeffs)
+            };
+
+            let c = combine(dr, &constraint.c);
+            if constraint.a.is_empty() || constraint.b.is_empty() {
+                c.enforce_zero(dr)?;
+                continue;
+            }
+
+            let product = if let Some(a) = constant(&constraint.a) {
+                combine(dr, &constraint.b).scale(dr, Coeff::Arbitrary(a))
+            } else if let Some(b) = constant(&constraint.b) {
+                combine(dr, &constraint.a).scale(dr, Coeff::Arbitrary(b))
+            } else {
+                let a = combine(dr, &constraint.a);
+                let b = combine(dr, &constraint.b);
+                a.mul(dr, &b)?
+            };
+            product.sub(dr, &c).enforce_zero(dr)?;
+        }
+
+        Ok(elements)
+    }
+}
+
+/// Parses the contents of a circom `.wtns` file into the assignment of every
+/// signal.
+///
+/// Returns [`Error::MalformedEncoding`] if the file is malformed or was
+/// computed over a different field.
+pub fn read_witness<F: PrimeField>(bytes: &[u8]) -> Result<Vec<F>> {
+    let sections = sections(bytes, b"wtns", 2)?;
+
+    let mut header = Reader::new(section(&sections, 1)?);
+    let n8 = header.u32()? as usize;
+    check_prime::<F>(header.take(n8)?)?;
+    let num_signals = header.u32()? as usize;
+
+    let mut reader = Reader::new(section(&sections, 2)?);
+    let signals = (0..num_signals)
+        .map(|_| field(reader.take(n8)?))
+        .collect::<Result<Vec<_>>>()?;
+    reader.finish()?;
+
+    Ok(signals)
+}
+
+/// A [`Circuit`] that synthesizes an [`R1cs`] constraint system and outputs
+/// its `PUBLIC` public signals.
+///
+/// The witness is the assignment of every signal, and the instance is the
+/// assignment of the public signals.
+pub struct R1csCircuit<'a, F, const PUBLIC: usize> {
+    r1cs: &'a R1cs<F>,
+}
+
+impl<'a, F: PrimeField, const PUBLIC: usize> R1csCircuit<'a, F, PUBLIC> {
+    /// Creates a circuit for `r1cs`, which must have `PUBLIC` public signals.
+    pub fn new(r1cs: &'a R1cs<F>) -> Result<Self> {
+        if r1cs.num_public() != PUBLIC {
+            return Err(Error::VectorLengthMismatch {
+                expected: PUBLIC,
+                actual: r1cs.num_public(),
+            });
+        }
+
+        Ok(R1csCircuit { r1cs })
+    }
+}
+
+impl<F: PrimeField, const PUBLIC: usize> Circuit<F> for R1csCircuit<'_, F, PUBLIC> {
+    type Instance<'source> = [F; PUBLIC];
+    type Witness<'source> = &'source [F];
+    type Output = Kind![F; FixedVec<Element<'_, _>, ConstLen<PUBLIC>>];
+    type Aux<'source> = ();
+
+    fn instance<'dr, 'source: 'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        instance: DriverValue<D, Self::Instance<'source>>,
+    ) -> Result<Bound<'dr, D, Self::Output>>
+    where
+        Self: 'dr,
+    {
+        let allocator = &mut Standard::new();
+        FixedVec::try_from_fn(|i| {
+            Element::alloc(dr, allocator, instance.as_ref().map(|instance| instance[i]))
+        })
+    }
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+    ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'source>>>>
+    where
+        Self: 'dr,
+    {
+        let signals = self.r1cs.synthesize(dr, witness)?;
+        let public = FixedVec::try_from(signals[1..=PUBLIC].to_vec())?;
+
+        Ok(WithAux::new(public, D::unit()))
+    }
+}
+
+/// Returns the value of a linear combination that only refers to the constant
+/// signal $0$.
+fn constant<F: Field>(lc: &[(usize, F)]) -> Option<F> {
+    lc.iter().try_fold(
+        F::ZERO,
+        |sum, &(signal, coeff)| {
+            if signal == 0 { Some(sum + coeff) } else { None }
+        },
+    )
+}
+
+fn malformed(msg: &'static str) -> Error {
+    Error::MalformedEncoding(msg.into())
+}
+
+/// Splits an iden3 binary file into its `(type, contents)` sections after
+/// checking its magic and version.
+fn sections<'a>(bytes: &'a [u8], magic: &[u8; 4], version: u32) -> Result<Vec<(u32, &'a [u8])>> {
+    let mut reader = Reader::new(bytes);
+    if reader.take(4)? != magic {
+        return Err(malformed("unexpected file type"));
+    }
+    if reader.u32()? != version {
+        return Err(malformed("unsupported file version"));
+    }
+
+    let sections = (0..reader.u32()?)
+        .map(|_| {
+            let ty = reader.u32()?;
+            let len =
+                usize::try_from(reader.u64()?).map_err(|_| malformed("section is too large"))?;
+            Ok((ty, reader.take(len)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    reader.finish()?;
+
+    Ok(sections)
+}
+
+fn section<'a>(sections: &[(u32, &'a [u8])], ty: u32) -> Result<&'a [u8]> {
+    let mut matching = sections.iter().filter(|(t, _)| *t == ty);
+    match (matching.next(), matching.next()) {
+        (Some((_, contents)), None) => Ok(contents),
+        (None, _) => Err(malformed("missing section")),
+        (Some(_), Some(_)) => Err(malformed("duplicate section")),
+    }
+}
+
+/// Checks that the little-endian `prime` is the modulus of `F`.
+fn check_prime<F: PrimeField>(prime: &[u8]) -> Result<()> {
+    // Compare p - 1 instead, which is representable in F.
+    let mut prime = prime.to_vec();
+    for byte in prime.iter_mut() {
+        let (decremented, borrow) = byte.overflowing_sub(1);
+        *byte = decremented;
+        if !borrow {
+            break;
+        }
+    }
+
+    if prime.as_slice() != (-F::ONE).to_repr().as_ref() {
+        return Err(malformed("circuit was compiled for a different field"));
+    }
+
+    Ok(())
+}
+
+fn field<F: PrimeField>(bytes: &[u8]) -> Result<F> {
+    let mut repr = F::Repr::default();
+    if repr.as_ref().len() != bytes.len() {
+        return Err(malformed("unexpected field element size"));
+    }
+    repr.as_mut().copy_from_slice(bytes);
+
+    Option::from(F::from_repr(repr)).ok_or_else(|| malformed("non-canonical field element"))
+}
+
+/// Reads little-endian integers from a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(malformed("unexpected end of file"));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().expect("took 4 bytes")))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().expect("took 8 bytes")))
+    }
+
+    fn finish(self) -> Result<()> {
+        if !self.bytes.is_empty() {
+            return Err(malformed("unexpected trailing bytes"));
+        }
+        Ok(())
+    }
+}
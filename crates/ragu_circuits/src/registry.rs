@@ -25,13 +25,14 @@ use ragu_arithmetic::{
 use ragu_core::{Error, Result};
 
 use crate::{
-    BondingObject, Circuit, WiringObject,
-    floor_planner::ConstraintSegment,
+    BondingObject, Circuit, CircuitMetrics, WiringObject,
+    export::ConstraintSystem,
+    floor_planner::{ConstraintSegment, DfsPlanner, FloorPlanner},
     polynomials::{Rank, sparse},
 };
 
 /// Represents a simple numeric index of a circuit in the registry.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct CircuitIndex(u32);
 
@@ -79,12 +80,16 @@ impl From<CircuitIndex> for usize {
 ///
 /// During finalization, circuits are concatenated in the order above,
 /// ensuring internal circuits get lower indices while maintaining
-/// proper PCD indexing.
+/// proper PCD indexing. Each circuit's segments are then laid out by the
+/// builder's [`FloorPlanner`] ([`DfsPlanner`] unless
+/// [`floor_planner`](Self::floor_planner) is called).
 pub struct RegistryBuilder<'params, F: PrimeField, R: Rank> {
     internal_circuits: Vec<Box<dyn WiringObject<F, R> + 'params>>,
     bonding: Vec<Box<dyn WiringObject<F, R> + 'params>>,
     internal_steps: Vec<Box<dyn WiringObject<F, R> + 'params>>,
     application_steps: Vec<Box<dyn WiringObject<F, R> + 'params>>,
+    floor_planner: Box<dyn FloorPlanner + 'params>,
+    domain_tag: Vec<u8>,
 }
 
 impl<F: FromUniformBytes<64>, R: Rank> Default for RegistryBuilder<'_, F, R> {
@@ -101,9 +106,26 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
             bonding: Vec::new(),
             internal_steps: Vec::new(),
             application_steps: Vec::new(),
+            floor_planner: Box::new(DfsPlanner),
+            domain_tag: Vec::new(),
         }
     }
 
+    /// Sets the [`FloorPlanner`] used to lay out every circuit's segments.
+    pub fn floor_planner(mut self, planner: impl FloorPlanner + 'params) -> Self {
+        self.floor_planner = Box::new(planner);
+        self
+    }
+
+    /// Binds the registry [`Key`] to `tag`, so that registries of identical
+    /// circuits under different tags have unrelated keys.
+    ///
+    /// The default empty tag leaves the key unchanged.
+    pub fn domain_tag(mut self, tag: &[u8]) -> Self {
+        self.domain_tag = tag.to_vec();
+        self
+    }
+
     /// Returns the number of internal circuits (circuits + bonding).
     pub fn num_internal_circuits(&self) -> usize {
         self.bonding.len() + self.internal_circuits.len()
@@ -119,7 +141,25 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
         self.num_circuits().next_power_of_two().trailing_zeros()
     }
 
+    /// Returns how each circuit registered so far fits within `R`, indexed as
+    /// [`finalize`](Self::finalize) will index it.
+    ///
+    /// Masking polynomials are omitted, as in [`Registry::circuit_fits`].
+    pub fn circuit_fits(&self) -> Vec<CircuitFit> {
+        circuit_fits(
+            self.internal_circuits
+                .iter()
+                .chain(&self.bonding)
+                .chain(&self.internal_steps)
+                .chain(&self.application_steps),
+        )
+    }
+
     /// Registers an application step circuit.
+    ///
+    /// The circuit is synthesized once to compute its [`CircuitMetrics`],
+    /// which are checked against `R` and can be read back through
+    /// [`application_step_metrics`](Self::application_step_metrics).
     pub fn register_circuit<C>(mut self, circuit: C) -> Result<Self>
     where
         C: Circuit<F> + 'params,
@@ -129,6 +169,24 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
         Ok(self)
     }
 
+    /// Returns the [`CircuitMetrics`] of each registered application step
+    /// circuit, in registration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Initialization`] if an application step was not
+    /// synthesized from a circuit and so has no metrics.
+    pub fn application_step_metrics(&self) -> Result<Vec<&CircuitMetrics>> {
+        self.application_steps
+            .iter()
+            .map(|circuit| {
+                circuit.metrics().ok_or_else(|| {
+                    Error::Initialization("application step has no circuit metrics".into())
+                })
+            })
+            .collect()
+    }
+
     /// Registers an internal circuit.
     pub fn register_internal_circuit<C>(mut self, circuit: C) -> Result<Self>
     where
@@ -176,7 +234,20 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
             });
         }
 
+        // Fields with a low 2-adicity cannot index many circuits; see the
+        // requirements of `Cycle`.
         let log2_circuits = self.log2_circuits();
+        if log2_circuits > F::S {
+            return Err(Error::Initialization(
+                alloc::format!(
+                    "{} circuits need a domain of size 2^{}, but the field has 2-adicity {}",
+                    total_circuits,
+                    log2_circuits,
+                    F::S
+                )
+                .into(),
+            ));
+        }
         let domain = Domain::<F>::new(log2_circuits);
 
         let circuits: Vec<_> = self
@@ -190,7 +261,7 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
         // Compute floor plans for each circuit.
         let floor_plans: Vec<Vec<ConstraintSegment>> = circuits
             .iter()
-            .map(|circuit| crate::floor_planner::floor_plan(circuit.segment_records()))
+            .map(|circuit| self.floor_planner.plan(circuit.segment_records()))
             .collect();
 
         // Build omega^j -> i lookup table.
@@ -218,7 +289,7 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
             omega_lookup,
             key: Key::default(),
         };
-        registry.key = Key::new(registry.compute_registry_digest());
+        registry.key = Key::new(registry.compute_registry_digest(&self.domain_tag));
 
         Ok(registry)
     }
@@ -294,6 +365,74 @@ impl<F: Field> Key<F> {
     }
 }
 
+/// The gate and constraint usage of a circuit in a [`Registry`], relative to
+/// the registry's [`Rank`].
+///
+/// Returned by [`Registry::circuit_fits`] and
+/// [`RegistryBuilder::circuit_fits`].
+#[derive(Clone, Copy, Debug)]
+pub struct CircuitFit {
+    /// The circuit's index in the registry.
+    pub index: CircuitIndex,
+    /// Number of multiplication gates used by the circuit.
+    pub num_gates: usize,
+    /// Number of linear constraints used by the circuit.
+    pub num_constraints: usize,
+    /// Number of gates still available to the circuit in this rank.
+    pub gate_headroom: usize,
+    /// Number of constraints still available to the circuit in this rank,
+    /// excluding the slot reserved for the registry key.
+    pub constraint_headroom: usize,
+    /// The smallest `RANK` this circuit would fit in, as computed by
+    /// [`min_rank`](crate::polynomials::min_rank).
+    pub min_rank: u32,
+}
+
+/// Returns how each of `circuits` fits within `R`, indexed by position.
+fn circuit_fits<'a, 'params: 'a, F: Field, R: Rank>(
+    circuits: impl IntoIterator<Item = &'a Box<dyn WiringObject<F, R> + 'params>>,
+) -> Vec<CircuitFit> {
+    circuits
+        .into_iter()
+        .enumerate()
+        .filter(|(_, circuit)| !circuit.is_mask())
+        .map(|(i, circuit)| {
+            let (num_gates, num_constraints) = circuit.constraint_counts();
+            CircuitFit {
+                index: CircuitIndex::new(i),
+                num_gates,
+                num_constraints,
+                gate_headroom: R::n() - num_gates,
+                constraint_headroom: R::num_coeffs() - 1 - num_constraints,
+                min_rank: crate::polynomials::min_rank(num_gates, num_constraints),
+            }
+        })
+        .collect()
+}
+
+/// The structural fingerprint of a circuit in a [`Registry`].
+///
+/// Returned by [`Registry::circuit_fingerprints`]. The `eval` scalar is the
+/// circuit's wiring polynomial $s(X, Y)$, as laid out by the registry's floor
+/// planner, evaluated at fixed pseudorandom points. Unlike
+/// the registry [`digest`](Registry::digest), it does not depend on the other
+/// circuits in the registry, so a changed fingerprint identifies exactly
+/// which circuit changed.
+///
+/// Since $s(X, Y)$ depends on the rank, fingerprints are only comparable
+/// between registries of the same [`Rank`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitFingerprint<F> {
+    /// The circuit's index in the registry.
+    pub index: CircuitIndex,
+    /// Number of multiplication gates used by the circuit.
+    pub num_gates: usize,
+    /// Number of linear constraints used by the circuit.
+    pub num_constraints: usize,
+    /// The evaluation of the circuit's wiring polynomial.
+    pub eval: F,
+}
+
 /// Represents a collection of circuits over a particular field, some of which
 /// may make reference to the others or be executed in similar contexts. The
 /// circuits are combined together using an interpolation polynomial so that
@@ -391,6 +530,18 @@ impl<F: PrimeField, R: Rank> Registry<'_, F, R> {
         self.key.value()
     }
 
+    /// Exports the constraint system of the given circuit, as laid out by this
+    /// registry's floor planner and tagged with its [`digest`](Self::digest).
+    ///
+    /// Returns `None` for masking polynomials, which are not synthesized from
+    /// a circuit.
+    pub fn export(&self, circuit: CircuitIndex) -> Option<ConstraintSystem<F>> {
+        let i = usize::from(circuit);
+        self.circuits[i]
+            .export(&self.floor_plans[i])
+            .map(|cs| cs.with_registry_digest(self.digest()))
+    }
+
     /// Returns the number of circuits in this registry.
     pub fn num_circuits(&self) -> usize {
         self.circuits.len()
@@ -401,6 +552,27 @@ impl<F: PrimeField, R: Rank> Registry<'_, F, R> {
         self.circuits[usize::from(circuit)].constraint_counts()
     }
 
+    /// Returns how each circuit in this registry fits within `R`.
+    ///
+    /// Masking polynomials are omitted, since they always span the whole
+    /// rank.
+    pub fn circuit_fits(&self) -> Vec<CircuitFit> {
+        circuit_fits(&self.circuits)
+    }
+
+    /// Returns the smallest `RANK` that fits every circuit in this registry,
+    /// as well as the number of circuits.
+    ///
+    /// The circuits' counts are those measured at `R`; see
+    /// [`min_rank`](crate::polynomials::min_rank).
+    pub fn min_rank(&self) -> u32 {
+        let for_circuits = self.circuits.len().next_power_of_two().trailing_zeros();
+        self.circuit_fits()
+            .iter()
+            .map(|fit| fit.min_rank)
+            .fold(for_circuits, u32::max)
+    }
+
     /// Evaluates the registry key contribution $k \cdot (XY)^{4n-1}$
     /// at $(x, y)$, returning a scalar.
     fn key_sxy(&self, x: F, y: F) -> F {
@@ -654,9 +826,48 @@ impl<F: PrimeField, R: Rank> RegistryAt<'_, F, R> {
 }
 
 impl<F: FromUniformBytes<64>, R: Rank> Registry<'_, F, R> {
+    /// Returns the structural fingerprint of each circuit in this registry.
+    ///
+    /// Masking polynomials are omitted, as in
+    /// [`circuit_fits`](Self::circuit_fits).
+    pub fn circuit_fingerprints(&self) -> Vec<CircuitFingerprint<F>> {
+        let point = |index: u8| {
+            F::from_uniform_bytes(
+                Params::new()
+                    .personal(b"ragu_circuit_fp_")
+                    .to_state()
+                    .update(&[index])
+                    .finalize()
+                    .as_array(),
+            )
+        };
+        let x = point(0);
+        let y = point(1);
+
+        self.circuits
+            .iter()
+            .zip(&self.floor_plans)
+            .enumerate()
+            .filter(|(_, (circuit, _))| !circuit.is_mask())
+            .map(|(i, (circuit, floor_plan))| {
+                let (num_gates, num_constraints) = circuit.constraint_counts();
+                CircuitFingerprint {
+                    index: CircuitIndex::new(i),
+                    num_gates,
+                    num_constraints,
+                    eval: circuit.sxy(x, y, floor_plan),
+                }
+            })
+            .collect()
+    }
+
     /// Compute a digest of this registry using BLAKE2b.
-    fn compute_registry_digest(&self) -> F {
+    fn compute_registry_digest(&self, domain_tag: &[u8]) -> F {
         let mut hasher = Params::new().personal(b"ragu_registry___").to_state();
+        if !domain_tag.is_empty() {
+            hasher.update(&(domain_tag.len() as u64).to_le_bytes());
+            hasher.update(domain_tag);
+        }
 
         let field_from_hash = |digest_state: &blake2b_simd::Hash, index: u8| {
             F::from_uniform_bytes(
@@ -708,7 +919,10 @@ mod tests {
     use ragu_pasta::Fp;
 
     use super::{CircuitIndex, OmegaKey, RegistryBuilder};
-    use crate::{polynomials::TestRank, tests::SquareCircuit};
+    use crate::{
+        polynomials::{Rank, TestRank},
+        tests::SquareCircuit,
+    };
     type TestRegistryBuilder<'a> = RegistryBuilder<'a, Fp, TestRank>;
 
     #[test]
@@ -734,6 +948,69 @@ mod tests {
         assert_eq!(order(CircuitIndex::new(7).omega_j::<Fp>()), 8);
     }
 
+    #[test]
+    fn test_domain_tag_binds_key() -> Result<()> {
+        let build = |tag: Option<&[u8]>| -> Result<Fp> {
+            let builder = TestRegistryBuilder::new()
+                .register_circuit(SquareCircuit { times: 2 })?
+                .register_circuit(SquareCircuit { times: 5 })?;
+            match tag {
+                Some(tag) => builder.domain_tag(tag),
+                None => builder,
+            }
+            .finalize()
+            .map(|registry| registry.digest())
+        };
+
+        let untagged = build(None)?;
+        assert_eq!(build(Some(b"".as_slice()))?, untagged);
+        assert_ne!(build(Some(b"app-a".as_slice()))?, untagged);
+        assert_ne!(
+            build(Some(b"app-a".as_slice()))?,
+            build(Some(b"app-b".as_slice()))?
+        );
+        assert_eq!(
+            build(Some(b"app-a".as_slice()))?,
+            build(Some(b"app-a".as_slice()))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_appended_circuits_keep_slots() -> Result<()> {
+        let small = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 5 })?
+            .register_circuit(SquareCircuit { times: 10 })?
+            .finalize()?;
+        let large = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 5 })?
+            .register_circuit(SquareCircuit { times: 10 })?
+            .register_circuit(SquareCircuit { times: 11 })?
+            .register_circuit(SquareCircuit { times: 19 })?
+            .finalize()?;
+
+        // The domain grows and the key changes, but every existing circuit
+        // stays at the same omega^j.
+        assert!(large.domain.log2_n() > small.domain.log2_n());
+        assert_ne!(small.digest(), large.digest());
+
+        let x = Fp::random(&mut ragu_arithmetic::rand::rng());
+        let y = Fp::random(&mut ragu_arithmetic::rand::rng());
+        for i in 0..small.num_circuits() {
+            let i = CircuitIndex::new(i);
+            assert!(large.circuit_in_domain(i));
+            assert_eq!(
+                small.circuit_xy(i, x, y) - small.key_sxy(x, y),
+                large.circuit_xy(i, x, y) - large.key_sxy(x, y)
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_registry_circuit_consistency() -> Result<()> {
         let registry = TestRegistryBuilder::new()
@@ -902,6 +1179,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_circuit_fits() -> Result<()> {
+        let registry = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 19 })?
+            .finalize()?;
+
+        let fits = registry.circuit_fits();
+        assert_eq!(fits.len(), 2);
+        for fit in &fits {
+            assert_eq!(
+                (fit.num_gates, fit.num_constraints),
+                registry.constraint_counts(fit.index)
+            );
+            assert_eq!(fit.num_gates + fit.gate_headroom, TestRank::n());
+            assert_eq!(
+                fit.num_constraints + fit.constraint_headroom,
+                TestRank::num_coeffs() - 1
+            );
+        }
+
+        assert!(fits[0].min_rank <= fits[1].min_rank);
+        assert_eq!(registry.min_rank(), fits[1].min_rank);
+        assert!(registry.min_rank() <= TestRank::RANK);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_circuit_fingerprints() -> Result<()> {
+        let small = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 5 })?
+            .finalize()?;
+        let large = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 6 })?
+            .register_circuit(SquareCircuit { times: 10 })?
+            .finalize()?;
+
+        // Changing one circuit changes the digest, but only that circuit's
+        // fingerprint.
+        let small = small.circuit_fingerprints();
+        let large = large.circuit_fingerprints();
+        assert_eq!(small.len(), 2);
+        assert_eq!(large.len(), 3);
+        assert_eq!(small[0], large[0]);
+        assert_ne!(small[1], large[1]);
+        assert_eq!(small[1].num_gates + 1, large[1].num_gates);
+
+        Ok(())
+    }
+
     #[test]
     fn test_single_circuit_registry() -> Result<()> {
         // Checks that a single circuit can be finalized without bit-shift overflows.
@@ -34,6 +34,7 @@ use ragu_core::{
 use super::{MultiStage, MultiStageCircuit, StageBuilder};
 use crate::{
     BondingObject, SegmentRecord, WiringObject,
+    export::ConstraintSystem,
     floor_planner::ConstraintSegment,
     into_wiring_object,
     polynomials::{Rank, sparse},
@@ -265,6 +266,11 @@ impl<F: Field, R: Rank> WiringObject<F, R> for Stripped<'_, F, R> {
     fn segment_records(&self) -> &[SegmentRecord] {
         self.0.segment_records()
     }
+
+    fn export(&self, floor_plan: &[ConstraintSegment]) -> Option<ConstraintSystem<F>> {
+        // The ONE constraint is the one at y^0.
+        self.0.export(floor_plan).map(ConstraintSystem::strip_one)
+    }
 }
 
 #[cfg(test)]
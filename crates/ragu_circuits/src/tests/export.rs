@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+
+use ragu_arithmetic::ff::Field;
+use ragu_pasta::Fp;
+
+use super::{RepeatedRoutineCircuit, SquareCircuit};
+use crate::{
+    CircuitExt, WiringObject,
+    export::{ConstraintSystem, WireKind, WireRef},
+    floor_planner, into_wiring_object,
+    polynomials::{Rank, TestRank},
+    registry::{CircuitIndex, RegistryBuilder},
+};
+
+/// Evaluates every exported constraint at the monomials the `sx` evaluator
+/// assigns to each wire, and checks that they are the coefficients of
+/// $s(x, Y)$.
+fn check_against_sx<R: Rank>(cs: &ConstraintSystem<Fp>, obj: &dyn WiringObject<Fp, R>) {
+    let x = Fp::random(&mut ragu_arithmetic::rand::rng());
+    let plan = floor_planner::floor_plan(obj.segment_records());
+    let n = R::n() as u64;
+
+    let monomial = |wire: &WireRef| {
+        let i = wire.gate() as u64;
+        let exponent = match wire.kind() {
+            WireKind::A => 2 * n + i,
+            WireKind::B => 2 * n - 1 - i,
+            WireKind::C => 4 * n - 1 - i,
+            WireKind::D => i,
+        };
+        x.pow_vartime([exponent])
+    };
+
+    let expected: Vec<Fp> = obj.sx(x, &plan).iter_coeffs().collect();
+    for constraint in cs.constraints() {
+        let value = constraint
+            .terms()
+            .iter()
+            .map(|(wire, coeff)| monomial(wire) * coeff)
+            .sum::<Fp>();
+        assert_eq!(value, expected[constraint.position()]);
+    }
+    assert!(
+        expected[cs.num_constraints()..]
+            .iter()
+            .all(|coeff| coeff.is_zero_vartime())
+    );
+}
+
+#[test]
+fn test_export_matches_sx() {
+    let circuit = SquareCircuit { times: 3 };
+    let cs = circuit.export().unwrap();
+    let obj = into_wiring_object::<_, _, TestRank>(circuit).unwrap();
+    check_against_sx::<TestRank>(&cs, &*obj);
+
+    let cs = RepeatedRoutineCircuit.export().unwrap();
+    let obj = into_wiring_object::<_, _, TestRank>(RepeatedRoutineCircuit).unwrap();
+    check_against_sx::<TestRank>(&cs, &*obj);
+    assert!(cs.segments().len() > 1);
+}
+
+#[test]
+fn test_export_layout() {
+    let circuit = SquareCircuit { times: 2 };
+    let metrics = circuit.metrics().unwrap();
+    let cs = circuit.export().unwrap();
+
+    assert_eq!(cs.num_gates(), metrics.num_gates());
+    assert_eq!(cs.num_constraints(), metrics.num_constraints());
+    assert_eq!(cs.segments().len(), 1);
+    assert!(cs.registry_digest().is_none());
+
+    // The ONE constraint is at position 0, followed by the single output.
+    assert_eq!(cs.constraints()[0].terms(), &[(WireRef::ONE, Fp::ONE)]);
+    let outputs: Vec<_> = cs.outputs().map(|output| output.position()).collect();
+    assert_eq!(outputs, [1]);
+}
+
+#[test]
+fn test_export_rendering() {
+    let cs = SquareCircuit { times: 2 }.export().unwrap();
+
+    let text = cs.to_text();
+    assert!(text.starts_with("ragu-constraint-system 1\ndigest "));
+    assert!(text.contains("\nconstraint 0 segment 0: 0x1*d0\n"));
+    assert!(text.ends_with("\noutput 0 1\n"));
+
+    let json = cs.to_json();
+    assert!(json.starts_with("{\n  \"version\": 1,\n"));
+    assert!(json.contains("\"registry_digest\": null,"));
+    assert!(json.contains("{\"position\": 0, \"segment\": 0, \"terms\": [[\"d0\", \"0x1\"]]}"));
+    assert!(json.ends_with("  \"outputs\": [1]\n}\n"));
+}
+
+#[test]
+fn test_export_digest() {
+    let digest = |times| SquareCircuit { times }.export().unwrap().digest();
+
+    assert_eq!(digest(2), digest(2));
+    assert_ne!(digest(2), digest(3));
+}
+
+#[test]
+fn test_registry_export() {
+    let registry = RegistryBuilder::<Fp, TestRank>::new()
+        .register_circuit(SquareCircuit { times: 2 })
+        .unwrap()
+        .register_circuit(RepeatedRoutineCircuit)
+        .unwrap()
+        .finalize()
+        .unwrap();
+
+    let cs = registry.export(CircuitIndex::new(1)).unwrap();
+    assert_eq!(cs.registry_digest(), Some(&registry.digest()));
+    assert_eq!(
+        cs.digest(),
+        RepeatedRoutineCircuit.export().unwrap().digest()
+    );
+    assert!(cs.to_text().contains("\nregistry 0x"));
+}
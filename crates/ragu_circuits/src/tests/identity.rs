@@ -99,6 +99,35 @@ impl Routine<Fp> for SquareOnceAlias {
     }
 }
 
+/// Same constraints as `SquareOnce`, but returns its input instead of the
+/// square.
+#[derive(Clone)]
+struct SquareDiscard;
+
+impl Routine<Fp> for SquareDiscard {
+    type Input = Kind![Fp; Element<'_, _>];
+    type Output = Kind![Fp; Element<'_, _>];
+    type Aux<'dr> = ();
+
+    fn execute<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        input: Bound<'dr, D, Self::Input>,
+        _aux: DriverValue<D, Self::Aux<'dr>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let _ = input.square(dr)?;
+        Ok(input)
+    }
+
+    fn predict<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        _dr: &mut D,
+        _input: &Bound<'dr, D, Self::Input>,
+    ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>> {
+        Ok(Prediction::Unknown(D::just(|| ())))
+    }
+}
+
 /// Zero input wires — produces an Element from nothing.
 #[derive(Clone)]
 struct Produce;
@@ -1476,6 +1505,17 @@ fn test_type_discrimination() {
     }
 }
 
+/// Routines with identical constraints but differently wired outputs are
+/// distinct, since the wiring evaluators replay memoized outputs.
+#[test]
+fn test_output_sensitivity() {
+    let square = fingerprint_elem(&SquareOnce);
+    let discard = fingerprint_elem(&SquareDiscard);
+
+    assert_eq!(square.eval(), discard.eval());
+    assert_ne!(square, discard);
+}
+
 /// Different input wire counts produce different fingerprints.
 #[test]
 fn test_input_wire_count() {
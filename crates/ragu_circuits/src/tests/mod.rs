@@ -1,8 +1,12 @@
 #![allow(non_snake_case)]
 
+mod export;
 mod identity;
+mod r1cs;
 mod segment_order;
 
+use alloc::vec::Vec;
+
 use ragu_arithmetic::ff::Field;
 use ragu_core::{
     Result,
@@ -15,7 +19,9 @@ use ragu_pasta::Fp;
 use ragu_primitives::{Element, Simulator, allocator::Standard};
 
 use crate::{
-    Circuit, CircuitExt, WiringObject, WithAux, floor_planner, into_wiring_object,
+    Circuit, CircuitExt, WiringObject, WithAux,
+    floor_planner::{self, ConstraintSegment},
+    into_wiring_object,
     polynomials::{Rank, TestRank},
 };
 
@@ -231,3 +237,250 @@ fn test_element() {
     let result = simulator.routine(TestRoutine, input).unwrap();
     assert_eq!(*result.value().take(), Fp::from(15u64));
 }
+
+/// Leaf routine computing `a * b + a`.
+#[derive(Clone)]
+struct MulAdd;
+
+impl Routine<Fp> for MulAdd {
+    type Input = Kind![Fp; (Element<'_, _>, Element<'_, _>)];
+    type Output = Kind![Fp; Element<'_, _>];
+    type Aux<'dr> = ();
+
+    fn execute<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        (a, b): Bound<'dr, D, Self::Input>,
+        _aux: DriverValue<D, Self::Aux<'dr>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let ab = a.mul(dr, &b)?;
+        Ok(ab.add(dr, &a))
+    }
+
+    fn predict<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        _dr: &mut D,
+        _input: &Bound<'dr, D, Self::Input>,
+    ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>> {
+        Ok(Prediction::Unknown(D::unit()))
+    }
+}
+
+/// Non-leaf routine that invokes [`MulAdd`] twice around a local square.
+#[derive(Clone)]
+struct MulAddTwice;
+
+impl Routine<Fp> for MulAddTwice {
+    type Input = Kind![Fp; (Element<'_, _>, Element<'_, _>)];
+    type Output = Kind![Fp; Element<'_, _>];
+    type Aux<'dr> = ();
+
+    fn execute<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        (a, b): Bound<'dr, D, Self::Input>,
+        _aux: DriverValue<D, Self::Aux<'dr>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let a = dr.routine(MulAdd, (a, b.clone()))?;
+        let a = a.square(dr)?;
+        dr.routine(MulAdd, (a, b))
+    }
+
+    fn predict<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        _dr: &mut D,
+        _input: &Bound<'dr, D, Self::Input>,
+    ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>> {
+        Ok(Prediction::Unknown(D::unit()))
+    }
+}
+
+/// Circuit dominated by repeated invocations of the same leaf routine.
+struct RepeatedRoutineCircuit;
+
+impl Circuit<Fp> for RepeatedRoutineCircuit {
+    type Instance<'instance> = Fp;
+    type Output = Kind![Fp; Element<'_, _>];
+    type Witness<'witness> = (Fp, Fp);
+    type Aux<'witness> = ();
+
+    fn instance<'dr, 'instance: 'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        instance: DriverValue<D, Self::Instance<'instance>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let allocator = &mut Standard::new();
+        Element::alloc(dr, allocator, instance)
+    }
+
+    fn witness<'dr, 'witness: 'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'witness>>,
+    ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'witness>>>> {
+        let allocator = &mut Standard::new();
+        let mut a = Element::alloc(dr, allocator, witness.as_ref().map(|w| w.0))?;
+        let b = Element::alloc(dr, allocator, witness.as_ref().map(|w| w.1))?;
+
+        for _ in 0..4 {
+            a = dr.routine(MulAdd, (a, b.clone()))?;
+            a = a.add(dr, &b);
+        }
+        let a = dr.routine(MulAddTwice, (a, b.clone()))?;
+        let a = dr.routine(MulAdd, (b, a))?;
+
+        Ok(WithAux::new(a, D::unit()))
+    }
+}
+
+/// Memoized leaf routines must contribute exactly what in-place synthesis
+/// would, at every placement.
+#[test]
+fn test_memoized_routines_match_synthesis() {
+    let obj = into_wiring_object::<_, _, TestRank>(RepeatedRoutineCircuit).unwrap();
+    consistency_checks::<TestRank>(&*obj);
+
+    let plan = floor_planner::floor_plan(obj.segment_records());
+    let memoized = plan.iter().filter(|seg| seg.memo_key.is_some()).count();
+    assert_eq!(memoized, 7, "every MulAdd invocation is a memoizable leaf");
+    assert!(plan[0].memo_key.is_none());
+
+    let unmemoized: Vec<_> = plan
+        .iter()
+        .map(|seg| ConstraintSegment {
+            memo_key: None,
+            ..*seg
+        })
+        .collect();
+
+    let x = Fp::random(&mut ragu_arithmetic::rand::rng());
+    let y = Fp::random(&mut ragu_arithmetic::rand::rng());
+    assert_eq!(obj.sxy(x, y, &plan), obj.sxy(x, y, &unmemoized));
+    assert_eq!(obj.sx(x, &plan).eval(y), obj.sx(x, &unmemoized).eval(y));
+    assert_eq!(obj.sy(y, &plan).eval(x), obj.sy(y, &unmemoized).eval(x));
+    assert_eq!(obj.sy(y, &plan).eval(x), obj.sxy(x, y, &plan));
+    assert_eq!(
+        obj.sxy(Fp::ZERO, y, &plan),
+        obj.sxy(Fp::ZERO, y, &unmemoized)
+    );
+}
+
+/// Non-leaf routine that squeezes its input through a chain of Poseidon
+/// permutations, each a memoizable leaf.
+#[cfg(feature = "multicore")]
+#[derive(Clone)]
+struct PoseidonChain;
+
+#[cfg(feature = "multicore")]
+impl Routine<Fp> for PoseidonChain {
+    type Input = Kind![Fp; Element<'_, _>];
+    type Output = Kind![Fp; Element<'_, _>];
+    type Aux<'dr> = ();
+
+    fn execute<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        input: Bound<'dr, D, Self::Input>,
+        _aux: DriverValue<D, Self::Aux<'dr>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        use ragu_arithmetic::Cycle;
+
+        let mut sponge = ragu_primitives::poseidon::Sponge::new(
+            dr,
+            ragu_pasta::Pasta::circuit_poseidon(ragu_pasta::Pasta::baked()),
+        );
+        let mut value = input;
+        for _ in 0..2 {
+            sponge.absorb(dr, &value)?;
+            value = sponge.squeeze(dr)?;
+        }
+        Ok(value)
+    }
+
+    fn predict<'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        _dr: &mut D,
+        _input: &Bound<'dr, D, Self::Input>,
+    ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>> {
+        Ok(Prediction::Unknown(D::unit()))
+    }
+}
+
+/// Circuit whose root scope alternates deferred [`PoseidonChain`] calls with
+/// local constraints on virtual wires.
+#[cfg(feature = "multicore")]
+struct PoseidonChainCircuit;
+
+#[cfg(feature = "multicore")]
+impl Circuit<Fp> for PoseidonChainCircuit {
+    type Instance<'instance> = Fp;
+    type Output = Kind![Fp; Element<'_, _>];
+    type Witness<'witness> = (Fp, Fp);
+    type Aux<'witness> = ();
+
+    fn instance<'dr, 'instance: 'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        instance: DriverValue<D, Self::Instance<'instance>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let allocator = &mut Standard::new();
+        Element::alloc(dr, allocator, instance)
+    }
+
+    fn witness<'dr, 'witness: 'dr, D: Driver<'dr, F = Fp>>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'witness>>,
+    ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'witness>>>> {
+        let allocator = &mut Standard::new();
+        let mut a = Element::alloc(dr, allocator, witness.as_ref().map(|w| w.0))?;
+        let b = Element::alloc(dr, allocator, witness.as_ref().map(|w| w.1))?;
+
+        for _ in 0..3 {
+            let input = a.add(dr, &b);
+            a = dr.routine(PoseidonChain, input)?;
+            a = a.mul(dr, &b)?;
+        }
+
+        Ok(WithAux::new(a, D::unit()))
+    }
+}
+
+/// Deferring routines to parallel jobs must not change any evaluation.
+#[cfg(feature = "multicore")]
+#[test]
+fn test_deferred_routines_match_sequential() {
+    use crate::{
+        polynomials::{ProductionRank, sparse},
+        raw::CircuitAdapterRef,
+        wiring::{sx, sxy, sy},
+    };
+
+    let obj = into_wiring_object::<_, _, ProductionRank>(PoseidonChainCircuit).unwrap();
+    consistency_checks::<ProductionRank>(&*obj);
+
+    let plan = floor_planner::floor_plan(obj.segment_records());
+    assert!(
+        plan.iter().filter(|seg| seg.memo_key.is_none()).count() > 3,
+        "the chains are deferred"
+    );
+
+    let raw = CircuitAdapterRef(&PoseidonChainCircuit);
+    let x = Fp::random(&mut ragu_arithmetic::rand::rng());
+    let y = Fp::random(&mut ragu_arithmetic::rand::rng());
+
+    let coeffs =
+        |poly: sparse::Polynomial<Fp, ProductionRank>| poly.iter_coeffs().collect::<Vec<_>>();
+    assert_eq!(
+        coeffs(sx::eval_with::<_, _, ProductionRank>(&raw, x, &plan, true).unwrap()),
+        coeffs(sx::eval_with::<_, _, ProductionRank>(&raw, x, &plan, false).unwrap())
+    );
+    assert_eq!(
+        coeffs(sy::eval_with::<_, _, ProductionRank>(&raw, y, &plan, true).unwrap()),
+        coeffs(sy::eval_with::<_, _, ProductionRank>(&raw, y, &plan, false).unwrap())
+    );
+    assert_eq!(
+        sxy::eval_with::<_, _, ProductionRank>(&raw, x, y, &plan, true).unwrap(),
+        sxy::eval_with::<_, _, ProductionRank>(&raw, x, y, &plan, false).unwrap()
+    );
+}
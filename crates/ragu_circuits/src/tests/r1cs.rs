@@ -0,0 +1,201 @@
+use alloc::{vec, vec::Vec};
+
+use ragu_arithmetic::ff::{Field, PrimeField};
+use ragu_core::{Error, maybe::Maybe};
+use ragu_pasta::Fp;
+use ragu_primitives::Simulator;
+
+use super::consistency_checks;
+use crate::{
+    Circuit, CircuitExt, into_wiring_object,
+    polynomials::TestRank,
+    r1cs::{R1cs, R1csCircuit, read_witness},
+};
+
+type Lc = Vec<(u32, Fp)>;
+
+/// Returns the little-endian modulus of [`Fp`].
+fn modulus() -> Vec<u8> {
+    let mut prime = (-Fp::ONE).to_repr().as_ref().to_vec();
+    for byte in prime.iter_mut() {
+        let (incremented, carry) = byte.overflowing_add(1);
+        *byte = incremented;
+        if !carry {
+            break;
+        }
+    }
+    prime
+}
+
+fn file(magic: &[u8; 4], version: u32, sections: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let mut bytes = magic.to_vec();
+    bytes.extend(version.to_le_bytes());
+    bytes.extend((sections.len() as u32).to_le_bytes());
+    for (ty, contents) in sections {
+        bytes.extend(ty.to_le_bytes());
+        bytes.extend((contents.len() as u64).to_le_bytes());
+        bytes.extend(contents);
+    }
+    bytes
+}
+
+fn encode_r1cs(counts: [u32; 4], constraints: &[[Lc; 3]]) -> Vec<u8> {
+    let mut header = 32u32.to_le_bytes().to_vec();
+    header.extend(modulus());
+    for count in counts {
+        header.extend(count.to_le_bytes());
+    }
+    header.extend(0u64.to_le_bytes());
+    header.extend((constraints.len() as u32).to_le_bytes());
+
+    let mut body = Vec::new();
+    for lc in constraints.iter().flatten() {
+        body.extend((lc.len() as u32).to_le_bytes());
+        for (signal, coeff) in lc {
+            body.extend(signal.to_le_bytes());
+            body.extend(coeff.to_repr().as_ref());
+        }
+    }
+
+    file(b"r1cs", 1, &[(1, header), (2, body)])
+}
+
+fn encode_wtns(signals: &[Fp]) -> Vec<u8> {
+    let mut header = 32u32.to_le_bytes().to_vec();
+    header.extend(modulus());
+    header.extend((signals.len() as u32).to_le_bytes());
+
+    let body = signals
+        .iter()
+        .flat_map(|signal| signal.to_repr().as_ref().to_vec())
+        .collect();
+
+    file(b"wtns", 2, &[(1, header), (2, body)])
+}
+
+/// A circuit with signals `[1, out, x, y, t]` proving that `out = x * y + 3`,
+/// where `out` is a public output, `x` a public input and `y` private.
+fn example() -> Vec<u8> {
+    let one = Fp::ONE;
+    encode_r1cs(
+        [5, 1, 1, 1],
+        &[
+            // x * y = t
+            [vec![(2, one)], vec![(3, one)], vec![(4, one)]],
+            // 0 = t + 3 - out
+            [vec![], vec![], vec![(4, one), (0, Fp::from(3)), (1, -one)]],
+            // 2 * x = x + x
+            [
+                vec![(0, Fp::from(2))],
+                vec![(2, one)],
+                vec![(2, one), (2, one)],
+            ],
+        ],
+    )
+}
+
+fn example_witness(x: u64, y: u64) -> Vec<Fp> {
+    let (x, y) = (Fp::from(x), Fp::from(y));
+    vec![Fp::ONE, x * y + Fp::from(3), x, y, x * y]
+}
+
+#[test]
+fn test_r1cs_read() {
+    let r1cs = R1cs::<Fp>::read(&example()).unwrap();
+    assert_eq!(r1cs.num_signals(), 5);
+    assert_eq!(r1cs.num_public_outputs(), 1);
+    assert_eq!(r1cs.num_public_inputs(), 1);
+    assert_eq!(r1cs.num_private_inputs(), 1);
+    assert_eq!(r1cs.num_public(), 2);
+    assert_eq!(r1cs.constraints().len(), 3);
+    assert_eq!(r1cs.constraints()[0].a(), &[(2, Fp::ONE)]);
+    assert!(r1cs.constraints()[1].b().is_empty());
+
+    let witness = example_witness(6, 7);
+    assert_eq!(read_witness::<Fp>(&encode_wtns(&witness)).unwrap(), witness);
+}
+
+#[test]
+fn test_r1cs_synthesize() {
+    let r1cs = R1cs::<Fp>::read(&example()).unwrap();
+
+    let sim = Simulator::simulate(example_witness(6, 7), |dr, witness| {
+        let signals = r1cs.synthesize(dr, witness.as_ref().map(Vec::as_slice))?;
+        assert_eq!(*signals[1].value().take(), Fp::from(45));
+        Ok(())
+    })
+    .unwrap();
+    // The four allocations share two gates, and only the first constraint is
+    // quadratic.
+    assert_eq!(sim.num_gates(), 3);
+
+    let result = Simulator::simulate(vec![Fp::ONE], |dr, witness| {
+        r1cs.synthesize(dr, witness.as_ref().map(Vec::as_slice))?;
+        Ok(())
+    });
+    assert!(matches!(
+        result,
+        Err(Error::VectorLengthMismatch {
+            expected: 5,
+            actual: 1
+        })
+    ));
+
+    let mut witness = example_witness(6, 7);
+    witness[4] += Fp::ONE;
+    let result = Simulator::simulate(witness, |dr, witness| {
+        r1cs.synthesize(dr, witness.as_ref().map(Vec::as_slice))?;
+        Ok(())
+    });
+    assert!(matches!(result, Err(Error::InvalidWitness(_))));
+}
+
+#[test]
+fn test_r1cs_circuit() {
+    let r1cs = R1cs::<Fp>::read(&example()).unwrap();
+    assert!(R1csCircuit::<Fp, 1>::new(&r1cs).is_err());
+
+    let circuit = R1csCircuit::<Fp, 2>::new(&r1cs).unwrap();
+    let witness = example_witness(6, 7);
+    Simulator::simulate(witness.as_slice(), |dr, witness| {
+        let output = circuit.witness(dr, witness)?.into_output();
+        let public: Vec<Fp> = output.iter().map(|e| *e.value().take()).collect();
+        assert_eq!(public, [Fp::from(45), Fp::from(6)]);
+        Ok(())
+    })
+    .unwrap();
+    circuit.trace(witness.as_slice()).unwrap();
+
+    let obj = into_wiring_object::<_, _, TestRank>(circuit).unwrap();
+    consistency_checks::<TestRank>(&*obj);
+}
+
+#[test]
+fn test_r1cs_malformed() {
+    let bytes = example();
+    let malformed =
+        |bytes: &[u8]| matches!(R1cs::<Fp>::read(bytes), Err(Error::MalformedEncoding(_)));
+
+    assert!(malformed(&bytes[..bytes.len() - 1]));
+    assert!(malformed(&[&b"wtns"[..], &bytes[4..]].concat()));
+    assert!(malformed(&encode_r1cs(
+        [5, 1, 1, 1],
+        &[[vec![(5, Fp::ONE)], vec![], vec![]]]
+    )));
+    assert!(malformed(&encode_r1cs([3, 1, 1, 1], &[])));
+
+    // A file compiled for a different prime.
+    let mut other = bytes.clone();
+    let prime_offset = 12 + 12 + 4;
+    assert_eq!(
+        &other[prime_offset..prime_offset + 32],
+        modulus().as_slice()
+    );
+    other[prime_offset] ^= 1;
+    assert!(malformed(&other));
+
+    assert!(matches!(
+        read_witness::<Fp>(&bytes),
+        Err(Error::MalformedEncoding(_))
+    ));
+}
@@ -1,11 +1,12 @@
 //! Property tests verifying that [`crate::metrics::eval`] and [`crate::trace::eval`]
 //! agree on segment count and per-segment gate counts, confirming
-//! that both evaluators traverse the routine call tree in identical DFS order.
+//! that both evaluators traverse the routine call tree in identical DFS order,
+//! and that every [`FloorPlanner`] lays those segments out consistently.
 
-use alloc::{format, vec, vec::Vec};
+use alloc::{collections::BTreeMap, format, vec, vec::Vec};
 
 use proptest::prelude::*;
-use ragu_arithmetic::Coeff;
+use ragu_arithmetic::{Coeff, ff::Field};
 use ragu_core::{
     Result,
     drivers::{Driver, DriverValue},
@@ -15,7 +16,12 @@ use ragu_core::{
 use ragu_pasta::Fp;
 use ragu_primitives::allocator::Allocator;
 
-use crate::{Circuit, WithAux};
+use crate::{
+    Circuit, CircuitExt, RoutineIdentity, WithAux,
+    floor_planner::{ConstraintSegment, DfsPlanner, FloorPlanner, PackingPlanner},
+    into_wiring_object,
+    polynomials::{ProductionRank, Rank},
+};
 
 /// Maximum number of wire allocations generated at any one point in a scope.
 const MAX_ALLOCS: usize = 6;
@@ -202,4 +208,88 @@ proptest! {
             );
         }
     }
+
+    /// Checks that each [`FloorPlanner`] keeps the root at the origin, lays
+    /// out segments without gaps or overlaps, and produces a floor plan under
+    /// which the assembled trace satisfies the wiring polynomial.
+    #[test]
+    fn proptest_floor_planners(tree in arb_tree()) {
+        let circuit = TreeCircuit(tree.clone());
+        let metrics = crate::metrics::eval::<Fp, _>(&circuit)
+            .map_err(|e| TestCaseError::fail(format!("metrics: {e:?}")))?;
+        let trace = crate::trace::eval::<Fp, _>(&circuit, ())
+            .map_err(|e| TestCaseError::fail(format!("trace: {e:?}")))?.into_output();
+        let obj = into_wiring_object::<_, _, ProductionRank>(TreeCircuit(tree))
+            .map_err(|e| TestCaseError::fail(format!("wiring: {e:?}")))?;
+
+        let planners: [&dyn FloorPlanner; 2] = [&DfsPlanner, &PackingPlanner];
+        for planner in planners {
+            let plan = planner.plan(&metrics.segments);
+            check_layout(&plan)?;
+
+            let x = Fp::random(&mut ragu_arithmetic::rand::rng());
+            let y = Fp::random(&mut ragu_arithmetic::rand::rng());
+            let z = Fp::random(&mut ragu_arithmetic::rand::rng());
+
+            let sxy = obj.sxy(x, y, &plan);
+            prop_assert_eq!(sxy, obj.sx(x, &plan).eval(y));
+            prop_assert_eq!(sxy, obj.sy(y, &plan).eval(x));
+
+            let a = trace
+                .assemble::<ProductionRank>(&plan, Fp::ZERO)
+                .map_err(|e| TestCaseError::fail(format!("assemble: {e:?}")))?;
+            let mut b = a.clone();
+            b.dilate(z);
+            b.add_assign(&obj.sy(y, &plan));
+            b.add_assign(&ProductionRank::tz(z));
+            let expected = circuit
+                .ky((), y)
+                .map_err(|e| TestCaseError::fail(format!("ky: {e:?}")))?;
+            prop_assert_eq!(expected, a.revdot(&b));
+        }
+
+        // Invocations sharing a fingerprint sit back to back, in DFS order.
+        let plan = PackingPlanner.plan(&metrics.segments);
+        let mut groups = BTreeMap::<_, Vec<usize>>::new();
+        for (index, record) in metrics.segments.iter().enumerate() {
+            if let RoutineIdentity::Routine(fingerprint) = *record.identity() {
+                groups.entry(fingerprint).or_default().push(index);
+            }
+        }
+        for indices in groups.values() {
+            for pair in indices.windows(2) {
+                let (prev, next) = (&plan[pair[0]], &plan[pair[1]]);
+                prop_assert_eq!(next.gate_start, prev.gate_start + prev.num_gates);
+                prop_assert_eq!(
+                    next.constraint_start,
+                    prev.constraint_start + prev.num_constraints
+                );
+            }
+        }
+    }
+}
+
+/// Checks that `plan` places the root segment at the origin and tiles the
+/// gate and constraint ranges with its segments.
+fn check_layout(plan: &[ConstraintSegment]) -> core::result::Result<(), TestCaseError> {
+    prop_assert_eq!(plan[0].gate_start, 0);
+    prop_assert_eq!(plan[0].constraint_start, 0);
+
+    let mut gates: Vec<_> = plan.iter().map(|s| (s.gate_start, s.num_gates)).collect();
+    let mut constraints: Vec<_> = plan
+        .iter()
+        .map(|s| (s.constraint_start, s.num_constraints))
+        .collect();
+    gates.sort();
+    constraints.sort();
+
+    for ranges in [gates, constraints] {
+        let mut end = 0;
+        for (start, len) in ranges {
+            prop_assert_eq!(start, end, "segments must not overlap or leave gaps");
+            end += len;
+        }
+    }
+
+    Ok(())
 }
@@ -9,7 +9,10 @@ use alloc::{vec, vec::Vec};
 #[cfg(feature = "multicore")]
 use std::sync::mpsc;
 
-use ragu_arithmetic::{Coeff, ff::Field};
+use ragu_arithmetic::{
+    Coeff,
+    ff::{Field, PrimeField},
+};
 use ragu_core::{
     Error, Result,
     convert::{CloneWires, StripWires, WireMap},
@@ -69,6 +72,8 @@ impl<F: Field> AnnotatedSegment<F> {
 ///
 /// Pass to [`Registry::assemble`](crate::registry::Registry::assemble)
 /// to obtain the corresponding [`sparse::Polynomial`].
+/// It can be encoded with [`Trace::to_bytes`], so that witness generation and
+/// proving can happen in different processes.
 pub struct Trace<F> {
     /// Gate groups in DFS order. Segment 0 is the root segment;
     /// segments 1+ are created by [`Driver::routine`] calls.
@@ -88,11 +93,22 @@ impl<F: Field> Trace<F> {
         floor_plan: &[ConstraintSegment],
         alpha: F,
     ) -> Result<sparse::Polynomial<F, R>> {
-        assert_eq!(
-            floor_plan.len(),
-            self.segments.len(),
-            "floor plan and trace must have the same number of segment entries"
-        );
+        // Traces may be deserialized, so a trace of some other circuit is an
+        // error rather than a bug.
+        if floor_plan.len() != self.segments.len() {
+            return Err(Error::VectorLengthMismatch {
+                expected: floor_plan.len(),
+                actual: self.segments.len(),
+            });
+        }
+        for (seg, segment) in self.segments.iter().zip(floor_plan) {
+            if seg.a.len() != segment.num_gates {
+                return Err(Error::VectorLengthMismatch {
+                    expected: segment.num_gates,
+                    actual: seg.a.len(),
+                });
+            }
+        }
         assert_eq!(
             floor_plan[0].gate_start, 0,
             "root segment must be placed at the polynomial origin"
@@ -120,15 +136,6 @@ impl<F: Field> Trace<F> {
         // Scatter each segment to its floor-plan position.
         for (seg_idx, seg) in self.segments.iter().enumerate() {
             let segment = &floor_plan[seg_idx];
-
-            // Verify segment size matches floor plan expectation.
-            assert_eq!(
-                seg.a.len(),
-                segment.num_gates,
-                "segment {} size must match floor plan",
-                seg_idx
-            );
-
             let offset = segment.gate_start;
             view.a[offset..offset + seg.a.len()].copy_from_slice(&seg.a);
             view.b[offset..offset + seg.b.len()].copy_from_slice(&seg.b);
@@ -146,6 +153,91 @@ impl<F: Field> Trace<F> {
     }
 }
 
+/// Magic bytes that begin an encoded [`Trace`].
+const TRACE_MAGIC: &[u8; 8] = b"ragutrce";
+
+impl<F: PrimeField> Trace<F> {
+    /// Encodes this trace so that it can be persisted or sent to another
+    /// process, and later decoded with [`Trace::from_bytes`].
+    ///
+    /// The encoding is the magic bytes `ragutrce`, the number of segments,
+    /// then for each segment its number of gates followed by the $(a, b, c,
+    /// d)$ values of each gate. Counts are little-endian `u32`s and values use
+    /// [`PrimeField::to_repr`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = TRACE_MAGIC.to_vec();
+        bytes.extend((self.segments.len() as u32).to_le_bytes());
+        for seg in &self.segments {
+            bytes.extend((seg.a.len() as u32).to_le_bytes());
+            for i in 0..seg.a.len() {
+                for value in [seg.a[i], seg.b[i], seg.c[i], seg.d[i]] {
+                    bytes.extend(value.to_repr().as_ref());
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a trace encoded by [`Trace::to_bytes`].
+    ///
+    /// Returns [`Error::MalformedEncoding`] if `bytes` is not such an
+    /// encoding. Whether the trace fits the circuit it is assembled for is
+    /// only checked by
+    /// [`Registry::assemble`](crate::registry::Registry::assemble).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let malformed = |msg: &'static str| Error::MalformedEncoding(msg.into());
+        let mut rest = bytes;
+        let mut take = |len: usize| -> Result<&[u8]> {
+            if rest.len() < len {
+                return Err(malformed("trace is truncated"));
+            }
+            let (taken, remaining) = rest.split_at(len);
+            rest = remaining;
+            Ok(taken)
+        };
+
+        if take(TRACE_MAGIC.len())? != TRACE_MAGIC {
+            return Err(malformed("not an encoding of a trace"));
+        }
+
+        let repr_len = F::Repr::default().as_ref().len();
+        let mut segments = Vec::new();
+        for _ in 0..read_u32(&mut take)? {
+            let mut seg = Segment {
+                a: Vec::new(),
+                b: Vec::new(),
+                c: Vec::new(),
+                d: Vec::new(),
+            };
+            for _ in 0..read_u32(&mut take)? {
+                for wires in [&mut seg.a, &mut seg.b, &mut seg.c, &mut seg.d] {
+                    let mut repr = F::Repr::default();
+                    repr.as_mut().copy_from_slice(take(repr_len)?);
+                    let value = Option::from(F::from_repr(repr))
+                        .ok_or_else(|| malformed("non-canonical field element in trace"))?;
+                    wires.push(value);
+                }
+            }
+            segments.push(seg);
+        }
+
+        if !rest.is_empty() {
+            return Err(malformed("unexpected trailing bytes after trace"));
+        }
+        if segments.first().is_none_or(|root| root.a.is_empty()) {
+            return Err(malformed("trace has no root segment"));
+        }
+
+        Ok(Trace { segments })
+    }
+}
+
+fn read_u32<'a>(take: &mut impl FnMut(usize) -> Result<&'a [u8]>) -> Result<u32> {
+    Ok(u32::from_le_bytes(
+        take(4)?.try_into().expect("took 4 bytes"),
+    ))
+}
+
 /// Per-routine state that is saved and restored by [`DriverScope`].
 struct TraceScope {
     /// Index of the segment that receives new gates.
@@ -433,6 +525,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_trace_bytes() {
+        let circuit = SquareCircuit { times: 10 };
+        let trace = eval::<Fp, _>(&circuit, Fp::from(3)).unwrap().into_output();
+        let bytes = trace.to_bytes();
+
+        let decoded = Trace::<Fp>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes(), bytes);
+
+        let malformed = |bytes: &[u8]| {
+            matches!(
+                Trace::<Fp>::from_bytes(bytes),
+                Err(Error::MalformedEncoding(_))
+            )
+        };
+        assert!(malformed(&bytes[..bytes.len() - 1]));
+        assert!(malformed(&[&bytes[..], &[0]].concat()));
+        assert!(malformed(&bytes[1..]));
+        assert!(malformed(&[&TRACE_MAGIC[..], &0u32.to_le_bytes()].concat()));
+    }
+
     /// Gadget whose [`Write`](ragu_primitives::io::Write) impl calls `dr.mul()`
     /// and `dr.enforce_zero()` during serialization, proving that `io.write()`
     /// in [`eval`] threads the driver to `write_gadget`.
@@ -0,0 +1,516 @@
+//! Memoization of leaf routine contributions to $s(X, Y)$.
+//!
+//! A routine invocation's contribution to $s(X, Y)$ depends on three things:
+//! its internal structure, its placement in the floor plan, and the wires its
+//! caller passed in. Two invocations with the same [`RoutineFingerprint`]
+//! share the first, so for a fixed $x$ the only differences between them are
+//! linear in the remaining two. This module captures that structure once
+//! per distinct routine and replays it for every later invocation.
+//!
+//! # Linear forms
+//!
+//! The [`Recorder`] driver synthesizes a routine as if it were placed at gate
+//! offset zero, representing every wire as a [`Form`]:
+//!
+//! $$
+//! w = p \cdot x^{m} + q \cdot x^{-m} + k + \sum\_{t} r\_{t} \cdot \mathsf{in}\_{t}
+//! $$
+//!
+//! where $m$ is the gate offset of the invocation being replayed. The $a$ and
+//! $d$ wire monomials ($x^{2n + i}$ and $x^{i}$) grow with the gate index, so
+//! they land in $p$; the $b$ and $c$ monomials ($x^{2n - 1 - i}$ and
+//! $x^{4n - 1 - i}$) shrink, so they land in $q$. The `ONE` wire lands in
+//! $k$, and each caller input wire $\mathsf{in}\_{t}$ is kept symbolic.
+//!
+//! Replaying a recording at a concrete [`Placement`] is then a handful of
+//! multiply-adds per constraint, rather than a full synthesis pass. Because
+//! field arithmetic is exact, a replayed contribution is identical to the one
+//! obtained by synthesizing the routine in place.
+//!
+//! # Eligibility
+//!
+//! Only *leaf* segments (routine invocations that call no further routines)
+//! are memoized; the [`floor_plan`] marks them with a
+//! [`memo_key`](crate::floor_planner::ConstraintSegment::memo_key). A nested
+//! routine call would jump to an unrelated floor plan offset that cannot be
+//! expressed as a translation of its parent's placement.
+//!
+//!
+//! # Sharing
+//!
+//! Recordings are cheap to clone and [`Send`], so that the `multicore`
+//! evaluators can hand the recordings made while synthesizing a deferred
+//! routine to the job that evaluates it, instead of recording its leaf
+//! routines again.
+//!
+//! [`RoutineFingerprint`]: crate::metrics::RoutineFingerprint
+//! [`floor_plan`]: crate::floor_planner::floor_plan
+
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+use core::{any::Any, marker::PhantomData};
+
+use ragu_arithmetic::{Coeff, ff::Field};
+use ragu_core::{
+    Error, Result,
+    convert::WireMap,
+    drivers::{Driver, DriverTypes, LinearExpression, emulator::Emulator},
+    gadgets::{Bound, GadgetKind},
+    maybe::Empty,
+    routines::Routine,
+};
+
+use ragu_primitives::Sendable;
+
+use crate::polynomials::Rank;
+
+/// The evaluation point and base monomials an evaluator was configured with.
+///
+/// These are the same values the evaluators use to jump to a routine's
+/// starting monomials; see [`super::sx`] for their definitions.
+pub(crate) struct Monomials<F> {
+    /// The evaluation point $x$.
+    pub(crate) x: F,
+    /// The inverse $x^{-1}$, or zero if $x = 0$.
+    pub(crate) x_inv: F,
+    /// Base monomial $x^{2n}$.
+    pub(crate) base_a_x: F,
+    /// Base monomial $x^{2n-1}$.
+    pub(crate) base_b_x: F,
+    /// Base monomial $x^{4n-1}$.
+    pub(crate) base_c_x: F,
+    /// Correction factor $x^{-2n}$ from an $a$-wire to a $d$-wire monomial.
+    pub(crate) a_to_d: F,
+}
+
+/// The position of one routine invocation, together with the values of the
+/// caller wires passed as its input.
+pub(crate) struct Placement<F> {
+    /// $x^{m}$ for the invocation's gate offset $m$.
+    pub(crate) x_pow: F,
+    /// $x^{-m}$ for the invocation's gate offset $m$.
+    pub(crate) x_inv_pow: F,
+    /// Input wire values, in [`GadgetKind::map_gadget`] order.
+    pub(crate) inputs: Vec<F>,
+}
+
+/// A wire (or linear combination of wires) expressed as a linear form in the
+/// placement of a routine invocation; see the [module
+/// documentation][`self`].
+#[derive(Clone)]
+pub(crate) struct Form<F> {
+    /// Coefficient of $x^{m}$.
+    plus: F,
+    /// Coefficient of $x^{-m}$.
+    minus: F,
+    /// Constant term, contributed by the `ONE` wire.
+    one: F,
+    /// Coefficients of the caller's input wires. Trailing zeros are omitted.
+    inputs: Vec<F>,
+}
+
+impl<F: Field> Form<F> {
+    const ZERO: Self = Form {
+        plus: F::ZERO,
+        minus: F::ZERO,
+        one: F::ZERO,
+        inputs: Vec::new(),
+    };
+
+    fn plus(plus: F) -> Self {
+        Form { plus, ..Self::ZERO }
+    }
+
+    fn minus(minus: F) -> Self {
+        Form {
+            minus,
+            ..Self::ZERO
+        }
+    }
+
+    fn input(index: usize) -> Self {
+        let mut inputs = vec![F::ZERO; index + 1];
+        inputs[index] = F::ONE;
+        Form {
+            inputs,
+            ..Self::ZERO
+        }
+    }
+
+    /// Computes `self += other * coeff`.
+    fn add_scaled(&mut self, other: &Self, coeff: F) {
+        self.plus += other.plus * coeff;
+        self.minus += other.minus * coeff;
+        self.one += other.one * coeff;
+        if self.inputs.len() < other.inputs.len() {
+            self.inputs.resize(other.inputs.len(), F::ZERO);
+        }
+        for (term, other) in self.inputs.iter_mut().zip(&other.inputs) {
+            *term += *other * coeff;
+        }
+    }
+
+    /// Computes `self *= factor`.
+    fn scale(&mut self, factor: F) {
+        self.plus *= factor;
+        self.minus *= factor;
+        self.one *= factor;
+        for term in &mut self.inputs {
+            *term *= factor;
+        }
+    }
+
+    /// Evaluates this form at a concrete placement.
+    pub(crate) fn eval(&self, placement: &Placement<F>) -> F {
+        let mut value = self.plus * placement.x_pow + self.minus * placement.x_inv_pow + self.one;
+        for (term, input) in self.inputs.iter().zip(&placement.inputs) {
+            value += *term * input;
+        }
+        value
+    }
+}
+
+/// A [`LinearExpression`] over [`Form`]s, analogous to
+/// [`DirectSum`](ragu_core::drivers::DirectSum).
+pub(crate) struct FormSum<F: Field> {
+    value: Form<F>,
+    gain: Coeff<F>,
+}
+
+impl<F: Field> Default for FormSum<F> {
+    fn default() -> Self {
+        FormSum {
+            value: Form::ZERO,
+            gain: Coeff::One,
+        }
+    }
+}
+
+impl<F: Field> LinearExpression<Form<F>, F> for FormSum<F> {
+    fn add_term(mut self, wire: &Form<F>, coeff: Coeff<F>) -> Self {
+        match coeff * self.gain {
+            Coeff::Zero => {}
+            coeff => self.value.add_scaled(wire, coeff.value()),
+        }
+        self
+    }
+
+    fn gain(mut self, coeff: Coeff<F>) -> Self {
+        self.gain = self.gain * coeff;
+        self
+    }
+}
+
+/// A [`Driver`] that records a routine's constraints as [`Form`]s.
+///
+/// Gates are allocated as if the routine were placed at gate offset zero; the
+/// offset of each replayed invocation is applied by [`Form::eval`].
+pub(crate) struct Recorder<F: Field> {
+    /// Running monomial for $a$ wires, relative to the routine's first gate.
+    current_a_x: F,
+    /// Running monomial for $b$ wires, relative to the routine's first gate.
+    current_b_x: F,
+    /// Running monomial for $c$ wires, relative to the routine's first gate.
+    current_c_x: F,
+    x: F,
+    x_inv: F,
+    a_to_d: F,
+    /// Number of gates allocated so far.
+    gates: usize,
+    /// Recorded constraints, in synthesis order.
+    constraints: Vec<Form<F>>,
+}
+
+impl<F: Field> DriverTypes for Recorder<F> {
+    type MaybeKind = Empty;
+    type LCadd = FormSum<F>;
+    type LCenforce = FormSum<F>;
+    type ImplField = F;
+    type ImplWire = Form<F>;
+    type Extra = F;
+
+    fn gate(
+        &mut self,
+        _: impl Fn() -> Result<(Coeff<F>, Coeff<F>, Coeff<F>)>,
+    ) -> Result<(Form<F>, Form<F>, Form<F>, F)> {
+        self.gates += 1;
+
+        let a = self.current_a_x;
+        let b = self.current_b_x;
+        let c = self.current_c_x;
+
+        self.current_a_x *= self.x;
+        self.current_b_x *= self.x_inv;
+        self.current_c_x *= self.x_inv;
+
+        Ok((Form::plus(a), Form::minus(b), Form::minus(c), a))
+    }
+
+    fn assign_extra(&mut self, a: F, _: impl Fn() -> Result<Coeff<F>>) -> Result<Form<F>> {
+        Ok(Form::plus(a * self.a_to_d))
+    }
+}
+
+impl<'dr, F: Field> Driver<'dr> for Recorder<F> {
+    type F = F;
+    type Wire = Form<F>;
+
+    const ONE: Self::Wire = Form {
+        plus: F::ZERO,
+        minus: F::ZERO,
+        one: F::ONE,
+        inputs: Vec::new(),
+    };
+
+    fn add(&mut self, lc: impl Fn(Self::LCadd) -> Self::LCadd) -> Self::Wire {
+        lc(FormSum::default()).value
+    }
+
+    fn enforce_zero(&mut self, lc: impl Fn(Self::LCenforce) -> Self::LCenforce) -> Result<()> {
+        self.constraints.push(lc(FormSum::default()).value);
+        Ok(())
+    }
+
+    // Only leaf routines are recorded, so nested routine calls never reach
+    // this driver; the default inline execution is left in place.
+}
+
+/// Maps caller input wires to symbolic [`Form::input`]s, in order.
+struct InputForms<F, D> {
+    next: usize,
+    _marker: PhantomData<(F, D)>,
+}
+
+impl<F: Field, D: DriverTypes<ImplField = F>> WireMap<F> for InputForms<F, D> {
+    type Src = D;
+    type Dst = Recorder<F>;
+
+    fn convert_wire(&mut self, _: &D::ImplWire) -> Result<Form<F>> {
+        let form = Form::input(self.next);
+        self.next += 1;
+        Ok(form)
+    }
+}
+
+/// Collects the values of an evaluator's input wires, in the same order as
+/// [`InputForms`] assigns them.
+struct InputValues<F, D> {
+    values: Vec<F>,
+    _marker: PhantomData<D>,
+}
+
+impl<F: Field, D: DriverTypes<ImplField = F, ImplWire = F>> WireMap<F> for InputValues<F, D> {
+    type Src = D;
+    type Dst = PhantomData<F>;
+
+    fn convert_wire(&mut self, wire: &F) -> Result<()> {
+        self.values.push(*wire);
+        Ok(())
+    }
+}
+
+/// Evaluates recorded output [`Form`]s back into an evaluator's wires.
+struct Replay<'a, F, D> {
+    placement: &'a Placement<F>,
+    _marker: PhantomData<D>,
+}
+
+impl<F: Field, D: DriverTypes<ImplField = F, ImplWire = F>> WireMap<F> for Replay<'_, F, D> {
+    type Src = Recorder<F>;
+    type Dst = D;
+
+    fn convert_wire(&mut self, wire: &Form<F>) -> Result<F> {
+        Ok(wire.eval(self.placement))
+    }
+}
+
+/// A recorded output gadget, with its driver and kind erased so that the
+/// recordings of different routines can share one memo.
+pub(crate) struct Output {
+    /// The gadget, as a `Sendable<Bound<'static, D, K>>`.
+    gadget: Box<dyn Any + Send>,
+    /// Clones `gadget`, which must have the type it was created with.
+    clone: fn(&dyn Any) -> Box<dyn Any + Send>,
+}
+
+impl Output {
+    pub(crate) fn new<D, K>(gadget: Bound<'static, D, K>) -> Self
+    where
+        D: Driver<'static> + 'static,
+        D::Wire: Send,
+        K: GadgetKind<D::F>,
+    {
+        Output {
+            gadget: Box::new(Sendable::new::<D>(gadget)),
+            clone: |gadget| {
+                Box::new(
+                    gadget
+                        .downcast_ref::<Sendable<Bound<'static, D, K>>>()
+                        .expect("outputs are cloned as the type they were created with")
+                        .clone(),
+                )
+            },
+        }
+    }
+
+    /// Returns the gadget as a `Bound<'static, D, K>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gadget was created with a different driver or kind.
+    pub(crate) fn get<D, K>(&self) -> &Bound<'static, D, K>
+    where
+        D: Driver<'static> + 'static,
+        K: GadgetKind<D::F>,
+    {
+        self.gadget
+            .downcast_ref::<Sendable<Bound<'static, D, K>>>()
+            .expect("fingerprints with equal output kinds record equal output types")
+    }
+}
+
+impl Clone for Output {
+    fn clone(&self) -> Self {
+        Output {
+            gadget: (self.clone)(&*self.gadget),
+            clone: self.clone,
+        }
+    }
+}
+
+/// A routine's constraints and output, recorded once and replayed for every
+/// invocation that shares its fingerprint.
+#[derive(Clone)]
+pub(crate) struct Recording<F> {
+    /// The routine's constraints, in synthesis order.
+    pub(crate) constraints: Arc<[Form<F>]>,
+    /// The routine's output gadget, on a [`Recorder`].
+    output: Output,
+}
+
+impl<F: Field> Recording<F> {
+    /// Synthesizes `routine` on a [`Recorder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the routine does not emit exactly `num_gates` gates and
+    /// `num_constraints` constraints, as given by its floor plan segment.
+    pub(crate) fn record<'dr, D, Ro>(
+        routine: &Ro,
+        input: &Bound<'dr, D, Ro::Input>,
+        monomials: &Monomials<F>,
+        num_gates: usize,
+        num_constraints: usize,
+    ) -> Result<Self>
+    where
+        D: Driver<'dr, F = F>,
+        Ro: Routine<F>,
+    {
+        let mut recorder = Recorder {
+            current_a_x: monomials.base_a_x,
+            current_b_x: monomials.base_b_x,
+            current_c_x: monomials.base_c_x,
+            x: monomials.x,
+            x_inv: monomials.x_inv,
+            a_to_d: monomials.a_to_d,
+            gates: 0,
+            constraints: Vec::with_capacity(num_constraints),
+        };
+
+        let input: Bound<'static, Recorder<F>, Ro::Input> = Ro::Input::map_gadget(
+            input,
+            &mut InputForms {
+                next: 0,
+                _marker: PhantomData::<(F, D)>,
+            },
+        )?;
+        let aux = Emulator::predict(routine, &input)?.into_aux();
+        let output: Bound<'static, Recorder<F>, Ro::Output> =
+            routine.execute(&mut recorder, input, aux)?;
+
+        assert_eq!(
+            recorder.gates, num_gates,
+            "routine gate count must match floor plan"
+        );
+        assert_eq!(
+            recorder.constraints.len(),
+            num_constraints,
+            "routine constraint count must match floor plan"
+        );
+
+        Ok(Recording {
+            constraints: recorder.constraints.into(),
+            output: Output::new::<Recorder<F>, Ro::Output>(output),
+        })
+    }
+
+    /// Folds the recorded constraints with Horner's rule in $y$, as
+    /// [`sxy`](super::sxy) would while synthesizing the routine.
+    pub(crate) fn horner(&self, y: F) -> Form<F> {
+        let mut result = Form::ZERO;
+        for constraint in self.constraints.iter() {
+            result.scale(y);
+            result.add_scaled(constraint, F::ONE);
+        }
+        result
+    }
+
+    /// Rebuilds the recorded output gadget on the evaluator `D` at the given
+    /// placement.
+    pub(crate) fn output<'dr, D, K>(&self, placement: &Placement<F>) -> Result<Bound<'dr, D, K>>
+    where
+        D: Driver<'dr, F = F, Wire = F>,
+        K: GadgetKind<F>,
+    {
+        K::map_gadget(
+            self.output.get::<Recorder<F>, K>(),
+            &mut Replay {
+                placement,
+                _marker: PhantomData::<D>,
+            },
+        )
+    }
+}
+
+/// Computes the [`Placement`] of a routine invocation at gate offset
+/// `gate_start` with the given input.
+pub(crate) fn placement<'dr, F, D, K>(
+    monomials: &Monomials<F>,
+    gate_start: usize,
+    input: &Bound<'dr, D, K>,
+) -> Result<Placement<F>>
+where
+    F: Field,
+    D: Driver<'dr, F = F, Wire = F>,
+    K: GadgetKind<F>,
+{
+    let mut values = InputValues {
+        values: Vec::new(),
+        _marker: PhantomData::<D>,
+    };
+    K::map_gadget(input, &mut values)?;
+
+    Ok(Placement {
+        x_pow: monomials.x.pow_vartime([gate_start as u64]),
+        x_inv_pow: monomials.x_inv.pow_vartime([gate_start as u64]),
+        inputs: values.values,
+    })
+}
+
+/// Checks that a memoized segment fits within the rank's bounds, returning
+/// the same errors the evaluators raise when synthesizing in place.
+pub(crate) fn check_bounds<R: Rank>(
+    gate_start: usize,
+    num_gates: usize,
+    constraint_start: usize,
+    num_constraints: usize,
+) -> Result<()> {
+    if gate_start + num_gates > R::n() {
+        return Err(Error::GateBoundExceeded { limit: R::n() });
+    }
+    if constraint_start + num_constraints > R::num_coeffs() - 1 {
+        return Err(Error::ConstraintBoundExceeded {
+            limit: R::num_coeffs() - 1,
+        });
+    }
+    Ok(())
+}
@@ -54,6 +54,11 @@
 //! inputs and to provide guarantees about those inputs that drivers can safely
 //! exploit to memoize.
 //!
+//! The [`sx`] and [`sxy`] evaluators use each leaf routine's
+//! [`RoutineFingerprint`] to detect structurally identical invocations,
+//! synthesizing each distinct routine once and translating its contribution
+//! to every other invocation's floor plan offset. See the `memo` module.
+//!
 //! ### Polynomial Encoding and Scope Jumps
 //!
 //! The [`floor_plan`] partitions the global constraint index space so that each
@@ -73,6 +78,23 @@
 //! routine for segment $i$, each evaluator jumps to $\ell\_{i}$ and restores
 //! the parent's offset on return.
 //!
+//! ### Parallelism
+//!
+//! These jumps also make each segment's contribution independent of the rest
+//! of synthesis, given the values of its input wires. With the `multicore`
+//! feature, the evaluators take advantage of this by deferring each routine
+//! invoked in the root scope of the circuit that is not memoized to a
+//! parallel job: the invocation is synthesized in place only for its output
+//! wires, skipping the evaluation of its constraints, and is then evaluated in
+//! full (including the routines nested in it) on a separate evaluator
+//! positioned at the same segment. Jobs do not defer further, so every routine
+//! is synthesized at most twice, and each job starts from the leaf routine
+//! recordings made while synthesizing its routine in place, so no leaf routine
+//! is recorded twice. Deferred jobs run in parallel once the enclosing
+//! synthesis completes, and their contributions are combined in a fixed
+//! order, so the result is identical to sequential evaluation; see [`sy`] for
+//! how it handles contributions to its callers' wires.
+//!
 //! # Overview
 //!
 //! This module provides implementations that interpret circuit code directly
@@ -85,10 +107,17 @@
 //!
 //! [`Driver`]: ragu_core::drivers::Driver
 //! [`Routine`]: ragu_core::routines::Routine
+//! [`RoutineFingerprint`]: crate::metrics::RoutineFingerprint
 //! [`enforce_zero`]: ragu_core::drivers::Driver::enforce_zero
 //! [`floor_plan`]: crate::floor_planner::floor_plan
 //! [wiring polynomials]: http://TODO
 
+mod memo;
 pub mod sx;
 pub mod sxy;
 pub mod sy;
+
+/// A deferred evaluation of a routine segment, run in parallel with the
+/// others once synthesis of the enclosing segment completes.
+#[cfg(feature = "multicore")]
+type Job<'fp, T> = alloc::boxed::Box<dyn FnOnce() -> ragu_core::Result<T> + Send + 'fp>;
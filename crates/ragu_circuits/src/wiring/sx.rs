@@ -67,14 +67,30 @@
 //! The registry key constraint is **not** included in these coefficients; it
 //! occupies the fixed $Y^{4n-1}$ slot and is injected at the registry level.
 //!
+//! ### Memoization
+//!
+//! Leaf routines that share a fingerprint (see the
+//! [`memo_key`](crate::floor_planner::ConstraintSegment::memo_key) in the floor
+//! plan) are synthesized only once per evaluation, on a recording driver that
+//! keeps each coefficient as a linear form in the invocation's gate offset and
+//! input wires. Later invocations write their coefficients by evaluating those
+//! forms at their own placement; see the [`memo`] module. The coefficients are
+//! written in synthesis order, so the per-routine reversal above applies
+//! unchanged.
+//!
 //! [`Driver`]: ragu_core::drivers::Driver
 //! [`Driver::add`]: ragu_core::drivers::Driver::add
 //! [`Driver::enforce_zero`]: ragu_core::drivers::Driver::enforce_zero
 //! [`Driver::mul`]: ragu_core::drivers::Driver::mul
 //! [`DriverTypes::gate`]: ragu_core::drivers::DriverTypes::gate
+//! [`memo`]: super::memo
 //! [`sxy`]: super::sxy
 
-use alloc::{vec, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, btree_map::Entry},
+    vec,
+    vec::Vec,
+};
 
 use ragu_arithmetic::{Coeff, ff::Field};
 use ragu_core::{
@@ -84,10 +100,16 @@ use ragu_core::{
     maybe::Empty,
     routines::Routine,
 };
+#[cfg(feature = "multicore")]
+use ragu_primitives::GadgetExt;
 
+#[cfg(feature = "multicore")]
+use super::Job;
+use super::memo::{self, Monomials, Recording};
 use crate::{
     DriverScope,
     floor_planner::ConstraintSegment,
+    metrics::RoutineFingerprint,
     polynomials::{Rank, sparse},
     raw::RawCircuit,
 };
@@ -108,6 +130,35 @@ struct SxScope<F> {
     constraints: usize,
 }
 
+/// Destination of the coefficients written by an [`Evaluator`].
+enum Coefficients<F> {
+    /// All coefficients of $s(x, Y)$, indexed by constraint.
+    Dense(Vec<F>),
+    /// Coefficients written by a deferred routine, as `(index, value)` pairs.
+    #[cfg(feature = "multicore")]
+    Sparse(Vec<(usize, F)>),
+}
+
+impl<F> Coefficients<F> {
+    /// Sets the coefficient of the `q`-th constraint.
+    fn set(&mut self, q: usize, value: F) {
+        match self {
+            Coefficients::Dense(coeffs) => coeffs[q] = value,
+            #[cfg(feature = "multicore")]
+            Coefficients::Sparse(coeffs) => coeffs.push((q, value)),
+        }
+    }
+
+    /// Returns the dense coefficients written by the root evaluator.
+    fn into_dense(self) -> Vec<F> {
+        match self {
+            Coefficients::Dense(coeffs) => coeffs,
+            #[cfg(feature = "multicore")]
+            Coefficients::Sparse(_) => unreachable!("the root evaluator writes dense coefficients"),
+        }
+    }
+}
+
 /// A [`Driver`] that computes the partial evaluation $s(x, Y)$.
 ///
 /// Given a fixed evaluation point $x \in \mathbb{F}$, this driver interprets
@@ -123,7 +174,7 @@ struct Evaluator<'fp, F: Field, R: Rank> {
     /// Each [`enforce_zero`](Driver::enforce_zero) call appends one
     /// coefficient. The vector is reversed at the end of [`eval`] to produce
     /// the canonical order.
-    result: Vec<F>,
+    result: Coefficients<F>,
 
     /// Per-routine scoped state.
     scope: SxScope<F>,
@@ -157,10 +208,211 @@ struct Evaluator<'fp, F: Field, R: Rank> {
     /// Global monotonic DFS counter for routine entries.
     current_routine: usize,
 
+    /// Recorded leaf routines, keyed by fingerprint.
+    memo: BTreeMap<RoutineFingerprint, Recording<F>>,
+
+    /// Whether the current routine's coefficients are being written by a
+    /// deferred job, so that only its output wires are needed here.
+    outputs_only: bool,
+
+    /// Whether routines invoked in this evaluator's root scope are deferred
+    /// to parallel jobs. Only the evaluator of the whole circuit defers, and
+    /// the routines nested in a deferred one are synthesized in place by its
+    /// job, so every routine is synthesized at most twice.
+    #[cfg(feature = "multicore")]
+    defers: bool,
+
+    /// Deferred routine evaluations, each yielding the coefficients it wrote.
+    #[cfg(feature = "multicore")]
+    jobs: Vec<Job<'fp, Vec<(usize, F)>>>,
+
     /// Marker for the rank type parameter.
     _marker: core::marker::PhantomData<R>,
 }
 
+impl<'fp, F: Field, R: Rank> Evaluator<'fp, F, R> {
+    fn new(
+        result: Coefficients<F>,
+        monomials: Monomials<F>,
+        floor_plan: &'fp [ConstraintSegment],
+    ) -> Self {
+        Evaluator {
+            result,
+            scope: SxScope {
+                current_a_x: monomials.base_a_x,
+                current_b_x: monomials.base_b_x,
+                current_c_x: monomials.base_c_x,
+                gates: 0,
+                constraints: 0,
+            },
+            x: monomials.x,
+            x_inv: monomials.x_inv,
+            base_a_x: monomials.base_a_x,
+            base_b_x: monomials.base_b_x,
+            base_c_x: monomials.base_c_x,
+            a_to_d: monomials.a_to_d,
+            floor_plan,
+            current_routine: 0,
+            memo: BTreeMap::new(),
+            outputs_only: false,
+            #[cfg(feature = "multicore")]
+            defers: false,
+            #[cfg(feature = "multicore")]
+            jobs: Vec::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    fn monomials(&self) -> Monomials<F> {
+        Monomials {
+            x: self.x,
+            x_inv: self.x_inv,
+            base_a_x: self.base_a_x,
+            base_b_x: self.base_b_x,
+            base_c_x: self.base_c_x,
+            a_to_d: self.a_to_d,
+        }
+    }
+
+    /// Returns this evaluator's coefficients, including those written by all
+    /// deferred jobs.
+    fn finish(self) -> Result<Coefficients<F>> {
+        let result = self.result;
+
+        #[cfg(feature = "multicore")]
+        let result = {
+            use maybe_rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+            let deferred = self
+                .jobs
+                .into_par_iter()
+                .map(|job| job())
+                .collect::<Result<Vec<_>>>()?;
+            let mut result = result;
+            for (q, value) in deferred.into_iter().flatten() {
+                result.set(q, value);
+            }
+            result
+        };
+
+        Ok(result)
+    }
+
+    /// Evaluates a routine invocation in place at its floor plan segment.
+    fn synthesize<Ro: Routine<F> + 'fp>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        self.current_routine += 1;
+        let seg = &self.floor_plan[self.current_routine];
+
+        // Leaf routines seen before are replayed from their recording,
+        // translated to this invocation's placement.
+        if let Some(key) = seg.memo_key {
+            memo::check_bounds::<R>(
+                seg.gate_start,
+                seg.num_gates,
+                seg.constraint_start,
+                seg.num_constraints,
+            )?;
+            let monomials = self.monomials();
+            if let Entry::Vacant(entry) = self.memo.entry(key) {
+                entry.insert(Recording::record::<Self, Ro>(
+                    &routine,
+                    &input,
+                    &monomials,
+                    seg.num_gates,
+                    seg.num_constraints,
+                )?);
+            }
+            let recording = &self.memo[&key];
+            let placement =
+                memo::placement::<_, Self, Ro::Input>(&monomials, seg.gate_start, &input)?;
+
+            if !self.outputs_only {
+                for (q, constraint) in (seg.constraint_start..).zip(recording.constraints.iter()) {
+                    self.result.set(q, constraint.eval(&placement));
+                }
+            }
+
+            return recording.output::<Self, Ro::Output>(&placement);
+        }
+
+        // Jump to this routine's absolute position in the polynomial;
+        // see "Polynomial Encoding and Scope Jumps" in the `s` module doc.
+        let x_pow = self.x.pow_vartime([seg.gate_start as u64]);
+        let x_inv_pow = self.x_inv.pow_vartime([seg.gate_start as u64]);
+        let init_scope = SxScope {
+            current_a_x: self.base_a_x * x_pow,
+            current_b_x: self.base_b_x * x_inv_pow,
+            current_c_x: self.base_c_x * x_inv_pow,
+            gates: seg.gate_start,
+            constraints: seg.constraint_start,
+        };
+
+        self.with_scope(init_scope, |this| {
+            let aux = Emulator::predict(&routine, &input)?.into_aux();
+            let result = routine.execute(this, input, aux)?;
+
+            // Verify this routine consumed exactly the expected constraints.
+            assert_eq!(
+                this.scope.gates,
+                seg.gate_start + seg.num_gates,
+                "routine gate count must match floor plan"
+            );
+            assert_eq!(
+                this.scope.constraints,
+                seg.constraint_start + seg.num_constraints,
+                "routine constraint count must match floor plan"
+            );
+
+            Ok(result)
+        })
+    }
+
+    /// Defers the evaluation of a routine invocation to a parallel job,
+    /// synthesizing it here only for its output wires.
+    ///
+    /// The job replays the invocation on a fresh evaluator positioned at the
+    /// same floor plan segment, so it writes exactly the coefficients that
+    /// [`synthesize`](Self::synthesize) would have written in place. The
+    /// synthesis here records every leaf routine the invocation reaches, so
+    /// the job starts from a copy of this evaluator's memo and replays them
+    /// without recording them again.
+    #[cfg(feature = "multicore")]
+    fn defer<Ro: Routine<F> + 'fp>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        let monomials = self.monomials();
+        let floor_plan = self.floor_plan;
+        let current_routine = self.current_routine;
+        let job_routine = routine.clone();
+        let job_input = input.clone().sendable();
+
+        self.outputs_only = true;
+        let output = self.synthesize(routine, input);
+        self.outputs_only = false;
+
+        let memo = self.memo.clone();
+        self.jobs.push(alloc::boxed::Box::new(move || {
+            let mut worker =
+                Evaluator::<F, R>::new(Coefficients::Sparse(Vec::new()), monomials, floor_plan);
+            worker.current_routine = current_routine;
+            worker.memo = memo;
+            worker.synthesize(job_routine, job_input.into_inner())?;
+            match worker.finish()? {
+                Coefficients::Sparse(coeffs) => Ok(coeffs),
+                Coefficients::Dense(_) => unreachable!("workers write sparse coefficients"),
+            }
+        }));
+
+        output
+    }
+}
+
 impl<F: Field, R: Rank> DriverScope<SxScope<F>> for Evaluator<'_, F, R> {
     fn scope(&mut self) -> &mut SxScope<F> {
         &mut self.scope
@@ -226,7 +478,7 @@ impl<F: Field, R: Rank> DriverTypes for Evaluator<'_, F, R> {
     }
 }
 
-impl<'dr, F: Field, R: Rank> Driver<'dr> for Evaluator<'_, F, R> {
+impl<'fp, F: Field, R: Rank> Driver<'fp> for Evaluator<'fp, F, R> {
     type F = F;
     type Wire = F;
 
@@ -261,49 +513,32 @@ impl<'dr, F: Field, R: Rank> Driver<'dr> for Evaluator<'_, F, R> {
         }
         self.scope.constraints += 1;
 
-        self.result[q] = lc(DirectSum::default()).value();
+        if !self.outputs_only {
+            self.result.set(q, lc(DirectSum::default()).value());
+        }
 
         Ok(())
     }
 
-    fn routine<Ro: Routine<Self::F> + 'dr>(
+    /// Evaluates a routine invocation at its floor plan segment.
+    ///
+    /// With the `multicore` feature, routines invoked in the root scope of the
+    /// circuit that cannot be memoized are deferred to a parallel job
+    /// instead; see [`eval`].
+    fn routine<Ro: Routine<Self::F> + 'fp>(
         &mut self,
         routine: Ro,
-        input: Bound<'dr, Self, Ro::Input>,
-    ) -> Result<Bound<'dr, Self, Ro::Output>> {
-        self.current_routine += 1;
-        let seg = &self.floor_plan[self.current_routine];
-
-        // Jump to this routine's absolute position in the polynomial;
-        // see "Polynomial Encoding and Scope Jumps" in the `s` module doc.
-        let x_pow = self.x.pow_vartime([seg.gate_start as u64]);
-        let x_inv_pow = self.x_inv.pow_vartime([seg.gate_start as u64]);
-        let init_scope = SxScope {
-            current_a_x: self.base_a_x * x_pow,
-            current_b_x: self.base_b_x * x_inv_pow,
-            current_c_x: self.base_c_x * x_inv_pow,
-            gates: seg.gate_start,
-            constraints: seg.constraint_start,
-        };
-
-        self.with_scope(init_scope, |this| {
-            let aux = Emulator::predict(&routine, &input)?.into_aux();
-            let result = routine.execute(this, input, aux)?;
-
-            // Verify this routine consumed exactly the expected constraints.
-            assert_eq!(
-                this.scope.gates,
-                seg.gate_start + seg.num_gates,
-                "routine gate count must match floor plan"
-            );
-            assert_eq!(
-                this.scope.constraints,
-                seg.constraint_start + seg.num_constraints,
-                "routine constraint count must match floor plan"
-            );
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        #[cfg(feature = "multicore")]
+        if self.defers
+            && !self.outputs_only
+            && self.floor_plan[self.current_routine + 1].memo_key.is_none()
+        {
+            return self.defer(routine, input);
+        }
 
-            Ok(result)
-        })
+        self.synthesize(routine, input)
     }
 }
 
@@ -324,6 +559,17 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
     circuit: &RC,
     x: F,
     floor_plan: &[ConstraintSegment],
+) -> Result<sparse::Polynomial<F, R>> {
+    eval_with(circuit, x, floor_plan, true)
+}
+
+/// Evaluates $s(x, Y)$ like [`eval`], but with the `multicore` feature defers
+/// routines to parallel jobs only if `defer` is set.
+pub(crate) fn eval_with<F: Field, RC: RawCircuit<F>, R: Rank>(
+    circuit: &RC,
+    x: F,
+    floor_plan: &[ConstraintSegment],
+    #[cfg_attr(not(feature = "multicore"), allow(unused_variables))] defer: bool,
 ) -> Result<sparse::Polynomial<F, R>> {
     // At x = 0 every monomial other than x^0 vanishes; the d[0] ONE wire
     // (at x^0) still contributes F::ONE. Set x_inv = 0 so the running
@@ -342,28 +588,26 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
     let xn_inv = x_inv.pow_vartime([R::n() as u64]);
     let base_a_x_inv = xn_inv.square();
 
-    let mut evaluator = Evaluator::<F, R> {
+    let mut evaluator = Evaluator::<F, R>::new(
         // Zero-initialized: the evaluator fills specific indices during
         // synthesis. Unfilled indices must remain zero as they represent
         // unused wire slots.
-        result: vec![F::ZERO; R::num_coeffs()],
-        scope: SxScope {
-            current_a_x: base_a_x,
-            current_b_x: base_b_x,
-            current_c_x: base_c_x,
-            gates: 0,
-            constraints: 0,
+        Coefficients::Dense(vec![F::ZERO; R::num_coeffs()]),
+        Monomials {
+            x,
+            x_inv,
+            base_a_x,
+            base_b_x,
+            base_c_x,
+            a_to_d: base_a_x_inv,
         },
-        x,
-        x_inv,
-        base_a_x,
-        base_b_x,
-        base_c_x,
-        a_to_d: base_a_x_inv,
         floor_plan,
-        current_routine: 0,
-        _marker: core::marker::PhantomData,
-    };
+    );
+
+    #[cfg(feature = "multicore")]
+    {
+        evaluator.defers = defer;
+    }
 
     crate::raw::orchestrate(&mut evaluator, circuit, Empty)?;
 
@@ -382,13 +626,15 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
         "root constraint count must match floor plan"
     );
 
+    let floor_plan = evaluator.floor_plan;
+    let mut result = evaluator.finish()?.into_dense();
+
     // Reverse to canonical coefficient order within each routine's constraint
     // range.
-    for seg in evaluator.floor_plan {
-        evaluator.result[seg.constraint_start..seg.constraint_start + seg.num_constraints]
-            .reverse();
+    for seg in floor_plan {
+        result[seg.constraint_start..seg.constraint_start + seg.num_constraints].reverse();
     }
-    assert_eq!(evaluator.result[0], F::ONE);
+    assert_eq!(result[0], F::ONE);
 
-    Ok(sparse::Polynomial::from_coeffs(evaluator.result))
+    Ok(sparse::Polynomial::from_coeffs(result))
 }
@@ -35,18 +35,25 @@
 //! constraints), this module maintains only a single field element
 //! accumulator.
 //!
-//! ### Memoization Eligibility
+//! ### Memoization
 //!
-//! Because [`sxy`](self) produces a single scalar result rather than a polynomial,
-//! routine memoization can cache these scalar values directly. When the same
-//! routine executes with related inputs across multiple evaluations, cached
-//! results may be reused or transformed with simple linear operations. See
-//! [issue #58](https://github.com/tachyon-zcash/ragu/issues/58) for the planned
+//! Because [`sxy`](self) produces a single scalar result rather than a
+//! polynomial, a routine's entire contribution collapses to one Horner
+//! accumulator. For each distinct leaf routine (keyed by the
+//! [`memo_key`](crate::floor_planner::ConstraintSegment::memo_key) in the floor
+//! plan), the evaluator records that accumulator once as a linear form in the
+//! invocation's gate offset and input wires (see the [`memo`] module). Every
+//! later invocation with the same fingerprint is then evaluated by
+//! substituting its own placement, without re-running synthesis. See
+//! [issue #58](https://github.com/tachyon-zcash/ragu/issues/58) for the broader
 //! multi-dimensional memoization strategy.
 //!
+//! [`memo`]: super::memo
 //! [`sx`]: super::sx
 //! [`Driver::enforce_zero`]: ragu_core::drivers::Driver::enforce_zero
 
+use alloc::collections::{BTreeMap, btree_map::Entry};
+
 use ragu_arithmetic::{Coeff, ff::Field};
 use ragu_core::{
     Error, Result,
@@ -55,8 +62,16 @@ use ragu_core::{
     maybe::Empty,
     routines::Routine,
 };
-
-use crate::{DriverScope, floor_planner::ConstraintSegment, polynomials::Rank, raw::RawCircuit};
+#[cfg(feature = "multicore")]
+use ragu_primitives::GadgetExt;
+
+#[cfg(feature = "multicore")]
+use super::Job;
+use super::memo::{self, Form, Monomials, Recording};
+use crate::{
+    DriverScope, floor_planner::ConstraintSegment, metrics::RoutineFingerprint, polynomials::Rank,
+    raw::RawCircuit,
+};
 
 /// Per-routine state saved and restored across routine boundaries.
 struct SxyScope<F> {
@@ -127,10 +142,219 @@ struct Evaluator<'fp, F, R> {
     /// Global monotonic DFS counter for routine entries.
     current_routine: usize,
 
+    /// Recorded leaf routines, keyed by fingerprint, together with the Horner
+    /// fold of their constraints.
+    memo: BTreeMap<RoutineFingerprint, (Form<F>, Recording<F>)>,
+
+    /// Whether the current routine's constraints are being evaluated by a
+    /// deferred [`Job`](super::Job), so that only its output wires are
+    /// needed here.
+    outputs_only: bool,
+
+    /// Whether routines invoked in this evaluator's root scope are deferred
+    /// to parallel jobs. Only the evaluator of the whole circuit defers, and
+    /// the routines nested in a deferred one are synthesized in place by its
+    /// job, so every routine is synthesized at most twice.
+    #[cfg(feature = "multicore")]
+    defers: bool,
+
+    /// Deferred routine evaluations, each yielding its contribution to
+    /// $s(x, y)$.
+    #[cfg(feature = "multicore")]
+    jobs: alloc::vec::Vec<Job<'fp, F>>,
+
     /// Marker for the rank type parameter.
     _marker: core::marker::PhantomData<R>,
 }
 
+impl<'fp, F: Field, R: Rank> Evaluator<'fp, F, R> {
+    fn new(monomials: Monomials<F>, y: F, floor_plan: &'fp [ConstraintSegment]) -> Self {
+        Evaluator {
+            scope: SxyScope {
+                current_a_x: monomials.base_a_x,
+                current_b_x: monomials.base_b_x,
+                current_c_x: monomials.base_c_x,
+                gates: 0,
+                constraints: 0,
+                result: F::ZERO,
+                sum: F::ZERO,
+            },
+            x: monomials.x,
+            x_inv: monomials.x_inv,
+            y,
+            base_a_x: monomials.base_a_x,
+            base_b_x: monomials.base_b_x,
+            base_c_x: monomials.base_c_x,
+            a_to_d: monomials.a_to_d,
+            floor_plan,
+            current_routine: 0,
+            memo: BTreeMap::new(),
+            outputs_only: false,
+            #[cfg(feature = "multicore")]
+            defers: false,
+            #[cfg(feature = "multicore")]
+            jobs: alloc::vec::Vec::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    fn monomials(&self) -> Monomials<F> {
+        Monomials {
+            x: self.x,
+            x_inv: self.x_inv,
+            base_a_x: self.base_a_x,
+            base_b_x: self.base_b_x,
+            base_c_x: self.base_c_x,
+            a_to_d: self.a_to_d,
+        }
+    }
+
+    /// Returns this evaluator's accumulated result, including the
+    /// contributions of all deferred jobs.
+    fn finish(self) -> Result<F> {
+        // The root's local Horner result plus any child contributions.
+        let result = self.scope.result + self.scope.sum;
+
+        #[cfg(feature = "multicore")]
+        let result = {
+            use maybe_rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+            let deferred = self
+                .jobs
+                .into_par_iter()
+                .map(|job| job())
+                .collect::<Result<alloc::vec::Vec<F>>>()?;
+            deferred.into_iter().fold(result, |acc, value| acc + value)
+        };
+
+        Ok(result)
+    }
+
+    /// Evaluates a routine invocation in place at its floor plan segment.
+    fn synthesize<Ro: Routine<F> + 'fp>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        self.current_routine += 1;
+        let seg = &self.floor_plan[self.current_routine];
+        let gate_start = seg.gate_start;
+        let constraint_start = seg.constraint_start;
+
+        // Leaf routines seen before are replayed from their recording,
+        // translated to this invocation's placement.
+        if let Some(key) = seg.memo_key {
+            memo::check_bounds::<R>(
+                gate_start,
+                seg.num_gates,
+                constraint_start,
+                seg.num_constraints,
+            )?;
+            let monomials = self.monomials();
+            if let Entry::Vacant(entry) = self.memo.entry(key) {
+                let recording = Recording::record::<Self, Ro>(
+                    &routine,
+                    &input,
+                    &monomials,
+                    seg.num_gates,
+                    seg.num_constraints,
+                )?;
+                entry.insert((recording.horner(self.y), recording));
+            }
+            let (horner, recording) = &self.memo[&key];
+            let placement = memo::placement::<_, Self, Ro::Input>(&monomials, gate_start, &input)?;
+
+            if !self.outputs_only {
+                let y_pow_constraint_start = self.y.pow_vartime([constraint_start as u64]);
+                self.scope.sum += y_pow_constraint_start * horner.eval(&placement);
+            }
+
+            return recording.output::<Self, Ro::Output>(&placement);
+        }
+
+        // Jump to this routine's absolute position in the polynomial;
+        // see "Polynomial Encoding and Scope Jumps" in the `s` module doc.
+        let x_pow = self.x.pow_vartime([gate_start as u64]);
+        let x_inv_pow = self.x_inv.pow_vartime([gate_start as u64]);
+        let init_scope = SxyScope {
+            current_a_x: self.base_a_x * x_pow,
+            current_b_x: self.base_b_x * x_inv_pow,
+            current_c_x: self.base_c_x * x_inv_pow,
+            gates: gate_start,
+            constraints: constraint_start,
+            result: F::ZERO,
+            sum: F::ZERO,
+        };
+
+        // Manual save/restore: we need to capture the routine's result
+        // before restoring parent state.
+        let saved = core::mem::replace(&mut self.scope, init_scope);
+        let exec_result = {
+            let aux = Emulator::predict(&routine, &input)?.into_aux();
+            routine.execute(self, input, aux)
+        };
+        // Verify this routine consumed exactly the expected constraints.
+        assert_eq!(
+            self.scope.gates,
+            seg.gate_start + seg.num_gates,
+            "routine gate count must match floor plan"
+        );
+        assert_eq!(
+            self.scope.constraints,
+            seg.constraint_start + seg.num_constraints,
+            "routine constraint count must match floor plan"
+        );
+
+        // Position the routine's local Horner result at its absolute Y offset,
+        // then combine with any nested child contributions.
+        let routine_contribution = if self.outputs_only {
+            F::ZERO
+        } else {
+            let y_pow_constraint_start = self.y.pow_vartime([constraint_start as u64]);
+            y_pow_constraint_start * self.scope.result + self.scope.sum
+        };
+        self.scope = saved;
+        self.scope.sum += routine_contribution;
+
+        exec_result
+    }
+
+    /// Defers the evaluation of a routine invocation to a parallel [`Job`],
+    /// synthesizing it here only for its output wires.
+    ///
+    /// The job replays the invocation on a fresh evaluator positioned at the
+    /// same floor plan segment, so it produces exactly the contribution that
+    /// [`synthesize`](Self::synthesize) would have added in place.
+    #[cfg(feature = "multicore")]
+    fn defer<Ro: Routine<F> + 'fp>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        let monomials = self.monomials();
+        let y = self.y;
+        let floor_plan = self.floor_plan;
+        let current_routine = self.current_routine;
+        let job_routine = routine.clone();
+        let job_input = input.clone().sendable();
+
+        self.outputs_only = true;
+        let output = self.synthesize(routine, input);
+        self.outputs_only = false;
+
+        let memo = self.memo.clone();
+        self.jobs.push(alloc::boxed::Box::new(move || {
+            let mut worker = Evaluator::<F, R>::new(monomials, y, floor_plan);
+            worker.current_routine = current_routine;
+            worker.memo = memo;
+            worker.synthesize(job_routine, job_input.into_inner())?;
+            worker.finish()
+        }));
+
+        output
+    }
+}
+
 impl<F: Field, R: Rank> DriverScope<SxyScope<F>> for Evaluator<'_, F, R> {
     fn scope(&mut self) -> &mut SxyScope<F> {
         &mut self.scope
@@ -196,7 +420,7 @@ impl<F: Field, R: Rank> DriverTypes for Evaluator<'_, F, R> {
     }
 }
 
-impl<'dr, F: Field, R: Rank> Driver<'dr> for Evaluator<'_, F, R> {
+impl<'fp, F: Field, R: Rank> Driver<'fp> for Evaluator<'fp, F, R> {
     type F = F;
     type Wire = F;
 
@@ -230,63 +454,33 @@ impl<'dr, F: Field, R: Rank> Driver<'dr> for Evaluator<'_, F, R> {
         }
         self.scope.constraints += 1;
 
-        self.scope.result *= self.y;
-        self.scope.result += lc(DirectSum::default()).value();
+        if !self.outputs_only {
+            self.scope.result *= self.y;
+            self.scope.result += lc(DirectSum::default()).value();
+        }
 
         Ok(())
     }
 
-    fn routine<Ro: Routine<Self::F> + 'dr>(
+    /// Evaluates a routine invocation at its floor plan segment.
+    ///
+    /// With the `multicore` feature, routines invoked in the root scope of the
+    /// circuit that cannot be memoized are deferred to a parallel job
+    /// instead; see [`eval`].
+    fn routine<Ro: Routine<Self::F> + 'fp>(
         &mut self,
         routine: Ro,
-        input: Bound<'dr, Self, Ro::Input>,
-    ) -> Result<Bound<'dr, Self, Ro::Output>> {
-        self.current_routine += 1;
-        let seg = &self.floor_plan[self.current_routine];
-        let gate_start = seg.gate_start;
-        let constraint_start = seg.constraint_start;
-
-        // Jump to this routine's absolute position in the polynomial;
-        // see "Polynomial Encoding and Scope Jumps" in the `s` module doc.
-        let x_pow = self.x.pow_vartime([gate_start as u64]);
-        let x_inv_pow = self.x_inv.pow_vartime([gate_start as u64]);
-        let init_scope = SxyScope {
-            current_a_x: self.base_a_x * x_pow,
-            current_b_x: self.base_b_x * x_inv_pow,
-            current_c_x: self.base_c_x * x_inv_pow,
-            gates: gate_start,
-            constraints: constraint_start,
-            result: F::ZERO,
-            sum: F::ZERO,
-        };
-
-        // Manual save/restore: we need to capture the routine's result
-        // before restoring parent state.
-        let saved = core::mem::replace(&mut self.scope, init_scope);
-        let exec_result = {
-            let aux = Emulator::predict(&routine, &input)?.into_aux();
-            routine.execute(self, input, aux)
-        };
-        // Verify this routine consumed exactly the expected constraints.
-        assert_eq!(
-            self.scope.gates,
-            seg.gate_start + seg.num_gates,
-            "routine gate count must match floor plan"
-        );
-        assert_eq!(
-            self.scope.constraints,
-            seg.constraint_start + seg.num_constraints,
-            "routine constraint count must match floor plan"
-        );
-
-        // Position the routine's local Horner result at its absolute Y offset,
-        // then combine with any nested child contributions.
-        let y_pow_constraint_start = self.y.pow_vartime([constraint_start as u64]);
-        let routine_contribution = y_pow_constraint_start * self.scope.result + self.scope.sum;
-        self.scope = saved;
-        self.scope.sum += routine_contribution;
+        input: Bound<'fp, Self, Ro::Input>,
+    ) -> Result<Bound<'fp, Self, Ro::Output>> {
+        #[cfg(feature = "multicore")]
+        if self.defers
+            && !self.outputs_only
+            && self.floor_plan[self.current_routine + 1].memo_key.is_none()
+        {
+            return self.defer(routine, input);
+        }
 
-        exec_result
+        self.synthesize(routine, input)
     }
 }
 
@@ -306,6 +500,18 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
     x: F,
     y: F,
     floor_plan: &[ConstraintSegment],
+) -> Result<F> {
+    eval_with::<F, RC, R>(circuit, x, y, floor_plan, true)
+}
+
+/// Evaluates $s(x, y)$ like [`eval`], but with the `multicore` feature defers
+/// routines to parallel jobs only if `defer` is set.
+pub(crate) fn eval_with<F: Field, RC: RawCircuit<F>, R: Rank>(
+    circuit: &RC,
+    x: F,
+    y: F,
+    floor_plan: &[ConstraintSegment],
+    #[cfg_attr(not(feature = "multicore"), allow(unused_variables))] defer: bool,
 ) -> Result<F> {
     // At x = 0 every monomial other than x^0 vanishes; the d[0] ONE wire
     // (at x^0) still contributes. Set x_inv = 0 so the running monomials
@@ -324,27 +530,23 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
     let xn_inv = x_inv.pow_vartime([R::n() as u64]); // x^(-n), or 0 if x = 0
     let base_a_x_inv = xn_inv.square(); // x^(-2n), or 0 if x = 0
 
-    let mut evaluator = Evaluator::<F, R> {
-        scope: SxyScope {
-            current_a_x: base_a_x,
-            current_b_x: base_b_x,
-            current_c_x: base_c_x,
-            gates: 0,
-            constraints: 0,
-            result: F::ZERO,
-            sum: F::ZERO,
+    let mut evaluator = Evaluator::<F, R>::new(
+        Monomials {
+            x,
+            x_inv,
+            base_a_x,
+            base_b_x,
+            base_c_x,
+            a_to_d: base_a_x_inv,
         },
-        x,
-        x_inv,
         y,
-        base_a_x,
-        base_b_x,
-        base_c_x,
-        a_to_d: base_a_x_inv,
         floor_plan,
-        current_routine: 0,
-        _marker: core::marker::PhantomData,
-    };
+    );
+
+    #[cfg(feature = "multicore")]
+    {
+        evaluator.defers = defer;
+    }
 
     crate::raw::orchestrate(&mut evaluator, circuit, Empty)?;
 
@@ -363,6 +565,5 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
         "root constraint count must match floor plan"
     );
 
-    // The root's local Horner result plus any child contributions.
-    Ok(evaluator.scope.result + evaluator.scope.sum)
+    evaluator.finish()
 }
@@ -54,26 +54,70 @@
 //! view provides direct access to the $a$, $b$, $c$, and $d$ coefficient regions.
 //! See [`sparse::View`] for details.
 //!
+//! ### Memoization
+//!
+//! Like [`sx`] and [`sxy`], this evaluator replays leaf routines that share a
+//! [`memo_key`](crate::floor_planner::ConstraintSegment::memo_key) from a
+//! recording. A leaf routine at gate offset $m$ and constraint offset $q_0$
+//! contributes $y^{q_0}$ times a fixed vector to the coefficients of its own
+//! gates (shifted by $m$), to the `ONE` wire and to each of its caller's input
+//! wires. Its output wires are linear combinations of the same wires. The
+//! [`Recorder`] captures all of these once per fingerprint, relative to the
+//! routine's placement, and [`Recording::replay`] adds the scaled contribution
+//! to the caller's wires and rebuilds the outputs as virtual wires. Since
+//! resolving virtual wires is linear, the result is identical to synthesizing
+//! the routine in place.
+//!
+//! ### Parallelism
+//!
+//! With the `multicore` feature, this evaluator defers routines invoked in the
+//! root scope like [`sx`] and [`sxy`] do, but a routine's contribution here is
+//! not confined to its own gates: its constraints also weigh the caller's
+//! input wires, which may be virtual wires shared with the rest of synthesis.
+//! A deferred job therefore synthesizes the routine on its own virtual table,
+//! with a placeholder virtual wire standing in for each input wire, and
+//! returns the values its placeholders accumulated alongside its writes to
+//! allocated wires. Meanwhile, the caller keeps the real input wires alive,
+//! so that the placeholder values can be added to them once the jobs finish,
+//! before they are resolved. Resolution is linear, so the result is identical
+//! to sequential evaluation.
+//!
 //! [`sx`]: super::sx
 //! [`sxy`]: super::sxy
 //! [`Driver::add`]: ragu_core::drivers::Driver::add
 //! [`sparse::View`]: crate::polynomials::sparse::View
 
-use alloc::{vec, vec::Vec};
-use core::cell::{RefCell, RefMut};
+use alloc::{
+    collections::{BTreeMap, btree_map::Entry},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
+    cell::{RefCell, RefMut},
+    marker::PhantomData,
+};
 
 use ragu_arithmetic::{Coeff, ff::Field};
 use ragu_core::{
     Error, Result,
+    convert::WireMap,
     drivers::{Driver, DriverTypes, LinearExpression, emulator::Emulator},
-    gadgets::Bound,
+    gadgets::{Bound, GadgetKind},
     maybe::Empty,
     routines::Routine,
 };
 
+#[cfg(feature = "multicore")]
+use ragu_primitives::GadgetExt;
+
+#[cfg(feature = "multicore")]
+use super::Job;
+use super::memo;
 use crate::{
     DriverScope,
     floor_planner::ConstraintSegment,
+    metrics::RoutineFingerprint,
     polynomials::{Rank, sparse},
     raw::RawCircuit,
 };
@@ -260,21 +304,38 @@ struct VirtualTable<'sy, F: Field, R: Rank> {
     c: &'sy mut Vec<F>,
     d: &'sy mut Vec<F>,
 
+    /// Writes to allocated wires made by a deferred routine's job, as
+    /// `(wire, value)` pairs. When present, these replace the buffers above,
+    /// which are left empty.
+    #[cfg(feature = "multicore")]
+    writes: Option<Vec<(WireIndex, F)>>,
+
     _marker: core::marker::PhantomData<R>,
 }
 
 impl<F: Field, R: Rank> VirtualTable<'_, F, R> {
     fn add(&mut self, index: WireIndex, value: Coeff<F>) {
+        match index {
+            WireIndex::Virtual(i) => self.wires[i].value = self.wires[i].value + value,
+            _ => self.write(index, value.value()),
+        }
+    }
+
+    /// Adds `value` to an allocated wire of the wiring view.
+    fn write(&mut self, index: WireIndex, value: F) {
+        #[cfg(feature = "multicore")]
+        if let Some(writes) = &mut self.writes {
+            writes.push((index, value));
+            return;
+        }
+
         *match index {
             WireIndex::A(i) => &mut self.a[i],
             WireIndex::B(i) => &mut self.b[i],
             WireIndex::C(i) => &mut self.c[i],
             WireIndex::D(i) => &mut self.d[i],
-            WireIndex::Virtual(i) => {
-                self.wires[i].value = self.wires[i].value + value;
-                return;
-            }
-        } += value.value();
+            WireIndex::Virtual(_) => unreachable!("virtual wires are resolved in the table"),
+        } += value;
     }
 
     /// Decrements the refcount of a virtual wire and **resolves** it (by adding
@@ -384,10 +445,222 @@ struct Evaluator<'table, 'sy, 'fp, F: Field, R: Rank> {
     /// Global monotonic DFS counter for routine entries.
     current_routine: usize,
 
+    /// Recordings of leaf routines, keyed by fingerprint.
+    memo: BTreeMap<RoutineFingerprint, Recording<F>>,
+
+    /// Whether the current routine's constraints are being evaluated by a
+    /// deferred job, so that only its output wires are needed here.
+    outputs_only: bool,
+
+    /// Whether routines invoked in this evaluator's root scope are deferred
+    /// to parallel jobs. Only the evaluator of the whole circuit defers, and
+    /// the routines nested in a deferred one are synthesized in place by its
+    /// job, so every routine is synthesized at most twice.
+    #[cfg(feature = "multicore")]
+    defers: bool,
+
+    /// Deferred routine evaluations, each yielding its contribution to
+    /// $s(X, y)$.
+    #[cfg(feature = "multicore")]
+    jobs: Vec<Job<'table, Deferred<F>>>,
+
+    /// The input wires of each deferred routine, in the same order as `jobs`.
+    /// They are kept alive until the job's contributions to them are added,
+    /// since resolving a virtual wire consumes its value.
+    #[cfg(feature = "multicore")]
+    held: Vec<Vec<Wire<'table, 'sy, F, R>>>,
+
     /// Marker for the rank type parameter.
     _marker: core::marker::PhantomData<R>,
 }
 
+impl<'table, 'sy, 'fp: 'table, F: Field, R: Rank> Evaluator<'table, 'sy, 'fp, F, R> {
+    fn new(
+        scope: SyScope<F>,
+        y: F,
+        virtual_table: &'table RefCell<VirtualTable<'sy, F, R>>,
+        floor_plan: &'fp [ConstraintSegment],
+    ) -> Self {
+        Evaluator {
+            scope,
+            y,
+            y_inv: y.invert().expect("y is not zero"),
+            virtual_table,
+            floor_plan,
+            current_routine: 0,
+            memo: BTreeMap::new(),
+            outputs_only: false,
+            #[cfg(feature = "multicore")]
+            defers: false,
+            #[cfg(feature = "multicore")]
+            jobs: Vec::new(),
+            #[cfg(feature = "multicore")]
+            held: Vec::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Evaluates a routine invocation in place at its floor plan segment.
+    fn synthesize<Ro: Routine<F> + 'table>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'table, Self, Ro::Input>,
+    ) -> Result<Bound<'table, Self, Ro::Output>> {
+        self.current_routine += 1;
+        let seg = &self.floor_plan[self.current_routine];
+
+        // Leaf routines seen before are replayed from their recording,
+        // translated to this invocation's placement.
+        if let Some(key) = seg.memo_key {
+            memo::check_bounds::<R>(
+                seg.gate_start,
+                seg.num_gates,
+                seg.constraint_start,
+                seg.num_constraints,
+            )?;
+            if let Entry::Vacant(entry) = self.memo.entry(key) {
+                entry.insert(Recording::record::<Self, Ro>(
+                    &routine,
+                    &input,
+                    self.y,
+                    self.y_inv,
+                    seg.num_gates,
+                    seg.num_constraints,
+                )?);
+            }
+
+            let mut inputs = InputIndices {
+                indices: vec![],
+                _marker: PhantomData,
+            };
+            Ro::Input::map_gadget(&input, &mut inputs)?;
+            let y_start = self.y.pow_vartime([seg.constraint_start as u64]);
+            return self.memo[&key].replay::<R, Ro::Output>(
+                self.virtual_table,
+                seg.gate_start,
+                y_start,
+                &inputs.indices,
+                self.outputs_only,
+            );
+        }
+
+        // Jump to this routine's absolute position in the polynomial;
+        // see "Polynomial Encoding and Scope Jumps" in the `s` module doc.
+        let init_scope = SyScope {
+            // When num_constraints == 0 the routine emits no
+            // enforce_zero calls, so current_y is never read; use
+            // F::ZERO as an inert sentinel.
+            current_y: if seg.num_constraints == 0 {
+                F::ZERO
+            } else {
+                self.y
+                    .pow_vartime([(seg.constraint_start + seg.num_constraints - 1) as u64])
+            },
+            gates: seg.gate_start,
+            constraints: seg.constraint_start,
+        };
+
+        self.with_scope(init_scope, |this| {
+            let aux = Emulator::predict(&routine, &input)?.into_aux();
+            let result = routine.execute(this, input, aux)?;
+
+            // Verify this routine consumed exactly the expected constraints.
+            assert_eq!(
+                this.scope.gates,
+                seg.gate_start + seg.num_gates,
+                "routine gate count must match floor plan"
+            );
+            assert_eq!(
+                this.scope.constraints,
+                seg.constraint_start + seg.num_constraints,
+                "routine constraint count must match floor plan"
+            );
+
+            Ok(result)
+        })
+    }
+
+    /// Defers the evaluation of a routine invocation to a parallel job,
+    /// synthesizing it here only for its output wires.
+    ///
+    /// The job synthesizes the invocation on a fresh evaluator positioned at
+    /// the same floor plan segment, with a placeholder virtual wire standing
+    /// in for each input wire. It returns its writes to allocated wires and
+    /// the values its placeholders accumulated, which [`eval`] adds to this
+    /// evaluator's wiring view and input wires once synthesis completes; by
+    /// linearity, the result is what [`synthesize`](Self::synthesize) would
+    /// have produced in place. The synthesis here records every leaf routine
+    /// the invocation reaches, so the job starts from a copy of the memo.
+    #[cfg(feature = "multicore")]
+    fn defer<Ro: Routine<F> + 'table>(
+        &mut self,
+        routine: Ro,
+        input: Bound<'table, Self, Ro::Input>,
+    ) -> Result<Bound<'table, Self, Ro::Output>> {
+        let y = self.y;
+        let floor_plan = self.floor_plan;
+        let current_routine = self.current_routine;
+        let job_routine = routine.clone();
+        let mut held = HeldInputs {
+            wires: vec![],
+            _marker: PhantomData,
+        };
+        let shape = Ro::Input::map_gadget(&input, &mut held)?.sendable();
+
+        self.outputs_only = true;
+        let output = self.synthesize(routine, input);
+        self.outputs_only = false;
+
+        let memo = self.memo.clone();
+        self.jobs.push(alloc::boxed::Box::new(move || {
+            let (mut a, mut b, mut c, mut d) = (vec![], vec![], vec![], vec![]);
+            let virtual_table = RefCell::new(VirtualTable::<F, R> {
+                wires: vec![],
+                free: vec![],
+                a: &mut a,
+                b: &mut b,
+                c: &mut c,
+                d: &mut d,
+                writes: Some(vec![]),
+                _marker: core::marker::PhantomData,
+            });
+
+            let mut placeholders = Placeholders {
+                table: &virtual_table,
+                indices: vec![],
+                _marker: PhantomData,
+            };
+            let scope = SyScope {
+                current_y: F::ZERO,
+                gates: 0,
+                constraints: 0,
+            };
+            let mut worker = Evaluator::<F, R>::new(scope, y, &virtual_table, floor_plan);
+            worker.current_routine = current_routine;
+            worker.memo = memo;
+            let input = Ro::Input::map_gadget(&*shape, &mut placeholders)?;
+            drop(worker.synthesize(job_routine, input)?);
+            drop(worker);
+            let indices = placeholders.indices;
+
+            let mut virtual_table = virtual_table.into_inner();
+            Ok(Deferred {
+                inputs: indices
+                    .iter()
+                    .map(|&i| virtual_table.wires[i].value.value())
+                    .collect(),
+                writes: virtual_table
+                    .writes
+                    .take()
+                    .expect("jobs record their writes"),
+            })
+        }));
+        self.held.push(held.wires);
+
+        output
+    }
+}
+
 /// Collects wire references when building a linear combination via [`Driver::add`].
 ///
 /// This accumulator builds a term list for a virtual wire. Each wire reference
@@ -470,6 +743,464 @@ impl<'table, 'sy, F: Field, R: Rank> LinearExpression<Wire<'table, 'sy, F, R>, F
     }
 }
 
+/// A wire of a recorded leaf routine, relative to the routine's placement.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Slot {
+    /// The $a$ wire of the routine's gate at this local index.
+    A(usize),
+    /// The $b$ wire of the routine's gate at this local index.
+    B(usize),
+    /// The $c$ wire of the routine's gate at this local index.
+    C(usize),
+    /// The $d$ wire of the routine's gate at this local index.
+    D(usize),
+    /// The caller's input wire at this index, in [`GadgetKind::map_gadget`]
+    /// order.
+    Input(usize),
+}
+
+/// A wire of a recorded leaf routine, expressed as a linear combination of
+/// [`Slot`]s and the `ONE` wire.
+#[derive(Clone)]
+struct SlotForm<F> {
+    /// Coefficient of the `ONE` wire.
+    one: F,
+    /// Terms of the linear combination, sorted by slot and without repeats.
+    terms: Vec<(Slot, F)>,
+}
+
+impl<F: Field> SlotForm<F> {
+    fn slot(slot: Slot) -> Self {
+        SlotForm {
+            one: F::ZERO,
+            terms: vec![(slot, F::ONE)],
+        }
+    }
+}
+
+/// A [`LinearExpression`] over [`SlotForm`]s, used by the [`Recorder`] both
+/// to build linear combinations and to enforce constraints.
+struct SlotSum<F: Field> {
+    one: F,
+    /// Unsorted terms, normalized by [`SlotSum::finish`].
+    terms: Vec<(Slot, F)>,
+    gain: Coeff<F>,
+}
+
+impl<F: Field> SlotSum<F> {
+    fn new() -> Self {
+        SlotSum {
+            one: F::ZERO,
+            terms: vec![],
+            gain: Coeff::One,
+        }
+    }
+
+    /// Sorts the terms and merges repeated slots.
+    fn finish(mut self) -> SlotForm<F> {
+        self.terms.sort_unstable_by_key(|(slot, _)| *slot);
+        let mut terms: Vec<(Slot, F)> = Vec::with_capacity(self.terms.len());
+        for (slot, coeff) in self.terms {
+            match terms.last_mut() {
+                Some((last, sum)) if *last == slot => *sum += coeff,
+                _ => terms.push((slot, coeff)),
+            }
+        }
+        terms.retain(|(_, coeff)| !bool::from(coeff.is_zero()));
+        SlotForm {
+            one: self.one,
+            terms,
+        }
+    }
+}
+
+impl<F: Field> LinearExpression<SlotForm<F>, F> for SlotSum<F> {
+    fn add_term(mut self, wire: &SlotForm<F>, coeff: Coeff<F>) -> Self {
+        let coeff = (coeff * self.gain).value();
+        self.one += wire.one * coeff;
+        self.terms
+            .extend(wire.terms.iter().map(|(slot, term)| (*slot, *term * coeff)));
+        self
+    }
+
+    fn gain(mut self, coeff: Coeff<F>) -> Self {
+        self.gain = self.gain * coeff;
+        self
+    }
+}
+
+/// The contribution of a leaf routine to $s(X, y)$, relative to its
+/// placement: the $y$ powers are those of a routine whose constraints start
+/// at $y^0$.
+struct Contribution<F> {
+    a: Vec<F>,
+    b: Vec<F>,
+    c: Vec<F>,
+    d: Vec<F>,
+    /// Contributions to the caller's input wires.
+    inputs: Vec<F>,
+    /// Contribution to the `ONE` wire.
+    one: F,
+}
+
+impl<F: Field> Contribution<F> {
+    fn add(&mut self, form: &SlotForm<F>, coeff: F) {
+        self.one += form.one * coeff;
+        for (slot, term) in &form.terms {
+            *match *slot {
+                Slot::A(i) => &mut self.a[i],
+                Slot::B(i) => &mut self.b[i],
+                Slot::C(i) => &mut self.c[i],
+                Slot::D(i) => &mut self.d[i],
+                Slot::Input(t) => &mut self.inputs[t],
+            } += *term * coeff;
+        }
+    }
+}
+
+/// A [`Driver`] that records a leaf routine's contribution to $s(X, y)$ in
+/// terms of [`Slot`]s.
+///
+/// Gates are allocated as if the routine were placed at gate offset zero, and
+/// constraints are weighted as if its last constraint were at $y^0$.
+struct Recorder<F: Field> {
+    /// Current $y$ power being applied to constraints.
+    current_y: F,
+    y_inv: F,
+    /// Number of constraints enforced so far.
+    constraints: usize,
+    contribution: Contribution<F>,
+}
+
+impl<F: Field> DriverTypes for Recorder<F> {
+    type MaybeKind = Empty;
+    type LCadd = SlotSum<F>;
+    type LCenforce = SlotSum<F>;
+    type ImplField = F;
+    type ImplWire = SlotForm<F>;
+    type Extra = usize;
+
+    fn gate(
+        &mut self,
+        _: impl Fn() -> Result<(Coeff<F>, Coeff<F>, Coeff<F>)>,
+    ) -> Result<(SlotForm<F>, SlotForm<F>, SlotForm<F>, usize)> {
+        let index = self.contribution.a.len();
+        let contribution = &mut self.contribution;
+        for wires in [
+            &mut contribution.a,
+            &mut contribution.b,
+            &mut contribution.c,
+            &mut contribution.d,
+        ] {
+            wires.push(F::ZERO);
+        }
+
+        Ok((
+            SlotForm::slot(Slot::A(index)),
+            SlotForm::slot(Slot::B(index)),
+            SlotForm::slot(Slot::C(index)),
+            index,
+        ))
+    }
+
+    fn assign_extra(
+        &mut self,
+        index: usize,
+        _: impl Fn() -> Result<Coeff<F>>,
+    ) -> Result<SlotForm<F>> {
+        Ok(SlotForm::slot(Slot::D(index)))
+    }
+}
+
+impl<'dr, F: Field> Driver<'dr> for Recorder<F> {
+    type F = F;
+    type Wire = SlotForm<F>;
+
+    const ONE: Self::Wire = SlotForm {
+        one: F::ONE,
+        terms: Vec::new(),
+    };
+
+    fn add(&mut self, lc: impl Fn(Self::LCadd) -> Self::LCadd) -> Self::Wire {
+        lc(SlotSum::new()).finish()
+    }
+
+    fn enforce_zero(&mut self, lc: impl Fn(Self::LCenforce) -> Self::LCenforce) -> Result<()> {
+        let form = lc(SlotSum::new()).finish();
+        self.contribution.add(&form, self.current_y);
+        self.current_y *= self.y_inv;
+        self.constraints += 1;
+        Ok(())
+    }
+
+    // Only leaf routines are recorded, so nested routine calls never reach
+    // this driver; the default inline execution is left in place.
+}
+
+/// Maps caller input wires to symbolic [`Slot::Input`]s, in order.
+struct InputSlots<F, D> {
+    next: usize,
+    _marker: PhantomData<(F, D)>,
+}
+
+impl<F: Field, D: DriverTypes<ImplField = F>> WireMap<F> for InputSlots<F, D> {
+    type Src = D;
+    type Dst = Recorder<F>;
+
+    fn convert_wire(&mut self, _: &D::ImplWire) -> Result<SlotForm<F>> {
+        let form = SlotForm::slot(Slot::Input(self.next));
+        self.next += 1;
+        Ok(form)
+    }
+}
+
+/// Collects the indices of an evaluator's input wires, in the same order as
+/// [`InputSlots`] assigns them.
+struct InputIndices<'table, 'sy, 'fp, F: Field, R: Rank> {
+    indices: Vec<WireIndex>,
+    _marker: PhantomData<Evaluator<'table, 'sy, 'fp, F, R>>,
+}
+
+impl<'table, 'sy, 'fp, F: Field, R: Rank> WireMap<F> for InputIndices<'table, 'sy, 'fp, F, R> {
+    type Src = Evaluator<'table, 'sy, 'fp, F, R>;
+    type Dst = PhantomData<F>;
+
+    fn convert_wire(&mut self, wire: &Wire<'table, 'sy, F, R>) -> Result<()> {
+        self.indices.push(wire.index);
+        Ok(())
+    }
+}
+
+/// Rebuilds recorded output wires as wires of the evaluator, at the
+/// placement of one routine invocation.
+struct Replay<'a, 'table, 'sy, 'fp, F: Field, R: Rank> {
+    table: &'table RefCell<VirtualTable<'sy, F, R>>,
+    gate_start: usize,
+    inputs: &'a [WireIndex],
+    _marker: PhantomData<Evaluator<'table, 'sy, 'fp, F, R>>,
+}
+
+impl<'table, 'sy, 'fp, F: Field, R: Rank> WireMap<F> for Replay<'_, 'table, 'sy, 'fp, F, R> {
+    type Src = Recorder<F>;
+    type Dst = Evaluator<'table, 'sy, 'fp, F, R>;
+
+    fn convert_wire(&mut self, wire: &SlotForm<F>) -> Result<Wire<'table, 'sy, F, R>> {
+        let index = |slot: Slot| match slot {
+            Slot::A(i) => WireIndex::A(self.gate_start + i),
+            Slot::B(i) => WireIndex::B(self.gate_start + i),
+            Slot::C(i) => WireIndex::C(self.gate_start + i),
+            Slot::D(i) => WireIndex::D(self.gate_start + i),
+            Slot::Input(t) => self.inputs[t],
+        };
+
+        // A wire that is exactly one of the routine's or caller's wires is
+        // returned as that wire rather than wrapped in a virtual wire.
+        if let [(slot, coeff)] = wire.terms[..]
+            && coeff == F::ONE
+            && wire.one.is_zero_vartime()
+        {
+            let wire = Wire::new(index(slot), self.table);
+            wire.increment_refcount();
+            return Ok(wire);
+        }
+
+        // Each stored term holds a reference to its wire, as in `Driver::add`.
+        let mut table = self.table.borrow_mut();
+        let mut terms = Vec::with_capacity(wire.terms.len() + 1);
+        if !wire.one.is_zero_vartime() {
+            terms.push((WireIndex::D(0), Coeff::Arbitrary(wire.one)));
+        }
+        for (slot, coeff) in &wire.terms {
+            let index = index(*slot);
+            if let WireIndex::Virtual(i) = index {
+                table.wires[i].refcount += 1;
+            }
+            terms.push((index, Coeff::Arbitrary(*coeff)));
+        }
+
+        let index = table.alloc();
+        table.update(index, terms);
+        drop(table);
+        Ok(Wire::new(index, self.table))
+    }
+}
+
+/// The contribution of a deferred routine invocation to $s(X, y)$.
+#[cfg(feature = "multicore")]
+struct Deferred<F> {
+    /// Writes to the allocated wires of the routine and of the routines
+    /// nested in it, as `(wire, value)` pairs.
+    writes: Vec<(WireIndex, F)>,
+    /// Values to add to the caller's input wires, in
+    /// [`GadgetKind::map_gadget`] order.
+    inputs: Vec<F>,
+}
+
+/// Keeps the input wires of a deferred routine alive, producing the shape of
+/// its input gadget for the job.
+#[cfg(feature = "multicore")]
+struct HeldInputs<'table, 'sy, 'fp, F: Field, R: Rank> {
+    wires: Vec<Wire<'table, 'sy, F, R>>,
+    _marker: PhantomData<&'fp ()>,
+}
+
+#[cfg(feature = "multicore")]
+impl<'table, 'sy, 'fp, F: Field, R: Rank> WireMap<F> for HeldInputs<'table, 'sy, 'fp, F, R> {
+    type Src = Evaluator<'table, 'sy, 'fp, F, R>;
+    type Dst = PhantomData<F>;
+
+    fn convert_wire(&mut self, wire: &Wire<'table, 'sy, F, R>) -> Result<()> {
+        self.wires.push(wire.clone());
+        Ok(())
+    }
+}
+
+/// Stands a fresh virtual wire in for each input wire of a deferred routine,
+/// in the same order as [`HeldInputs`].
+///
+/// Each placeholder is given an extra reference so that it is never
+/// resolved, leaving its accumulated value to be read once the job's
+/// synthesis completes.
+#[cfg(feature = "multicore")]
+struct Placeholders<'table, 'sy, 'fp, F: Field, R: Rank> {
+    table: &'table RefCell<VirtualTable<'sy, F, R>>,
+    indices: Vec<usize>,
+    _marker: PhantomData<&'fp ()>,
+}
+
+#[cfg(feature = "multicore")]
+impl<'table, 'sy, 'fp, F: Field, R: Rank> WireMap<F> for Placeholders<'table, 'sy, 'fp, F, R> {
+    type Src = PhantomData<F>;
+    type Dst = Evaluator<'table, 'sy, 'fp, F, R>;
+
+    fn convert_wire(&mut self, _: &()) -> Result<Wire<'table, 'sy, F, R>> {
+        let mut table = self.table.borrow_mut();
+        let index = table.alloc();
+        let WireIndex::Virtual(i) = index else {
+            unreachable!("the table allocates virtual wires")
+        };
+        table.wires[i].refcount += 1;
+        self.indices.push(i);
+        Ok(Wire::new(index, self.table))
+    }
+}
+
+/// A leaf routine's contribution and output, recorded once and replayed for
+/// every invocation that shares its fingerprint.
+#[derive(Clone)]
+struct Recording<F> {
+    contribution: Arc<Contribution<F>>,
+    /// The routine's output gadget, on a [`Recorder`].
+    output: memo::Output,
+}
+
+impl<F: Field> Recording<F> {
+    /// Synthesizes `routine` on a [`Recorder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the routine does not emit exactly `num_gates` gates and
+    /// `num_constraints` constraints, as given by its floor plan segment.
+    fn record<'dr, D, Ro>(
+        routine: &Ro,
+        input: &Bound<'dr, D, Ro::Input>,
+        y: F,
+        y_inv: F,
+        num_gates: usize,
+        num_constraints: usize,
+    ) -> Result<Self>
+    where
+        D: Driver<'dr, F = F>,
+        Ro: Routine<F>,
+    {
+        let mut inputs = InputSlots {
+            next: 0,
+            _marker: PhantomData::<(F, D)>,
+        };
+        let input: Bound<'static, Recorder<F>, Ro::Input> =
+            Ro::Input::map_gadget(input, &mut inputs)?;
+
+        let mut recorder = Recorder {
+            current_y: match num_constraints {
+                0 => F::ZERO,
+                n => y.pow_vartime([(n - 1) as u64]),
+            },
+            y_inv,
+            constraints: 0,
+            contribution: Contribution {
+                a: Vec::with_capacity(num_gates),
+                b: Vec::with_capacity(num_gates),
+                c: Vec::with_capacity(num_gates),
+                d: Vec::with_capacity(num_gates),
+                inputs: vec![F::ZERO; inputs.next],
+                one: F::ZERO,
+            },
+        };
+
+        let aux = Emulator::predict(routine, &input)?.into_aux();
+        let output: Bound<'static, Recorder<F>, Ro::Output> =
+            routine.execute(&mut recorder, input, aux)?;
+
+        assert_eq!(
+            recorder.contribution.a.len(),
+            num_gates,
+            "routine gate count must match floor plan"
+        );
+        assert_eq!(
+            recorder.constraints, num_constraints,
+            "routine constraint count must match floor plan"
+        );
+
+        Ok(Recording {
+            contribution: Arc::new(recorder.contribution),
+            output: memo::Output::new::<Recorder<F>, Ro::Output>(output),
+        })
+    }
+
+    /// Adds the recorded contribution, scaled by `y_start` (the $y$ power of
+    /// the invocation's first constraint slot), to the evaluator's wires at
+    /// gate offset `gate_start`, and rebuilds the recorded output.
+    ///
+    /// The contribution is skipped if `outputs_only` is set.
+    fn replay<'table, 'sy, 'fp: 'table, R: Rank, K: GadgetKind<F>>(
+        &self,
+        table: &'table RefCell<VirtualTable<'sy, F, R>>,
+        gate_start: usize,
+        y_start: F,
+        inputs: &[WireIndex],
+        outputs_only: bool,
+    ) -> Result<Bound<'table, Evaluator<'table, 'sy, 'fp, F, R>, K>> {
+        if !outputs_only {
+            let contribution = &*self.contribution;
+            let mut table = table.borrow_mut();
+            for i in 0..contribution.a.len() {
+                let gate = gate_start + i;
+                table.write(WireIndex::A(gate), contribution.a[i] * y_start);
+                table.write(WireIndex::B(gate), contribution.b[i] * y_start);
+                table.write(WireIndex::C(gate), contribution.c[i] * y_start);
+                table.write(WireIndex::D(gate), contribution.d[i] * y_start);
+            }
+            table.add(
+                WireIndex::D(0),
+                Coeff::Arbitrary(contribution.one * y_start),
+            );
+            for (index, value) in inputs.iter().zip(&contribution.inputs) {
+                table.add(*index, Coeff::Arbitrary(*value * y_start));
+            }
+        }
+
+        K::map_gadget(
+            self.output.get::<Recorder<F>, K>(),
+            &mut Replay {
+                table,
+                gate_start,
+                inputs,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
 impl<'table, 'sy, F: Field, R: Rank> DriverScope<SyScope<F>> for Evaluator<'table, 'sy, '_, F, R> {
     fn scope(&mut self) -> &mut SyScope<F> {
         &mut self.scope
@@ -536,7 +1267,9 @@ impl<'table, 'sy, F: Field, R: Rank> DriverTypes for Evaluator<'table, 'sy, '_,
     }
 }
 
-impl<'table, 'sy, F: Field, R: Rank> Driver<'table> for Evaluator<'table, 'sy, '_, F, R> {
+impl<'table, 'sy, 'fp: 'table, F: Field, R: Rank> Driver<'table>
+    for Evaluator<'table, 'sy, 'fp, F, R>
+{
     type F = F;
     type Wire = Wire<'table, 'sy, F, R>;
 
@@ -581,58 +1314,37 @@ impl<'table, 'sy, F: Field, R: Rank> Driver<'table> for Evaluator<'table, 'sy, '
         }
         self.scope.constraints += 1;
 
-        lc(TermEnforcer(
-            self.virtual_table.borrow_mut(),
-            Coeff::Arbitrary(self.scope.current_y),
-        ));
+        if !self.outputs_only {
+            lc(TermEnforcer(
+                self.virtual_table.borrow_mut(),
+                Coeff::Arbitrary(self.scope.current_y),
+            ));
+        }
 
         self.scope.current_y *= self.y_inv;
 
         Ok(())
     }
 
+    /// Evaluates a routine invocation at its floor plan segment.
+    ///
+    /// With the `multicore` feature, routines invoked in the root scope of the
+    /// circuit that cannot be memoized are deferred to a parallel job
+    /// instead; see the [module documentation](self).
     fn routine<Ro: Routine<Self::F> + 'table>(
         &mut self,
         routine: Ro,
         input: Bound<'table, Self, Ro::Input>,
     ) -> Result<Bound<'table, Self, Ro::Output>> {
-        self.current_routine += 1;
-        let seg = &self.floor_plan[self.current_routine];
-
-        // Jump to this routine's absolute position in the polynomial;
-        // see "Polynomial Encoding and Scope Jumps" in the `s` module doc.
-        let init_scope = SyScope {
-            // When num_constraints == 0 the routine emits no
-            // enforce_zero calls, so current_y is never read; use
-            // F::ZERO as an inert sentinel.
-            current_y: if seg.num_constraints == 0 {
-                F::ZERO
-            } else {
-                self.y
-                    .pow_vartime([(seg.constraint_start + seg.num_constraints - 1) as u64])
-            },
-            gates: seg.gate_start,
-            constraints: seg.constraint_start,
-        };
-
-        self.with_scope(init_scope, |this| {
-            let aux = Emulator::predict(&routine, &input)?.into_aux();
-            let result = routine.execute(this, input, aux)?;
-
-            // Verify this routine consumed exactly the expected constraints.
-            assert_eq!(
-                this.scope.gates,
-                seg.gate_start + seg.num_gates,
-                "routine gate count must match floor plan"
-            );
-            assert_eq!(
-                this.scope.constraints,
-                seg.constraint_start + seg.num_constraints,
-                "routine constraint count must match floor plan"
-            );
+        #[cfg(feature = "multicore")]
+        if self.defers
+            && !self.outputs_only
+            && self.floor_plan[self.current_routine + 1].memo_key.is_none()
+        {
+            return self.defer(routine, input);
+        }
 
-            Ok(result)
-        })
+        self.synthesize(routine, input)
     }
 }
 
@@ -654,6 +1366,17 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
     circuit: &RC,
     y: F,
     floor_plan: &[ConstraintSegment],
+) -> Result<sparse::Polynomial<F, R>> {
+    eval_with(circuit, y, floor_plan, true)
+}
+
+/// Evaluates $s(X, y)$ like [`eval`], but with the `multicore` feature defers
+/// routines to parallel jobs only if `defer` is set.
+pub(crate) fn eval_with<F: Field, RC: RawCircuit<F>, R: Rank>(
+    circuit: &RC,
+    y: F,
+    floor_plan: &[ConstraintSegment],
+    #[cfg_attr(not(feature = "multicore"), allow(unused_variables))] defer: bool,
 ) -> Result<sparse::Polynomial<F, R>> {
     let mut view = sparse::View::wiring();
 
@@ -682,6 +1405,8 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
             b: &mut view.b,
             c: &mut view.c,
             d: &mut view.d,
+            #[cfg(feature = "multicore")]
+            writes: None,
             _marker: core::marker::PhantomData,
         });
 
@@ -695,20 +1420,18 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
         }
 
         {
-            let mut evaluator = Evaluator::<'_, '_, '_, F, R> {
-                scope: SyScope {
-                    // Assertion above prevents this from underflowing.
-                    current_y: y.pow_vartime([(root_constraints - 1) as u64]),
-                    gates: 0,
-                    constraints: 0,
-                },
-                y,
-                y_inv: y.invert().expect("y is not zero"),
-                virtual_table: &virtual_table,
-                floor_plan,
-                current_routine: 0,
-                _marker: core::marker::PhantomData,
+            let scope = SyScope {
+                // Assertion above prevents this from underflowing.
+                current_y: y.pow_vartime([(root_constraints - 1) as u64]),
+                gates: 0,
+                constraints: 0,
             };
+            let mut evaluator = Evaluator::<F, R>::new(scope, y, &virtual_table, floor_plan);
+
+            #[cfg(feature = "multicore")]
+            {
+                evaluator.defers = defer;
+            }
 
             crate::raw::orchestrate(&mut evaluator, circuit, Empty)?;
 
@@ -726,6 +1449,33 @@ pub fn eval<F: Field, RC: RawCircuit<F>, R: Rank>(
                 evaluator.scope.constraints, evaluator.floor_plan[0].num_constraints,
                 "root constraint count must match floor plan"
             );
+
+            #[cfg(feature = "multicore")]
+            {
+                use maybe_rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+                let deferred = core::mem::take(&mut evaluator.jobs)
+                    .into_par_iter()
+                    .map(|job| job())
+                    .collect::<Result<Vec<_>>>()?;
+                for (deferred, inputs) in deferred
+                    .into_iter()
+                    .zip(core::mem::take(&mut evaluator.held))
+                {
+                    let mut table = virtual_table.borrow_mut();
+                    for (index, value) in deferred.writes {
+                        table.write(index, value);
+                    }
+                    for (wire, value) in inputs.iter().zip(deferred.inputs) {
+                        table.add(wire.index, Coeff::Arbitrary(value));
+                    }
+                    drop(table);
+
+                    // With every contribution to them known, the input wires
+                    // can now be resolved.
+                    drop(inputs);
+                }
+            }
         }
 
         // Invariant: all virtual wires must have been freed during synthesis,
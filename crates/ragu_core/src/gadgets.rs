@@ -44,9 +44,10 @@
 //! One of the direct consequences of fungibility is that a [`Gadget`] impl must
 //! always contain the same number of wires in every instance, and cannot carry
 //! any additional state that would influence synthesis behavior. It also means
-//! that gadgets usually cannot be `enum`s. Fortunately, most gadgets only
-//! contain wires, witness data and other gadgets. These simple gadgets always
-//! qualify as fungible by definition.
+//! that gadgets usually cannot be `enum`s; the [`Gadget`](derive@Gadget)
+//! derive instead lowers an enum of gadgets into a tagged-union struct.
+//! Fortunately, most gadgets only contain wires, witness data and other
+//! gadgets. These simple gadgets always qualify as fungible by definition.
 //!
 //! #### Transformations between Drivers
 //!
@@ -298,9 +299,10 @@ pub unsafe trait GadgetKind<F: Field>: core::any::Any {
 /// Automatically derives the [`Gadget`], [`GadgetKind`] and [`Clone`] traits
 /// for common gadget types.
 ///
-/// This only works for structs with named fields. Enums are disallowed because
-/// their discriminants constitute instance state that would violate the
-/// fungibility requirement.
+/// This works for structs with named fields and for enums of gadgets. An
+/// enum's discriminant would be instance state that violates the fungibility
+/// requirement, so an enum `E` is not itself a gadget: the derive generates a
+/// tagged-union struct `EGadget` instead. See [enums](#enums) below.
 ///
 /// ## Example
 ///
@@ -344,6 +346,26 @@ pub unsafe trait GadgetKind<F: Field>: core::any::Any {
 ///     value: DriverValue<MyD, MyD::F>,
 /// }
 /// ```
+///
+/// ## Enums
+///
+/// Deriving [`Gadget`] for an enum whose variant fields are all gadgets
+/// generates a struct `EGadget` with the same generics, holding a
+/// `ragu_primitives::OneHot` tag and the fields of every variant side by side.
+/// The payloads of variants the tag does not select are constrained to zero,
+/// so every payload gadget must accept an all-zero encoding. The generated
+/// struct has the following inherent methods:
+/// * `new` takes the tag and every variant in declaration order, and
+///   constrains the inactive payloads to zero.
+/// * `tag` returns the tag and `variants` returns every variant as an `E`.
+/// * `match_variants` computes a result for every variant with a closure and
+///   returns the one for the active variant, selected with
+///   `ragu_primitives::OneHot::select`.
+///
+/// The `Write`, `GadgetEquals`, `Consistent` and `ConditionalSelect` derives
+/// of `ragu_primitives` also accept such an enum and implement their traits
+/// for `EGadget`. Its `Consistent` implementation re-enforces that inactive
+/// payloads are zero.
 pub use ragu_macros::Gadget;
 /// Obtains the concrete [`GadgetKind<F>`] of a [`Gadget`] type given only the
 /// gadget's type and a field type `F`. This is particularly useful in contexts
@@ -0,0 +1,266 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    AngleBracketedGenericArguments, Data, DeriveInput, Error, Fields, GenericParam, Generics,
+    Ident, Result, parse_quote, spanned::Spanned,
+};
+
+use crate::{
+    derive::tagged::TaggedUnion,
+    helpers::{GenericDriver, attr_is},
+    path_resolution::{RaguArithmeticPath, RaguCorePath, RaguPrimitivesPath},
+    substitution::replace_driver_field_in_generic_param,
+};
+
+pub fn derive(
+    input: DeriveInput,
+    ragu_arithmetic_path: RaguArithmeticPath,
+    ragu_core_path: RaguCorePath,
+    ragu_primitives_path: RaguPrimitivesPath,
+) -> Result<TokenStream> {
+    if let Some(tagged) = TaggedUnion::extract(&input, &ragu_primitives_path)? {
+        return derive(
+            tagged.into_struct(),
+            ragu_arithmetic_path,
+            ragu_core_path,
+            ragu_primitives_path,
+        );
+    }
+
+    let DeriveInput {
+        ident: struct_ident,
+        generics,
+        data,
+        ..
+    } = &input;
+
+    let driver = &GenericDriver::extract(generics)?;
+    let driverfield_ident = format_ident!("DriverField");
+
+    enum FieldType {
+        Value,
+        Gadget,
+        Phantom,
+    }
+
+    let fields: Vec<(Ident, FieldType)> = match data {
+        Data::Struct(s) => {
+            let fields = match &s.fields {
+                Fields::Named(named) => &named.named,
+                _ => {
+                    return Err(Error::new(
+                        s.struct_token.span(),
+                        "ConditionalSelect derive only works on structs with named fields",
+                    ));
+                }
+            };
+
+            let mut res = vec![];
+
+            for f in fields {
+                let fid = f.ident.clone().expect("fields contains only named fields");
+                let is_value = f.attrs.iter().any(|a| attr_is(a, "value"));
+                let is_wire = f.attrs.iter().any(|a| attr_is(a, "wire"));
+                let is_gadget = f.attrs.iter().any(|a| attr_is(a, "gadget"));
+                let is_phantom = f.attrs.iter().any(|a| attr_is(a, "phantom"));
+
+                match (is_value, is_wire, is_gadget, is_phantom) {
+                    (true, false, false, false) => {
+                        res.push((fid, FieldType::Value));
+                    }
+                    (false, true, false, false) => {
+                        return Err(Error::new(
+                            fid.span(),
+                            "ConditionalSelect derive does not support #[ragu(wire)] fields; wrap the wire in a gadget such as Element",
+                        ));
+                    }
+                    (false, false, true, false) | (false, false, false, false) => {
+                        res.push((fid, FieldType::Gadget));
+                    }
+                    (false, false, false, true) => {
+                        res.push((fid, FieldType::Phantom));
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            fid.span(),
+                            "field cannot have multiple annotations; use only one of: #[ragu(value)], #[ragu(wire)], #[ragu(gadget)], or #[ragu(phantom)]",
+                        ));
+                    }
+                }
+            }
+
+            res
+        }
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "ConditionalSelect derive only works on structs and enums",
+            ));
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    if let Some(wc) = where_clause {
+        return Err(Error::new(
+            wc.span(),
+            "ConditionalSelect derive does not yet support where clauses",
+        ));
+    }
+    let impl_generics: Generics = {
+        let mut impl_generics: Generics = parse_quote!( #impl_generics );
+        impl_generics.params.iter_mut().for_each(|gp| match gp {
+            GenericParam::Type(ty) if ty.ident == driver.ident => {
+                ty.attrs.retain(|a| !attr_is(a, "driver"));
+            }
+            _ => {}
+        });
+        impl_generics
+    };
+    let ty_generics: AngleBracketedGenericArguments = parse_quote!( #ty_generics );
+    let gadget_kind_generic_params: Generics = {
+        let mut params: Vec<GenericParam> = impl_generics
+            .params
+            .clone()
+            .into_iter()
+            .filter(|gp| match gp {
+                GenericParam::Type(ty) if ty.ident == driver.ident => false,
+                GenericParam::Lifetime(lt) if lt.lifetime.ident == driver.lifetime.ident => false,
+                _ => true,
+            })
+            .collect();
+        for param in &mut params {
+            replace_driver_field_in_generic_param(param, &driver.ident, &driverfield_ident);
+        }
+        params.push(parse_quote!( #driverfield_ident: #ragu_arithmetic_path::ff::Field ));
+
+        parse_quote!( < #( #params ),* >)
+    };
+    let kind_subst_arguments = driver.kind_subst_arguments(&ty_generics);
+
+    let driver_ident = &driver.ident;
+    let driver_lifetime = &driver.lifetime;
+    let selected_inits = fields.iter().map(|(id, ty)| {
+        let init = match ty {
+            FieldType::Value => quote! {
+                {
+                    use #ragu_core_path::maybe::Maybe;
+
+                    let condition = condition.value();
+                    <#driver_ident as #ragu_core_path::drivers::Driver<#driver_lifetime>>::just(|| {
+                        if condition.take() {
+                            b.#id.as_ref().take().clone()
+                        } else {
+                            a.#id.as_ref().take().clone()
+                        }
+                    })
+                }
+            },
+            FieldType::Gadget => quote! { condition.select(dr, &a.#id, &b.#id)? },
+            FieldType::Phantom => quote! { ::core::marker::PhantomData },
+        };
+        quote! { #id: #init }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #gadget_kind_generic_params #ragu_primitives_path::select::ConditionalSelect<#driverfield_ident>
+            for #struct_ident #kind_subst_arguments
+        {
+            fn conditional_select<
+                #driver_lifetime,
+                #driver_ident: #ragu_core_path::drivers::Driver<#driver_lifetime, F = #driverfield_ident>,
+            >(
+                dr: &mut #driver_ident,
+                condition: &#ragu_primitives_path::Boolean<#driver_lifetime, #driver_ident>,
+                a: &#ragu_core_path::gadgets::Bound<#driver_lifetime, #driver_ident, Self>,
+                b: &#ragu_core_path::gadgets::Bound<#driver_lifetime, #driver_ident, Self>,
+            ) -> #ragu_core_path::Result<#ragu_core_path::gadgets::Bound<#driver_lifetime, #driver_ident, Self>>
+            {
+                Ok(#struct_ident {
+                    #( #selected_inits, )*
+                })
+            }
+        }
+    })
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_conditionalselect_derive() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(ConditionalSelect)]
+        struct MyGadget<'mydr, #[ragu(driver)] MyD: Driver<'mydr>> {
+            field: Element<'mydr, MyD>,
+            #[ragu(value)]
+            value: DriverValue<MyD, bool>,
+            #[ragu(phantom)]
+            marker: ::core::marker::PhantomData<()>,
+        }
+    };
+
+    let result = derive(
+        input,
+        RaguArithmeticPath::default(),
+        RaguCorePath::default(),
+        RaguPrimitivesPath::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        result.to_string(),
+        quote!(
+            #[automatically_derived]
+            impl<DriverField: ::ragu_arithmetic::ff::Field> ::ragu_primitives::select::ConditionalSelect<DriverField>
+                for MyGadget<'static, ::core::marker::PhantomData<DriverField> >
+            {
+                fn conditional_select<
+                    'mydr,
+                    MyD: ::ragu_core::drivers::Driver<'mydr, F = DriverField>,
+                >(
+                    dr: &mut MyD,
+                    condition: &::ragu_primitives::Boolean<'mydr, MyD>,
+                    a: &::ragu_core::gadgets::Bound<'mydr, MyD, Self>,
+                    b: &::ragu_core::gadgets::Bound<'mydr, MyD, Self>,
+                ) -> ::ragu_core::Result<::ragu_core::gadgets::Bound<'mydr, MyD, Self>>
+                {
+                    Ok(MyGadget {
+                        field: condition.select(dr, &a.field, &b.field)?,
+                        value: {
+                            use ::ragu_core::maybe::Maybe;
+
+                            let condition = condition.value();
+                            <MyD as ::ragu_core::drivers::Driver<'mydr>>::just(|| {
+                                if condition.take() {
+                                    b.value.as_ref().take().clone()
+                                } else {
+                                    a.value.as_ref().take().clone()
+                                }
+                            })
+                        },
+                        marker: ::core::marker::PhantomData,
+                    })
+                }
+            }
+        ).to_string()
+    );
+}
+
+#[test]
+fn test_conditionalselect_derive_rejects_wire() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(ConditionalSelect)]
+        struct MyGadget<'mydr, #[ragu(driver)] MyD: Driver<'mydr>> {
+            #[ragu(wire)]
+            wire: MyD::Wire,
+        }
+    };
+
+    let err = derive(
+        input,
+        RaguArithmeticPath::default(),
+        RaguCorePath::default(),
+        RaguPrimitivesPath::default(),
+    )
+    .expect_err("Expected error for wire field");
+    assert!(err.to_string().contains("wire"));
+}
@@ -6,6 +6,7 @@ use syn::{
 };
 
 use crate::{
+    derive::tagged::TaggedUnion,
     helpers::{GenericDriver, attr_is},
     path_resolution::{RaguCorePath, RaguPrimitivesPath},
 };
@@ -14,6 +15,27 @@ pub fn derive(
     input: DeriveInput,
     ragu_core_path: RaguCorePath,
     ragu_primitives_path: RaguPrimitivesPath,
+) -> Result<TokenStream> {
+    match TaggedUnion::extract(&input, &ragu_primitives_path)? {
+        // Tagged unions additionally re-enforce that inactive payloads are zero.
+        Some(tagged) => {
+            let extra_calls = tagged.enforce_inactive_zero();
+            derive_with(
+                tagged.into_struct(),
+                ragu_core_path,
+                ragu_primitives_path,
+                extra_calls,
+            )
+        }
+        None => derive_with(input, ragu_core_path, ragu_primitives_path, quote! {}),
+    }
+}
+
+fn derive_with(
+    input: DeriveInput,
+    ragu_core_path: RaguCorePath,
+    ragu_primitives_path: RaguPrimitivesPath,
+    extra_calls: TokenStream,
 ) -> Result<TokenStream> {
     let DeriveInput {
         ident: struct_ident,
@@ -78,7 +100,7 @@ pub fn derive(
         _ => {
             return Err(Error::new(
                 Span::call_site(),
-                "Consistent derive only works on structs",
+                "Consistent derive only works on structs and enums",
             ));
         }
     };
@@ -100,6 +122,7 @@ pub fn derive(
         impl #impl_generics #ragu_primitives_path::consistent::Consistent<#driver_lifetime, #driver_ident> for #struct_ident #ty_generics {
             fn enforce_consistent(&self, dr: &mut #driver_ident) -> #ragu_core_path::Result<()> {
                 #( #enforce_calls )*
+                #extra_calls
                 Ok(())
             }
         }
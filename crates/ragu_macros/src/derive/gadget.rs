@@ -6,8 +6,9 @@ use syn::{
 };
 
 use crate::{
+    derive::tagged::TaggedUnion,
     helpers::{GenericDriver, attr_is},
-    path_resolution::{RaguArithmeticPath, RaguCorePath},
+    path_resolution::{RaguArithmeticPath, RaguCorePath, RaguPrimitivesPath},
     substitution::replace_driver_field_in_generic_param,
 };
 
@@ -56,6 +57,29 @@ impl GenericDriver {
     }
 }
 
+/// Derives `Gadget` for an enum by generating its tagged-union struct.
+///
+/// This is separate from [`derive`] because only enums need `ragu_primitives`,
+/// which `ragu_core` itself cannot depend on.
+pub fn derive_enum(
+    input: DeriveInput,
+    ragu_arithmetic_path: RaguArithmeticPath,
+    ragu_core_path: RaguCorePath,
+    ragu_primitives_path: RaguPrimitivesPath,
+) -> Result<TokenStream> {
+    let Some(tagged) = TaggedUnion::extract(&input, &ragu_primitives_path)? else {
+        return derive(input, ragu_arithmetic_path, ragu_core_path);
+    };
+    let definition = tagged.definition(&ragu_core_path, &ragu_primitives_path);
+    let gadget_impls = derive(tagged.into_struct(), ragu_arithmetic_path, ragu_core_path)?;
+
+    Ok(quote! {
+        #definition
+
+        #gadget_impls
+    })
+}
+
 pub fn derive(
     input: DeriveInput,
     ragu_arithmetic_path: RaguArithmeticPath,
@@ -154,7 +178,7 @@ pub fn derive(
         _ => {
             return Err(Error::new(
                 Span::call_site(),
-                "Gadget derive only works on structs",
+                "Gadget derive only works on structs and enums",
             ));
         }
     };
@@ -291,24 +315,65 @@ pub fn derive(
 }
 
 #[test]
-fn test_fail_enum() {
+fn test_enum() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(Gadget)]
+        enum Shape<'my_dr, #[ragu(driver)] MyD: ragu_core::Driver<'my_dr>> {
+            Circle { radius: Element<'my_dr, MyD> },
+            Pair(Element<'my_dr, MyD>, Element<'my_dr, MyD>),
+            Empty,
+        }
+    };
+
+    let result = derive_enum(
+        input,
+        RaguArithmeticPath::default(),
+        RaguCorePath::default(),
+        RaguPrimitivesPath::default(),
+    )
+    .unwrap()
+    .to_string();
+
+    let definition = quote! {
+        struct ShapeGadget<'my_dr, MyD: ragu_core::Driver<'my_dr> > {
+            tag: ::ragu_primitives::OneHot<'my_dr, MyD, 3usize>,
+            circle_radius: Element<'my_dr, MyD>,
+            pair_0: Element<'my_dr, MyD>,
+            pair_1: Element<'my_dr, MyD>,
+        }
+    };
+    assert!(result.contains(&definition.to_string()));
+    for call in [
+        quote! { self.tag.enforce_zero_unless(dr, 0usize, &self.circle_radius)?; },
+        quote! { self.tag.enforce_zero_unless(dr, 1usize, &self.pair_0)?; },
+        quote! { self.tag.enforce_zero_unless(dr, 1usize, &self.pair_1)?; },
+    ] {
+        assert!(result.contains(&call.to_string()));
+    }
+    assert!(result.contains(
+        &quote! { impl<'my_dr, MyD: ragu_core::Driver<'my_dr> > ::ragu_core::gadgets::Gadget<'my_dr, MyD> for ShapeGadget<'my_dr, MyD> }
+            .to_string()
+    ));
+}
+
+#[test]
+fn test_fail_enum_wire_payload() {
     let input: DeriveInput = parse_quote! {
         #[derive(Gadget)]
         enum Boolean<'my_dr, #[ragu(driver)] MyD: ragu_core::Driver<'my_dr>> {
-            Is(MyD::W),
-            Not(MyD::W)
+            Is(#[ragu(wire)] MyD::W),
+            Not(#[ragu(wire)] MyD::W)
         }
     };
 
-    assert!(
-        derive(
-            input,
-            RaguArithmeticPath::default(),
-            RaguCorePath::default()
-        )
-        .is_err(),
-        "Expected error for enum usage"
-    );
+    let err = derive_enum(
+        input,
+        RaguArithmeticPath::default(),
+        RaguCorePath::default(),
+        RaguPrimitivesPath::default(),
+    )
+    .expect_err("Expected error for wire payloads");
+    assert!(err.to_string().contains("must be gadgets"));
 }
 
 #[test]
@@ -6,6 +6,7 @@ use syn::{
 };
 
 use crate::{
+    derive::tagged::TaggedUnion,
     helpers::{GenericDriver, attr_is},
     path_resolution::{RaguArithmeticPath, RaguCorePath, RaguPrimitivesPath},
     substitution::replace_driver_field_in_generic_param,
@@ -17,6 +18,15 @@ pub fn derive(
     ragu_core_path: RaguCorePath,
     ragu_primitives_path: RaguPrimitivesPath,
 ) -> Result<proc_macro2::TokenStream> {
+    if let Some(tagged) = TaggedUnion::extract(&input, &ragu_primitives_path)? {
+        return derive(
+            tagged.into_struct(),
+            ragu_arithmetic_path,
+            ragu_core_path,
+            ragu_primitives_path,
+        );
+    }
+
     let DeriveInput {
         ident: struct_ident,
         generics,
@@ -85,7 +95,7 @@ pub fn derive(
         _ => {
             return Err(Error::new(
                 Span::call_site(),
-                "GadgetEquals derive only works on structs",
+                "GadgetEquals derive only works on structs and enums",
             ));
         }
     };
@@ -0,0 +1,379 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Expr, Fields, Ident, Result, Type, parse_quote,
+    spanned::Spanned,
+};
+
+use crate::{
+    helpers::attr_is,
+    path_resolution::{RaguCorePath, RaguPcdPath, RaguPrimitivesPath},
+};
+
+/// How the innermost (non-array) type of a header field is encoded.
+enum Leaf {
+    Element,
+    Boolean,
+    Point,
+    Header,
+}
+
+impl Leaf {
+    fn from_attrs(attrs: &[Attribute], span: Span) -> Result<Self> {
+        let leaves = [
+            ("element", Leaf::Element),
+            ("boolean", Leaf::Boolean),
+            ("point", Leaf::Point),
+            ("header", Leaf::Header),
+        ];
+        let mut found = None;
+        for (name, leaf) in leaves {
+            if attrs.iter().any(|a| attr_is(a, name)) {
+                if found.is_some() {
+                    return Err(Error::new(
+                        span,
+                        "field cannot have multiple annotations; use only one of: #[ragu(element)], #[ragu(boolean)], #[ragu(point)], or #[ragu(header)]",
+                    ));
+                }
+                found = Some(leaf);
+            }
+        }
+
+        // Default to element when no annotation is present
+        Ok(found.unwrap_or(Leaf::Element))
+    }
+}
+
+/// The struct-level `#[ragu(suffix = ..., field = ...)]` options.
+struct Options {
+    suffix: Expr,
+    field: Type,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut suffix = None;
+        let mut field = None;
+        for attr in attrs.iter().filter(|a| a.path().is_ident("ragu")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("suffix") {
+                    suffix = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("field") {
+                    field = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `suffix` or `field`"))
+                }
+            })?;
+        }
+
+        Ok(Options {
+            suffix: suffix.ok_or_else(|| {
+                Error::new(
+                    Span::call_site(),
+                    "Header derive requires a suffix, e.g. #[ragu(suffix = 0)]",
+                )
+            })?,
+            field: field.unwrap_or_else(|| parse_quote!(F)),
+        })
+    }
+}
+
+pub fn derive(
+    input: DeriveInput,
+    ragu_core_path: RaguCorePath,
+    ragu_primitives_path: RaguPrimitivesPath,
+    ragu_pcd_path: RaguPcdPath,
+) -> Result<TokenStream> {
+    let DeriveInput {
+        attrs,
+        ident: struct_ident,
+        generics,
+        data,
+        ..
+    } = &input;
+
+    let Options { suffix, field } = Options::parse(attrs)?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields: Vec<(Ident, Type, Leaf)> = match data {
+        Data::Struct(s) => {
+            let fields = match &s.fields {
+                Fields::Named(named) => &named.named,
+                _ => {
+                    return Err(Error::new(
+                        s.struct_token.span(),
+                        "Header derive only works on structs with named fields",
+                    ));
+                }
+            };
+
+            let mut res = vec![];
+            for f in fields {
+                let fid = f.ident.clone().expect("fields contains only named fields");
+                let leaf = Leaf::from_attrs(&f.attrs, fid.span())?;
+                res.push((fid, f.ty.clone(), leaf));
+            }
+
+            res
+        }
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Header derive only works on structs",
+            ));
+        }
+    };
+
+    let header_trait = quote!( #ragu_pcd_path::header::Header<#field> );
+    let phantom_field = quote!( ::core::marker::PhantomData<#field> );
+    let some = quote!(::core::option::Option::Some);
+    let none = quote!(::core::option::Option::None);
+
+    // The gadget kind, encoding expression and encoded length of a (possibly
+    // array) field type, where the encoding expression turns `witness` into a
+    // `Result` of the gadget.
+    let encoding = |ty: &Type, leaf: &Leaf| -> (TokenStream, TokenStream, TokenStream) {
+        let mut lens = vec![];
+        let mut ty = ty;
+        while let Type::Array(array) = ty {
+            lens.push(&array.len);
+            ty = &array.elem;
+        }
+
+        let (mut kind, mut encode, len) = match leaf {
+            Leaf::Element => (
+                quote!( <#ragu_primitives_path::Element<'static, #phantom_field> as #ragu_core_path::gadgets::Gadget<'static, #phantom_field>>::Kind ),
+                quote!( #ragu_primitives_path::Element::alloc(dr, allocator, witness) ),
+                quote!( #some(1) ),
+            ),
+            Leaf::Boolean => (
+                quote!( <#ragu_primitives_path::Boolean<'static, #phantom_field> as #ragu_core_path::gadgets::Gadget<'static, #phantom_field>>::Kind ),
+                quote!( #ragu_primitives_path::Boolean::alloc(dr, allocator, witness) ),
+                quote!( #some(1) ),
+            ),
+            Leaf::Point => (
+                quote!( <#ragu_primitives_path::Point<'static, #phantom_field, #ty> as #ragu_core_path::gadgets::Gadget<'static, #phantom_field>>::Kind ),
+                quote!( #ragu_primitives_path::Point::alloc(dr, witness) ),
+                quote!( #some(2) ),
+            ),
+            Leaf::Header => (
+                quote!( <#ty as #ragu_pcd_path::header::Header<#field>>::Output ),
+                quote!( <#ty as #ragu_pcd_path::header::Header<#field>>::encode(dr, allocator, witness) ),
+                quote!( <#ty as #ragu_pcd_path::header::Header<#field>>::ENCODED_LEN ),
+            ),
+        };
+
+        for array_len in lens.iter().rev() {
+            kind = quote!( [::core::marker::PhantomData<#kind>; #array_len] );
+            encode =
+                quote!( #ragu_pcd_path::header::encode_array(dr, witness, |dr, witness| #encode) );
+        }
+        let len = quote! {
+            match #len {
+                #some(len) => #some(len #( * (#lens) )*),
+                #none => #none,
+            }
+        };
+
+        (kind, encode, len)
+    };
+
+    let mut kinds = vec![];
+    let mut encodes = vec![];
+    let mut lens = vec![];
+    for (id, ty, leaf) in &fields {
+        let (kind, encode, len) = encoding(ty, leaf);
+        kinds.push(kind);
+        encodes.push(quote! {
+            let #id = {
+                let witness = witness.as_ref().map(|witness| ::core::clone::Clone::clone(&witness.#id));
+                #encode?
+            };
+        });
+        lens.push(len);
+    }
+
+    // Fields are encoded in declaration order as right-nested pairs, since
+    // gadgets are only implemented for pairs.
+    let ids: Vec<&Ident> = fields.iter().map(|(id, _, _)| id).collect();
+    let (output_kind, output) = match (kinds.split_last(), ids.split_last()) {
+        (Some((last_kind, kinds)), Some((last_id, ids))) => {
+            let mut output_kind = quote!( #last_kind );
+            let mut output = quote!( #last_id );
+            for (kind, id) in kinds.iter().zip(ids).rev() {
+                output_kind = quote!( (::core::marker::PhantomData<#kind>, ::core::marker::PhantomData<#output_kind>) );
+                output = quote!( (#id, #output) );
+            }
+            (output_kind, output)
+        }
+        _ => (quote!(()), quote!(())),
+    };
+
+    let encoded_len = lens.iter().fold(quote!( #some(0) ), |acc, len| {
+        quote! {
+            match (#acc, #len) {
+                (#some(a), #some(b)) => #some(a + b),
+                _ => #none,
+            }
+        }
+    });
+
+    // Headers without fields, or with only points, leave some of the
+    // arguments of `encode` unused.
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #header_trait for #struct_ident #ty_generics #where_clause {
+            const SUFFIX: #ragu_pcd_path::header::Suffix = #ragu_pcd_path::header::Suffix::new(#suffix);
+            const ENCODED_LEN: ::core::option::Option<usize> = #encoded_len;
+            type Data = Self;
+            type Output = #output_kind;
+
+            fn encode<'dr, D: #ragu_core_path::drivers::Driver<'dr, F = #field>, A: #ragu_primitives_path::allocator::Allocator<'dr, D>>(
+                dr: &mut D,
+                allocator: &mut A,
+                witness: #ragu_core_path::drivers::DriverValue<D, Self>,
+            ) -> #ragu_core_path::Result<#ragu_core_path::gadgets::Bound<'dr, D, Self::Output>> {
+                use #ragu_core_path::maybe::Maybe as _;
+                let _ = (&dr, &allocator, &witness);
+                #( #encodes )*
+                Ok(#output)
+            }
+        }
+    })
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_header_derive() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(Header)]
+        #[ragu(suffix = 3)]
+        struct MyHeader<F: Field> {
+            value: F,
+            #[ragu(boolean)]
+            flag: bool,
+            #[ragu(header)]
+            children: [Child<F>; 2],
+        }
+    };
+
+    let result = derive(
+        input,
+        RaguCorePath::default(),
+        RaguPrimitivesPath::default(),
+        RaguPcdPath::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        result.to_string(),
+        quote!(
+            #[automatically_derived]
+            impl<F: Field> ::ragu_pcd::header::Header<F> for MyHeader<F> {
+                const SUFFIX: ::ragu_pcd::header::Suffix = ::ragu_pcd::header::Suffix::new(3);
+                const ENCODED_LEN: ::core::option::Option<usize> = match (
+                    match (
+                        match (
+                            ::core::option::Option::Some(0),
+                            match ::core::option::Option::Some(1) {
+                                ::core::option::Option::Some(len) => ::core::option::Option::Some(len),
+                                ::core::option::Option::None => ::core::option::Option::None,
+                            }
+                        ) {
+                            (::core::option::Option::Some(a), ::core::option::Option::Some(b)) => ::core::option::Option::Some(a + b),
+                            _ => ::core::option::Option::None,
+                        },
+                        match ::core::option::Option::Some(1) {
+                            ::core::option::Option::Some(len) => ::core::option::Option::Some(len),
+                            ::core::option::Option::None => ::core::option::Option::None,
+                        }
+                    ) {
+                        (::core::option::Option::Some(a), ::core::option::Option::Some(b)) => ::core::option::Option::Some(a + b),
+                        _ => ::core::option::Option::None,
+                    },
+                    match <Child<F> as ::ragu_pcd::header::Header<F>>::ENCODED_LEN {
+                        ::core::option::Option::Some(len) => ::core::option::Option::Some(len * (2)),
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                ) {
+                    (::core::option::Option::Some(a), ::core::option::Option::Some(b)) => ::core::option::Option::Some(a + b),
+                    _ => ::core::option::Option::None,
+                };
+                type Data = Self;
+                type Output = (
+                    ::core::marker::PhantomData< <::ragu_primitives::Element<'static, ::core::marker::PhantomData<F> > as ::ragu_core::gadgets::Gadget<'static, ::core::marker::PhantomData<F> >>::Kind>,
+                    ::core::marker::PhantomData<(
+                        ::core::marker::PhantomData< <::ragu_primitives::Boolean<'static, ::core::marker::PhantomData<F> > as ::ragu_core::gadgets::Gadget<'static, ::core::marker::PhantomData<F> >>::Kind>,
+                        ::core::marker::PhantomData<[::core::marker::PhantomData< <Child<F> as ::ragu_pcd::header::Header<F>>::Output>; 2]>
+                    )>
+                );
+
+                fn encode<'dr, D: ::ragu_core::drivers::Driver<'dr, F = F>, A: ::ragu_primitives::allocator::Allocator<'dr, D>>(
+                    dr: &mut D,
+                    allocator: &mut A,
+                    witness: ::ragu_core::drivers::DriverValue<D, Self>,
+                ) -> ::ragu_core::Result<::ragu_core::gadgets::Bound<'dr, D, Self::Output>> {
+                    use ::ragu_core::maybe::Maybe as _;
+                    let _ = (&dr, &allocator, &witness);
+                    let value = {
+                        let witness = witness.as_ref().map(|witness| ::core::clone::Clone::clone(&witness.value));
+                        ::ragu_primitives::Element::alloc(dr, allocator, witness)?
+                    };
+                    let flag = {
+                        let witness = witness.as_ref().map(|witness| ::core::clone::Clone::clone(&witness.flag));
+                        ::ragu_primitives::Boolean::alloc(dr, allocator, witness)?
+                    };
+                    let children = {
+                        let witness = witness.as_ref().map(|witness| ::core::clone::Clone::clone(&witness.children));
+                        ::ragu_pcd::header::encode_array(dr, witness, |dr, witness| <Child<F> as ::ragu_pcd::header::Header<F>>::encode(dr, allocator, witness))?
+                    };
+                    Ok((value, (flag, children)))
+                }
+            }
+        ).to_string()
+    );
+}
+
+#[test]
+fn test_header_derive_requires_suffix() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(Header)]
+        struct MyHeader<F: Field> {
+            value: F,
+        }
+    };
+
+    assert!(
+        derive(
+            input,
+            RaguCorePath::default(),
+            RaguPrimitivesPath::default(),
+            RaguPcdPath::default(),
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_header_derive_fail_multiple_annotations() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(Header)]
+        #[ragu(suffix = 0)]
+        struct MyHeader<F: Field> {
+            #[ragu(boolean, header)]
+            value: bool,
+        }
+    };
+
+    assert!(
+        derive(
+            input,
+            RaguCorePath::default(),
+            RaguPrimitivesPath::default(),
+            RaguPcdPath::default(),
+        )
+        .is_err()
+    );
+}
@@ -1,7 +1,10 @@
+pub mod conditionalselect;
 pub mod consistent;
 pub mod gadget;
 pub mod gadgetequals;
 pub mod gadgetwrite;
+pub mod header;
+mod tagged;
 
 use proc_macro2::Span;
 use syn::{AngleBracketedGenericArguments, GenericArgument, Lifetime, Type, parse_quote};
@@ -0,0 +1,349 @@
+//! Lowering of enums into tagged-union gadget structs.
+//!
+//! Gadgets must have the same wires for every witness, so an enum `E` of
+//! gadgets is represented in the circuit by a generated struct `EGadget`
+//! holding a one-hot tag and the payload of every variant side by side. The
+//! payloads of inactive variants are constrained to zero. Each derive lowers
+//! the enum the same way and then derives its trait for the struct, so the
+//! derives compose exactly as they do for hand-written structs.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Error, Fields, GenericParam, Generics, Ident, LitStr, Member, Result, Type,
+    Visibility, parse_quote, spanned::Spanned,
+};
+
+use crate::{
+    helpers::{GenericDriver, attr_is},
+    path_resolution::{RaguCorePath, RaguPrimitivesPath},
+};
+
+/// The fields of an enum variant, as a tagged-union struct stores them.
+struct TaggedVariant {
+    ident: Ident,
+    shape: VariantShape,
+    /// The variant's fields: how the enum names each one, the struct field
+    /// that stores it, and its type.
+    fields: Vec<(Member, Ident, Type)>,
+}
+
+enum VariantShape {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+pub struct TaggedUnion {
+    vis: Visibility,
+    enum_ident: Ident,
+    struct_ident: Ident,
+    generics: Generics,
+    driver: GenericDriver,
+    tag_ty: Type,
+    variants: Vec<TaggedVariant>,
+}
+
+fn snake_case(ident: &Ident) -> String {
+    let mut res = String::new();
+    for (i, c) in ident.to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                res.push('_');
+            }
+            res.extend(c.to_lowercase());
+        } else {
+            res.push(c);
+        }
+    }
+    res
+}
+
+impl TaggedUnion {
+    /// Lowers `input` if it is an enum, returning `None` for any other item.
+    pub fn extract(
+        input: &DeriveInput,
+        ragu_primitives_path: &RaguPrimitivesPath,
+    ) -> Result<Option<Self>> {
+        let Data::Enum(e) = &input.data else {
+            return Ok(None);
+        };
+        if e.variants.is_empty() {
+            return Err(Error::new(
+                e.enum_token.span(),
+                "tagged-union gadgets need at least one variant",
+            ));
+        }
+        if let Some(wc) = &input.generics.where_clause {
+            return Err(Error::new(
+                wc.span(),
+                "tagged-union gadgets do not yet support where clauses",
+            ));
+        }
+
+        let driver = GenericDriver::extract(&input.generics)?;
+        let mut names = HashSet::new();
+        names.insert(format_ident!("tag"));
+
+        let mut variants = vec![];
+        for variant in &e.variants {
+            let prefix = snake_case(&variant.ident);
+            let shape = match &variant.fields {
+                Fields::Named(_) => VariantShape::Named,
+                Fields::Unnamed(_) => VariantShape::Unnamed,
+                Fields::Unit => VariantShape::Unit,
+            };
+            let mut fields = vec![];
+            for (i, f) in variant.fields.iter().enumerate() {
+                if ["value", "wire", "phantom"]
+                    .iter()
+                    .any(|needle| f.attrs.iter().any(|a| attr_is(a, needle)))
+                {
+                    return Err(Error::new(
+                        f.span(),
+                        "tagged-union payload fields must be gadgets",
+                    ));
+                }
+                let (member, name) = match &f.ident {
+                    Some(ident) => (
+                        Member::Named(ident.clone()),
+                        format_ident!("{}_{}", prefix, ident),
+                    ),
+                    None => (Member::Unnamed(i.into()), format_ident!("{}_{}", prefix, i)),
+                };
+                if !names.insert(name.clone()) {
+                    return Err(Error::new(
+                        f.span(),
+                        format!("payload field `{name}` collides with another variant's field"),
+                    ));
+                }
+                fields.push((member, name, f.ty.clone()));
+            }
+            variants.push(TaggedVariant {
+                ident: variant.ident.clone(),
+                shape,
+                fields,
+            });
+        }
+
+        let num_variants = variants.len();
+        let driver_ident = &driver.ident;
+        let driver_lifetime = &driver.lifetime;
+        let tag_ty = parse_quote! {
+            #ragu_primitives_path::OneHot<#driver_lifetime, #driver_ident, #num_variants>
+        };
+
+        Ok(Some(TaggedUnion {
+            vis: input.vis.clone(),
+            enum_ident: input.ident.clone(),
+            struct_ident: format_ident!("{}Gadget", input.ident),
+            generics: input.generics.clone(),
+            driver,
+            tag_ty,
+            variants,
+        }))
+    }
+
+    fn struct_fields(&self) -> impl Iterator<Item = (&Ident, &Type)> {
+        self.variants
+            .iter()
+            .flat_map(|v| v.fields.iter().map(|(_, name, ty)| (name, ty)))
+    }
+
+    /// The generated struct, as input for the struct form of each derive.
+    pub fn into_struct(self) -> DeriveInput {
+        let TaggedUnion {
+            vis,
+            struct_ident,
+            generics,
+            tag_ty,
+            ..
+        } = &self;
+        let (names, tys): (Vec<_>, Vec<_>) = self.struct_fields().unzip();
+
+        parse_quote! {
+            #vis struct #struct_ident #generics {
+                tag: #tag_ty,
+                #( #names: #tys, )*
+            }
+        }
+    }
+
+    /// The generics with the `#[ragu(driver)]` helper attribute removed, which
+    /// is not available outside of a derive input.
+    fn stripped_generics(&self) -> Generics {
+        let mut generics = self.generics.clone();
+        generics.params.iter_mut().for_each(|gp| {
+            if let GenericParam::Type(ty) = gp {
+                ty.attrs.retain(|a| !attr_is(a, "driver"));
+            }
+        });
+        generics
+    }
+
+    /// Constraints that zero the payload of every variant other than the one
+    /// the tag selects, written against `self` and `dr`.
+    pub fn enforce_inactive_zero(&self) -> TokenStream {
+        let calls = self.variants.iter().enumerate().flat_map(|(i, v)| {
+            v.fields.iter().map(move |(_, name, _)| {
+                quote! { self.tag.enforce_zero_unless(dr, #i, &self.#name)?; }
+            })
+        });
+        quote! { #( #calls )* }
+    }
+
+    /// The definition of the generated struct and its inherent methods.
+    pub fn definition(
+        &self,
+        ragu_core_path: &RaguCorePath,
+        ragu_primitives_path: &RaguPrimitivesPath,
+    ) -> TokenStream {
+        let TaggedUnion {
+            vis,
+            enum_ident,
+            struct_ident,
+            tag_ty,
+            driver,
+            ..
+        } = self;
+        let generics = self.stripped_generics();
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+        let driver_ident = &driver.ident;
+        let driver_lifetime = &driver.lifetime;
+        let num_variants = self.variants.len();
+        let (names, tys): (Vec<_>, Vec<_>) = self.struct_fields().unzip();
+
+        let struct_doc = LitStr::new(
+            &format!(
+                "Gadget form of [`{enum_ident}`], generated by the `Gadget` derive.\n\n\
+                 A one-hot tag selects the active variant, and the payloads of all \
+                 other variants are constrained to zero. Use \
+                 [`match_variants`]({struct_ident}::match_variants) to compute a \
+                 result from the active variant."
+            ),
+            enum_ident.span(),
+        );
+        let new_doc = LitStr::new(
+            &format!(
+                "Builds the gadget from its tag and every variant of [`{enum_ident}`] \
+                 in declaration order.\n\n\
+                 The payload of each variant other than the one `tag` selects is \
+                 constrained to zero, so those variants must carry zero payloads. \
+                 Synthesis fails if `variants` is not in declaration order."
+            ),
+            enum_ident.span(),
+        );
+        let order_error = LitStr::new(
+            &format!("variants of `{enum_ident}` must be given in declaration order"),
+            enum_ident.span(),
+        );
+
+        let patterns = self.variants.iter().map(|v| {
+            let ident = &v.ident;
+            let bindings = v.fields.iter().map(|(member, name, _)| match member {
+                Member::Named(_) => quote! { #member: #name },
+                Member::Unnamed(_) => quote! { #name },
+            });
+            match v.shape {
+                VariantShape::Named => quote! { #enum_ident::#ident { #( #bindings ),* } },
+                VariantShape::Unnamed => quote! { #enum_ident::#ident( #( #bindings ),* ) },
+                VariantShape::Unit => quote! { #enum_ident::#ident },
+            }
+        });
+        let views = self.variants.iter().map(|v| {
+            let ident = &v.ident;
+            let clones = v.fields.iter().map(|(member, name, _)| {
+                quote! { #member: ::core::clone::Clone::clone(&self.#name) }
+            });
+            match v.shape {
+                VariantShape::Unit => quote! { #enum_ident::#ident },
+                _ => quote! { #enum_ident::#ident { #( #clones ),* } },
+            }
+        });
+        let view_bindings = (0..num_variants)
+            .map(|i| format_ident!("variant_{}", i))
+            .collect::<Vec<_>>();
+        let enforce_inactive_zero = self.enforce_inactive_zero();
+
+        quote! {
+            #[doc = #struct_doc]
+            #vis struct #struct_ident #generics {
+                tag: #tag_ty,
+                #( #names: #tys, )*
+            }
+
+            impl #impl_generics #struct_ident #ty_generics {
+                #[doc = #new_doc]
+                pub fn new(
+                    dr: &mut #driver_ident,
+                    tag: #tag_ty,
+                    variants: [#enum_ident #ty_generics; #num_variants],
+                ) -> #ragu_core_path::Result<Self>
+                where
+                    #( <#tys as #ragu_core_path::gadgets::Gadget<#driver_lifetime, #driver_ident>>::Kind:
+                        #ragu_primitives_path::io::Write<<#driver_ident as #ragu_core_path::drivers::Driver<#driver_lifetime>>::F>, )*
+                {
+                    #[allow(irrefutable_let_patterns)]
+                    let [#( #patterns ),*] = variants else {
+                        return Err(#ragu_core_path::Error::Initialization(#order_error.into()));
+                    };
+                    let this = Self {
+                        tag,
+                        #( #names, )*
+                    };
+                    this.enforce_inactive_zero(dr)?;
+
+                    Ok(this)
+                }
+
+                #[allow(unused_variables)]
+                fn enforce_inactive_zero(&self, dr: &mut #driver_ident) -> #ragu_core_path::Result<()>
+                where
+                    #( <#tys as #ragu_core_path::gadgets::Gadget<#driver_lifetime, #driver_ident>>::Kind:
+                        #ragu_primitives_path::io::Write<<#driver_ident as #ragu_core_path::drivers::Driver<#driver_lifetime>>::F>, )*
+                {
+                    #enforce_inactive_zero
+                    Ok(())
+                }
+
+                /// Returns the tag, whose set boolean is at the index of the
+                /// active variant.
+                pub fn tag(&self) -> &#tag_ty {
+                    &self.tag
+                }
+
+                /// Returns every variant in declaration order. Only the
+                /// active variant carries a nonzero payload.
+                pub fn variants(&self) -> [#enum_ident #ty_generics; #num_variants] {
+                    [#( #views ),*]
+                }
+
+                /// Computes `arm` for every variant and returns the result for
+                /// the active one, selected with the tag.
+                ///
+                /// Every arm is synthesized, so each must succeed for a zero
+                /// payload as well.
+                pub fn match_variants<Output: #ragu_core_path::gadgets::Gadget<#driver_lifetime, #driver_ident>>(
+                    &self,
+                    dr: &mut #driver_ident,
+                    mut arm: impl FnMut(&mut #driver_ident, #enum_ident #ty_generics) -> #ragu_core_path::Result<Output>,
+                ) -> #ragu_core_path::Result<Output>
+                where
+                    Output::Kind: #ragu_primitives_path::select::ConditionalSelect<<#driver_ident as #ragu_core_path::drivers::Driver<#driver_lifetime>>::F>,
+                {
+                    let [#( #view_bindings ),*] = self.variants();
+                    let results = [#( arm(dr, #view_bindings)? ),*];
+                    self.tag.select(dr, &results)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_snake_case() {
+    assert_eq!(snake_case(&format_ident!("Circle")), "circle");
+    assert_eq!(snake_case(&format_ident!("TwoPoints")), "two_points");
+}
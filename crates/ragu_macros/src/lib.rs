@@ -58,7 +58,17 @@ pub fn derive_gadget(input: TokenStream) -> TokenStream {
     macro_body(|| {
         let ragu_arithmetic_path = path_resolution::RaguArithmeticPath::resolve()?;
         let ragu_core_path = path_resolution::RaguCorePath::resolve()?;
-        derive::gadget::derive(input, ragu_arithmetic_path, ragu_core_path)
+        if let syn::Data::Enum(_) = input.data {
+            let ragu_primitives_path = path_resolution::RaguPrimitivesPath::resolve()?;
+            derive::gadget::derive_enum(
+                input,
+                ragu_arithmetic_path,
+                ragu_core_path,
+                ragu_primitives_path,
+            )
+        } else {
+            derive::gadget::derive(input, ragu_arithmetic_path, ragu_core_path)
+        }
     })
 }
 
@@ -122,6 +132,41 @@ pub fn derive_gadget_equals(input: TokenStream) -> TokenStream {
     })
 }
 
+#[cfg(test)]
+#[allow(unused_imports)]
+use ragu_primitives::select::ConditionalSelect as _;
+
+// Documentation for the `ConditionalSelect` derive macro is in `derive@ragu_primitives::select::ConditionalSelect`.
+#[allow(missing_docs)]
+#[proc_macro_derive(ConditionalSelect, attributes(ragu))]
+pub fn derive_conditional_select(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    macro_body(|| {
+        let ragu_arithmetic_path = path_resolution::RaguArithmeticPath::resolve()?;
+        let ragu_core_path = path_resolution::RaguCorePath::resolve()?;
+        let ragu_primitives_path = path_resolution::RaguPrimitivesPath::resolve()?;
+        derive::conditionalselect::derive(
+            input,
+            ragu_arithmetic_path,
+            ragu_core_path,
+            ragu_primitives_path,
+        )
+    })
+}
+
+// Documentation for the `Header` derive macro is in `derive@ragu_pcd::header::Header`.
+#[allow(missing_docs)]
+#[proc_macro_derive(Header, attributes(ragu))]
+pub fn derive_header(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    macro_body(|| {
+        let ragu_core_path = path_resolution::RaguCorePath::resolve()?;
+        let ragu_primitives_path = path_resolution::RaguPrimitivesPath::resolve()?;
+        let ragu_pcd_path = path_resolution::RaguPcdPath::resolve()?;
+        derive::header::derive(input, ragu_core_path, ragu_primitives_path, ragu_pcd_path)
+    })
+}
+
 #[cfg(test)]
 #[allow(unused_imports)]
 use ragu_core::maybe::MaybeCast as _;
@@ -1,4 +1,5 @@
-//! Resolving paths for `ragu_arithmetic`, `ragu_core`, and `ragu_primitives`.
+//! Resolving paths for `ragu_arithmetic`, `ragu_core`, `ragu_primitives`, and
+//! `ragu_pcd`.
 //!
 //! If the end-user invoking the procedural macro is using the `ragu` crate and
 //! not importing `ragu_core`, we need to identify the path inside `ragu` that
@@ -7,7 +8,8 @@
 //!
 //! Only `ragu_core` and `ragu_primitives` support that `ragu` umbrella-crate
 //! fallback. `ragu_arithmetic` must be present as a direct dependency of the
-//! caller, possibly renamed; its resolution does not fall back to `ragu`.
+//! caller, possibly renamed; its resolution does not fall back to `ragu`. The
+//! same holds for `ragu_pcd`, which `ragu` does not re-export.
 
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::Span;
@@ -23,6 +25,9 @@ pub struct RaguCorePath(Path);
 #[derive(Clone)]
 pub struct RaguPrimitivesPath(Path);
 
+#[derive(Clone)]
+pub struct RaguPcdPath(Path);
+
 impl ToTokens for RaguArithmeticPath {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         self.0.to_tokens(tokens)
@@ -41,6 +46,12 @@ impl ToTokens for RaguPrimitivesPath {
     }
 }
 
+impl ToTokens for RaguPcdPath {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
 impl Default for RaguArithmeticPath {
     fn default() -> Self {
         Self(parse_quote! { ::ragu_arithmetic })
@@ -59,6 +70,12 @@ impl Default for RaguPrimitivesPath {
     }
 }
 
+impl Default for RaguPcdPath {
+    fn default() -> Self {
+        Self(parse_quote! { ::ragu_pcd })
+    }
+}
+
 fn ragu_arithmetic_path() -> Result<Path> {
     Ok(match crate_name("ragu_arithmetic") {
         Ok(FoundCrate::Itself) => parse_quote! { ::ragu_arithmetic },
@@ -113,6 +130,22 @@ fn ragu_primitives_path() -> Result<Path> {
     })
 }
 
+fn ragu_pcd_path() -> Result<Path> {
+    Ok(match crate_name("ragu_pcd") {
+        Ok(FoundCrate::Itself) => parse_quote! { ::ragu_pcd },
+        Ok(FoundCrate::Name(name)) => {
+            let name: Ident = format_ident!("{}", name);
+            parse_quote! { ::#name }
+        }
+        Err(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Failed to find ragu_pcd crate. Ensure it is included in your Cargo.toml.",
+            ));
+        }
+    })
+}
+
 impl RaguArithmeticPath {
     pub fn resolve() -> Result<Self> {
         ragu_arithmetic_path().map(Self)
@@ -130,3 +163,9 @@ impl RaguPrimitivesPath {
         ragu_primitives_path().map(Self)
     }
 }
+
+impl RaguPcdPath {
+    pub fn resolve() -> Result<Self> {
+        ragu_pcd_path().map(Self)
+    }
+}
@@ -9,6 +9,8 @@ use std::{
 
 use ragu_arithmetic::{ff::PrimeField, pasta_curves::arithmetic::CurveAffine};
 
+// The build script only serializes the generators, not their tables.
+#[allow(dead_code)]
 mod common {
     include!("pasta_common.rs");
 }
@@ -37,12 +39,30 @@ fn main() {
         return;
     }
 
+    // The number of baked generators can be raised for applications with a
+    // larger rank; see `Pasta::baked_for`.
+    println!("cargo:rerun-if-env-changed=RAGU_PASTA_BAKED_K");
+    let (ep_k, eq_k) = match env::var("RAGU_PASTA_BAKED_K") {
+        Ok(k) => {
+            let k: usize = k.parse().expect("RAGU_PASTA_BAKED_K must be an integer");
+            assert!(
+                (common::DEFAULT_EP_K..=28).contains(&k),
+                "RAGU_PASTA_BAKED_K must be between {} and 28",
+                common::DEFAULT_EP_K
+            );
+            (k, k)
+        }
+        Err(_) => (common::DEFAULT_EP_K, common::DEFAULT_EQ_K),
+    };
+
     let out_dir = env::var("OUT_DIR").unwrap();
     let out_path = Path::new(&out_dir).join("pasta_parameters.bin");
 
-    let params = common::PastaParams::generate();
+    let params = common::PastaParams::generate_with(ep_k, eq_k);
 
     let mut f = File::create(out_path).unwrap();
+    f.write_all(&(ep_k as u32).to_le_bytes()).unwrap();
+    f.write_all(&(eq_k as u32).to_le_bytes()).unwrap();
     write_params_for_curve(&mut f, &params.pallas.g, &params.pallas.h).unwrap();
     write_params_for_curve(&mut f, &params.vesta.g, &params.vesta.h).unwrap();
 }
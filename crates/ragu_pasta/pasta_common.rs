@@ -1,4 +1,4 @@
-use ragu_arithmetic::CurveExt;
+use ragu_arithmetic::{CurveExt, FixedBaseTable};
 use ragu_arithmetic::group::Curve;
 use ragu_arithmetic::pasta_curves::{
     EpAffine,
@@ -25,15 +25,17 @@ pub struct PastaParams {
 pub struct PallasGenerators {
     pub(crate) g: Vec<EpAffine>,
     pub(crate) h: EpAffine,
+    pub(crate) table: Option<FixedBaseTable<EpAffine>>,
 }
 
 /// Fixed generators for the Vesta curve.
 pub struct VestaGenerators {
     pub(crate) g: Vec<EqAffine>,
     pub(crate) h: EqAffine,
+    pub(crate) table: Option<FixedBaseTable<EqAffine>>,
 }
 
-fn params_for_curve<C: CurveExt>(n: usize) -> (Vec<C::AffineExt>, C::AffineExt) {
+pub(crate) fn params_for_curve<C: CurveExt>(n: usize) -> (Vec<C::AffineExt>, C::AffineExt) {
     let g_projective = {
         let hasher = C::hash_to_curve(DOMAIN_PREFIX);
         let mut g = Vec::with_capacity(n);
@@ -56,17 +58,26 @@ fn params_for_curve<C: CurveExt>(n: usize) -> (Vec<C::AffineExt>, C::AffineExt)
 impl PastaParams {
     /// Generate Pasta parameters at runtime via hash-to-curve.
     pub(crate) fn generate() -> Self {
-        let (ep_g, ep_h) = params_for_curve::<Ep>(1usize << DEFAULT_EP_K);
-        let (eq_g, eq_h) = params_for_curve::<Eq>(1usize << DEFAULT_EQ_K);
+        Self::generate_with(DEFAULT_EP_K, DEFAULT_EQ_K)
+    }
+
+    /// Generate Pasta parameters with $2^{ep\_k}$ Pallas and $2^{eq\_k}$
+    /// Vesta generators. The generators of a smaller set are a prefix of
+    /// those of a larger one.
+    pub(crate) fn generate_with(ep_k: usize, eq_k: usize) -> Self {
+        let (ep_g, ep_h) = params_for_curve::<Ep>(1usize << ep_k);
+        let (eq_g, eq_h) = params_for_curve::<Eq>(1usize << eq_k);
 
         PastaParams {
             pallas: PallasGenerators {
                 g: ep_g,
                 h: ep_h,
+                table: None,
             },
             vesta: VestaGenerators {
                 g: eq_g,
                 h: eq_h,
+                table: None,
             }
         }
     }
@@ -0,0 +1,351 @@
+//! Runtime serialization of [`PastaParams`].
+
+use alloc::vec::Vec;
+
+use ragu_arithmetic::{
+    ff::PrimeField,
+    pasta_curves::{Ep, Eq, arithmetic::CurveAffine},
+};
+
+use super::{PallasGenerators, PastaParams, VestaGenerators, common::params_for_curve};
+
+const MAGIC: &[u8; 8] = b"ragupsta";
+const PALLAS_ID: u32 = 0;
+const VESTA_ID: u32 = 1;
+const POINT_BYTES: usize = 64;
+const DIGEST_BYTES: usize = 64;
+
+/// How thoroughly [`PastaParams::from_bytes`] validates the generators it
+/// reads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Validation {
+    /// Check the digest of each curve's generators, and that every point is
+    /// on its curve.
+    Digest,
+
+    /// Additionally check that the generators are exactly those produced by
+    /// [`PastaParams::generate_for`], by recomputing them via hash-to-curve.
+    /// This is as slow as generating the parameters in the first place.
+    Strict,
+}
+
+/// Errors that can occur while decoding [`PastaParams`].
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ParamsError {
+    /// The data does not start with the expected magic bytes.
+    #[error("not an encoding of Pasta parameters")]
+    BadMagic,
+
+    /// The data ended before all of the parameters were read.
+    #[error("Pasta parameters are truncated")]
+    Truncated,
+
+    /// The data continues after all of the parameters were read.
+    #[error("unexpected trailing bytes after Pasta parameters")]
+    TrailingBytes,
+
+    /// A section holds generators for an unexpected curve.
+    #[error("expected generators for curve {expected}, found curve {found}")]
+    WrongCurve {
+        /// The expected curve id.
+        expected: u32,
+        /// The curve id found in the section header.
+        found: u32,
+    },
+
+    /// The digest of a curve's generators does not match its header.
+    #[error("digest mismatch for the {curve} generators")]
+    DigestMismatch {
+        /// The name of the curve.
+        curve: &'static str,
+    },
+
+    /// A point is not a valid, non-identity point on its curve.
+    #[error("invalid {curve} point at index {index}")]
+    InvalidPoint {
+        /// The name of the curve.
+        curve: &'static str,
+        /// The index of the point, where `h` follows the `g` generators.
+        index: usize,
+    },
+
+    /// The generators differ from those recomputed via hash-to-curve.
+    #[error("the {curve} generators do not match hash-to-curve")]
+    NotCanonical {
+        /// The name of the curve.
+        curve: &'static str,
+    },
+
+    /// Reading the parameters failed.
+    #[cfg(feature = "std")]
+    #[error("failed to read Pasta parameters: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+fn digest(points: &[u8]) -> blake2b_simd::Hash {
+    blake2b_simd::Params::new()
+        .personal(b"ragu_params_____")
+        .hash(points)
+}
+
+fn write_curve<C: CurveAffine>(out: &mut Vec<u8>, id: u32, g: &[C], h: &C) {
+    let count = u32::try_from(g.len()).expect("too many generators to encode");
+    out.extend_from_slice(&id.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+
+    let digest_at = out.len();
+    out.resize(digest_at + DIGEST_BYTES, 0);
+    for point in g.iter().chain([h]) {
+        let xy = point
+            .coordinates()
+            .expect("generators are never the identity");
+        out.extend_from_slice(xy.x().to_repr().as_ref());
+        out.extend_from_slice(xy.y().to_repr().as_ref());
+    }
+
+    let hash = digest(&out[digest_at + DIGEST_BYTES..]);
+    out[digest_at..digest_at + DIGEST_BYTES].copy_from_slice(hash.as_bytes());
+}
+
+fn take<'a>(source: &mut &'a [u8], n: usize) -> Result<&'a [u8], ParamsError> {
+    if source.len() < n {
+        return Err(ParamsError::Truncated);
+    }
+    let (head, tail) = source.split_at(n);
+    *source = tail;
+    Ok(head)
+}
+
+fn take_u32(source: &mut &[u8]) -> Result<u32, ParamsError> {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(take(source, 4)?);
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_curve<C: CurveAffine>(
+    source: &mut &[u8],
+    id: u32,
+    curve: &'static str,
+) -> Result<(Vec<C>, C), ParamsError> {
+    let found = take_u32(source)?;
+    if found != id {
+        return Err(ParamsError::WrongCurve {
+            expected: id,
+            found,
+        });
+    }
+    let count = take_u32(source)? as usize;
+    let expected = take(source, DIGEST_BYTES)?;
+    let len = count
+        .checked_add(1)
+        .and_then(|n| n.checked_mul(POINT_BYTES))
+        .ok_or(ParamsError::Truncated)?;
+    let points = take(source, len)?;
+    if digest(points).as_bytes() != expected {
+        return Err(ParamsError::DigestMismatch { curve });
+    }
+
+    let mut g = points
+        .chunks_exact(POINT_BYTES)
+        .enumerate()
+        .map(|(index, bytes)| {
+            let coordinate = |bytes: &[u8]| {
+                let mut repr = <C::Base as PrimeField>::Repr::default();
+                repr.as_mut().copy_from_slice(bytes);
+                Option::<C::Base>::from(C::Base::from_repr(repr))
+            };
+            let (x, y) = bytes.split_at(POINT_BYTES / 2);
+            coordinate(x)
+                .zip(coordinate(y))
+                .and_then(|(x, y)| Option::from(C::from_xy(x, y)))
+                .ok_or(ParamsError::InvalidPoint { curve, index })
+        })
+        .collect::<Result<Vec<C>, _>>()?;
+    let h = g.pop().expect("h always follows the generators");
+
+    Ok((g, h))
+}
+
+impl PastaParams {
+    /// Encodes these parameters, without any fixed-base tables.
+    ///
+    /// The encoding is the magic bytes `ragupsta`, followed by a section for
+    /// each of the Pallas and Vesta curves (in that order). A section consists
+    /// of a header holding
+    ///
+    /// - the curve id (`0` for Pallas, `1` for Vesta) as a little-endian
+    ///   `u32`,
+    /// - the number of `g` generators as a little-endian `u32`, and
+    /// - the 64-byte BLAKE2b digest of the encoded generators,
+    ///
+    /// followed by the `g` generators and then `h`, each encoded as the
+    /// 32-byte representations of its $x$ and $y$ coordinates. This is the
+    /// same point layout used by the `baked` parameters.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(
+            MAGIC.len()
+                + 2 * (8 + DIGEST_BYTES)
+                + (self.pallas.g.len() + self.vesta.g.len() + 2) * POINT_BYTES,
+        );
+        out.extend_from_slice(MAGIC);
+        write_curve(&mut out, PALLAS_ID, &self.pallas.g, &self.pallas.h);
+        write_curve(&mut out, VESTA_ID, &self.vesta.g, &self.vesta.h);
+        out
+    }
+
+    /// Decodes parameters produced by [`to_bytes`](Self::to_bytes), such as
+    /// the contents of a memory-mapped file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParamsError`] if `bytes` is malformed or fails the
+    /// requested [`Validation`].
+    pub fn from_bytes(mut bytes: &[u8], validation: Validation) -> Result<Self, ParamsError> {
+        if take(&mut bytes, MAGIC.len())? != MAGIC {
+            return Err(ParamsError::BadMagic);
+        }
+        let (ep_g, ep_h) = read_curve(&mut bytes, PALLAS_ID, "Pallas")?;
+        let (eq_g, eq_h) = read_curve(&mut bytes, VESTA_ID, "Vesta")?;
+        if !bytes.is_empty() {
+            return Err(ParamsError::TrailingBytes);
+        }
+
+        if validation == Validation::Strict {
+            let (g, h) = params_for_curve::<Ep>(ep_g.len());
+            if g != ep_g || h != ep_h {
+                return Err(ParamsError::NotCanonical { curve: "Pallas" });
+            }
+            let (g, h) = params_for_curve::<Eq>(eq_g.len());
+            if g != eq_g || h != eq_h {
+                return Err(ParamsError::NotCanonical { curve: "Vesta" });
+            }
+        }
+
+        Ok(PastaParams {
+            pallas: PallasGenerators {
+                g: ep_g,
+                h: ep_h,
+                table: None,
+            },
+            vesta: VestaGenerators {
+                g: eq_g,
+                h: eq_h,
+                table: None,
+            },
+        })
+    }
+
+    /// Writes these parameters to `writer` in the format of
+    /// [`to_bytes`](Self::to_bytes).
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Reads parameters written by [`write_to`](Self::write_to) from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParamsError`] if reading fails, or for the same reasons as
+    /// [`from_bytes`](Self::from_bytes).
+    #[cfg(feature = "std")]
+    pub fn read_from<R: std::io::Read>(
+        mut reader: R,
+        validation: Validation,
+    ) -> Result<Self, ParamsError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes, validation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ragu_arithmetic::pasta_curves::EpAffine;
+
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let params = PastaParams::generate_for(4);
+        let bytes = params.to_bytes();
+        assert_eq!(bytes.len(), 8 + 2 * (8 + 64) + 2 * 17 * 64);
+
+        for validation in [Validation::Digest, Validation::Strict] {
+            let decoded = PastaParams::from_bytes(&bytes, validation).unwrap();
+            assert_eq!(decoded.pallas.g, params.pallas.g);
+            assert_eq!(decoded.pallas.h, params.pallas.h);
+            assert_eq!(decoded.vesta.g, params.vesta.g);
+            assert_eq!(decoded.vesta.h, params.vesta.h);
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        let bytes = PastaParams::generate_for(3).to_bytes();
+        let decode = |bytes: &[u8]| PastaParams::from_bytes(bytes, Validation::Digest);
+
+        let mut bad = bytes.clone();
+        bad[0] ^= 1;
+        assert!(matches!(decode(&bad), Err(ParamsError::BadMagic)));
+
+        assert!(matches!(
+            decode(&bytes[..bytes.len() - 1]),
+            Err(ParamsError::Truncated)
+        ));
+
+        let mut bad = bytes.clone();
+        bad.push(0);
+        assert!(matches!(decode(&bad), Err(ParamsError::TrailingBytes)));
+
+        let mut bad = bytes.clone();
+        bad[8] = 1;
+        assert!(matches!(
+            decode(&bad),
+            Err(ParamsError::WrongCurve {
+                expected: 0,
+                found: 1
+            })
+        ));
+
+        // Corrupt the x coordinate of the first Vesta generator.
+        let vesta_points = 8 + (8 + 64) + 9 * 64 + (8 + 64);
+        let mut bad = bytes.clone();
+        bad[vesta_points] ^= 1;
+        assert!(matches!(
+            decode(&bad),
+            Err(ParamsError::DigestMismatch { curve: "Vesta" })
+        ));
+    }
+
+    #[test]
+    fn test_validation() {
+        // Rewrite the digest over points that are valid but not canonical.
+        let mut params = PastaParams::generate_for(3);
+        params.pallas.g.swap(0, 1);
+        let bytes = params.to_bytes();
+
+        assert!(PastaParams::from_bytes(&bytes, Validation::Digest).is_ok());
+        assert!(matches!(
+            PastaParams::from_bytes(&bytes, Validation::Strict),
+            Err(ParamsError::NotCanonical { curve: "Pallas" })
+        ));
+
+        // Points off the curve are caught even with a correct digest.
+        let mut bytes = Vec::new();
+        write_curve::<EpAffine>(&mut bytes, PALLAS_ID, &[], &params.pallas.h);
+        let points = bytes.len() - POINT_BYTES;
+        bytes[points + 32] ^= 1;
+        let hash = digest(&bytes[points..]);
+        bytes[points - DIGEST_BYTES..points].copy_from_slice(hash.as_bytes());
+        assert!(matches!(
+            read_curve::<EpAffine>(&mut &bytes[..], PALLAS_ID, "Pallas"),
+            Err(ParamsError::InvalidPoint {
+                curve: "Pallas",
+                index: 0
+            })
+        ));
+    }
+}
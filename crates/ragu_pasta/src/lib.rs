@@ -16,6 +16,21 @@
 //! substantially cheaper than regenerating via [`Pasta::generate`], at the
 //! expense of a larger binary size.
 //!
+//! ## Fixed-Base Tables
+//!
+//! Commitments are multiscalar multiplications against the same generators,
+//! which can be accelerated by precomputing multiples of each generator.
+//! [`PastaParams::with_tables`] builds these tables for both curves, trading
+//! memory for speed according to a [`TableConfig`]. With the `baked` feature,
+//! [`Pasta::baked_with_tables`] builds them with the default configuration on
+//! first access. Commitments, and thus proofs, are identical with and without
+//! tables.
+//!
+//! Parameters can also be generated once and stored, for example in a file
+//! that is read or memory-mapped at startup: [`PastaParams::to_bytes`] encodes
+//! them along with a digest of the generators, which
+//! [`PastaParams::from_bytes`] checks according to a [`Validation`] mode.
+//!
 //! For configuration guidance, see the
 //! [Configuration](https://tachyon.z.cash/ragu/guide/configuration.html) chapter
 //! in the Ragu book.
@@ -31,6 +46,8 @@
 #[cfg(not(feature = "alloc"))]
 compile_error!("`ragu_pasta` requires the `alloc` feature to be enabled.");
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[macro_use]
 mod macros;
@@ -39,14 +56,17 @@ mod common {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/pasta_common.rs"));
 }
 
+mod encoding;
 mod poseidon_fp;
 mod poseidon_fq;
 
 pub use common::{PallasGenerators, PastaParams, VestaGenerators};
+pub use encoding::{ParamsError, Validation};
 pub use poseidon_fp::PoseidonFp;
 pub use poseidon_fq::PoseidonFq;
+pub use ragu_arithmetic::TableConfig;
 pub use ragu_arithmetic::pasta_curves::{Ep, EpAffine, Eq, EqAffine, Fp, Fq};
-use ragu_arithmetic::{Cycle, FixedGenerators};
+use ragu_arithmetic::{Cycle, FixedBaseTable, FixedGenerators};
 
 /// Zero-sized marker type for the [Pasta
 /// curve](https://electriccoin.co/blog/the-pasta-curves-for-halo-2-and-beyond/)
@@ -91,6 +111,44 @@ impl Cycle for Pasta {
     fn generate() -> Self::Params {
         PastaParams::generate()
     }
+
+    fn generate_for(k: u32) -> Self::Params {
+        PastaParams::generate_for(k)
+    }
+}
+
+impl PastaParams {
+    /// Generates parameters at runtime with $2^k$ generators for each curve,
+    /// enough to commit to polynomials of a rank of $k$.
+    ///
+    /// For a `Rank` type `R` from `ragu_circuits`, this is what
+    /// `R::generate_params::<Pasta>()` returns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if $k$ exceeds 28, the largest supported rank.
+    pub fn generate_for(k: u32) -> Self {
+        assert!(k <= 28, "cannot generate 2^{} generators", k);
+        Self::generate_with(k as usize, k as usize)
+    }
+
+    /// Precomputes fixed-base tables for the `g` generators of both curves,
+    /// which are then used by
+    /// [`FixedGenerators::commit`](ragu_arithmetic::FixedGenerators::commit).
+    ///
+    /// Any existing tables are replaced. See [`TableConfig`] for the
+    /// memory/speed tradeoff.
+    pub fn with_tables(mut self, config: TableConfig) -> Self {
+        self.pallas.table = Some(FixedBaseTable::new(&self.pallas.g, config));
+        self.vesta.table = Some(FixedBaseTable::new(&self.vesta.g, config));
+        self
+    }
+
+    /// Returns the configuration of the fixed-base tables, if they have been
+    /// precomputed.
+    pub fn table_config(&self) -> Option<TableConfig> {
+        self.vesta.table.as_ref().map(FixedBaseTable::config)
+    }
 }
 
 impl FixedGenerators<ragu_arithmetic::pasta_curves::EpAffine> for PallasGenerators {
@@ -101,6 +159,10 @@ impl FixedGenerators<ragu_arithmetic::pasta_curves::EpAffine> for PallasGenerato
     fn h(&self) -> &ragu_arithmetic::pasta_curves::EpAffine {
         &self.h
     }
+
+    fn table(&self) -> Option<&FixedBaseTable<ragu_arithmetic::pasta_curves::EpAffine>> {
+        self.table.as_ref()
+    }
 }
 
 impl FixedGenerators<ragu_arithmetic::pasta_curves::EqAffine> for VestaGenerators {
@@ -111,6 +173,10 @@ impl FixedGenerators<ragu_arithmetic::pasta_curves::EqAffine> for VestaGenerator
     fn h(&self) -> &ragu_arithmetic::pasta_curves::EqAffine {
         &self.h
     }
+
+    fn table(&self) -> Option<&FixedBaseTable<ragu_arithmetic::pasta_curves::EqAffine>> {
+        self.table.as_ref()
+    }
 }
 
 #[cfg(feature = "baked")]
@@ -120,7 +186,7 @@ mod baked {
     use lazy_static::lazy_static;
     use ragu_arithmetic::{ff::PrimeField, pasta_curves::arithmetic::CurveAffine};
 
-    use super::{PallasGenerators, Pasta, PastaParams, VestaGenerators};
+    use super::{PallasGenerators, Pasta, PastaParams, TableConfig, VestaGenerators};
 
     const RAW_PARAMETERS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/pasta_parameters.bin"));
 
@@ -147,20 +213,42 @@ mod baked {
         (g, h)
     }
 
-    lazy_static! {
-        static ref PASTA_PARAMETERS: PastaParams = {
-            let mut params = RAW_PARAMETERS;
+    fn get_k(source: &mut &[u8]) -> usize {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&source[0..4]);
+        *source = &source[4..];
 
-            let (ep_g, ep_h) = get_points_for_curve(&mut params, 1 << crate::common::DEFAULT_EP_K);
-            let (eq_g, eq_h) = get_points_for_curve(&mut params, 1 << crate::common::DEFAULT_EQ_K);
+        u32::from_le_bytes(bytes) as usize
+    }
 
-            assert_eq!(params.len(), 0);
+    fn load() -> PastaParams {
+        let mut params = RAW_PARAMETERS;
+
+        let ep_k = get_k(&mut params);
+        let eq_k = get_k(&mut params);
+        let (ep_g, ep_h) = get_points_for_curve(&mut params, 1 << ep_k);
+        let (eq_g, eq_h) = get_points_for_curve(&mut params, 1 << eq_k);
+
+        assert_eq!(params.len(), 0);
+
+        PastaParams {
+            pallas: PallasGenerators {
+                g: ep_g,
+                h: ep_h,
+                table: None,
+            },
+            vesta: VestaGenerators {
+                g: eq_g,
+                h: eq_h,
+                table: None,
+            },
+        }
+    }
 
-            PastaParams {
-                pallas: PallasGenerators { g: ep_g, h: ep_h },
-                vesta: VestaGenerators { g: eq_g, h: eq_h },
-            }
-        };
+    lazy_static! {
+        static ref PASTA_PARAMETERS: PastaParams = load();
+        static ref PASTA_PARAMETERS_WITH_TABLES: PastaParams =
+            load().with_tables(TableConfig::default());
     }
 
     impl Pasta {
@@ -168,6 +256,30 @@ mod baked {
         pub fn baked() -> &'static PastaParams {
             &PASTA_PARAMETERS
         }
+
+        /// Returns the baked-in parameters if they have at least $2^k$
+        /// generators for each curve, as needed by a rank of $k$.
+        ///
+        /// The build script bakes $2^{13}$ generators by default, enough for
+        /// `ProductionRank`. Set the `RAGU_PASTA_BAKED_K` environment variable
+        /// at build time to bake more; otherwise use
+        /// [`PastaParams::generate_for`] for larger ranks.
+        pub fn baked_for(k: u32) -> Option<&'static PastaParams> {
+            let params = Self::baked();
+            let fits = |len: usize| k < usize::BITS && len >= 1 << k;
+            (fits(params.pallas.g.len()) && fits(params.vesta.g.len())).then_some(params)
+        }
+
+        /// Returns a static reference to the baked-in parameters for the Pasta
+        /// cycle, with fixed-base tables built using the default
+        /// [`TableConfig`] on first access.
+        ///
+        /// The tables occupy tens of megabytes and take a while to compute, so
+        /// this is only worthwhile for long-running provers. Use
+        /// [`PastaParams::with_tables`] for other configurations.
+        pub fn baked_with_tables() -> &'static PastaParams {
+            &PASTA_PARAMETERS_WITH_TABLES
+        }
     }
 
     #[test]
@@ -176,23 +288,22 @@ mod baked {
 
         let params = Pasta::baked();
 
-        assert_eq!(
-            Pasta::nested_generators(params).g().len(),
-            1 << crate::common::DEFAULT_EP_K
-        );
-        assert_eq!(
-            Pasta::host_generators(params).g().len(),
-            1 << crate::common::DEFAULT_EQ_K
-        );
-
+        // Unless more were baked with `RAGU_PASTA_BAKED_K`, the baked
+        // parameters are exactly those of `Pasta::generate`.
         let regenerated = Pasta::generate();
+        let (ep_n, eq_n) = (
+            Pasta::nested_generators(&regenerated).g().len(),
+            Pasta::host_generators(&regenerated).g().len(),
+        );
+        assert_eq!(ep_n, 1 << crate::common::DEFAULT_EP_K);
+        assert_eq!(eq_n, 1 << crate::common::DEFAULT_EQ_K);
 
         assert_eq!(
-            Pasta::nested_generators(params).g(),
+            &Pasta::nested_generators(params).g()[..ep_n],
             Pasta::nested_generators(&regenerated).g()
         );
         assert_eq!(
-            Pasta::host_generators(params).g(),
+            &Pasta::host_generators(params).g()[..eq_n],
             Pasta::host_generators(&regenerated).g()
         );
         assert_eq!(
@@ -204,4 +315,80 @@ mod baked {
             Pasta::host_generators(&regenerated).h()
         );
     }
+
+    #[test]
+    fn test_baked_for() {
+        let k = crate::common::DEFAULT_EQ_K as u32;
+        assert!(core::ptr::eq(Pasta::baked_for(7).unwrap(), Pasta::baked()));
+        assert!(core::ptr::eq(Pasta::baked_for(k).unwrap(), Pasta::baked()));
+        assert!(Pasta::baked_for(29).is_none());
+        assert!(Pasta::baked_for(64).is_none());
+        assert!(Pasta::baked_for(u32::MAX).is_none());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use ragu_arithmetic::{
+        FixedGenerators, TableConfig,
+        ff::{Field, PrimeField},
+        group::{Curve, prime::PrimeCurveAffine},
+    };
+
+    use super::{EpAffine, EqAffine, Fp, Fq, PallasGenerators, PastaParams, VestaGenerators};
+
+    #[test]
+    fn test_tables_match_commitments() {
+        let params = PastaParams {
+            pallas: PallasGenerators {
+                g: (1..=64u64)
+                    .map(|i| (EpAffine::generator() * Fq::from(i)).to_affine())
+                    .collect(),
+                h: EpAffine::generator(),
+                table: None,
+            },
+            vesta: VestaGenerators {
+                g: (1..=64u64)
+                    .map(|i| (EqAffine::generator() * Fp::from(i)).to_affine())
+                    .collect(),
+                h: EqAffine::generator(),
+                table: None,
+            },
+        };
+        let fp: Vec<Fp> = (0..64u64).map(|i| Fp::DELTA.pow([i]) - Fp::ONE).collect();
+        let fq: Vec<Fq> = (0..64u64).map(|i| Fq::DELTA.pow([i]) - Fq::ONE).collect();
+        let expected = (
+            params.pallas.commit(fq.iter().enumerate()),
+            params.vesta.commit(fp.iter().enumerate().skip(3)),
+        );
+        assert_eq!(params.table_config(), None);
+
+        let config = TableConfig {
+            window_bits: 5,
+            spacing: 3,
+        };
+        let params = params.with_tables(config);
+        assert_eq!(params.table_config(), Some(config));
+        assert_eq!(
+            (
+                params.pallas.commit(fq.iter().enumerate()),
+                params.vesta.commit(fp.iter().enumerate().skip(3)),
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_generate_for() {
+        let params = PastaParams::generate_for(7);
+        assert_eq!(params.pallas.g().len(), 128);
+        assert_eq!(params.vesta.g().len(), 128);
+
+        let regenerated = PastaParams::generate_for(8);
+        assert_eq!(params.pallas.g(), &regenerated.pallas.g()[..128]);
+        assert_eq!(params.vesta.g(), &regenerated.vesta.g()[..128]);
+        assert_eq!(params.vesta.h(), regenerated.vesta.h());
+    }
 }
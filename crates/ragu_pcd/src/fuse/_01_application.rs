@@ -1,52 +1,32 @@
-//! Evaluate the [`Step`] circuit.
+//! Assemble the [`Step`](crate::step::Step) circuit's trace.
 //!
-//! This creates a witness for the step circuit given the two input [`Pcd`]s and
-//! the step witness. This sets the application fields on the [`ProofBuilder`]
-//! and returns the child proofs along with the output data from the step circuit.
+//! This assembles the witness of the step circuit, computed by
+//! [`Application::trace_step`] from the data of the two input [`Pcd`](crate::Pcd)s
+//! and the step witness, and sets the application fields on the
+//! [`ProofBuilder`].
 
 use ragu_arithmetic::{CryptoRngCore, Cycle};
-use ragu_circuits::{CircuitExt, polynomials::Rank};
+use ragu_circuits::polynomials::Rank;
 use ragu_core::Result;
 
-use crate::{
-    Application, Header, Pcd, Proof,
-    proof::ProofBuilder,
-    step::{Step, internal::adapter::Adapter},
-};
+use crate::{Application, StepTrace, proof::ProofBuilder};
 
 impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_SIZE> {
-    pub(super) fn compute_application_proof<'source, RNG: CryptoRngCore, S: Step<C>>(
+    pub(super) fn compute_application_proof<RNG: CryptoRngCore>(
         &self,
         rng: &mut RNG,
-        step: S,
-        witness: S::Witness<'source>,
-        left: Pcd<C, R, S::Left>,
-        right: Pcd<C, R, S::Right>,
+        trace: StepTrace<C>,
         builder: &mut ProofBuilder<'_, C, R>,
-    ) -> Result<(
-        Proof<C, R>,
-        Proof<C, R>,
-        <S::Output as Header<C::CircuitField>>::Data,
-        S::Aux<'source>,
-    )> {
-        let (left_proof, left_data) = left.into_parts();
-        let (right_proof, right_data) = right.into_parts();
-        let (trace, aux) = Adapter::<C, S, R, HEADER_SIZE>::new(step)
-            .trace((left_data, right_data, witness))?
-            .into_parts();
-        let rx = self.native_registry.assemble(
-            &trace,
-            S::INDEX.circuit_index(self.num_application_steps)?,
-            &mut *rng,
-        )?;
+    ) -> Result<()> {
+        let rx = self
+            .native_registry
+            .assemble(&trace.trace, trace.circuit_id, &mut *rng)?;
 
-        let ((left_header, right_header), output_data, step_aux) = aux;
-
-        builder.set_circuit_id(S::INDEX.circuit_index(self.num_application_steps)?);
-        builder.set_left_header(left_header.into_inner());
-        builder.set_right_header(right_header.into_inner());
+        builder.set_circuit_id(trace.circuit_id);
+        builder.set_left_header(trace.left_header);
+        builder.set_right_header(trace.right_header);
         builder.set_native_application_rx(rx);
 
-        Ok((left_proof, right_proof, output_data, step_aux))
+        Ok(())
     }
 }
@@ -52,6 +52,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         >::new(
             self.params,
             total_circuit_counts(self.num_application_steps).1,
+            &self.transcript_tag,
         )
         .trace(native::circuits::hashes_1::Witness {
             unified,
@@ -19,11 +19,12 @@ pub(crate) mod claims;
 use claims::FuseProofSource;
 use ragu_arithmetic::{CryptoRngCore, Cycle, ff::Field};
 use ragu_circuits::polynomials::{Rank, sparse};
-use ragu_core::{Result, drivers::emulator::Emulator, maybe::Maybe};
+use ragu_core::{Error, Result, drivers::emulator::Emulator, maybe::Maybe};
 use ragu_primitives::{GadgetExt, Point, vec::CollectFixed};
 
 use crate::{
-    Application, Pcd, RAGU_TAG, internal::transcript::Transcript, proof::ProofBuilder, step::Step,
+    Application, Header, Pcd, Proof, StepTrace, internal::transcript::Transcript,
+    proof::ProofBuilder, step::Step, trace::encode_header,
 };
 
 /// Ephemeral native-field data for $f(X)$, used only during the fuse step.
@@ -74,13 +75,87 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         left: Pcd<C, R, S::Left>,
         right: Pcd<C, R, S::Right>,
     ) -> Result<(Pcd<C, R, S::Output>, S::Aux<'source>)> {
+        let (left, left_data) = left.into_parts();
+        let (right, right_data) = right.into_parts();
+        let (trace, application_data, application_aux) =
+            self.trace_step(step, witness, left_data, right_data)?;
+
+        let proof = self.prove(rng, trace, left, right)?;
+
+        Ok((proof.carry(application_data), application_aux))
+    }
+
+    /// Fuse two [`Pcd`] into one using a [`StepTrace`] of a [`Step`],
+    /// previously computed by [`Application::trace_step`] (possibly in another
+    /// process).
+    ///
+    /// This behaves like [`Application::fuse`], except that the step's
+    /// witness generation is skipped. The `trace` must have been computed
+    /// from the data of `left` and `right` for the step `S`, and `output` is
+    /// the output header data that [`Application::trace_step`] returned with
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `trace` was computed for a step other than `S`, if
+    /// its header encodings do not match the data of `left`, `right` and
+    /// `output`, or if it does not fit its circuit.
+    pub fn fuse_from_trace<RNG: CryptoRngCore, S: Step<C>>(
+        &self,
+        rng: &mut RNG,
+        trace: StepTrace<C>,
+        output: <S::Output as Header<C::CircuitField>>::Data,
+        left: Pcd<C, R, S::Left>,
+        right: Pcd<C, R, S::Right>,
+    ) -> Result<Pcd<C, R, S::Output>> {
+        if trace.circuit_id != S::INDEX.circuit_index(self.num_application_steps)? {
+            return Err(Error::InvalidWitness(
+                "step trace was computed for a different step".into(),
+            ));
+        }
+
+        let (left, left_data) = left.into_parts();
+        let (right, right_data) = right.into_parts();
+        if trace.left_header != encode_header::<_, S::Left, HEADER_SIZE>(left_data)? {
+            return Err(Error::InvalidWitness(
+                "step trace was computed for different left header data".into(),
+            ));
+        }
+        if trace.right_header != encode_header::<_, S::Right, HEADER_SIZE>(right_data)? {
+            return Err(Error::InvalidWitness(
+                "step trace was computed for different right header data".into(),
+            ));
+        }
+        if trace.output_header != encode_header::<_, S::Output, HEADER_SIZE>(output.clone())? {
+            return Err(Error::InvalidWitness(
+                "step trace was computed for different output header data".into(),
+            ));
+        }
+
+        let proof = self.prove(rng, trace, left, right)?;
+
+        Ok(proof.carry(output))
+    }
+
+    /// Computes each proof component in sequence for an application step
+    /// whose witness has already been generated.
+    fn prove<RNG: CryptoRngCore>(
+        &self,
+        rng: &mut RNG,
+        trace: StepTrace<C>,
+        left: Proof<C, R>,
+        right: Proof<C, R>,
+    ) -> Result<Proof<C, R>> {
         let mut builder = ProofBuilder::new(self.params, C::ScalarField::random(&mut *rng));
 
-        let (left, right, application_data, application_aux) =
-            self.compute_application_proof(rng, step, witness, left, right, &mut builder)?;
+        self.compute_application_proof(rng, trace, &mut builder)?;
 
         let mut dr = Emulator::execute();
-        let mut transcript = Transcript::new(&mut dr, C::circuit_poseidon(self.params), RAGU_TAG)?;
+        let mut transcript = Transcript::new(
+            &mut dr,
+            C::circuit_poseidon(self.params),
+            &self.transcript_tag,
+        )?;
 
         let preamble_witness = self.compute_preamble(rng, &left, &right, &mut builder)?;
         let preamble_commitment = Point::constant(&mut dr, builder.bridge_preamble_commitment())?;
@@ -225,8 +300,6 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             &mut builder,
         )?;
 
-        let proof = builder.build()?;
-
-        Ok((proof.carry(application_data), application_aux))
+        builder.build()
     }
 }
@@ -1,6 +1,7 @@
 //! Headers are succinct representations of data used to represent the current
 //! state of a computation.
 
+use alloc::vec::Vec;
 use core::any::Any;
 
 use ragu_arithmetic::ff::Field;
@@ -8,6 +9,7 @@ use ragu_core::{
     Result,
     drivers::{Driver, DriverValue},
     gadgets::Bound,
+    maybe::Maybe,
 };
 use ragu_primitives::{allocator::Allocator, io::Write};
 
@@ -92,6 +94,13 @@ pub trait Header<F: Field>: Send + Sync + Any {
     /// The output gadget that encodes the data for this header.
     type Output: Write<F>;
 
+    /// The number of elements that [`Output`](Header::Output) writes, if it is
+    /// known at compile time.
+    ///
+    /// Steps fail to compile when this does not fit in the `HEADER_SIZE - 1`
+    /// elements available to the header, rather than failing at runtime.
+    const ENCODED_LEN: Option<usize> = None;
+
     /// Encode some data into a gadget representing this header.
     ///
     /// Implementations should pass `allocator` through to all allocation
@@ -103,9 +112,74 @@ pub trait Header<F: Field>: Send + Sync + Any {
     ) -> Result<Bound<'dr, D, Self::Output>>;
 }
 
+/// Automatically derives [`Header`] for a struct of plain data, using the
+/// struct itself as the [`Data`](Header::Data).
+///
+/// Each field is encoded according to its annotation:
+/// * Fields without any annotation, or annotated with `#[ragu(element)]`, hold
+///   a field element and are encoded as an `Element`.
+/// * `#[ragu(boolean)]` for `bool` fields, encoded as a `Boolean`.
+/// * `#[ragu(point)]` for curve points, encoded as a `Point`.
+/// * `#[ragu(header)]` for nested headers, which must themselves use
+///   `Data = Self` (as derived headers do).
+///
+/// Fixed-size arrays (possibly nested) of any of these are encoded
+/// elementwise. The struct must be annotated with its
+/// [`SUFFIX`](Header::SUFFIX) as `#[ragu(suffix = N)]`, which is passed to
+/// [`Suffix::new`]. The field is the type parameter `F` unless another is given
+/// with `#[ragu(field = Fp)]`.
+///
+/// The [`Output`](Header::Output) gadget holds the encoded fields in
+/// declaration order as right-nested pairs, so that three fields `a`, `b`, `c`
+/// are encoded as `(a, (b, c))` and a single field as its own gadget. The
+/// derived [`ENCODED_LEN`](Header::ENCODED_LEN) lets steps check at compile
+/// time that the header fits in their `HEADER_SIZE`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use ragu_arithmetic::ff::Field;
+/// # use ragu_pcd::header::Header;
+/// #[derive(Clone, Header)]
+/// #[ragu(suffix = 0)]
+/// struct Account<F: Field> {
+///     balance: F,
+///     #[ragu(boolean)]
+///     frozen: bool,
+///     history: [F; 4],
+/// }
+///
+/// assert_eq!(
+///     <Account<ragu_pasta::Fp> as Header<ragu_pasta::Fp>>::ENCODED_LEN,
+///     Some(6)
+/// );
+/// ```
+pub use ragu_macros::Header;
+
+/// Encodes each entry of an array witness with `f`, in order.
+///
+/// This is used by the [`Header`](derive@Header) derive to encode array
+/// fields.
+pub fn encode_array<'dr, D: Driver<'dr>, T: Send + Sync + Clone, G, const N: usize>(
+    dr: &mut D,
+    witness: DriverValue<D, [T; N]>,
+    mut f: impl FnMut(&mut D, DriverValue<D, T>) -> Result<G>,
+) -> Result<[G; N]> {
+    let mut encoded = Vec::with_capacity(N);
+    for i in 0..N {
+        encoded.push(f(dr, witness.as_ref().map(|witness| witness[i].clone()))?);
+    }
+    let Ok(encoded) = encoded.try_into() else {
+        unreachable!("exactly N entries were encoded")
+    };
+
+    Ok(encoded)
+}
+
 /// Trivial header that encodes no data.
 impl<F: Field> Header<F> for () {
     const SUFFIX: Suffix = Suffix::internal(1);
+    const ENCODED_LEN: Option<usize> = Some(0);
 
     type Data = ();
     type Output = ();
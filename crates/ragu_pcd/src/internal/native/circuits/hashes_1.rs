@@ -69,6 +69,7 @@
 //! [`WithSuffix`]: ragu_primitives::suffix::WithSuffix
 //! [`Transcript::save_state`]: crate::internal::transcript::Transcript::save_state
 
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use ragu_arithmetic::Cycle;
@@ -94,10 +95,7 @@ use super::super::{
     stages::{outer_error as native_outer_error, preamble as native_preamble},
     unified::{self, OutputBuilder},
 };
-use crate::{
-    RAGU_TAG,
-    internal::{fold_revdot, transcript::Transcript},
-};
+use crate::internal::{fold_revdot, transcript::Transcript};
 
 /// Public output of the first hash circuit.
 ///
@@ -130,6 +128,7 @@ pub struct Output<'dr, D: Driver<'dr>, C: Cycle<CircuitField = D::F>, const HEAD
 pub struct Circuit<'params, C: Cycle, R, const HEADER_SIZE: usize, FP: fold_revdot::Parameters> {
     params: &'params C::Params,
     log2_circuits: u32,
+    transcript_tag: Vec<u8>,
     _marker: PhantomData<(R, FP)>,
 }
 
@@ -143,13 +142,16 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize, FP: fold_revdot::Para
     /// - `params`: Curve cycle parameters providing Poseidon configuration.
     /// - `log2_circuits`: Log₂ of the registry domain size (number of circuits).
     ///   Used to verify circuit IDs are valid roots of unity.
+    /// - `transcript_tag`: The application's transcript domain separation tag.
     pub fn new(
         params: &'params C::Params,
         log2_circuits: u32,
+        transcript_tag: &[u8],
     ) -> MultiStage<C::CircuitField, R, Self> {
         MultiStage::new(Circuit {
             params,
             log2_circuits,
+            transcript_tag: transcript_tag.to_vec(),
             _marker: PhantomData,
         })
     }
@@ -232,7 +234,8 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize, FP: fold_revdot::Parameters>
         let mut unified_output = OutputBuilder::new(witness.map(|w| w.unified));
 
         // Create a transcript for all challenge derivations
-        let mut transcript = Transcript::new(dr, C::circuit_poseidon(self.params), RAGU_TAG)?;
+        let mut transcript =
+            Transcript::new(dr, C::circuit_poseidon(self.params), &self.transcript_tag)?;
 
         // Derive w by absorbing bridge_preamble_commitment and squeezing
         let w = {
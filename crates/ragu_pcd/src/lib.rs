@@ -9,6 +9,11 @@
 //! - [`step::Step`] — the trait that defines computation nodes (transitions).
 //! - [`header::Header`] — the trait that defines succinct state representations.
 //! - [`Proof`] / [`Pcd`] — the proof and proof-carrying-data structures.
+//! - [`StepTrace`] — a step's witness, generated with
+//!   [`trace_step`](Application::trace_step) and proven separately with
+//!   [`fuse_from_trace`](Application::fuse_from_trace).
+//! - [`RankedApplication`] / [`with_min_rank`] — build an application at the
+//!   smallest [`Rank`] that fits its circuits.
 
 #![no_std]
 #![allow(clippy::type_complexity, clippy::too_many_arguments)]
@@ -31,31 +36,46 @@ pub mod header;
 mod internal;
 mod proof;
 pub mod step;
+mod trace;
 mod verify;
 
-use alloc::collections::BTreeMap;
-use core::{any::TypeId, cell::OnceCell, marker::PhantomData};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{any::TypeId, cell::OnceCell, fmt, marker::PhantomData};
 
 use header::Header;
 pub use proof::{Pcd, Proof};
-use ragu_arithmetic::{CryptoRngCore, Cycle};
+use ragu_arithmetic::{CryptoRngCore, Cycle, FixedGenerators};
 use ragu_circuits::{
-    polynomials::Rank,
-    registry::{Registry, RegistryBuilder},
+    CircuitMetrics,
+    floor_planner::FloorPlanner,
+    polynomials::{ProductionRank, Rank, TestRank},
+    registry::{CircuitFingerprint, CircuitFit, CircuitIndex, Registry, RegistryBuilder},
 };
 use ragu_core::{Error, Result};
 use step::{Step, internal::adapter::Adapter};
+pub use trace::{PersistentStep, StepTrace};
 
 /// Domain separation tag for Ragu PCD protocol.
 // FIXME: choose a permanent domain separation tag before release.
 pub(crate) const RAGU_TAG: &[u8] = b"FIXME";
 
+/// Returns the tag that an application with the given domain tag passes to
+/// its transcripts: [`RAGU_TAG`] followed by the application's tag. Because
+/// [`RAGU_TAG`] is fixed, the application's tag is recoverable from the
+/// result, and the empty tag yields [`RAGU_TAG`] itself.
+pub(crate) fn transcript_tag(domain_tag: &[u8]) -> Vec<u8> {
+    let mut tag = RAGU_TAG.to_vec();
+    tag.extend_from_slice(domain_tag);
+    tag
+}
+
 /// Builder for an [`Application`] for proof-carrying data.
 pub struct ApplicationBuilder<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize> {
     native_registry: RegistryBuilder<'params, C::CircuitField, R>,
     nested_registry: RegistryBuilder<'params, C::ScalarField, R>,
     num_application_steps: usize,
     header_map: BTreeMap<header::Suffix, TypeId>,
+    domain_tag: Vec<u8>,
     _marker: PhantomData<[(); HEADER_SIZE]>,
 }
 
@@ -77,10 +97,35 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
             nested_registry: RegistryBuilder::new(),
             num_application_steps: 0,
             header_map: BTreeMap::new(),
+            domain_tag: Vec::new(),
             _marker: PhantomData,
         }
     }
 
+    /// Sets the domain separation tag of this application.
+    ///
+    /// The tag is absorbed into the Fiat-Shamir transcript and bound into the
+    /// keys of both registries, so proofs created by an application are
+    /// rejected by applications with a different tag, even if they register
+    /// the same steps. The default is the empty tag.
+    pub fn with_domain_tag(mut self, tag: &[u8]) -> Self {
+        self.domain_tag = tag.to_vec();
+        self
+    }
+
+    /// Sets the [`FloorPlanner`] that lays out the segments of every circuit
+    /// of this application, internal or application-defined.
+    ///
+    /// The default is [`DfsPlanner`](ragu_circuits::floor_planner::DfsPlanner).
+    /// The layout of a circuit is part of its wiring polynomial, so a planner
+    /// that moves any segment changes the registry digests, and proofs
+    /// created under one layout are rejected under the other.
+    pub fn floor_planner(mut self, planner: impl FloorPlanner + Clone + 'params) -> Self {
+        self.native_registry = self.native_registry.floor_planner(planner.clone());
+        self.nested_registry = self.nested_registry.floor_planner(planner);
+        self
+    }
+
     /// Register a new application-defined [`Step`] in this context. The
     /// provided [`Step`]'s [`INDEX`](Step::INDEX) must be the next sequential
     /// index that has not been inserted yet.
@@ -105,6 +150,20 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
         Ok(self)
     }
 
+    /// Returns the cost of each registered application [`Step`], indexed by
+    /// [`Step::INDEX`].
+    ///
+    /// The counts include the header encoding performed by the step adapter,
+    /// so they are the exact values checked against `R` during registration.
+    /// See [`CircuitMetrics::routines`] for a per-routine breakdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Initialization`] if a registered step has no metrics.
+    pub fn step_metrics(&self) -> Result<Vec<&CircuitMetrics>> {
+        self.native_registry.application_step_metrics()
+    }
+
     /// Register `count` trivial circuits to simulate application steps
     /// registration.
     ///
@@ -125,38 +184,44 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
     ///
     /// # Errors
     ///
-    /// Returns an error if internal circuit registration or registry
-    /// finalization fails.
+    /// Returns [`Error::Initialization`] if `params` has too few generators
+    /// to commit to polynomials of rank `R` (see [`Rank::generate_params`]),
+    /// or an error if internal circuit registration or registry finalization
+    /// fails.
     pub fn finalize(
         mut self,
         params: &'params C::Params,
     ) -> Result<Application<'params, C, R, HEADER_SIZE>> {
+        let generators = C::host_generators(params)
+            .g()
+            .len()
+            .min(C::nested_generators(params).g().len());
+        if generators < R::num_coeffs() {
+            return Err(Error::Initialization(
+                alloc::format!(
+                    "parameters have {} generators but rank {} needs {}",
+                    generators,
+                    R::RANK,
+                    R::num_coeffs(),
+                )
+                .into(),
+            ));
+        }
+
         // Build the native registry:
         // 1. Application circuits (already registered)
         // 2. Internal circuits and masks
         // 3. Internal steps
         let (total_circuits, log2_circuits) =
             internal::native::total_circuit_counts(self.num_application_steps);
-
-        // First, register internal circuits and masks
-        self.native_registry = internal::native::register_all::<C, R, HEADER_SIZE>(
-            self.native_registry,
+        let transcript_tag = transcript_tag(&self.domain_tag);
+        self.native_registry = Self::register_internal(
+            self.native_registry.domain_tag(&self.domain_tag),
+            self.num_application_steps,
             params,
-            log2_circuits,
+            &transcript_tag,
         )?;
 
-        // Then, register internal steps
-        self.native_registry =
-            self.native_registry
-                .register_internal_step(Adapter::<C, _, R, HEADER_SIZE>::new(
-                    step::internal::rerandomize::Rerandomize::<()>::new(),
-                ))?;
-        self.native_registry =
-            self.native_registry
-                .register_internal_step(Adapter::<C, _, R, HEADER_SIZE>::new(
-                    step::internal::trivial::Trivial::new(),
-                ))?;
-
         assert_eq!(
             self.native_registry.log2_circuits(),
             log2_circuits,
@@ -169,18 +234,92 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
         );
 
         // Register nested internal circuits (no application steps, no headers).
-        self.nested_registry = internal::nested::register_all::<C, R>(self.nested_registry)?;
+        self.nested_registry = internal::nested::register_all::<C, R>(
+            self.nested_registry.domain_tag(&self.domain_tag),
+        )?;
 
         Ok(Application {
             native_registry: self.native_registry.finalize()?,
             nested_registry: self.nested_registry.finalize()?,
             params,
             num_application_steps: self.num_application_steps,
+            transcript_tag,
             seeded_trivial: OnceCell::new(),
             _marker: PhantomData,
         })
     }
 
+    /// Reports how every circuit of the application, internal or
+    /// application-defined, fits within `R`, without finalizing it.
+    ///
+    /// The internal circuits are synthesized for the steps registered so far,
+    /// exactly as [`finalize`](Self::finalize) would, but the registries are
+    /// not built, so this is cheaper than finalizing the application and
+    /// calling [`Application::rank_report`]. Both return the same report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an internal circuit does not fit within `R`.
+    pub fn rank_report(&self, params: &'params C::Params) -> Result<RankReport> {
+        let transcript_tag = transcript_tag(&self.domain_tag);
+        let internal = Self::register_internal(
+            RegistryBuilder::new(),
+            self.num_application_steps,
+            params,
+            &transcript_tag,
+        )?;
+        let nested = internal::nested::register_all::<C, R>(RegistryBuilder::new())?;
+
+        // Application steps are indexed after every internal circuit.
+        let offset = internal.num_circuits();
+        let mut native = internal.circuit_fits();
+        native.extend(
+            self.native_registry
+                .circuit_fits()
+                .into_iter()
+                .map(|fit| CircuitFit {
+                    index: CircuitIndex::new(offset + usize::from(fit.index)),
+                    ..fit
+                }),
+        );
+
+        Ok(RankReport::new(
+            native,
+            offset + self.num_application_steps,
+            nested.circuit_fits(),
+            nested.num_circuits(),
+            self.num_application_steps,
+        ))
+    }
+
+    /// Registers the internal circuits, masks and internal steps of an
+    /// application with `num_application_steps` steps into `registry`.
+    fn register_internal(
+        registry: RegistryBuilder<'params, C::CircuitField, R>,
+        num_application_steps: usize,
+        params: &'params C::Params,
+        transcript_tag: &[u8],
+    ) -> Result<RegistryBuilder<'params, C::CircuitField, R>> {
+        let (_, log2_circuits) = internal::native::total_circuit_counts(num_application_steps);
+
+        // First, register internal circuits and masks
+        let registry = internal::native::register_all::<C, R, HEADER_SIZE>(
+            registry,
+            params,
+            log2_circuits,
+            transcript_tag,
+        )?;
+
+        // Then, register internal steps
+        registry
+            .register_internal_step(Adapter::<C, _, R, HEADER_SIZE>::new(
+                step::internal::rerandomize::Rerandomize::<()>::new(),
+            ))?
+            .register_internal_step(Adapter::<C, _, R, HEADER_SIZE>::new(
+                step::internal::trivial::Trivial::new(),
+            ))
+    }
+
     fn prevent_duplicate_suffixes<H: Header<C::CircuitField>>(&mut self) -> Result<()> {
         match self.header_map.get(&H::SUFFIX) {
             Some(ty) => {
@@ -205,6 +344,8 @@ pub struct Application<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize> {
     nested_registry: Registry<'params, C::ScalarField, R>,
     params: &'params C::Params,
     num_application_steps: usize,
+    /// Tag for this application's transcripts; see [`transcript_tag`].
+    transcript_tag: Vec<u8>,
     /// Cached seeded trivial proof for rerandomization.
     seeded_trivial: OnceCell<Proof<C, R>>,
     _marker: PhantomData<[(); HEADER_SIZE]>,
@@ -225,6 +366,21 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         self.fuse(rng, step, witness, self.trivial_pcd(), self.trivial_pcd())
     }
 
+    /// Seed a new computation from a [`StepTrace`] of a step with trivial
+    /// inputs, as [`Application::seed`] would.
+    ///
+    /// The `trace` and `output` are those returned by
+    /// [`Application::trace_step`] given `()` for the left and right data. See
+    /// [`Application::fuse_from_trace`].
+    pub fn seed_from_trace<RNG: CryptoRngCore, S: Step<C, Left = (), Right = ()>>(
+        &self,
+        rng: &mut RNG,
+        trace: StepTrace<C>,
+        output: <S::Output as Header<C::CircuitField>>::Data,
+    ) -> Result<Pcd<C, R, S::Output>> {
+        self.fuse_from_trace::<_, S>(rng, trace, output, self.trivial_pcd(), self.trivial_pcd())
+    }
+
     /// Returns a seeded trivial proof for use in rerandomization.
     ///
     /// A seeded trivial is a trivial proof that has been through `seed()`
@@ -278,4 +434,236 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
     pub fn native_registry(&self) -> &Registry<'_, C::CircuitField, R> {
         &self.native_registry
     }
+
+    /// Returns a reference to the nested [`Registry`].
+    ///
+    /// The nested registry holds only internal circuits, so its
+    /// [`digest`](Registry::digest) does not depend on the application's
+    /// steps. Registering, removing or changing a step changes only the
+    /// native registry's digest; the nested digest changes with the cycle,
+    /// the rank, the domain tag or the floor planner.
+    pub fn nested_registry(&self) -> &Registry<'_, C::ScalarField, R> {
+        &self.nested_registry
+    }
+
+    /// Returns the structural fingerprint of every circuit of this
+    /// application, internal or application-defined.
+    ///
+    /// A change to any circuit, such as a refactor of gadget code that
+    /// alters its constraints, changes its fingerprint and invalidates every
+    /// proof created before the change. See
+    /// [`CircuitFingerprint`] for what the fingerprint covers.
+    pub fn circuit_fingerprints(&self) -> FingerprintReport<C> {
+        FingerprintReport {
+            native: self.native_registry.circuit_fingerprints(),
+            nested: self.nested_registry.circuit_fingerprints(),
+            num_application_steps: self.num_application_steps,
+        }
+    }
+
+    /// Reports how every circuit of this application, internal or
+    /// application-defined, fits within `R`.
+    ///
+    /// The reported [`min_rank`](RankReport::min_rank) is computed from the
+    /// counts measured at `R`. Internal circuit costs never decrease as the
+    /// rank grows, so an application built with that rank (for instance by
+    /// [`with_min_rank`]) is guaranteed to fit whenever it is at
+    /// most `R`; building it and requesting a new report may lower the rank
+    /// further.
+    pub fn rank_report(&self) -> RankReport {
+        RankReport::new(
+            self.native_registry.circuit_fits(),
+            self.native_registry.num_circuits(),
+            self.nested_registry.circuit_fits(),
+            self.nested_registry.num_circuits(),
+            self.num_application_steps,
+        )
+    }
+}
+
+/// The structural fingerprints of the circuits of an [`Application`].
+///
+/// Returned by [`Application::circuit_fingerprints`]. Masking polynomials are
+/// not included.
+#[derive(Clone)]
+pub struct FingerprintReport<C: Cycle> {
+    native: Vec<CircuitFingerprint<C::CircuitField>>,
+    nested: Vec<CircuitFingerprint<C::ScalarField>>,
+    num_application_steps: usize,
+}
+
+impl<C: Cycle> FingerprintReport<C> {
+    /// Returns the fingerprint of each circuit in the native registry,
+    /// including the application steps.
+    pub fn native(&self) -> &[CircuitFingerprint<C::CircuitField>] {
+        &self.native
+    }
+
+    /// Returns the fingerprint of each circuit in the nested registry.
+    pub fn nested(&self) -> &[CircuitFingerprint<C::ScalarField>] {
+        &self.nested
+    }
+
+    /// Returns the fingerprint of each application [`Step`], indexed by
+    /// [`Step::INDEX`].
+    pub fn steps(&self) -> &[CircuitFingerprint<C::CircuitField>] {
+        // Application steps are registered last in the native registry.
+        &self.native[self.native.len() - self.num_application_steps..]
+    }
+}
+
+// Implemented by hand, since deriving would require the `Cycle` itself to
+// implement these traits.
+impl<C: Cycle> fmt::Debug for FingerprintReport<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FingerprintReport")
+            .field("native", &self.native)
+            .field("nested", &self.nested)
+            .field("num_application_steps", &self.num_application_steps)
+            .finish()
+    }
+}
+
+impl<C: Cycle> PartialEq for FingerprintReport<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.native == other.native
+            && self.nested == other.nested
+            && self.num_application_steps == other.num_application_steps
+    }
+}
+
+impl<C: Cycle> Eq for FingerprintReport<C> {}
+
+/// How the circuits of an [`Application`] fit within its [`Rank`].
+///
+/// Returned by [`ApplicationBuilder::rank_report`] and
+/// [`Application::rank_report`]. Masking polynomials, which
+/// always span the whole rank, are not included.
+#[derive(Clone, Debug)]
+pub struct RankReport {
+    native: Vec<CircuitFit>,
+    nested: Vec<CircuitFit>,
+    num_application_steps: usize,
+    min_rank: u32,
+}
+
+impl RankReport {
+    /// Creates a report from the fits of the circuits of both registries,
+    /// which hold `num_native` and `num_nested` circuits (including masks).
+    fn new(
+        native: Vec<CircuitFit>,
+        num_native: usize,
+        nested: Vec<CircuitFit>,
+        num_nested: usize,
+        num_application_steps: usize,
+    ) -> Self {
+        // Each registry needs a domain large enough to index its circuits;
+        // see `Registry::min_rank`.
+        let log2_circuits = |num_circuits: usize| num_circuits.next_power_of_two().trailing_zeros();
+        let min_rank = native.iter().chain(&nested).map(|fit| fit.min_rank).fold(
+            log2_circuits(num_native).max(log2_circuits(num_nested)),
+            u32::max,
+        );
+
+        RankReport {
+            native,
+            nested,
+            num_application_steps,
+            min_rank,
+        }
+    }
+
+    /// Returns the fit of each circuit in the native registry, including the
+    /// application steps.
+    pub fn native(&self) -> &[CircuitFit] {
+        &self.native
+    }
+
+    /// Returns the fit of each circuit in the nested registry.
+    pub fn nested(&self) -> &[CircuitFit] {
+        &self.nested
+    }
+
+    /// Returns the fit of each application [`Step`], indexed by
+    /// [`Step::INDEX`].
+    pub fn steps(&self) -> &[CircuitFit] {
+        // Application steps are registered last in the native registry.
+        &self.native[self.native.len() - self.num_application_steps..]
+    }
+
+    /// Returns the smallest `RANK` that fits every circuit of the
+    /// application, in both registries.
+    ///
+    /// This may be smaller than the smallest implemented rank.
+    pub fn min_rank(&self) -> u32 {
+        self.min_rank
+    }
+}
+
+/// An application that can be built at any [`Rank`], for use with
+/// [`with_min_rank`].
+///
+/// Because the [`Rank`] of an [`Application`] is a type parameter, code that
+/// builds or uses an application at a rank chosen at runtime must be generic
+/// over it. Implementations configure an [`ApplicationBuilder`] in
+/// [`build`](Self::build), and use the resulting application in
+/// [`run`](Self::run).
+pub trait RankedApplication<'params, C: Cycle, const HEADER_SIZE: usize> {
+    /// The value produced by [`run`](Self::run).
+    type Output;
+
+    /// Registers the application's steps in `builder`, and applies any other
+    /// configuration (such as a domain tag or floor planner).
+    ///
+    /// This is called once to measure the application and once more to build
+    /// it, so it must configure `builder` the same way each time.
+    fn build<R: Rank>(
+        &self,
+        builder: ApplicationBuilder<'params, C, R, HEADER_SIZE>,
+    ) -> Result<ApplicationBuilder<'params, C, R, HEADER_SIZE>>;
+
+    /// Uses the finalized application.
+    fn run<R: Rank>(
+        self,
+        application: Application<'params, C, R, HEADER_SIZE>,
+    ) -> Result<Self::Output>;
+}
+
+/// Builds `application` at the smallest implemented [`Rank`] that fits all of
+/// its circuits, and runs it.
+///
+/// The application is first measured with [`ApplicationBuilder::rank_report`]
+/// at [`ProductionRank`], the largest implemented rank, and then built at the
+/// reported [`min_rank`](RankReport::min_rank) (or the smallest implemented
+/// rank, if that is smaller) with [`ragu_circuits::with_rank!`]. `params` must
+/// have enough generators for the selected rank.
+///
+/// # Errors
+///
+/// Returns an error if the application does not fit within [`ProductionRank`],
+/// or if building or running it fails.
+pub fn with_min_rank<
+    'params,
+    C: Cycle,
+    A: RankedApplication<'params, C, HEADER_SIZE>,
+    const HEADER_SIZE: usize,
+>(
+    params: &'params C::Params,
+    application: A,
+) -> Result<A::Output> {
+    let report = application
+        .build(ApplicationBuilder::<C, ProductionRank, HEADER_SIZE>::new())?
+        .rank_report(params)?;
+    let rank = report.min_rank().max(TestRank::RANK);
+
+    ragu_circuits::with_rank!(rank, MinRank => {
+        let builder =
+            application.build(ApplicationBuilder::<C, MinRank, HEADER_SIZE>::new())?;
+        application.run(builder.finalize(params)?)
+    })
+    .unwrap_or_else(|| {
+        Err(Error::Initialization(
+            alloc::format!("rank {rank} is not implemented").into(),
+        ))
+    })
 }
@@ -65,7 +65,7 @@ impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Pcd<C, R, H> {
 
     /// Consumes the proof-carrying data and returns the proof and data
     /// separately.
-    pub(crate) fn into_parts(self) -> (Proof<C, R>, H::Data) {
+    pub fn into_parts(self) -> (Proof<C, R>, H::Data) {
         (self.proof, self.data)
     }
 }
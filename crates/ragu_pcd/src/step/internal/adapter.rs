@@ -26,12 +26,20 @@ impl<const N: usize> Len for TripleConstLen<N> {
     }
 }
 
-pub(crate) struct Adapter<C, S, R, const HEADER_SIZE: usize> {
+/// The circuit that a [`Step`] is registered as.
+///
+/// Its witness is the step's left and right header data and its own witness.
+/// It runs [`Step::witness`] and outputs the left, right and output header
+/// encodings, each padded to `HEADER_SIZE` elements, which it also returns
+/// as auxiliary data. It can be synthesized
+/// directly (for example under a simulator) to test a step without proving.
+pub struct Adapter<C, S, R, const HEADER_SIZE: usize> {
     step: S,
     _marker: PhantomData<(C, R)>,
 }
 
 impl<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize> Adapter<C, S, R, HEADER_SIZE> {
+    /// Wraps `step` as a circuit.
     pub fn new(step: S) -> Self {
         Adapter {
             step,
@@ -58,6 +66,7 @@ impl<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize> Circuit<C::Circuit
         (
             FixedVec<C::CircuitField, ConstLen<HEADER_SIZE>>,
             FixedVec<C::CircuitField, ConstLen<HEADER_SIZE>>,
+            FixedVec<C::CircuitField, ConstLen<HEADER_SIZE>>,
         ),
         <S::Output as Header<C::CircuitField>>::Data,
         S::Aux<'source>,
@@ -101,8 +110,13 @@ impl<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize> Circuit<C::Circuit
                 .map(|e| *e.value().take())
                 .collect_fixed()?;
 
+            let output_header = elements[HEADER_SIZE * 2..]
+                .iter()
+                .map(|e| *e.value().take())
+                .collect_fixed()?;
+
             Ok((
-                (left_header, right_header),
+                (left_header, right_header, output_header),
                 output_data.take(),
                 step_aux.take(),
             ))
@@ -227,12 +241,14 @@ mod tests {
             .expect("witness should succeed")
             .into_aux();
 
-        let ((left_header, right_header), output_data, _step_aux) = aux.take();
+        let ((left_header, right_header, output_header), output_data, _step_aux) = aux.take();
 
         // Left header should start with 10
         assert_eq!(left_header[0], Fp::from(10u64));
         // Right header should start with 20
         assert_eq!(right_header[0], Fp::from(20u64));
+        // Output header should start with 10 + 20 = 30
+        assert_eq!(output_header[0], Fp::from(30u64));
         // Step aux should be 10 + 20 = 30
         assert_eq!(output_data, Fp::from(30u64));
     }
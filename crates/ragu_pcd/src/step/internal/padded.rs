@@ -41,6 +41,15 @@ pub(crate) fn for_header<
     dr: &mut D,
     gadget: Bound<'dr, D, H::Output>,
 ) -> Result<Padded<'dr, D, H::Output, HEADER_SIZE>> {
+    const {
+        if let Some(len) = H::ENCODED_LEN {
+            assert!(
+                len < HEADER_SIZE,
+                "header encoding does not fit in HEADER_SIZE - 1 elements"
+            );
+        }
+    }
+
     let padded_content = PaddedContent { gadget };
     let suffix = Element::constant(dr, D::F::from(H::SUFFIX.get()));
     Ok(Padded {
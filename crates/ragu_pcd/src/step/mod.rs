@@ -2,8 +2,11 @@
 
 mod encoder;
 pub(crate) mod internal;
+mod r1cs;
 
 pub use encoder::Encoded;
+pub use internal::adapter::Adapter;
+pub use r1cs::R1csStep;
 use ragu_arithmetic::Cycle;
 use ragu_circuits::registry::CircuitIndex;
 use ragu_core::{
@@ -0,0 +1,138 @@
+//! Steps defined by imported R1CS circuits.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use ragu_arithmetic::Cycle;
+use ragu_circuits::r1cs::R1cs;
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue},
+    gadgets::Bound,
+    maybe::Maybe,
+};
+use ragu_primitives::{Element, GadgetExt, allocator::Standard};
+
+use super::{Encoded, Header, Index, Step};
+
+/// A [`Step`] that synthesizes an [`R1cs`] constraint system, such as one
+/// compiled by circom, and binds its headers to chosen public signals.
+///
+/// Each header is encoded from its data as usual and every element it
+/// [writes](ragu_primitives::io::Write) is constrained to equal the
+/// corresponding public signal, in the order given to [`R1csStep::new`]. The
+/// witness is the assignment of every signal (see
+/// [`read_witness`](ragu_circuits::r1cs::read_witness)) together with the
+/// data of the output header, which must be consistent with the signals it is
+/// bound to.
+pub struct R1csStep<'a, C: Cycle, L, R, O, const INDEX: usize> {
+    r1cs: &'a R1cs<C::CircuitField>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    output: Vec<usize>,
+    _marker: PhantomData<fn() -> (L, R, O)>,
+}
+
+impl<'a, C: Cycle, L, R, O, const INDEX: usize> R1csStep<'a, C, L, R, O, INDEX> {
+    /// Creates a step for `r1cs` whose left, right and output headers encode
+    /// to the public signals `left`, `right` and `output`, respectively.
+    ///
+    /// Returns [`Error::Initialization`] if any of the signals is not public.
+    pub fn new(
+        r1cs: &'a R1cs<C::CircuitField>,
+        left: &[usize],
+        right: &[usize],
+        output: &[usize],
+    ) -> Result<Self> {
+        let public = 1..=r1cs.num_public();
+        if !left
+            .iter()
+            .chain(right)
+            .chain(output)
+            .all(|signal| public.contains(signal))
+        {
+            return Err(Error::Initialization(
+                "headers can only be bound to public signals".into(),
+            ));
+        }
+
+        Ok(R1csStep {
+            r1cs,
+            left: left.to_vec(),
+            right: right.to_vec(),
+            output: output.to_vec(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<C: Cycle, L, R, O, const INDEX: usize> Step<C> for R1csStep<'_, C, L, R, O, INDEX>
+where
+    L: Header<C::CircuitField>,
+    R: Header<C::CircuitField>,
+    O: Header<C::CircuitField>,
+{
+    const INDEX: Index = Index::new(INDEX);
+    type Witness<'source> = (&'source [C::CircuitField], O::Data);
+    type Aux<'source> = ();
+    type Left = L;
+    type Right = R;
+    type Output = O;
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = C::CircuitField>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, L::Data>,
+        right: DriverValue<D, R::Data>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<C::CircuitField>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )>
+    where
+        Self: 'dr,
+    {
+        let (signals, output_data) = witness.cast();
+        let signals = self.r1cs.synthesize(dr, signals)?;
+
+        let allocator = &mut Standard::new();
+        let left = Encoded::new(dr, allocator, left)?;
+        let right = Encoded::new(dr, allocator, right)?;
+        let output = Encoded::new(dr, allocator, output_data.clone())?;
+
+        bind::<_, L>(dr, left.as_gadget(), &signals, &self.left)?;
+        bind::<_, R>(dr, right.as_gadget(), &signals, &self.right)?;
+        bind::<_, O>(dr, output.as_gadget(), &signals, &self.output)?;
+
+        Ok(((left, right, output), output_data, D::unit()))
+    }
+}
+
+/// Constrains the elements written by a header gadget to equal `bound`
+/// signals.
+fn bind<'dr, D: Driver<'dr>, H: Header<D::F>>(
+    dr: &mut D,
+    gadget: &Bound<'dr, D, H::Output>,
+    signals: &[Element<'dr, D>],
+    bound: &[usize],
+) -> Result<()> {
+    let mut elements = Vec::new();
+    gadget.write(dr, &mut elements)?;
+
+    if elements.len() != bound.len() {
+        return Err(Error::VectorLengthMismatch {
+            expected: bound.len(),
+            actual: elements.len(),
+        });
+    }
+    for (element, &signal) in elements.iter().zip(bound) {
+        dr.enforce_equal(element.wire(), signals[signal].wire())?;
+    }
+
+    Ok(())
+}
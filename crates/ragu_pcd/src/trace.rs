@@ -0,0 +1,246 @@
+//! Witness generation for a [`Step`], separately from proving.
+//!
+//! [`Application::trace_step`] runs only the witness generation of a step,
+//! which is cheap compared to the rest of [`Application::fuse`]. The resulting
+//! [`StepTrace`] can be encoded with [`StepTrace::to_bytes`] and proven
+//! elsewhere with [`Application::fuse_from_trace`]. Steps that implement
+//! [`PersistentStep`] can also persist their output header data and auxiliary
+//! data with the trace, using [`StepTrace::to_bytes_with`].
+
+use alloc::vec::Vec;
+
+use ragu_arithmetic::{Cycle, ff::PrimeField};
+use ragu_circuits::{CircuitExt, Trace, polynomials::Rank, registry::CircuitIndex};
+use ragu_core::{
+    Error, Result,
+    drivers::emulator::{Emulator, Wireless},
+    maybe::{Always, Maybe},
+};
+
+use crate::{
+    Application, Header,
+    step::{Encoded, Step, internal::adapter::Adapter},
+};
+
+/// Magic bytes that begin an encoded [`StepTrace`].
+const MAGIC: &[u8; 8] = b"ragustep";
+
+/// Magic bytes that begin an encoded [`StepTrace`] with step data.
+const MAGIC_WITH_DATA: &[u8; 8] = b"ragustpd";
+
+/// A [`Step`] whose output header data and [`Aux`](Step::Aux) can be persisted
+/// alongside its [`StepTrace`].
+///
+/// Both are application types, so the step provides their encoding. See
+/// [`StepTrace::to_bytes_with`].
+pub trait PersistentStep<C: Cycle>: Step<C> {
+    /// Appends an encoding of the output header data and auxiliary data of
+    /// this step to `bytes`.
+    fn encode_data(
+        output: &<Self::Output as Header<C::CircuitField>>::Data,
+        aux: &Self::Aux<'_>,
+        bytes: &mut Vec<u8>,
+    );
+
+    /// Decodes the output header data and auxiliary data encoded by
+    /// [`encode_data`](Self::encode_data), which occupy all of `bytes`.
+    ///
+    /// Returns [`Error::MalformedEncoding`] if `bytes` is not such an
+    /// encoding.
+    fn decode_data<'source>(
+        bytes: &[u8],
+    ) -> Result<(
+        <Self::Output as Header<C::CircuitField>>::Data,
+        Self::Aux<'source>,
+    )>;
+}
+
+/// The witness of a [`Step`] circuit, produced by
+/// [`Application::trace_step`] and consumed by
+/// [`Application::fuse_from_trace`].
+///
+/// This holds the [`Trace`] of the step circuit and the encodings of its left,
+/// right and output headers, as the circuit wrote them, all of which
+/// [`StepTrace::to_bytes`] writes. The output header data and the step's
+/// [`Aux`](Step::Aux) are application types, so they are returned alongside
+/// the trace; steps that implement [`PersistentStep`] can write them with the
+/// trace using [`StepTrace::to_bytes_with`].
+pub struct StepTrace<C: Cycle> {
+    pub(crate) circuit_id: CircuitIndex,
+    pub(crate) left_header: Vec<C::CircuitField>,
+    pub(crate) right_header: Vec<C::CircuitField>,
+    pub(crate) output_header: Vec<C::CircuitField>,
+    pub(crate) trace: Trace<C::CircuitField>,
+}
+
+impl<C: Cycle> StepTrace<C> {
+    /// Encodes this step trace so that it can be persisted or sent to another
+    /// process, and later decoded with [`StepTrace::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend((usize::from(self.circuit_id) as u32).to_le_bytes());
+        bytes.extend((self.left_header.len() as u32).to_le_bytes());
+        for value in self
+            .left_header
+            .iter()
+            .chain(&self.right_header)
+            .chain(&self.output_header)
+        {
+            bytes.extend(value.to_repr().as_ref());
+        }
+        bytes.extend(self.trace.to_bytes());
+        bytes
+    }
+
+    /// Encodes this step trace together with the output header data and
+    /// auxiliary data that [`Application::trace_step`] returned with it, to be
+    /// decoded with [`StepTrace::from_bytes_with`].
+    pub fn to_bytes_with<S: PersistentStep<C>>(
+        &self,
+        output: &<S::Output as Header<C::CircuitField>>::Data,
+        aux: &S::Aux<'_>,
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        S::encode_data(output, aux, &mut data);
+
+        let mut bytes = MAGIC_WITH_DATA.to_vec();
+        bytes.extend((data.len() as u32).to_le_bytes());
+        bytes.extend(data);
+        bytes.extend(self.to_bytes());
+        bytes
+    }
+
+    /// Decodes a step trace encoded by [`StepTrace::to_bytes`].
+    ///
+    /// Returns [`Error::MalformedEncoding`] if `bytes` is not such an
+    /// encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let malformed = |msg: &'static str| Error::MalformedEncoding(msg.into());
+        let mut rest = bytes;
+        let mut take = |len: usize| -> Result<&[u8]> {
+            if rest.len() < len {
+                return Err(malformed("step trace is truncated"));
+            }
+            let (taken, remaining) = rest.split_at(len);
+            rest = remaining;
+            Ok(taken)
+        };
+        if take(MAGIC.len())? != MAGIC {
+            return Err(malformed("not an encoding of a step trace"));
+        }
+
+        let mut read_u32 = || -> Result<u32> {
+            Ok(u32::from_le_bytes(
+                take(4)?.try_into().expect("took 4 bytes"),
+            ))
+        };
+        let circuit_id = CircuitIndex::from_u32(read_u32()?);
+        let header_size = read_u32()? as usize;
+
+        let repr_len = <C::CircuitField as PrimeField>::Repr::default()
+            .as_ref()
+            .len();
+        let mut headers = (0..3 * header_size).map(|_| {
+            let mut repr = <C::CircuitField as PrimeField>::Repr::default();
+            repr.as_mut().copy_from_slice(take(repr_len)?);
+            Option::from(C::CircuitField::from_repr(repr))
+                .ok_or_else(|| malformed("non-canonical field element in step trace"))
+        });
+        let left_header = headers
+            .by_ref()
+            .take(header_size)
+            .collect::<Result<Vec<_>>>()?;
+        let right_header = headers
+            .by_ref()
+            .take(header_size)
+            .collect::<Result<Vec<_>>>()?;
+        let output_header = headers.collect::<Result<Vec<_>>>()?;
+
+        Ok(StepTrace {
+            circuit_id,
+            left_header,
+            right_header,
+            output_header,
+            trace: Trace::from_bytes(rest)?,
+        })
+    }
+
+    /// Decodes a step trace and the step data encoded by
+    /// [`StepTrace::to_bytes_with`].
+    ///
+    /// Returns [`Error::MalformedEncoding`] if `bytes` is not such an
+    /// encoding.
+    pub fn from_bytes_with<'source, S: PersistentStep<C>>(
+        bytes: &[u8],
+    ) -> Result<(
+        Self,
+        <S::Output as Header<C::CircuitField>>::Data,
+        S::Aux<'source>,
+    )> {
+        let malformed = |msg: &'static str| Error::MalformedEncoding(msg.into());
+        let rest = bytes
+            .strip_prefix(MAGIC_WITH_DATA.as_slice())
+            .ok_or_else(|| malformed("not an encoding of a step trace with data"))?;
+        if rest.len() < 4 {
+            return Err(malformed("step trace is truncated"));
+        }
+        let (len, rest) = rest.split_at(4);
+        let len = u32::from_le_bytes(len.try_into().expect("split 4 bytes")) as usize;
+        if rest.len() < len {
+            return Err(malformed("step trace is truncated"));
+        }
+        let (data, rest) = rest.split_at(len);
+
+        let (output, aux) = S::decode_data(data)?;
+        Ok((Self::from_bytes(rest)?, output, aux))
+    }
+}
+
+impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_SIZE> {
+    /// Runs the witness generation of a [`Step`] without proving it.
+    ///
+    /// Returns the [`StepTrace`] to pass to
+    /// [`Application::fuse_from_trace`], together with the output header data
+    /// and the step's auxiliary data, exactly as [`Application::fuse`] would.
+    /// `left` and `right` are the data of the [`Pcd`](crate::Pcd) that the
+    /// trace will be fused with.
+    pub fn trace_step<'source, S: Step<C>>(
+        &self,
+        step: S,
+        witness: S::Witness<'source>,
+        left: <S::Left as Header<C::CircuitField>>::Data,
+        right: <S::Right as Header<C::CircuitField>>::Data,
+    ) -> Result<(
+        StepTrace<C>,
+        <S::Output as Header<C::CircuitField>>::Data,
+        S::Aux<'source>,
+    )> {
+        let circuit_id = S::INDEX.circuit_index(self.num_application_steps)?;
+        let (trace, aux) = Adapter::<C, S, R, HEADER_SIZE>::new(step)
+            .trace((left, right, witness))?
+            .into_parts();
+        let ((left_header, right_header, output_header), output_data, step_aux) = aux;
+
+        let trace = StepTrace {
+            circuit_id,
+            left_header: left_header.into_inner(),
+            right_header: right_header.into_inner(),
+            output_header: output_header.into_inner(),
+            trace,
+        };
+
+        Ok((trace, output_data, step_aux))
+    }
+}
+
+/// Encodes `data` as the `HEADER_SIZE` elements, including padding and
+/// suffix, that a step circuit writes for the header `H`.
+pub(crate) fn encode_header<F: PrimeField, H: Header<F>, const HEADER_SIZE: usize>(
+    data: H::Data,
+) -> Result<Vec<F>> {
+    Emulator::<Wireless<Always<()>, F>>::emulate_wireless(data, |dr, data| {
+        let mut elements = Vec::with_capacity(HEADER_SIZE);
+        Encoded::<_, H, HEADER_SIZE>::new(dr, &mut (), data)?.write(dr, &mut elements)?;
+        Ok(elements.iter().map(|e| *e.value().take()).collect())
+    })
+}
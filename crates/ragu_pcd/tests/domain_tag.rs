@@ -0,0 +1,114 @@
+use ff::Field;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    gadgets::Bound,
+};
+use ragu_pasta::Pasta;
+use ragu_pcd::{
+    Application, ApplicationBuilder,
+    header::{Header, Suffix},
+    step::{Encoded, Index, Step},
+};
+use ragu_primitives::allocator::{Allocator, Standard};
+use rand::{SeedableRng, rngs::StdRng};
+
+struct HeaderA;
+
+impl<F: Field> Header<F> for HeaderA {
+    const SUFFIX: Suffix = Suffix::new(0);
+    type Data = ();
+    type Output = ();
+    fn encode<'dr, D: Driver<'dr, F = F>, A: Allocator<'dr, D>>(
+        _: &mut D,
+        _: &mut A,
+        _: DriverValue<D, Self::Data>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        Ok(())
+    }
+}
+
+struct Step0;
+impl<C: Cycle> Step<C> for Step0 {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = ();
+    type Aux<'source> = ();
+    type Left = ();
+    type Right = ();
+    type Output = HeaderA;
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = C::CircuitField>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        _: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<C::CircuitField>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let allocator = &mut Standard::new();
+        let left = Encoded::new(dr, allocator, left)?;
+        let right = Encoded::new(dr, allocator, right)?;
+        let output = Encoded::from_gadget(());
+        Ok(((left, right, output), D::unit(), D::unit()))
+    }
+}
+
+fn app(tag: Option<&[u8]>) -> Application<'static, Pasta, ProductionRank, 4> {
+    let builder = ApplicationBuilder::<Pasta, ProductionRank, 4>::new();
+    match tag {
+        Some(tag) => builder.with_domain_tag(tag),
+        None => builder,
+    }
+    .register(Step0)
+    .unwrap()
+    .finalize(Pasta::baked())
+    .unwrap()
+}
+
+#[test]
+fn proofs_verify_under_their_own_tag() {
+    let app_a = app(Some(b"application a".as_slice()));
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    let (pcd, _) = app_a.seed(&mut rng, Step0, ()).unwrap();
+    assert!(app_a.verify(&pcd, &mut rng).unwrap());
+
+    // An application rebuilt with the same tag accepts the proof.
+    assert!(
+        app(Some(b"application a".as_slice()))
+            .verify(&pcd, &mut rng)
+            .unwrap()
+    );
+}
+
+#[test]
+fn cross_application_proofs_fail_verification() {
+    let app_a = app(Some(b"application a".as_slice()));
+    let app_b = app(Some(b"application b".as_slice()));
+    let untagged = app(None);
+    let mut rng = StdRng::seed_from_u64(5678);
+
+    let (pcd_a, _) = app_a.seed(&mut rng, Step0, ()).unwrap();
+    let (pcd_b, _) = app_b.seed(&mut rng, Step0, ()).unwrap();
+    let (pcd_untagged, _) = untagged.seed(&mut rng, Step0, ()).unwrap();
+
+    for (verifier, pcd) in [
+        (&app_a, &pcd_b),
+        (&app_a, &pcd_untagged),
+        (&app_b, &pcd_a),
+        (&untagged, &pcd_a),
+    ] {
+        assert!(
+            !matches!(verifier.verify(pcd, &mut rng), Ok(true)),
+            "proof from another application must not verify"
+        );
+    }
+}
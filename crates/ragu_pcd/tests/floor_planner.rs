@@ -0,0 +1,34 @@
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{floor_planner::PackingPlanner, polynomials::ProductionRank};
+use ragu_core::Result;
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::ApplicationBuilder;
+use ragu_testing::pcd::nontrivial::{Hash2, WitnessLeaf};
+use rand::{SeedableRng, rngs::StdRng};
+
+#[test]
+fn packed_application_seeds_fuses_and_verifies() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let hash2 = || Hash2 {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .floor_planner(PackingPlanner)
+        .register(leaf())?
+        .register(hash2())?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    let (leaf1, _) = app.seed(&mut rng, leaf(), Fp::from(42u64))?;
+    assert!(app.verify(&leaf1, &mut rng)?);
+    let (leaf2, _) = app.seed(&mut rng, leaf(), Fp::from(43u64))?;
+
+    let (node, ()) = app.fuse(&mut rng, hash2(), (), leaf1, leaf2)?;
+    assert!(app.verify(&node, &mut rng)?);
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::Result;
+use ragu_pasta::Pasta;
+use ragu_pcd::{Application, ApplicationBuilder};
+use ragu_testing::{
+    golden::Golden,
+    pcd::nontrivial::{Hash2, WitnessLeaf},
+};
+
+fn app(with_hash2: bool) -> Result<Application<'static, Pasta, ProductionRank, 4>> {
+    let pasta = Pasta::baked();
+    let builder = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().register(WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    })?;
+    let builder = if with_hash2 {
+        builder.register(Hash2 {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        })?
+    } else {
+        builder
+    };
+    builder.finalize(pasta)
+}
+
+/// A golden file in the temporary directory, removed when dropped so that
+/// tests which panic do not leave it behind.
+struct TempGolden(PathBuf);
+
+impl TempGolden {
+    fn new(name: &str) -> Self {
+        TempGolden(
+            std::env::temp_dir().join(format!("ragu_golden_{}_{name}.txt", std::process::id())),
+        )
+    }
+}
+
+impl Drop for TempGolden {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn nontrivial_application_matches_golden_file() -> Result<()> {
+    Golden::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/golden/nontrivial.txt"
+    ))
+    .check_application(&app(true)?);
+
+    Ok(())
+}
+
+#[test]
+fn fingerprints_are_deterministic() -> Result<()> {
+    let report = app(true)?.circuit_fingerprints();
+    assert_eq!(report.steps().len(), 2);
+    assert_ne!(report.steps()[0], report.steps()[1]);
+    assert_eq!(report, app(true)?.circuit_fingerprints());
+
+    let golden = TempGolden::new("deterministic");
+    Golden::new(&golden.0)
+        .update(true)
+        .check_application(&app(true)?);
+    Golden::new(&golden.0)
+        .update(false)
+        .check_application(&app(true)?);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "added step 0001")]
+fn golden_file_detects_changes() {
+    let golden = TempGolden::new("changes");
+    Golden::new(&golden.0)
+        .update(true)
+        .check_application(&app(false).unwrap());
+    Golden::new(&golden.0)
+        .update(false)
+        .check_application(&app(true).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn golden_file_must_exist() {
+    let golden = TempGolden::new("missing");
+    Golden::new(&golden.0)
+        .update(false)
+        .check_application(&app(false).unwrap());
+}
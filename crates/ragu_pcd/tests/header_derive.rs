@@ -0,0 +1,87 @@
+use ff::Field;
+use ragu_arithmetic::group::prime::PrimeCurveAffine;
+use ragu_core::{
+    Result,
+    drivers::emulator::Emulator,
+    maybe::{Always, Maybe, MaybeKind},
+};
+use ragu_pasta::{EpAffine, Fp};
+use ragu_pcd::header::{Header, Suffix};
+use ragu_primitives::GadgetExt;
+
+#[derive(Clone, Header)]
+#[ragu(suffix = 0)]
+struct Leaf<F: Field> {
+    value: F,
+    #[ragu(boolean)]
+    flag: bool,
+}
+
+#[derive(Clone, Header)]
+#[ragu(suffix = 1)]
+struct Node<F: Field> {
+    #[ragu(header)]
+    children: [Leaf<F>; 2],
+    weights: [[F; 2]; 2],
+}
+
+#[derive(Clone, Header)]
+#[ragu(suffix = 2, field = Fp)]
+struct Commitment {
+    #[ragu(point)]
+    point: EpAffine,
+}
+
+#[derive(Clone, Header)]
+#[ragu(suffix = 3, field = Fp)]
+struct Empty {}
+
+#[test]
+fn test_derived_header_constants() {
+    assert_eq!(<Leaf<Fp> as Header<Fp>>::SUFFIX, Suffix::new(0));
+    assert_eq!(<Node<Fp> as Header<Fp>>::SUFFIX, Suffix::new(1));
+    assert_eq!(<Leaf<Fp> as Header<Fp>>::ENCODED_LEN, Some(2));
+    assert_eq!(<Node<Fp> as Header<Fp>>::ENCODED_LEN, Some(8));
+    assert_eq!(<Commitment as Header<Fp>>::ENCODED_LEN, Some(2));
+    assert_eq!(<Empty as Header<Fp>>::ENCODED_LEN, Some(0));
+}
+
+#[test]
+fn test_derived_header_encoding() -> Result<()> {
+    let mut dr = Emulator::execute();
+    let dr = &mut dr;
+
+    let leaf = |n: u64| Leaf {
+        value: Fp::from(n),
+        flag: n % 2 == 1,
+    };
+    let node = Node {
+        children: [leaf(1), leaf(2)],
+        weights: [[Fp::from(3), Fp::from(4)], [Fp::from(5), Fp::from(6)]],
+    };
+
+    let (children, weights) =
+        <Node<Fp> as Header<Fp>>::encode(dr, &mut (), Always::maybe_just(|| node))?;
+    assert!(children[0].1.value().take());
+    assert!(!children[1].1.value().take());
+    assert_eq!(*weights[1][0].value().take(), Fp::from(5));
+
+    let mut buf = vec![];
+    (children, weights).write(dr, &mut buf)?;
+    let expected = [1, 1, 2, 0, 3, 4, 5, 6].map(Fp::from);
+    assert_eq!(buf.len(), expected.len());
+    for (element, expected) in buf.iter().zip(expected) {
+        assert_eq!(*element.value().take(), expected);
+    }
+
+    let point = <Commitment as Header<Fp>>::encode(
+        dr,
+        &mut (),
+        Always::maybe_just(|| Commitment {
+            point: EpAffine::generator(),
+        }),
+    )?;
+    assert_eq!(point.value().take(), EpAffine::generator());
+
+    Ok(())
+}
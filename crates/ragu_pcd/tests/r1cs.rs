@@ -0,0 +1,129 @@
+use ff::{Field, PrimeField};
+use ragu_circuits::{polynomials::ProductionRank, r1cs::R1cs};
+use ragu_core::Error;
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::step::R1csStep;
+use ragu_testing::{
+    pcd::nontrivial::{InternalNode, LeafNode},
+    step::simulate,
+};
+
+type Step<'a> = R1csStep<'a, Pasta, LeafNode, (), InternalNode, 0>;
+
+/// Encodes a circom `.r1cs` file with signals `[1, out, in, t]` proving that
+/// `out = in^2 + in`, where `out` is a public output and `in` a public input.
+fn example() -> Vec<u8> {
+    let mut prime = (-Fp::ONE).to_repr();
+    prime[0] += 1;
+
+    let mut header = 32u32.to_le_bytes().to_vec();
+    header.extend(prime);
+    for count in [4u32, 1, 1, 0] {
+        header.extend(count.to_le_bytes());
+    }
+    header.extend(0u64.to_le_bytes());
+    header.extend(2u32.to_le_bytes());
+
+    let one = Fp::ONE;
+    let constraints: [[&[(u32, Fp)]; 3]; 2] = [
+        // in * in = t
+        [&[(2, one)], &[(2, one)], &[(3, one)]],
+        // 0 = t + in - out
+        [&[], &[], &[(3, one), (2, one), (1, -one)]],
+    ];
+    let mut body = Vec::new();
+    for lc in constraints.iter().flatten() {
+        body.extend((lc.len() as u32).to_le_bytes());
+        for (signal, coeff) in lc.iter() {
+            body.extend(signal.to_le_bytes());
+            body.extend(coeff.to_repr());
+        }
+    }
+
+    let mut bytes = b"r1cs".to_vec();
+    bytes.extend(1u32.to_le_bytes());
+    bytes.extend(2u32.to_le_bytes());
+    for (ty, contents) in [(1u32, header), (2, body)] {
+        bytes.extend(ty.to_le_bytes());
+        bytes.extend((contents.len() as u64).to_le_bytes());
+        bytes.extend(contents);
+    }
+    bytes
+}
+
+fn signals(input: Fp) -> Vec<Fp> {
+    vec![Fp::ONE, input.square() + input, input, input.square()]
+}
+
+#[test]
+fn r1cs_step_binds_headers_to_signals() {
+    let r1cs = R1cs::read(&example()).unwrap();
+    let input = Fp::from(5);
+    let signals = signals(input);
+
+    let step = Step::new(&r1cs, &[2], &[], &[1]).unwrap();
+    let sim = simulate::<Pasta, _, ProductionRank, 4>(
+        step,
+        input,
+        (),
+        (signals.as_slice(), Fp::from(30)),
+    )
+    .unwrap();
+    assert!(sim.fits());
+    assert_eq!(sim.left_header[0], input);
+    assert_eq!(sim.output_header[0], Fp::from(30));
+    assert_eq!(sim.output_data, Fp::from(30));
+
+    // Header data that disagrees with the bound signals is unsatisfiable.
+    let step = Step::new(&r1cs, &[2], &[], &[1]).unwrap();
+    assert!(
+        simulate::<Pasta, _, ProductionRank, 4>(
+            step,
+            input,
+            (),
+            (signals.as_slice(), Fp::from(31))
+        )
+        .is_err()
+    );
+    let step = Step::new(&r1cs, &[2], &[], &[1]).unwrap();
+    assert!(
+        simulate::<Pasta, _, ProductionRank, 4>(
+            step,
+            Fp::from(6),
+            (),
+            (signals.as_slice(), Fp::from(30))
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn r1cs_step_rejects_invalid_bindings() {
+    let r1cs = R1cs::read(&example()).unwrap();
+
+    // Signal 3 is an intermediate signal.
+    assert!(matches!(
+        Step::new(&r1cs, &[3], &[], &[1]),
+        Err(Error::Initialization(_))
+    ));
+    assert!(matches!(
+        Step::new(&r1cs, &[0], &[], &[1]),
+        Err(Error::Initialization(_))
+    ));
+
+    // The output header encodes a single element.
+    let step = Step::new(&r1cs, &[2], &[], &[1, 2]).unwrap();
+    let input = Fp::from(5);
+    assert!(matches!(
+        simulate::<Pasta, _, ProductionRank, 4>(
+            step,
+            input,
+            (),
+            (signals(input).as_slice(), Fp::from(30))
+        ),
+        Err(Error::VectorLengthMismatch {
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
@@ -1,15 +1,19 @@
 use ff::Field;
-use ragu_circuits::polynomials::ProductionRank;
+use ragu_circuits::{
+    polynomials::{ProductionRank, Rank, TestRank},
+    with_rank,
+};
 use ragu_core::{
-    Result,
+    Error, Result,
     drivers::{Driver, DriverValue},
     gadgets::Bound,
 };
-use ragu_pasta::Pasta;
+use ragu_pasta::{Pasta, PastaParams};
 use ragu_pcd::{
-    ApplicationBuilder,
+    Application, ApplicationBuilder, RankReport, RankedApplication,
     header::{Header, Suffix},
     step::{Encoded, Index, Step},
+    with_min_rank,
 };
 use ragu_primitives::allocator::{Allocator, Standard};
 
@@ -169,6 +173,16 @@ fn register_steps_success_and_finalize() {
     builder.finalize(pasta).unwrap();
 }
 
+#[test]
+fn finalize_rejects_params_too_small_for_rank() {
+    let params = PastaParams::generate_for(ProductionRank::RANK - 6);
+    let result = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Step0)
+        .unwrap()
+        .finalize(&params);
+    assert!(matches!(result, Err(Error::Initialization(_))));
+}
+
 #[test]
 #[should_panic]
 fn register_steps_out_of_order_should_fail() {
@@ -186,3 +200,109 @@ fn register_steps_duplicate_suffix_should_fail() {
         .register(Step1Dup)
         .unwrap();
 }
+
+#[test]
+fn register_steps_reports_step_metrics() {
+    let builder = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Step0)
+        .unwrap()
+        .register(Step1)
+        .unwrap();
+
+    let metrics = builder.step_metrics().unwrap();
+    assert_eq!(metrics.len(), 2);
+    for step in metrics {
+        let root = step.routines();
+        assert_eq!(root.num_gates(), step.num_gates());
+        assert_eq!(root.num_constraints(), step.num_constraints());
+        assert!(step.num_gates() <= ProductionRank::n());
+    }
+}
+
+#[test]
+fn finalized_application_reports_rank_fit() {
+    let pasta = Pasta::baked();
+    let builder = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Step0)
+        .unwrap()
+        .register(Step1)
+        .unwrap();
+    let step_counts: Vec<_> = builder
+        .step_metrics()
+        .unwrap()
+        .into_iter()
+        .map(|metrics| (metrics.num_gates(), metrics.num_constraints()))
+        .collect();
+    let before = builder.rank_report(pasta).unwrap();
+    let report = builder.finalize(pasta).unwrap().rank_report();
+
+    // The report is available before finalizing, and does not change.
+    assert_eq!(before.min_rank(), report.min_rank());
+    for (before, after) in [
+        (before.native(), report.native()),
+        (before.nested(), report.nested()),
+    ] {
+        assert_eq!(before.len(), after.len());
+        for (before, after) in before.iter().zip(after) {
+            assert_eq!(before.index, after.index);
+            assert_eq!(
+                (before.num_gates, before.num_constraints),
+                (after.num_gates, after.num_constraints)
+            );
+        }
+    }
+
+    assert_eq!(report.steps().len(), 2);
+    for (fit, counts) in report.steps().iter().zip(step_counts) {
+        assert_eq!((fit.num_gates, fit.num_constraints), counts);
+        assert_eq!(fit.num_gates + fit.gate_headroom, ProductionRank::n());
+    }
+    assert!(report.min_rank() <= ProductionRank::RANK);
+
+    // The reported rank fits the whole application, and can only shrink when
+    // measured again at that rank.
+    let rebuilt = with_rank!(report.min_rank(), MinRank => {
+        ApplicationBuilder::<Pasta, MinRank, 4>::new()
+            .register(Step0)
+            .unwrap()
+            .register(Step1)
+            .unwrap()
+            .finalize(pasta)
+            .unwrap()
+            .rank_report()
+            .min_rank()
+    });
+    let min_rank = rebuilt.expect("the reported rank is implemented");
+    assert!(min_rank <= report.min_rank());
+}
+
+struct TwoSteps;
+
+impl<'params> RankedApplication<'params, Pasta, 4> for TwoSteps {
+    type Output = (u32, RankReport);
+
+    fn build<R: Rank>(
+        &self,
+        builder: ApplicationBuilder<'params, Pasta, R, 4>,
+    ) -> Result<ApplicationBuilder<'params, Pasta, R, 4>> {
+        builder.register(Step0)?.register(Step1)
+    }
+
+    fn run<R: Rank>(self, application: Application<'params, Pasta, R, 4>) -> Result<Self::Output> {
+        Ok((R::RANK, application.rank_report()))
+    }
+}
+
+#[test]
+fn with_min_rank_builds_the_reported_rank() {
+    let pasta = Pasta::baked();
+    let report = TwoSteps
+        .build(ApplicationBuilder::<Pasta, ProductionRank, 4>::new())
+        .unwrap()
+        .rank_report(pasta)
+        .unwrap();
+
+    let (rank, rebuilt) = with_min_rank(pasta, TwoSteps).unwrap();
+    assert_eq!(rank, report.min_rank().max(TestRank::RANK));
+    assert!(rebuilt.min_rank() <= rank);
+}
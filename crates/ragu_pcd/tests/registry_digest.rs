@@ -0,0 +1,69 @@
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::Result;
+use ragu_pasta::Pasta;
+use ragu_pcd::ApplicationBuilder;
+use ragu_testing::pcd::nontrivial::{Hash2, WitnessLeaf};
+
+#[test]
+fn steps_change_only_the_native_digest() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let small = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .finalize(pasta)?;
+    let large = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .register(Hash2 {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        })?
+        .finalize(pasta)?;
+
+    assert_ne!(
+        small.native_registry().digest(),
+        large.native_registry().digest()
+    );
+    assert_eq!(
+        small.nested_registry().digest(),
+        large.nested_registry().digest()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn appended_steps_keep_existing_step_slots() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let hash2 = || Hash2 {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let small = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .finalize(pasta)?;
+    let large = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .register(hash2())?
+        .finalize(pasta)?;
+
+    // Internal circuits come first and application steps are appended after
+    // them, so every existing circuit keeps its index (and thus its omega^j).
+    // Internal circuits may depend on the number of circuits, but the
+    // existing steps also keep their wiring polynomials.
+    let before = small.circuit_fingerprints();
+    let after = large.circuit_fingerprints();
+    assert_eq!(after.steps().len(), before.steps().len() + 1);
+    for (before, after) in before.native().iter().zip(after.native()) {
+        assert_eq!(before.index, after.index);
+        assert!(large.native_registry().circuit_in_domain(after.index));
+    }
+    for (before, after) in before.steps().iter().zip(after.steps()) {
+        assert_eq!(before, after);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use ff::Field;
+use proptest::prelude::*;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_pasta::{Fp, Pasta};
+use ragu_testing::{
+    pcd::nontrivial::{Hash2, WitnessLeaf},
+    step::simulate,
+    strategies::prime_field_element,
+};
+
+proptest! {
+    #[test]
+    fn proptest_simulate_leaf(value in prime_field_element::<Fp>()) {
+        let pasta = Pasta::baked();
+        let step = WitnessLeaf {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        };
+
+        let sim = simulate::<Pasta, _, ProductionRank, 4>(step, (), (), value).unwrap();
+        prop_assert!(sim.fits());
+        prop_assert_eq!(sim.output_header[0], sim.output_data);
+        prop_assert_eq!(&sim.output_header[1..3], &[Fp::ZERO; 2]);
+        // Application suffixes are offset by the internal ones.
+        prop_assert_eq!(sim.output_header[3], Fp::from(2));
+        prop_assert_eq!(sim.left_header[3], Fp::from(1));
+    }
+
+    #[test]
+    fn proptest_simulate_hash2(
+        left in prime_field_element::<Fp>(),
+        right in prime_field_element::<Fp>(),
+    ) {
+        let pasta = Pasta::baked();
+        let step = Hash2 {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        };
+
+        let sim = simulate::<Pasta, _, ProductionRank, 4>(step, left, right, ()).unwrap();
+        prop_assert!(sim.num_gates > 0);
+        prop_assert_eq!(sim.left_header[0], left);
+        prop_assert_eq!(sim.right_header[0], right);
+        prop_assert_eq!(sim.output_header[0], sim.output_data);
+        prop_assert_eq!(sim.output_header[3], Fp::from(3));
+    }
+}
@@ -0,0 +1,177 @@
+use ff::Field;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{Error, Result};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{ApplicationBuilder, StepTrace};
+use ragu_testing::pcd::nontrivial::{Hash2, WitnessLeaf};
+use rand::{SeedableRng, rngs::StdRng};
+
+#[test]
+fn fuse_from_persisted_trace() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let hash2 = || Hash2 {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .register(hash2())?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    // Witness generation only needs the header data of the children, so the
+    // trace can be computed in another process and persisted.
+    let (trace, leaf_data, ()) = app.trace_step(leaf(), Fp::from(42u64), (), ())?;
+    let trace = StepTrace::<Pasta>::from_bytes(&trace.to_bytes())?;
+    let leaf1 = app.seed_from_trace::<_, WitnessLeaf<'_, Pasta>>(&mut rng, trace, leaf_data)?;
+    assert!(app.verify(&leaf1, &mut rng)?);
+
+    let (leaf2, _) = app.seed(&mut rng, leaf(), Fp::from(42u64))?;
+    assert_eq!(leaf1.data(), leaf2.data());
+
+    let (trace, node_data, ()) = app.trace_step(hash2(), (), *leaf1.data(), *leaf2.data())?;
+    let bytes = trace.to_bytes();
+    let trace = StepTrace::<Pasta>::from_bytes(&bytes)?;
+    assert_eq!(trace.to_bytes(), bytes);
+    let node =
+        app.fuse_from_trace::<_, Hash2<'_, Pasta>>(&mut rng, trace, node_data, leaf1, leaf2)?;
+    assert!(app.verify(&node, &mut rng)?);
+
+    Ok(())
+}
+
+#[test]
+fn fuse_from_trace_with_persisted_data() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let hash2 = || Hash2 {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .register(hash2())?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+    let (leaf1, _) = app.seed(&mut rng, leaf(), Fp::from(1u64))?;
+    let (leaf2, _) = app.seed(&mut rng, leaf(), Fp::from(2u64))?;
+
+    // The output header data and aux are persisted with the trace, so the
+    // proving process needs nothing else from witness generation.
+    let (trace, node_data, aux) = app.trace_step(hash2(), (), *leaf1.data(), *leaf2.data())?;
+    let bytes = trace.to_bytes_with::<Hash2<'_, Pasta>>(&node_data, &aux);
+    let (trace, output, ()) = StepTrace::<Pasta>::from_bytes_with::<Hash2<'_, Pasta>>(&bytes)?;
+    assert_eq!(output, node_data);
+    assert_eq!(trace.to_bytes_with::<Hash2<'_, Pasta>>(&output, &()), bytes);
+
+    let node = app.fuse_from_trace::<_, Hash2<'_, Pasta>>(&mut rng, trace, output, leaf1, leaf2)?;
+    assert!(app.verify(&node, &mut rng)?);
+    assert_eq!(*node.data(), node_data);
+
+    // Traces written without data are not mistaken for ones with data.
+    let (trace, _, ()) = app.trace_step(leaf(), Fp::from(3u64), (), ())?;
+    assert!(matches!(
+        StepTrace::<Pasta>::from_bytes_with::<WitnessLeaf<'_, Pasta>>(&trace.to_bytes()),
+        Err(Error::MalformedEncoding(_))
+    ));
+    assert!(matches!(
+        StepTrace::<Pasta>::from_bytes_with::<Hash2<'_, Pasta>>(&bytes[..20]),
+        Err(Error::MalformedEncoding(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn fuse_from_trace_rejects_other_steps() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .register(Hash2 {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        })?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+    let (leaf1, _) = app.seed(&mut rng, leaf(), Fp::from(1u64))?;
+    let (leaf2, _) = app.seed(&mut rng, leaf(), Fp::from(2u64))?;
+
+    let (trace, data, ()) = app.trace_step(leaf(), Fp::from(3u64), (), ())?;
+    let result = app.fuse_from_trace::<_, Hash2<'_, Pasta>>(&mut rng, trace, data, leaf1, leaf2);
+    assert!(matches!(result, Err(Error::InvalidWitness(_))));
+
+    let (trace, _, ()) = app.trace_step(leaf(), Fp::from(3u64), (), ())?;
+    let bytes = trace.to_bytes();
+    assert!(matches!(
+        StepTrace::<Pasta>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::MalformedEncoding(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn fuse_from_trace_rejects_other_data() -> Result<()> {
+    let pasta = Pasta::baked();
+    let leaf = || WitnessLeaf {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let hash2 = || Hash2 {
+        poseidon_params: Pasta::circuit_poseidon(pasta),
+    };
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(leaf())?
+        .register(hash2())?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+    let seed = |rng: &mut StdRng, value: u64| app.seed(rng, leaf(), Fp::from(value));
+    let (leaf1, _) = seed(&mut rng, 1)?;
+    let (leaf2, _) = seed(&mut rng, 2)?;
+    let (trace, node_data, ()) = app.trace_step(hash2(), (), *leaf1.data(), *leaf2.data())?;
+    let bytes = trace.to_bytes();
+
+    // Children whose data the trace was not computed from.
+    let (other1, _) = seed(&mut rng, 3)?;
+    let (other2, _) = seed(&mut rng, 4)?;
+    let trace = StepTrace::<Pasta>::from_bytes(&bytes)?;
+    let result = app.fuse_from_trace::<_, Hash2<'_, Pasta>>(
+        &mut rng,
+        trace,
+        node_data,
+        other1,
+        leaf2.clone(),
+    );
+    assert!(matches!(result, Err(Error::InvalidWitness(_))));
+    let trace = StepTrace::<Pasta>::from_bytes(&bytes)?;
+    let result = app.fuse_from_trace::<_, Hash2<'_, Pasta>>(
+        &mut rng,
+        trace,
+        node_data,
+        leaf1.clone(),
+        other2,
+    );
+    assert!(matches!(result, Err(Error::InvalidWitness(_))));
+
+    // Output data that the trace did not produce.
+    let trace = StepTrace::<Pasta>::from_bytes(&bytes)?;
+    let result = app.fuse_from_trace::<_, Hash2<'_, Pasta>>(
+        &mut rng,
+        trace,
+        node_data + Fp::ONE,
+        leaf1,
+        leaf2,
+    );
+    assert!(matches!(result, Err(Error::InvalidWitness(_))));
+
+    Ok(())
+}
@@ -25,6 +25,7 @@ use crate::{
     consistent::Consistent,
     io::{Buffer, Write},
     promotion::{Demoted, Promotion},
+    select::ConditionalSelect,
     util::InternalMaybe,
 };
 
@@ -111,6 +112,19 @@ impl<'dr, D: Driver<'dr>> Boolean<'dr, D> {
         Ok(a.add(dr, &cond_times_diff))
     }
 
+    /// Selects between two gadgets based on this boolean's value.
+    /// Returns `a` when false, `b` when true.
+    ///
+    /// This is the gadget-generic form of
+    /// [`conditional_select`](Self::conditional_select); its cost is
+    /// determined by the gadget's [`ConditionalSelect`] implementation.
+    pub fn select<G: Gadget<'dr, D>>(&self, dr: &mut D, a: &G, b: &G) -> Result<G>
+    where
+        G::Kind: ConditionalSelect<D::F>,
+    {
+        G::Kind::conditional_select(dr, self, a, b)
+    }
+
     /// Conditionally enforces that two elements are equal.
     /// When this boolean is true, enforces `a == b`; when false, no constraint.
     ///
@@ -228,6 +242,28 @@ impl<F: Field> Write<F> for Kind![F; @Boolean<'_, _>] {
     }
 }
 
+impl<F: Field> ConditionalSelect<F> for Kind![F; @Boolean<'_, _>] {
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Boolean<'dr, D>,
+        b: &Boolean<'dr, D>,
+    ) -> Result<Boolean<'dr, D>> {
+        // The selected wire is one of two booleans, so it remains boolean.
+        let selected = condition.conditional_select(dr, &a.element(), &b.element())?;
+        Ok(Boolean {
+            wire: selected.wire().clone(),
+            value: D::just(|| {
+                if condition.value().take() {
+                    b.value().take()
+                } else {
+                    a.value().take()
+                }
+            }),
+        })
+    }
+}
+
 impl<F: Field> Promotion<F> for Kind![F; @Boolean<'_, _>] {
     type Value = bool;
 
@@ -20,6 +20,7 @@ use crate::{
     comparison::GadgetEquals,
     consistent::Consistent,
     io::{Buffer, Write},
+    select::ConditionalSelect,
 };
 
 /// Represents a wire and its corresponding field element value, but generally
@@ -428,6 +429,17 @@ impl<F: Field> GadgetEquals<F> for Kind![F; @Element<'_, _>] {
     }
 }
 
+impl<F: Field> ConditionalSelect<F> for Kind![F; @Element<'_, _>] {
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Element<'dr, D>,
+        b: &Element<'dr, D>,
+    ) -> Result<Element<'dr, D>> {
+        condition.conditional_select(dr, a, b)
+    }
+}
+
 /// Simple buffer that collects pushed values into a vector.
 impl<'dr, D: Driver<'dr>> Buffer<'dr, D> for Vec<Element<'dr, D>> {
     fn write(&mut self, _: &mut D, value: &Element<'dr, D>) -> Result<()> {
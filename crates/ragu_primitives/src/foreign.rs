@@ -1,18 +1,20 @@
-//! [`Write`] and [`GadgetEquals`] implementations for foreign standard library
-//! types.
+//! [`Write`], [`GadgetEquals`] and [`ConditionalSelect`] implementations for
+//! foreign standard library types.
 //!
 //! Enables types like `()`, arrays, tuples, and `Box<T>` to participate in
-//! circuit I/O and equality checking.
+//! circuit I/O, equality checking and selection.
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::marker::PhantomData;
 
 use ragu_arithmetic::ff::Field;
 use ragu_core::{Result, drivers::Driver, gadgets::Bound};
 
 use crate::{
+    Boolean,
     comparison::GadgetEquals,
     io::{Buffer, Write},
+    select::ConditionalSelect,
 };
 
 impl<F: Field> Write<F> for () {
@@ -121,3 +123,61 @@ impl<F: Field, G: GadgetEquals<F>> GadgetEquals<F> for PhantomData<Box<G>> {
         G::enforce_equal_gadget(dr, a, b)
     }
 }
+
+impl<F: Field> ConditionalSelect<F> for () {
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        _: &mut D,
+        _: &Boolean<'dr, D>,
+        _: &(),
+        _: &(),
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<F: Field, G: ConditionalSelect<F>, const N: usize> ConditionalSelect<F>
+    for [PhantomData<G>; N]
+{
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Bound<'dr, D, Self>,
+        b: &Bound<'dr, D, Self>,
+    ) -> Result<Bound<'dr, D, Self>> {
+        let mut selected = Vec::with_capacity(N);
+        for (a, b) in a.iter().zip(b.iter()) {
+            selected.push(G::conditional_select(dr, condition, a, b)?);
+        }
+        let Ok(selected) = selected.try_into() else {
+            unreachable!("exactly N items were selected")
+        };
+        Ok(selected)
+    }
+}
+
+impl<F: Field, G1: ConditionalSelect<F>, G2: ConditionalSelect<F>> ConditionalSelect<F>
+    for (PhantomData<G1>, PhantomData<G2>)
+{
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Bound<'dr, D, Self>,
+        b: &Bound<'dr, D, Self>,
+    ) -> Result<Bound<'dr, D, Self>> {
+        Ok((
+            G1::conditional_select(dr, condition, &a.0, &b.0)?,
+            G2::conditional_select(dr, condition, &a.1, &b.1)?,
+        ))
+    }
+}
+
+impl<F: Field, G: ConditionalSelect<F>> ConditionalSelect<F> for PhantomData<Box<G>> {
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Bound<'dr, D, Self>,
+        b: &Bound<'dr, D, Self>,
+    ) -> Result<Bound<'dr, D, Self>> {
+        Ok(Box::new(G::conditional_select(dr, condition, a, b)?))
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
     comparison::GadgetEquals,
     consistent::Consistent,
     io::{Buffer, Write},
+    select::ConditionalSelect,
 };
 
 /// An [`Element`] that has been constrained nonzero in the constraint system.
@@ -22,7 +23,7 @@ use crate::{
 ///
 /// [`Nonzero`] dereferences to the underlying [`Element`], so all of
 /// [`Element`]'s methods are available directly on a [`Nonzero`].
-#[derive(Gadget, Write, GadgetEquals)]
+#[derive(Gadget, Write, GadgetEquals, ConditionalSelect)]
 pub struct Nonzero<'dr, D: Driver<'dr>> {
     element: Element<'dr, D>,
 }
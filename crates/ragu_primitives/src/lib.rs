@@ -26,9 +26,11 @@ mod endoscalar;
 mod foreign;
 mod invertible;
 pub mod io;
+mod one_hot;
 mod point;
 pub mod poseidon;
 pub mod promotion;
+pub mod select;
 mod sendable;
 mod simulator;
 pub mod suffix;
@@ -40,6 +42,7 @@ pub use element::{Element, multiadd};
 pub use endoscalar::{Endoscalar, extract_endoscalar, lift_endoscalar};
 pub use invertible::{Invertible, Nonzero, NonzeroBank};
 use io::{Buffer, Write};
+pub use one_hot::OneHot;
 pub use point::Point;
 use promotion::Demoted;
 use ragu_core::{Result, drivers::Driver, gadgets::Gadget};
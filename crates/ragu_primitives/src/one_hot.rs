@@ -0,0 +1,371 @@
+//! One-hot selector gadget for in-circuit tagged unions.
+//!
+//! Provides the [`OneHot`] type, a vector of [`Boolean`]s constrained so that
+//! exactly one of them is set.
+//!
+//! Gadgets must have the same shape for every witness, so the active variant
+//! of a tagged union cannot decide which wires exist. A tagged union instead
+//! holds a [`OneHot`] tag alongside the payload of every variant, with the
+//! payloads of inactive variants constrained to zero by
+//! [`OneHot::enforce_zero_unless`]. The tag then selects between per-variant
+//! results with [`OneHot::select`] and gates per-variant constraints with
+//! [`Boolean::conditional_enforce_equal`] on [`OneHot::is`]. The
+//! [`Gadget`](derive@ragu_core::gadgets::Gadget) derive generates this layout
+//! for a Rust `enum` of gadgets.
+
+use alloc::vec::Vec;
+
+use ragu_arithmetic::{Coeff, ff::Field};
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue, LinearExpression},
+    gadgets::Gadget,
+    maybe::Maybe,
+};
+
+#[cfg(test)]
+use crate::allocator::Standard;
+use crate::{
+    Boolean, Element, GadgetExt, allocator::Allocator, comparison::GadgetEquals,
+    consistent::Consistent, io::Write, select::ConditionalSelect,
+};
+
+/// Represents `N` booleans constrained so that exactly one of them is set,
+/// along with the index of the set boolean as its witness.
+#[derive(Gadget, Write, GadgetEquals, ConditionalSelect)]
+pub struct OneHot<'dr, D: Driver<'dr>, const N: usize> {
+    /// The booleans, of which exactly one is set.
+    #[ragu(gadget)]
+    bits: [Boolean<'dr, D>; N],
+}
+
+impl<'dr, D: Driver<'dr>, const N: usize> OneHot<'dr, D, N> {
+    /// Allocates a one-hot vector with the boolean at `index` set.
+    ///
+    /// This costs `N` gates and `2N + 1` constraints. Synthesis fails if
+    /// `index` is not less than `N`.
+    pub fn alloc(
+        dr: &mut D,
+        allocator: &mut impl Allocator<'dr, D>,
+        index: DriverValue<D, usize>,
+    ) -> Result<Self> {
+        let mut bits = Vec::with_capacity(N);
+        for i in 0..N {
+            bits.push(Boolean::alloc(
+                dr,
+                allocator,
+                index.as_ref().map(|index| *index == i),
+            )?);
+        }
+        let Ok(bits) = bits.try_into() else {
+            unreachable!("exactly N booleans were allocated")
+        };
+
+        let this = OneHot { bits };
+        this.enforce_sum(dr)?;
+
+        Ok(this)
+    }
+
+    /// Enforces that exactly one of the (already boolean) bits is set.
+    fn enforce_sum(&self, dr: &mut D) -> Result<()> {
+        dr.enforce_zero(|mut lc| {
+            for bit in &self.bits {
+                lc = lc.add(bit.wire());
+            }
+            lc.sub(&D::ONE)
+        })
+    }
+
+    /// Returns the boolean that is set when the index is `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    pub fn is(&self, i: usize) -> &Boolean<'dr, D> {
+        &self.bits[i]
+    }
+
+    /// Returns all `N` booleans.
+    pub fn bits(&self) -> &[Boolean<'dr, D>; N] {
+        &self.bits
+    }
+
+    /// Returns the index of the set boolean as an element. This is "free" in
+    /// the circuit model.
+    pub fn index(&self, dr: &mut D) -> Element<'dr, D> {
+        let mut weight = D::F::ZERO;
+        let mut elements = Vec::with_capacity(N);
+        for bit in &self.bits {
+            elements.push(bit.element().scale(dr, Coeff::Arbitrary(weight)));
+            weight += D::F::ONE;
+        }
+        Element::sum(dr, &elements)
+    }
+
+    /// Returns the gadget of `options` at the index of the set boolean.
+    ///
+    /// This performs `N - 1` [conditional selections](ConditionalSelect), so
+    /// selecting an [`Element`] costs `N - 1` gates and `2N - 2`
+    /// constraints. Synthesis fails if `N` is zero.
+    pub fn select<G: Gadget<'dr, D>>(&self, dr: &mut D, options: &[G; N]) -> Result<G>
+    where
+        G::Kind: ConditionalSelect<D::F>,
+    {
+        let Some((first, rest)) = options.split_first() else {
+            return Err(Error::Initialization(
+                "cannot select from zero options".into(),
+            ));
+        };
+
+        // At most one of the remaining bits is set, so each option replaces
+        // the running selection only when it is the selected one.
+        let mut selected = first.clone();
+        for (bit, option) in self.bits[1..].iter().zip(rest) {
+            selected = bit.select(dr, &selected, option)?;
+        }
+        Ok(selected)
+    }
+
+    /// Enforces that every element written by `payload` is zero unless the
+    /// boolean at index `i` is set.
+    ///
+    /// This costs one gate and three constraints per written element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `N`.
+    pub fn enforce_zero_unless<G: Gadget<'dr, D>>(
+        &self,
+        dr: &mut D,
+        i: usize,
+        payload: &G,
+    ) -> Result<()>
+    where
+        G::Kind: Write<D::F>,
+    {
+        let inactive = self.bits[i].not(dr).element();
+        let mut elements = Vec::new();
+        payload.write(dr, &mut elements)?;
+        for element in &elements {
+            inactive.mul(dr, element)?.enforce_zero(dr)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'dr, D: Driver<'dr>, const N: usize> Consistent<'dr, D> for OneHot<'dr, D, N> {
+    fn enforce_consistent(&self, dr: &mut D) -> Result<()> {
+        // The derive would only restore the invariants of each boolean, not
+        // the constraint between them.
+        self.bits.enforce_consistent(dr)?;
+        self.enforce_sum(dr)
+    }
+}
+
+#[test]
+fn test_one_hot_alloc() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    for index in 0..4 {
+        let sim = Simulator::simulate(index, |dr, index| {
+            let tag = OneHot::<_, 4>::alloc(dr, &mut (), index.clone())?;
+
+            for i in 0..4 {
+                assert_eq!(tag.is(i).value().take(), i == index.clone().take());
+            }
+            assert_eq!(*tag.index(dr).value().take(), F::from(index.take() as u64));
+
+            Ok(())
+        })?;
+        assert_eq!(sim.num_gates(), 4);
+        assert_eq!(sim.num_constraints(), 9);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_one_hot_out_of_range() {
+    type Simulator = crate::Simulator<ragu_pasta::Fp>;
+
+    assert!(
+        Simulator::simulate(4, |dr, index| {
+            OneHot::<_, 4>::alloc(dr, &mut (), index)?;
+            Ok(())
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn test_one_hot_select() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    for index in 0..3 {
+        let sim = Simulator::simulate(index, |dr, index| {
+            let allocator = &mut Standard::new();
+            let tag = OneHot::<_, 3>::alloc(dr, allocator, index.clone())?;
+            let options = [10u64, 20, 30].map(|value| Element::constant(dr, F::from(value)));
+
+            dr.reset();
+            let selected = tag.select(dr, &options)?;
+            assert_eq!(
+                *selected.value().take(),
+                F::from(10 * (index.take() as u64 + 1))
+            );
+
+            Ok(())
+        })?;
+        assert_eq!(sim.num_gates(), 2);
+        assert_eq!(sim.num_constraints(), 4);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_one_hot_consistent() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let sim = Simulator::simulate(1, |dr, index| {
+        let tag = OneHot::<_, 3>::alloc(dr, &mut (), index)?;
+
+        dr.reset();
+        tag.enforce_consistent(dr)
+    })?;
+    assert_eq!(sim.num_gates(), 3);
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[derive(Gadget, Write, GadgetEquals, Consistent, ConditionalSelect)]
+enum Shape<'dr, D: Driver<'dr>> {
+    Circle { radius: Element<'dr, D> },
+    Pair(Element<'dr, D>, Boolean<'dr, D>),
+    Empty,
+}
+
+#[cfg(test)]
+fn alloc_shape<'dr, D: Driver<'dr, F = ragu_pasta::Fp>>(
+    dr: &mut D,
+    index: DriverValue<D, usize>,
+    radius: u64,
+) -> Result<ShapeGadget<'dr, D>> {
+    let active = |i: usize| index.as_ref().map(move |index| *index == i);
+    let tag = OneHot::alloc(dr, &mut (), index.clone())?;
+    let radius = Element::alloc(
+        dr,
+        &mut (),
+        active(0).map(|active| ragu_pasta::Fp::from(if active { radius } else { 0 })),
+    )?;
+    let first = Element::alloc(
+        dr,
+        &mut (),
+        active(1).map(|active| ragu_pasta::Fp::from(if active { 7 } else { 0 })),
+    )?;
+    let second = Boolean::alloc(dr, &mut (), active(1))?;
+
+    ShapeGadget::new(
+        dr,
+        tag,
+        [
+            Shape::Circle { radius },
+            Shape::Pair(first, second),
+            Shape::Empty,
+        ],
+    )
+}
+
+#[test]
+fn test_tagged_union() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    for index in 0..3 {
+        Simulator::simulate(index, |dr, index| {
+            let shape = alloc_shape(dr, index.clone(), 5)?;
+            let area = shape.match_variants(dr, |dr, variant| match variant {
+                Shape::Circle { radius } => radius.square(dr),
+                Shape::Pair(first, second) => {
+                    let zero = Element::zero(dr);
+                    second.conditional_select(dr, &zero, &first)
+                }
+                Shape::Empty => Ok(Element::one()),
+            })?;
+            let expected = [25u64, 7, 1][*index.snag()];
+            assert_eq!(*area.value().take(), F::from(expected));
+
+            // The tag and every payload are written, inactive payloads as zero.
+            let mut elements = Vec::new();
+            shape.write(dr, &mut elements)?;
+            assert_eq!(elements.len(), 6);
+
+            shape.enforce_consistent(dr)?;
+
+            // Selecting between two instances agrees with the selected one.
+            let empty = alloc_shape(dr, Simulator::just(|| 2), 0)?;
+            let yes = Boolean::alloc(dr, &mut (), Simulator::just(|| true))?;
+            let selected = yes.select(dr, &empty, &shape)?;
+            selected.enforce_equal(dr, &shape)?;
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_tagged_union_inactive_payload() {
+    type Simulator = crate::Simulator<ragu_pasta::Fp>;
+
+    // The circle's radius is nonzero while the pair is active.
+    assert!(
+        Simulator::simulate(1, |dr, index| {
+            let tag = OneHot::alloc(dr, &mut (), index)?;
+            let radius = Element::alloc(dr, &mut (), Simulator::just(|| 5u64.into()))?;
+            let first = Element::zero(dr);
+            let second = Boolean::alloc(dr, &mut (), Simulator::just(|| false))?;
+            ShapeGadget::new(
+                dr,
+                tag,
+                [
+                    Shape::Circle { radius },
+                    Shape::Pair(first, second),
+                    Shape::Empty,
+                ],
+            )?;
+            Ok(())
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn test_tagged_union_variant_order() {
+    type Simulator = crate::Simulator<ragu_pasta::Fp>;
+
+    assert!(
+        Simulator::simulate(2, |dr, index| {
+            let tag = OneHot::alloc(dr, &mut (), index)?;
+            let first = Element::zero(dr);
+            let second = Boolean::alloc(dr, &mut (), Simulator::just(|| false))?;
+            ShapeGadget::new(
+                dr,
+                tag,
+                [
+                    Shape::Empty,
+                    Shape::Pair(first.clone(), second),
+                    Shape::Circle { radius: first },
+                ],
+            )?;
+            Ok(())
+        })
+        .is_err()
+    );
+}
@@ -16,7 +16,7 @@ use ragu_core::{
 
 use crate::{
     Boolean, Element, Nonzero, NonzeroBank, comparison::GadgetEquals, consistent::Consistent,
-    io::Write,
+    io::Write, select::ConditionalSelect,
 };
 
 /// Represents an affine point on a curve defined over the circuit's field.
@@ -30,7 +30,7 @@ use crate::{
 /// These assumptions are satisfied by the Pasta curves.
 ///
 /// As a result, the $x$ and $y$ coordinates are nonzero for every affine point.
-#[derive(Gadget, Write, GadgetEquals)]
+#[derive(Gadget, Write, GadgetEquals, ConditionalSelect)]
 pub struct Point<'dr, D: Driver<'dr>, C: CurveAffine<Base = D::F>> {
     #[ragu(gadget)]
     x: Nonzero<'dr, D>,
@@ -349,7 +349,11 @@ fn add_round_constants<'dr, D: Driver<'dr>>(
     }
 }
 
-struct Permutation<'a, F: Field, P: ragu_arithmetic::PoseidonPermutation<F>> {
+/// The Poseidon permutation over a [`SpongeState`], as a [`Routine`].
+///
+/// [`Sponge`] invokes this internally; it is exposed so that the permutation
+/// can be synthesized on its own, for example to extract its constraints.
+pub struct Permutation<'a, F: Field, P: ragu_arithmetic::PoseidonPermutation<F>> {
     params: &'a P,
     _marker: PhantomData<F>,
 }
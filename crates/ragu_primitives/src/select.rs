@@ -0,0 +1,46 @@
+//! Gadget-generic conditional selection.
+
+use ragu_arithmetic::ff::Field;
+use ragu_core::{
+    Result,
+    drivers::Driver,
+    gadgets::{Bound, GadgetKind},
+};
+
+use crate::Boolean;
+
+/// Represents a gadget that can be chosen between two instances of itself by a
+/// [`Boolean`] condition.
+///
+/// Like [`GadgetEquals`](crate::comparison::GadgetEquals), this is a subtrait
+/// of [`GadgetKind`] because selection needs the gadget's representation: raw
+/// wires carry no values, so each gadget decides how its wires and witness
+/// data are multiplexed. Concrete [`Gadget`](ragu_core::gadgets::Gadget)s are
+/// selected with [`Boolean::select`].
+///
+/// ### Automatic Derivation
+///
+/// Gadgets that consist of other gadgets and witness values are candidates for
+/// [automatic derivation](derive@ConditionalSelect) of this trait.
+pub trait ConditionalSelect<F: Field>: GadgetKind<F> {
+    /// Returns `a` when `condition` is false and `b` when it is true.
+    ///
+    /// Implementations may rely on `a` and `b` satisfying their gadget's
+    /// invariants; the result then satisfies them as well.
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Bound<'dr, D, Self>,
+        b: &Bound<'dr, D, Self>,
+    ) -> Result<Bound<'dr, D, Self>>;
+}
+
+/// Derives [`ConditionalSelect`] by selecting every nested gadget and witness
+/// value with the same condition.
+///
+/// This only works for structs with named fields, and uses the same field
+/// annotations as the [`Gadget`](derive@ragu_core::gadgets::Gadget) derive.
+/// Raw `#[ragu(wire)]` fields are rejected, since a wire has no selection
+/// strategy of its own; wrap it in a gadget such as
+/// [`Element`](crate::Element) instead.
+pub use ragu_macros::ConditionalSelect;
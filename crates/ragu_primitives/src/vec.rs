@@ -28,9 +28,11 @@ use ragu_core::{
 };
 
 use crate::{
+    Boolean,
     comparison::GadgetEquals,
     consistent::Consistent,
     io::{Buffer, Write},
+    select::ConditionalSelect,
 };
 
 /// A type that statically determines the length of a [`FixedVec`].
@@ -198,6 +200,23 @@ impl<F: Field, G: GadgetEquals<F>, L: Len> GadgetEquals<F> for FixedVec<PhantomD
     }
 }
 
+impl<F: Field, G: ConditionalSelect<F>, L: Len> ConditionalSelect<F>
+    for FixedVec<PhantomData<G>, L>
+{
+    fn conditional_select<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        condition: &Boolean<'dr, D>,
+        a: &Bound<'dr, D, Self>,
+        b: &Bound<'dr, D, Self>,
+    ) -> Result<Bound<'dr, D, Self>> {
+        let mut selected = Vec::with_capacity(L::len());
+        for (a, b) in a.iter().zip(b.iter()) {
+            selected.push(G::conditional_select(dr, condition, a, b)?);
+        }
+        FixedVec::new(selected)
+    }
+}
+
 impl<T, L: Len> Deref for FixedVec<T, L> {
     type Target = [T];
 
@@ -4,8 +4,9 @@
 //!
 //! - [`MySimpleCircuit`]: Proves knowledge of a and b such that a^5 = b^2 and outputs c = a+b, d = a-b.
 //! - [`SquareCircuit`]: Parameterized circuit that squares an input `times` times.
+//! - [`BitsCircuit`]: Parameterized circuit that allocates the low `bits` bits of an input as booleans.
 
-use ff::Field;
+use ff::{Field, PrimeField};
 use ragu_circuits::{Circuit, WithAux};
 use ragu_core::{
     Result,
@@ -13,7 +14,7 @@ use ragu_core::{
     gadgets::{Bound, Kind},
     maybe::Maybe,
 };
-use ragu_primitives::{Element, allocator::Standard};
+use ragu_primitives::{Boolean, Element, allocator::Standard};
 
 /// A simple circuit that proves knowledge of a and b such that a^5 = b^2
 /// and a + b = c and a - b = d where c and d are public inputs.
@@ -100,3 +101,48 @@ impl<F: Field> Circuit<F> for SquareCircuit {
         Ok(WithAux::new(a, D::unit()))
     }
 }
+
+/// A parameterized circuit that allocates the low `bits` bits of its input as
+/// booleans.
+///
+/// Given witness `w`, this circuit returns `w` as output. Its trace is
+/// dominated by zeros and ones, like the traces of circuits that decompose
+/// values into bits. The number of gates is `bits + 1`.
+pub struct BitsCircuit {
+    /// The number of bits to allocate.
+    pub bits: usize,
+}
+
+impl<F: PrimeField> Circuit<F> for BitsCircuit {
+    type Instance<'instance> = F;
+    type Output = Kind![F; Element<'_, _>];
+    type Witness<'witness> = F;
+    type Aux<'witness> = ();
+
+    fn instance<'dr, 'instance: 'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        instance: DriverValue<D, Self::Instance<'instance>>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        let allocator = &mut Standard::new();
+        Element::alloc(dr, allocator, instance)
+    }
+
+    fn witness<'dr, 'witness: 'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'witness>>,
+    ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'witness>>>> {
+        let allocator = &mut Standard::new();
+
+        for i in 0..self.bits {
+            let bit = witness
+                .as_ref()
+                .map(|w| (w.to_repr().as_ref()[i / 8] >> (i % 8)) & 1 == 1);
+            Boolean::alloc(dr, allocator, bit)?;
+        }
+
+        let a = Element::alloc(dr, allocator, witness)?;
+        Ok(WithAux::new(a, D::unit()))
+    }
+}
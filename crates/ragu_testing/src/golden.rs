@@ -0,0 +1,177 @@
+//! Golden-file guard against unintended circuit changes.
+//!
+//! Small refactors of gadget code can silently change a circuit, which
+//! invalidates every proof created before the change. [`Golden`] snapshots the
+//! [`CircuitFingerprint`] of every registered circuit into a golden file, and
+//! panics when any of them differ, naming the circuits that changed.
+//!
+//! Once a change has been reviewed and is intended, rerun the test with the
+//! [`UPDATE_VAR`] environment variable set to rewrite the golden file, and
+//! commit it alongside the change.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use ff::{FromUniformBytes, PrimeField};
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{
+    polynomials::Rank,
+    registry::{CircuitFingerprint, Registry},
+};
+use ragu_pcd::Application;
+
+/// The environment variable that makes [`Golden`] write the golden file
+/// instead of checking it.
+pub const UPDATE_VAR: &str = "RAGU_UPDATE_GOLDEN";
+
+/// A golden file of circuit fingerprints.
+pub struct Golden {
+    path: PathBuf,
+    update: bool,
+}
+
+impl Golden {
+    /// Creates a guard for the golden file at `path`, which is written
+    /// rather than checked if [`UPDATE_VAR`] is set.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Golden {
+            path: path.as_ref().to_path_buf(),
+            update: std::env::var_os(UPDATE_VAR).is_some(),
+        }
+    }
+
+    /// Overrides whether the golden file is written rather than checked.
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Checks the fingerprint of every circuit of `app` against the golden
+    /// file.
+    ///
+    /// Application steps are labeled by [`Step::INDEX`](ragu_pcd::step::Step::INDEX)
+    /// and internal circuits by their index in the native or nested registry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the golden file does not exist or any fingerprint differs
+    /// from it.
+    pub fn check_application<C: Cycle, R: Rank, const HEADER_SIZE: usize>(
+        &self,
+        app: &Application<'_, C, R, HEADER_SIZE>,
+    ) {
+        let report = app.circuit_fingerprints();
+        let num_internal = report.native().len() - report.steps().len();
+
+        let native = report.native()[..num_internal]
+            .iter()
+            .map(|fp| (label("native", usize::from(fp.index)), entry(fp)));
+        let steps = report
+            .steps()
+            .iter()
+            .enumerate()
+            .map(|(i, fp)| (label("step", i), entry(fp)));
+        let nested = report
+            .nested()
+            .iter()
+            .map(|fp| (label("nested", usize::from(fp.index)), entry(fp)));
+
+        self.check(native.chain(steps).chain(nested).collect());
+    }
+
+    /// Checks the fingerprint of every circuit of `registry` against the
+    /// golden file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the golden file does not exist or any fingerprint differs
+    /// from it.
+    pub fn check_registry<F: FromUniformBytes<64>, R: Rank>(&self, registry: &Registry<'_, F, R>) {
+        self.check(
+            registry
+                .circuit_fingerprints()
+                .into_iter()
+                .map(|fp| (label("circuit", usize::from(fp.index)), entry(&fp)))
+                .collect(),
+        );
+    }
+
+    fn check(&self, actual: BTreeMap<String, String>) {
+        if self.update {
+            let mut contents = format!("# Circuit fingerprints; set {UPDATE_VAR} to regenerate.\n");
+            for (label, entry) in &actual {
+                writeln!(contents, "{label}: {entry}").expect("writing to a string succeeds");
+            }
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent).expect("golden file directory can be created");
+            }
+            fs::write(&self.path, contents).expect("golden file can be written");
+            return;
+        }
+
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => panic!(
+                "golden file {} does not exist; set {UPDATE_VAR} to create it",
+                self.path.display()
+            ),
+            Err(err) => panic!("golden file {} is unreadable: {err}", self.path.display()),
+        };
+        let expected: BTreeMap<String, String> = contents
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (label, entry) = line
+                    .split_once(": ")
+                    .unwrap_or_else(|| panic!("malformed golden file line: {line}"));
+                (label.into(), entry.into())
+            })
+            .collect();
+
+        let mut changes = Vec::new();
+        for (label, entry) in &expected {
+            match actual.get(label) {
+                None => changes.push(format!("removed {label}: {entry}")),
+                Some(actual) if actual != entry => {
+                    changes.push(format!("changed {label}: {entry} -> {actual}"))
+                }
+                Some(_) => {}
+            }
+        }
+        for (label, entry) in &actual {
+            if !expected.contains_key(label) {
+                changes.push(format!("added {label}: {entry}"));
+            }
+        }
+
+        assert!(
+            changes.is_empty(),
+            "circuits differ from golden file {}; this invalidates existing proofs, \
+             so if it is intended, set {UPDATE_VAR} to update it:\n  {}",
+            self.path.display(),
+            changes.join("\n  ")
+        );
+    }
+}
+
+/// Renders a fingerprint as a golden file entry, with the evaluation in
+/// big-endian hexadecimal.
+fn entry<F: PrimeField>(fp: &CircuitFingerprint<F>) -> String {
+    let mut eval = String::new();
+    for byte in fp.eval.to_repr().as_ref().iter().rev() {
+        write!(eval, "{byte:02x}").expect("writing to a string succeeds");
+    }
+    format!(
+        "gates {} constraints {} eval {eval}",
+        fp.num_gates, fp.num_constraints
+    )
+}
+
+/// Labels a circuit so that golden file entries sort by kind, then index.
+fn label(kind: &str, index: usize) -> String {
+    format!("{kind} {index:04}")
+}
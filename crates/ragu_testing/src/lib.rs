@@ -1,6 +1,8 @@
 //! Test fixtures for Ragu crates.
 
 pub mod circuits;
+pub mod golden;
 pub mod pcd;
 pub mod registry;
+pub mod step;
 pub mod strategies;
@@ -1,14 +1,15 @@
 //! Nontrivial test fixtures with Poseidon hashing.
 
-use ff::Field;
+use ff::{Field, PrimeField};
 use ragu_arithmetic::Cycle;
 use ragu_core::{
-    Result,
+    Error, Result,
     drivers::{Driver, DriverValue},
     gadgets::{Bound, Kind},
     maybe::Maybe,
 };
 use ragu_pcd::{
+    PersistentStep,
     header::{Header, Suffix},
     step::{Encoded, Index, Step},
 };
@@ -144,3 +145,36 @@ impl<C: Cycle> Step<C> for WitnessLeaf<'_, C> {
         ))
     }
 }
+
+impl<C: Cycle> PersistentStep<C> for Hash2<'_, C> {
+    fn encode_data(output: &C::CircuitField, _: &(), bytes: &mut Vec<u8>) {
+        bytes.extend(output.to_repr().as_ref());
+    }
+
+    fn decode_data<'source>(bytes: &[u8]) -> Result<(C::CircuitField, Self::Aux<'source>)> {
+        Ok((decode_field(bytes)?, ()))
+    }
+}
+
+impl<C: Cycle> PersistentStep<C> for WitnessLeaf<'_, C> {
+    fn encode_data(output: &C::CircuitField, _: &(), bytes: &mut Vec<u8>) {
+        bytes.extend(output.to_repr().as_ref());
+    }
+
+    fn decode_data<'source>(bytes: &[u8]) -> Result<(C::CircuitField, Self::Aux<'source>)> {
+        Ok((decode_field(bytes)?, ()))
+    }
+}
+
+/// Decodes a field element from exactly its canonical representation.
+fn decode_field<F: PrimeField>(bytes: &[u8]) -> Result<F> {
+    let mut repr = F::Repr::default();
+    if bytes.len() != repr.as_ref().len() {
+        return Err(Error::MalformedEncoding(
+            "step data is not a field element".into(),
+        ));
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    Option::from(F::from_repr(repr))
+        .ok_or_else(|| Error::MalformedEncoding("non-canonical field element".into()))
+}
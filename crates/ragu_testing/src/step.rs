@@ -0,0 +1,103 @@
+//! Test harness for running a [`Step`] without proving.
+//!
+//! Testing a step through an [`Application`](ragu_pcd::Application) requires
+//! finalizing both registries and creating proofs for every case. [`simulate`]
+//! instead synthesizes the step's [`Adapter`] circuit under a [`Simulator`],
+//! which checks every gate and constraint as it goes. This is cheap enough to
+//! drive from proptest with the [`strategies`](crate::strategies) module.
+
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{
+    Circuit,
+    polynomials::{Rank, min_rank},
+};
+use ragu_core::{Result, maybe::Maybe};
+use ragu_pcd::{
+    header::Header,
+    step::{Adapter, Step},
+};
+use ragu_primitives::Simulator;
+
+/// The header data of a step's left input.
+pub type LeftData<C, S> = <<S as Step<C>>::Left as Header<<C as Cycle>::CircuitField>>::Data;
+
+/// The header data of a step's right input.
+pub type RightData<C, S> = <<S as Step<C>>::Right as Header<<C as Cycle>::CircuitField>>::Data;
+
+/// The header data of a step's output.
+pub type OutputData<C, S> = <<S as Step<C>>::Output as Header<<C as Cycle>::CircuitField>>::Data;
+
+/// The result of simulating a [`Step`] whose constraints were satisfied.
+pub struct Simulation<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize, A> {
+    /// The encoding of the left header, including its padding and suffix.
+    pub left_header: [C::CircuitField; HEADER_SIZE],
+    /// The encoding of the right header, including its padding and suffix.
+    pub right_header: [C::CircuitField; HEADER_SIZE],
+    /// The encoding of the output header, including its padding and suffix.
+    pub output_header: [C::CircuitField; HEADER_SIZE],
+    /// The header data the step produced.
+    pub output_data: OutputData<C, S>,
+    /// The auxiliary data the step produced.
+    pub aux: A,
+    /// The number of gates the step used.
+    pub num_gates: usize,
+    /// The number of constraints the step used.
+    pub num_constraints: usize,
+    _marker: core::marker::PhantomData<R>,
+}
+
+impl<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize, A>
+    Simulation<C, S, R, HEADER_SIZE, A>
+{
+    /// Returns the smallest rank with room for the step's gates and
+    /// constraints.
+    ///
+    /// This only accounts for the step itself, so the registered circuit may
+    /// need slightly more.
+    pub fn min_rank(&self) -> u32 {
+        min_rank(self.num_gates, self.num_constraints)
+    }
+
+    /// Returns true if the step's gates and constraints fit within `R`.
+    pub fn fits(&self) -> bool {
+        self.min_rank() <= R::RANK
+    }
+}
+
+/// Runs `step` on the given header data and witness under a [`Simulator`],
+/// without proving.
+///
+/// Returns an error if the step's constraints are not satisfied, or if the
+/// step itself fails, for example because its header encodings do not fit in
+/// `HEADER_SIZE`.
+pub fn simulate<'source, C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize>(
+    step: S,
+    left: LeftData<C, S>,
+    right: RightData<C, S>,
+    witness: S::Witness<'source>,
+) -> Result<Simulation<C, S, R, HEADER_SIZE, S::Aux<'source>>> {
+    let adapter = Adapter::<C, S, R, HEADER_SIZE>::new(step);
+
+    let mut result = None;
+    let sim = Simulator::simulate((left, right, witness), |dr, witness| {
+        let (elements, aux) = adapter.witness(dr, witness)?.into_parts();
+        let encoding =
+            |i: usize| core::array::from_fn(|j| *elements[i * HEADER_SIZE + j].value().take());
+        let (_, output_data, aux) = aux.take();
+        result = Some((encoding(0), encoding(1), encoding(2), output_data, aux));
+        Ok(())
+    })?;
+    let (left_header, right_header, output_header, output_data, aux) =
+        result.expect("simulation succeeded");
+
+    Ok(Simulation {
+        left_header,
+        right_header,
+        output_header,
+        output_data,
+        aux,
+        num_gates: sim.num_gates(),
+        num_constraints: sim.num_constraints(),
+        _marker: core::marker::PhantomData,
+    })
+}
@@ -37,6 +37,12 @@ use crate::{
 /// [`Driver::ONE`] is represented as the constant expression `1`, not as a
 /// wire. Fresh allocations begin at index 0.
 ///
+/// Virtual wires are inlined into every expression that uses them, so a
+/// chain of linear layers (as in Poseidon) grows exponentially. A driver built
+/// with [`Self::flattening`] instead [flattens](Expr::flatten) each virtual
+/// wire into a sum over physical wires, which keeps large circuits tractable
+/// but changes the shape of the recorded expressions.
+///
 /// After synthesis, the collected [`Op`]s are available via [`Self::ops`].
 pub struct ExtractionDriver<F: Field> {
     /// Next wire index to assign on allocation.
@@ -45,6 +51,8 @@ pub struct ExtractionDriver<F: Field> {
     next_input_wire: usize,
     /// Ordered sequence of operations recorded during synthesis.
     pub ops: Vec<Op<F>>,
+    /// Whether virtual wires are flattened into sums over physical wires.
+    flatten: bool,
     _phantom: PhantomData<F>,
 }
 
@@ -55,10 +63,19 @@ impl<F: Field> ExtractionDriver<F> {
             next_wire: 0,
             next_input_wire: 0,
             ops: Vec::new(),
+            flatten: false,
             _phantom: PhantomData,
         }
     }
 
+    /// Creates a new extraction driver that flattens virtual wires.
+    pub fn flattening() -> Self {
+        ExtractionDriver {
+            flatten: true,
+            ..Self::new()
+        }
+    }
+
     /// Allocates the next available wire index and advances the counter.
     fn alloc_wire(&mut self) -> usize {
         let idx = self.next_wire;
@@ -171,7 +188,8 @@ impl<'dr, F: Field> Driver<'dr> for ExtractionDriver<F> {
     /// [`Expr`] can be used freely in subsequent [`Driver::mul`] and [`Driver::enforce_zero`]
     /// calls.
     fn add(&mut self, lc: impl Fn(ExprLc<F>) -> ExprLc<F>) -> Expr<F> {
-        lc(ExprLc::default()).into_expr()
+        let expr = lc(ExprLc::default()).into_expr();
+        if self.flatten { expr.flatten() } else { expr }
     }
 
     /// Builds the constraint expression and records an [`Op::Assert`].
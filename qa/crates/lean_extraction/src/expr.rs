@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use ff::Field;
 use ragu_arithmetic::Coeff;
 
@@ -31,6 +33,76 @@ pub enum Expr<F: Field> {
     Mul(Box<Expr<F>>, Box<Expr<F>>),
 }
 
+impl<F: Field> Expr<F> {
+    /// Rewrites a linear expression as a sum of scaled wires, in order of
+    /// physical wires before input wires and then by index, followed by a
+    /// single constant. Repeated wires and constants are merged.
+    ///
+    /// Nonlinear expressions are returned unchanged.
+    pub fn flatten(self) -> Self {
+        let mut terms = BTreeMap::new();
+        let mut constant = F::ZERO;
+        if !self.collect_linear(F::ONE, &mut terms, &mut constant) {
+            return self;
+        }
+
+        let scaled = |coeff: F, wire: Expr<F>| {
+            if coeff == F::ONE {
+                wire
+            } else {
+                Expr::Mul(
+                    Box::new(Expr::Const(Coeff::Arbitrary(coeff))),
+                    Box::new(wire),
+                )
+            }
+        };
+        terms
+            .into_iter()
+            .filter(|(_, coeff)| !bool::from(coeff.is_zero()))
+            .map(|((is_input, index), coeff)| {
+                let wire = if is_input {
+                    Expr::InputVar(index)
+                } else {
+                    Expr::Var(index)
+                };
+                scaled(coeff, wire)
+            })
+            .chain(
+                (!bool::from(constant.is_zero()))
+                    .then(|| scaled(constant, Expr::Const(Coeff::One))),
+            )
+            .reduce(|acc, term| Expr::Add(Box::new(acc), Box::new(term)))
+            .unwrap_or(Expr::Const(Coeff::Zero))
+    }
+
+    /// Adds `scale` times this expression to `terms` and `constant`, keyed by
+    /// whether each wire is an input and its index. Returns `false` if the
+    /// expression is not linear.
+    fn collect_linear(
+        &self,
+        scale: F,
+        terms: &mut BTreeMap<(bool, usize), F>,
+        constant: &mut F,
+    ) -> bool {
+        match self {
+            Expr::Var(index) => *terms.entry((false, *index)).or_insert(F::ZERO) += scale,
+            Expr::InputVar(index) => *terms.entry((true, *index)).or_insert(F::ZERO) += scale,
+            Expr::Const(coeff) => *constant += scale * coeff.value(),
+            Expr::Add(left, right) => {
+                return left.collect_linear(scale, terms, constant)
+                    && right.collect_linear(scale, terms, constant);
+            }
+            Expr::Mul(left, right) => match (&**left, &**right) {
+                (Expr::Const(coeff), other) | (other, Expr::Const(coeff)) => {
+                    return other.collect_linear(scale * coeff.value(), terms, constant);
+                }
+                _ => return false,
+            },
+        }
+        true
+    }
+}
+
 /// A single operation collected during circuit synthesis.
 ///
 /// Mirrors the flat operations of Clean's `FlatOperation F`:
@@ -0,0 +1,209 @@
+//! Generic extraction of circuits, routines and gadget-level functions.
+//!
+//! The formal instances in [`crate::instances`] build their input gadgets by
+//! hand. The helpers here instead build them from a *template* gadget of the
+//! input type, so that any [`Circuit`], [`Routine`] or function over gadgets
+//! (including an application step wrapped in `ragu_pcd::step::Adapter`) can be
+//! extracted without writing per-instance glue. [`lean_module`] then renders
+//! the extracted trace as a Lean constraint module, for auditors to reason
+//! about directly rather than through a handwritten reimplementation.
+
+use ff::{Field, PrimeField};
+use ragu_circuits::Circuit;
+use ragu_core::{
+    Result,
+    drivers::Driver,
+    gadgets::{Bound, Gadget},
+    maybe::Empty,
+    routines::Routine,
+};
+
+use crate::{
+    driver::ExtractionDriver,
+    expr::{Expr, Op},
+    fingerprint::INPUT_VAR_OFFSET,
+    instance::{ExtractedTrace, WireCollector, WireDeserializer},
+};
+
+/// Allocates input wires for a gadget shaped like the one `template` returns.
+///
+/// The template is built on a scratch driver, so any operations it emits are
+/// discarded; only its structure is used.
+pub fn inputs<'dr, F: Field, G>(
+    dr: &mut ExtractionDriver<F>,
+    template: impl FnOnce(&mut ExtractionDriver<F>) -> Result<G>,
+) -> Result<G>
+where
+    G: Gadget<'dr, ExtractionDriver<F>>,
+    ExtractionDriver<F>: Driver<'dr, F = F>,
+{
+    let template = template(&mut ExtractionDriver::new())?;
+    let wires = dr.alloc_input_wires(template.num_wires()?);
+    WireDeserializer::new(wires).into_gadget(&template)
+}
+
+/// Synthesizes the witness of `circuit` and returns its output wires.
+///
+/// The circuit has no input wires: everything it allocates is part of the
+/// extracted trace.
+pub fn circuit<'dr, F: Field, C: Circuit<F> + 'dr>(
+    dr: &mut ExtractionDriver<F>,
+    circuit: &C,
+) -> Result<Vec<Expr<F>>> {
+    let output = circuit.witness(dr, Empty)?.into_output();
+    WireCollector::collect_from(&output)
+}
+
+/// Executes `routine` on input wires shaped like `template` and returns its
+/// output wires.
+pub fn routine<'dr, F: Field, R: Routine<F> + 'dr>(
+    dr: &mut ExtractionDriver<F>,
+    routine: R,
+    template: impl FnOnce(&mut ExtractionDriver<F>) -> Result<Bound<'dr, ExtractionDriver<F>, R::Input>>,
+) -> Result<Vec<Expr<F>>> {
+    let input = inputs(dr, template)?;
+    let output = dr.routine(routine, input)?;
+    WireCollector::collect_from(&output)
+}
+
+/// Returns the Lean name of the prime defining `F`.
+fn lean_prime<F: PrimeField>() -> &'static str {
+    if F::MODULUS == ragu_pasta::Fp::MODULUS {
+        "Ragu.Core.Primes.p"
+    } else if F::MODULUS == ragu_pasta::Fq::MODULUS {
+        "Ragu.Core.Primes.q"
+    } else {
+        panic!("no Lean prime is defined for modulus {}", F::MODULUS)
+    }
+}
+
+/// Renders `value` as a big-endian hexadecimal literal.
+fn lean_field_element<F: PrimeField>(value: F) -> String {
+    let repr = value.to_repr();
+    let hex: String = repr
+        .as_ref()
+        .iter()
+        .rev()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!("0x{hex}")
+}
+
+fn lean_expr<F: PrimeField>(expr: &Expr<F>) -> String {
+    match expr {
+        Expr::Var(index) => format!(".var ⟨{index}⟩"),
+        // Input variables follow the fingerprint convention, so that the
+        // module can be instantiated at the canonical input vector.
+        Expr::InputVar(index) => format!(".var ⟨{}⟩", INPUT_VAR_OFFSET + *index as u64),
+        Expr::Const(coeff) => format!(".const {}", lean_field_element(coeff.value())),
+        Expr::Add(left, right) => format!(".add ({}) ({})", lean_expr(left), lean_expr(right)),
+        Expr::Mul(left, right) => format!(".mul ({}) ({})", lean_expr(left), lean_expr(right)),
+    }
+}
+
+fn lean_op<F: PrimeField>(op: &Op<F>) -> String {
+    match op {
+        Op::Witness { count } => format!(".witness {count} (fun _ => default)"),
+        Op::Assert(expr) => format!(".assert ({})", lean_expr(expr)),
+    }
+}
+
+fn lean_list(items: impl Iterator<Item = String>) -> String {
+    let items = items
+        .map(|item| format!("  {item}"))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    if items.is_empty() {
+        "[]".into()
+    } else {
+        format!("[\n{items}\n]")
+    }
+}
+
+/// Renders `trace` as the Lean module `name`.
+///
+/// The module defines the trace's `inputLen`, its flat `operations` and its
+/// `outputs`, and records `fingerprint` (see [`crate::fingerprint`]) in its
+/// documentation. Witness computation functions are not extracted, so every
+/// witness operation is rendered with a default one.
+pub fn lean_module<F: PrimeField>(
+    name: &str,
+    trace: &ExtractedTrace<F>,
+    fingerprint: &str,
+) -> String {
+    let prime = lean_prime::<F>();
+    let input_len = trace.input_len;
+    let operations = lean_list(trace.ops.iter().map(lean_op));
+    let outputs = lean_list(trace.outputs.iter().map(lean_expr));
+
+    format!(
+        "import Ragu.Core\n\n\
+         /-!\n\
+         # `{name}`\n\n\
+         Autogenerated by `cargo run -p lean_extraction -- extract {name}`; do not edit.\n\n\
+         Fingerprint: `{fingerprint}`\n\
+         -/\n\n\
+         namespace {name}\n\n\
+         @[reducible]\n\
+         def p := {prime}\n\n\
+         /-- The number of input variables, which are numbered from `2³²`. -/\n\
+         def inputLen : ℕ := {input_len}\n\n\
+         set_option maxRecDepth 100000 in\n\
+         /-- The extracted operations. -/\n\
+         def operations : List (FlatOperation (F p)) := {operations}\n\n\
+         set_option maxRecDepth 100000 in\n\
+         /-- The extracted output expressions. -/\n\
+         def outputs : List (Expression (F p)) := {outputs}\n\n\
+         end {name}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lean_module;
+    use crate::{
+        instance::CircuitInstance,
+        instances::{
+            element_square::ElementSquareInstance,
+            endoscalar_group_scale::EndoscalarGroupScaleInstance,
+            poseidon_permutation::PoseidonPermutationInstance, step_hash2::StepHash2Instance,
+        },
+    };
+
+    #[test]
+    fn committed_module_is_up_to_date() {
+        let trace = ElementSquareInstance::flattened_trace();
+        let digest = crate::fingerprint::digest_hex(trace.input_len, &trace.ops, &trace.outputs);
+        assert_eq!(
+            lean_module("Ragu.Extracted.Element.Square", &trace, &digest),
+            include_str!("../../../lean/Ragu/Extracted/Element/Square.lean")
+        );
+    }
+
+    #[test]
+    fn routine_inputs_match_outputs() {
+        let trace = PoseidonPermutationInstance::flattened_trace();
+        assert!(trace.input_len > 0);
+        assert_eq!(trace.outputs.len(), trace.input_len);
+    }
+
+    #[test]
+    fn group_scale_takes_endoscalar_and_point() {
+        let trace = EndoscalarGroupScaleInstance::flattened_trace();
+        // 128 endoscalar bits and the two point coordinates.
+        assert_eq!(trace.input_len, 128 + 2);
+        assert_eq!(trace.outputs.len(), 2);
+    }
+
+    #[test]
+    fn step_outputs_three_headers() {
+        let trace = StepHash2Instance::flattened_trace();
+        assert_eq!(trace.input_len, 0);
+        assert_eq!(trace.outputs.len(), 3 * 4);
+
+        let module = lean_module("Ragu.Extracted.Step.Hash2", &trace, "00");
+        assert!(module.contains("namespace Ragu.Extracted.Step.Hash2\n"));
+        assert!(module.contains("def inputLen : ℕ := 0\n"));
+        assert!(module.ends_with("end Ragu.Extracted.Step.Hash2\n"));
+    }
+}
@@ -125,7 +125,17 @@ pub trait CircuitInstance {
 
     /// Run the circuit on a fresh driver and return its extracted trace.
     fn extracted_trace() -> ExtractedTrace<Self::Field> {
-        let mut dr = ExtractionDriver::<Self::Field>::new();
+        Self::trace_with(ExtractionDriver::new())
+    }
+
+    /// Run the circuit on a fresh [flattening](ExtractionDriver::flattening)
+    /// driver and return its extracted trace.
+    fn flattened_trace() -> ExtractedTrace<Self::Field> {
+        Self::trace_with(ExtractionDriver::flattening())
+    }
+
+    /// Run the circuit on `dr` and return its extracted trace.
+    fn trace_with(mut dr: ExtractionDriver<Self::Field>) -> ExtractedTrace<Self::Field> {
         let outputs = Self::circuit(&mut dr).expect("circuit failed");
         ExtractedTrace {
             input_len: dr.input_wire_count(),
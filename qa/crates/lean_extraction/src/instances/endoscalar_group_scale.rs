@@ -0,0 +1,29 @@
+use group::CurveAffine;
+use ragu_core::drivers::Driver;
+use ragu_pasta::{EpAffine, Fp};
+use ragu_primitives::{Endoscalar, Point};
+
+use crate::{
+    driver::ExtractionDriver,
+    expr::Expr,
+    extract,
+    instance::{CircuitInstance, WireCollector},
+};
+
+pub struct EndoscalarGroupScaleInstance;
+
+impl CircuitInstance for EndoscalarGroupScaleInstance {
+    type Field = Fp;
+
+    fn circuit(dr: &mut ExtractionDriver<Fp>) -> ragu_core::Result<Vec<Expr<Fp>>> {
+        // MaybeKind = Empty: the closure is never called.
+        let endoscalar = extract::inputs(dr, |dr| {
+            Endoscalar::alloc(dr, ExtractionDriver::<Fp>::just(|| 0))
+        })?;
+        let point = extract::inputs(dr, |dr| Point::constant(dr, EpAffine::generator()))?;
+
+        let scaled = endoscalar.group_scale(dr, &point)?;
+
+        WireCollector::collect_from(&scaled)
+    }
+}
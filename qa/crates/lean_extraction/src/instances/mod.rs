@@ -16,6 +16,7 @@ pub mod element_is_equal;
 pub mod element_is_zero;
 pub mod element_mul;
 pub mod element_square;
+pub mod endoscalar_group_scale;
 pub mod nonzero_bank_scope;
 pub mod point_add_incomplete;
 pub mod point_alloc;
@@ -23,3 +24,5 @@ pub mod point_conditional_endo;
 pub mod point_conditional_negate;
 pub mod point_double;
 pub mod point_double_and_add_incomplete;
+pub mod poseidon_permutation;
+pub mod step_hash2;
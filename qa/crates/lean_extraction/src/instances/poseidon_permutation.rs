@@ -0,0 +1,21 @@
+use ragu_pasta::{Fp, PoseidonFp};
+use ragu_primitives::{
+    Element,
+    poseidon::{Permutation, SpongeState},
+    vec::FixedVec,
+};
+
+use crate::{driver::ExtractionDriver, expr::Expr, extract, instance::CircuitInstance};
+
+pub struct PoseidonPermutationInstance;
+
+impl CircuitInstance for PoseidonPermutationInstance {
+    type Field = Fp;
+
+    fn circuit(dr: &mut ExtractionDriver<Fp>) -> ragu_core::Result<Vec<Expr<Fp>>> {
+        extract::routine(dr, Permutation::from(&PoseidonFp), |dr| {
+            let state = FixedVec::from_fn(|_| Element::constant(dr, Fp::zero()));
+            Ok(SpongeState::from_elements(state))
+        })
+    }
+}
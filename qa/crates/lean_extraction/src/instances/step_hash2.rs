@@ -0,0 +1,21 @@
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_pasta::{Fp, Pasta, PoseidonFp};
+use ragu_pcd::step::Adapter;
+use ragu_testing::pcd::nontrivial::Hash2;
+
+use crate::{driver::ExtractionDriver, expr::Expr, extract, instance::CircuitInstance};
+
+/// The `Hash2` test fixture step, as registered in an application with
+/// `HEADER_SIZE = 4`.
+pub struct StepHash2Instance;
+
+impl CircuitInstance for StepHash2Instance {
+    type Field = Fp;
+
+    fn circuit(dr: &mut ExtractionDriver<Fp>) -> ragu_core::Result<Vec<Expr<Fp>>> {
+        let step = Hash2::<Pasta> {
+            poseidon_params: &PoseidonFp,
+        };
+        extract::circuit(dr, &Adapter::<Pasta, _, ProductionRank, 4>::new(step))
+    }
+}
@@ -1,5 +1,6 @@
 mod driver;
 mod expr;
+mod extract;
 mod fingerprint;
 mod instance;
 mod instances;
@@ -36,6 +37,7 @@ use crate::instances::{
     element_is_zero::ElementIsZeroInstance,
     element_mul::ElementMulInstance,
     element_square::ElementSquareInstance,
+    endoscalar_group_scale::EndoscalarGroupScaleInstance,
     nonzero_bank_scope::NonzeroBankScopeInstanceK2,
     point_add_incomplete::PointAddIncompleteInstance,
     point_alloc::{PointAllocInstanceFp, PointAllocInstanceFq},
@@ -43,6 +45,8 @@ use crate::instances::{
     point_conditional_negate::PointConditionalNegateInstance,
     point_double::PointDoubleInstance,
     point_double_and_add_incomplete::PointDoubleAndAddIncompleteInstance,
+    poseidon_permutation::PoseidonPermutationInstance,
+    step_hash2::StepHash2Instance,
 };
 
 struct ExportTarget {
@@ -169,6 +173,43 @@ static EXPORT_TARGETS: &[ExportTarget] = &[
     },
 ];
 
+struct ExtractTarget {
+    /// Lean module name of the generated constraint module.
+    name: &'static str,
+    /// Renders the generated Lean module and computes the canonical digest of
+    /// the instance's extracted trace.
+    extract: fn(&str) -> (String, String),
+    /// Whether the generated module is committed to the Lean tree, so that
+    /// `check` keeps it up to date and `lake build` type-checks it.
+    committed: bool,
+}
+
+/// Instances that `extract` can render into a generated Lean constraint
+/// module, without a handwritten reimplementation. Add an entry here to make
+/// a circuit, routine or application step available to auditors.
+static EXTRACT_TARGETS: &[ExtractTarget] = &[
+    ExtractTarget {
+        name: "Ragu.Extracted.Element.Square",
+        extract: extract_instance::<ElementSquareInstance>,
+        committed: true,
+    },
+    ExtractTarget {
+        name: "Ragu.Extracted.Poseidon.Permutation",
+        extract: extract_instance::<PoseidonPermutationInstance>,
+        committed: true,
+    },
+    ExtractTarget {
+        name: "Ragu.Extracted.Endoscalar.GroupScale",
+        extract: extract_instance::<EndoscalarGroupScaleInstance>,
+        committed: true,
+    },
+    ExtractTarget {
+        name: "Ragu.Extracted.Step.Hash2",
+        extract: extract_instance::<StepHash2Instance>,
+        committed: true,
+    },
+];
+
 fn default_autogen_root() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../lean")
 }
@@ -185,18 +226,26 @@ struct Cli {
     autogen_root: PathBuf,
 }
 
-#[derive(Subcommand, Clone, Copy)]
+#[derive(Subcommand, Clone)]
 enum Command {
     /// Write the generated Lean files (the instance import root and the
     /// fingerprint instance list) to disk.
     Export,
-    /// Compare the generated Lean files with the files already on disk.
+    /// Compare the generated Lean files, including the committed constraint
+    /// modules of extraction targets, with the files already on disk.
     Check,
     /// Print the canonical fingerprint digest of every exported instance.
     ///
     /// CI compares this output against the same digests computed in Lean from
     /// the Clean reimplementations.
     Fingerprint,
+    /// Write the generated Lean constraint module of an extraction target to
+    /// disk, and print its fingerprint.
+    Extract {
+        /// Lean module name of the target, e.g.
+        /// `Ragu.Extracted.Poseidon.Permutation`.
+        name: String,
+    },
 }
 
 /// Monomorphized helper used by the static export target table.
@@ -204,6 +253,13 @@ fn fingerprint_instance<I: CircuitInstance>() -> String {
     I::fingerprint()
 }
 
+/// Monomorphized helper used by the static extraction target table.
+fn extract_instance<I: CircuitInstance>(name: &str) -> (String, String) {
+    let trace = I::flattened_trace();
+    let digest = fingerprint::digest_hex::<I::Field>(trace.input_len, &trace.ops, &trace.outputs);
+    (extract::lean_module(name, &trace, &digest), digest)
+}
+
 fn generated_instances_root(autogen_root: &Path) -> (PathBuf, String) {
     let path = autogen_root.join("Ragu/Instances.lean");
     let mut contents = EXPORT_TARGETS
@@ -259,6 +315,33 @@ fn fingerprint_all() {
     }
 }
 
+/// Write the generated Lean module of the extraction target `name`, and print
+/// `<module name> <digest>`.
+fn extract_one(autogen_root: &Path, name: &str) -> std::io::Result<bool> {
+    let Some(target) = EXTRACT_TARGETS.iter().find(|target| target.name == name) else {
+        eprintln!("unknown extraction target {name}; available targets:");
+        for target in EXTRACT_TARGETS {
+            eprintln!("  {}", target.name);
+        }
+        return Ok(false);
+    };
+
+    let (contents, digest) = (target.extract)(target.name);
+    let path = extracted_module_path(autogen_root, target.name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, contents)?;
+    println!("wrote {} to {}", target.name, path.display());
+    println!("{} {digest}", target.name);
+
+    Ok(true)
+}
+
+fn extracted_module_path(autogen_root: &Path, name: &str) -> PathBuf {
+    autogen_root.join(format!("{}.lean", name.replace('.', "/")))
+}
+
 fn check_file(
     name: &str,
     path: PathBuf,
@@ -297,10 +380,17 @@ fn check_all(autogen_root: &Path) -> std::io::Result<bool> {
         &mut mismatches,
     )?;
 
+    for target in EXTRACT_TARGETS.iter().filter(|target| target.committed) {
+        let (expected, _) = (target.extract)(target.name);
+        let path = extracted_module_path(autogen_root, target.name);
+        check_file(target.name, path, expected, &mut mismatches)?;
+    }
+
     if mismatches > 0 {
         eprintln!(
             "\n{mismatches} generated Lean file(s) out of date.\n\
-             hint: run `cargo run -p lean_extraction -- export` and commit the result."
+             hint: run `cargo run -p lean_extraction -- export`, or `extract <name>` for\n\
+             a generated constraint module, and commit the result."
         );
     }
 
@@ -323,6 +413,13 @@ fn main() -> ExitCode {
             fingerprint_all();
             Ok(ExitCode::SUCCESS)
         }
+        Command::Extract { name } => extract_one(&cli.autogen_root, &name).map(|ok| {
+            if ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            }
+        }),
     };
 
     match result {
@@ -0,0 +1,34 @@
+import Ragu.Core
+
+/-!
+# `Ragu.Extracted.Element.Square`
+
+Autogenerated by `cargo run -p lean_extraction -- extract Ragu.Extracted.Element.Square`; do not edit.
+
+Fingerprint: `4157238a4d26893955a97e22ec9223d13af78fd8cd0360f20f77abf6149b71dc`
+-/
+
+namespace Ragu.Extracted.Element.Square
+
+@[reducible]
+def p := Ragu.Core.Primes.p
+
+/-- The number of input variables, which are numbered from `2³²`. -/
+def inputLen : ℕ := 1
+
+set_option maxRecDepth 100000 in
+/-- The extracted operations. -/
+def operations : List (FlatOperation (F p)) := [
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨0⟩) (.var ⟨1⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨2⟩))),
+  .assert (.add (.var ⟨0⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967296⟩))),
+  .assert (.add (.var ⟨1⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967296⟩)))
+]
+
+set_option maxRecDepth 100000 in
+/-- The extracted output expressions. -/
+def outputs : List (Expression (F p)) := [
+  .var ⟨2⟩
+]
+
+end Ragu.Extracted.Element.Square
@@ -0,0 +1,1851 @@
+import Ragu.Core
+
+/-!
+# `Ragu.Extracted.Endoscalar.GroupScale`
+
+Autogenerated by `cargo run -p lean_extraction -- extract Ragu.Extracted.Endoscalar.GroupScale`; do not edit.
+
+Fingerprint: `fbcb16e98337b49858c78588b585a0a1a20c3c456323282bb6a1818bdefca231`
+-/
+
+namespace Ragu.Extracted.Endoscalar.GroupScale
+
+@[reducible]
+def p := Ragu.Core.Primes.p
+
+/-- The number of input variables, which are numbered from `2³²`. -/
+def inputLen : ℕ := 130
+
+set_option maxRecDepth 100000 in
+/-- The extracted operations. -/
+def operations : List (FlatOperation (F p)) := [
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨0⟩) (.var ⟨1⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨2⟩))),
+  .assert (.add (.const 0x0000000000000000000000000000000000000000000000000000000000000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨2⟩))),
+  .assert (.add (.mul (.const 0x2d33357cb532458ed3552a23a8554e5005270d29d19fc7d27b7fd22f0201b549) (.var ⟨4294967424⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨3⟩) (.var ⟨4⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨5⟩))),
+  .assert (.add (.var ⟨3⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨0⟩))),
+  .assert (.add (.var ⟨4⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨0⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨6⟩) (.var ⟨7⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩))),
+  .assert (.add (.var ⟨6⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨0⟩))),
+  .assert (.add (.var ⟨7⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨5⟩)) (.mul (.const 0x25999506959b74e25955abb8af5563603a3f17a46f5a62923b5abd7bfbfc9573) (.var ⟨4294967424⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨9⟩) (.var ⟨10⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨11⟩))),
+  .assert (.add (.var ⟨9⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨5⟩) (.mul (.const 0x2d33357cb532458ed3552a23a8554e5005270d29d19fc7d27b7fd22f0201b547) (.var ⟨4294967424⟩))))),
+  .assert (.add (.var ⟨10⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨5⟩) (.mul (.const 0x2d33357cb532458ed3552a23a8554e5005270d29d19fc7d27b7fd22f0201b547) (.var ⟨4294967424⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨12⟩) (.var ⟨13⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨14⟩))),
+  .assert (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨11⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨14⟩))),
+  .assert (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨13⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨15⟩) (.var ⟨16⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨17⟩))),
+  .assert (.add (.var ⟨15⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨12⟩))),
+  .assert (.add (.var ⟨16⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨12⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨18⟩) (.var ⟨19⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))),
+  .assert (.add (.var ⟨18⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨12⟩))),
+  .assert (.add (.var ⟨19⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨5⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨17⟩))) (.mul (.const 0x0799a0761f96d0ac79ff7e6af8ffeaefcae7f58562456540402514b306051fd3) (.var ⟨4294967424⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨21⟩) (.var ⟨22⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨23⟩))),
+  .assert (.add (.var ⟨21⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967296⟩))),
+  .assert (.add (.var ⟨22⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨24⟩) (.var ⟨25⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨26⟩))),
+  .assert (.add (.var ⟨24⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967297⟩))),
+  .assert (.add (.var ⟨25⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨27⟩) (.var ⟨28⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨29⟩))),
+  .assert (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨23⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨29⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨5⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨17⟩))) (.var ⟨26⟩)) (.mul (.const 0x1a666af96a648b1da6aa544750aa9c9fe807815799f296895dd2737104036a8e) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨28⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨30⟩) (.var ⟨31⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨32⟩))),
+  .assert (.add (.var ⟨30⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩))),
+  .assert (.add (.var ⟨31⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨33⟩) (.var ⟨34⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨35⟩))),
+  .assert (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨35⟩))),
+  .assert (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecfffffffd) (.var ⟨5⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨17⟩))) (.var ⟨26⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨32⟩))) (.mul (.const 0x0b332a0d2b36e9c4b2ab57715eaac6c05237964cd567cc08dd884a0af7f92ae8) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨34⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨36⟩) (.var ⟨37⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨38⟩))),
+  .assert (.add (.var ⟨36⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩)) (.var ⟨33⟩)))),
+  .assert (.add (.var ⟨37⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩)) (.var ⟨33⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨39⟩) (.var ⟨40⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))),
+  .assert (.add (.var ⟨39⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩)) (.var ⟨33⟩)))),
+  .assert (.add (.var ⟨40⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨5⟩)) (.var ⟨17⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨26⟩))) (.var ⟨32⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨38⟩))) (.mul (.const 0x25999506959b74e25955abb8af5563603a3f17a46f5a62923b5abd7bfbfc9573) (.var ⟨4294967424⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨42⟩) (.var ⟨43⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨44⟩))),
+  .assert (.add (.var ⟨42⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967298⟩))),
+  .assert (.add (.var ⟨43⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨45⟩) (.var ⟨46⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨47⟩))),
+  .assert (.add (.var ⟨45⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967299⟩))),
+  .assert (.add (.var ⟨46⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨48⟩) (.var ⟨49⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨50⟩))),
+  .assert (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨44⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨50⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨26⟩)) (.var ⟨32⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨38⟩))) (.var ⟨47⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨49⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨51⟩) (.var ⟨52⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨53⟩))),
+  .assert (.add (.var ⟨51⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨48⟩))),
+  .assert (.add (.var ⟨52⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨48⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨54⟩) (.var ⟨55⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨56⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨56⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨26⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨32⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨38⟩))) (.var ⟨47⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨53⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨55⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨57⟩) (.var ⟨58⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨59⟩))),
+  .assert (.add (.var ⟨57⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨48⟩)) (.var ⟨54⟩)))),
+  .assert (.add (.var ⟨58⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨48⟩)) (.var ⟨54⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨60⟩) (.var ⟨61⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))),
+  .assert (.add (.var ⟨60⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨48⟩)) (.var ⟨54⟩)))),
+  .assert (.add (.var ⟨61⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨26⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨32⟩))) (.var ⟨38⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨47⟩))) (.var ⟨53⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨59⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨63⟩) (.var ⟨64⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨65⟩))),
+  .assert (.add (.var ⟨63⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967300⟩))),
+  .assert (.add (.var ⟨64⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨66⟩) (.var ⟨67⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨68⟩))),
+  .assert (.add (.var ⟨66⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967301⟩))),
+  .assert (.add (.var ⟨67⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨69⟩) (.var ⟨70⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨71⟩))),
+  .assert (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨65⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨71⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨47⟩)) (.var ⟨53⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨59⟩))) (.var ⟨68⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨70⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨72⟩) (.var ⟨73⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨74⟩))),
+  .assert (.add (.var ⟨72⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩))),
+  .assert (.add (.var ⟨73⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨75⟩) (.var ⟨76⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨77⟩))),
+  .assert (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨77⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨47⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨53⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨59⟩))) (.var ⟨68⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨74⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨76⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨78⟩) (.var ⟨79⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨80⟩))),
+  .assert (.add (.var ⟨78⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩)) (.var ⟨75⟩)))),
+  .assert (.add (.var ⟨79⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩)) (.var ⟨75⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨81⟩) (.var ⟨82⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))),
+  .assert (.add (.var ⟨81⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩)) (.var ⟨75⟩)))),
+  .assert (.add (.var ⟨82⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨47⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨53⟩))) (.var ⟨59⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨68⟩))) (.var ⟨74⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨80⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨84⟩) (.var ⟨85⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨86⟩))),
+  .assert (.add (.var ⟨84⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967302⟩))),
+  .assert (.add (.var ⟨85⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨87⟩) (.var ⟨88⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨89⟩))),
+  .assert (.add (.var ⟨87⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967303⟩))),
+  .assert (.add (.var ⟨88⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨90⟩) (.var ⟨91⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨92⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨86⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨92⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨68⟩)) (.var ⟨74⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨80⟩))) (.var ⟨89⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨91⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨93⟩) (.var ⟨94⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨95⟩))),
+  .assert (.add (.var ⟨93⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩))),
+  .assert (.add (.var ⟨94⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨96⟩) (.var ⟨97⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨98⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨98⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨68⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨74⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨80⟩))) (.var ⟨89⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨95⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨97⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨99⟩) (.var ⟨100⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨101⟩))),
+  .assert (.add (.var ⟨99⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩)) (.var ⟨96⟩)))),
+  .assert (.add (.var ⟨100⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩)) (.var ⟨96⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨102⟩) (.var ⟨103⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))),
+  .assert (.add (.var ⟨102⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩)) (.var ⟨96⟩)))),
+  .assert (.add (.var ⟨103⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨68⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨74⟩))) (.var ⟨80⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨89⟩))) (.var ⟨95⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨101⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨105⟩) (.var ⟨106⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨107⟩))),
+  .assert (.add (.var ⟨105⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967304⟩))),
+  .assert (.add (.var ⟨106⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨108⟩) (.var ⟨109⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨110⟩))),
+  .assert (.add (.var ⟨108⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967305⟩))),
+  .assert (.add (.var ⟨109⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨111⟩) (.var ⟨112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨113⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨107⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨113⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨89⟩)) (.var ⟨95⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨101⟩))) (.var ⟨110⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨112⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨114⟩) (.var ⟨115⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨116⟩))),
+  .assert (.add (.var ⟨114⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨111⟩))),
+  .assert (.add (.var ⟨115⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨111⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨117⟩) (.var ⟨118⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨119⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨119⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨89⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨95⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨101⟩))) (.var ⟨110⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨116⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨118⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨120⟩) (.var ⟨121⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨122⟩))),
+  .assert (.add (.var ⟨120⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨111⟩)) (.var ⟨117⟩)))),
+  .assert (.add (.var ⟨121⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨111⟩)) (.var ⟨117⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨123⟩) (.var ⟨124⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))),
+  .assert (.add (.var ⟨123⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨111⟩)) (.var ⟨117⟩)))),
+  .assert (.add (.var ⟨124⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨89⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨95⟩))) (.var ⟨101⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨110⟩))) (.var ⟨116⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨122⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨126⟩) (.var ⟨127⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨128⟩))),
+  .assert (.add (.var ⟨126⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967306⟩))),
+  .assert (.add (.var ⟨127⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨129⟩) (.var ⟨130⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨131⟩))),
+  .assert (.add (.var ⟨129⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967307⟩))),
+  .assert (.add (.var ⟨130⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨132⟩) (.var ⟨133⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨134⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨128⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨134⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨110⟩)) (.var ⟨116⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨122⟩))) (.var ⟨131⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨133⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨135⟩) (.var ⟨136⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨137⟩))),
+  .assert (.add (.var ⟨135⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩))),
+  .assert (.add (.var ⟨136⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨138⟩) (.var ⟨139⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨140⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨140⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨110⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨116⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨122⟩))) (.var ⟨131⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨137⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨139⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨141⟩) (.var ⟨142⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨143⟩))),
+  .assert (.add (.var ⟨141⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩)) (.var ⟨138⟩)))),
+  .assert (.add (.var ⟨142⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩)) (.var ⟨138⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨144⟩) (.var ⟨145⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))),
+  .assert (.add (.var ⟨144⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩)) (.var ⟨138⟩)))),
+  .assert (.add (.var ⟨145⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨110⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨116⟩))) (.var ⟨122⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨131⟩))) (.var ⟨137⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨143⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨147⟩) (.var ⟨148⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨149⟩))),
+  .assert (.add (.var ⟨147⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967308⟩))),
+  .assert (.add (.var ⟨148⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨150⟩) (.var ⟨151⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨152⟩))),
+  .assert (.add (.var ⟨150⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967309⟩))),
+  .assert (.add (.var ⟨151⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨153⟩) (.var ⟨154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨155⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨149⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨155⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨131⟩)) (.var ⟨137⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨143⟩))) (.var ⟨152⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨154⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨156⟩) (.var ⟨157⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨158⟩))),
+  .assert (.add (.var ⟨156⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩))),
+  .assert (.add (.var ⟨157⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨159⟩) (.var ⟨160⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨161⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨161⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨131⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨137⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨143⟩))) (.var ⟨152⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨158⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨160⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨162⟩) (.var ⟨163⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨164⟩))),
+  .assert (.add (.var ⟨162⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩)) (.var ⟨159⟩)))),
+  .assert (.add (.var ⟨163⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩)) (.var ⟨159⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨165⟩) (.var ⟨166⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))),
+  .assert (.add (.var ⟨165⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩)) (.var ⟨159⟩)))),
+  .assert (.add (.var ⟨166⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨131⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨137⟩))) (.var ⟨143⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨152⟩))) (.var ⟨158⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨164⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨168⟩) (.var ⟨169⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨170⟩))),
+  .assert (.add (.var ⟨168⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967310⟩))),
+  .assert (.add (.var ⟨169⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨171⟩) (.var ⟨172⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨173⟩))),
+  .assert (.add (.var ⟨171⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967311⟩))),
+  .assert (.add (.var ⟨172⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨174⟩) (.var ⟨175⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨176⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨170⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨176⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨152⟩)) (.var ⟨158⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨164⟩))) (.var ⟨173⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨175⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨177⟩) (.var ⟨178⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨179⟩))),
+  .assert (.add (.var ⟨177⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨174⟩))),
+  .assert (.add (.var ⟨178⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨174⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨180⟩) (.var ⟨181⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨182⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨182⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨152⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨158⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨164⟩))) (.var ⟨173⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨179⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨181⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨183⟩) (.var ⟨184⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨185⟩))),
+  .assert (.add (.var ⟨183⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨174⟩)) (.var ⟨180⟩)))),
+  .assert (.add (.var ⟨184⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨174⟩)) (.var ⟨180⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨186⟩) (.var ⟨187⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))),
+  .assert (.add (.var ⟨186⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨174⟩)) (.var ⟨180⟩)))),
+  .assert (.add (.var ⟨187⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨152⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨158⟩))) (.var ⟨164⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨173⟩))) (.var ⟨179⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨185⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨189⟩) (.var ⟨190⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨191⟩))),
+  .assert (.add (.var ⟨189⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967312⟩))),
+  .assert (.add (.var ⟨190⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨192⟩) (.var ⟨193⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨194⟩))),
+  .assert (.add (.var ⟨192⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967313⟩))),
+  .assert (.add (.var ⟨193⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨195⟩) (.var ⟨196⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨197⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨191⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨197⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨173⟩)) (.var ⟨179⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨185⟩))) (.var ⟨194⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨196⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨198⟩) (.var ⟨199⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨200⟩))),
+  .assert (.add (.var ⟨198⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩))),
+  .assert (.add (.var ⟨199⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨201⟩) (.var ⟨202⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨203⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨203⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨173⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨179⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨185⟩))) (.var ⟨194⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨200⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨202⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨204⟩) (.var ⟨205⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨206⟩))),
+  .assert (.add (.var ⟨204⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩)) (.var ⟨201⟩)))),
+  .assert (.add (.var ⟨205⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩)) (.var ⟨201⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨207⟩) (.var ⟨208⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))),
+  .assert (.add (.var ⟨207⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩)) (.var ⟨201⟩)))),
+  .assert (.add (.var ⟨208⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨173⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨179⟩))) (.var ⟨185⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨194⟩))) (.var ⟨200⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨206⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨210⟩) (.var ⟨211⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨212⟩))),
+  .assert (.add (.var ⟨210⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967314⟩))),
+  .assert (.add (.var ⟨211⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨213⟩) (.var ⟨214⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨215⟩))),
+  .assert (.add (.var ⟨213⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967315⟩))),
+  .assert (.add (.var ⟨214⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨216⟩) (.var ⟨217⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨218⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨212⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨218⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨194⟩)) (.var ⟨200⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨206⟩))) (.var ⟨215⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨217⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨219⟩) (.var ⟨220⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨221⟩))),
+  .assert (.add (.var ⟨219⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩))),
+  .assert (.add (.var ⟨220⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨222⟩) (.var ⟨223⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨224⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨224⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨194⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨200⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨206⟩))) (.var ⟨215⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨221⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨223⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨225⟩) (.var ⟨226⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨227⟩))),
+  .assert (.add (.var ⟨225⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩)) (.var ⟨222⟩)))),
+  .assert (.add (.var ⟨226⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩)) (.var ⟨222⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨228⟩) (.var ⟨229⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))),
+  .assert (.add (.var ⟨228⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩)) (.var ⟨222⟩)))),
+  .assert (.add (.var ⟨229⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨194⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨200⟩))) (.var ⟨206⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨215⟩))) (.var ⟨221⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨227⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨231⟩) (.var ⟨232⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨233⟩))),
+  .assert (.add (.var ⟨231⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967316⟩))),
+  .assert (.add (.var ⟨232⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨234⟩) (.var ⟨235⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨236⟩))),
+  .assert (.add (.var ⟨234⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967317⟩))),
+  .assert (.add (.var ⟨235⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨237⟩) (.var ⟨238⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨239⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨233⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨239⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨215⟩)) (.var ⟨221⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨227⟩))) (.var ⟨236⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨238⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨240⟩) (.var ⟨241⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨242⟩))),
+  .assert (.add (.var ⟨240⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨237⟩))),
+  .assert (.add (.var ⟨241⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨237⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨243⟩) (.var ⟨244⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨245⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨245⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨215⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨221⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨227⟩))) (.var ⟨236⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨242⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨244⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨246⟩) (.var ⟨247⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨248⟩))),
+  .assert (.add (.var ⟨246⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨237⟩)) (.var ⟨243⟩)))),
+  .assert (.add (.var ⟨247⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨237⟩)) (.var ⟨243⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨249⟩) (.var ⟨250⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))),
+  .assert (.add (.var ⟨249⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨237⟩)) (.var ⟨243⟩)))),
+  .assert (.add (.var ⟨250⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨215⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨221⟩))) (.var ⟨227⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨236⟩))) (.var ⟨242⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨248⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨252⟩) (.var ⟨253⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨254⟩))),
+  .assert (.add (.var ⟨252⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967318⟩))),
+  .assert (.add (.var ⟨253⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨255⟩) (.var ⟨256⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨257⟩))),
+  .assert (.add (.var ⟨255⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967319⟩))),
+  .assert (.add (.var ⟨256⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨258⟩) (.var ⟨259⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨260⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨254⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨260⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨236⟩)) (.var ⟨242⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨248⟩))) (.var ⟨257⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨259⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨261⟩) (.var ⟨262⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨263⟩))),
+  .assert (.add (.var ⟨261⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩))),
+  .assert (.add (.var ⟨262⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨264⟩) (.var ⟨265⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨266⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨266⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨236⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨242⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨248⟩))) (.var ⟨257⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨263⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨265⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨267⟩) (.var ⟨268⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨269⟩))),
+  .assert (.add (.var ⟨267⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩)) (.var ⟨264⟩)))),
+  .assert (.add (.var ⟨268⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩)) (.var ⟨264⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨270⟩) (.var ⟨271⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))),
+  .assert (.add (.var ⟨270⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩)) (.var ⟨264⟩)))),
+  .assert (.add (.var ⟨271⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨236⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨242⟩))) (.var ⟨248⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨257⟩))) (.var ⟨263⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨269⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨273⟩) (.var ⟨274⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨275⟩))),
+  .assert (.add (.var ⟨273⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967320⟩))),
+  .assert (.add (.var ⟨274⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨276⟩) (.var ⟨277⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨278⟩))),
+  .assert (.add (.var ⟨276⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967321⟩))),
+  .assert (.add (.var ⟨277⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨279⟩) (.var ⟨280⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨281⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨275⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨281⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨257⟩)) (.var ⟨263⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨269⟩))) (.var ⟨278⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨280⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨282⟩) (.var ⟨283⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨284⟩))),
+  .assert (.add (.var ⟨282⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩))),
+  .assert (.add (.var ⟨283⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨285⟩) (.var ⟨286⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨287⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨287⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨257⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨263⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨269⟩))) (.var ⟨278⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨284⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨286⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨288⟩) (.var ⟨289⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨290⟩))),
+  .assert (.add (.var ⟨288⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩)) (.var ⟨285⟩)))),
+  .assert (.add (.var ⟨289⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩)) (.var ⟨285⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨291⟩) (.var ⟨292⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))),
+  .assert (.add (.var ⟨291⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩)) (.var ⟨285⟩)))),
+  .assert (.add (.var ⟨292⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨257⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨263⟩))) (.var ⟨269⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨278⟩))) (.var ⟨284⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨290⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨294⟩) (.var ⟨295⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨296⟩))),
+  .assert (.add (.var ⟨294⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967322⟩))),
+  .assert (.add (.var ⟨295⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨297⟩) (.var ⟨298⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨299⟩))),
+  .assert (.add (.var ⟨297⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967323⟩))),
+  .assert (.add (.var ⟨298⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨300⟩) (.var ⟨301⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨302⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨296⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨302⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨278⟩)) (.var ⟨284⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨290⟩))) (.var ⟨299⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨301⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨303⟩) (.var ⟨304⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨305⟩))),
+  .assert (.add (.var ⟨303⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨300⟩))),
+  .assert (.add (.var ⟨304⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨300⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨306⟩) (.var ⟨307⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨308⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨308⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨278⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨284⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨290⟩))) (.var ⟨299⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨305⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨307⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨309⟩) (.var ⟨310⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨311⟩))),
+  .assert (.add (.var ⟨309⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨300⟩)) (.var ⟨306⟩)))),
+  .assert (.add (.var ⟨310⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨300⟩)) (.var ⟨306⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨312⟩) (.var ⟨313⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))),
+  .assert (.add (.var ⟨312⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨300⟩)) (.var ⟨306⟩)))),
+  .assert (.add (.var ⟨313⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨278⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨284⟩))) (.var ⟨290⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨299⟩))) (.var ⟨305⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨311⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨315⟩) (.var ⟨316⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨317⟩))),
+  .assert (.add (.var ⟨315⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967324⟩))),
+  .assert (.add (.var ⟨316⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨318⟩) (.var ⟨319⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨320⟩))),
+  .assert (.add (.var ⟨318⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967325⟩))),
+  .assert (.add (.var ⟨319⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨321⟩) (.var ⟨322⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨323⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨317⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨323⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨299⟩)) (.var ⟨305⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨311⟩))) (.var ⟨320⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨322⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨324⟩) (.var ⟨325⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨326⟩))),
+  .assert (.add (.var ⟨324⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩))),
+  .assert (.add (.var ⟨325⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨327⟩) (.var ⟨328⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨329⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨329⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨299⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨305⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨311⟩))) (.var ⟨320⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨326⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨328⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨330⟩) (.var ⟨331⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨332⟩))),
+  .assert (.add (.var ⟨330⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩)) (.var ⟨327⟩)))),
+  .assert (.add (.var ⟨331⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩)) (.var ⟨327⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨333⟩) (.var ⟨334⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))),
+  .assert (.add (.var ⟨333⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩)) (.var ⟨327⟩)))),
+  .assert (.add (.var ⟨334⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨299⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨305⟩))) (.var ⟨311⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨320⟩))) (.var ⟨326⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨332⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨336⟩) (.var ⟨337⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨338⟩))),
+  .assert (.add (.var ⟨336⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967326⟩))),
+  .assert (.add (.var ⟨337⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨339⟩) (.var ⟨340⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨341⟩))),
+  .assert (.add (.var ⟨339⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967327⟩))),
+  .assert (.add (.var ⟨340⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨342⟩) (.var ⟨343⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨344⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨338⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨344⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨320⟩)) (.var ⟨326⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨332⟩))) (.var ⟨341⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨343⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨345⟩) (.var ⟨346⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨347⟩))),
+  .assert (.add (.var ⟨345⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩))),
+  .assert (.add (.var ⟨346⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨348⟩) (.var ⟨349⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨350⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨350⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨320⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨326⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨332⟩))) (.var ⟨341⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨347⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨349⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨351⟩) (.var ⟨352⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨353⟩))),
+  .assert (.add (.var ⟨351⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩)) (.var ⟨348⟩)))),
+  .assert (.add (.var ⟨352⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩)) (.var ⟨348⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨354⟩) (.var ⟨355⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))),
+  .assert (.add (.var ⟨354⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩)) (.var ⟨348⟩)))),
+  .assert (.add (.var ⟨355⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨320⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨326⟩))) (.var ⟨332⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨341⟩))) (.var ⟨347⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨353⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨357⟩) (.var ⟨358⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨359⟩))),
+  .assert (.add (.var ⟨357⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967328⟩))),
+  .assert (.add (.var ⟨358⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨360⟩) (.var ⟨361⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨362⟩))),
+  .assert (.add (.var ⟨360⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967329⟩))),
+  .assert (.add (.var ⟨361⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨363⟩) (.var ⟨364⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨365⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨359⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨365⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨341⟩)) (.var ⟨347⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨353⟩))) (.var ⟨362⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨364⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨366⟩) (.var ⟨367⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨368⟩))),
+  .assert (.add (.var ⟨366⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨363⟩))),
+  .assert (.add (.var ⟨367⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨363⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨369⟩) (.var ⟨370⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨371⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨371⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨341⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨347⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨353⟩))) (.var ⟨362⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨368⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨370⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨372⟩) (.var ⟨373⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨374⟩))),
+  .assert (.add (.var ⟨372⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨363⟩)) (.var ⟨369⟩)))),
+  .assert (.add (.var ⟨373⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨363⟩)) (.var ⟨369⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨375⟩) (.var ⟨376⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))),
+  .assert (.add (.var ⟨375⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨363⟩)) (.var ⟨369⟩)))),
+  .assert (.add (.var ⟨376⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨341⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨347⟩))) (.var ⟨353⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨362⟩))) (.var ⟨368⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨374⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨378⟩) (.var ⟨379⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨380⟩))),
+  .assert (.add (.var ⟨378⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967330⟩))),
+  .assert (.add (.var ⟨379⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨381⟩) (.var ⟨382⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨383⟩))),
+  .assert (.add (.var ⟨381⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967331⟩))),
+  .assert (.add (.var ⟨382⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨384⟩) (.var ⟨385⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨386⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨380⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨386⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨362⟩)) (.var ⟨368⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨374⟩))) (.var ⟨383⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨385⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨387⟩) (.var ⟨388⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨389⟩))),
+  .assert (.add (.var ⟨387⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩))),
+  .assert (.add (.var ⟨388⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨390⟩) (.var ⟨391⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨392⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨392⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨362⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨368⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨374⟩))) (.var ⟨383⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨389⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨391⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨393⟩) (.var ⟨394⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨395⟩))),
+  .assert (.add (.var ⟨393⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩)) (.var ⟨390⟩)))),
+  .assert (.add (.var ⟨394⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩)) (.var ⟨390⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨396⟩) (.var ⟨397⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))),
+  .assert (.add (.var ⟨396⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩)) (.var ⟨390⟩)))),
+  .assert (.add (.var ⟨397⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨362⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨368⟩))) (.var ⟨374⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨383⟩))) (.var ⟨389⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨395⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨399⟩) (.var ⟨400⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨401⟩))),
+  .assert (.add (.var ⟨399⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967332⟩))),
+  .assert (.add (.var ⟨400⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨402⟩) (.var ⟨403⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨404⟩))),
+  .assert (.add (.var ⟨402⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967333⟩))),
+  .assert (.add (.var ⟨403⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨405⟩) (.var ⟨406⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨407⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨401⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨407⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨383⟩)) (.var ⟨389⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨395⟩))) (.var ⟨404⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨406⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨408⟩) (.var ⟨409⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨410⟩))),
+  .assert (.add (.var ⟨408⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩))),
+  .assert (.add (.var ⟨409⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨411⟩) (.var ⟨412⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨413⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨413⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨383⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨389⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨395⟩))) (.var ⟨404⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨410⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨412⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨414⟩) (.var ⟨415⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨416⟩))),
+  .assert (.add (.var ⟨414⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩)) (.var ⟨411⟩)))),
+  .assert (.add (.var ⟨415⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩)) (.var ⟨411⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨417⟩) (.var ⟨418⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))),
+  .assert (.add (.var ⟨417⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩)) (.var ⟨411⟩)))),
+  .assert (.add (.var ⟨418⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨383⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨389⟩))) (.var ⟨395⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨404⟩))) (.var ⟨410⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨416⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨420⟩) (.var ⟨421⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨422⟩))),
+  .assert (.add (.var ⟨420⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967334⟩))),
+  .assert (.add (.var ⟨421⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨423⟩) (.var ⟨424⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨425⟩))),
+  .assert (.add (.var ⟨423⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967335⟩))),
+  .assert (.add (.var ⟨424⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨426⟩) (.var ⟨427⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨428⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨422⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨428⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨404⟩)) (.var ⟨410⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨416⟩))) (.var ⟨425⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨427⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨429⟩) (.var ⟨430⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨431⟩))),
+  .assert (.add (.var ⟨429⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨426⟩))),
+  .assert (.add (.var ⟨430⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨426⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨432⟩) (.var ⟨433⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨434⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨434⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨404⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨410⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨416⟩))) (.var ⟨425⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨431⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨433⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨435⟩) (.var ⟨436⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨437⟩))),
+  .assert (.add (.var ⟨435⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨426⟩)) (.var ⟨432⟩)))),
+  .assert (.add (.var ⟨436⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨426⟩)) (.var ⟨432⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨438⟩) (.var ⟨439⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))),
+  .assert (.add (.var ⟨438⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨426⟩)) (.var ⟨432⟩)))),
+  .assert (.add (.var ⟨439⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨404⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨410⟩))) (.var ⟨416⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨425⟩))) (.var ⟨431⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨437⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨441⟩) (.var ⟨442⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨443⟩))),
+  .assert (.add (.var ⟨441⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967336⟩))),
+  .assert (.add (.var ⟨442⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨444⟩) (.var ⟨445⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨446⟩))),
+  .assert (.add (.var ⟨444⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967337⟩))),
+  .assert (.add (.var ⟨445⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨447⟩) (.var ⟨448⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨449⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨443⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨449⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨425⟩)) (.var ⟨431⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨437⟩))) (.var ⟨446⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨448⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨450⟩) (.var ⟨451⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨452⟩))),
+  .assert (.add (.var ⟨450⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩))),
+  .assert (.add (.var ⟨451⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨453⟩) (.var ⟨454⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨455⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨455⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨425⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨431⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨437⟩))) (.var ⟨446⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨452⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨454⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨456⟩) (.var ⟨457⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨458⟩))),
+  .assert (.add (.var ⟨456⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩)) (.var ⟨453⟩)))),
+  .assert (.add (.var ⟨457⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩)) (.var ⟨453⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨459⟩) (.var ⟨460⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))),
+  .assert (.add (.var ⟨459⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩)) (.var ⟨453⟩)))),
+  .assert (.add (.var ⟨460⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨425⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨431⟩))) (.var ⟨437⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨446⟩))) (.var ⟨452⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨458⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨462⟩) (.var ⟨463⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨464⟩))),
+  .assert (.add (.var ⟨462⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967338⟩))),
+  .assert (.add (.var ⟨463⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨465⟩) (.var ⟨466⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨467⟩))),
+  .assert (.add (.var ⟨465⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967339⟩))),
+  .assert (.add (.var ⟨466⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨468⟩) (.var ⟨469⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨470⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨464⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨470⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨446⟩)) (.var ⟨452⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨458⟩))) (.var ⟨467⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨469⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨471⟩) (.var ⟨472⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨473⟩))),
+  .assert (.add (.var ⟨471⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩))),
+  .assert (.add (.var ⟨472⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨474⟩) (.var ⟨475⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨476⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨476⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨446⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨452⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨458⟩))) (.var ⟨467⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨473⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨475⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨477⟩) (.var ⟨478⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨479⟩))),
+  .assert (.add (.var ⟨477⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩)) (.var ⟨474⟩)))),
+  .assert (.add (.var ⟨478⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩)) (.var ⟨474⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨480⟩) (.var ⟨481⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))),
+  .assert (.add (.var ⟨480⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩)) (.var ⟨474⟩)))),
+  .assert (.add (.var ⟨481⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨446⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨452⟩))) (.var ⟨458⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨467⟩))) (.var ⟨473⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨479⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨483⟩) (.var ⟨484⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨485⟩))),
+  .assert (.add (.var ⟨483⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967340⟩))),
+  .assert (.add (.var ⟨484⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨486⟩) (.var ⟨487⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨488⟩))),
+  .assert (.add (.var ⟨486⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967341⟩))),
+  .assert (.add (.var ⟨487⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨489⟩) (.var ⟨490⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨491⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨485⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨491⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨467⟩)) (.var ⟨473⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨479⟩))) (.var ⟨488⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨490⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨492⟩) (.var ⟨493⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨494⟩))),
+  .assert (.add (.var ⟨492⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨489⟩))),
+  .assert (.add (.var ⟨493⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨489⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨495⟩) (.var ⟨496⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨497⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨497⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨467⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨473⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨479⟩))) (.var ⟨488⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨494⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨496⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨498⟩) (.var ⟨499⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨500⟩))),
+  .assert (.add (.var ⟨498⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨489⟩)) (.var ⟨495⟩)))),
+  .assert (.add (.var ⟨499⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨489⟩)) (.var ⟨495⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨501⟩) (.var ⟨502⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))),
+  .assert (.add (.var ⟨501⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨489⟩)) (.var ⟨495⟩)))),
+  .assert (.add (.var ⟨502⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨467⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨473⟩))) (.var ⟨479⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨488⟩))) (.var ⟨494⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨500⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨504⟩) (.var ⟨505⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨506⟩))),
+  .assert (.add (.var ⟨504⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967342⟩))),
+  .assert (.add (.var ⟨505⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨507⟩) (.var ⟨508⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨509⟩))),
+  .assert (.add (.var ⟨507⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967343⟩))),
+  .assert (.add (.var ⟨508⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨510⟩) (.var ⟨511⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨512⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨506⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨512⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨488⟩)) (.var ⟨494⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨500⟩))) (.var ⟨509⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨511⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨513⟩) (.var ⟨514⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨515⟩))),
+  .assert (.add (.var ⟨513⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩))),
+  .assert (.add (.var ⟨514⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨516⟩) (.var ⟨517⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨518⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨518⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨488⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨494⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨500⟩))) (.var ⟨509⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨515⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨517⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨519⟩) (.var ⟨520⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨521⟩))),
+  .assert (.add (.var ⟨519⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩)) (.var ⟨516⟩)))),
+  .assert (.add (.var ⟨520⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩)) (.var ⟨516⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨522⟩) (.var ⟨523⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))),
+  .assert (.add (.var ⟨522⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩)) (.var ⟨516⟩)))),
+  .assert (.add (.var ⟨523⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨488⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨494⟩))) (.var ⟨500⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨509⟩))) (.var ⟨515⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨521⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨525⟩) (.var ⟨526⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨527⟩))),
+  .assert (.add (.var ⟨525⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967344⟩))),
+  .assert (.add (.var ⟨526⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨528⟩) (.var ⟨529⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨530⟩))),
+  .assert (.add (.var ⟨528⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967345⟩))),
+  .assert (.add (.var ⟨529⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨531⟩) (.var ⟨532⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨533⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨527⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨533⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨509⟩)) (.var ⟨515⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨521⟩))) (.var ⟨530⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨532⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨534⟩) (.var ⟨535⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨536⟩))),
+  .assert (.add (.var ⟨534⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩))),
+  .assert (.add (.var ⟨535⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨537⟩) (.var ⟨538⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨539⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨539⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨509⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨515⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨521⟩))) (.var ⟨530⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨536⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨538⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨540⟩) (.var ⟨541⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨542⟩))),
+  .assert (.add (.var ⟨540⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩)) (.var ⟨537⟩)))),
+  .assert (.add (.var ⟨541⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩)) (.var ⟨537⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨543⟩) (.var ⟨544⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))),
+  .assert (.add (.var ⟨543⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩)) (.var ⟨537⟩)))),
+  .assert (.add (.var ⟨544⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨509⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨515⟩))) (.var ⟨521⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨530⟩))) (.var ⟨536⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨542⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨546⟩) (.var ⟨547⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨548⟩))),
+  .assert (.add (.var ⟨546⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967346⟩))),
+  .assert (.add (.var ⟨547⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨549⟩) (.var ⟨550⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨551⟩))),
+  .assert (.add (.var ⟨549⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967347⟩))),
+  .assert (.add (.var ⟨550⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨552⟩) (.var ⟨553⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨554⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨548⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨554⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨530⟩)) (.var ⟨536⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨542⟩))) (.var ⟨551⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨553⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨555⟩) (.var ⟨556⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨557⟩))),
+  .assert (.add (.var ⟨555⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨552⟩))),
+  .assert (.add (.var ⟨556⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨552⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨558⟩) (.var ⟨559⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨560⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨560⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨530⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨536⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨542⟩))) (.var ⟨551⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨557⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨559⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨561⟩) (.var ⟨562⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨563⟩))),
+  .assert (.add (.var ⟨561⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨552⟩)) (.var ⟨558⟩)))),
+  .assert (.add (.var ⟨562⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨552⟩)) (.var ⟨558⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨564⟩) (.var ⟨565⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))),
+  .assert (.add (.var ⟨564⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨552⟩)) (.var ⟨558⟩)))),
+  .assert (.add (.var ⟨565⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨530⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨536⟩))) (.var ⟨542⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨551⟩))) (.var ⟨557⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨563⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨567⟩) (.var ⟨568⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨569⟩))),
+  .assert (.add (.var ⟨567⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967348⟩))),
+  .assert (.add (.var ⟨568⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨570⟩) (.var ⟨571⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨572⟩))),
+  .assert (.add (.var ⟨570⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967349⟩))),
+  .assert (.add (.var ⟨571⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨573⟩) (.var ⟨574⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨575⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨569⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨575⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨551⟩)) (.var ⟨557⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨563⟩))) (.var ⟨572⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨574⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨576⟩) (.var ⟨577⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨578⟩))),
+  .assert (.add (.var ⟨576⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩))),
+  .assert (.add (.var ⟨577⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨579⟩) (.var ⟨580⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨581⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨581⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨551⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨557⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨563⟩))) (.var ⟨572⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨578⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨580⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨582⟩) (.var ⟨583⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨584⟩))),
+  .assert (.add (.var ⟨582⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩)) (.var ⟨579⟩)))),
+  .assert (.add (.var ⟨583⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩)) (.var ⟨579⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨585⟩) (.var ⟨586⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))),
+  .assert (.add (.var ⟨585⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩)) (.var ⟨579⟩)))),
+  .assert (.add (.var ⟨586⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨551⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨557⟩))) (.var ⟨563⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨572⟩))) (.var ⟨578⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨584⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨588⟩) (.var ⟨589⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨590⟩))),
+  .assert (.add (.var ⟨588⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967350⟩))),
+  .assert (.add (.var ⟨589⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨591⟩) (.var ⟨592⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨593⟩))),
+  .assert (.add (.var ⟨591⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967351⟩))),
+  .assert (.add (.var ⟨592⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨594⟩) (.var ⟨595⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨596⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨590⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨596⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨572⟩)) (.var ⟨578⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨584⟩))) (.var ⟨593⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨595⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨597⟩) (.var ⟨598⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨599⟩))),
+  .assert (.add (.var ⟨597⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩))),
+  .assert (.add (.var ⟨598⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨600⟩) (.var ⟨601⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨602⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨602⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨572⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨578⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨584⟩))) (.var ⟨593⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨599⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨601⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨603⟩) (.var ⟨604⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨605⟩))),
+  .assert (.add (.var ⟨603⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩)) (.var ⟨600⟩)))),
+  .assert (.add (.var ⟨604⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩)) (.var ⟨600⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨606⟩) (.var ⟨607⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))),
+  .assert (.add (.var ⟨606⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩)) (.var ⟨600⟩)))),
+  .assert (.add (.var ⟨607⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨572⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨578⟩))) (.var ⟨584⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨593⟩))) (.var ⟨599⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨605⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨609⟩) (.var ⟨610⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨611⟩))),
+  .assert (.add (.var ⟨609⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967352⟩))),
+  .assert (.add (.var ⟨610⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨612⟩) (.var ⟨613⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨614⟩))),
+  .assert (.add (.var ⟨612⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967353⟩))),
+  .assert (.add (.var ⟨613⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨615⟩) (.var ⟨616⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨617⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨611⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨617⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨593⟩)) (.var ⟨599⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨605⟩))) (.var ⟨614⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨616⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨618⟩) (.var ⟨619⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨620⟩))),
+  .assert (.add (.var ⟨618⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨615⟩))),
+  .assert (.add (.var ⟨619⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨615⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨621⟩) (.var ⟨622⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨623⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨623⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨593⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨599⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨605⟩))) (.var ⟨614⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨620⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨622⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨624⟩) (.var ⟨625⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨626⟩))),
+  .assert (.add (.var ⟨624⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨615⟩)) (.var ⟨621⟩)))),
+  .assert (.add (.var ⟨625⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨615⟩)) (.var ⟨621⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨627⟩) (.var ⟨628⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))),
+  .assert (.add (.var ⟨627⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨615⟩)) (.var ⟨621⟩)))),
+  .assert (.add (.var ⟨628⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨593⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨599⟩))) (.var ⟨605⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨614⟩))) (.var ⟨620⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨626⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨630⟩) (.var ⟨631⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨632⟩))),
+  .assert (.add (.var ⟨630⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967354⟩))),
+  .assert (.add (.var ⟨631⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨633⟩) (.var ⟨634⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨635⟩))),
+  .assert (.add (.var ⟨633⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967355⟩))),
+  .assert (.add (.var ⟨634⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨636⟩) (.var ⟨637⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨638⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨632⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨638⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨614⟩)) (.var ⟨620⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨626⟩))) (.var ⟨635⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨637⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨639⟩) (.var ⟨640⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨641⟩))),
+  .assert (.add (.var ⟨639⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩))),
+  .assert (.add (.var ⟨640⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨642⟩) (.var ⟨643⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨644⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨644⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨614⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨620⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨626⟩))) (.var ⟨635⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨641⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨643⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨645⟩) (.var ⟨646⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨647⟩))),
+  .assert (.add (.var ⟨645⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩)) (.var ⟨642⟩)))),
+  .assert (.add (.var ⟨646⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩)) (.var ⟨642⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨648⟩) (.var ⟨649⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))),
+  .assert (.add (.var ⟨648⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩)) (.var ⟨642⟩)))),
+  .assert (.add (.var ⟨649⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨614⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨620⟩))) (.var ⟨626⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨635⟩))) (.var ⟨641⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨647⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨651⟩) (.var ⟨652⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨653⟩))),
+  .assert (.add (.var ⟨651⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967356⟩))),
+  .assert (.add (.var ⟨652⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨654⟩) (.var ⟨655⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨656⟩))),
+  .assert (.add (.var ⟨654⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967357⟩))),
+  .assert (.add (.var ⟨655⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨657⟩) (.var ⟨658⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨659⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨653⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨659⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨635⟩)) (.var ⟨641⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨647⟩))) (.var ⟨656⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨658⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨660⟩) (.var ⟨661⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨662⟩))),
+  .assert (.add (.var ⟨660⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩))),
+  .assert (.add (.var ⟨661⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨663⟩) (.var ⟨664⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨665⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨665⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨635⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨641⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨647⟩))) (.var ⟨656⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨662⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨664⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨666⟩) (.var ⟨667⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨668⟩))),
+  .assert (.add (.var ⟨666⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩)) (.var ⟨663⟩)))),
+  .assert (.add (.var ⟨667⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩)) (.var ⟨663⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨669⟩) (.var ⟨670⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))),
+  .assert (.add (.var ⟨669⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩)) (.var ⟨663⟩)))),
+  .assert (.add (.var ⟨670⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨635⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨641⟩))) (.var ⟨647⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨656⟩))) (.var ⟨662⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨668⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨672⟩) (.var ⟨673⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨674⟩))),
+  .assert (.add (.var ⟨672⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967358⟩))),
+  .assert (.add (.var ⟨673⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨675⟩) (.var ⟨676⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨677⟩))),
+  .assert (.add (.var ⟨675⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967359⟩))),
+  .assert (.add (.var ⟨676⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨678⟩) (.var ⟨679⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨680⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨674⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨680⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨656⟩)) (.var ⟨662⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨668⟩))) (.var ⟨677⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨679⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨681⟩) (.var ⟨682⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨683⟩))),
+  .assert (.add (.var ⟨681⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨678⟩))),
+  .assert (.add (.var ⟨682⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨678⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨684⟩) (.var ⟨685⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨686⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨686⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨656⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨662⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨668⟩))) (.var ⟨677⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨683⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨685⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨687⟩) (.var ⟨688⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨689⟩))),
+  .assert (.add (.var ⟨687⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨678⟩)) (.var ⟨684⟩)))),
+  .assert (.add (.var ⟨688⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨678⟩)) (.var ⟨684⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨690⟩) (.var ⟨691⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))),
+  .assert (.add (.var ⟨690⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨678⟩)) (.var ⟨684⟩)))),
+  .assert (.add (.var ⟨691⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨656⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨662⟩))) (.var ⟨668⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨677⟩))) (.var ⟨683⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨689⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨693⟩) (.var ⟨694⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨695⟩))),
+  .assert (.add (.var ⟨693⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967360⟩))),
+  .assert (.add (.var ⟨694⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨696⟩) (.var ⟨697⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨698⟩))),
+  .assert (.add (.var ⟨696⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967361⟩))),
+  .assert (.add (.var ⟨697⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨699⟩) (.var ⟨700⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨701⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨695⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨701⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨677⟩)) (.var ⟨683⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨689⟩))) (.var ⟨698⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨700⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨702⟩) (.var ⟨703⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨704⟩))),
+  .assert (.add (.var ⟨702⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩))),
+  .assert (.add (.var ⟨703⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨705⟩) (.var ⟨706⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨707⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨707⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨677⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨683⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨689⟩))) (.var ⟨698⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨704⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨706⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨708⟩) (.var ⟨709⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨710⟩))),
+  .assert (.add (.var ⟨708⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩)) (.var ⟨705⟩)))),
+  .assert (.add (.var ⟨709⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩)) (.var ⟨705⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨711⟩) (.var ⟨712⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))),
+  .assert (.add (.var ⟨711⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩)) (.var ⟨705⟩)))),
+  .assert (.add (.var ⟨712⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨677⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨683⟩))) (.var ⟨689⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨698⟩))) (.var ⟨704⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨710⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨714⟩) (.var ⟨715⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨716⟩))),
+  .assert (.add (.var ⟨714⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967362⟩))),
+  .assert (.add (.var ⟨715⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨717⟩) (.var ⟨718⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨719⟩))),
+  .assert (.add (.var ⟨717⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967363⟩))),
+  .assert (.add (.var ⟨718⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨720⟩) (.var ⟨721⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨722⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨716⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨722⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨698⟩)) (.var ⟨704⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨710⟩))) (.var ⟨719⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨721⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨723⟩) (.var ⟨724⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨725⟩))),
+  .assert (.add (.var ⟨723⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩))),
+  .assert (.add (.var ⟨724⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨726⟩) (.var ⟨727⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨728⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨728⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨698⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨704⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨710⟩))) (.var ⟨719⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨725⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨727⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨729⟩) (.var ⟨730⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨731⟩))),
+  .assert (.add (.var ⟨729⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩)) (.var ⟨726⟩)))),
+  .assert (.add (.var ⟨730⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩)) (.var ⟨726⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨732⟩) (.var ⟨733⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))),
+  .assert (.add (.var ⟨732⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩)) (.var ⟨726⟩)))),
+  .assert (.add (.var ⟨733⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨698⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨704⟩))) (.var ⟨710⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨719⟩))) (.var ⟨725⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨731⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨735⟩) (.var ⟨736⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨737⟩))),
+  .assert (.add (.var ⟨735⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967364⟩))),
+  .assert (.add (.var ⟨736⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨738⟩) (.var ⟨739⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨740⟩))),
+  .assert (.add (.var ⟨738⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967365⟩))),
+  .assert (.add (.var ⟨739⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨741⟩) (.var ⟨742⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨743⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨737⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨743⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨719⟩)) (.var ⟨725⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨731⟩))) (.var ⟨740⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨742⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨744⟩) (.var ⟨745⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨746⟩))),
+  .assert (.add (.var ⟨744⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨741⟩))),
+  .assert (.add (.var ⟨745⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨741⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨747⟩) (.var ⟨748⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨749⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨749⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨719⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨725⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨731⟩))) (.var ⟨740⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨746⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨748⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨750⟩) (.var ⟨751⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨752⟩))),
+  .assert (.add (.var ⟨750⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨741⟩)) (.var ⟨747⟩)))),
+  .assert (.add (.var ⟨751⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨741⟩)) (.var ⟨747⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨753⟩) (.var ⟨754⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))),
+  .assert (.add (.var ⟨753⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨741⟩)) (.var ⟨747⟩)))),
+  .assert (.add (.var ⟨754⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨719⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨725⟩))) (.var ⟨731⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨740⟩))) (.var ⟨746⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨752⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨756⟩) (.var ⟨757⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨758⟩))),
+  .assert (.add (.var ⟨756⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967366⟩))),
+  .assert (.add (.var ⟨757⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨759⟩) (.var ⟨760⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨761⟩))),
+  .assert (.add (.var ⟨759⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967367⟩))),
+  .assert (.add (.var ⟨760⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨762⟩) (.var ⟨763⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨764⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨758⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨764⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨740⟩)) (.var ⟨746⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨752⟩))) (.var ⟨761⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨763⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨765⟩) (.var ⟨766⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨767⟩))),
+  .assert (.add (.var ⟨765⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩))),
+  .assert (.add (.var ⟨766⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨768⟩) (.var ⟨769⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨770⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨770⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨740⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨746⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨752⟩))) (.var ⟨761⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨767⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨769⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨771⟩) (.var ⟨772⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨773⟩))),
+  .assert (.add (.var ⟨771⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩)) (.var ⟨768⟩)))),
+  .assert (.add (.var ⟨772⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩)) (.var ⟨768⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨774⟩) (.var ⟨775⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))),
+  .assert (.add (.var ⟨774⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩)) (.var ⟨768⟩)))),
+  .assert (.add (.var ⟨775⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨740⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨746⟩))) (.var ⟨752⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨761⟩))) (.var ⟨767⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨773⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨777⟩) (.var ⟨778⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨779⟩))),
+  .assert (.add (.var ⟨777⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967368⟩))),
+  .assert (.add (.var ⟨778⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨780⟩) (.var ⟨781⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨782⟩))),
+  .assert (.add (.var ⟨780⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967369⟩))),
+  .assert (.add (.var ⟨781⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨783⟩) (.var ⟨784⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨785⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨779⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨785⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨761⟩)) (.var ⟨767⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨773⟩))) (.var ⟨782⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨784⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨786⟩) (.var ⟨787⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨788⟩))),
+  .assert (.add (.var ⟨786⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩))),
+  .assert (.add (.var ⟨787⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨789⟩) (.var ⟨790⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨791⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨791⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨761⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨767⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨773⟩))) (.var ⟨782⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨788⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨790⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨792⟩) (.var ⟨793⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨794⟩))),
+  .assert (.add (.var ⟨792⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩)) (.var ⟨789⟩)))),
+  .assert (.add (.var ⟨793⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩)) (.var ⟨789⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨795⟩) (.var ⟨796⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))),
+  .assert (.add (.var ⟨795⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩)) (.var ⟨789⟩)))),
+  .assert (.add (.var ⟨796⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨761⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨767⟩))) (.var ⟨773⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨782⟩))) (.var ⟨788⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨794⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨798⟩) (.var ⟨799⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨800⟩))),
+  .assert (.add (.var ⟨798⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967370⟩))),
+  .assert (.add (.var ⟨799⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨801⟩) (.var ⟨802⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨803⟩))),
+  .assert (.add (.var ⟨801⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967371⟩))),
+  .assert (.add (.var ⟨802⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨804⟩) (.var ⟨805⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨806⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨800⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨806⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨782⟩)) (.var ⟨788⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨794⟩))) (.var ⟨803⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨805⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨807⟩) (.var ⟨808⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨809⟩))),
+  .assert (.add (.var ⟨807⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨804⟩))),
+  .assert (.add (.var ⟨808⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨804⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨810⟩) (.var ⟨811⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨812⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨812⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨782⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨788⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨794⟩))) (.var ⟨803⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨809⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨811⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨813⟩) (.var ⟨814⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨815⟩))),
+  .assert (.add (.var ⟨813⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨804⟩)) (.var ⟨810⟩)))),
+  .assert (.add (.var ⟨814⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨804⟩)) (.var ⟨810⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨816⟩) (.var ⟨817⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))),
+  .assert (.add (.var ⟨816⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨804⟩)) (.var ⟨810⟩)))),
+  .assert (.add (.var ⟨817⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨782⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨788⟩))) (.var ⟨794⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨803⟩))) (.var ⟨809⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨815⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨819⟩) (.var ⟨820⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨821⟩))),
+  .assert (.add (.var ⟨819⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967372⟩))),
+  .assert (.add (.var ⟨820⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨822⟩) (.var ⟨823⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨824⟩))),
+  .assert (.add (.var ⟨822⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967373⟩))),
+  .assert (.add (.var ⟨823⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨825⟩) (.var ⟨826⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨827⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨821⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨827⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨803⟩)) (.var ⟨809⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨815⟩))) (.var ⟨824⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨826⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨828⟩) (.var ⟨829⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨830⟩))),
+  .assert (.add (.var ⟨828⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩))),
+  .assert (.add (.var ⟨829⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨831⟩) (.var ⟨832⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨833⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨833⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨803⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨809⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨815⟩))) (.var ⟨824⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨830⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨832⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨834⟩) (.var ⟨835⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨836⟩))),
+  .assert (.add (.var ⟨834⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩)) (.var ⟨831⟩)))),
+  .assert (.add (.var ⟨835⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩)) (.var ⟨831⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨837⟩) (.var ⟨838⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))),
+  .assert (.add (.var ⟨837⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩)) (.var ⟨831⟩)))),
+  .assert (.add (.var ⟨838⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨803⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨809⟩))) (.var ⟨815⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨824⟩))) (.var ⟨830⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨836⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨840⟩) (.var ⟨841⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨842⟩))),
+  .assert (.add (.var ⟨840⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967374⟩))),
+  .assert (.add (.var ⟨841⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨843⟩) (.var ⟨844⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨845⟩))),
+  .assert (.add (.var ⟨843⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967375⟩))),
+  .assert (.add (.var ⟨844⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨846⟩) (.var ⟨847⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨848⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨842⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨848⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨824⟩)) (.var ⟨830⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨836⟩))) (.var ⟨845⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨847⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨849⟩) (.var ⟨850⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨851⟩))),
+  .assert (.add (.var ⟨849⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩))),
+  .assert (.add (.var ⟨850⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨852⟩) (.var ⟨853⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨854⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨854⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨824⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨830⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨836⟩))) (.var ⟨845⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨851⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨853⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨855⟩) (.var ⟨856⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨857⟩))),
+  .assert (.add (.var ⟨855⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩)) (.var ⟨852⟩)))),
+  .assert (.add (.var ⟨856⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩)) (.var ⟨852⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨858⟩) (.var ⟨859⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))),
+  .assert (.add (.var ⟨858⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩)) (.var ⟨852⟩)))),
+  .assert (.add (.var ⟨859⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨824⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨830⟩))) (.var ⟨836⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨845⟩))) (.var ⟨851⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨857⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨861⟩) (.var ⟨862⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨863⟩))),
+  .assert (.add (.var ⟨861⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967376⟩))),
+  .assert (.add (.var ⟨862⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨864⟩) (.var ⟨865⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨866⟩))),
+  .assert (.add (.var ⟨864⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967377⟩))),
+  .assert (.add (.var ⟨865⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨867⟩) (.var ⟨868⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨869⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨863⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨869⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨845⟩)) (.var ⟨851⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨857⟩))) (.var ⟨866⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨868⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨870⟩) (.var ⟨871⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨872⟩))),
+  .assert (.add (.var ⟨870⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨867⟩))),
+  .assert (.add (.var ⟨871⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨867⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨873⟩) (.var ⟨874⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨875⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨875⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨845⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨851⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨857⟩))) (.var ⟨866⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨872⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨874⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨876⟩) (.var ⟨877⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨878⟩))),
+  .assert (.add (.var ⟨876⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨867⟩)) (.var ⟨873⟩)))),
+  .assert (.add (.var ⟨877⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨867⟩)) (.var ⟨873⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨879⟩) (.var ⟨880⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))),
+  .assert (.add (.var ⟨879⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨867⟩)) (.var ⟨873⟩)))),
+  .assert (.add (.var ⟨880⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨845⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨851⟩))) (.var ⟨857⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨866⟩))) (.var ⟨872⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨878⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨882⟩) (.var ⟨883⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨884⟩))),
+  .assert (.add (.var ⟨882⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967378⟩))),
+  .assert (.add (.var ⟨883⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨885⟩) (.var ⟨886⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨887⟩))),
+  .assert (.add (.var ⟨885⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967379⟩))),
+  .assert (.add (.var ⟨886⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨888⟩) (.var ⟨889⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨890⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨884⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨890⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨866⟩)) (.var ⟨872⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨878⟩))) (.var ⟨887⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨889⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨891⟩) (.var ⟨892⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨893⟩))),
+  .assert (.add (.var ⟨891⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨888⟩))),
+  .assert (.add (.var ⟨892⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨888⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨894⟩) (.var ⟨895⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨896⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨896⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨866⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨872⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨878⟩))) (.var ⟨887⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨893⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨895⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨897⟩) (.var ⟨898⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨899⟩))),
+  .assert (.add (.var ⟨897⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨888⟩)) (.var ⟨894⟩)))),
+  .assert (.add (.var ⟨898⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨888⟩)) (.var ⟨894⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨900⟩) (.var ⟨901⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))),
+  .assert (.add (.var ⟨900⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨888⟩)) (.var ⟨894⟩)))),
+  .assert (.add (.var ⟨901⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨866⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨872⟩))) (.var ⟨878⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨887⟩))) (.var ⟨893⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨899⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨903⟩) (.var ⟨904⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨905⟩))),
+  .assert (.add (.var ⟨903⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967380⟩))),
+  .assert (.add (.var ⟨904⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨906⟩) (.var ⟨907⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨908⟩))),
+  .assert (.add (.var ⟨906⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967381⟩))),
+  .assert (.add (.var ⟨907⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨909⟩) (.var ⟨910⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨911⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨905⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨911⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨887⟩)) (.var ⟨893⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨899⟩))) (.var ⟨908⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨910⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨912⟩) (.var ⟨913⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨914⟩))),
+  .assert (.add (.var ⟨912⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨909⟩))),
+  .assert (.add (.var ⟨913⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨909⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨915⟩) (.var ⟨916⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨917⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨917⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨887⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨893⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨899⟩))) (.var ⟨908⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨914⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨916⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨918⟩) (.var ⟨919⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨920⟩))),
+  .assert (.add (.var ⟨918⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨909⟩)) (.var ⟨915⟩)))),
+  .assert (.add (.var ⟨919⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨909⟩)) (.var ⟨915⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨921⟩) (.var ⟨922⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))),
+  .assert (.add (.var ⟨921⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨909⟩)) (.var ⟨915⟩)))),
+  .assert (.add (.var ⟨922⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨887⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨893⟩))) (.var ⟨899⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨908⟩))) (.var ⟨914⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨920⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨924⟩) (.var ⟨925⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨926⟩))),
+  .assert (.add (.var ⟨924⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967382⟩))),
+  .assert (.add (.var ⟨925⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨927⟩) (.var ⟨928⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨929⟩))),
+  .assert (.add (.var ⟨927⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967383⟩))),
+  .assert (.add (.var ⟨928⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨930⟩) (.var ⟨931⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨932⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨926⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨932⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨908⟩)) (.var ⟨914⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨920⟩))) (.var ⟨929⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨931⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨933⟩) (.var ⟨934⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨935⟩))),
+  .assert (.add (.var ⟨933⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨930⟩))),
+  .assert (.add (.var ⟨934⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨930⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨936⟩) (.var ⟨937⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨938⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨938⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨908⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨914⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨920⟩))) (.var ⟨929⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨935⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨937⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨939⟩) (.var ⟨940⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨941⟩))),
+  .assert (.add (.var ⟨939⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨930⟩)) (.var ⟨936⟩)))),
+  .assert (.add (.var ⟨940⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨930⟩)) (.var ⟨936⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨942⟩) (.var ⟨943⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))),
+  .assert (.add (.var ⟨942⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨930⟩)) (.var ⟨936⟩)))),
+  .assert (.add (.var ⟨943⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨908⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨914⟩))) (.var ⟨920⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨929⟩))) (.var ⟨935⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨941⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨945⟩) (.var ⟨946⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨947⟩))),
+  .assert (.add (.var ⟨945⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967384⟩))),
+  .assert (.add (.var ⟨946⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨948⟩) (.var ⟨949⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨950⟩))),
+  .assert (.add (.var ⟨948⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967385⟩))),
+  .assert (.add (.var ⟨949⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨951⟩) (.var ⟨952⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨953⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨947⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨953⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨929⟩)) (.var ⟨935⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨941⟩))) (.var ⟨950⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨952⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨954⟩) (.var ⟨955⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨956⟩))),
+  .assert (.add (.var ⟨954⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨951⟩))),
+  .assert (.add (.var ⟨955⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨951⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨957⟩) (.var ⟨958⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨959⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨959⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨929⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨935⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨941⟩))) (.var ⟨950⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨956⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨958⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨960⟩) (.var ⟨961⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨962⟩))),
+  .assert (.add (.var ⟨960⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨951⟩)) (.var ⟨957⟩)))),
+  .assert (.add (.var ⟨961⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨951⟩)) (.var ⟨957⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨963⟩) (.var ⟨964⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))),
+  .assert (.add (.var ⟨963⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨951⟩)) (.var ⟨957⟩)))),
+  .assert (.add (.var ⟨964⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨929⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨935⟩))) (.var ⟨941⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨950⟩))) (.var ⟨956⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨962⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨966⟩) (.var ⟨967⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨968⟩))),
+  .assert (.add (.var ⟨966⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967386⟩))),
+  .assert (.add (.var ⟨967⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨969⟩) (.var ⟨970⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨971⟩))),
+  .assert (.add (.var ⟨969⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967387⟩))),
+  .assert (.add (.var ⟨970⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨972⟩) (.var ⟨973⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨974⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨968⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨974⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨950⟩)) (.var ⟨956⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨962⟩))) (.var ⟨971⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨973⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨975⟩) (.var ⟨976⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨977⟩))),
+  .assert (.add (.var ⟨975⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨972⟩))),
+  .assert (.add (.var ⟨976⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨972⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨978⟩) (.var ⟨979⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨980⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨980⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨950⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨956⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨962⟩))) (.var ⟨971⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨977⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨979⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨981⟩) (.var ⟨982⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨983⟩))),
+  .assert (.add (.var ⟨981⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨972⟩)) (.var ⟨978⟩)))),
+  .assert (.add (.var ⟨982⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨972⟩)) (.var ⟨978⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨984⟩) (.var ⟨985⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))),
+  .assert (.add (.var ⟨984⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨972⟩)) (.var ⟨978⟩)))),
+  .assert (.add (.var ⟨985⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨950⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨956⟩))) (.var ⟨962⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨971⟩))) (.var ⟨977⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨983⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨987⟩) (.var ⟨988⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨989⟩))),
+  .assert (.add (.var ⟨987⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967388⟩))),
+  .assert (.add (.var ⟨988⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨990⟩) (.var ⟨991⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨992⟩))),
+  .assert (.add (.var ⟨990⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967389⟩))),
+  .assert (.add (.var ⟨991⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨993⟩) (.var ⟨994⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨995⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨989⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨995⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨971⟩)) (.var ⟨977⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨983⟩))) (.var ⟨992⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨994⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨996⟩) (.var ⟨997⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨998⟩))),
+  .assert (.add (.var ⟨996⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨993⟩))),
+  .assert (.add (.var ⟨997⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨993⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨999⟩) (.var ⟨1000⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1001⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1001⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨971⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨977⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨983⟩))) (.var ⟨992⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨998⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1000⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1002⟩) (.var ⟨1003⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1004⟩))),
+  .assert (.add (.var ⟨1002⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨993⟩)) (.var ⟨999⟩)))),
+  .assert (.add (.var ⟨1003⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨993⟩)) (.var ⟨999⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1005⟩) (.var ⟨1006⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))),
+  .assert (.add (.var ⟨1005⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨993⟩)) (.var ⟨999⟩)))),
+  .assert (.add (.var ⟨1006⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨971⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨977⟩))) (.var ⟨983⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨992⟩))) (.var ⟨998⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1004⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1008⟩) (.var ⟨1009⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1010⟩))),
+  .assert (.add (.var ⟨1008⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967390⟩))),
+  .assert (.add (.var ⟨1009⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1011⟩) (.var ⟨1012⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1013⟩))),
+  .assert (.add (.var ⟨1011⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967391⟩))),
+  .assert (.add (.var ⟨1012⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1014⟩) (.var ⟨1015⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1016⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1010⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1016⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨992⟩)) (.var ⟨998⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1004⟩))) (.var ⟨1013⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1015⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1017⟩) (.var ⟨1018⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1019⟩))),
+  .assert (.add (.var ⟨1017⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1014⟩))),
+  .assert (.add (.var ⟨1018⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1014⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1020⟩) (.var ⟨1021⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1022⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1022⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨992⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨998⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1004⟩))) (.var ⟨1013⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1019⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1021⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1023⟩) (.var ⟨1024⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1025⟩))),
+  .assert (.add (.var ⟨1023⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1014⟩)) (.var ⟨1020⟩)))),
+  .assert (.add (.var ⟨1024⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1014⟩)) (.var ⟨1020⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1026⟩) (.var ⟨1027⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))),
+  .assert (.add (.var ⟨1026⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1014⟩)) (.var ⟨1020⟩)))),
+  .assert (.add (.var ⟨1027⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨992⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨998⟩))) (.var ⟨1004⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1013⟩))) (.var ⟨1019⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1025⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1029⟩) (.var ⟨1030⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1031⟩))),
+  .assert (.add (.var ⟨1029⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967392⟩))),
+  .assert (.add (.var ⟨1030⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1032⟩) (.var ⟨1033⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1034⟩))),
+  .assert (.add (.var ⟨1032⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967393⟩))),
+  .assert (.add (.var ⟨1033⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1035⟩) (.var ⟨1036⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1037⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1031⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1037⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1013⟩)) (.var ⟨1019⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1025⟩))) (.var ⟨1034⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1036⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1038⟩) (.var ⟨1039⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1040⟩))),
+  .assert (.add (.var ⟨1038⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1035⟩))),
+  .assert (.add (.var ⟨1039⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1035⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1041⟩) (.var ⟨1042⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1043⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1043⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1013⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1019⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1025⟩))) (.var ⟨1034⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1040⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1042⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1044⟩) (.var ⟨1045⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1046⟩))),
+  .assert (.add (.var ⟨1044⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1035⟩)) (.var ⟨1041⟩)))),
+  .assert (.add (.var ⟨1045⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1035⟩)) (.var ⟨1041⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1047⟩) (.var ⟨1048⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))),
+  .assert (.add (.var ⟨1047⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1035⟩)) (.var ⟨1041⟩)))),
+  .assert (.add (.var ⟨1048⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1013⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1019⟩))) (.var ⟨1025⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1034⟩))) (.var ⟨1040⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1046⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1050⟩) (.var ⟨1051⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1052⟩))),
+  .assert (.add (.var ⟨1050⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967394⟩))),
+  .assert (.add (.var ⟨1051⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1053⟩) (.var ⟨1054⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1055⟩))),
+  .assert (.add (.var ⟨1053⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967395⟩))),
+  .assert (.add (.var ⟨1054⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1056⟩) (.var ⟨1057⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1058⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1052⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1058⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1034⟩)) (.var ⟨1040⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1046⟩))) (.var ⟨1055⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1057⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1059⟩) (.var ⟨1060⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1061⟩))),
+  .assert (.add (.var ⟨1059⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1056⟩))),
+  .assert (.add (.var ⟨1060⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1056⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1062⟩) (.var ⟨1063⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1064⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1064⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1034⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1040⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1046⟩))) (.var ⟨1055⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1061⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1063⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1065⟩) (.var ⟨1066⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1067⟩))),
+  .assert (.add (.var ⟨1065⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1056⟩)) (.var ⟨1062⟩)))),
+  .assert (.add (.var ⟨1066⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1056⟩)) (.var ⟨1062⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1068⟩) (.var ⟨1069⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))),
+  .assert (.add (.var ⟨1068⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1056⟩)) (.var ⟨1062⟩)))),
+  .assert (.add (.var ⟨1069⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1034⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1040⟩))) (.var ⟨1046⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1055⟩))) (.var ⟨1061⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1067⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1071⟩) (.var ⟨1072⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1073⟩))),
+  .assert (.add (.var ⟨1071⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967396⟩))),
+  .assert (.add (.var ⟨1072⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1074⟩) (.var ⟨1075⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1076⟩))),
+  .assert (.add (.var ⟨1074⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967397⟩))),
+  .assert (.add (.var ⟨1075⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1077⟩) (.var ⟨1078⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1079⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1073⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1079⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1055⟩)) (.var ⟨1061⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1067⟩))) (.var ⟨1076⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1078⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1080⟩) (.var ⟨1081⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1082⟩))),
+  .assert (.add (.var ⟨1080⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1077⟩))),
+  .assert (.add (.var ⟨1081⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1077⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1083⟩) (.var ⟨1084⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1085⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1085⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1055⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1061⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1067⟩))) (.var ⟨1076⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1082⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1084⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1086⟩) (.var ⟨1087⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1088⟩))),
+  .assert (.add (.var ⟨1086⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1077⟩)) (.var ⟨1083⟩)))),
+  .assert (.add (.var ⟨1087⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1077⟩)) (.var ⟨1083⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1089⟩) (.var ⟨1090⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))),
+  .assert (.add (.var ⟨1089⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1077⟩)) (.var ⟨1083⟩)))),
+  .assert (.add (.var ⟨1090⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1055⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1061⟩))) (.var ⟨1067⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1076⟩))) (.var ⟨1082⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1088⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1092⟩) (.var ⟨1093⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1094⟩))),
+  .assert (.add (.var ⟨1092⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967398⟩))),
+  .assert (.add (.var ⟨1093⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1095⟩) (.var ⟨1096⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1097⟩))),
+  .assert (.add (.var ⟨1095⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967399⟩))),
+  .assert (.add (.var ⟨1096⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1098⟩) (.var ⟨1099⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1100⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1094⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1100⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1076⟩)) (.var ⟨1082⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1088⟩))) (.var ⟨1097⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1099⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1101⟩) (.var ⟨1102⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1103⟩))),
+  .assert (.add (.var ⟨1101⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1098⟩))),
+  .assert (.add (.var ⟨1102⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1098⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1104⟩) (.var ⟨1105⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1106⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1106⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1076⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1082⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1088⟩))) (.var ⟨1097⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1103⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1105⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1107⟩) (.var ⟨1108⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1109⟩))),
+  .assert (.add (.var ⟨1107⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1098⟩)) (.var ⟨1104⟩)))),
+  .assert (.add (.var ⟨1108⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1098⟩)) (.var ⟨1104⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1110⟩) (.var ⟨1111⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))),
+  .assert (.add (.var ⟨1110⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1098⟩)) (.var ⟨1104⟩)))),
+  .assert (.add (.var ⟨1111⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1076⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1082⟩))) (.var ⟨1088⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1097⟩))) (.var ⟨1103⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1109⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1113⟩) (.var ⟨1114⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1115⟩))),
+  .assert (.add (.var ⟨1113⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967400⟩))),
+  .assert (.add (.var ⟨1114⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1116⟩) (.var ⟨1117⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1118⟩))),
+  .assert (.add (.var ⟨1116⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967401⟩))),
+  .assert (.add (.var ⟨1117⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1119⟩) (.var ⟨1120⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1121⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1091⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))) (.var ⟨1115⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1121⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1097⟩)) (.var ⟨1103⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1109⟩))) (.var ⟨1118⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1120⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1122⟩) (.var ⟨1123⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1124⟩))),
+  .assert (.add (.var ⟨1122⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1119⟩))),
+  .assert (.add (.var ⟨1123⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1119⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1125⟩) (.var ⟨1126⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1127⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1091⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1127⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1097⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1103⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1109⟩))) (.var ⟨1118⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1124⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1126⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1128⟩) (.var ⟨1129⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1130⟩))),
+  .assert (.add (.var ⟨1128⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1119⟩)) (.var ⟨1125⟩)))),
+  .assert (.add (.var ⟨1129⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1119⟩)) (.var ⟨1125⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1131⟩) (.var ⟨1132⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))),
+  .assert (.add (.var ⟨1131⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1119⟩)) (.var ⟨1125⟩)))),
+  .assert (.add (.var ⟨1132⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1097⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1103⟩))) (.var ⟨1109⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1118⟩))) (.var ⟨1124⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1130⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1134⟩) (.var ⟨1135⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1136⟩))),
+  .assert (.add (.var ⟨1134⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967402⟩))),
+  .assert (.add (.var ⟨1135⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1137⟩) (.var ⟨1138⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1139⟩))),
+  .assert (.add (.var ⟨1137⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967403⟩))),
+  .assert (.add (.var ⟨1138⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1140⟩) (.var ⟨1141⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1142⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1136⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1142⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1118⟩)) (.var ⟨1124⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1130⟩))) (.var ⟨1139⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1141⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1143⟩) (.var ⟨1144⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1145⟩))),
+  .assert (.add (.var ⟨1143⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1140⟩))),
+  .assert (.add (.var ⟨1144⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1140⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1146⟩) (.var ⟨1147⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1148⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1133⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1148⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1118⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1124⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1130⟩))) (.var ⟨1139⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1145⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1147⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1149⟩) (.var ⟨1150⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1151⟩))),
+  .assert (.add (.var ⟨1149⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1140⟩)) (.var ⟨1146⟩)))),
+  .assert (.add (.var ⟨1150⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1140⟩)) (.var ⟨1146⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1152⟩) (.var ⟨1153⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1154⟩))),
+  .assert (.add (.var ⟨1152⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1140⟩)) (.var ⟨1146⟩)))),
+  .assert (.add (.var ⟨1153⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1118⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1124⟩))) (.var ⟨1130⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1139⟩))) (.var ⟨1145⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1151⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1155⟩) (.var ⟨1156⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1157⟩))),
+  .assert (.add (.var ⟨1155⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967404⟩))),
+  .assert (.add (.var ⟨1156⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1158⟩) (.var ⟨1159⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1160⟩))),
+  .assert (.add (.var ⟨1158⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967405⟩))),
+  .assert (.add (.var ⟨1159⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1161⟩) (.var ⟨1162⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1163⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1091⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))) (.var ⟨1133⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1154⟩))) (.var ⟨1157⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1163⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1139⟩)) (.var ⟨1145⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1151⟩))) (.var ⟨1160⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1162⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1164⟩) (.var ⟨1165⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1166⟩))),
+  .assert (.add (.var ⟨1164⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1161⟩))),
+  .assert (.add (.var ⟨1165⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1161⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1167⟩) (.var ⟨1168⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1169⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1091⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1112⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1133⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1154⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1169⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1139⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1145⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1151⟩))) (.var ⟨1160⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1166⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1168⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1170⟩) (.var ⟨1171⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1172⟩))),
+  .assert (.add (.var ⟨1170⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1161⟩)) (.var ⟨1167⟩)))),
+  .assert (.add (.var ⟨1171⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1161⟩)) (.var ⟨1167⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1173⟩) (.var ⟨1174⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))),
+  .assert (.add (.var ⟨1173⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1161⟩)) (.var ⟨1167⟩)))),
+  .assert (.add (.var ⟨1174⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1139⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1145⟩))) (.var ⟨1151⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1160⟩))) (.var ⟨1166⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1172⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1176⟩) (.var ⟨1177⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1178⟩))),
+  .assert (.add (.var ⟨1176⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967406⟩))),
+  .assert (.add (.var ⟨1177⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1179⟩) (.var ⟨1180⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1181⟩))),
+  .assert (.add (.var ⟨1179⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967407⟩))),
+  .assert (.add (.var ⟨1180⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1182⟩) (.var ⟨1183⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1184⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))) (.var ⟨1178⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1184⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1160⟩)) (.var ⟨1166⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1172⟩))) (.var ⟨1181⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1183⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1185⟩) (.var ⟨1186⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1187⟩))),
+  .assert (.add (.var ⟨1185⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1182⟩))),
+  .assert (.add (.var ⟨1186⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1182⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1188⟩) (.var ⟨1189⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1190⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1133⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1154⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1175⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1190⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1160⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1166⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1172⟩))) (.var ⟨1181⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1187⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1189⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1191⟩) (.var ⟨1192⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1193⟩))),
+  .assert (.add (.var ⟨1191⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1182⟩)) (.var ⟨1188⟩)))),
+  .assert (.add (.var ⟨1192⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1182⟩)) (.var ⟨1188⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1194⟩) (.var ⟨1195⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1196⟩))),
+  .assert (.add (.var ⟨1194⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1182⟩)) (.var ⟨1188⟩)))),
+  .assert (.add (.var ⟨1195⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1160⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1166⟩))) (.var ⟨1172⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1181⟩))) (.var ⟨1187⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1193⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1197⟩) (.var ⟨1198⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1199⟩))),
+  .assert (.add (.var ⟨1197⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967408⟩))),
+  .assert (.add (.var ⟨1198⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1200⟩) (.var ⟨1201⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1202⟩))),
+  .assert (.add (.var ⟨1200⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967409⟩))),
+  .assert (.add (.var ⟨1201⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1203⟩) (.var ⟨1204⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1205⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1091⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))) (.var ⟨1133⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1154⟩))) (.var ⟨1175⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1196⟩))) (.var ⟨1199⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1205⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1181⟩)) (.var ⟨1187⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1193⟩))) (.var ⟨1202⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1204⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1206⟩) (.var ⟨1207⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1208⟩))),
+  .assert (.add (.var ⟨1206⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1203⟩))),
+  .assert (.add (.var ⟨1207⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1203⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1209⟩) (.var ⟨1210⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1211⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1091⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1112⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1133⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1154⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1175⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1196⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1211⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1181⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1187⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1193⟩))) (.var ⟨1202⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1208⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1210⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1212⟩) (.var ⟨1213⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1214⟩))),
+  .assert (.add (.var ⟨1212⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1203⟩)) (.var ⟨1209⟩)))),
+  .assert (.add (.var ⟨1213⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1203⟩)) (.var ⟨1209⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1215⟩) (.var ⟨1216⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1217⟩))),
+  .assert (.add (.var ⟨1215⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1203⟩)) (.var ⟨1209⟩)))),
+  .assert (.add (.var ⟨1216⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1181⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1187⟩))) (.var ⟨1193⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1202⟩))) (.var ⟨1208⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1214⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1218⟩) (.var ⟨1219⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1220⟩))),
+  .assert (.add (.var ⟨1218⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967410⟩))),
+  .assert (.add (.var ⟨1219⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1221⟩) (.var ⟨1222⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1223⟩))),
+  .assert (.add (.var ⟨1221⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967411⟩))),
+  .assert (.add (.var ⟨1222⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1224⟩) (.var ⟨1225⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1226⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))) (.var ⟨1196⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1217⟩))) (.var ⟨1220⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1226⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1202⟩)) (.var ⟨1208⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1214⟩))) (.var ⟨1223⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1225⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1227⟩) (.var ⟨1228⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1229⟩))),
+  .assert (.add (.var ⟨1227⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1224⟩))),
+  .assert (.add (.var ⟨1228⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1224⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1230⟩) (.var ⟨1231⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1232⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1133⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1154⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1175⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1196⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1217⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1232⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1202⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1208⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1214⟩))) (.var ⟨1223⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1229⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1231⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1233⟩) (.var ⟨1234⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1235⟩))),
+  .assert (.add (.var ⟨1233⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1224⟩)) (.var ⟨1230⟩)))),
+  .assert (.add (.var ⟨1234⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1224⟩)) (.var ⟨1230⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1236⟩) (.var ⟨1237⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1238⟩))),
+  .assert (.add (.var ⟨1236⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1224⟩)) (.var ⟨1230⟩)))),
+  .assert (.add (.var ⟨1237⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1202⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1208⟩))) (.var ⟨1214⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1223⟩))) (.var ⟨1229⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1235⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1239⟩) (.var ⟨1240⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1241⟩))),
+  .assert (.add (.var ⟨1239⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967412⟩))),
+  .assert (.add (.var ⟨1240⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1242⟩) (.var ⟨1243⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1244⟩))),
+  .assert (.add (.var ⟨1242⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967413⟩))),
+  .assert (.add (.var ⟨1243⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1245⟩) (.var ⟨1246⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1247⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1091⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))) (.var ⟨1133⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1154⟩))) (.var ⟨1175⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1196⟩))) (.var ⟨1217⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1238⟩))) (.var ⟨1241⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1247⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1223⟩)) (.var ⟨1229⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1235⟩))) (.var ⟨1244⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1246⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1248⟩) (.var ⟨1249⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1250⟩))),
+  .assert (.add (.var ⟨1248⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1245⟩))),
+  .assert (.add (.var ⟨1249⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1245⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1251⟩) (.var ⟨1252⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1253⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1091⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1112⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1133⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1154⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1175⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1196⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1217⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1238⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1253⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1223⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1229⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1235⟩))) (.var ⟨1244⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1250⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1252⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1254⟩) (.var ⟨1255⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1256⟩))),
+  .assert (.add (.var ⟨1254⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1245⟩)) (.var ⟨1251⟩)))),
+  .assert (.add (.var ⟨1255⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1245⟩)) (.var ⟨1251⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1257⟩) (.var ⟨1258⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1259⟩))),
+  .assert (.add (.var ⟨1257⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1245⟩)) (.var ⟨1251⟩)))),
+  .assert (.add (.var ⟨1258⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1223⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1229⟩))) (.var ⟨1235⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1244⟩))) (.var ⟨1250⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1256⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1260⟩) (.var ⟨1261⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1262⟩))),
+  .assert (.add (.var ⟨1260⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967414⟩))),
+  .assert (.add (.var ⟨1261⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1263⟩) (.var ⟨1264⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1265⟩))),
+  .assert (.add (.var ⟨1263⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967415⟩))),
+  .assert (.add (.var ⟨1264⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1266⟩) (.var ⟨1267⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1268⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))) (.var ⟨1196⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1217⟩))) (.var ⟨1238⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1259⟩))) (.var ⟨1262⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1268⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1244⟩)) (.var ⟨1250⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1256⟩))) (.var ⟨1265⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1267⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1269⟩) (.var ⟨1270⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1271⟩))),
+  .assert (.add (.var ⟨1269⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1266⟩))),
+  .assert (.add (.var ⟨1270⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1266⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1272⟩) (.var ⟨1273⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1274⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1133⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1154⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1175⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1196⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1217⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1238⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1259⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1274⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1244⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1250⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1256⟩))) (.var ⟨1265⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1271⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1273⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1275⟩) (.var ⟨1276⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1277⟩))),
+  .assert (.add (.var ⟨1275⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1266⟩)) (.var ⟨1272⟩)))),
+  .assert (.add (.var ⟨1276⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1266⟩)) (.var ⟨1272⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1278⟩) (.var ⟨1279⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1280⟩))),
+  .assert (.add (.var ⟨1278⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1266⟩)) (.var ⟨1272⟩)))),
+  .assert (.add (.var ⟨1279⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1244⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1250⟩))) (.var ⟨1256⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1265⟩))) (.var ⟨1271⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1277⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1281⟩) (.var ⟨1282⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1283⟩))),
+  .assert (.add (.var ⟨1281⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967416⟩))),
+  .assert (.add (.var ⟨1282⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1284⟩) (.var ⟨1285⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1286⟩))),
+  .assert (.add (.var ⟨1284⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967417⟩))),
+  .assert (.add (.var ⟨1285⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1287⟩) (.var ⟨1288⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1289⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1091⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))) (.var ⟨1133⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1154⟩))) (.var ⟨1175⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1196⟩))) (.var ⟨1217⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1238⟩))) (.var ⟨1259⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1280⟩))) (.var ⟨1283⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1289⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1265⟩)) (.var ⟨1271⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1277⟩))) (.var ⟨1286⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1288⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1290⟩) (.var ⟨1291⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1292⟩))),
+  .assert (.add (.var ⟨1290⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1287⟩))),
+  .assert (.add (.var ⟨1291⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1287⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1293⟩) (.var ⟨1294⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1295⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1091⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1112⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1133⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1154⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1175⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1196⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1217⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1238⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1259⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1280⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1295⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1265⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1271⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1277⟩))) (.var ⟨1286⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1292⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1294⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1296⟩) (.var ⟨1297⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1298⟩))),
+  .assert (.add (.var ⟨1296⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1287⟩)) (.var ⟨1293⟩)))),
+  .assert (.add (.var ⟨1297⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1287⟩)) (.var ⟨1293⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1299⟩) (.var ⟨1300⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1301⟩))),
+  .assert (.add (.var ⟨1299⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1287⟩)) (.var ⟨1293⟩)))),
+  .assert (.add (.var ⟨1300⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1265⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1271⟩))) (.var ⟨1277⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1286⟩))) (.var ⟨1292⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1298⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1302⟩) (.var ⟨1303⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1304⟩))),
+  .assert (.add (.var ⟨1302⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967418⟩))),
+  .assert (.add (.var ⟨1303⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1305⟩) (.var ⟨1306⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1307⟩))),
+  .assert (.add (.var ⟨1305⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967419⟩))),
+  .assert (.add (.var ⟨1306⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1308⟩) (.var ⟨1309⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1310⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))) (.var ⟨1196⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1217⟩))) (.var ⟨1238⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1259⟩))) (.var ⟨1280⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1301⟩))) (.var ⟨1304⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1310⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1286⟩)) (.var ⟨1292⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1298⟩))) (.var ⟨1307⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1309⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1311⟩) (.var ⟨1312⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1313⟩))),
+  .assert (.add (.var ⟨1311⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1308⟩))),
+  .assert (.add (.var ⟨1312⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1308⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1314⟩) (.var ⟨1315⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1316⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1133⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1154⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1175⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1196⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1217⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1238⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1259⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1280⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1301⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1316⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1286⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1292⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1298⟩))) (.var ⟨1307⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1313⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1315⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1317⟩) (.var ⟨1318⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1319⟩))),
+  .assert (.add (.var ⟨1317⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1308⟩)) (.var ⟨1314⟩)))),
+  .assert (.add (.var ⟨1318⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1308⟩)) (.var ⟨1314⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1320⟩) (.var ⟨1321⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1322⟩))),
+  .assert (.add (.var ⟨1320⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1308⟩)) (.var ⟨1314⟩)))),
+  .assert (.add (.var ⟨1321⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1286⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1292⟩))) (.var ⟨1298⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1307⟩))) (.var ⟨1313⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1319⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1323⟩) (.var ⟨1324⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1325⟩))),
+  .assert (.add (.var ⟨1323⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967420⟩))),
+  .assert (.add (.var ⟨1324⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1326⟩) (.var ⟨1327⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1328⟩))),
+  .assert (.add (.var ⟨1326⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967421⟩))),
+  .assert (.add (.var ⟨1327⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1329⟩) (.var ⟨1330⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1331⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨20⟩))) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨62⟩))) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨104⟩))) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨146⟩))) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨188⟩))) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨230⟩))) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨272⟩))) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨314⟩))) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨356⟩))) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨398⟩))) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨440⟩))) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨482⟩))) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨524⟩))) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨566⟩))) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨608⟩))) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨650⟩))) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨692⟩))) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨734⟩))) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨776⟩))) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨818⟩))) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨860⟩))) (.var ⟨881⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨902⟩))) (.var ⟨923⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨944⟩))) (.var ⟨965⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨986⟩))) (.var ⟨1007⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1028⟩))) (.var ⟨1049⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1070⟩))) (.var ⟨1091⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1112⟩))) (.var ⟨1133⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1154⟩))) (.var ⟨1175⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1196⟩))) (.var ⟨1217⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1238⟩))) (.var ⟨1259⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1280⟩))) (.var ⟨1301⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1322⟩))) (.var ⟨1325⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1331⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1307⟩)) (.var ⟨1313⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1319⟩))) (.var ⟨1328⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1330⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1332⟩) (.var ⟨1333⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1334⟩))),
+  .assert (.add (.var ⟨1332⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1329⟩))),
+  .assert (.add (.var ⟨1333⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1329⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1335⟩) (.var ⟨1336⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1337⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨8⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨20⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨41⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨62⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨83⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨104⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨125⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨146⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨167⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨188⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨209⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨230⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨251⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨272⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨293⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨314⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨335⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨356⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨377⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨398⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨419⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨440⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨461⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨482⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨503⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨524⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨545⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨566⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨587⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨608⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨629⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨650⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨671⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨692⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨713⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨734⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨755⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨776⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨797⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨818⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨839⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨860⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨881⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨902⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨923⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨944⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨965⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨986⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1007⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1028⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1049⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1070⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1091⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1112⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1133⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1154⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1175⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1196⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1217⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1238⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1259⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1280⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1301⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1322⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1337⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1307⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1313⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1319⟩))) (.var ⟨1328⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1334⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1336⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1338⟩) (.var ⟨1339⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1340⟩))),
+  .assert (.add (.var ⟨1338⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1329⟩)) (.var ⟨1335⟩)))),
+  .assert (.add (.var ⟨1339⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1329⟩)) (.var ⟨1335⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1341⟩) (.var ⟨1342⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1343⟩))),
+  .assert (.add (.var ⟨1341⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1329⟩)) (.var ⟨1335⟩)))),
+  .assert (.add (.var ⟨1342⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1307⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1313⟩))) (.var ⟨1319⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1328⟩))) (.var ⟨1334⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1340⟩))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1344⟩) (.var ⟨1345⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1346⟩))),
+  .assert (.add (.var ⟨1344⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967422⟩))),
+  .assert (.add (.var ⟨1345⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1347⟩) (.var ⟨1348⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1349⟩))),
+  .assert (.add (.var ⟨1347⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨4294967423⟩))),
+  .assert (.add (.var ⟨1348⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab8) (.var ⟨4294967424⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1350⟩) (.var ⟨1351⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1352⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))) (.var ⟨1196⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1217⟩))) (.var ⟨1238⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1259⟩))) (.var ⟨1280⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1301⟩))) (.var ⟨1322⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1343⟩))) (.var ⟨1346⟩)) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1352⟩))),
+  .assert (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1328⟩)) (.var ⟨1334⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1340⟩))) (.var ⟨1349⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1351⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1353⟩) (.var ⟨1354⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1355⟩))),
+  .assert (.add (.var ⟨1353⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1350⟩))),
+  .assert (.add (.var ⟨1354⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1350⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1356⟩) (.var ⟨1357⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1358⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨20⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨41⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨62⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨83⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨104⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨125⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨146⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨167⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨188⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨209⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨230⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨251⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨272⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨293⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨314⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨335⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨356⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨377⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨398⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨419⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨440⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨461⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨482⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨503⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨524⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨545⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨566⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨587⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨608⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨629⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨650⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨671⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨692⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨713⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨734⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨755⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨776⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨797⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨818⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨839⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨860⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨881⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨902⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨923⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨944⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨965⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨986⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1007⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1028⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1049⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1070⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1091⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1112⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1133⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1154⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1175⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1196⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1217⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1238⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1259⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1280⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1301⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1322⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1343⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨4294967425⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1358⟩))),
+  .assert (.add (.add (.add (.add (.add (.add (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1328⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ecffffffff) (.var ⟨1334⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000002) (.var ⟨1340⟩))) (.var ⟨1349⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1355⟩))) (.mul (.const 0x0000000000000000000000000000000000000000000000000000000000000003) (.var ⟨4294967424⟩))) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1357⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1359⟩) (.var ⟨1360⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1361⟩))),
+  .assert (.add (.var ⟨1359⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1350⟩)) (.var ⟨1356⟩)))),
+  .assert (.add (.var ⟨1360⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1350⟩)) (.var ⟨1356⟩)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨1362⟩) (.var ⟨1363⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1364⟩))),
+  .assert (.add (.var ⟨1362⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1350⟩)) (.var ⟨1356⟩)))),
+  .assert (.add (.var ⟨1363⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.var ⟨1328⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1334⟩))) (.var ⟨1340⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1349⟩))) (.var ⟨1355⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1361⟩)))))
+]
+
+set_option maxRecDepth 100000 in
+/-- The extracted output expressions. -/
+def outputs : List (Expression (F p)) := [
+  .add (.add (.add (.var ⟨1349⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1355⟩))) (.var ⟨1361⟩)) (.var ⟨4294967424⟩),
+  .add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨8⟩)) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨41⟩))) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨83⟩))) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨125⟩))) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨167⟩))) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨209⟩))) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨251⟩))) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨293⟩))) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨335⟩))) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨377⟩))) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨419⟩))) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨461⟩))) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨503⟩))) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨545⟩))) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨587⟩))) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨629⟩))) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨671⟩))) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨713⟩))) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨755⟩))) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨797⟩))) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨839⟩))) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨881⟩))) (.var ⟨902⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨923⟩))) (.var ⟨944⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨965⟩))) (.var ⟨986⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1007⟩))) (.var ⟨1028⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1049⟩))) (.var ⟨1070⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1091⟩))) (.var ⟨1112⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1133⟩))) (.var ⟨1154⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1175⟩))) (.var ⟨1196⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1217⟩))) (.var ⟨1238⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1259⟩))) (.var ⟨1280⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1301⟩))) (.var ⟨1322⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨1343⟩))) (.var ⟨1364⟩)) (.var ⟨4294967425⟩)
+]
+
+end Ragu.Extracted.Endoscalar.GroupScale
@@ -0,0 +1,1196 @@
+import Ragu.Core
+
+/-!
+# `Ragu.Extracted.Step.Hash2`
+
+Autogenerated by `cargo run -p lean_extraction -- extract Ragu.Extracted.Step.Hash2`; do not edit.
+
+Fingerprint: `20ce98c7a8851c05eb025da29f86ef80faaa314db3b49803a86d3006728fb7ba`
+-/
+
+namespace Ragu.Extracted.Step.Hash2
+
+@[reducible]
+def p := Ragu.Core.Primes.p
+
+/-- The number of input variables, which are numbered from `2³²`. -/
+def inputLen : ℕ := 0
+
+set_option maxRecDepth 100000 in
+/-- The extracted operations. -/
+def operations : List (FlatOperation (F p)) := [
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨0⟩) (.var ⟨1⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨2⟩))),
+  .witness 1 (fun _ => default),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨4⟩) (.var ⟨5⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨6⟩))),
+  .assert (.add (.var ⟨4⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨0⟩) (.mul (.const 0x2c44402b93c5ac82bc8bb58e947fca107e865d85b1cb0f1f32f0c05cdaf439f8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨5⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨0⟩) (.mul (.const 0x2c44402b93c5ac82bc8bb58e947fca107e865d85b1cb0f1f32f0c05cdaf439f8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨7⟩) (.var ⟨8⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨9⟩))),
+  .assert (.add (.var ⟨7⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨6⟩))),
+  .assert (.add (.var ⟨8⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨6⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨10⟩) (.var ⟨11⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨12⟩))),
+  .assert (.add (.var ⟨10⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨9⟩))),
+  .assert (.add (.var ⟨11⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨0⟩) (.mul (.const 0x2c44402b93c5ac82bc8bb58e947fca107e865d85b1cb0f1f32f0c05cdaf439f8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨13⟩) (.var ⟨14⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨15⟩))),
+  .assert (.add (.var ⟨13⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨3⟩) (.mul (.const 0x023eb54d53e89505d0c9258bee0de17bb0a11e451b48d22d88549e05e2018403) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨14⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨3⟩) (.mul (.const 0x023eb54d53e89505d0c9258bee0de17bb0a11e451b48d22d88549e05e2018403) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨16⟩) (.var ⟨17⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨18⟩))),
+  .assert (.add (.var ⟨16⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨15⟩))),
+  .assert (.add (.var ⟨17⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨15⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨19⟩) (.var ⟨20⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨21⟩))),
+  .assert (.add (.var ⟨19⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨18⟩))),
+  .assert (.add (.var ⟨20⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.var ⟨3⟩) (.mul (.const 0x023eb54d53e89505d0c9258bee0de17bb0a11e451b48d22d88549e05e2018403) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨22⟩) (.var ⟨23⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨24⟩))),
+  .assert (.add (.var ⟨22⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x349b3f7366f89983b3858e40a22f53fa2e62ad46932303ce85d42591ca5141a3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .assert (.add (.var ⟨23⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x349b3f7366f89983b3858e40a22f53fa2e62ad46932303ce85d42591ca5141a3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨25⟩) (.var ⟨26⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩))),
+  .assert (.add (.var ⟨25⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨24⟩))),
+  .assert (.add (.var ⟨26⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨24⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨28⟩) (.var ⟨29⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨30⟩))),
+  .assert (.add (.var ⟨28⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨27⟩))),
+  .assert (.add (.var ⟨29⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x349b3f7366f89983b3858e40a22f53fa2e62ad46932303ce85d42591ca5141a3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨31⟩) (.var ⟨32⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨33⟩))),
+  .assert (.add (.var ⟨31⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x0b383428a756701b8c1c1c38c9c3abbb4df3b6fbb5a581fe9bba326455776e91) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .assert (.add (.var ⟨32⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x0b383428a756701b8c1c1c38c9c3abbb4df3b6fbb5a581fe9bba326455776e91) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨34⟩) (.var ⟨35⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨36⟩))),
+  .assert (.add (.var ⟨34⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨33⟩))),
+  .assert (.add (.var ⟨35⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨33⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨37⟩) (.var ⟨38⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨39⟩))),
+  .assert (.add (.var ⟨37⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨36⟩))),
+  .assert (.add (.var ⟨38⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x0b383428a756701b8c1c1c38c9c3abbb4df3b6fbb5a581fe9bba326455776e91) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨40⟩) (.var ⟨41⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨42⟩))),
+  .assert (.add (.var ⟨40⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x382d39c6bdbba256b12d3fa4476187c14c4867255ea999c6503e92f520e9a918) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .assert (.add (.var ⟨41⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x382d39c6bdbba256b12d3fa4476187c14c4867255ea999c6503e92f520e9a918) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨43⟩) (.var ⟨44⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨45⟩))),
+  .assert (.add (.var ⟨43⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨42⟩))),
+  .assert (.add (.var ⟨44⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨42⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨46⟩) (.var ⟨47⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨48⟩))),
+  .assert (.add (.var ⟨46⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨45⟩))),
+  .assert (.add (.var ⟨47⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.mul (.const 0x382d39c6bdbba256b12d3fa4476187c14c4867255ea999c6503e92f520e9a918) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨49⟩) (.var ⟨50⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨51⟩))),
+  .assert (.add (.var ⟨49⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨12⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨21⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨30⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨39⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨48⟩))) (.mul (.const 0x3bd1bbe1f40eb3fa7def818257305e9c50a675d756e6aed051fc4e7b7b132d8b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨50⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨12⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨21⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨30⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨39⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨48⟩))) (.mul (.const 0x3bd1bbe1f40eb3fa7def818257305e9c50a675d756e6aed051fc4e7b7b132d8b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨52⟩) (.var ⟨53⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨54⟩))),
+  .assert (.add (.var ⟨52⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨51⟩))),
+  .assert (.add (.var ⟨53⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨51⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨55⟩) (.var ⟨56⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨57⟩))),
+  .assert (.add (.var ⟨55⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨54⟩))),
+  .assert (.add (.var ⟨56⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨12⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨21⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨30⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨39⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨48⟩))) (.mul (.const 0x3bd1bbe1f40eb3fa7def818257305e9c50a675d756e6aed051fc4e7b7b132d8b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨58⟩) (.var ⟨59⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨60⟩))),
+  .assert (.add (.var ⟨58⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨12⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨21⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨30⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨39⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨48⟩))) (.mul (.const 0x36a92b9f74005c1802eb60930a7135d4af73fc20a535506093f98fa0aa1fbd91) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨59⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨12⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨21⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨30⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨39⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨48⟩))) (.mul (.const 0x36a92b9f74005c1802eb60930a7135d4af73fc20a535506093f98fa0aa1fbd91) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨61⟩) (.var ⟨62⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨63⟩))),
+  .assert (.add (.var ⟨61⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨60⟩))),
+  .assert (.add (.var ⟨62⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨60⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨64⟩) (.var ⟨65⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨66⟩))),
+  .assert (.add (.var ⟨64⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨63⟩))),
+  .assert (.add (.var ⟨65⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨12⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨21⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨30⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨39⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨48⟩))) (.mul (.const 0x36a92b9f74005c1802eb60930a7135d4af73fc20a535506093f98fa0aa1fbd91) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨67⟩) (.var ⟨68⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩))),
+  .assert (.add (.var ⟨67⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨12⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨21⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨30⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨39⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨48⟩))) (.mul (.const 0x1eeb8e80a4a1382bda0c063aa61dad5da57fa80865d0beeb2b9e69a07b5965f4) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨68⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨12⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨21⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨30⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨39⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨48⟩))) (.mul (.const 0x1eeb8e80a4a1382bda0c063aa61dad5da57fa80865d0beeb2b9e69a07b5965f4) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨70⟩) (.var ⟨71⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨72⟩))),
+  .assert (.add (.var ⟨70⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩))),
+  .assert (.add (.var ⟨71⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨69⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨73⟩) (.var ⟨74⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨75⟩))),
+  .assert (.add (.var ⟨73⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨72⟩))),
+  .assert (.add (.var ⟨74⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨12⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨21⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨30⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨39⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨48⟩))) (.mul (.const 0x1eeb8e80a4a1382bda0c063aa61dad5da57fa80865d0beeb2b9e69a07b5965f4) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨76⟩) (.var ⟨77⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨78⟩))),
+  .assert (.add (.var ⟨76⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨12⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨21⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨30⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨39⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨48⟩))) (.mul (.const 0x245c1c6d6f21bf7dd2abd1626514169678fa53bd900f6bd2cd854a9a965cb77b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨77⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨12⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨21⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨30⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨39⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨48⟩))) (.mul (.const 0x245c1c6d6f21bf7dd2abd1626514169678fa53bd900f6bd2cd854a9a965cb77b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨79⟩) (.var ⟨80⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨81⟩))),
+  .assert (.add (.var ⟨79⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨78⟩))),
+  .assert (.add (.var ⟨80⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨78⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨82⟩) (.var ⟨83⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨84⟩))),
+  .assert (.add (.var ⟨82⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨81⟩))),
+  .assert (.add (.var ⟨83⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨12⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨21⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨30⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨39⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨48⟩))) (.mul (.const 0x245c1c6d6f21bf7dd2abd1626514169678fa53bd900f6bd2cd854a9a965cb77b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨85⟩) (.var ⟨86⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨87⟩))),
+  .assert (.add (.var ⟨85⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨12⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨21⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨30⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨39⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨48⟩))) (.mul (.const 0x1a1492b84929e7080f0b6c8b1c46e9164beed12136837032670d44165d269c69) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨86⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨12⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨21⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨30⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨39⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨48⟩))) (.mul (.const 0x1a1492b84929e7080f0b6c8b1c46e9164beed12136837032670d44165d269c69) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨88⟩) (.var ⟨89⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩))),
+  .assert (.add (.var ⟨88⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨87⟩))),
+  .assert (.add (.var ⟨89⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨87⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨91⟩) (.var ⟨92⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨93⟩))),
+  .assert (.add (.var ⟨91⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨90⟩))),
+  .assert (.add (.var ⟨92⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨12⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨21⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨30⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨39⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨48⟩))) (.mul (.const 0x1a1492b84929e7080f0b6c8b1c46e9164beed12136837032670d44165d269c69) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨94⟩) (.var ⟨95⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨96⟩))),
+  .assert (.add (.var ⟨94⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨57⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨66⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨75⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨84⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨93⟩))) (.mul (.const 0x0b0a1a2b2762612d71d2f2d692c632218b5e47f3f0e38d638cfd365c313dbd48) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨95⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨57⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨66⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨75⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨84⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨93⟩))) (.mul (.const 0x0b0a1a2b2762612d71d2f2d692c632218b5e47f3f0e38d638cfd365c313dbd48) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨97⟩) (.var ⟨98⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨99⟩))),
+  .assert (.add (.var ⟨97⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨96⟩))),
+  .assert (.add (.var ⟨98⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨96⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨100⟩) (.var ⟨101⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨102⟩))),
+  .assert (.add (.var ⟨100⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨99⟩))),
+  .assert (.add (.var ⟨101⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨57⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨66⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨75⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨84⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨93⟩))) (.mul (.const 0x0b0a1a2b2762612d71d2f2d692c632218b5e47f3f0e38d638cfd365c313dbd48) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨103⟩) (.var ⟨104⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨105⟩))),
+  .assert (.add (.var ⟨103⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨57⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨66⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨75⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨84⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨93⟩))) (.mul (.const 0x05b20208ea0377139f322767d36fea854d28ccedb083ab8901f4054f822c5101) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨104⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨57⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨66⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨75⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨84⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨93⟩))) (.mul (.const 0x05b20208ea0377139f322767d36fea854d28ccedb083ab8901f4054f822c5101) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨106⟩) (.var ⟨107⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨108⟩))),
+  .assert (.add (.var ⟨106⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨105⟩))),
+  .assert (.add (.var ⟨107⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨105⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨109⟩) (.var ⟨110⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨111⟩))),
+  .assert (.add (.var ⟨109⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨108⟩))),
+  .assert (.add (.var ⟨110⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨57⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨66⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨75⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨84⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨93⟩))) (.mul (.const 0x05b20208ea0377139f322767d36fea854d28ccedb083ab8901f4054f822c5101) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨112⟩) (.var ⟨113⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨114⟩))),
+  .assert (.add (.var ⟨112⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨57⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨66⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨75⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨84⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨93⟩))) (.mul (.const 0x0710c608d8a48043e99dd94a0b1d22906b67dafb6bc5b95adf8c2f24ce81b0cd) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨113⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨57⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨66⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨75⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨84⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨93⟩))) (.mul (.const 0x0710c608d8a48043e99dd94a0b1d22906b67dafb6bc5b95adf8c2f24ce81b0cd) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨115⟩) (.var ⟨116⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨117⟩))),
+  .assert (.add (.var ⟨115⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨114⟩))),
+  .assert (.add (.var ⟨116⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨114⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨118⟩) (.var ⟨119⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨120⟩))),
+  .assert (.add (.var ⟨118⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨117⟩))),
+  .assert (.add (.var ⟨119⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨57⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨66⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨75⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨84⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨93⟩))) (.mul (.const 0x0710c608d8a48043e99dd94a0b1d22906b67dafb6bc5b95adf8c2f24ce81b0cd) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨121⟩) (.var ⟨122⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨123⟩))),
+  .assert (.add (.var ⟨121⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨57⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨66⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨75⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨84⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨93⟩))) (.mul (.const 0x09221b8a932a7820912b20bf5b919b7e0a22b3c9d2a1f0d9832ca03b0fc0ed9f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨122⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨57⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨66⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨75⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨84⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨93⟩))) (.mul (.const 0x09221b8a932a7820912b20bf5b919b7e0a22b3c9d2a1f0d9832ca03b0fc0ed9f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨124⟩) (.var ⟨125⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨126⟩))),
+  .assert (.add (.var ⟨124⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨123⟩))),
+  .assert (.add (.var ⟨125⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨123⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨127⟩) (.var ⟨128⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨129⟩))),
+  .assert (.add (.var ⟨127⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨126⟩))),
+  .assert (.add (.var ⟨128⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨57⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨66⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨75⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨84⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨93⟩))) (.mul (.const 0x09221b8a932a7820912b20bf5b919b7e0a22b3c9d2a1f0d9832ca03b0fc0ed9f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨130⟩) (.var ⟨131⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩))),
+  .assert (.add (.var ⟨130⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨57⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨66⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨75⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨84⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨93⟩))) (.mul (.const 0x114be59c06f561e8324e306a28896fb1dd3802773891f54f0b8243718ef12542) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨131⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨57⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨66⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨75⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨84⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨93⟩))) (.mul (.const 0x114be59c06f561e8324e306a28896fb1dd3802773891f54f0b8243718ef12542) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨133⟩) (.var ⟨134⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨135⟩))),
+  .assert (.add (.var ⟨133⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩))),
+  .assert (.add (.var ⟨134⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨132⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨136⟩) (.var ⟨137⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨138⟩))),
+  .assert (.add (.var ⟨136⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨135⟩))),
+  .assert (.add (.var ⟨137⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨57⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨66⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨75⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨84⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨93⟩))) (.mul (.const 0x114be59c06f561e8324e306a28896fb1dd3802773891f54f0b8243718ef12542) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨139⟩) (.var ⟨140⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨141⟩))),
+  .assert (.add (.var ⟨139⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨102⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨111⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨120⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨129⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨138⟩))) (.mul (.const 0x103454e35e0f4d690d8d1ab122b4c5c92e0512004f1af1e3140694c686cbeba9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨140⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨102⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨111⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨120⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨129⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨138⟩))) (.mul (.const 0x103454e35e0f4d690d8d1ab122b4c5c92e0512004f1af1e3140694c686cbeba9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨142⟩) (.var ⟨143⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨144⟩))),
+  .assert (.add (.var ⟨142⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨141⟩))),
+  .assert (.add (.var ⟨143⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨141⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨145⟩) (.var ⟨146⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨147⟩))),
+  .assert (.add (.var ⟨145⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨144⟩))),
+  .assert (.add (.var ⟨146⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨102⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨111⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨120⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨129⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨138⟩))) (.mul (.const 0x103454e35e0f4d690d8d1ab122b4c5c92e0512004f1af1e3140694c686cbeba9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨148⟩) (.var ⟨149⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨150⟩))),
+  .assert (.add (.var ⟨148⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨102⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨111⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨120⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨129⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨138⟩))) (.mul (.const 0x2d2d628ded9373dcfc3ad59072a51b9a2d6c8c470753066195b77ca3a821fe47) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨149⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨102⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨111⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨120⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨129⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨138⟩))) (.mul (.const 0x2d2d628ded9373dcfc3ad59072a51b9a2d6c8c470753066195b77ca3a821fe47) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨151⟩) (.var ⟨152⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩))),
+  .assert (.add (.var ⟨151⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨150⟩))),
+  .assert (.add (.var ⟨152⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨150⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨154⟩) (.var ⟨155⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨156⟩))),
+  .assert (.add (.var ⟨154⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨153⟩))),
+  .assert (.add (.var ⟨155⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨102⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨111⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨120⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨129⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨138⟩))) (.mul (.const 0x2d2d628ded9373dcfc3ad59072a51b9a2d6c8c470753066195b77ca3a821fe47) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨157⟩) (.var ⟨158⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨159⟩))),
+  .assert (.add (.var ⟨157⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨102⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨111⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨120⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨129⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨138⟩))) (.mul (.const 0x2b0bf337fd4454a5e38344e0e711b9ae2281545fd99ad3fe46da2e1e4b9c98ce) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨158⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨102⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨111⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨120⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨129⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨138⟩))) (.mul (.const 0x2b0bf337fd4454a5e38344e0e711b9ae2281545fd99ad3fe46da2e1e4b9c98ce) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨160⟩) (.var ⟨161⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨162⟩))),
+  .assert (.add (.var ⟨160⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨159⟩))),
+  .assert (.add (.var ⟨161⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨159⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨163⟩) (.var ⟨164⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨165⟩))),
+  .assert (.add (.var ⟨163⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨162⟩))),
+  .assert (.add (.var ⟨164⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨102⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨111⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨120⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨129⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨138⟩))) (.mul (.const 0x2b0bf337fd4454a5e38344e0e711b9ae2281545fd99ad3fe46da2e1e4b9c98ce) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨166⟩) (.var ⟨167⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨168⟩))),
+  .assert (.add (.var ⟨166⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨102⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨111⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨120⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨129⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨138⟩))) (.mul (.const 0x0575c431f72a40242de5963e47cc7dad1aced5d9a1c9de5ac7ab5c22fad3cd67) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨167⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨102⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨111⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨120⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨129⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨138⟩))) (.mul (.const 0x0575c431f72a40242de5963e47cc7dad1aced5d9a1c9de5ac7ab5c22fad3cd67) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨169⟩) (.var ⟨170⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨171⟩))),
+  .assert (.add (.var ⟨169⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨168⟩))),
+  .assert (.add (.var ⟨170⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨168⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨172⟩) (.var ⟨173⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨174⟩))),
+  .assert (.add (.var ⟨172⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨171⟩))),
+  .assert (.add (.var ⟨173⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨102⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨111⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨120⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨129⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨138⟩))) (.mul (.const 0x0575c431f72a40242de5963e47cc7dad1aced5d9a1c9de5ac7ab5c22fad3cd67) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨175⟩) (.var ⟨176⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨177⟩))),
+  .assert (.add (.var ⟨175⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨102⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨111⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨120⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨129⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨138⟩))) (.mul (.const 0x0ec8f181daf128e716d3d7726dcf8ecbdddadca0063dc726196baec197eb3612) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨176⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨102⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨111⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨120⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨129⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨138⟩))) (.mul (.const 0x0ec8f181daf128e716d3d7726dcf8ecbdddadca0063dc726196baec197eb3612) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨178⟩) (.var ⟨179⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨180⟩))),
+  .assert (.add (.var ⟨178⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨177⟩))),
+  .assert (.add (.var ⟨179⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨177⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨181⟩) (.var ⟨182⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨183⟩))),
+  .assert (.add (.var ⟨181⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨180⟩))),
+  .assert (.add (.var ⟨182⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨102⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨111⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨120⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨129⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨138⟩))) (.mul (.const 0x0ec8f181daf128e716d3d7726dcf8ecbdddadca0063dc726196baec197eb3612) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨184⟩) (.var ⟨185⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨186⟩))),
+  .assert (.add (.var ⟨184⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨147⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨156⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨165⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨174⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨183⟩))) (.mul (.const 0x33779259084a94462f31c2650f6affd717ea200e62d2349f07fba50dd64b4875) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨185⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨147⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨156⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨165⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨174⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨183⟩))) (.mul (.const 0x33779259084a94462f31c2650f6affd717ea200e62d2349f07fba50dd64b4875) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨187⟩) (.var ⟨188⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨189⟩))),
+  .assert (.add (.var ⟨187⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨186⟩))),
+  .assert (.add (.var ⟨188⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨186⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨190⟩) (.var ⟨191⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨192⟩))),
+  .assert (.add (.var ⟨190⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨189⟩))),
+  .assert (.add (.var ⟨191⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨147⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨156⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨165⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨174⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨183⟩))) (.mul (.const 0x33779259084a94462f31c2650f6affd717ea200e62d2349f07fba50dd64b4875) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨193⟩) (.var ⟨194⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩))),
+  .assert (.add (.var ⟨193⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨147⟩)) (.mul (.const 0x01f92a779df3ac70b961bf91865dac805c0739dabcd6e1f273b70ac7a91ca0ad) (.var ⟨156⟩))) (.mul (.const 0x1dc95917fb067a8a6fe42baea636b4cf42053a007bf8a5536a51802d7c95badf) (.var ⟨165⟩))) (.mul (.const 0x1c7dd6baa30ae7357ba8cb88872497e37141b481569da59467b1a6714606cfe2) (.var ⟨174⟩))) (.mul (.const 0x39fc3a66398a4966173ce524391cde2eab38c1e9a071fa1a0db8a590bff84dda) (.var ⟨183⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨192⟩))) (.mul (.const 0x0f18c786b92ecd2f9b548cd29a953562cbb30075462273fb57ff3ac3af2e56ab) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨194⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨147⟩)) (.mul (.const 0x01f92a779df3ac70b961bf91865dac805c0739dabcd6e1f273b70ac7a91ca0ad) (.var ⟨156⟩))) (.mul (.const 0x1dc95917fb067a8a6fe42baea636b4cf42053a007bf8a5536a51802d7c95badf) (.var ⟨165⟩))) (.mul (.const 0x1c7dd6baa30ae7357ba8cb88872497e37141b481569da59467b1a6714606cfe2) (.var ⟨174⟩))) (.mul (.const 0x39fc3a66398a4966173ce524391cde2eab38c1e9a071fa1a0db8a590bff84dda) (.var ⟨183⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨192⟩))) (.mul (.const 0x0f18c786b92ecd2f9b548cd29a953562cbb30075462273fb57ff3ac3af2e56ab) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨196⟩) (.var ⟨197⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨198⟩))),
+  .assert (.add (.var ⟨196⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩))),
+  .assert (.add (.var ⟨197⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨195⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨199⟩) (.var ⟨200⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨201⟩))),
+  .assert (.add (.var ⟨199⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨198⟩))),
+  .assert (.add (.var ⟨200⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨147⟩)) (.mul (.const 0x01f92a779df3ac70b961bf91865dac805c0739dabcd6e1f273b70ac7a91ca0ad) (.var ⟨156⟩))) (.mul (.const 0x1dc95917fb067a8a6fe42baea636b4cf42053a007bf8a5536a51802d7c95badf) (.var ⟨165⟩))) (.mul (.const 0x1c7dd6baa30ae7357ba8cb88872497e37141b481569da59467b1a6714606cfe2) (.var ⟨174⟩))) (.mul (.const 0x39fc3a66398a4966173ce524391cde2eab38c1e9a071fa1a0db8a590bff84dda) (.var ⟨183⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨192⟩))) (.mul (.const 0x0f18c786b92ecd2f9b548cd29a953562cbb30075462273fb57ff3ac3af2e56ab) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨202⟩) (.var ⟨203⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨204⟩))),
+  .assert (.add (.var ⟨202⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨147⟩)) (.mul (.const 0x3fc20cf71a7e5cc896648a4b10f2a9bcbcfd60ee495954d8510ca870d4e3b1a2) (.var ⟨156⟩))) (.mul (.const 0x22301c64eaa3080f68e3a40deb9c311f1c95d5680a06fe0a1047ea71bcd98996) (.var ⟨165⟩))) (.mul (.const 0x12690b5469ebeea57d597f1ebe3bc4aeb725e50d606bf8f2df805d743f199924) (.var ⟨174⟩))) (.mul (.const 0x2c7f648d24011f319971593fe87813871ced87aa47ef62f591f0e441bc68ddb2) (.var ⟨183⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨192⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨201⟩))) (.mul (.const 0x3bd303ad68547136dee287521bb7ea960b3720a9211e5522784f88f32e205a7f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨203⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨147⟩)) (.mul (.const 0x3fc20cf71a7e5cc896648a4b10f2a9bcbcfd60ee495954d8510ca870d4e3b1a2) (.var ⟨156⟩))) (.mul (.const 0x22301c64eaa3080f68e3a40deb9c311f1c95d5680a06fe0a1047ea71bcd98996) (.var ⟨165⟩))) (.mul (.const 0x12690b5469ebeea57d597f1ebe3bc4aeb725e50d606bf8f2df805d743f199924) (.var ⟨174⟩))) (.mul (.const 0x2c7f648d24011f319971593fe87813871ced87aa47ef62f591f0e441bc68ddb2) (.var ⟨183⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨192⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨201⟩))) (.mul (.const 0x3bd303ad68547136dee287521bb7ea960b3720a9211e5522784f88f32e205a7f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨205⟩) (.var ⟨206⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨207⟩))),
+  .assert (.add (.var ⟨205⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨204⟩))),
+  .assert (.add (.var ⟨206⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨204⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨208⟩) (.var ⟨209⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨210⟩))),
+  .assert (.add (.var ⟨208⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨207⟩))),
+  .assert (.add (.var ⟨209⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨147⟩)) (.mul (.const 0x3fc20cf71a7e5cc896648a4b10f2a9bcbcfd60ee495954d8510ca870d4e3b1a2) (.var ⟨156⟩))) (.mul (.const 0x22301c64eaa3080f68e3a40deb9c311f1c95d5680a06fe0a1047ea71bcd98996) (.var ⟨165⟩))) (.mul (.const 0x12690b5469ebeea57d597f1ebe3bc4aeb725e50d606bf8f2df805d743f199924) (.var ⟨174⟩))) (.mul (.const 0x2c7f648d24011f319971593fe87813871ced87aa47ef62f591f0e441bc68ddb2) (.var ⟨183⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨192⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨201⟩))) (.mul (.const 0x3bd303ad68547136dee287521bb7ea960b3720a9211e5522784f88f32e205a7f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨211⟩) (.var ⟨212⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨213⟩))),
+  .assert (.add (.var ⟨211⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨147⟩)) (.mul (.const 0x16c4602336052dd45876fca4738ee1ec9104c3b17a0b83a25d95436acdc17af9) (.var ⟨156⟩))) (.mul (.const 0x11fba5f451dee4e7ff23899878c3a94b312fe460a1ee671316c059cbb9a3f852) (.var ⟨165⟩))) (.mul (.const 0x37e8d22b8ce8f072c1439e0a59354c630e4d823564523a2f9924d629ac8a827c) (.var ⟨174⟩))) (.mul (.const 0x065e22bc04f76a8b033a7c5e2f030df249b38a4b9356a1d8a3084e97b7bfca69) (.var ⟨183⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨192⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨201⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨210⟩))) (.mul (.const 0x3ece4b38306668eb5b9fa1463197e255665c712236439622fde3313fc4e24e51) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨212⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨147⟩)) (.mul (.const 0x16c4602336052dd45876fca4738ee1ec9104c3b17a0b83a25d95436acdc17af9) (.var ⟨156⟩))) (.mul (.const 0x11fba5f451dee4e7ff23899878c3a94b312fe460a1ee671316c059cbb9a3f852) (.var ⟨165⟩))) (.mul (.const 0x37e8d22b8ce8f072c1439e0a59354c630e4d823564523a2f9924d629ac8a827c) (.var ⟨174⟩))) (.mul (.const 0x065e22bc04f76a8b033a7c5e2f030df249b38a4b9356a1d8a3084e97b7bfca69) (.var ⟨183⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨192⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨201⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨210⟩))) (.mul (.const 0x3ece4b38306668eb5b9fa1463197e255665c712236439622fde3313fc4e24e51) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨214⟩) (.var ⟨215⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩))),
+  .assert (.add (.var ⟨214⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨213⟩))),
+  .assert (.add (.var ⟨215⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨213⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨217⟩) (.var ⟨218⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨219⟩))),
+  .assert (.add (.var ⟨217⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨216⟩))),
+  .assert (.add (.var ⟨218⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨147⟩)) (.mul (.const 0x16c4602336052dd45876fca4738ee1ec9104c3b17a0b83a25d95436acdc17af9) (.var ⟨156⟩))) (.mul (.const 0x11fba5f451dee4e7ff23899878c3a94b312fe460a1ee671316c059cbb9a3f852) (.var ⟨165⟩))) (.mul (.const 0x37e8d22b8ce8f072c1439e0a59354c630e4d823564523a2f9924d629ac8a827c) (.var ⟨174⟩))) (.mul (.const 0x065e22bc04f76a8b033a7c5e2f030df249b38a4b9356a1d8a3084e97b7bfca69) (.var ⟨183⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨192⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨201⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨210⟩))) (.mul (.const 0x3ece4b38306668eb5b9fa1463197e255665c712236439622fde3313fc4e24e51) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨220⟩) (.var ⟨221⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨222⟩))),
+  .assert (.add (.var ⟨220⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨147⟩)) (.mul (.const 0x29915e24c01976db8aacad0c43998e0b1bfb66a3af2bee3ea1c77e4f3227c0af) (.var ⟨156⟩))) (.mul (.const 0x1481f1d134fe72e8e43c578f723e71aee67a5fd814809b7d9f701e9dab449e7b) (.var ⟨165⟩))) (.mul (.const 0x2ce7158cf7eeb40f2a97379d29ef128cbc349542d7118678330cedf5439aceda) (.var ⟨174⟩))) (.mul (.const 0x2d2443616efd115179ba0db07ac1d39eed8a84c13ab69780f12563f46161c5d7) (.var ⟨183⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨192⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨201⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨210⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨219⟩))) (.mul (.const 0x29e1cb790204254af79a7fd0bd2fcb1f5f9aa93fedba371fd8ee3a777d82d9a9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨221⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨147⟩)) (.mul (.const 0x29915e24c01976db8aacad0c43998e0b1bfb66a3af2bee3ea1c77e4f3227c0af) (.var ⟨156⟩))) (.mul (.const 0x1481f1d134fe72e8e43c578f723e71aee67a5fd814809b7d9f701e9dab449e7b) (.var ⟨165⟩))) (.mul (.const 0x2ce7158cf7eeb40f2a97379d29ef128cbc349542d7118678330cedf5439aceda) (.var ⟨174⟩))) (.mul (.const 0x2d2443616efd115179ba0db07ac1d39eed8a84c13ab69780f12563f46161c5d7) (.var ⟨183⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨192⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨201⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨210⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨219⟩))) (.mul (.const 0x29e1cb790204254af79a7fd0bd2fcb1f5f9aa93fedba371fd8ee3a777d82d9a9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨223⟩) (.var ⟨224⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨225⟩))),
+  .assert (.add (.var ⟨223⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨222⟩))),
+  .assert (.add (.var ⟨224⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨222⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨226⟩) (.var ⟨227⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨228⟩))),
+  .assert (.add (.var ⟨226⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨225⟩))),
+  .assert (.add (.var ⟨227⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨147⟩)) (.mul (.const 0x29915e24c01976db8aacad0c43998e0b1bfb66a3af2bee3ea1c77e4f3227c0af) (.var ⟨156⟩))) (.mul (.const 0x1481f1d134fe72e8e43c578f723e71aee67a5fd814809b7d9f701e9dab449e7b) (.var ⟨165⟩))) (.mul (.const 0x2ce7158cf7eeb40f2a97379d29ef128cbc349542d7118678330cedf5439aceda) (.var ⟨174⟩))) (.mul (.const 0x2d2443616efd115179ba0db07ac1d39eed8a84c13ab69780f12563f46161c5d7) (.var ⟨183⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨192⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨201⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨210⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨219⟩))) (.mul (.const 0x29e1cb790204254af79a7fd0bd2fcb1f5f9aa93fedba371fd8ee3a777d82d9a9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨229⟩) (.var ⟨230⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨231⟩))),
+  .assert (.add (.var ⟨229⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨147⟩)) (.mul (.const 0x05a3360d5b63fcc3ebadb7c651e329b7da56cc679c2c9f0350fb47ed04bfd354) (.var ⟨156⟩))) (.mul (.const 0x3a782be27e45e290f0e43bb1f1230f90d5ac25a30d23c2bdb37448e1d19ff921) (.var ⟨165⟩))) (.mul (.const 0x0825b35c21a240884c08a835ff858edbb239bbf90a202809e06fd10c1783e4c3) (.var ⟨174⟩))) (.mul (.const 0x0389ad4789866c55964912712836e9a5660d474dcd30621905ecaf9fe092c11c) (.var ⟨183⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨192⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨201⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨210⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨219⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨228⟩))) (.mul (.const 0x17f9b7e3c7323191aa63578042e371fb6d3094b1bd5b0ce5c5971a9b67f61261) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨230⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨147⟩)) (.mul (.const 0x05a3360d5b63fcc3ebadb7c651e329b7da56cc679c2c9f0350fb47ed04bfd354) (.var ⟨156⟩))) (.mul (.const 0x3a782be27e45e290f0e43bb1f1230f90d5ac25a30d23c2bdb37448e1d19ff921) (.var ⟨165⟩))) (.mul (.const 0x0825b35c21a240884c08a835ff858edbb239bbf90a202809e06fd10c1783e4c3) (.var ⟨174⟩))) (.mul (.const 0x0389ad4789866c55964912712836e9a5660d474dcd30621905ecaf9fe092c11c) (.var ⟨183⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨192⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨201⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨210⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨219⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨228⟩))) (.mul (.const 0x17f9b7e3c7323191aa63578042e371fb6d3094b1bd5b0ce5c5971a9b67f61261) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨232⟩) (.var ⟨233⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨234⟩))),
+  .assert (.add (.var ⟨232⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨231⟩))),
+  .assert (.add (.var ⟨233⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨231⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨235⟩) (.var ⟨236⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨237⟩))),
+  .assert (.add (.var ⟨235⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨234⟩))),
+  .assert (.add (.var ⟨236⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨147⟩)) (.mul (.const 0x05a3360d5b63fcc3ebadb7c651e329b7da56cc679c2c9f0350fb47ed04bfd354) (.var ⟨156⟩))) (.mul (.const 0x3a782be27e45e290f0e43bb1f1230f90d5ac25a30d23c2bdb37448e1d19ff921) (.var ⟨165⟩))) (.mul (.const 0x0825b35c21a240884c08a835ff858edbb239bbf90a202809e06fd10c1783e4c3) (.var ⟨174⟩))) (.mul (.const 0x0389ad4789866c55964912712836e9a5660d474dcd30621905ecaf9fe092c11c) (.var ⟨183⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨192⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨201⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨210⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨219⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨228⟩))) (.mul (.const 0x17f9b7e3c7323191aa63578042e371fb6d3094b1bd5b0ce5c5971a9b67f61261) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨238⟩) (.var ⟨239⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨240⟩))),
+  .assert (.add (.var ⟨238⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨147⟩)) (.mul (.const 0x0cefdb59b3dfdf0faae73fb44e42306209df4339c2815b5d5c1dc57e748bccb1) (.var ⟨156⟩))) (.mul (.const 0x10c744b02b48011d928c4fca013490824a2c5b29202fc520e2d8e496dcb1358c) (.var ⟨165⟩))) (.mul (.const 0x32911e907ac96ae69a6599648e5cf0e1082dc45f901d7d35587b2e2d80602975) (.var ⟨174⟩))) (.mul (.const 0x3fdb95b352b8156adb4fcbfbf9d65fd3ccd565795b77e44ea39367fed3bc3af4) (.var ⟨183⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨192⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨201⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨210⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨219⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨228⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨237⟩))) (.mul (.const 0x0969b0dd95079454906aa968495b6cd3d68b29b449091263f67c0cad1d9b3cf7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨239⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨147⟩)) (.mul (.const 0x0cefdb59b3dfdf0faae73fb44e42306209df4339c2815b5d5c1dc57e748bccb1) (.var ⟨156⟩))) (.mul (.const 0x10c744b02b48011d928c4fca013490824a2c5b29202fc520e2d8e496dcb1358c) (.var ⟨165⟩))) (.mul (.const 0x32911e907ac96ae69a6599648e5cf0e1082dc45f901d7d35587b2e2d80602975) (.var ⟨174⟩))) (.mul (.const 0x3fdb95b352b8156adb4fcbfbf9d65fd3ccd565795b77e44ea39367fed3bc3af4) (.var ⟨183⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨192⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨201⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨210⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨219⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨228⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨237⟩))) (.mul (.const 0x0969b0dd95079454906aa968495b6cd3d68b29b449091263f67c0cad1d9b3cf7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨241⟩) (.var ⟨242⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨243⟩))),
+  .assert (.add (.var ⟨241⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨240⟩))),
+  .assert (.add (.var ⟨242⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨240⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨244⟩) (.var ⟨245⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨246⟩))),
+  .assert (.add (.var ⟨244⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨243⟩))),
+  .assert (.add (.var ⟨245⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨147⟩)) (.mul (.const 0x0cefdb59b3dfdf0faae73fb44e42306209df4339c2815b5d5c1dc57e748bccb1) (.var ⟨156⟩))) (.mul (.const 0x10c744b02b48011d928c4fca013490824a2c5b29202fc520e2d8e496dcb1358c) (.var ⟨165⟩))) (.mul (.const 0x32911e907ac96ae69a6599648e5cf0e1082dc45f901d7d35587b2e2d80602975) (.var ⟨174⟩))) (.mul (.const 0x3fdb95b352b8156adb4fcbfbf9d65fd3ccd565795b77e44ea39367fed3bc3af4) (.var ⟨183⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨192⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨201⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨210⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨219⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨228⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨237⟩))) (.mul (.const 0x0969b0dd95079454906aa968495b6cd3d68b29b449091263f67c0cad1d9b3cf7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨247⟩) (.var ⟨248⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨249⟩))),
+  .assert (.add (.var ⟨247⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨147⟩)) (.mul (.const 0x0aa2da8a9864863b5bdd12d32a6b93ca2bf30dc82b5f03f346b2ec38429f7e7e) (.var ⟨156⟩))) (.mul (.const 0x359a577821440b0312b22d4060b5cb411cec359e57d4f41168b8c3ed6b01d8ec) (.var ⟨165⟩))) (.mul (.const 0x1b6b783e0ec3d9bed7fd1da8f934a82aa7fdd83083300da7a8fe70155c5a993a) (.var ⟨174⟩))) (.mul (.const 0x3f96f9253b3c5813faad7714ea12a6a91142527ee15a859accf273dd6901a0e2) (.var ⟨183⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨192⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨201⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨210⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨219⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨228⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨237⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨246⟩))) (.mul (.const 0x217912e6b659880d16ebf23bffbabfa9a066cc8f2c55297fe61257cf1266edef) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨248⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨147⟩)) (.mul (.const 0x0aa2da8a9864863b5bdd12d32a6b93ca2bf30dc82b5f03f346b2ec38429f7e7e) (.var ⟨156⟩))) (.mul (.const 0x359a577821440b0312b22d4060b5cb411cec359e57d4f41168b8c3ed6b01d8ec) (.var ⟨165⟩))) (.mul (.const 0x1b6b783e0ec3d9bed7fd1da8f934a82aa7fdd83083300da7a8fe70155c5a993a) (.var ⟨174⟩))) (.mul (.const 0x3f96f9253b3c5813faad7714ea12a6a91142527ee15a859accf273dd6901a0e2) (.var ⟨183⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨192⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨201⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨210⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨219⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨228⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨237⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨246⟩))) (.mul (.const 0x217912e6b659880d16ebf23bffbabfa9a066cc8f2c55297fe61257cf1266edef) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨250⟩) (.var ⟨251⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨252⟩))),
+  .assert (.add (.var ⟨250⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨249⟩))),
+  .assert (.add (.var ⟨251⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨249⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨253⟩) (.var ⟨254⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨255⟩))),
+  .assert (.add (.var ⟨253⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨252⟩))),
+  .assert (.add (.var ⟨254⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨147⟩)) (.mul (.const 0x0aa2da8a9864863b5bdd12d32a6b93ca2bf30dc82b5f03f346b2ec38429f7e7e) (.var ⟨156⟩))) (.mul (.const 0x359a577821440b0312b22d4060b5cb411cec359e57d4f41168b8c3ed6b01d8ec) (.var ⟨165⟩))) (.mul (.const 0x1b6b783e0ec3d9bed7fd1da8f934a82aa7fdd83083300da7a8fe70155c5a993a) (.var ⟨174⟩))) (.mul (.const 0x3f96f9253b3c5813faad7714ea12a6a91142527ee15a859accf273dd6901a0e2) (.var ⟨183⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨192⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨201⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨210⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨219⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨228⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨237⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨246⟩))) (.mul (.const 0x217912e6b659880d16ebf23bffbabfa9a066cc8f2c55297fe61257cf1266edef) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨256⟩) (.var ⟨257⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩))),
+  .assert (.add (.var ⟨256⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨147⟩)) (.mul (.const 0x2feba7002f57bda29f394690f93a9cd5352c412745631fb77cd45b6621fbbb29) (.var ⟨156⟩))) (.mul (.const 0x13a1a0b5d3a9a50d4cdaa3b9c33557fd724c118136858dee279667181a93923f) (.var ⟨165⟩))) (.mul (.const 0x0e1b0e80870e31ac0e267e754c5a4463f9b97186b270ab87a02c7510d2d0dec7) (.var ⟨174⟩))) (.mul (.const 0x28ba2da9c28d562fce330c218f22cf932b3db62f6fbdeccd3a08a81498200ec1) (.var ⟨183⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨192⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨201⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨210⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨219⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨228⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨237⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨246⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨255⟩))) (.mul (.const 0x3af701417315f4bed0b9f1415e7497c6e55c70b49cc2b75c7dccb44458cad9f9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨257⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨147⟩)) (.mul (.const 0x2feba7002f57bda29f394690f93a9cd5352c412745631fb77cd45b6621fbbb29) (.var ⟨156⟩))) (.mul (.const 0x13a1a0b5d3a9a50d4cdaa3b9c33557fd724c118136858dee279667181a93923f) (.var ⟨165⟩))) (.mul (.const 0x0e1b0e80870e31ac0e267e754c5a4463f9b97186b270ab87a02c7510d2d0dec7) (.var ⟨174⟩))) (.mul (.const 0x28ba2da9c28d562fce330c218f22cf932b3db62f6fbdeccd3a08a81498200ec1) (.var ⟨183⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨192⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨201⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨210⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨219⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨228⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨237⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨246⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨255⟩))) (.mul (.const 0x3af701417315f4bed0b9f1415e7497c6e55c70b49cc2b75c7dccb44458cad9f9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨259⟩) (.var ⟨260⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨261⟩))),
+  .assert (.add (.var ⟨259⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩))),
+  .assert (.add (.var ⟨260⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨258⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨262⟩) (.var ⟨263⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨264⟩))),
+  .assert (.add (.var ⟨262⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨261⟩))),
+  .assert (.add (.var ⟨263⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨147⟩)) (.mul (.const 0x2feba7002f57bda29f394690f93a9cd5352c412745631fb77cd45b6621fbbb29) (.var ⟨156⟩))) (.mul (.const 0x13a1a0b5d3a9a50d4cdaa3b9c33557fd724c118136858dee279667181a93923f) (.var ⟨165⟩))) (.mul (.const 0x0e1b0e80870e31ac0e267e754c5a4463f9b97186b270ab87a02c7510d2d0dec7) (.var ⟨174⟩))) (.mul (.const 0x28ba2da9c28d562fce330c218f22cf932b3db62f6fbdeccd3a08a81498200ec1) (.var ⟨183⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨192⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨201⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨210⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨219⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨228⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨237⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨246⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨255⟩))) (.mul (.const 0x3af701417315f4bed0b9f1415e7497c6e55c70b49cc2b75c7dccb44458cad9f9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨265⟩) (.var ⟨266⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨267⟩))),
+  .assert (.add (.var ⟨265⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨147⟩)) (.mul (.const 0x32f4f147a0433d4bfaa0420bec70d62c655bcf02d704a53c2d8271b9cdf608ed) (.var ⟨156⟩))) (.mul (.const 0x107dbd4daf43d526198feeec3f2b51cf1651b459e4a37adeecfe6a03d700d702) (.var ⟨165⟩))) (.mul (.const 0x265a9cf7a48cf9fd7b16c2d786e91951843f8dd18c89b4df4105f6844a939828) (.var ⟨174⟩))) (.mul (.const 0x18e03ee41335214a948acc06d14eeb30568ddcbe3ec3f6f13e0634cb442f66a3) (.var ⟨183⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨192⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨201⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨210⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨219⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨228⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨237⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨246⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨255⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨264⟩))) (.mul (.const 0x2bbcbfcc59045d791f33415645ce44ceaabffe0ef08ff26c4127289c097295b3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨266⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨147⟩)) (.mul (.const 0x32f4f147a0433d4bfaa0420bec70d62c655bcf02d704a53c2d8271b9cdf608ed) (.var ⟨156⟩))) (.mul (.const 0x107dbd4daf43d526198feeec3f2b51cf1651b459e4a37adeecfe6a03d700d702) (.var ⟨165⟩))) (.mul (.const 0x265a9cf7a48cf9fd7b16c2d786e91951843f8dd18c89b4df4105f6844a939828) (.var ⟨174⟩))) (.mul (.const 0x18e03ee41335214a948acc06d14eeb30568ddcbe3ec3f6f13e0634cb442f66a3) (.var ⟨183⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨192⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨201⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨210⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨219⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨228⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨237⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨246⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨255⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨264⟩))) (.mul (.const 0x2bbcbfcc59045d791f33415645ce44ceaabffe0ef08ff26c4127289c097295b3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨268⟩) (.var ⟨269⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨270⟩))),
+  .assert (.add (.var ⟨268⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨267⟩))),
+  .assert (.add (.var ⟨269⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨267⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨271⟩) (.var ⟨272⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨273⟩))),
+  .assert (.add (.var ⟨271⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨270⟩))),
+  .assert (.add (.var ⟨272⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨147⟩)) (.mul (.const 0x32f4f147a0433d4bfaa0420bec70d62c655bcf02d704a53c2d8271b9cdf608ed) (.var ⟨156⟩))) (.mul (.const 0x107dbd4daf43d526198feeec3f2b51cf1651b459e4a37adeecfe6a03d700d702) (.var ⟨165⟩))) (.mul (.const 0x265a9cf7a48cf9fd7b16c2d786e91951843f8dd18c89b4df4105f6844a939828) (.var ⟨174⟩))) (.mul (.const 0x18e03ee41335214a948acc06d14eeb30568ddcbe3ec3f6f13e0634cb442f66a3) (.var ⟨183⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨192⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨201⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨210⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨219⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨228⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨237⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨246⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨255⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨264⟩))) (.mul (.const 0x2bbcbfcc59045d791f33415645ce44ceaabffe0ef08ff26c4127289c097295b3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨274⟩) (.var ⟨275⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨276⟩))),
+  .assert (.add (.var ⟨274⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨147⟩)) (.mul (.const 0x38005ad12eb7a9c56ff4d42f896abe26904190033c66000a40fbb058ac423e92) (.var ⟨156⟩))) (.mul (.const 0x1777264ba10d8c8aa48f66c7517099434dbfada8a254d3114b8a5fe63461ff0c) (.var ⟨165⟩))) (.mul (.const 0x147e116018b72222ba19ad2d263f0dd32f3a91d1842565dde20b43303e8228d9) (.var ⟨174⟩))) (.mul (.const 0x041e5d561fe0e318006427502974d184c644e2c22a925452cf4e39118229b903) (.var ⟨183⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨192⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨201⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨210⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨219⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨228⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨237⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨246⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨255⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨264⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨273⟩))) (.mul (.const 0x0100e6b4c81e91c059097367ad53a5bd0aeb2687b82f9e8bad75a648f014aa2d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨275⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨147⟩)) (.mul (.const 0x38005ad12eb7a9c56ff4d42f896abe26904190033c66000a40fbb058ac423e92) (.var ⟨156⟩))) (.mul (.const 0x1777264ba10d8c8aa48f66c7517099434dbfada8a254d3114b8a5fe63461ff0c) (.var ⟨165⟩))) (.mul (.const 0x147e116018b72222ba19ad2d263f0dd32f3a91d1842565dde20b43303e8228d9) (.var ⟨174⟩))) (.mul (.const 0x041e5d561fe0e318006427502974d184c644e2c22a925452cf4e39118229b903) (.var ⟨183⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨192⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨201⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨210⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨219⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨228⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨237⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨246⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨255⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨264⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨273⟩))) (.mul (.const 0x0100e6b4c81e91c059097367ad53a5bd0aeb2687b82f9e8bad75a648f014aa2d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨277⟩) (.var ⟨278⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩))),
+  .assert (.add (.var ⟨277⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨276⟩))),
+  .assert (.add (.var ⟨278⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨276⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨280⟩) (.var ⟨281⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨282⟩))),
+  .assert (.add (.var ⟨280⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨279⟩))),
+  .assert (.add (.var ⟨281⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨147⟩)) (.mul (.const 0x38005ad12eb7a9c56ff4d42f896abe26904190033c66000a40fbb058ac423e92) (.var ⟨156⟩))) (.mul (.const 0x1777264ba10d8c8aa48f66c7517099434dbfada8a254d3114b8a5fe63461ff0c) (.var ⟨165⟩))) (.mul (.const 0x147e116018b72222ba19ad2d263f0dd32f3a91d1842565dde20b43303e8228d9) (.var ⟨174⟩))) (.mul (.const 0x041e5d561fe0e318006427502974d184c644e2c22a925452cf4e39118229b903) (.var ⟨183⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨192⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨201⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨210⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨219⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨228⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨237⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨246⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨255⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨264⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨273⟩))) (.mul (.const 0x0100e6b4c81e91c059097367ad53a5bd0aeb2687b82f9e8bad75a648f014aa2d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨283⟩) (.var ⟨284⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨285⟩))),
+  .assert (.add (.var ⟨283⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨147⟩)) (.mul (.const 0x0bf22c866cc4c2fe9aaca2b19073a7b17d512fd320f6ec309c3a4e3fd054c035) (.var ⟨156⟩))) (.mul (.const 0x26d9bb15911d1dfc7e617ae414648b306d633d5cbc0662661f1577e521561fa4) (.var ⟨165⟩))) (.mul (.const 0x2bbe632d0cebb02292ff1b2ccea239db559a538845ad7e7afe4e303f75a38335) (.var ⟨174⟩))) (.mul (.const 0x258c7b3ceb1701502486ff77d54f08dc0999b5330aa5b8e4c95820b30f3f8e14) (.var ⟨183⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨192⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨201⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨210⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨219⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨228⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨237⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨246⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨255⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨264⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨273⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨282⟩))) (.mul (.const 0x08ad07d5bfdebab68fe109770fb6cce921b85ed6df1fa5d913e1e9a4be0a08d2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨284⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨147⟩)) (.mul (.const 0x0bf22c866cc4c2fe9aaca2b19073a7b17d512fd320f6ec309c3a4e3fd054c035) (.var ⟨156⟩))) (.mul (.const 0x26d9bb15911d1dfc7e617ae414648b306d633d5cbc0662661f1577e521561fa4) (.var ⟨165⟩))) (.mul (.const 0x2bbe632d0cebb02292ff1b2ccea239db559a538845ad7e7afe4e303f75a38335) (.var ⟨174⟩))) (.mul (.const 0x258c7b3ceb1701502486ff77d54f08dc0999b5330aa5b8e4c95820b30f3f8e14) (.var ⟨183⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨192⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨201⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨210⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨219⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨228⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨237⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨246⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨255⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨264⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨273⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨282⟩))) (.mul (.const 0x08ad07d5bfdebab68fe109770fb6cce921b85ed6df1fa5d913e1e9a4be0a08d2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨286⟩) (.var ⟨287⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨288⟩))),
+  .assert (.add (.var ⟨286⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨285⟩))),
+  .assert (.add (.var ⟨287⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨285⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨289⟩) (.var ⟨290⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨291⟩))),
+  .assert (.add (.var ⟨289⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨288⟩))),
+  .assert (.add (.var ⟨290⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨147⟩)) (.mul (.const 0x0bf22c866cc4c2fe9aaca2b19073a7b17d512fd320f6ec309c3a4e3fd054c035) (.var ⟨156⟩))) (.mul (.const 0x26d9bb15911d1dfc7e617ae414648b306d633d5cbc0662661f1577e521561fa4) (.var ⟨165⟩))) (.mul (.const 0x2bbe632d0cebb02292ff1b2ccea239db559a538845ad7e7afe4e303f75a38335) (.var ⟨174⟩))) (.mul (.const 0x258c7b3ceb1701502486ff77d54f08dc0999b5330aa5b8e4c95820b30f3f8e14) (.var ⟨183⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨192⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨201⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨210⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨219⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨228⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨237⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨246⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨255⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨264⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨273⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨282⟩))) (.mul (.const 0x08ad07d5bfdebab68fe109770fb6cce921b85ed6df1fa5d913e1e9a4be0a08d2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨292⟩) (.var ⟨293⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨294⟩))),
+  .assert (.add (.var ⟨292⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨147⟩)) (.mul (.const 0x283d7ad6c60bf100170146f109079a06174f08bb3fb6e215632faf4e61b72e4e) (.var ⟨156⟩))) (.mul (.const 0x305fc24f4e3497b9b1f41a55a538b4f864c433e82c24ae30fbd5fd6d8eeeef2a) (.var ⟨165⟩))) (.mul (.const 0x3ac529579350b23463ad48d76e665889a493bb2a80b93c868b1b6acdd9ee409a) (.var ⟨174⟩))) (.mul (.const 0x2a8c6e59073d8fba0d35c7002d8a1207f2229e3addb859bbc318b6359b5d0f37) (.var ⟨183⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨192⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨201⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨210⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨219⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨228⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨237⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨246⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨255⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨264⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨273⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨282⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨291⟩))) (.mul (.const 0x330aa62216abe43087aaf7683bb549e1021db679e5e43fce166c84b5444a797c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨293⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨147⟩)) (.mul (.const 0x283d7ad6c60bf100170146f109079a06174f08bb3fb6e215632faf4e61b72e4e) (.var ⟨156⟩))) (.mul (.const 0x305fc24f4e3497b9b1f41a55a538b4f864c433e82c24ae30fbd5fd6d8eeeef2a) (.var ⟨165⟩))) (.mul (.const 0x3ac529579350b23463ad48d76e665889a493bb2a80b93c868b1b6acdd9ee409a) (.var ⟨174⟩))) (.mul (.const 0x2a8c6e59073d8fba0d35c7002d8a1207f2229e3addb859bbc318b6359b5d0f37) (.var ⟨183⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨192⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨201⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨210⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨219⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨228⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨237⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨246⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨255⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨264⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨273⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨282⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨291⟩))) (.mul (.const 0x330aa62216abe43087aaf7683bb549e1021db679e5e43fce166c84b5444a797c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨295⟩) (.var ⟨296⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨297⟩))),
+  .assert (.add (.var ⟨295⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨294⟩))),
+  .assert (.add (.var ⟨296⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨294⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨298⟩) (.var ⟨299⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨300⟩))),
+  .assert (.add (.var ⟨298⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨297⟩))),
+  .assert (.add (.var ⟨299⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨147⟩)) (.mul (.const 0x283d7ad6c60bf100170146f109079a06174f08bb3fb6e215632faf4e61b72e4e) (.var ⟨156⟩))) (.mul (.const 0x305fc24f4e3497b9b1f41a55a538b4f864c433e82c24ae30fbd5fd6d8eeeef2a) (.var ⟨165⟩))) (.mul (.const 0x3ac529579350b23463ad48d76e665889a493bb2a80b93c868b1b6acdd9ee409a) (.var ⟨174⟩))) (.mul (.const 0x2a8c6e59073d8fba0d35c7002d8a1207f2229e3addb859bbc318b6359b5d0f37) (.var ⟨183⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨192⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨201⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨210⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨219⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨228⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨237⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨246⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨255⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨264⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨273⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨282⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨291⟩))) (.mul (.const 0x330aa62216abe43087aaf7683bb549e1021db679e5e43fce166c84b5444a797c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨301⟩) (.var ⟨302⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨303⟩))),
+  .assert (.add (.var ⟨301⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨147⟩)) (.mul (.const 0x21e962bd2d55bf096206ae6066207e0ddc3c2cbb2046e073ecd24ed4b436c235) (.var ⟨156⟩))) (.mul (.const 0x02cecea298481bb8620790fdafe42577557505bd8f9da346730ebb20a249cc52) (.var ⟨165⟩))) (.mul (.const 0x256ea383daa487b746acbc029a5bbf29a4f00737b6b53ff836a3bf158d715826) (.var ⟨174⟩))) (.mul (.const 0x3b98beb9cb2e88ef0b630cc5032df613613f40e1537b8312cd5c26f2410c562f) (.var ⟨183⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨192⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨201⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨210⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨219⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨228⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨237⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨246⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨255⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨264⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨273⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨282⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨291⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨300⟩))) (.mul (.const 0x3136e61bc7518d1c24dee38413e273c52991a46f1e9b87e60ebb4a2af1a16f8a) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨302⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨147⟩)) (.mul (.const 0x21e962bd2d55bf096206ae6066207e0ddc3c2cbb2046e073ecd24ed4b436c235) (.var ⟨156⟩))) (.mul (.const 0x02cecea298481bb8620790fdafe42577557505bd8f9da346730ebb20a249cc52) (.var ⟨165⟩))) (.mul (.const 0x256ea383daa487b746acbc029a5bbf29a4f00737b6b53ff836a3bf158d715826) (.var ⟨174⟩))) (.mul (.const 0x3b98beb9cb2e88ef0b630cc5032df613613f40e1537b8312cd5c26f2410c562f) (.var ⟨183⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨192⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨201⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨210⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨219⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨228⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨237⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨246⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨255⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨264⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨273⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨282⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨291⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨300⟩))) (.mul (.const 0x3136e61bc7518d1c24dee38413e273c52991a46f1e9b87e60ebb4a2af1a16f8a) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨304⟩) (.var ⟨305⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨306⟩))),
+  .assert (.add (.var ⟨304⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨303⟩))),
+  .assert (.add (.var ⟨305⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨303⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨307⟩) (.var ⟨308⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨309⟩))),
+  .assert (.add (.var ⟨307⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨306⟩))),
+  .assert (.add (.var ⟨308⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨147⟩)) (.mul (.const 0x21e962bd2d55bf096206ae6066207e0ddc3c2cbb2046e073ecd24ed4b436c235) (.var ⟨156⟩))) (.mul (.const 0x02cecea298481bb8620790fdafe42577557505bd8f9da346730ebb20a249cc52) (.var ⟨165⟩))) (.mul (.const 0x256ea383daa487b746acbc029a5bbf29a4f00737b6b53ff836a3bf158d715826) (.var ⟨174⟩))) (.mul (.const 0x3b98beb9cb2e88ef0b630cc5032df613613f40e1537b8312cd5c26f2410c562f) (.var ⟨183⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨192⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨201⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨210⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨219⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨228⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨237⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨246⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨255⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨264⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨273⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨282⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨291⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨300⟩))) (.mul (.const 0x3136e61bc7518d1c24dee38413e273c52991a46f1e9b87e60ebb4a2af1a16f8a) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨310⟩) (.var ⟨311⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨312⟩))),
+  .assert (.add (.var ⟨310⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨147⟩)) (.mul (.const 0x2c1ad3c82d71b31ef6a0b9e8119279336c0933270b00ca99628345586626102e) (.var ⟨156⟩))) (.mul (.const 0x3517225e8241ae0140e9000ed5f76492429d5701a11e579c794acc22106e7df6) (.var ⟨165⟩))) (.mul (.const 0x34554d640b366b9deff6b38067826fb645a026886d3b7a3a23e4e1258b073939) (.var ⟨174⟩))) (.mul (.const 0x39a64df497b40268d708846fbe4c56a22f7b70cf43b5dba616cf60fe3cecaa29) (.var ⟨183⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨192⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨201⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨210⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨219⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨228⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨237⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨246⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨255⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨264⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨273⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨282⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨291⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨300⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨309⟩))) (.mul (.const 0x0fdb35162ec5ddfb5c46af707f2a312734ae053775eaff7329cad98e905e060d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨311⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨147⟩)) (.mul (.const 0x2c1ad3c82d71b31ef6a0b9e8119279336c0933270b00ca99628345586626102e) (.var ⟨156⟩))) (.mul (.const 0x3517225e8241ae0140e9000ed5f76492429d5701a11e579c794acc22106e7df6) (.var ⟨165⟩))) (.mul (.const 0x34554d640b366b9deff6b38067826fb645a026886d3b7a3a23e4e1258b073939) (.var ⟨174⟩))) (.mul (.const 0x39a64df497b40268d708846fbe4c56a22f7b70cf43b5dba616cf60fe3cecaa29) (.var ⟨183⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨192⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨201⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨210⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨219⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨228⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨237⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨246⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨255⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨264⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨273⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨282⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨291⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨300⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨309⟩))) (.mul (.const 0x0fdb35162ec5ddfb5c46af707f2a312734ae053775eaff7329cad98e905e060d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨313⟩) (.var ⟨314⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨315⟩))),
+  .assert (.add (.var ⟨313⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨312⟩))),
+  .assert (.add (.var ⟨314⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨312⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨316⟩) (.var ⟨317⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨318⟩))),
+  .assert (.add (.var ⟨316⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨315⟩))),
+  .assert (.add (.var ⟨317⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨147⟩)) (.mul (.const 0x2c1ad3c82d71b31ef6a0b9e8119279336c0933270b00ca99628345586626102e) (.var ⟨156⟩))) (.mul (.const 0x3517225e8241ae0140e9000ed5f76492429d5701a11e579c794acc22106e7df6) (.var ⟨165⟩))) (.mul (.const 0x34554d640b366b9deff6b38067826fb645a026886d3b7a3a23e4e1258b073939) (.var ⟨174⟩))) (.mul (.const 0x39a64df497b40268d708846fbe4c56a22f7b70cf43b5dba616cf60fe3cecaa29) (.var ⟨183⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨192⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨201⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨210⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨219⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨228⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨237⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨246⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨255⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨264⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨273⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨282⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨291⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨300⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨309⟩))) (.mul (.const 0x0fdb35162ec5ddfb5c46af707f2a312734ae053775eaff7329cad98e905e060d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨319⟩) (.var ⟨320⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩))),
+  .assert (.add (.var ⟨319⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨147⟩)) (.mul (.const 0x2afdc1874bbf06b58060d386bff61acbc84536f4dbd28cab0a161ff96c2fc719) (.var ⟨156⟩))) (.mul (.const 0x37210a167156152157c798dd22ea9fa8a9c89e7b5bb1c00d0b1ce4b62ba48cd0) (.var ⟨165⟩))) (.mul (.const 0x2c274deecebec7bad306f4fc8c60adc8d9355430a909874ac2f982ba721b9009) (.var ⟨174⟩))) (.mul (.const 0x0479809be44f0552b373bf8d73c3795e867cf70505240a48fab1ebf70495ddcd) (.var ⟨183⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨192⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨201⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨210⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨219⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨228⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨237⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨246⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨255⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨264⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨273⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨282⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨291⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨300⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨309⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨318⟩))) (.mul (.const 0x231afb47756b34d8d420b15743a65f2378a48a1186e07d485a7bbed8770d0fa1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨320⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨147⟩)) (.mul (.const 0x2afdc1874bbf06b58060d386bff61acbc84536f4dbd28cab0a161ff96c2fc719) (.var ⟨156⟩))) (.mul (.const 0x37210a167156152157c798dd22ea9fa8a9c89e7b5bb1c00d0b1ce4b62ba48cd0) (.var ⟨165⟩))) (.mul (.const 0x2c274deecebec7bad306f4fc8c60adc8d9355430a909874ac2f982ba721b9009) (.var ⟨174⟩))) (.mul (.const 0x0479809be44f0552b373bf8d73c3795e867cf70505240a48fab1ebf70495ddcd) (.var ⟨183⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨192⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨201⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨210⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨219⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨228⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨237⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨246⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨255⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨264⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨273⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨282⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨291⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨300⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨309⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨318⟩))) (.mul (.const 0x231afb47756b34d8d420b15743a65f2378a48a1186e07d485a7bbed8770d0fa1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨322⟩) (.var ⟨323⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨324⟩))),
+  .assert (.add (.var ⟨322⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩))),
+  .assert (.add (.var ⟨323⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨321⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨325⟩) (.var ⟨326⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨327⟩))),
+  .assert (.add (.var ⟨325⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨324⟩))),
+  .assert (.add (.var ⟨326⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨147⟩)) (.mul (.const 0x2afdc1874bbf06b58060d386bff61acbc84536f4dbd28cab0a161ff96c2fc719) (.var ⟨156⟩))) (.mul (.const 0x37210a167156152157c798dd22ea9fa8a9c89e7b5bb1c00d0b1ce4b62ba48cd0) (.var ⟨165⟩))) (.mul (.const 0x2c274deecebec7bad306f4fc8c60adc8d9355430a909874ac2f982ba721b9009) (.var ⟨174⟩))) (.mul (.const 0x0479809be44f0552b373bf8d73c3795e867cf70505240a48fab1ebf70495ddcd) (.var ⟨183⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨192⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨201⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨210⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨219⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨228⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨237⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨246⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨255⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨264⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨273⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨282⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨291⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨300⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨309⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨318⟩))) (.mul (.const 0x231afb47756b34d8d420b15743a65f2378a48a1186e07d485a7bbed8770d0fa1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨328⟩) (.var ⟨329⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨330⟩))),
+  .assert (.add (.var ⟨328⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨147⟩)) (.mul (.const 0x0d8cbdad2f44e58d462f2cf96bae35c0727b4fcb5bb968bda363b787d45ebd70) (.var ⟨156⟩))) (.mul (.const 0x192299ffea16f987f36b443b9d77d5dc0be78e2dace9d3f9728cd681e5e11187) (.var ⟨165⟩))) (.mul (.const 0x012b9bca921907d9e63f0d447026daa2082c450ba90917b8cf8c16d3dbe52eb4) (.var ⟨174⟩))) (.mul (.const 0x008473e453025a21c451b1645cae7850f66355b1b30bc9172753b59114a917a3) (.var ⟨183⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨192⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨201⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨210⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨219⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨228⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨237⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨246⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨255⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨264⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨273⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨282⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨291⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨300⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨309⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨318⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨327⟩))) (.mul (.const 0x11c3d0cb3adc5a30d005f06a640639ccf39730bc76c56138ae888aa070fdf523) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨329⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨147⟩)) (.mul (.const 0x0d8cbdad2f44e58d462f2cf96bae35c0727b4fcb5bb968bda363b787d45ebd70) (.var ⟨156⟩))) (.mul (.const 0x192299ffea16f987f36b443b9d77d5dc0be78e2dace9d3f9728cd681e5e11187) (.var ⟨165⟩))) (.mul (.const 0x012b9bca921907d9e63f0d447026daa2082c450ba90917b8cf8c16d3dbe52eb4) (.var ⟨174⟩))) (.mul (.const 0x008473e453025a21c451b1645cae7850f66355b1b30bc9172753b59114a917a3) (.var ⟨183⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨192⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨201⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨210⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨219⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨228⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨237⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨246⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨255⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨264⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨273⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨282⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨291⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨300⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨309⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨318⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨327⟩))) (.mul (.const 0x11c3d0cb3adc5a30d005f06a640639ccf39730bc76c56138ae888aa070fdf523) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨331⟩) (.var ⟨332⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨333⟩))),
+  .assert (.add (.var ⟨331⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨330⟩))),
+  .assert (.add (.var ⟨332⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨330⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨334⟩) (.var ⟨335⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨336⟩))),
+  .assert (.add (.var ⟨334⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨333⟩))),
+  .assert (.add (.var ⟨335⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨147⟩)) (.mul (.const 0x0d8cbdad2f44e58d462f2cf96bae35c0727b4fcb5bb968bda363b787d45ebd70) (.var ⟨156⟩))) (.mul (.const 0x192299ffea16f987f36b443b9d77d5dc0be78e2dace9d3f9728cd681e5e11187) (.var ⟨165⟩))) (.mul (.const 0x012b9bca921907d9e63f0d447026daa2082c450ba90917b8cf8c16d3dbe52eb4) (.var ⟨174⟩))) (.mul (.const 0x008473e453025a21c451b1645cae7850f66355b1b30bc9172753b59114a917a3) (.var ⟨183⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨192⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨201⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨210⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨219⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨228⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨237⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨246⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨255⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨264⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨273⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨282⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨291⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨300⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨309⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨318⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨327⟩))) (.mul (.const 0x11c3d0cb3adc5a30d005f06a640639ccf39730bc76c56138ae888aa070fdf523) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨337⟩) (.var ⟨338⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨339⟩))),
+  .assert (.add (.var ⟨337⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨147⟩)) (.mul (.const 0x1a7f5ec57a2988f84d6579bad873d165f06dd6cd091044f1f2790be85b221f0b) (.var ⟨156⟩))) (.mul (.const 0x2d2ac21775f2936b72b23cc761477ce62227ce2630d4cd84aea0271360bad015) (.var ⟨165⟩))) (.mul (.const 0x1338f74bdf53cda330af14fe1823f8639109ef3e7c0dbf5f41dabcfef2cb42c8) (.var ⟨174⟩))) (.mul (.const 0x278bbc49e70745543c9624e339ad8ae96804dd367455bcc3bdf8b555dd0e68f1) (.var ⟨183⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨192⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨201⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨210⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨219⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨228⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨237⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨246⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨255⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨264⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨273⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨282⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨291⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨300⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨309⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨318⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨327⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨336⟩))) (.mul (.const 0x07500767b874e0870f5f7c91e88681f6b31f69ace4957387a211ef69406117a8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨338⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨147⟩)) (.mul (.const 0x1a7f5ec57a2988f84d6579bad873d165f06dd6cd091044f1f2790be85b221f0b) (.var ⟨156⟩))) (.mul (.const 0x2d2ac21775f2936b72b23cc761477ce62227ce2630d4cd84aea0271360bad015) (.var ⟨165⟩))) (.mul (.const 0x1338f74bdf53cda330af14fe1823f8639109ef3e7c0dbf5f41dabcfef2cb42c8) (.var ⟨174⟩))) (.mul (.const 0x278bbc49e70745543c9624e339ad8ae96804dd367455bcc3bdf8b555dd0e68f1) (.var ⟨183⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨192⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨201⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨210⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨219⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨228⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨237⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨246⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨255⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨264⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨273⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨282⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨291⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨300⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨309⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨318⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨327⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨336⟩))) (.mul (.const 0x07500767b874e0870f5f7c91e88681f6b31f69ace4957387a211ef69406117a8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨340⟩) (.var ⟨341⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩))),
+  .assert (.add (.var ⟨340⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨339⟩))),
+  .assert (.add (.var ⟨341⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨339⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨343⟩) (.var ⟨344⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨345⟩))),
+  .assert (.add (.var ⟨343⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨342⟩))),
+  .assert (.add (.var ⟨344⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨147⟩)) (.mul (.const 0x1a7f5ec57a2988f84d6579bad873d165f06dd6cd091044f1f2790be85b221f0b) (.var ⟨156⟩))) (.mul (.const 0x2d2ac21775f2936b72b23cc761477ce62227ce2630d4cd84aea0271360bad015) (.var ⟨165⟩))) (.mul (.const 0x1338f74bdf53cda330af14fe1823f8639109ef3e7c0dbf5f41dabcfef2cb42c8) (.var ⟨174⟩))) (.mul (.const 0x278bbc49e70745543c9624e339ad8ae96804dd367455bcc3bdf8b555dd0e68f1) (.var ⟨183⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨192⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨201⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨210⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨219⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨228⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨237⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨246⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨255⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨264⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨273⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨282⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨291⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨300⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨309⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨318⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨327⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨336⟩))) (.mul (.const 0x07500767b874e0870f5f7c91e88681f6b31f69ace4957387a211ef69406117a8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨346⟩) (.var ⟨347⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨348⟩))),
+  .assert (.add (.var ⟨346⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨147⟩)) (.mul (.const 0x1d84a461a47e0d8992d06e137aef4aa0ded1f54d14dda0e34556bd73108c4db2) (.var ⟨156⟩))) (.mul (.const 0x283c81b787d80ff7d4f637ab84bdc4b9e1fd907021174084c7b90c13b8374626) (.var ⟨165⟩))) (.mul (.const 0x1cfe799aca8f4371043efa374c13990cbb023f3e524cfb25bbd4fb4341c13a57) (.var ⟨174⟩))) (.mul (.const 0x3ad5b099fb178349dbb80d20c357dce462cebf4256b823040101dcf8b5f42352) (.var ⟨183⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨192⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨201⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨210⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨219⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨228⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨237⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨246⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨255⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨264⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨273⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨282⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨291⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨300⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨309⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨318⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨327⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨336⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨345⟩))) (.mul (.const 0x33888edc1848fa712fccabddcc997a66bdacbb2aaf924455dc7cb74ac8063953) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨347⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨147⟩)) (.mul (.const 0x1d84a461a47e0d8992d06e137aef4aa0ded1f54d14dda0e34556bd73108c4db2) (.var ⟨156⟩))) (.mul (.const 0x283c81b787d80ff7d4f637ab84bdc4b9e1fd907021174084c7b90c13b8374626) (.var ⟨165⟩))) (.mul (.const 0x1cfe799aca8f4371043efa374c13990cbb023f3e524cfb25bbd4fb4341c13a57) (.var ⟨174⟩))) (.mul (.const 0x3ad5b099fb178349dbb80d20c357dce462cebf4256b823040101dcf8b5f42352) (.var ⟨183⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨192⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨201⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨210⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨219⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨228⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨237⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨246⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨255⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨264⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨273⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨282⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨291⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨300⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨309⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨318⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨327⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨336⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨345⟩))) (.mul (.const 0x33888edc1848fa712fccabddcc997a66bdacbb2aaf924455dc7cb74ac8063953) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨349⟩) (.var ⟨350⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨351⟩))),
+  .assert (.add (.var ⟨349⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨348⟩))),
+  .assert (.add (.var ⟨350⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨348⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨352⟩) (.var ⟨353⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨354⟩))),
+  .assert (.add (.var ⟨352⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨351⟩))),
+  .assert (.add (.var ⟨353⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨147⟩)) (.mul (.const 0x1d84a461a47e0d8992d06e137aef4aa0ded1f54d14dda0e34556bd73108c4db2) (.var ⟨156⟩))) (.mul (.const 0x283c81b787d80ff7d4f637ab84bdc4b9e1fd907021174084c7b90c13b8374626) (.var ⟨165⟩))) (.mul (.const 0x1cfe799aca8f4371043efa374c13990cbb023f3e524cfb25bbd4fb4341c13a57) (.var ⟨174⟩))) (.mul (.const 0x3ad5b099fb178349dbb80d20c357dce462cebf4256b823040101dcf8b5f42352) (.var ⟨183⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨192⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨201⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨210⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨219⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨228⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨237⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨246⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨255⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨264⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨273⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨282⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨291⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨300⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨309⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨318⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨327⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨336⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨345⟩))) (.mul (.const 0x33888edc1848fa712fccabddcc997a66bdacbb2aaf924455dc7cb74ac8063953) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨355⟩) (.var ⟨356⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨357⟩))),
+  .assert (.add (.var ⟨355⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨147⟩)) (.mul (.const 0x331355e86670671446469eaf9204e85fbe9d0e3cbf83acc695c771c1cc9bc1da) (.var ⟨156⟩))) (.mul (.const 0x033d8e463a2352834a367292e9c78bf853422ae057a3725a83be81cfc27ce284) (.var ⟨165⟩))) (.mul (.const 0x37e9e2e7bd34219640d7eccdf448c099cec80cf603a8b9feacced0280698288e) (.var ⟨174⟩))) (.mul (.const 0x3e168ffa2d0bba2d36d0c591d4e4798b9a8ad5cca7cad59ebdc041d0b42d7a52) (.var ⟨183⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨192⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨201⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨210⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨219⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨228⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨237⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨246⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨255⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨264⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨273⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨282⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨291⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨300⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨309⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨318⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨327⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨336⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨345⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨354⟩))) (.mul (.const 0x2ac9b41ef42b5a21e15d68b1bd1b7bc507718c0817819270f856c720c545b5d8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨356⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨147⟩)) (.mul (.const 0x331355e86670671446469eaf9204e85fbe9d0e3cbf83acc695c771c1cc9bc1da) (.var ⟨156⟩))) (.mul (.const 0x033d8e463a2352834a367292e9c78bf853422ae057a3725a83be81cfc27ce284) (.var ⟨165⟩))) (.mul (.const 0x37e9e2e7bd34219640d7eccdf448c099cec80cf603a8b9feacced0280698288e) (.var ⟨174⟩))) (.mul (.const 0x3e168ffa2d0bba2d36d0c591d4e4798b9a8ad5cca7cad59ebdc041d0b42d7a52) (.var ⟨183⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨192⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨201⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨210⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨219⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨228⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨237⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨246⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨255⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨264⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨273⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨282⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨291⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨300⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨309⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨318⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨327⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨336⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨345⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨354⟩))) (.mul (.const 0x2ac9b41ef42b5a21e15d68b1bd1b7bc507718c0817819270f856c720c545b5d8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨358⟩) (.var ⟨359⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨360⟩))),
+  .assert (.add (.var ⟨358⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨357⟩))),
+  .assert (.add (.var ⟨359⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨357⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨361⟩) (.var ⟨362⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨363⟩))),
+  .assert (.add (.var ⟨361⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨360⟩))),
+  .assert (.add (.var ⟨362⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨147⟩)) (.mul (.const 0x331355e86670671446469eaf9204e85fbe9d0e3cbf83acc695c771c1cc9bc1da) (.var ⟨156⟩))) (.mul (.const 0x033d8e463a2352834a367292e9c78bf853422ae057a3725a83be81cfc27ce284) (.var ⟨165⟩))) (.mul (.const 0x37e9e2e7bd34219640d7eccdf448c099cec80cf603a8b9feacced0280698288e) (.var ⟨174⟩))) (.mul (.const 0x3e168ffa2d0bba2d36d0c591d4e4798b9a8ad5cca7cad59ebdc041d0b42d7a52) (.var ⟨183⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨192⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨201⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨210⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨219⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨228⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨237⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨246⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨255⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨264⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨273⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨282⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨291⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨300⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨309⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨318⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨327⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨336⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨345⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨354⟩))) (.mul (.const 0x2ac9b41ef42b5a21e15d68b1bd1b7bc507718c0817819270f856c720c545b5d8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨364⟩) (.var ⟨365⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨366⟩))),
+  .assert (.add (.var ⟨364⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨147⟩)) (.mul (.const 0x2e59a95a7b66b34fc1695aeda93976caf9cd137b05ba8c61604bc3547b829971) (.var ⟨156⟩))) (.mul (.const 0x279ca24ae3045e280fef8475e47adaf51d2c044dc0dfb8c66d20097cb1518773) (.var ⟨165⟩))) (.mul (.const 0x36b61f4d5df8c185ae2ec450da2e1719cc4dc77e9dea6ad9ddb28fddb81ad995) (.var ⟨174⟩))) (.mul (.const 0x2e09cffb4c41fdd9e8aec2e46dad413d04f43ffe36471d502c386bc563fa1059) (.var ⟨183⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨192⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨201⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨210⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨219⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨228⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨237⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨246⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨255⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨264⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨273⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨282⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨291⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨300⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨309⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨318⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨327⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨336⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨345⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨354⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨363⟩))) (.mul (.const 0x350467165f563caa1b9dceb090539715bc5a7c0022fa4ec66654682c2135c6a8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨365⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨147⟩)) (.mul (.const 0x2e59a95a7b66b34fc1695aeda93976caf9cd137b05ba8c61604bc3547b829971) (.var ⟨156⟩))) (.mul (.const 0x279ca24ae3045e280fef8475e47adaf51d2c044dc0dfb8c66d20097cb1518773) (.var ⟨165⟩))) (.mul (.const 0x36b61f4d5df8c185ae2ec450da2e1719cc4dc77e9dea6ad9ddb28fddb81ad995) (.var ⟨174⟩))) (.mul (.const 0x2e09cffb4c41fdd9e8aec2e46dad413d04f43ffe36471d502c386bc563fa1059) (.var ⟨183⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨192⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨201⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨210⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨219⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨228⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨237⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨246⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨255⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨264⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨273⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨282⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨291⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨300⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨309⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨318⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨327⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨336⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨345⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨354⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨363⟩))) (.mul (.const 0x350467165f563caa1b9dceb090539715bc5a7c0022fa4ec66654682c2135c6a8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨367⟩) (.var ⟨368⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨369⟩))),
+  .assert (.add (.var ⟨367⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨366⟩))),
+  .assert (.add (.var ⟨368⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨366⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨370⟩) (.var ⟨371⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨372⟩))),
+  .assert (.add (.var ⟨370⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨369⟩))),
+  .assert (.add (.var ⟨371⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨147⟩)) (.mul (.const 0x2e59a95a7b66b34fc1695aeda93976caf9cd137b05ba8c61604bc3547b829971) (.var ⟨156⟩))) (.mul (.const 0x279ca24ae3045e280fef8475e47adaf51d2c044dc0dfb8c66d20097cb1518773) (.var ⟨165⟩))) (.mul (.const 0x36b61f4d5df8c185ae2ec450da2e1719cc4dc77e9dea6ad9ddb28fddb81ad995) (.var ⟨174⟩))) (.mul (.const 0x2e09cffb4c41fdd9e8aec2e46dad413d04f43ffe36471d502c386bc563fa1059) (.var ⟨183⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨192⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨201⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨210⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨219⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨228⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨237⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨246⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨255⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨264⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨273⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨282⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨291⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨300⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨309⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨318⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨327⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨336⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨345⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨354⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨363⟩))) (.mul (.const 0x350467165f563caa1b9dceb090539715bc5a7c0022fa4ec66654682c2135c6a8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨373⟩) (.var ⟨374⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨375⟩))),
+  .assert (.add (.var ⟨373⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨147⟩)) (.mul (.const 0x10115fa486621feae132429fbcb9ffe3cad91dc81d989171bc6304f4aa87d207) (.var ⟨156⟩))) (.mul (.const 0x017d6b5785d9053a9c0939e5934d1147ceda85f3f0f2a137afb503945465cf9a) (.var ⟨165⟩))) (.mul (.const 0x1b0d067d2e5fe503ab42bc4854d620b75c9040d17f50ace2f96098a20108dc9e) (.var ⟨174⟩))) (.mul (.const 0x0611413026eeab97ca88206a8eb444bb58f0d78946a220e123d9ab5bda93623b) (.var ⟨183⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨192⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨201⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨210⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨219⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨228⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨237⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨246⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨255⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨264⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨273⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨282⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨291⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨300⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨309⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨318⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨327⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨336⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨345⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨354⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨363⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨372⟩))) (.mul (.const 0x2c4cb2c9c1ec96f4b1d275f93996d610fe58e1fba9d92c9c53c3c65e792f0658) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨374⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨147⟩)) (.mul (.const 0x10115fa486621feae132429fbcb9ffe3cad91dc81d989171bc6304f4aa87d207) (.var ⟨156⟩))) (.mul (.const 0x017d6b5785d9053a9c0939e5934d1147ceda85f3f0f2a137afb503945465cf9a) (.var ⟨165⟩))) (.mul (.const 0x1b0d067d2e5fe503ab42bc4854d620b75c9040d17f50ace2f96098a20108dc9e) (.var ⟨174⟩))) (.mul (.const 0x0611413026eeab97ca88206a8eb444bb58f0d78946a220e123d9ab5bda93623b) (.var ⟨183⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨192⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨201⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨210⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨219⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨228⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨237⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨246⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨255⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨264⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨273⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨282⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨291⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨300⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨309⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨318⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨327⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨336⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨345⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨354⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨363⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨372⟩))) (.mul (.const 0x2c4cb2c9c1ec96f4b1d275f93996d610fe58e1fba9d92c9c53c3c65e792f0658) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨376⟩) (.var ⟨377⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨378⟩))),
+  .assert (.add (.var ⟨376⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨375⟩))),
+  .assert (.add (.var ⟨377⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨375⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨379⟩) (.var ⟨380⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨381⟩))),
+  .assert (.add (.var ⟨379⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨378⟩))),
+  .assert (.add (.var ⟨380⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨147⟩)) (.mul (.const 0x10115fa486621feae132429fbcb9ffe3cad91dc81d989171bc6304f4aa87d207) (.var ⟨156⟩))) (.mul (.const 0x017d6b5785d9053a9c0939e5934d1147ceda85f3f0f2a137afb503945465cf9a) (.var ⟨165⟩))) (.mul (.const 0x1b0d067d2e5fe503ab42bc4854d620b75c9040d17f50ace2f96098a20108dc9e) (.var ⟨174⟩))) (.mul (.const 0x0611413026eeab97ca88206a8eb444bb58f0d78946a220e123d9ab5bda93623b) (.var ⟨183⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨192⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨201⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨210⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨219⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨228⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨237⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨246⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨255⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨264⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨273⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨282⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨291⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨300⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨309⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨318⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨327⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨336⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨345⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨354⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨363⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨372⟩))) (.mul (.const 0x2c4cb2c9c1ec96f4b1d275f93996d610fe58e1fba9d92c9c53c3c65e792f0658) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨382⟩) (.var ⟨383⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩))),
+  .assert (.add (.var ⟨382⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨147⟩)) (.mul (.const 0x22adc6778c4f8b2253ecf87afa56ea103bf2e530eecc1f9d189f231639b2264a) (.var ⟨156⟩))) (.mul (.const 0x083a20c7ca82e9d435d1ba71ab85098b95d09e42899c1393461de57c021d85b0) (.var ⟨165⟩))) (.mul (.const 0x01975df14a0f3aebcfd8f6284e037dcc005ade06ca257b9d63e34f7e672dd779) (.var ⟨174⟩))) (.mul (.const 0x2e0aed1900a3cc1eeaa1018d290817938e4a0ebd86123808904aaffba293e628) (.var ⟨183⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨192⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨201⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨210⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨219⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨228⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨237⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨246⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨255⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨264⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨273⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨282⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨291⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨300⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨309⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨318⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨327⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨336⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨345⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨354⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨363⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨372⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨381⟩))) (.mul (.const 0x02f920ef3e41839784bc107f0c102e1b11a4fe0f32bfa11e72258856d00d9ad2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨383⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨147⟩)) (.mul (.const 0x22adc6778c4f8b2253ecf87afa56ea103bf2e530eecc1f9d189f231639b2264a) (.var ⟨156⟩))) (.mul (.const 0x083a20c7ca82e9d435d1ba71ab85098b95d09e42899c1393461de57c021d85b0) (.var ⟨165⟩))) (.mul (.const 0x01975df14a0f3aebcfd8f6284e037dcc005ade06ca257b9d63e34f7e672dd779) (.var ⟨174⟩))) (.mul (.const 0x2e0aed1900a3cc1eeaa1018d290817938e4a0ebd86123808904aaffba293e628) (.var ⟨183⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨192⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨201⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨210⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨219⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨228⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨237⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨246⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨255⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨264⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨273⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨282⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨291⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨300⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨309⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨318⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨327⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨336⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨345⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨354⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨363⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨372⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨381⟩))) (.mul (.const 0x02f920ef3e41839784bc107f0c102e1b11a4fe0f32bfa11e72258856d00d9ad2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨385⟩) (.var ⟨386⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨387⟩))),
+  .assert (.add (.var ⟨385⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩))),
+  .assert (.add (.var ⟨386⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨384⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨388⟩) (.var ⟨389⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨390⟩))),
+  .assert (.add (.var ⟨388⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨387⟩))),
+  .assert (.add (.var ⟨389⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨147⟩)) (.mul (.const 0x22adc6778c4f8b2253ecf87afa56ea103bf2e530eecc1f9d189f231639b2264a) (.var ⟨156⟩))) (.mul (.const 0x083a20c7ca82e9d435d1ba71ab85098b95d09e42899c1393461de57c021d85b0) (.var ⟨165⟩))) (.mul (.const 0x01975df14a0f3aebcfd8f6284e037dcc005ade06ca257b9d63e34f7e672dd779) (.var ⟨174⟩))) (.mul (.const 0x2e0aed1900a3cc1eeaa1018d290817938e4a0ebd86123808904aaffba293e628) (.var ⟨183⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨192⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨201⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨210⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨219⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨228⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨237⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨246⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨255⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨264⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨273⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨282⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨291⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨300⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨309⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨318⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨327⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨336⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨345⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨354⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨363⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨372⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨381⟩))) (.mul (.const 0x02f920ef3e41839784bc107f0c102e1b11a4fe0f32bfa11e72258856d00d9ad2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨391⟩) (.var ⟨392⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨393⟩))),
+  .assert (.add (.var ⟨391⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨147⟩)) (.mul (.const 0x006eac39c256840c968f6862c42f40e99dd64702806d72e6450690a7525d323d) (.var ⟨156⟩))) (.mul (.const 0x18013e871dd9707ea5a5eac68b409914ebf6682c9ac9a9827d1c7cdcb23145eb) (.var ⟨165⟩))) (.mul (.const 0x3aa8e37362e06abfe23c9fd8848b0a5c326e9befedb9fad7bb04fdbc42ca3307) (.var ⟨174⟩))) (.mul (.const 0x3dc15535ecfc9e349cb6f1e5b5b4f257eb3294cfd7f1262616b7d45b84682385) (.var ⟨183⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨192⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨201⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨210⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨219⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨228⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨237⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨246⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨255⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨264⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨273⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨282⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨291⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨300⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨309⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨318⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨327⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨336⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨345⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨354⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨363⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨372⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨381⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨390⟩))) (.mul (.const 0x2ee27b9fdae66d7edd95e5a87292b5387cbfc2086264f2a9fd27d0323de904fc) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨392⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨147⟩)) (.mul (.const 0x006eac39c256840c968f6862c42f40e99dd64702806d72e6450690a7525d323d) (.var ⟨156⟩))) (.mul (.const 0x18013e871dd9707ea5a5eac68b409914ebf6682c9ac9a9827d1c7cdcb23145eb) (.var ⟨165⟩))) (.mul (.const 0x3aa8e37362e06abfe23c9fd8848b0a5c326e9befedb9fad7bb04fdbc42ca3307) (.var ⟨174⟩))) (.mul (.const 0x3dc15535ecfc9e349cb6f1e5b5b4f257eb3294cfd7f1262616b7d45b84682385) (.var ⟨183⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨192⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨201⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨210⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨219⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨228⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨237⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨246⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨255⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨264⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨273⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨282⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨291⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨300⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨309⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨318⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨327⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨336⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨345⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨354⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨363⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨372⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨381⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨390⟩))) (.mul (.const 0x2ee27b9fdae66d7edd95e5a87292b5387cbfc2086264f2a9fd27d0323de904fc) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨394⟩) (.var ⟨395⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨396⟩))),
+  .assert (.add (.var ⟨394⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨393⟩))),
+  .assert (.add (.var ⟨395⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨393⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨397⟩) (.var ⟨398⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨399⟩))),
+  .assert (.add (.var ⟨397⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨396⟩))),
+  .assert (.add (.var ⟨398⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨147⟩)) (.mul (.const 0x006eac39c256840c968f6862c42f40e99dd64702806d72e6450690a7525d323d) (.var ⟨156⟩))) (.mul (.const 0x18013e871dd9707ea5a5eac68b409914ebf6682c9ac9a9827d1c7cdcb23145eb) (.var ⟨165⟩))) (.mul (.const 0x3aa8e37362e06abfe23c9fd8848b0a5c326e9befedb9fad7bb04fdbc42ca3307) (.var ⟨174⟩))) (.mul (.const 0x3dc15535ecfc9e349cb6f1e5b5b4f257eb3294cfd7f1262616b7d45b84682385) (.var ⟨183⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨192⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨201⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨210⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨219⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨228⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨237⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨246⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨255⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨264⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨273⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨282⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨291⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨300⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨309⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨318⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨327⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨336⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨345⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨354⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨363⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨372⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨381⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨390⟩))) (.mul (.const 0x2ee27b9fdae66d7edd95e5a87292b5387cbfc2086264f2a9fd27d0323de904fc) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨400⟩) (.var ⟨401⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨402⟩))),
+  .assert (.add (.var ⟨400⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨147⟩)) (.mul (.const 0x1c20d85c2af5b9dd8a9c4f37d5a1213e2e619b0164a2f0b8fc67becf44a28210) (.var ⟨156⟩))) (.mul (.const 0x016ae7a7ab3056cc336329c006c8313236e1e291e7fb267746c007ccc27c385a) (.var ⟨165⟩))) (.mul (.const 0x1957225a5896c6cc4951edbbf30d147fd3c96b11036bd59c6fe2155d7937da09) (.var ⟨174⟩))) (.mul (.const 0x0b21bff4ba0c8cf61b55fc3e144e01025c580392f103a4254f49f94308f7755e) (.var ⟨183⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨192⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨201⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨210⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨219⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨228⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨237⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨246⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨255⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨264⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨273⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨282⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨291⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨300⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨309⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨318⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨327⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨336⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨345⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨354⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨363⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨372⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨381⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨390⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨399⟩))) (.mul (.const 0x274ba9bc564763839970ef808ef5ad678cf9d6b88bc44c5d682586f652020a32) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨401⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨147⟩)) (.mul (.const 0x1c20d85c2af5b9dd8a9c4f37d5a1213e2e619b0164a2f0b8fc67becf44a28210) (.var ⟨156⟩))) (.mul (.const 0x016ae7a7ab3056cc336329c006c8313236e1e291e7fb267746c007ccc27c385a) (.var ⟨165⟩))) (.mul (.const 0x1957225a5896c6cc4951edbbf30d147fd3c96b11036bd59c6fe2155d7937da09) (.var ⟨174⟩))) (.mul (.const 0x0b21bff4ba0c8cf61b55fc3e144e01025c580392f103a4254f49f94308f7755e) (.var ⟨183⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨192⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨201⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨210⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨219⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨228⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨237⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨246⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨255⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨264⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨273⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨282⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨291⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨300⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨309⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨318⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨327⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨336⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨345⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨354⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨363⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨372⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨381⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨390⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨399⟩))) (.mul (.const 0x274ba9bc564763839970ef808ef5ad678cf9d6b88bc44c5d682586f652020a32) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨403⟩) (.var ⟨404⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩))),
+  .assert (.add (.var ⟨403⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨402⟩))),
+  .assert (.add (.var ⟨404⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨402⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨406⟩) (.var ⟨407⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨408⟩))),
+  .assert (.add (.var ⟨406⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨405⟩))),
+  .assert (.add (.var ⟨407⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨147⟩)) (.mul (.const 0x1c20d85c2af5b9dd8a9c4f37d5a1213e2e619b0164a2f0b8fc67becf44a28210) (.var ⟨156⟩))) (.mul (.const 0x016ae7a7ab3056cc336329c006c8313236e1e291e7fb267746c007ccc27c385a) (.var ⟨165⟩))) (.mul (.const 0x1957225a5896c6cc4951edbbf30d147fd3c96b11036bd59c6fe2155d7937da09) (.var ⟨174⟩))) (.mul (.const 0x0b21bff4ba0c8cf61b55fc3e144e01025c580392f103a4254f49f94308f7755e) (.var ⟨183⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨192⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨201⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨210⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨219⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨228⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨237⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨246⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨255⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨264⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨273⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨282⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨291⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨300⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨309⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨318⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨327⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨336⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨345⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨354⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨363⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨372⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨381⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨390⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨399⟩))) (.mul (.const 0x274ba9bc564763839970ef808ef5ad678cf9d6b88bc44c5d682586f652020a32) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨409⟩) (.var ⟨410⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨411⟩))),
+  .assert (.add (.var ⟨409⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨147⟩)) (.mul (.const 0x08df77ba5fbeaa6e23cdd70c6d416ece81bbb9f144edb011b465a909a5bf124c) (.var ⟨156⟩))) (.mul (.const 0x0d6ea0ea90b4262ce9075c5493a664b96b9c1ed0accf377ff2e5991a8c7265a2) (.var ⟨165⟩))) (.mul (.const 0x32c563c15aa763eb242a0052b838b2688624aafbc74069217bc2f91da328619d) (.var ⟨174⟩))) (.mul (.const 0x152d61f4af6233530d216e6c9cbc5349290bcf3b65d27fcf9921a37da7176cfe) (.var ⟨183⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨192⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨201⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨210⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨219⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨228⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨237⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨246⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨255⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨264⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨273⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨282⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨291⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨300⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨309⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨318⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨327⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨336⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨345⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨354⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨363⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨372⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨381⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨390⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨399⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨408⟩))) (.mul (.const 0x0341604e13e9d42d2848492db5783e63c2722d92c16ca81f8b9b57c3713b17df) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨410⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨147⟩)) (.mul (.const 0x08df77ba5fbeaa6e23cdd70c6d416ece81bbb9f144edb011b465a909a5bf124c) (.var ⟨156⟩))) (.mul (.const 0x0d6ea0ea90b4262ce9075c5493a664b96b9c1ed0accf377ff2e5991a8c7265a2) (.var ⟨165⟩))) (.mul (.const 0x32c563c15aa763eb242a0052b838b2688624aafbc74069217bc2f91da328619d) (.var ⟨174⟩))) (.mul (.const 0x152d61f4af6233530d216e6c9cbc5349290bcf3b65d27fcf9921a37da7176cfe) (.var ⟨183⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨192⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨201⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨210⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨219⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨228⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨237⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨246⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨255⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨264⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨273⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨282⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨291⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨300⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨309⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨318⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨327⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨336⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨345⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨354⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨363⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨372⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨381⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨390⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨399⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨408⟩))) (.mul (.const 0x0341604e13e9d42d2848492db5783e63c2722d92c16ca81f8b9b57c3713b17df) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨412⟩) (.var ⟨413⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨414⟩))),
+  .assert (.add (.var ⟨412⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨411⟩))),
+  .assert (.add (.var ⟨413⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨411⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨415⟩) (.var ⟨416⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨417⟩))),
+  .assert (.add (.var ⟨415⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨414⟩))),
+  .assert (.add (.var ⟨416⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨147⟩)) (.mul (.const 0x08df77ba5fbeaa6e23cdd70c6d416ece81bbb9f144edb011b465a909a5bf124c) (.var ⟨156⟩))) (.mul (.const 0x0d6ea0ea90b4262ce9075c5493a664b96b9c1ed0accf377ff2e5991a8c7265a2) (.var ⟨165⟩))) (.mul (.const 0x32c563c15aa763eb242a0052b838b2688624aafbc74069217bc2f91da328619d) (.var ⟨174⟩))) (.mul (.const 0x152d61f4af6233530d216e6c9cbc5349290bcf3b65d27fcf9921a37da7176cfe) (.var ⟨183⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨192⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨201⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨210⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨219⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨228⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨237⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨246⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨255⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨264⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨273⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨282⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨291⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨300⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨309⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨318⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨327⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨336⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨345⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨354⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨363⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨372⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨381⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨390⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨399⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨408⟩))) (.mul (.const 0x0341604e13e9d42d2848492db5783e63c2722d92c16ca81f8b9b57c3713b17df) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨418⟩) (.var ⟨419⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨420⟩))),
+  .assert (.add (.var ⟨418⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨147⟩)) (.mul (.const 0x14ff116cbf15bcc8a931c4e26237e29e314555a71448bf99b180b6991759b176) (.var ⟨156⟩))) (.mul (.const 0x2fa034818ec4e3b93770c4eedcdb87019dfe9e84f58a39339ae2b53606f8af82) (.var ⟨165⟩))) (.mul (.const 0x3fb3013c22cce38a02f9d2a701342b573465c54946fb156b1d6e5b3023ea8cc1) (.var ⟨174⟩))) (.mul (.const 0x33014b6824af39afd61030ff80f34a6d17aa44ff2be23e7de3cbb7fc2c1b5ad5) (.var ⟨183⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨192⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨201⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨210⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨219⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨228⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨237⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨246⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨255⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨264⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨273⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨282⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨291⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨300⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨309⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨318⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨327⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨336⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨345⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨354⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨363⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨372⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨381⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨390⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨399⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨408⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨417⟩))) (.mul (.const 0x060295e867668f5c93ee4bd1b0f0b310cb1f7ee3ce31a39fb9b2e217ea484ac6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨419⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨147⟩)) (.mul (.const 0x14ff116cbf15bcc8a931c4e26237e29e314555a71448bf99b180b6991759b176) (.var ⟨156⟩))) (.mul (.const 0x2fa034818ec4e3b93770c4eedcdb87019dfe9e84f58a39339ae2b53606f8af82) (.var ⟨165⟩))) (.mul (.const 0x3fb3013c22cce38a02f9d2a701342b573465c54946fb156b1d6e5b3023ea8cc1) (.var ⟨174⟩))) (.mul (.const 0x33014b6824af39afd61030ff80f34a6d17aa44ff2be23e7de3cbb7fc2c1b5ad5) (.var ⟨183⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨192⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨201⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨210⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨219⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨228⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨237⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨246⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨255⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨264⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨273⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨282⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨291⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨300⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨309⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨318⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨327⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨336⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨345⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨354⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨363⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨372⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨381⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨390⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨399⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨408⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨417⟩))) (.mul (.const 0x060295e867668f5c93ee4bd1b0f0b310cb1f7ee3ce31a39fb9b2e217ea484ac6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨421⟩) (.var ⟨422⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨423⟩))),
+  .assert (.add (.var ⟨421⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨420⟩))),
+  .assert (.add (.var ⟨422⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨420⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨424⟩) (.var ⟨425⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨426⟩))),
+  .assert (.add (.var ⟨424⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨423⟩))),
+  .assert (.add (.var ⟨425⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨147⟩)) (.mul (.const 0x14ff116cbf15bcc8a931c4e26237e29e314555a71448bf99b180b6991759b176) (.var ⟨156⟩))) (.mul (.const 0x2fa034818ec4e3b93770c4eedcdb87019dfe9e84f58a39339ae2b53606f8af82) (.var ⟨165⟩))) (.mul (.const 0x3fb3013c22cce38a02f9d2a701342b573465c54946fb156b1d6e5b3023ea8cc1) (.var ⟨174⟩))) (.mul (.const 0x33014b6824af39afd61030ff80f34a6d17aa44ff2be23e7de3cbb7fc2c1b5ad5) (.var ⟨183⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨192⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨201⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨210⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨219⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨228⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨237⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨246⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨255⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨264⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨273⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨282⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨291⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨300⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨309⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨318⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨327⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨336⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨345⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨354⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨363⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨372⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨381⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨390⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨399⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨408⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨417⟩))) (.mul (.const 0x060295e867668f5c93ee4bd1b0f0b310cb1f7ee3ce31a39fb9b2e217ea484ac6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨427⟩) (.var ⟨428⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨429⟩))),
+  .assert (.add (.var ⟨427⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨147⟩)) (.mul (.const 0x03fa06bf28fc9a4169c06d622b706b1685756aa36f6ce35fd748292499bf74d9) (.var ⟨156⟩))) (.mul (.const 0x1ee0c32dd8df0feb7dbc59b6666f75920b6ed9c72aa1f04c5f18a92a56fa071d) (.var ⟨165⟩))) (.mul (.const 0x183d3e5de1746bc5f991fdc7bdff1de416961e09afa316004cd7e7b8c1ecd457) (.var ⟨174⟩))) (.mul (.const 0x09d9c2ae4e4cd35e13321baa442ac50944d5e1b3b8773e0a42ccd9ec75d15fe4) (.var ⟨183⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨192⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨201⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨210⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨219⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨228⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨237⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨246⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨255⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨264⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨273⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨282⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨291⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨300⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨309⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨318⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨327⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨336⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨345⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨354⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨363⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨372⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨381⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨390⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨399⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨408⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨417⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨426⟩))) (.mul (.const 0x0fe0012da12045baa459c7ab246f55d29f1ede46d9d46557192101d0c9fc8238) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨428⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨147⟩)) (.mul (.const 0x03fa06bf28fc9a4169c06d622b706b1685756aa36f6ce35fd748292499bf74d9) (.var ⟨156⟩))) (.mul (.const 0x1ee0c32dd8df0feb7dbc59b6666f75920b6ed9c72aa1f04c5f18a92a56fa071d) (.var ⟨165⟩))) (.mul (.const 0x183d3e5de1746bc5f991fdc7bdff1de416961e09afa316004cd7e7b8c1ecd457) (.var ⟨174⟩))) (.mul (.const 0x09d9c2ae4e4cd35e13321baa442ac50944d5e1b3b8773e0a42ccd9ec75d15fe4) (.var ⟨183⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨192⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨201⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨210⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨219⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨228⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨237⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨246⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨255⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨264⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨273⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨282⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨291⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨300⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨309⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨318⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨327⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨336⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨345⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨354⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨363⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨372⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨381⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨390⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨399⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨408⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨417⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨426⟩))) (.mul (.const 0x0fe0012da12045baa459c7ab246f55d29f1ede46d9d46557192101d0c9fc8238) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨430⟩) (.var ⟨431⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨432⟩))),
+  .assert (.add (.var ⟨430⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨429⟩))),
+  .assert (.add (.var ⟨431⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨429⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨433⟩) (.var ⟨434⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨435⟩))),
+  .assert (.add (.var ⟨433⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨432⟩))),
+  .assert (.add (.var ⟨434⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨147⟩)) (.mul (.const 0x03fa06bf28fc9a4169c06d622b706b1685756aa36f6ce35fd748292499bf74d9) (.var ⟨156⟩))) (.mul (.const 0x1ee0c32dd8df0feb7dbc59b6666f75920b6ed9c72aa1f04c5f18a92a56fa071d) (.var ⟨165⟩))) (.mul (.const 0x183d3e5de1746bc5f991fdc7bdff1de416961e09afa316004cd7e7b8c1ecd457) (.var ⟨174⟩))) (.mul (.const 0x09d9c2ae4e4cd35e13321baa442ac50944d5e1b3b8773e0a42ccd9ec75d15fe4) (.var ⟨183⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨192⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨201⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨210⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨219⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨228⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨237⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨246⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨255⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨264⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨273⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨282⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨291⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨300⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨309⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨318⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨327⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨336⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨345⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨354⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨363⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨372⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨381⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨390⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨399⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨408⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨417⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨426⟩))) (.mul (.const 0x0fe0012da12045baa459c7ab246f55d29f1ede46d9d46557192101d0c9fc8238) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨436⟩) (.var ⟨437⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨438⟩))),
+  .assert (.add (.var ⟨436⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨147⟩)) (.mul (.const 0x09fa748c351c3ff3f6d9c514eb8f27db6f33e37e6ba339316b9783cda532accc) (.var ⟨156⟩))) (.mul (.const 0x30e606c1b83d1e43f125b47cff0c2495f3944bf5822289865d5287ab8cc250dc) (.var ⟨165⟩))) (.mul (.const 0x18119f01c5b9ccd31d6538ac7081710615c3c11754a9273ce86031dbe5dd29d3) (.var ⟨174⟩))) (.mul (.const 0x012668d882c4bf96fbaa2d134369df464a8b5098435181ebb073ba9126f2b8f4) (.var ⟨183⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨192⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨201⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨210⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨219⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨228⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨237⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨246⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨255⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨264⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨273⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨282⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨291⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨300⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨309⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨318⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨327⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨336⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨345⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨354⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨363⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨372⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨381⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨390⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨399⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨408⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨417⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨426⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨435⟩))) (.mul (.const 0x07f45e3b101508987cf33c921b014d386468e35e78e392f9698b694ccd3e0d75) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨437⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨147⟩)) (.mul (.const 0x09fa748c351c3ff3f6d9c514eb8f27db6f33e37e6ba339316b9783cda532accc) (.var ⟨156⟩))) (.mul (.const 0x30e606c1b83d1e43f125b47cff0c2495f3944bf5822289865d5287ab8cc250dc) (.var ⟨165⟩))) (.mul (.const 0x18119f01c5b9ccd31d6538ac7081710615c3c11754a9273ce86031dbe5dd29d3) (.var ⟨174⟩))) (.mul (.const 0x012668d882c4bf96fbaa2d134369df464a8b5098435181ebb073ba9126f2b8f4) (.var ⟨183⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨192⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨201⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨210⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨219⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨228⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨237⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨246⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨255⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨264⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨273⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨282⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨291⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨300⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨309⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨318⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨327⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨336⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨345⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨354⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨363⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨372⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨381⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨390⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨399⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨408⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨417⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨426⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨435⟩))) (.mul (.const 0x07f45e3b101508987cf33c921b014d386468e35e78e392f9698b694ccd3e0d75) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨439⟩) (.var ⟨440⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨441⟩))),
+  .assert (.add (.var ⟨439⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨438⟩))),
+  .assert (.add (.var ⟨440⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨438⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨442⟩) (.var ⟨443⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨444⟩))),
+  .assert (.add (.var ⟨442⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨441⟩))),
+  .assert (.add (.var ⟨443⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨147⟩)) (.mul (.const 0x09fa748c351c3ff3f6d9c514eb8f27db6f33e37e6ba339316b9783cda532accc) (.var ⟨156⟩))) (.mul (.const 0x30e606c1b83d1e43f125b47cff0c2495f3944bf5822289865d5287ab8cc250dc) (.var ⟨165⟩))) (.mul (.const 0x18119f01c5b9ccd31d6538ac7081710615c3c11754a9273ce86031dbe5dd29d3) (.var ⟨174⟩))) (.mul (.const 0x012668d882c4bf96fbaa2d134369df464a8b5098435181ebb073ba9126f2b8f4) (.var ⟨183⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨192⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨201⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨210⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨219⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨228⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨237⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨246⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨255⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨264⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨273⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨282⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨291⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨300⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨309⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨318⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨327⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨336⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨345⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨354⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨363⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨372⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨381⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨390⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨399⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨408⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨417⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨426⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨435⟩))) (.mul (.const 0x07f45e3b101508987cf33c921b014d386468e35e78e392f9698b694ccd3e0d75) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨445⟩) (.var ⟨446⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩))),
+  .assert (.add (.var ⟨445⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨147⟩)) (.mul (.const 0x06d33d276cde52cd78fe56e16d0533739ac82a9b42379ac3699debf38848c75c) (.var ⟨156⟩))) (.mul (.const 0x1ba435913ab003826b3888a145a1f8d1f53ba550f104c95274e524c02ece4654) (.var ⟨165⟩))) (.mul (.const 0x269c92561049fed01d55147abe0b4307f6104b284de81c267cfd70f93ea6b74c) (.var ⟨174⟩))) (.mul (.const 0x218a8a16f05c1b5c34d57ad5decb96547783b814f8053825a2380477c260c21d) (.var ⟨183⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨192⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨201⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨210⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨219⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨228⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨237⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨246⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨255⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨264⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨273⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨282⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨291⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨300⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨309⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨318⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨327⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨336⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨345⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨354⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨363⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨372⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨381⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨390⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨399⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨408⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨417⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨426⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨435⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨444⟩))) (.mul (.const 0x0c3ff6c6581d5f999f658a1ec1d4e214a870b8626edb9726e351696e1299770f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨446⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨147⟩)) (.mul (.const 0x06d33d276cde52cd78fe56e16d0533739ac82a9b42379ac3699debf38848c75c) (.var ⟨156⟩))) (.mul (.const 0x1ba435913ab003826b3888a145a1f8d1f53ba550f104c95274e524c02ece4654) (.var ⟨165⟩))) (.mul (.const 0x269c92561049fed01d55147abe0b4307f6104b284de81c267cfd70f93ea6b74c) (.var ⟨174⟩))) (.mul (.const 0x218a8a16f05c1b5c34d57ad5decb96547783b814f8053825a2380477c260c21d) (.var ⟨183⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨192⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨201⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨210⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨219⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨228⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨237⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨246⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨255⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨264⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨273⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨282⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨291⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨300⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨309⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨318⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨327⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨336⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨345⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨354⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨363⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨372⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨381⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨390⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨399⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨408⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨417⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨426⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨435⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨444⟩))) (.mul (.const 0x0c3ff6c6581d5f999f658a1ec1d4e214a870b8626edb9726e351696e1299770f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨448⟩) (.var ⟨449⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨450⟩))),
+  .assert (.add (.var ⟨448⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩))),
+  .assert (.add (.var ⟨449⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨447⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨451⟩) (.var ⟨452⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨453⟩))),
+  .assert (.add (.var ⟨451⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨450⟩))),
+  .assert (.add (.var ⟨452⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨147⟩)) (.mul (.const 0x06d33d276cde52cd78fe56e16d0533739ac82a9b42379ac3699debf38848c75c) (.var ⟨156⟩))) (.mul (.const 0x1ba435913ab003826b3888a145a1f8d1f53ba550f104c95274e524c02ece4654) (.var ⟨165⟩))) (.mul (.const 0x269c92561049fed01d55147abe0b4307f6104b284de81c267cfd70f93ea6b74c) (.var ⟨174⟩))) (.mul (.const 0x218a8a16f05c1b5c34d57ad5decb96547783b814f8053825a2380477c260c21d) (.var ⟨183⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨192⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨201⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨210⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨219⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨228⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨237⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨246⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨255⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨264⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨273⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨282⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨291⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨300⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨309⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨318⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨327⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨336⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨345⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨354⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨363⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨372⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨381⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨390⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨399⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨408⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨417⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨426⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨435⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨444⟩))) (.mul (.const 0x0c3ff6c6581d5f999f658a1ec1d4e214a870b8626edb9726e351696e1299770f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨454⟩) (.var ⟨455⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨456⟩))),
+  .assert (.add (.var ⟨454⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨147⟩)) (.mul (.const 0x2500d943cf08059b0ede5e846e550436bea9a0a144411e5964ef451c4a41a7c5) (.var ⟨156⟩))) (.mul (.const 0x1b908b1be461c5e8d52ac3f40b25e1b8fab71a248c08da8bc0847f52273d2b6a) (.var ⟨165⟩))) (.mul (.const 0x3d48a44f8efda365fd37120481b39bc8d403addb7e1bda9705ce44c80e11b1fd) (.var ⟨174⟩))) (.mul (.const 0x138261713dc4d75e805b49042ba047bac78c3134e0f2f0d1a22f18b77ed52392) (.var ⟨183⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨192⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨201⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨210⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨219⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨228⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨237⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨246⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨255⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨264⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨273⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨282⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨291⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨300⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨309⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨318⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨327⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨336⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨345⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨354⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨363⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨372⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨381⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨390⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨399⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨408⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨417⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨426⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨435⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨444⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨453⟩))) (.mul (.const 0x0deae94d52fcea941871770e541c8d31ae5fae7c55721d8bd6b82400fd239677) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨455⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨147⟩)) (.mul (.const 0x2500d943cf08059b0ede5e846e550436bea9a0a144411e5964ef451c4a41a7c5) (.var ⟨156⟩))) (.mul (.const 0x1b908b1be461c5e8d52ac3f40b25e1b8fab71a248c08da8bc0847f52273d2b6a) (.var ⟨165⟩))) (.mul (.const 0x3d48a44f8efda365fd37120481b39bc8d403addb7e1bda9705ce44c80e11b1fd) (.var ⟨174⟩))) (.mul (.const 0x138261713dc4d75e805b49042ba047bac78c3134e0f2f0d1a22f18b77ed52392) (.var ⟨183⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨192⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨201⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨210⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨219⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨228⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨237⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨246⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨255⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨264⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨273⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨282⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨291⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨300⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨309⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨318⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨327⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨336⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨345⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨354⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨363⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨372⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨381⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨390⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨399⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨408⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨417⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨426⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨435⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨444⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨453⟩))) (.mul (.const 0x0deae94d52fcea941871770e541c8d31ae5fae7c55721d8bd6b82400fd239677) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨457⟩) (.var ⟨458⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨459⟩))),
+  .assert (.add (.var ⟨457⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨456⟩))),
+  .assert (.add (.var ⟨458⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨456⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨460⟩) (.var ⟨461⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨462⟩))),
+  .assert (.add (.var ⟨460⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨459⟩))),
+  .assert (.add (.var ⟨461⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨147⟩)) (.mul (.const 0x2500d943cf08059b0ede5e846e550436bea9a0a144411e5964ef451c4a41a7c5) (.var ⟨156⟩))) (.mul (.const 0x1b908b1be461c5e8d52ac3f40b25e1b8fab71a248c08da8bc0847f52273d2b6a) (.var ⟨165⟩))) (.mul (.const 0x3d48a44f8efda365fd37120481b39bc8d403addb7e1bda9705ce44c80e11b1fd) (.var ⟨174⟩))) (.mul (.const 0x138261713dc4d75e805b49042ba047bac78c3134e0f2f0d1a22f18b77ed52392) (.var ⟨183⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨192⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨201⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨210⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨219⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨228⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨237⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨246⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨255⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨264⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨273⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨282⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨291⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨300⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨309⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨318⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨327⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨336⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨345⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨354⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨363⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨372⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨381⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨390⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨399⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨408⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨417⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨426⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨435⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨444⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨453⟩))) (.mul (.const 0x0deae94d52fcea941871770e541c8d31ae5fae7c55721d8bd6b82400fd239677) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨463⟩) (.var ⟨464⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨465⟩))),
+  .assert (.add (.var ⟨463⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨147⟩)) (.mul (.const 0x1244627bc048acbb42616ec06acc5d3ac1cf81e72dfef5b2b48a16bb0d0e3e8a) (.var ⟨156⟩))) (.mul (.const 0x3a14f0f16214265f2ff56fce71c2810eb879d70f12f4297cc98a9d1c237c9497) (.var ⟨165⟩))) (.mul (.const 0x3f39954b8f0f6a909b25e063c798b023c99400dff638412134771e3797904807) (.var ⟨174⟩))) (.mul (.const 0x28ce24e05aa0e2dfb45f19f43c70d26df4e75ecb784095cc9c0863bc924cc8c5) (.var ⟨183⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨192⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨201⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨210⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨219⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨228⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨237⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨246⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨255⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨264⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨273⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨282⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨291⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨300⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨309⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨318⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨327⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨336⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨345⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨354⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨363⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨372⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨381⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨390⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨399⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨408⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨417⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨426⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨435⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨444⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨453⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨462⟩))) (.mul (.const 0x2968428ef281279b30eb850357376a4670c47f22a6ca83770343358fcdaa5fe7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨464⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨147⟩)) (.mul (.const 0x1244627bc048acbb42616ec06acc5d3ac1cf81e72dfef5b2b48a16bb0d0e3e8a) (.var ⟨156⟩))) (.mul (.const 0x3a14f0f16214265f2ff56fce71c2810eb879d70f12f4297cc98a9d1c237c9497) (.var ⟨165⟩))) (.mul (.const 0x3f39954b8f0f6a909b25e063c798b023c99400dff638412134771e3797904807) (.var ⟨174⟩))) (.mul (.const 0x28ce24e05aa0e2dfb45f19f43c70d26df4e75ecb784095cc9c0863bc924cc8c5) (.var ⟨183⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨192⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨201⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨210⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨219⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨228⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨237⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨246⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨255⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨264⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨273⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨282⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨291⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨300⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨309⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨318⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨327⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨336⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨345⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨354⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨363⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨372⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨381⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨390⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨399⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨408⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨417⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨426⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨435⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨444⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨453⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨462⟩))) (.mul (.const 0x2968428ef281279b30eb850357376a4670c47f22a6ca83770343358fcdaa5fe7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨466⟩) (.var ⟨467⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩))),
+  .assert (.add (.var ⟨466⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨465⟩))),
+  .assert (.add (.var ⟨467⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨465⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨469⟩) (.var ⟨470⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨471⟩))),
+  .assert (.add (.var ⟨469⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨468⟩))),
+  .assert (.add (.var ⟨470⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨147⟩)) (.mul (.const 0x1244627bc048acbb42616ec06acc5d3ac1cf81e72dfef5b2b48a16bb0d0e3e8a) (.var ⟨156⟩))) (.mul (.const 0x3a14f0f16214265f2ff56fce71c2810eb879d70f12f4297cc98a9d1c237c9497) (.var ⟨165⟩))) (.mul (.const 0x3f39954b8f0f6a909b25e063c798b023c99400dff638412134771e3797904807) (.var ⟨174⟩))) (.mul (.const 0x28ce24e05aa0e2dfb45f19f43c70d26df4e75ecb784095cc9c0863bc924cc8c5) (.var ⟨183⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨192⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨201⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨210⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨219⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨228⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨237⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨246⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨255⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨264⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨273⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨282⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨291⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨300⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨309⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨318⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨327⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨336⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨345⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨354⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨363⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨372⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨381⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨390⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨399⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨408⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨417⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨426⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨435⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨444⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨453⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨462⟩))) (.mul (.const 0x2968428ef281279b30eb850357376a4670c47f22a6ca83770343358fcdaa5fe7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨472⟩) (.var ⟨473⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨474⟩))),
+  .assert (.add (.var ⟨472⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨147⟩)) (.mul (.const 0x1ce2e20feb33952e2515faab1bd8382f86f5981f574660aa5e64ca99e39f4384) (.var ⟨156⟩))) (.mul (.const 0x3a1bf54ec7be23684b52a2b8c81dd147ffac6b63d85adb970b43927e77a6943a) (.var ⟨165⟩))) (.mul (.const 0x03dea29d09d254e79f9b85baf7104579cf14bbd02e7f00f8d22369df6642a0c5) (.var ⟨174⟩))) (.mul (.const 0x2783732fe1db91b15de561b9f5d950aea368a44667c507ed44c460fb45608283) (.var ⟨183⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨192⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨201⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨210⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨219⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨228⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨237⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨246⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨255⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨264⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨273⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨282⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨291⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨300⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨309⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨318⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨327⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨336⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨345⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨354⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨363⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨372⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨381⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨390⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨399⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨408⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨417⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨426⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨435⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨444⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨453⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨462⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨471⟩))) (.mul (.const 0x22642a2db080535fad707f07f1924013727c03a3d59de1f54f96bbbf0dddaf90) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨473⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨147⟩)) (.mul (.const 0x1ce2e20feb33952e2515faab1bd8382f86f5981f574660aa5e64ca99e39f4384) (.var ⟨156⟩))) (.mul (.const 0x3a1bf54ec7be23684b52a2b8c81dd147ffac6b63d85adb970b43927e77a6943a) (.var ⟨165⟩))) (.mul (.const 0x03dea29d09d254e79f9b85baf7104579cf14bbd02e7f00f8d22369df6642a0c5) (.var ⟨174⟩))) (.mul (.const 0x2783732fe1db91b15de561b9f5d950aea368a44667c507ed44c460fb45608283) (.var ⟨183⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨192⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨201⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨210⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨219⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨228⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨237⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨246⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨255⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨264⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨273⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨282⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨291⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨300⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨309⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨318⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨327⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨336⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨345⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨354⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨363⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨372⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨381⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨390⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨399⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨408⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨417⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨426⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨435⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨444⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨453⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨462⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨471⟩))) (.mul (.const 0x22642a2db080535fad707f07f1924013727c03a3d59de1f54f96bbbf0dddaf90) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨475⟩) (.var ⟨476⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨477⟩))),
+  .assert (.add (.var ⟨475⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨474⟩))),
+  .assert (.add (.var ⟨476⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨474⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨478⟩) (.var ⟨479⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨480⟩))),
+  .assert (.add (.var ⟨478⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨477⟩))),
+  .assert (.add (.var ⟨479⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨147⟩)) (.mul (.const 0x1ce2e20feb33952e2515faab1bd8382f86f5981f574660aa5e64ca99e39f4384) (.var ⟨156⟩))) (.mul (.const 0x3a1bf54ec7be23684b52a2b8c81dd147ffac6b63d85adb970b43927e77a6943a) (.var ⟨165⟩))) (.mul (.const 0x03dea29d09d254e79f9b85baf7104579cf14bbd02e7f00f8d22369df6642a0c5) (.var ⟨174⟩))) (.mul (.const 0x2783732fe1db91b15de561b9f5d950aea368a44667c507ed44c460fb45608283) (.var ⟨183⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨192⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨201⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨210⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨219⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨228⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨237⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨246⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨255⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨264⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨273⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨282⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨291⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨300⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨309⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨318⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨327⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨336⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨345⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨354⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨363⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨372⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨381⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨390⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨399⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨408⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨417⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨426⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨435⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨444⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨453⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨462⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨471⟩))) (.mul (.const 0x22642a2db080535fad707f07f1924013727c03a3d59de1f54f96bbbf0dddaf90) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨481⟩) (.var ⟨482⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨483⟩))),
+  .assert (.add (.var ⟨481⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨147⟩)) (.mul (.const 0x0235ca162ad59ed0d164ff5f1c9da7018aa99ab7a16ef73a067496276824c6fc) (.var ⟨156⟩))) (.mul (.const 0x2ff30baeedb627c1fae4e025a0e452e10bab88d0f6598897e5a276906cac68eb) (.var ⟨165⟩))) (.mul (.const 0x10603b507f95bdeb3462702a7c3225199ee4672c7a6f847c3aa6d7bebb57ea3c) (.var ⟨174⟩))) (.mul (.const 0x06500cab2d372f33bb0094bdc760d137ddceb2053d3215e56102ef7a5da202da) (.var ⟨183⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨192⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨201⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨210⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨219⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨228⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨237⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨246⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨255⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨264⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨273⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨282⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨291⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨300⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨309⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨318⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨327⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨336⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨345⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨354⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨363⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨372⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨381⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨390⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨399⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨408⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨417⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨426⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨435⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨444⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨453⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨462⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨471⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨480⟩))) (.mul (.const 0x03a25cddf92ee9109095238d7485d02a06b94363bdfd666bd5a39b010098bf0f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨482⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨147⟩)) (.mul (.const 0x0235ca162ad59ed0d164ff5f1c9da7018aa99ab7a16ef73a067496276824c6fc) (.var ⟨156⟩))) (.mul (.const 0x2ff30baeedb627c1fae4e025a0e452e10bab88d0f6598897e5a276906cac68eb) (.var ⟨165⟩))) (.mul (.const 0x10603b507f95bdeb3462702a7c3225199ee4672c7a6f847c3aa6d7bebb57ea3c) (.var ⟨174⟩))) (.mul (.const 0x06500cab2d372f33bb0094bdc760d137ddceb2053d3215e56102ef7a5da202da) (.var ⟨183⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨192⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨201⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨210⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨219⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨228⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨237⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨246⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨255⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨264⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨273⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨282⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨291⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨300⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨309⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨318⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨327⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨336⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨345⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨354⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨363⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨372⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨381⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨390⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨399⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨408⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨417⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨426⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨435⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨444⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨453⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨462⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨471⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨480⟩))) (.mul (.const 0x03a25cddf92ee9109095238d7485d02a06b94363bdfd666bd5a39b010098bf0f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨484⟩) (.var ⟨485⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨486⟩))),
+  .assert (.add (.var ⟨484⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨483⟩))),
+  .assert (.add (.var ⟨485⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨483⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨487⟩) (.var ⟨488⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨489⟩))),
+  .assert (.add (.var ⟨487⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨486⟩))),
+  .assert (.add (.var ⟨488⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨147⟩)) (.mul (.const 0x0235ca162ad59ed0d164ff5f1c9da7018aa99ab7a16ef73a067496276824c6fc) (.var ⟨156⟩))) (.mul (.const 0x2ff30baeedb627c1fae4e025a0e452e10bab88d0f6598897e5a276906cac68eb) (.var ⟨165⟩))) (.mul (.const 0x10603b507f95bdeb3462702a7c3225199ee4672c7a6f847c3aa6d7bebb57ea3c) (.var ⟨174⟩))) (.mul (.const 0x06500cab2d372f33bb0094bdc760d137ddceb2053d3215e56102ef7a5da202da) (.var ⟨183⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨192⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨201⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨210⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨219⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨228⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨237⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨246⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨255⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨264⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨273⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨282⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨291⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨300⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨309⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨318⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨327⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨336⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨345⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨354⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨363⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨372⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨381⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨390⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨399⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨408⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨417⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨426⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨435⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨444⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨453⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨462⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨471⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨480⟩))) (.mul (.const 0x03a25cddf92ee9109095238d7485d02a06b94363bdfd666bd5a39b010098bf0f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨490⟩) (.var ⟨491⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨492⟩))),
+  .assert (.add (.var ⟨490⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨147⟩)) (.mul (.const 0x09e48a9b9f60147334acd044feffe324db6635c406dd4c9b97d5fcc9ac3f3588) (.var ⟨156⟩))) (.mul (.const 0x0073173a4b068b13a769b55b0bc5a23fa41801c63163494ccbcbe6ef568b16f5) (.var ⟨165⟩))) (.mul (.const 0x1a8327144763e71d8393dc39c4c72c49c22c74220685fe0fbce89631d9e5b9ed) (.var ⟨174⟩))) (.mul (.const 0x15312b45c980f751b24a9ba8fb56815c5002989eb38b9a57f28f5378ff3f8fd8) (.var ⟨183⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨192⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨201⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨210⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨219⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨228⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨237⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨246⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨255⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨264⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨273⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨282⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨291⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨300⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨309⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨318⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨327⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨336⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨345⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨354⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨363⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨372⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨381⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨390⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨399⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨408⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨417⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨426⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨435⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨444⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨453⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨462⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨471⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨480⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨489⟩))) (.mul (.const 0x0b6bd8df7529839987505ac9835847e01bd419f5508fc53e8115b58e85a1f002) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨491⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨147⟩)) (.mul (.const 0x09e48a9b9f60147334acd044feffe324db6635c406dd4c9b97d5fcc9ac3f3588) (.var ⟨156⟩))) (.mul (.const 0x0073173a4b068b13a769b55b0bc5a23fa41801c63163494ccbcbe6ef568b16f5) (.var ⟨165⟩))) (.mul (.const 0x1a8327144763e71d8393dc39c4c72c49c22c74220685fe0fbce89631d9e5b9ed) (.var ⟨174⟩))) (.mul (.const 0x15312b45c980f751b24a9ba8fb56815c5002989eb38b9a57f28f5378ff3f8fd8) (.var ⟨183⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨192⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨201⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨210⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨219⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨228⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨237⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨246⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨255⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨264⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨273⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨282⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨291⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨300⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨309⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨318⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨327⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨336⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨345⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨354⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨363⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨372⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨381⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨390⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨399⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨408⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨417⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨426⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨435⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨444⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨453⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨462⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨471⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨480⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨489⟩))) (.mul (.const 0x0b6bd8df7529839987505ac9835847e01bd419f5508fc53e8115b58e85a1f002) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨493⟩) (.var ⟨494⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨495⟩))),
+  .assert (.add (.var ⟨493⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨492⟩))),
+  .assert (.add (.var ⟨494⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨492⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨496⟩) (.var ⟨497⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨498⟩))),
+  .assert (.add (.var ⟨496⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨495⟩))),
+  .assert (.add (.var ⟨497⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨147⟩)) (.mul (.const 0x09e48a9b9f60147334acd044feffe324db6635c406dd4c9b97d5fcc9ac3f3588) (.var ⟨156⟩))) (.mul (.const 0x0073173a4b068b13a769b55b0bc5a23fa41801c63163494ccbcbe6ef568b16f5) (.var ⟨165⟩))) (.mul (.const 0x1a8327144763e71d8393dc39c4c72c49c22c74220685fe0fbce89631d9e5b9ed) (.var ⟨174⟩))) (.mul (.const 0x15312b45c980f751b24a9ba8fb56815c5002989eb38b9a57f28f5378ff3f8fd8) (.var ⟨183⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨192⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨201⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨210⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨219⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨228⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨237⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨246⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨255⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨264⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨273⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨282⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨291⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨300⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨309⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨318⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨327⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨336⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨345⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨354⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨363⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨372⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨381⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨390⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨399⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨408⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨417⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨426⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨435⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨444⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨453⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨462⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨471⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨480⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨489⟩))) (.mul (.const 0x0b6bd8df7529839987505ac9835847e01bd419f5508fc53e8115b58e85a1f002) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨499⟩) (.var ⟨500⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨501⟩))),
+  .assert (.add (.var ⟨499⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨147⟩)) (.mul (.const 0x250d54457d7d31fc9146d642a529e122bc719b0e67e992a471f8857d39a87554) (.var ⟨156⟩))) (.mul (.const 0x1429719a78c47b11d6a5a9d5520d7f4d6d63aeded5e03a8970b430752819fe41) (.var ⟨165⟩))) (.mul (.const 0x2c7192690348930cd424be9d7d5a7a3e3ed9418ab81108f0e3ca97055bd0ee66) (.var ⟨174⟩))) (.mul (.const 0x00bf5c10e2c8d8b37f479c50e2dc97d74f53065ac6d1e8242c4263804fc270fb) (.var ⟨183⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨192⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨201⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨210⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨219⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨228⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨237⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨246⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨255⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨264⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨273⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨282⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨291⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨300⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨309⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨318⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨327⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨336⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨345⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨354⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨363⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨372⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨381⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨390⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨399⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨408⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨417⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨426⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨435⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨444⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨453⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨462⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨471⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨480⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨489⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨498⟩))) (.mul (.const 0x2dc3056c2dd03b5bb6360f08ae9e46b1cedeeeb9d5101f00618a6bd3f1c1ec63) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨500⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨147⟩)) (.mul (.const 0x250d54457d7d31fc9146d642a529e122bc719b0e67e992a471f8857d39a87554) (.var ⟨156⟩))) (.mul (.const 0x1429719a78c47b11d6a5a9d5520d7f4d6d63aeded5e03a8970b430752819fe41) (.var ⟨165⟩))) (.mul (.const 0x2c7192690348930cd424be9d7d5a7a3e3ed9418ab81108f0e3ca97055bd0ee66) (.var ⟨174⟩))) (.mul (.const 0x00bf5c10e2c8d8b37f479c50e2dc97d74f53065ac6d1e8242c4263804fc270fb) (.var ⟨183⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨192⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨201⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨210⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨219⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨228⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨237⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨246⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨255⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨264⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨273⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨282⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨291⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨300⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨309⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨318⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨327⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨336⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨345⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨354⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨363⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨372⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨381⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨390⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨399⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨408⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨417⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨426⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨435⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨444⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨453⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨462⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨471⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨480⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨489⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨498⟩))) (.mul (.const 0x2dc3056c2dd03b5bb6360f08ae9e46b1cedeeeb9d5101f00618a6bd3f1c1ec63) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨502⟩) (.var ⟨503⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨504⟩))),
+  .assert (.add (.var ⟨502⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨501⟩))),
+  .assert (.add (.var ⟨503⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨501⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨505⟩) (.var ⟨506⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨507⟩))),
+  .assert (.add (.var ⟨505⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨504⟩))),
+  .assert (.add (.var ⟨506⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨147⟩)) (.mul (.const 0x250d54457d7d31fc9146d642a529e122bc719b0e67e992a471f8857d39a87554) (.var ⟨156⟩))) (.mul (.const 0x1429719a78c47b11d6a5a9d5520d7f4d6d63aeded5e03a8970b430752819fe41) (.var ⟨165⟩))) (.mul (.const 0x2c7192690348930cd424be9d7d5a7a3e3ed9418ab81108f0e3ca97055bd0ee66) (.var ⟨174⟩))) (.mul (.const 0x00bf5c10e2c8d8b37f479c50e2dc97d74f53065ac6d1e8242c4263804fc270fb) (.var ⟨183⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨192⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨201⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨210⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨219⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨228⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨237⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨246⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨255⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨264⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨273⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨282⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨291⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨300⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨309⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨318⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨327⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨336⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨345⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨354⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨363⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨372⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨381⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨390⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨399⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨408⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨417⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨426⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨435⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨444⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨453⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨462⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨471⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨480⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨489⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨498⟩))) (.mul (.const 0x2dc3056c2dd03b5bb6360f08ae9e46b1cedeeeb9d5101f00618a6bd3f1c1ec63) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨508⟩) (.var ⟨509⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩))),
+  .assert (.add (.var ⟨508⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨147⟩)) (.mul (.const 0x223533bf62c78b1b7129ff953bf4ac85c352873d63a9f69200c86848c30b27a4) (.var ⟨156⟩))) (.mul (.const 0x381568510371ecc7d4e0c3a8c2b82c9fcfedaaee050ad8b019806700eb3df149) (.var ⟨165⟩))) (.mul (.const 0x370e7302b3dec8cbb5670d15cda4d3755dd5fccbee7f12327e7cd89332a205fe) (.var ⟨174⟩))) (.mul (.const 0x1713cd1f9c993124dfa9c5c97a4344e35b9a831022f1d6911ae503e7b8f8bb24) (.var ⟨183⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨192⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨201⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨210⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨219⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨228⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨237⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨246⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨255⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨264⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨273⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨282⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨291⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨300⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨309⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨318⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨327⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨336⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨345⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨354⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨363⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨372⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨381⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨390⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨399⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨408⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨417⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨426⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨435⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨444⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨453⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨462⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨471⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨480⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨489⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨498⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨507⟩))) (.mul (.const 0x19e0791b6ff1ac6ac063d05cf2c4849ef28136e15d26090d911f6a011c6f9fd6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨509⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨147⟩)) (.mul (.const 0x223533bf62c78b1b7129ff953bf4ac85c352873d63a9f69200c86848c30b27a4) (.var ⟨156⟩))) (.mul (.const 0x381568510371ecc7d4e0c3a8c2b82c9fcfedaaee050ad8b019806700eb3df149) (.var ⟨165⟩))) (.mul (.const 0x370e7302b3dec8cbb5670d15cda4d3755dd5fccbee7f12327e7cd89332a205fe) (.var ⟨174⟩))) (.mul (.const 0x1713cd1f9c993124dfa9c5c97a4344e35b9a831022f1d6911ae503e7b8f8bb24) (.var ⟨183⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨192⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨201⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨210⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨219⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨228⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨237⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨246⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨255⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨264⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨273⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨282⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨291⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨300⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨309⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨318⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨327⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨336⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨345⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨354⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨363⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨372⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨381⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨390⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨399⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨408⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨417⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨426⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨435⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨444⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨453⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨462⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨471⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨480⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨489⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨498⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨507⟩))) (.mul (.const 0x19e0791b6ff1ac6ac063d05cf2c4849ef28136e15d26090d911f6a011c6f9fd6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨511⟩) (.var ⟨512⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨513⟩))),
+  .assert (.add (.var ⟨511⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩))),
+  .assert (.add (.var ⟨512⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨510⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨514⟩) (.var ⟨515⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨516⟩))),
+  .assert (.add (.var ⟨514⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨513⟩))),
+  .assert (.add (.var ⟨515⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨147⟩)) (.mul (.const 0x223533bf62c78b1b7129ff953bf4ac85c352873d63a9f69200c86848c30b27a4) (.var ⟨156⟩))) (.mul (.const 0x381568510371ecc7d4e0c3a8c2b82c9fcfedaaee050ad8b019806700eb3df149) (.var ⟨165⟩))) (.mul (.const 0x370e7302b3dec8cbb5670d15cda4d3755dd5fccbee7f12327e7cd89332a205fe) (.var ⟨174⟩))) (.mul (.const 0x1713cd1f9c993124dfa9c5c97a4344e35b9a831022f1d6911ae503e7b8f8bb24) (.var ⟨183⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨192⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨201⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨210⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨219⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨228⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨237⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨246⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨255⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨264⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨273⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨282⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨291⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨300⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨309⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨318⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨327⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨336⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨345⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨354⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨363⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨372⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨381⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨390⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨399⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨408⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨417⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨426⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨435⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨444⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨453⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨462⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨471⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨480⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨489⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨498⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨507⟩))) (.mul (.const 0x19e0791b6ff1ac6ac063d05cf2c4849ef28136e15d26090d911f6a011c6f9fd6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨517⟩) (.var ⟨518⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨519⟩))),
+  .assert (.add (.var ⟨517⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨147⟩)) (.mul (.const 0x389c041046995c23132a04de3575b1cab3e0d4f84fb50213e696957aec2eab81) (.var ⟨156⟩))) (.mul (.const 0x134db433bc2e85637bbae22e0513a1d64fa14b30f78151df4237aa5de91589ae) (.var ⟨165⟩))) (.mul (.const 0x045f776e4da4346a670c3d11d763e464362549a001f6eda206e67c11ebff6181) (.var ⟨174⟩))) (.mul (.const 0x3c1eb63a6ac6b27a01d668435f099308b3cf91fdbb2d43d255d8c5ff0cf5f0ed) (.var ⟨183⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨192⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨201⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨210⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨219⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨228⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨237⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨246⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨255⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨264⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨273⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨282⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨291⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨300⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨309⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨318⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨327⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨336⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨345⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨354⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨363⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨372⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨381⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨390⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨399⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨408⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨417⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨426⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨435⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨444⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨453⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨462⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨471⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨480⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨489⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨498⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨507⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨516⟩))) (.mul (.const 0x111f5a51f706bf0fa2478ab3942b0a06b760420b13d9a824ef39aa966c0f9442) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨518⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨147⟩)) (.mul (.const 0x389c041046995c23132a04de3575b1cab3e0d4f84fb50213e696957aec2eab81) (.var ⟨156⟩))) (.mul (.const 0x134db433bc2e85637bbae22e0513a1d64fa14b30f78151df4237aa5de91589ae) (.var ⟨165⟩))) (.mul (.const 0x045f776e4da4346a670c3d11d763e464362549a001f6eda206e67c11ebff6181) (.var ⟨174⟩))) (.mul (.const 0x3c1eb63a6ac6b27a01d668435f099308b3cf91fdbb2d43d255d8c5ff0cf5f0ed) (.var ⟨183⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨192⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨201⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨210⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨219⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨228⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨237⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨246⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨255⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨264⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨273⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨282⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨291⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨300⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨309⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨318⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨327⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨336⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨345⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨354⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨363⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨372⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨381⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨390⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨399⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨408⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨417⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨426⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨435⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨444⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨453⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨462⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨471⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨480⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨489⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨498⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨507⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨516⟩))) (.mul (.const 0x111f5a51f706bf0fa2478ab3942b0a06b760420b13d9a824ef39aa966c0f9442) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨520⟩) (.var ⟨521⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨522⟩))),
+  .assert (.add (.var ⟨520⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨519⟩))),
+  .assert (.add (.var ⟨521⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨519⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨523⟩) (.var ⟨524⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨525⟩))),
+  .assert (.add (.var ⟨523⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨522⟩))),
+  .assert (.add (.var ⟨524⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨147⟩)) (.mul (.const 0x389c041046995c23132a04de3575b1cab3e0d4f84fb50213e696957aec2eab81) (.var ⟨156⟩))) (.mul (.const 0x134db433bc2e85637bbae22e0513a1d64fa14b30f78151df4237aa5de91589ae) (.var ⟨165⟩))) (.mul (.const 0x045f776e4da4346a670c3d11d763e464362549a001f6eda206e67c11ebff6181) (.var ⟨174⟩))) (.mul (.const 0x3c1eb63a6ac6b27a01d668435f099308b3cf91fdbb2d43d255d8c5ff0cf5f0ed) (.var ⟨183⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨192⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨201⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨210⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨219⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨228⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨237⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨246⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨255⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨264⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨273⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨282⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨291⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨300⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨309⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨318⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨327⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨336⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨345⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨354⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨363⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨372⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨381⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨390⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨399⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨408⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨417⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨426⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨435⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨444⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨453⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨462⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨471⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨480⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨489⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨498⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨507⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨516⟩))) (.mul (.const 0x111f5a51f706bf0fa2478ab3942b0a06b760420b13d9a824ef39aa966c0f9442) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨526⟩) (.var ⟨527⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨528⟩))),
+  .assert (.add (.var ⟨526⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨147⟩)) (.mul (.const 0x382bfb07fdeeb2bc28f8cf07b7630b60282ebfdfca3f63204877ec4e7308192b) (.var ⟨156⟩))) (.mul (.const 0x3500ef399826cf120ba96fbd0e813c5cb5f8f28e9d44d10545d531e2f1aaaafa) (.var ⟨165⟩))) (.mul (.const 0x2be622f43b4c5037f612fdab54fad705417db019c96498d07a1c0e2ed8a164b2) (.var ⟨174⟩))) (.mul (.const 0x1accb00d2a5680bf20dd26cf0eaafc8164e8b3e883dc0ebfed59f2f00c1b7569) (.var ⟨183⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨192⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨201⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨210⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨219⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨228⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨237⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨246⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨255⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨264⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨273⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨282⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨291⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨300⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨309⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨318⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨327⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨336⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨345⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨354⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨363⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨372⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨381⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨390⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨399⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨408⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨417⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨426⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨435⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨444⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨453⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨462⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨471⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨480⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨489⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨498⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨507⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨516⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨525⟩))) (.mul (.const 0x1aa285484c5ab08817edef7fd83d87c3d2afde0f460d2f6859d8e741d497b1ae) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨527⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨147⟩)) (.mul (.const 0x382bfb07fdeeb2bc28f8cf07b7630b60282ebfdfca3f63204877ec4e7308192b) (.var ⟨156⟩))) (.mul (.const 0x3500ef399826cf120ba96fbd0e813c5cb5f8f28e9d44d10545d531e2f1aaaafa) (.var ⟨165⟩))) (.mul (.const 0x2be622f43b4c5037f612fdab54fad705417db019c96498d07a1c0e2ed8a164b2) (.var ⟨174⟩))) (.mul (.const 0x1accb00d2a5680bf20dd26cf0eaafc8164e8b3e883dc0ebfed59f2f00c1b7569) (.var ⟨183⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨192⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨201⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨210⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨219⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨228⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨237⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨246⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨255⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨264⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨273⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨282⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨291⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨300⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨309⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨318⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨327⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨336⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨345⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨354⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨363⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨372⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨381⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨390⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨399⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨408⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨417⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨426⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨435⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨444⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨453⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨462⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨471⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨480⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨489⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨498⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨507⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨516⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨525⟩))) (.mul (.const 0x1aa285484c5ab08817edef7fd83d87c3d2afde0f460d2f6859d8e741d497b1ae) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨529⟩) (.var ⟨530⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩))),
+  .assert (.add (.var ⟨529⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨528⟩))),
+  .assert (.add (.var ⟨530⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨528⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨532⟩) (.var ⟨533⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨534⟩))),
+  .assert (.add (.var ⟨532⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨531⟩))),
+  .assert (.add (.var ⟨533⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨147⟩)) (.mul (.const 0x382bfb07fdeeb2bc28f8cf07b7630b60282ebfdfca3f63204877ec4e7308192b) (.var ⟨156⟩))) (.mul (.const 0x3500ef399826cf120ba96fbd0e813c5cb5f8f28e9d44d10545d531e2f1aaaafa) (.var ⟨165⟩))) (.mul (.const 0x2be622f43b4c5037f612fdab54fad705417db019c96498d07a1c0e2ed8a164b2) (.var ⟨174⟩))) (.mul (.const 0x1accb00d2a5680bf20dd26cf0eaafc8164e8b3e883dc0ebfed59f2f00c1b7569) (.var ⟨183⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨192⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨201⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨210⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨219⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨228⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨237⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨246⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨255⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨264⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨273⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨282⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨291⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨300⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨309⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨318⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨327⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨336⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨345⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨354⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨363⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨372⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨381⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨390⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨399⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨408⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨417⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨426⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨435⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨444⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨453⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨462⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨471⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨480⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨489⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨498⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨507⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨516⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨525⟩))) (.mul (.const 0x1aa285484c5ab08817edef7fd83d87c3d2afde0f460d2f6859d8e741d497b1ae) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨535⟩) (.var ⟨536⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨537⟩))),
+  .assert (.add (.var ⟨535⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨147⟩)) (.mul (.const 0x176014f0a077fbb5d07823bd813d25f7eca1212cd907c8cef6e75e04c97faaae) (.var ⟨156⟩))) (.mul (.const 0x3a952f27d5d93d645251f0cf7ce944f9c8c1ca15401b5ddbabf953792f74cab6) (.var ⟨165⟩))) (.mul (.const 0x1cf24b0fd127df6f75de479ded1b1e30cddf9101a54af577fa26c90893ff17c5) (.var ⟨174⟩))) (.mul (.const 0x1b8bf32155c8b657a4fbf712cd1c638fc40f9eb5008c80d2cec6a814a89a71ef) (.var ⟨183⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨192⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨201⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨210⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨219⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨228⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨237⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨246⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨255⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨264⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨273⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨282⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨291⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨300⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨309⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨318⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨327⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨336⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨345⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨354⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨363⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨372⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨381⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨390⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨399⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨408⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨417⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨426⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨435⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨444⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨453⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨462⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨471⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨480⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨489⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨498⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨507⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨516⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨525⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨534⟩))) (.mul (.const 0x21b38f2f3d56a38a0c3d5ae33926e39e6a2305827e8fd19edca140141c0e09db) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨536⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨147⟩)) (.mul (.const 0x176014f0a077fbb5d07823bd813d25f7eca1212cd907c8cef6e75e04c97faaae) (.var ⟨156⟩))) (.mul (.const 0x3a952f27d5d93d645251f0cf7ce944f9c8c1ca15401b5ddbabf953792f74cab6) (.var ⟨165⟩))) (.mul (.const 0x1cf24b0fd127df6f75de479ded1b1e30cddf9101a54af577fa26c90893ff17c5) (.var ⟨174⟩))) (.mul (.const 0x1b8bf32155c8b657a4fbf712cd1c638fc40f9eb5008c80d2cec6a814a89a71ef) (.var ⟨183⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨192⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨201⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨210⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨219⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨228⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨237⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨246⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨255⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨264⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨273⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨282⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨291⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨300⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨309⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨318⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨327⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨336⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨345⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨354⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨363⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨372⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨381⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨390⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨399⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨408⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨417⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨426⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨435⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨444⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨453⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨462⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨471⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨480⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨489⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨498⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨507⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨516⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨525⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨534⟩))) (.mul (.const 0x21b38f2f3d56a38a0c3d5ae33926e39e6a2305827e8fd19edca140141c0e09db) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨538⟩) (.var ⟨539⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨540⟩))),
+  .assert (.add (.var ⟨538⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨537⟩))),
+  .assert (.add (.var ⟨539⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨537⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨541⟩) (.var ⟨542⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨543⟩))),
+  .assert (.add (.var ⟨541⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨540⟩))),
+  .assert (.add (.var ⟨542⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨147⟩)) (.mul (.const 0x176014f0a077fbb5d07823bd813d25f7eca1212cd907c8cef6e75e04c97faaae) (.var ⟨156⟩))) (.mul (.const 0x3a952f27d5d93d645251f0cf7ce944f9c8c1ca15401b5ddbabf953792f74cab6) (.var ⟨165⟩))) (.mul (.const 0x1cf24b0fd127df6f75de479ded1b1e30cddf9101a54af577fa26c90893ff17c5) (.var ⟨174⟩))) (.mul (.const 0x1b8bf32155c8b657a4fbf712cd1c638fc40f9eb5008c80d2cec6a814a89a71ef) (.var ⟨183⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨192⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨201⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨210⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨219⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨228⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨237⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨246⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨255⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨264⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨273⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨282⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨291⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨300⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨309⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨318⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨327⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨336⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨345⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨354⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨363⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨372⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨381⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨390⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨399⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨408⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨417⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨426⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨435⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨444⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨453⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨462⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨471⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨480⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨489⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨498⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨507⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨516⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨525⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨534⟩))) (.mul (.const 0x21b38f2f3d56a38a0c3d5ae33926e39e6a2305827e8fd19edca140141c0e09db) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨544⟩) (.var ⟨545⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨546⟩))),
+  .assert (.add (.var ⟨544⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨147⟩)) (.mul (.const 0x23ae2da1662a214c26a19b9899d01aba745db0b361110c11201de4099c2e7715) (.var ⟨156⟩))) (.mul (.const 0x287546894f92bc763120f6f1b4c7fa4f501af041d5d40f641db3f84147f24363) (.var ⟨165⟩))) (.mul (.const 0x17e1fa2671a339d61895c4c32132066dd17a1a9b6fc0be3d11c0600fc371cd17) (.var ⟨174⟩))) (.mul (.const 0x184d179c92e8160231bd463a9f2de2b7454fc2f83d439c0c5f9268d96115edbc) (.var ⟨183⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨192⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨201⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨210⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨219⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨228⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨237⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨246⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨255⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨264⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨273⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨282⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨291⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨300⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨309⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨318⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨327⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨336⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨345⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨354⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨363⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨372⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨381⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨390⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨399⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨408⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨417⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨426⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨435⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨444⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨453⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨462⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨471⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨480⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨489⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨498⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨507⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨516⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨525⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨534⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨543⟩))) (.mul (.const 0x11f293f29303735039400c03a835a40e905a12251d194a349267565a43bc0ac1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨545⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨147⟩)) (.mul (.const 0x23ae2da1662a214c26a19b9899d01aba745db0b361110c11201de4099c2e7715) (.var ⟨156⟩))) (.mul (.const 0x287546894f92bc763120f6f1b4c7fa4f501af041d5d40f641db3f84147f24363) (.var ⟨165⟩))) (.mul (.const 0x17e1fa2671a339d61895c4c32132066dd17a1a9b6fc0be3d11c0600fc371cd17) (.var ⟨174⟩))) (.mul (.const 0x184d179c92e8160231bd463a9f2de2b7454fc2f83d439c0c5f9268d96115edbc) (.var ⟨183⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨192⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨201⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨210⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨219⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨228⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨237⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨246⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨255⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨264⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨273⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨282⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨291⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨300⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨309⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨318⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨327⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨336⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨345⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨354⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨363⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨372⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨381⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨390⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨399⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨408⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨417⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨426⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨435⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨444⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨453⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨462⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨471⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨480⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨489⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨498⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨507⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨516⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨525⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨534⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨543⟩))) (.mul (.const 0x11f293f29303735039400c03a835a40e905a12251d194a349267565a43bc0ac1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨547⟩) (.var ⟨548⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨549⟩))),
+  .assert (.add (.var ⟨547⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨546⟩))),
+  .assert (.add (.var ⟨548⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨546⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨550⟩) (.var ⟨551⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨552⟩))),
+  .assert (.add (.var ⟨550⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨549⟩))),
+  .assert (.add (.var ⟨551⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨147⟩)) (.mul (.const 0x23ae2da1662a214c26a19b9899d01aba745db0b361110c11201de4099c2e7715) (.var ⟨156⟩))) (.mul (.const 0x287546894f92bc763120f6f1b4c7fa4f501af041d5d40f641db3f84147f24363) (.var ⟨165⟩))) (.mul (.const 0x17e1fa2671a339d61895c4c32132066dd17a1a9b6fc0be3d11c0600fc371cd17) (.var ⟨174⟩))) (.mul (.const 0x184d179c92e8160231bd463a9f2de2b7454fc2f83d439c0c5f9268d96115edbc) (.var ⟨183⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨192⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨201⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨210⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨219⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨228⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨237⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨246⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨255⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨264⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨273⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨282⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨291⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨300⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨309⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨318⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨327⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨336⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨345⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨354⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨363⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨372⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨381⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨390⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨399⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨408⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨417⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨426⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨435⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨444⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨453⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨462⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨471⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨480⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨489⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨498⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨507⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨516⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨525⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨534⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨543⟩))) (.mul (.const 0x11f293f29303735039400c03a835a40e905a12251d194a349267565a43bc0ac1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨553⟩) (.var ⟨554⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨555⟩))),
+  .assert (.add (.var ⟨553⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨147⟩)) (.mul (.const 0x12ad2fc0d3ba88399f14dd0302129eef2102b98c723786b6579ff2578e680941) (.var ⟨156⟩))) (.mul (.const 0x25535d387233cd28480a7ed60924b7f1559b769061c46a2227e9a2ae88e8eef5) (.var ⟨165⟩))) (.mul (.const 0x061934d439b09383756a923e74d9493a62e20f0b00e54c7c6e09d0e03d288ac9) (.var ⟨174⟩))) (.mul (.const 0x0225801d97737a1a5e8b97a21331c434392766c0840df9633000840079163c5c) (.var ⟨183⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨192⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨201⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨210⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨219⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨228⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨237⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨246⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨255⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨264⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨273⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨282⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨291⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨300⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨309⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨318⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨327⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨336⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨345⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨354⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨363⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨372⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨381⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨390⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨399⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨408⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨417⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨426⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨435⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨444⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨453⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨462⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨471⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨480⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨489⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨498⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨507⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨516⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨525⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨534⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨543⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨552⟩))) (.mul (.const 0x0bd950af788ea3a56040892f6e8ce3d5dd588ecaa4bbf5d50d301d9d629df312) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨554⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨147⟩)) (.mul (.const 0x12ad2fc0d3ba88399f14dd0302129eef2102b98c723786b6579ff2578e680941) (.var ⟨156⟩))) (.mul (.const 0x25535d387233cd28480a7ed60924b7f1559b769061c46a2227e9a2ae88e8eef5) (.var ⟨165⟩))) (.mul (.const 0x061934d439b09383756a923e74d9493a62e20f0b00e54c7c6e09d0e03d288ac9) (.var ⟨174⟩))) (.mul (.const 0x0225801d97737a1a5e8b97a21331c434392766c0840df9633000840079163c5c) (.var ⟨183⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨192⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨201⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨210⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨219⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨228⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨237⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨246⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨255⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨264⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨273⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨282⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨291⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨300⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨309⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨318⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨327⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨336⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨345⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨354⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨363⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨372⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨381⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨390⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨399⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨408⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨417⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨426⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨435⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨444⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨453⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨462⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨471⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨480⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨489⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨498⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨507⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨516⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨525⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨534⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨543⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨552⟩))) (.mul (.const 0x0bd950af788ea3a56040892f6e8ce3d5dd588ecaa4bbf5d50d301d9d629df312) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨556⟩) (.var ⟨557⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨558⟩))),
+  .assert (.add (.var ⟨556⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨555⟩))),
+  .assert (.add (.var ⟨557⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨555⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨559⟩) (.var ⟨560⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨561⟩))),
+  .assert (.add (.var ⟨559⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨558⟩))),
+  .assert (.add (.var ⟨560⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨147⟩)) (.mul (.const 0x12ad2fc0d3ba88399f14dd0302129eef2102b98c723786b6579ff2578e680941) (.var ⟨156⟩))) (.mul (.const 0x25535d387233cd28480a7ed60924b7f1559b769061c46a2227e9a2ae88e8eef5) (.var ⟨165⟩))) (.mul (.const 0x061934d439b09383756a923e74d9493a62e20f0b00e54c7c6e09d0e03d288ac9) (.var ⟨174⟩))) (.mul (.const 0x0225801d97737a1a5e8b97a21331c434392766c0840df9633000840079163c5c) (.var ⟨183⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨192⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨201⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨210⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨219⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨228⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨237⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨246⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨255⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨264⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨273⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨282⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨291⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨300⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨309⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨318⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨327⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨336⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨345⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨354⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨363⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨372⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨381⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨390⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨399⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨408⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨417⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨426⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨435⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨444⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨453⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨462⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨471⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨480⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨489⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨498⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨507⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨516⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨525⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨534⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨543⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨552⟩))) (.mul (.const 0x0bd950af788ea3a56040892f6e8ce3d5dd588ecaa4bbf5d50d301d9d629df312) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨562⟩) (.var ⟨563⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨564⟩))),
+  .assert (.add (.var ⟨562⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨147⟩)) (.mul (.const 0x231ebd21b553548be1ac558371d61443cf459f3e2434a6cb10340f0e410246ca) (.var ⟨156⟩))) (.mul (.const 0x127880d38a243317584085053b953aba6c2c215ba54cdaa8b78f1d2960eae95b) (.var ⟨165⟩))) (.mul (.const 0x1b2622ab8bdf0bc4df085ad76cb3f46a331602f6d296f9fcd011ceffa8949309) (.var ⟨174⟩))) (.mul (.const 0x189cdf8b1c62430e9ecda3a76976634d4c26fc0b79911ad580ad1e9f2862cb98) (.var ⟨183⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨192⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨201⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨210⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨219⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨228⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨237⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨246⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨255⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨264⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨273⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨282⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨291⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨300⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨309⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨318⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨327⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨336⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨345⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨354⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨363⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨372⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨381⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨390⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨399⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨408⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨417⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨426⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨435⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨444⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨453⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨462⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨471⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨480⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨489⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨498⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨507⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨516⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨525⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨534⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨543⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨552⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨561⟩))) (.mul (.const 0x0bd6a60525e300512d56742f579de3b06b560b6dcc0ea6bb117baa4c8caab9e7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨563⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨147⟩)) (.mul (.const 0x231ebd21b553548be1ac558371d61443cf459f3e2434a6cb10340f0e410246ca) (.var ⟨156⟩))) (.mul (.const 0x127880d38a243317584085053b953aba6c2c215ba54cdaa8b78f1d2960eae95b) (.var ⟨165⟩))) (.mul (.const 0x1b2622ab8bdf0bc4df085ad76cb3f46a331602f6d296f9fcd011ceffa8949309) (.var ⟨174⟩))) (.mul (.const 0x189cdf8b1c62430e9ecda3a76976634d4c26fc0b79911ad580ad1e9f2862cb98) (.var ⟨183⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨192⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨201⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨210⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨219⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨228⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨237⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨246⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨255⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨264⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨273⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨282⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨291⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨300⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨309⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨318⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨327⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨336⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨345⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨354⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨363⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨372⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨381⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨390⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨399⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨408⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨417⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨426⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨435⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨444⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨453⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨462⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨471⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨480⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨489⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨498⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨507⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨516⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨525⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨534⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨543⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨552⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨561⟩))) (.mul (.const 0x0bd6a60525e300512d56742f579de3b06b560b6dcc0ea6bb117baa4c8caab9e7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨565⟩) (.var ⟨566⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨567⟩))),
+  .assert (.add (.var ⟨565⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨564⟩))),
+  .assert (.add (.var ⟨566⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨564⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨568⟩) (.var ⟨569⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨570⟩))),
+  .assert (.add (.var ⟨568⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨567⟩))),
+  .assert (.add (.var ⟨569⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨147⟩)) (.mul (.const 0x231ebd21b553548be1ac558371d61443cf459f3e2434a6cb10340f0e410246ca) (.var ⟨156⟩))) (.mul (.const 0x127880d38a243317584085053b953aba6c2c215ba54cdaa8b78f1d2960eae95b) (.var ⟨165⟩))) (.mul (.const 0x1b2622ab8bdf0bc4df085ad76cb3f46a331602f6d296f9fcd011ceffa8949309) (.var ⟨174⟩))) (.mul (.const 0x189cdf8b1c62430e9ecda3a76976634d4c26fc0b79911ad580ad1e9f2862cb98) (.var ⟨183⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨192⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨201⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨210⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨219⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨228⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨237⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨246⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨255⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨264⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨273⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨282⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨291⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨300⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨309⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨318⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨327⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨336⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨345⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨354⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨363⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨372⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨381⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨390⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨399⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨408⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨417⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨426⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨435⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨444⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨453⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨462⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨471⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨480⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨489⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨498⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨507⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨516⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨525⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨534⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨543⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨552⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨561⟩))) (.mul (.const 0x0bd6a60525e300512d56742f579de3b06b560b6dcc0ea6bb117baa4c8caab9e7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨571⟩) (.var ⟨572⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩))),
+  .assert (.add (.var ⟨571⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨147⟩)) (.mul (.const 0x26e64758f2cc14d494e7b05fd6b796a07777e28b0ad661f17ba04b83ad2e3f52) (.var ⟨156⟩))) (.mul (.const 0x0c8abc7b62a3e77cfd859d0c0b706254fb02bf8888d173fa823be9ec7dd08657) (.var ⟨165⟩))) (.mul (.const 0x1ccaf790b0f5834d1fd34a1092a3eb773f5680ff3dc87c753aa8eab4d7a641ec) (.var ⟨174⟩))) (.mul (.const 0x09592a57354b6e277694555f7f42200e2dff7d89f673f63cbf75334663737ddd) (.var ⟨183⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨192⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨201⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨210⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨219⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨228⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨237⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨246⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨255⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨264⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨273⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨282⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨291⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨300⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨309⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨318⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨327⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨336⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨345⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨354⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨363⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨372⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨381⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨390⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨399⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨408⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨417⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨426⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨435⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨444⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨453⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨462⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨471⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨480⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨489⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨498⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨507⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨516⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨525⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨534⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨543⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨552⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨561⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨570⟩))) (.mul (.const 0x24100cbbfbd5de4649aeff7440bbd8ada86c364a703b2ac480d87be38a6beaaf) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨572⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨147⟩)) (.mul (.const 0x26e64758f2cc14d494e7b05fd6b796a07777e28b0ad661f17ba04b83ad2e3f52) (.var ⟨156⟩))) (.mul (.const 0x0c8abc7b62a3e77cfd859d0c0b706254fb02bf8888d173fa823be9ec7dd08657) (.var ⟨165⟩))) (.mul (.const 0x1ccaf790b0f5834d1fd34a1092a3eb773f5680ff3dc87c753aa8eab4d7a641ec) (.var ⟨174⟩))) (.mul (.const 0x09592a57354b6e277694555f7f42200e2dff7d89f673f63cbf75334663737ddd) (.var ⟨183⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨192⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨201⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨210⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨219⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨228⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨237⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨246⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨255⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨264⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨273⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨282⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨291⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨300⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨309⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨318⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨327⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨336⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨345⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨354⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨363⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨372⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨381⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨390⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨399⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨408⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨417⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨426⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨435⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨444⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨453⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨462⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨471⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨480⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨489⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨498⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨507⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨516⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨525⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨534⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨543⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨552⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨561⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨570⟩))) (.mul (.const 0x24100cbbfbd5de4649aeff7440bbd8ada86c364a703b2ac480d87be38a6beaaf) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨574⟩) (.var ⟨575⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨576⟩))),
+  .assert (.add (.var ⟨574⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩))),
+  .assert (.add (.var ⟨575⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨573⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨577⟩) (.var ⟨578⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨579⟩))),
+  .assert (.add (.var ⟨577⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨576⟩))),
+  .assert (.add (.var ⟨578⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨147⟩)) (.mul (.const 0x26e64758f2cc14d494e7b05fd6b796a07777e28b0ad661f17ba04b83ad2e3f52) (.var ⟨156⟩))) (.mul (.const 0x0c8abc7b62a3e77cfd859d0c0b706254fb02bf8888d173fa823be9ec7dd08657) (.var ⟨165⟩))) (.mul (.const 0x1ccaf790b0f5834d1fd34a1092a3eb773f5680ff3dc87c753aa8eab4d7a641ec) (.var ⟨174⟩))) (.mul (.const 0x09592a57354b6e277694555f7f42200e2dff7d89f673f63cbf75334663737ddd) (.var ⟨183⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨192⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨201⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨210⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨219⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨228⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨237⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨246⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨255⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨264⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨273⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨282⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨291⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨300⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨309⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨318⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨327⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨336⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨345⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨354⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨363⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨372⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨381⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨390⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨399⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨408⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨417⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨426⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨435⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨444⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨453⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨462⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨471⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨480⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨489⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨498⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨507⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨516⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨525⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨534⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨543⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨552⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨561⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨570⟩))) (.mul (.const 0x24100cbbfbd5de4649aeff7440bbd8ada86c364a703b2ac480d87be38a6beaaf) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨580⟩) (.var ⟨581⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨582⟩))),
+  .assert (.add (.var ⟨580⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨147⟩)) (.mul (.const 0x3d77966b044cec43f348a2464701e5e826a25bdf52b9aa1992f16afbab0f9d84) (.var ⟨156⟩))) (.mul (.const 0x3251e9e459874a9ed14ff2d67982ae61d47e1667928123cdfeeeae61eb7507ec) (.var ⟨165⟩))) (.mul (.const 0x102fa7a9cedd88257aa7a421d2f3426f31e831ae3ae6bbedd2be683d9b4a4ee4) (.var ⟨174⟩))) (.mul (.const 0x1873a673fde79e65f48590efbdb78d007885c0e16411528c9fff4112737c6908) (.var ⟨183⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨192⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨201⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨210⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨219⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨228⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨237⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨246⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨255⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨264⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨273⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨282⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨291⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨300⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨309⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨318⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨327⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨336⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨345⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨354⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨363⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨372⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨381⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨390⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨399⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨408⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨417⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨426⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨435⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨444⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨453⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨462⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨471⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨480⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨489⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨498⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨507⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨516⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨525⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨534⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨543⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨552⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨561⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨570⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨579⟩))) (.mul (.const 0x0bdfc3aca16e1cdcb7f3cbdb1895a50335e139d4ede2b66dfbd6e83331c39197) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨581⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨147⟩)) (.mul (.const 0x3d77966b044cec43f348a2464701e5e826a25bdf52b9aa1992f16afbab0f9d84) (.var ⟨156⟩))) (.mul (.const 0x3251e9e459874a9ed14ff2d67982ae61d47e1667928123cdfeeeae61eb7507ec) (.var ⟨165⟩))) (.mul (.const 0x102fa7a9cedd88257aa7a421d2f3426f31e831ae3ae6bbedd2be683d9b4a4ee4) (.var ⟨174⟩))) (.mul (.const 0x1873a673fde79e65f48590efbdb78d007885c0e16411528c9fff4112737c6908) (.var ⟨183⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨192⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨201⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨210⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨219⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨228⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨237⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨246⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨255⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨264⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨273⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨282⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨291⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨300⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨309⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨318⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨327⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨336⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨345⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨354⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨363⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨372⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨381⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨390⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨399⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨408⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨417⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨426⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨435⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨444⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨453⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨462⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨471⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨480⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨489⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨498⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨507⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨516⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨525⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨534⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨543⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨552⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨561⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨570⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨579⟩))) (.mul (.const 0x0bdfc3aca16e1cdcb7f3cbdb1895a50335e139d4ede2b66dfbd6e83331c39197) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨583⟩) (.var ⟨584⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨585⟩))),
+  .assert (.add (.var ⟨583⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨582⟩))),
+  .assert (.add (.var ⟨584⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨582⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨586⟩) (.var ⟨587⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨588⟩))),
+  .assert (.add (.var ⟨586⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨585⟩))),
+  .assert (.add (.var ⟨587⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨147⟩)) (.mul (.const 0x3d77966b044cec43f348a2464701e5e826a25bdf52b9aa1992f16afbab0f9d84) (.var ⟨156⟩))) (.mul (.const 0x3251e9e459874a9ed14ff2d67982ae61d47e1667928123cdfeeeae61eb7507ec) (.var ⟨165⟩))) (.mul (.const 0x102fa7a9cedd88257aa7a421d2f3426f31e831ae3ae6bbedd2be683d9b4a4ee4) (.var ⟨174⟩))) (.mul (.const 0x1873a673fde79e65f48590efbdb78d007885c0e16411528c9fff4112737c6908) (.var ⟨183⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨192⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨201⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨210⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨219⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨228⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨237⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨246⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨255⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨264⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨273⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨282⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨291⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨300⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨309⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨318⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨327⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨336⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨345⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨354⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨363⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨372⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨381⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨390⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨399⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨408⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨417⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨426⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨435⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨444⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨453⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨462⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨471⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨480⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨489⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨498⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨507⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨516⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨525⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨534⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨543⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨552⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨561⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨570⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨579⟩))) (.mul (.const 0x0bdfc3aca16e1cdcb7f3cbdb1895a50335e139d4ede2b66dfbd6e83331c39197) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨589⟩) (.var ⟨590⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨591⟩))),
+  .assert (.add (.var ⟨589⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨147⟩)) (.mul (.const 0x2495783cae575a74f32038398f8d4dfc9a929f3ae6ef463b1a24da2b84e79897) (.var ⟨156⟩))) (.mul (.const 0x32399e3c7a825d17976416cfe1f62396d8289b365b4e26b836195839c3ec052e) (.var ⟨165⟩))) (.mul (.const 0x115b8c18f6e05ffbb1a24d1d22efa756e165cc297b224b83878cdd82ee68cc15) (.var ⟨174⟩))) (.mul (.const 0x363bad695becf4c7ac347af11a1197947d6aa7d369b46fe211ad426399b39921) (.var ⟨183⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨192⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨201⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨210⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨219⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨228⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨237⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨246⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨255⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨264⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨273⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨282⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨291⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨300⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨309⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨318⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨327⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨336⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨345⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨354⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨363⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨372⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨381⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨390⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨399⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨408⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨417⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨426⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨435⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨444⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨453⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨462⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨471⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨480⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨489⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨498⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨507⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨516⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨525⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨534⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨543⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨552⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨561⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨570⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨579⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨588⟩))) (.mul (.const 0x2a72055bf5d13d2a4998959d7311c1e10d5dd604006dc81111c9f9194f75a487) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨590⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨147⟩)) (.mul (.const 0x2495783cae575a74f32038398f8d4dfc9a929f3ae6ef463b1a24da2b84e79897) (.var ⟨156⟩))) (.mul (.const 0x32399e3c7a825d17976416cfe1f62396d8289b365b4e26b836195839c3ec052e) (.var ⟨165⟩))) (.mul (.const 0x115b8c18f6e05ffbb1a24d1d22efa756e165cc297b224b83878cdd82ee68cc15) (.var ⟨174⟩))) (.mul (.const 0x363bad695becf4c7ac347af11a1197947d6aa7d369b46fe211ad426399b39921) (.var ⟨183⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨192⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨201⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨210⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨219⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨228⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨237⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨246⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨255⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨264⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨273⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨282⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨291⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨300⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨309⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨318⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨327⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨336⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨345⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨354⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨363⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨372⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨381⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨390⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨399⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨408⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨417⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨426⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨435⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨444⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨453⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨462⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨471⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨480⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨489⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨498⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨507⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨516⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨525⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨534⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨543⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨552⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨561⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨570⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨579⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨588⟩))) (.mul (.const 0x2a72055bf5d13d2a4998959d7311c1e10d5dd604006dc81111c9f9194f75a487) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨592⟩) (.var ⟨593⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩))),
+  .assert (.add (.var ⟨592⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨591⟩))),
+  .assert (.add (.var ⟨593⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨591⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨595⟩) (.var ⟨596⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨597⟩))),
+  .assert (.add (.var ⟨595⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨594⟩))),
+  .assert (.add (.var ⟨596⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨147⟩)) (.mul (.const 0x2495783cae575a74f32038398f8d4dfc9a929f3ae6ef463b1a24da2b84e79897) (.var ⟨156⟩))) (.mul (.const 0x32399e3c7a825d17976416cfe1f62396d8289b365b4e26b836195839c3ec052e) (.var ⟨165⟩))) (.mul (.const 0x115b8c18f6e05ffbb1a24d1d22efa756e165cc297b224b83878cdd82ee68cc15) (.var ⟨174⟩))) (.mul (.const 0x363bad695becf4c7ac347af11a1197947d6aa7d369b46fe211ad426399b39921) (.var ⟨183⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨192⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨201⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨210⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨219⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨228⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨237⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨246⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨255⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨264⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨273⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨282⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨291⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨300⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨309⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨318⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨327⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨336⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨345⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨354⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨363⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨372⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨381⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨390⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨399⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨408⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨417⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨426⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨435⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨444⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨453⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨462⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨471⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨480⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨489⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨498⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨507⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨516⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨525⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨534⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨543⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨552⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨561⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨570⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨579⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨588⟩))) (.mul (.const 0x2a72055bf5d13d2a4998959d7311c1e10d5dd604006dc81111c9f9194f75a487) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨598⟩) (.var ⟨599⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨600⟩))),
+  .assert (.add (.var ⟨598⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨147⟩)) (.mul (.const 0x3431b0a4de6e385a05f4e691f65e9f042ce9e93507255776bdb8c63419c40d2f) (.var ⟨156⟩))) (.mul (.const 0x09f1bb30e5eb81213fe660973e2d0dc0b3f7c43d16218dd398872b30c185be73) (.var ⟨165⟩))) (.mul (.const 0x3ae2d9be7df01bc6d114f9d157e0c9bf490e4f096d74b08ee31ad482372e76c4) (.var ⟨174⟩))) (.mul (.const 0x0b0625637fd7944645c29c9c06381c08c932b2ed4bee4bba0f2cf54f3fb76f12) (.var ⟨183⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨192⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨201⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨210⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨219⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨228⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨237⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨246⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨255⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨264⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨273⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨282⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨291⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨300⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨309⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨318⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨327⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨336⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨345⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨354⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨363⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨372⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨381⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨390⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨399⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨408⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨417⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨426⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨435⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨444⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨453⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨462⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨471⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨480⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨489⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨498⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨507⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨516⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨525⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨534⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨543⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨552⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨561⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨570⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨579⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨588⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨597⟩))) (.mul (.const 0x13190d77a9951b8970771a34beb912926d08b4f13d4eb782d11b8c834cdc751f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨599⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨147⟩)) (.mul (.const 0x3431b0a4de6e385a05f4e691f65e9f042ce9e93507255776bdb8c63419c40d2f) (.var ⟨156⟩))) (.mul (.const 0x09f1bb30e5eb81213fe660973e2d0dc0b3f7c43d16218dd398872b30c185be73) (.var ⟨165⟩))) (.mul (.const 0x3ae2d9be7df01bc6d114f9d157e0c9bf490e4f096d74b08ee31ad482372e76c4) (.var ⟨174⟩))) (.mul (.const 0x0b0625637fd7944645c29c9c06381c08c932b2ed4bee4bba0f2cf54f3fb76f12) (.var ⟨183⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨192⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨201⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨210⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨219⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨228⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨237⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨246⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨255⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨264⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨273⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨282⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨291⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨300⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨309⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨318⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨327⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨336⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨345⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨354⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨363⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨372⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨381⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨390⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨399⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨408⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨417⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨426⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨435⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨444⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨453⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨462⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨471⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨480⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨489⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨498⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨507⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨516⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨525⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨534⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨543⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨552⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨561⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨570⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨579⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨588⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨597⟩))) (.mul (.const 0x13190d77a9951b8970771a34beb912926d08b4f13d4eb782d11b8c834cdc751f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨601⟩) (.var ⟨602⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨603⟩))),
+  .assert (.add (.var ⟨601⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨600⟩))),
+  .assert (.add (.var ⟨602⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨600⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨604⟩) (.var ⟨605⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨606⟩))),
+  .assert (.add (.var ⟨604⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨603⟩))),
+  .assert (.add (.var ⟨605⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨147⟩)) (.mul (.const 0x3431b0a4de6e385a05f4e691f65e9f042ce9e93507255776bdb8c63419c40d2f) (.var ⟨156⟩))) (.mul (.const 0x09f1bb30e5eb81213fe660973e2d0dc0b3f7c43d16218dd398872b30c185be73) (.var ⟨165⟩))) (.mul (.const 0x3ae2d9be7df01bc6d114f9d157e0c9bf490e4f096d74b08ee31ad482372e76c4) (.var ⟨174⟩))) (.mul (.const 0x0b0625637fd7944645c29c9c06381c08c932b2ed4bee4bba0f2cf54f3fb76f12) (.var ⟨183⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨192⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨201⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨210⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨219⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨228⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨237⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨246⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨255⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨264⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨273⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨282⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨291⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨300⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨309⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨318⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨327⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨336⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨345⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨354⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨363⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨372⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨381⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨390⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨399⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨408⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨417⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨426⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨435⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨444⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨453⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨462⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨471⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨480⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨489⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨498⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨507⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨516⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨525⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨534⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨543⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨552⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨561⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨570⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨579⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨588⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨597⟩))) (.mul (.const 0x13190d77a9951b8970771a34beb912926d08b4f13d4eb782d11b8c834cdc751f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨607⟩) (.var ⟨608⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨609⟩))),
+  .assert (.add (.var ⟨607⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨147⟩)) (.mul (.const 0x1b0bb16ede86a7e0ad7cb84f794fc666c3dea60836c2f8b975e7398df1cdcd8f) (.var ⟨156⟩))) (.mul (.const 0x077918fdfcf5b05bf3215923848e870100337d5dee6654f85135171635242643) (.var ⟨165⟩))) (.mul (.const 0x2b74a1611e3406da8025dc9dda81d3fd6e0b7bf2bf65e9373772d2de1ee75022) (.var ⟨174⟩))) (.mul (.const 0x331199e5fe04eaadb3aa3bdc38222b8b02c35b0e5d5cfa9b8c6efca7bcb14fcd) (.var ⟨183⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨192⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨201⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨210⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨219⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨228⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨237⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨246⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨255⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨264⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨273⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨282⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨291⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨300⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨309⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨318⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨327⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨336⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨345⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨354⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨363⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨372⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨381⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨390⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨399⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨408⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨417⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨426⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨435⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨444⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨453⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨462⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨471⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨480⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨489⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨498⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨507⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨516⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨525⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨534⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨543⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨552⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨561⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨570⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨579⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨588⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨597⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨606⟩))) (.mul (.const 0x083fc05554dbbd5b941675288008b47eb471ba8392a9e1a6203b6a147363f4b7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨608⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨147⟩)) (.mul (.const 0x1b0bb16ede86a7e0ad7cb84f794fc666c3dea60836c2f8b975e7398df1cdcd8f) (.var ⟨156⟩))) (.mul (.const 0x077918fdfcf5b05bf3215923848e870100337d5dee6654f85135171635242643) (.var ⟨165⟩))) (.mul (.const 0x2b74a1611e3406da8025dc9dda81d3fd6e0b7bf2bf65e9373772d2de1ee75022) (.var ⟨174⟩))) (.mul (.const 0x331199e5fe04eaadb3aa3bdc38222b8b02c35b0e5d5cfa9b8c6efca7bcb14fcd) (.var ⟨183⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨192⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨201⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨210⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨219⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨228⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨237⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨246⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨255⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨264⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨273⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨282⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨291⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨300⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨309⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨318⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨327⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨336⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨345⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨354⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨363⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨372⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨381⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨390⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨399⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨408⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨417⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨426⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨435⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨444⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨453⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨462⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨471⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨480⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨489⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨498⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨507⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨516⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨525⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨534⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨543⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨552⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨561⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨570⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨579⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨588⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨597⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨606⟩))) (.mul (.const 0x083fc05554dbbd5b941675288008b47eb471ba8392a9e1a6203b6a147363f4b7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨610⟩) (.var ⟨611⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨612⟩))),
+  .assert (.add (.var ⟨610⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨609⟩))),
+  .assert (.add (.var ⟨611⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨609⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨613⟩) (.var ⟨614⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨615⟩))),
+  .assert (.add (.var ⟨613⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨612⟩))),
+  .assert (.add (.var ⟨614⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨147⟩)) (.mul (.const 0x1b0bb16ede86a7e0ad7cb84f794fc666c3dea60836c2f8b975e7398df1cdcd8f) (.var ⟨156⟩))) (.mul (.const 0x077918fdfcf5b05bf3215923848e870100337d5dee6654f85135171635242643) (.var ⟨165⟩))) (.mul (.const 0x2b74a1611e3406da8025dc9dda81d3fd6e0b7bf2bf65e9373772d2de1ee75022) (.var ⟨174⟩))) (.mul (.const 0x331199e5fe04eaadb3aa3bdc38222b8b02c35b0e5d5cfa9b8c6efca7bcb14fcd) (.var ⟨183⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨192⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨201⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨210⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨219⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨228⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨237⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨246⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨255⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨264⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨273⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨282⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨291⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨300⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨309⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨318⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨327⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨336⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨345⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨354⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨363⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨372⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨381⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨390⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨399⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨408⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨417⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨426⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨435⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨444⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨453⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨462⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨471⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨480⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨489⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨498⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨507⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨516⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨525⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨534⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨543⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨552⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨561⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨570⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨579⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨588⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨597⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨606⟩))) (.mul (.const 0x083fc05554dbbd5b941675288008b47eb471ba8392a9e1a6203b6a147363f4b7) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨616⟩) (.var ⟨617⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨618⟩))),
+  .assert (.add (.var ⟨616⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨147⟩)) (.mul (.const 0x2f6ef85600e2e6a7ee0c9e9541160308968a77771050f3c06810d31dfd3762ab) (.var ⟨156⟩))) (.mul (.const 0x3976794670a0c843411fed70c7efa7185e31c22dd7be23f5e100b0d593a391c5) (.var ⟨165⟩))) (.mul (.const 0x384e84fcc01327c9d0e7407d84c0cfc477927fa9ab0d1b0d7bfe53ece240bf6a) (.var ⟨174⟩))) (.mul (.const 0x1e1d2fa3bc7f9109835a8db4d1aefd87fb45a1fbf907947e278d0678d84c07f6) (.var ⟨183⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨192⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨201⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨210⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨219⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨228⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨237⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨246⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨255⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨264⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨273⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨282⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨291⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨300⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨309⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨318⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨327⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨336⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨345⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨354⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨363⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨372⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨381⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨390⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨399⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨408⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨417⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨426⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨435⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨444⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨453⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨462⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨471⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨480⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨489⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨498⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨507⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨516⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨525⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨534⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨543⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨552⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨561⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨570⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨579⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨588⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨597⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨606⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨615⟩))) (.mul (.const 0x072c574c3e4a22110d81698cb924dbea866fa7d9606ef6fd2158769bdb52eede) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨617⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨147⟩)) (.mul (.const 0x2f6ef85600e2e6a7ee0c9e9541160308968a77771050f3c06810d31dfd3762ab) (.var ⟨156⟩))) (.mul (.const 0x3976794670a0c843411fed70c7efa7185e31c22dd7be23f5e100b0d593a391c5) (.var ⟨165⟩))) (.mul (.const 0x384e84fcc01327c9d0e7407d84c0cfc477927fa9ab0d1b0d7bfe53ece240bf6a) (.var ⟨174⟩))) (.mul (.const 0x1e1d2fa3bc7f9109835a8db4d1aefd87fb45a1fbf907947e278d0678d84c07f6) (.var ⟨183⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨192⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨201⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨210⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨219⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨228⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨237⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨246⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨255⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨264⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨273⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨282⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨291⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨300⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨309⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨318⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨327⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨336⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨345⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨354⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨363⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨372⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨381⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨390⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨399⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨408⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨417⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨426⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨435⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨444⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨453⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨462⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨471⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨480⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨489⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨498⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨507⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨516⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨525⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨534⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨543⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨552⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨561⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨570⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨579⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨588⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨597⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨606⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨615⟩))) (.mul (.const 0x072c574c3e4a22110d81698cb924dbea866fa7d9606ef6fd2158769bdb52eede) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨619⟩) (.var ⟨620⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨621⟩))),
+  .assert (.add (.var ⟨619⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨618⟩))),
+  .assert (.add (.var ⟨620⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨618⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨622⟩) (.var ⟨623⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨624⟩))),
+  .assert (.add (.var ⟨622⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨621⟩))),
+  .assert (.add (.var ⟨623⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨147⟩)) (.mul (.const 0x2f6ef85600e2e6a7ee0c9e9541160308968a77771050f3c06810d31dfd3762ab) (.var ⟨156⟩))) (.mul (.const 0x3976794670a0c843411fed70c7efa7185e31c22dd7be23f5e100b0d593a391c5) (.var ⟨165⟩))) (.mul (.const 0x384e84fcc01327c9d0e7407d84c0cfc477927fa9ab0d1b0d7bfe53ece240bf6a) (.var ⟨174⟩))) (.mul (.const 0x1e1d2fa3bc7f9109835a8db4d1aefd87fb45a1fbf907947e278d0678d84c07f6) (.var ⟨183⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨192⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨201⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨210⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨219⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨228⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨237⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨246⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨255⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨264⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨273⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨282⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨291⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨300⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨309⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨318⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨327⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨336⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨345⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨354⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨363⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨372⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨381⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨390⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨399⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨408⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨417⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨426⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨435⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨444⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨453⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨462⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨471⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨480⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨489⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨498⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨507⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨516⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨525⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨534⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨543⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨552⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨561⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨570⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨579⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨588⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨597⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨606⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨615⟩))) (.mul (.const 0x072c574c3e4a22110d81698cb924dbea866fa7d9606ef6fd2158769bdb52eede) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨625⟩) (.var ⟨626⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨627⟩))),
+  .assert (.add (.var ⟨625⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨147⟩)) (.mul (.const 0x24173a57cc575bbbc428dfd2d2795c232f5df764d6d8d882d11fee6cade1712a) (.var ⟨156⟩))) (.mul (.const 0x0201d8e2208c31f836b22fc4d585dad0103e11aa2e146b280241511e53a13489) (.var ⟨165⟩))) (.mul (.const 0x078f84ae6452886adbd0f278a38350db0cb217d45487b27b40db085fc42caa9c) (.var ⟨174⟩))) (.mul (.const 0x36f7dc641765ca5f6fabe88729db44b069a9ac63d1aa79741ca37c12af6ebb17) (.var ⟨183⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨192⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨201⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨210⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨219⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨228⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨237⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨246⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨255⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨264⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨273⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨282⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨291⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨300⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨309⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨318⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨327⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨336⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨345⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨354⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨363⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨372⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨381⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨390⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨399⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨408⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨417⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨426⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨435⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨444⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨453⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨462⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨471⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨480⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨489⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨498⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨507⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨516⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨525⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨534⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨543⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨552⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨561⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨570⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨579⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨588⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨597⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨606⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨615⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨624⟩))) (.mul (.const 0x1f75b49e4b66ddf8e0185ed87ff069e15684f2e471a58c52bfeb78cb3e04592c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨626⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨147⟩)) (.mul (.const 0x24173a57cc575bbbc428dfd2d2795c232f5df764d6d8d882d11fee6cade1712a) (.var ⟨156⟩))) (.mul (.const 0x0201d8e2208c31f836b22fc4d585dad0103e11aa2e146b280241511e53a13489) (.var ⟨165⟩))) (.mul (.const 0x078f84ae6452886adbd0f278a38350db0cb217d45487b27b40db085fc42caa9c) (.var ⟨174⟩))) (.mul (.const 0x36f7dc641765ca5f6fabe88729db44b069a9ac63d1aa79741ca37c12af6ebb17) (.var ⟨183⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨192⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨201⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨210⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨219⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨228⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨237⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨246⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨255⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨264⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨273⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨282⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨291⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨300⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨309⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨318⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨327⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨336⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨345⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨354⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨363⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨372⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨381⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨390⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨399⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨408⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨417⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨426⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨435⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨444⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨453⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨462⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨471⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨480⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨489⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨498⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨507⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨516⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨525⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨534⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨543⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨552⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨561⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨570⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨579⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨588⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨597⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨606⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨615⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨624⟩))) (.mul (.const 0x1f75b49e4b66ddf8e0185ed87ff069e15684f2e471a58c52bfeb78cb3e04592c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨628⟩) (.var ⟨629⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨630⟩))),
+  .assert (.add (.var ⟨628⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨627⟩))),
+  .assert (.add (.var ⟨629⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨627⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨631⟩) (.var ⟨632⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨633⟩))),
+  .assert (.add (.var ⟨631⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨630⟩))),
+  .assert (.add (.var ⟨632⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨147⟩)) (.mul (.const 0x24173a57cc575bbbc428dfd2d2795c232f5df764d6d8d882d11fee6cade1712a) (.var ⟨156⟩))) (.mul (.const 0x0201d8e2208c31f836b22fc4d585dad0103e11aa2e146b280241511e53a13489) (.var ⟨165⟩))) (.mul (.const 0x078f84ae6452886adbd0f278a38350db0cb217d45487b27b40db085fc42caa9c) (.var ⟨174⟩))) (.mul (.const 0x36f7dc641765ca5f6fabe88729db44b069a9ac63d1aa79741ca37c12af6ebb17) (.var ⟨183⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨192⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨201⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨210⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨219⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨228⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨237⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨246⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨255⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨264⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨273⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨282⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨291⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨300⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨309⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨318⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨327⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨336⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨345⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨354⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨363⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨372⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨381⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨390⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨399⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨408⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨417⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨426⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨435⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨444⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨453⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨462⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨471⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨480⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨489⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨498⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨507⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨516⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨525⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨534⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨543⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨552⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨561⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨570⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨579⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨588⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨597⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨606⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨615⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨624⟩))) (.mul (.const 0x1f75b49e4b66ddf8e0185ed87ff069e15684f2e471a58c52bfeb78cb3e04592c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨634⟩) (.var ⟨635⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩))),
+  .assert (.add (.var ⟨634⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨147⟩)) (.mul (.const 0x17c8447d2348a6b527427ea6e7fc4bf1af93a2c9c702cad432bf686f43bd1056) (.var ⟨156⟩))) (.mul (.const 0x2acbdcf1ddeb8481faffd93d52969229e3a22401cd26be5be8d30297a87aaf78) (.var ⟨165⟩))) (.mul (.const 0x2f25c247bb5bf2ca185d4395402e6111338f2bc564883a9f713ef1cf20c1c2bc) (.var ⟨174⟩))) (.mul (.const 0x395cf2f45f73997c2da00d66e9f473995a3f53fd8eada10441aa6accb4f78d9a) (.var ⟨183⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨192⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨201⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨210⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨219⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨228⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨237⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨246⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨255⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨264⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨273⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨282⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨291⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨300⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨309⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨318⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨327⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨336⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨345⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨354⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨363⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨372⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨381⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨390⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨399⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨408⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨417⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨426⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨435⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨444⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨453⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨462⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨471⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨480⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨489⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨498⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨507⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨516⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨525⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨534⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨543⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨552⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨561⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨570⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨579⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨588⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨597⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨606⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨615⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨624⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨633⟩))) (.mul (.const 0x0a9547205f258106d38d05b6aab8d1e119a178ce9e007c0334f96b56f17f3cb5) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨635⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨147⟩)) (.mul (.const 0x17c8447d2348a6b527427ea6e7fc4bf1af93a2c9c702cad432bf686f43bd1056) (.var ⟨156⟩))) (.mul (.const 0x2acbdcf1ddeb8481faffd93d52969229e3a22401cd26be5be8d30297a87aaf78) (.var ⟨165⟩))) (.mul (.const 0x2f25c247bb5bf2ca185d4395402e6111338f2bc564883a9f713ef1cf20c1c2bc) (.var ⟨174⟩))) (.mul (.const 0x395cf2f45f73997c2da00d66e9f473995a3f53fd8eada10441aa6accb4f78d9a) (.var ⟨183⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨192⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨201⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨210⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨219⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨228⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨237⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨246⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨255⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨264⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨273⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨282⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨291⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨300⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨309⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨318⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨327⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨336⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨345⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨354⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨363⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨372⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨381⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨390⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨399⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨408⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨417⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨426⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨435⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨444⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨453⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨462⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨471⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨480⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨489⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨498⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨507⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨516⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨525⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨534⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨543⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨552⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨561⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨570⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨579⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨588⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨597⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨606⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨615⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨624⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨633⟩))) (.mul (.const 0x0a9547205f258106d38d05b6aab8d1e119a178ce9e007c0334f96b56f17f3cb5) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨637⟩) (.var ⟨638⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨639⟩))),
+  .assert (.add (.var ⟨637⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩))),
+  .assert (.add (.var ⟨638⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨636⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨640⟩) (.var ⟨641⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨642⟩))),
+  .assert (.add (.var ⟨640⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨639⟩))),
+  .assert (.add (.var ⟨641⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨147⟩)) (.mul (.const 0x17c8447d2348a6b527427ea6e7fc4bf1af93a2c9c702cad432bf686f43bd1056) (.var ⟨156⟩))) (.mul (.const 0x2acbdcf1ddeb8481faffd93d52969229e3a22401cd26be5be8d30297a87aaf78) (.var ⟨165⟩))) (.mul (.const 0x2f25c247bb5bf2ca185d4395402e6111338f2bc564883a9f713ef1cf20c1c2bc) (.var ⟨174⟩))) (.mul (.const 0x395cf2f45f73997c2da00d66e9f473995a3f53fd8eada10441aa6accb4f78d9a) (.var ⟨183⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨192⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨201⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨210⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨219⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨228⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨237⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨246⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨255⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨264⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨273⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨282⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨291⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨300⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨309⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨318⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨327⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨336⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨345⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨354⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨363⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨372⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨381⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨390⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨399⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨408⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨417⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨426⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨435⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨444⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨453⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨462⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨471⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨480⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨489⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨498⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨507⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨516⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨525⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨534⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨543⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨552⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨561⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨570⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨579⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨588⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨597⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨606⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨615⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨624⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨633⟩))) (.mul (.const 0x0a9547205f258106d38d05b6aab8d1e119a178ce9e007c0334f96b56f17f3cb5) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨643⟩) (.var ⟨644⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨645⟩))),
+  .assert (.add (.var ⟨643⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨147⟩)) (.mul (.const 0x120a52c17b290788c1d7b906f7eb5391dfbceb5c9d46d5493bd042e957aab627) (.var ⟨156⟩))) (.mul (.const 0x3eba0414a6c4a3e3177ab62089432c48159a50bea2cbca0bdfd647e7190a0f57) (.var ⟨165⟩))) (.mul (.const 0x315100339114daafd22b33257914579b2bed94914904e70ab9c168902fc69b86) (.var ⟨174⟩))) (.mul (.const 0x39a53b35ba378ceaf523e60ccbe9ac03cc2dd89ef34d724371ea10d83a352245) (.var ⟨183⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨192⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨201⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨210⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨219⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨228⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨237⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨246⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨255⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨264⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨273⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨282⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨291⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨300⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨309⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨318⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨327⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨336⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨345⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨354⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨363⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨372⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨381⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨390⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨399⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨408⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨417⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨426⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨435⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨444⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨453⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨462⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨471⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨480⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨489⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨498⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨507⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨516⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨525⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨534⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨543⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨552⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨561⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨570⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨579⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨588⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨597⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨606⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨615⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨624⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨633⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨642⟩))) (.mul (.const 0x3cb64d0e96471507a98ff99d965dec3505427c546d4a6f8401f573c633ebfb84) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨644⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨147⟩)) (.mul (.const 0x120a52c17b290788c1d7b906f7eb5391dfbceb5c9d46d5493bd042e957aab627) (.var ⟨156⟩))) (.mul (.const 0x3eba0414a6c4a3e3177ab62089432c48159a50bea2cbca0bdfd647e7190a0f57) (.var ⟨165⟩))) (.mul (.const 0x315100339114daafd22b33257914579b2bed94914904e70ab9c168902fc69b86) (.var ⟨174⟩))) (.mul (.const 0x39a53b35ba378ceaf523e60ccbe9ac03cc2dd89ef34d724371ea10d83a352245) (.var ⟨183⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨192⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨201⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨210⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨219⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨228⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨237⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨246⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨255⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨264⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨273⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨282⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨291⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨300⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨309⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨318⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨327⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨336⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨345⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨354⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨363⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨372⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨381⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨390⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨399⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨408⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨417⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨426⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨435⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨444⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨453⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨462⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨471⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨480⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨489⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨498⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨507⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨516⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨525⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨534⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨543⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨552⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨561⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨570⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨579⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨588⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨597⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨606⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨615⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨624⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨633⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨642⟩))) (.mul (.const 0x3cb64d0e96471507a98ff99d965dec3505427c546d4a6f8401f573c633ebfb84) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨646⟩) (.var ⟨647⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨648⟩))),
+  .assert (.add (.var ⟨646⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨645⟩))),
+  .assert (.add (.var ⟨647⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨645⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨649⟩) (.var ⟨650⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨651⟩))),
+  .assert (.add (.var ⟨649⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨648⟩))),
+  .assert (.add (.var ⟨650⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨147⟩)) (.mul (.const 0x120a52c17b290788c1d7b906f7eb5391dfbceb5c9d46d5493bd042e957aab627) (.var ⟨156⟩))) (.mul (.const 0x3eba0414a6c4a3e3177ab62089432c48159a50bea2cbca0bdfd647e7190a0f57) (.var ⟨165⟩))) (.mul (.const 0x315100339114daafd22b33257914579b2bed94914904e70ab9c168902fc69b86) (.var ⟨174⟩))) (.mul (.const 0x39a53b35ba378ceaf523e60ccbe9ac03cc2dd89ef34d724371ea10d83a352245) (.var ⟨183⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨192⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨201⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨210⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨219⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨228⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨237⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨246⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨255⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨264⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨273⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨282⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨291⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨300⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨309⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨318⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨327⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨336⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨345⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨354⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨363⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨372⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨381⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨390⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨399⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨408⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨417⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨426⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨435⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨444⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨453⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨462⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨471⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨480⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨489⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨498⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨507⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨516⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨525⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨534⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨543⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨552⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨561⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨570⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨579⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨588⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨597⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨606⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨615⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨624⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨633⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨642⟩))) (.mul (.const 0x3cb64d0e96471507a98ff99d965dec3505427c546d4a6f8401f573c633ebfb84) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨652⟩) (.var ⟨653⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨654⟩))),
+  .assert (.add (.var ⟨652⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨147⟩)) (.mul (.const 0x39f170d0818b44f2fa99c6e6d3edc02331f6964c46c71d197577de4e4bdef3b0) (.var ⟨156⟩))) (.mul (.const 0x3838f3ad7e71cfeb66d4547a42dbc2fc8f4d6b3dfa8c5234e0099522351fe1bd) (.var ⟨165⟩))) (.mul (.const 0x3b5972ca194b12386cce20256c0cf87d789229f7dad14fedb94d31bb95bbbb57) (.var ⟨174⟩))) (.mul (.const 0x137d485d8ce79c82627f9ea8825a472029678bde1dd2bf5baa844fe4d3b829a8) (.var ⟨183⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨192⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨201⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨210⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨219⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨228⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨237⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨246⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨255⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨264⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨273⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨282⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨291⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨300⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨309⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨318⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨327⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨336⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨345⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨354⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨363⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨372⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨381⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨390⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨399⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨408⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨417⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨426⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨435⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨444⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨453⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨462⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨471⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨480⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨489⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨498⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨507⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨516⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨525⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨534⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨543⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨552⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨561⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨570⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨579⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨588⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨597⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨606⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨615⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨624⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨633⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨642⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨651⟩))) (.mul (.const 0x01268bf74bcf42bd0b3463408beeeff7ded7d9b4f27020cf144753bc0e258c7f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨653⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨147⟩)) (.mul (.const 0x39f170d0818b44f2fa99c6e6d3edc02331f6964c46c71d197577de4e4bdef3b0) (.var ⟨156⟩))) (.mul (.const 0x3838f3ad7e71cfeb66d4547a42dbc2fc8f4d6b3dfa8c5234e0099522351fe1bd) (.var ⟨165⟩))) (.mul (.const 0x3b5972ca194b12386cce20256c0cf87d789229f7dad14fedb94d31bb95bbbb57) (.var ⟨174⟩))) (.mul (.const 0x137d485d8ce79c82627f9ea8825a472029678bde1dd2bf5baa844fe4d3b829a8) (.var ⟨183⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨192⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨201⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨210⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨219⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨228⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨237⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨246⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨255⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨264⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨273⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨282⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨291⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨300⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨309⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨318⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨327⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨336⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨345⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨354⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨363⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨372⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨381⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨390⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨399⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨408⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨417⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨426⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨435⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨444⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨453⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨462⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨471⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨480⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨489⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨498⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨507⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨516⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨525⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨534⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨543⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨552⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨561⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨570⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨579⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨588⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨597⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨606⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨615⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨624⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨633⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨642⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨651⟩))) (.mul (.const 0x01268bf74bcf42bd0b3463408beeeff7ded7d9b4f27020cf144753bc0e258c7f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨655⟩) (.var ⟨656⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩))),
+  .assert (.add (.var ⟨655⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨654⟩))),
+  .assert (.add (.var ⟨656⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨654⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨658⟩) (.var ⟨659⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨660⟩))),
+  .assert (.add (.var ⟨658⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨657⟩))),
+  .assert (.add (.var ⟨659⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨147⟩)) (.mul (.const 0x39f170d0818b44f2fa99c6e6d3edc02331f6964c46c71d197577de4e4bdef3b0) (.var ⟨156⟩))) (.mul (.const 0x3838f3ad7e71cfeb66d4547a42dbc2fc8f4d6b3dfa8c5234e0099522351fe1bd) (.var ⟨165⟩))) (.mul (.const 0x3b5972ca194b12386cce20256c0cf87d789229f7dad14fedb94d31bb95bbbb57) (.var ⟨174⟩))) (.mul (.const 0x137d485d8ce79c82627f9ea8825a472029678bde1dd2bf5baa844fe4d3b829a8) (.var ⟨183⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨192⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨201⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨210⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨219⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨228⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨237⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨246⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨255⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨264⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨273⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨282⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨291⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨300⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨309⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨318⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨327⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨336⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨345⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨354⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨363⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨372⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨381⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨390⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨399⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨408⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨417⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨426⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨435⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨444⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨453⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨462⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨471⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨480⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨489⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨498⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨507⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨516⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨525⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨534⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨543⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨552⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨561⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨570⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨579⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨588⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨597⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨606⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨615⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨624⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨633⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨642⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨651⟩))) (.mul (.const 0x01268bf74bcf42bd0b3463408beeeff7ded7d9b4f27020cf144753bc0e258c7f) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨661⟩) (.var ⟨662⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨663⟩))),
+  .assert (.add (.var ⟨661⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨147⟩)) (.mul (.const 0x0eac3ca1e396ee9c59c5785280a5d09f9e8f75597f469fbc834c1ca183332e5c) (.var ⟨156⟩))) (.mul (.const 0x337655ee8c3a38e3701cb231a347f7a85daf90d7e052cd611c9199c44568baf4) (.var ⟨165⟩))) (.mul (.const 0x1faba0d7472b4552d6e9a663b41dde25acc169fb4997c9651973851846dc2806) (.var ⟨174⟩))) (.mul (.const 0x218788ceef8151b199f39d45f5c4629cdefd250fb16d90935676a7415fdc2cdb) (.var ⟨183⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨192⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨201⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨210⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨219⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨228⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨237⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨246⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨255⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨264⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨273⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨282⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨291⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨300⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨309⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨318⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨327⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨336⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨345⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨354⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨363⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨372⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨381⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨390⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨399⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨408⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨417⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨426⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨435⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨444⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨453⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨462⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨471⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨480⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨489⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨498⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨507⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨516⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨525⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨534⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨543⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨552⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨561⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨570⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨579⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨588⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨597⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨606⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨615⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨624⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨633⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨642⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨651⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨660⟩))) (.mul (.const 0x098ef805fa62c84ca8db70b4652013afb3aa33f11532fff692e61c8f51f0d4ab) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨662⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨147⟩)) (.mul (.const 0x0eac3ca1e396ee9c59c5785280a5d09f9e8f75597f469fbc834c1ca183332e5c) (.var ⟨156⟩))) (.mul (.const 0x337655ee8c3a38e3701cb231a347f7a85daf90d7e052cd611c9199c44568baf4) (.var ⟨165⟩))) (.mul (.const 0x1faba0d7472b4552d6e9a663b41dde25acc169fb4997c9651973851846dc2806) (.var ⟨174⟩))) (.mul (.const 0x218788ceef8151b199f39d45f5c4629cdefd250fb16d90935676a7415fdc2cdb) (.var ⟨183⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨192⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨201⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨210⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨219⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨228⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨237⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨246⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨255⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨264⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨273⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨282⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨291⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨300⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨309⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨318⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨327⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨336⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨345⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨354⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨363⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨372⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨381⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨390⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨399⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨408⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨417⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨426⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨435⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨444⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨453⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨462⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨471⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨480⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨489⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨498⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨507⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨516⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨525⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨534⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨543⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨552⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨561⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨570⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨579⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨588⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨597⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨606⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨615⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨624⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨633⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨642⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨651⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨660⟩))) (.mul (.const 0x098ef805fa62c84ca8db70b4652013afb3aa33f11532fff692e61c8f51f0d4ab) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨664⟩) (.var ⟨665⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨666⟩))),
+  .assert (.add (.var ⟨664⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨663⟩))),
+  .assert (.add (.var ⟨665⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨663⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨667⟩) (.var ⟨668⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨669⟩))),
+  .assert (.add (.var ⟨667⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨666⟩))),
+  .assert (.add (.var ⟨668⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨147⟩)) (.mul (.const 0x0eac3ca1e396ee9c59c5785280a5d09f9e8f75597f469fbc834c1ca183332e5c) (.var ⟨156⟩))) (.mul (.const 0x337655ee8c3a38e3701cb231a347f7a85daf90d7e052cd611c9199c44568baf4) (.var ⟨165⟩))) (.mul (.const 0x1faba0d7472b4552d6e9a663b41dde25acc169fb4997c9651973851846dc2806) (.var ⟨174⟩))) (.mul (.const 0x218788ceef8151b199f39d45f5c4629cdefd250fb16d90935676a7415fdc2cdb) (.var ⟨183⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨192⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨201⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨210⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨219⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨228⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨237⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨246⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨255⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨264⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨273⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨282⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨291⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨300⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨309⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨318⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨327⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨336⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨345⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨354⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨363⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨372⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨381⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨390⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨399⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨408⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨417⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨426⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨435⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨444⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨453⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨462⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨471⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨480⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨489⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨498⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨507⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨516⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨525⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨534⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨543⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨552⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨561⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨570⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨579⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨588⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨597⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨606⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨615⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨624⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨633⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨642⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨651⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨660⟩))) (.mul (.const 0x098ef805fa62c84ca8db70b4652013afb3aa33f11532fff692e61c8f51f0d4ab) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨670⟩) (.var ⟨671⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨672⟩))),
+  .assert (.add (.var ⟨670⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨147⟩)) (.mul (.const 0x1b8dc45eff7e7cd9f754fd403267970c1462f5f73b668300c42d7b18e5f83cdd) (.var ⟨156⟩))) (.mul (.const 0x052c1ba0469b53921bf017f1aa5bcd6223a3e1456c2ac73ec61418ccc98407f4) (.var ⟨165⟩))) (.mul (.const 0x3227840a41d22185dfe569f0adf357ad1ce7f32d28d3f8fd9391d666edd12e8d) (.var ⟨174⟩))) (.mul (.const 0x09d1038dde1e289c1d272040ea66e308624574295b8cde6f88501cdead9e07b4) (.var ⟨183⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨192⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨201⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨210⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨219⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨228⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨237⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨246⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨255⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨264⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨273⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨282⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨291⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨300⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨309⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨318⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨327⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨336⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨345⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨354⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨363⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨372⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨381⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨390⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨399⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨408⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨417⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨426⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨435⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨444⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨453⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨462⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨471⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨480⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨489⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨498⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨507⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨516⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨525⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨534⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨543⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨552⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨561⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨570⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨579⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨588⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨597⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨606⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨615⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨624⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨633⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨642⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨651⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨660⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨669⟩))) (.mul (.const 0x2915c7af2461247c28aca497dd3c6241a5215ae127bc053a2e80efd25fe565a9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨671⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨147⟩)) (.mul (.const 0x1b8dc45eff7e7cd9f754fd403267970c1462f5f73b668300c42d7b18e5f83cdd) (.var ⟨156⟩))) (.mul (.const 0x052c1ba0469b53921bf017f1aa5bcd6223a3e1456c2ac73ec61418ccc98407f4) (.var ⟨165⟩))) (.mul (.const 0x3227840a41d22185dfe569f0adf357ad1ce7f32d28d3f8fd9391d666edd12e8d) (.var ⟨174⟩))) (.mul (.const 0x09d1038dde1e289c1d272040ea66e308624574295b8cde6f88501cdead9e07b4) (.var ⟨183⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨192⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨201⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨210⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨219⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨228⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨237⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨246⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨255⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨264⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨273⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨282⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨291⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨300⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨309⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨318⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨327⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨336⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨345⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨354⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨363⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨372⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨381⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨390⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨399⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨408⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨417⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨426⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨435⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨444⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨453⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨462⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨471⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨480⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨489⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨498⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨507⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨516⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨525⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨534⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨543⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨552⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨561⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨570⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨579⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨588⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨597⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨606⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨615⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨624⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨633⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨642⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨651⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨660⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨669⟩))) (.mul (.const 0x2915c7af2461247c28aca497dd3c6241a5215ae127bc053a2e80efd25fe565a9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨673⟩) (.var ⟨674⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨675⟩))),
+  .assert (.add (.var ⟨673⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨672⟩))),
+  .assert (.add (.var ⟨674⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨672⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨676⟩) (.var ⟨677⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨678⟩))),
+  .assert (.add (.var ⟨676⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨675⟩))),
+  .assert (.add (.var ⟨677⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨147⟩)) (.mul (.const 0x1b8dc45eff7e7cd9f754fd403267970c1462f5f73b668300c42d7b18e5f83cdd) (.var ⟨156⟩))) (.mul (.const 0x052c1ba0469b53921bf017f1aa5bcd6223a3e1456c2ac73ec61418ccc98407f4) (.var ⟨165⟩))) (.mul (.const 0x3227840a41d22185dfe569f0adf357ad1ce7f32d28d3f8fd9391d666edd12e8d) (.var ⟨174⟩))) (.mul (.const 0x09d1038dde1e289c1d272040ea66e308624574295b8cde6f88501cdead9e07b4) (.var ⟨183⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨192⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨201⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨210⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨219⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨228⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨237⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨246⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨255⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨264⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨273⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨282⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨291⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨300⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨309⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨318⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨327⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨336⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨345⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨354⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨363⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨372⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨381⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨390⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨399⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨408⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨417⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨426⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨435⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨444⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨453⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨462⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨471⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨480⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨489⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨498⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨507⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨516⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨525⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨534⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨543⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨552⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨561⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨570⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨579⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨588⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨597⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨606⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨615⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨624⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨633⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨642⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨651⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨660⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨669⟩))) (.mul (.const 0x2915c7af2461247c28aca497dd3c6241a5215ae127bc053a2e80efd25fe565a9) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨679⟩) (.var ⟨680⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨681⟩))),
+  .assert (.add (.var ⟨679⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x240b20a2dbd641fee83b6dcac6c82ad55ca655d2ea884045bddd0007c803dcab) (.var ⟨147⟩)) (.mul (.const 0x250e3477a01abbfb7038f56409a35021913ca704417509e565d13018709e59be) (.var ⟨156⟩))) (.mul (.const 0x30421ee94ef2bc7900dcec62b4fea29ec9cf4b1f574155360dece8455093e700) (.var ⟨165⟩))) (.mul (.const 0x054a3f02a9b30c2441cb151063f28ea1174369c2833b195b9d572ae128a1019f) (.var ⟨174⟩))) (.mul (.const 0x36e4244c9bbaec4ec271c08c5c5a3e8dcbf89c2c9e8d70ee6adb55410ceaa554) (.var ⟨183⟩))) (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨192⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨201⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨210⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨219⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨228⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨237⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨246⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨255⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨264⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨273⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨282⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨291⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨300⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨309⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨318⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨327⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨336⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨345⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨354⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨363⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨372⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨381⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨390⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨399⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨408⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨417⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨426⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨435⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨444⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨453⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨462⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨471⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨480⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨489⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨498⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨507⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨516⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨525⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨534⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨543⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨552⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨561⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨570⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨579⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨588⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨597⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨606⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨615⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨624⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨633⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨642⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨651⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨660⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨669⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨678⟩))) (.mul (.const 0x25ed5dc742b0916087cdd06c0481a38ce74d8905578bd1a879c7f6e4018f17f1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨680⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x240b20a2dbd641fee83b6dcac6c82ad55ca655d2ea884045bddd0007c803dcab) (.var ⟨147⟩)) (.mul (.const 0x250e3477a01abbfb7038f56409a35021913ca704417509e565d13018709e59be) (.var ⟨156⟩))) (.mul (.const 0x30421ee94ef2bc7900dcec62b4fea29ec9cf4b1f574155360dece8455093e700) (.var ⟨165⟩))) (.mul (.const 0x054a3f02a9b30c2441cb151063f28ea1174369c2833b195b9d572ae128a1019f) (.var ⟨174⟩))) (.mul (.const 0x36e4244c9bbaec4ec271c08c5c5a3e8dcbf89c2c9e8d70ee6adb55410ceaa554) (.var ⟨183⟩))) (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨192⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨201⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨210⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨219⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨228⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨237⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨246⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨255⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨264⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨273⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨282⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨291⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨300⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨309⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨318⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨327⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨336⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨345⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨354⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨363⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨372⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨381⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨390⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨399⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨408⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨417⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨426⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨435⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨444⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨453⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨462⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨471⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨480⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨489⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨498⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨507⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨516⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨525⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨534⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨543⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨552⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨561⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨570⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨579⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨588⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨597⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨606⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨615⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨624⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨633⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨642⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨651⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨660⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨669⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨678⟩))) (.mul (.const 0x25ed5dc742b0916087cdd06c0481a38ce74d8905578bd1a879c7f6e4018f17f1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨682⟩) (.var ⟨683⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨684⟩))),
+  .assert (.add (.var ⟨682⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨681⟩))),
+  .assert (.add (.var ⟨683⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨681⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨685⟩) (.var ⟨686⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨687⟩))),
+  .assert (.add (.var ⟨685⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨684⟩))),
+  .assert (.add (.var ⟨686⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x240b20a2dbd641fee83b6dcac6c82ad55ca655d2ea884045bddd0007c803dcab) (.var ⟨147⟩)) (.mul (.const 0x250e3477a01abbfb7038f56409a35021913ca704417509e565d13018709e59be) (.var ⟨156⟩))) (.mul (.const 0x30421ee94ef2bc7900dcec62b4fea29ec9cf4b1f574155360dece8455093e700) (.var ⟨165⟩))) (.mul (.const 0x054a3f02a9b30c2441cb151063f28ea1174369c2833b195b9d572ae128a1019f) (.var ⟨174⟩))) (.mul (.const 0x36e4244c9bbaec4ec271c08c5c5a3e8dcbf89c2c9e8d70ee6adb55410ceaa554) (.var ⟨183⟩))) (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨192⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨201⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨210⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨219⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨228⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨237⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨246⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨255⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨264⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨273⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨282⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨291⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨300⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨309⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨318⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨327⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨336⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨345⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨354⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨363⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨372⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨381⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨390⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨399⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨408⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨417⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨426⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨435⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨444⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨453⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨462⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨471⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨480⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨489⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨498⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨507⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨516⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨525⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨534⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨543⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨552⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨561⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨570⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨579⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨588⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨597⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨606⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨615⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨624⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨633⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨642⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨651⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨660⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨669⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨678⟩))) (.mul (.const 0x25ed5dc742b0916087cdd06c0481a38ce74d8905578bd1a879c7f6e4018f17f1) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨688⟩) (.var ⟨689⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨690⟩))),
+  .assert (.add (.var ⟨688⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1751758c3824597f2f2c2970cf72f633daf9a3a7dd7821e8a3769cd1848eae2e) (.var ⟨147⟩)) (.mul (.const 0x11ee04a530410b36aa93dfd215201fc57ca627f0d5d03bb00a4223cac7cc7aa0) (.var ⟨156⟩))) (.mul (.const 0x16990b0be9d255b83f1ad6d41b468bb386f2c6c510161794fc023dcdfb9fe956) (.var ⟨165⟩))) (.mul (.const 0x07d749a68526bd4c415e7fea89874f6f34ca0e3ea8c9366f12e5b250eb4d06f1) (.var ⟨174⟩))) (.mul (.const 0x233307ff60626695f29f61633812759ed42955f58c04f186e56835c331c57a8a) (.var ⟨183⟩))) (.mul (.const 0x240b20a2dbd641fee83b6dcac6c82ad55ca655d2ea884045bddd0007c803dcab) (.var ⟨192⟩))) (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨201⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨210⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨219⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨228⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨237⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨246⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨255⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨264⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨273⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨282⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨291⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨300⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨309⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨318⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨327⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨336⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨345⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨354⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨363⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨372⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨381⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨390⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨399⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨408⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨417⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨426⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨435⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨444⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨453⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨462⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨471⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨480⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨489⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨498⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨507⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨516⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨525⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨534⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨543⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨552⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨561⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨570⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨579⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨588⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨597⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨606⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨615⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨624⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨633⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨642⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨651⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨660⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨669⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨678⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨687⟩))) (.mul (.const 0x218a5f285cce92caed349bffe8c9b3262f77990ca4dc18e8bf02cc585f6d51c3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨689⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1751758c3824597f2f2c2970cf72f633daf9a3a7dd7821e8a3769cd1848eae2e) (.var ⟨147⟩)) (.mul (.const 0x11ee04a530410b36aa93dfd215201fc57ca627f0d5d03bb00a4223cac7cc7aa0) (.var ⟨156⟩))) (.mul (.const 0x16990b0be9d255b83f1ad6d41b468bb386f2c6c510161794fc023dcdfb9fe956) (.var ⟨165⟩))) (.mul (.const 0x07d749a68526bd4c415e7fea89874f6f34ca0e3ea8c9366f12e5b250eb4d06f1) (.var ⟨174⟩))) (.mul (.const 0x233307ff60626695f29f61633812759ed42955f58c04f186e56835c331c57a8a) (.var ⟨183⟩))) (.mul (.const 0x240b20a2dbd641fee83b6dcac6c82ad55ca655d2ea884045bddd0007c803dcab) (.var ⟨192⟩))) (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨201⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨210⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨219⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨228⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨237⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨246⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨255⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨264⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨273⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨282⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨291⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨300⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨309⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨318⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨327⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨336⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨345⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨354⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨363⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨372⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨381⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨390⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨399⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨408⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨417⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨426⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨435⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨444⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨453⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨462⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨471⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨480⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨489⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨498⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨507⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨516⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨525⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨534⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨543⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨552⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨561⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨570⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨579⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨588⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨597⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨606⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨615⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨624⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨633⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨642⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨651⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨660⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨669⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨678⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨687⟩))) (.mul (.const 0x218a5f285cce92caed349bffe8c9b3262f77990ca4dc18e8bf02cc585f6d51c3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨691⟩) (.var ⟨692⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨693⟩))),
+  .assert (.add (.var ⟨691⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨690⟩))),
+  .assert (.add (.var ⟨692⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨690⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨694⟩) (.var ⟨695⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨696⟩))),
+  .assert (.add (.var ⟨694⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨693⟩))),
+  .assert (.add (.var ⟨695⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x1751758c3824597f2f2c2970cf72f633daf9a3a7dd7821e8a3769cd1848eae2e) (.var ⟨147⟩)) (.mul (.const 0x11ee04a530410b36aa93dfd215201fc57ca627f0d5d03bb00a4223cac7cc7aa0) (.var ⟨156⟩))) (.mul (.const 0x16990b0be9d255b83f1ad6d41b468bb386f2c6c510161794fc023dcdfb9fe956) (.var ⟨165⟩))) (.mul (.const 0x07d749a68526bd4c415e7fea89874f6f34ca0e3ea8c9366f12e5b250eb4d06f1) (.var ⟨174⟩))) (.mul (.const 0x233307ff60626695f29f61633812759ed42955f58c04f186e56835c331c57a8a) (.var ⟨183⟩))) (.mul (.const 0x240b20a2dbd641fee83b6dcac6c82ad55ca655d2ea884045bddd0007c803dcab) (.var ⟨192⟩))) (.mul (.const 0x0d205580f059d40497ef61ac7adae917735e9dd6654eedef1219b2562c850658) (.var ⟨201⟩))) (.mul (.const 0x3d8d2cfe4a42c9a4725727b441d3cff177ea0974eaf661eaa9d7dd11ed2e8dcf) (.var ⟨210⟩))) (.mul (.const 0x04f05ecbfbe190aa7cb0ff6ac841b6d3e9d2c5554cbc6130e03bff5b3be67e3a) (.var ⟨219⟩))) (.mul (.const 0x13c5d5ea6fb6fd9ed21fad5a3446e7eb7164f72172b12fddd3051220d1b79b55) (.var ⟨228⟩))) (.mul (.const 0x2414807f459f8120150d68f12bd3297d6e192916e3b1399d334de3d06cadd606) (.var ⟨237⟩))) (.mul (.const 0x299cb46dfe92bc6ded8bd4b08f40e9cc41cfa68c9ccd8b29be377304e4d46ba6) (.var ⟨246⟩))) (.mul (.const 0x135298c542bedea90c1b91f9558a0dabfb9105d38a0547d7747c89fe0f4a11ef) (.var ⟨255⟩))) (.mul (.const 0x36c9999f408fda1a1314b3180f28dce6dec5ee1d76469c80cba964e04cbf5d5b) (.var ⟨264⟩))) (.mul (.const 0x2a8c125ccc8009a1d7122ba5231d12a587959400f93161a416abcb5752e07ed7) (.var ⟨273⟩))) (.mul (.const 0x08a514b67f6cbe00bbb69a570edb74bfac9f143f26f66330acae5e696e3198d5) (.var ⟨282⟩))) (.mul (.const 0x3bf534ab4a3c68726a361c5963a1d2a1bc82ddbcb591fcadca537ac4b944abfe) (.var ⟨291⟩))) (.mul (.const 0x3b609ab36c4540d86cf26b44443ae33da49860cd7e0a0890d31d2dcb6fb41b30) (.var ⟨300⟩))) (.mul (.const 0x1d1515c8e098a215de4ed2e34d7ce88ec2077f5cfe1cbc478f7992ba9ce03611) (.var ⟨309⟩))) (.mul (.const 0x36dd1f5f363cbdda4a633ca57920891f99a287e1f5bb1888dfd06c2588007062) (.var ⟨318⟩))) (.mul (.const 0x31693903239c66cb65e75af0032da90d0ac8fab7916ed3f2ed9d8eadef03cf65) (.var ⟨327⟩))) (.mul (.const 0x0c507302b6ec29a1eca2d0a981ec89d31a3385106bbeb5ba43b59aa499418abe) (.var ⟨336⟩))) (.mul (.const 0x057a21db227e641c37942035b022dc177f3254dc131f1da19615b0b2914cbc6c) (.var ⟨345⟩))) (.mul (.const 0x2c1fb8320b2899c516ff614a033506c97020cddd4967de866af960a9dcc3402a) (.var ⟨354⟩))) (.mul (.const 0x3de66c3000226ebd12ff02fb4e2907cab1dc6acfc05d4bee418fece2257089bc) (.var ⟨363⟩))) (.mul (.const 0x2714aeb70ee91f77589ea8484a54fc488da98b5ba598db05d2abb0eef407c74d) (.var ⟨372⟩))) (.mul (.const 0x246790f3cdd71c5bc9ec13db0998c7be61efba6bd9acec47944fa1aefa5c1189) (.var ⟨381⟩))) (.mul (.const 0x18dd0af4a1d9e22c8497a1e9df45e791607e9aeb60a7b2e62c0fb92c474b492e) (.var ⟨390⟩))) (.mul (.const 0x249b25de5fe59d1811b01292c21fc5bb3868427a74bbce59b93b02881d68a808) (.var ⟨399⟩))) (.mul (.const 0x098cd004da96508554a137c31bd512e06b286d6690d04c6ac0e1c7dbe5dc5d90) (.var ⟨408⟩))) (.mul (.const 0x3589610aef6087fd0d40c77f983f0f3b482d00a939fc7993be91dff89fee45cc) (.var ⟨417⟩))) (.mul (.const 0x1d216040a691ec05457bc66c8afe00eaee5ab1231c6257c48ed5b595fae5a2c3) (.var ⟨426⟩))) (.mul (.const 0x3e70be07373f44272fe2dc4d7bbcefff1a7cf68c7f3ffca743608733ec076ba2) (.var ⟨435⟩))) (.mul (.const 0x2d85218a298ebf85d53db65d9a5f75ef27b4a1302883fe71bf25b6d5ab9ce367) (.var ⟨444⟩))) (.mul (.const 0x2b5cfad8d08ec6f9818bfc3cb49b7c46bc97b4dff4da6452b5843551348037b0) (.var ⟨453⟩))) (.mul (.const 0x2c5e611b87d22e42b8246be0715d55806c4221b8b6ca1764386f06b3052355bb) (.var ⟨462⟩))) (.mul (.const 0x231793c5f98d3ac05bb25c5f5d7075b6b6ff0da5173a3d7379a7db32773c7439) (.var ⟨471⟩))) (.mul (.const 0x395aa62620cbdbcb7fb3d0f16b9abb000da30316c47dfa8c835baa1c75663a01) (.var ⟨480⟩))) (.mul (.const 0x39ddeeb216c65cd40940cebf1ecec0191204458b15b3a94ade890b95ffb41adf) (.var ⟨489⟩))) (.mul (.const 0x3cada4a60e2ed00cb1dc8ecd6020a919d0a7e1335bff2b9214c3d05b234eabc8) (.var ⟨498⟩))) (.mul (.const 0x072fb99ae950f7952eb41bb72da1bdf4a930390b82da49f648cde07328a82da0) (.var ⟨507⟩))) (.mul (.const 0x126ecd0f29bb4db493236f494459b261e84e13c4f295a4edfed7754b9063b569) (.var ⟨516⟩))) (.mul (.const 0x27306bc0631931ab4cb5dc9174a8b016b4a1b1ebdc9ab50902f36630a1f51225) (.var ⟨525⟩))) (.mul (.const 0x0e25c1aa3b7272bcd9939821f5e34e2347858e029b1fd5ae65dcc477106c3994) (.var ⟨534⟩))) (.mul (.const 0x0d62d68158351bf82e7b2eef70dab6c5dea0e3678fa077976f1605eb391ceb28) (.var ⟨543⟩))) (.mul (.const 0x2c2d86e590fc3ce4300c9394fa4e8a12562ddc2435c6331bffee8095fcb2770a) (.var ⟨552⟩))) (.mul (.const 0x17fc578b3f56dc1103cdd672c0ab164e9455eaba0692279378957cf5f97ae9b7) (.var ⟨561⟩))) (.mul (.const 0x27c22519526b12d6ac760502ef5f61a4a2ff16906bbbecfd76eba1aeaea84a3e) (.var ⟨570⟩))) (.mul (.const 0x02c11c26513bf44c630674d012b0d61d5151b24294221b543815241d8f0b1c3f) (.var ⟨579⟩))) (.mul (.const 0x345787eee488666487c26b8c1175367c7df25e7703b3624981c0f68b6c0024fc) (.var ⟨588⟩))) (.mul (.const 0x1703239d010d5a2ff9f65cf7153a04b716a9dfbc49e6cc4a91634f82dc999cba) (.var ⟨597⟩))) (.mul (.const 0x32060f6467c34ee536abf6e21382642d409914bd82bab221361afafaa5fa1ba7) (.var ⟨606⟩))) (.mul (.const 0x3d59ea9bbc413212477606874f3d8adbd7d40fe5643cb78a06da13d79a7529d4) (.var ⟨615⟩))) (.mul (.const 0x24f8b40c85554731d4280585eb74c03bbc6bc01375af44462bafebf25db3b470) (.var ⟨624⟩))) (.mul (.const 0x04a741efc9cca5001083fdd1b4842c96b34c6731ef3691adeddec14f293bedeb) (.var ⟨633⟩))) (.mul (.const 0x3f1a88cf9fe4e0f1a86c90ca775d12532e1515ca73ec5ae31d568d5d8df2b229) (.var ⟨642⟩))) (.mul (.const 0x2d11e4c40bd85f0a71e90cc384e66cdd0f052a4ea32a7e0de598b016abedac94) (.var ⟨651⟩))) (.mul (.const 0x300323ff2ca5fe75e7e8773666aeed80629ba3ed0a36093992775e9fc8ac0622) (.var ⟨660⟩))) (.mul (.const 0x0557e5b7cad81454588cacb6217a70bd437447c0b7b5ffe9aea1497de2ede49a) (.var ⟨669⟩))) (.mul (.const 0x177ca7466c333165fe26337fed2a57048b1df1c99ac6ff87fd7cf2603c04be33) (.var ⟨678⟩))) (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨687⟩))) (.mul (.const 0x218a5f285cce92caed349bffe8c9b3262f77990ca4dc18e8bf02cc585f6d51c3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨697⟩) (.var ⟨698⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩))),
+  .assert (.add (.var ⟨697⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3dadaba963bd2f573ec054de4f60ff93fdb6503364fecdfb700a9deaf95058ab) (.var ⟨147⟩)) (.mul (.const 0x1423a9a2e41a3d1a15d076bf9fe268c855aa343a1d368b2c3737c596fef1a079) (.var ⟨156⟩))) (.mul (.const 0x2afd4d4d722d30804731e87f472a33674b640772f13ffc73daedb844d3c08249) (.var ⟨165⟩))) (.mul (.const 0x0af5c4300f6dd0bcb7d47754cd4da28d5407cbb503a7e255fc76890b39f9ab47) (.var ⟨174⟩))) (.mul (.const 0x06778c97db8245e267369e4fbf4d3127b0d883172697f5bd8fe17d5f51ab388c) (.var ⟨183⟩))) (.mul (.const 0x114f7f650c5fed13fafd86cc54f5f1a4cdfb53d39ded66b1d8b7b62e98ad09a0) (.var ⟨192⟩))) (.mul (.const 0x36cf75429522da3105388847a407694be9594d1dc1231cdc9bf532fe21534a78) (.var ⟨201⟩))) (.mul (.const 0x15e44ed38d000c3c8363d84e3fdc1a61baf2cb18b767bd74cdf799488d0c975e) (.var ⟨210⟩))) (.mul (.const 0x192acd63791a030d0c787d556816126f9268d003d0e22a0796cd4bbceceb7293) (.var ⟨219⟩))) (.mul (.const 0x0fe7c710e496ad114b0aaa4cc60563c353161c84309f0cd664ff5a368617cc13) (.var ⟨228⟩))) (.mul (.const 0x2aade52909c97c676e710cf3a6ae3e008b0e6a4fd551f75b036856a1cc8888b2) (.var ⟨237⟩))) (.mul (.const 0x35c0f03c642a844b485716e5c518e57e1b13fcf007c5ea1d9a6ca0596bed3005) (.var ⟨246⟩))) (.mul (.const 0x2bef346b74c17bd2039e5a3bc30c09b027ad3b469ca34d0342aa20e7ea84cbfa) (.var ⟨255⟩))) (.mul (.const 0x2c4214a1ac2ad57bfe49fe0d4f3a163a3c248c5863e3c9b182cd09b42c8decc2) (.var ⟨264⟩))) (.mul (.const 0x01b285ce7d70c625c9cba09f87d3e5c7534eda0907bac45293c21490914797a0) (.var ⟨273⟩))) (.mul (.const 0x004f53925678dbb2c653ab179ae0ca6a54e39e8029eec54cf9983524d82aa0a3) (.var ⟨282⟩))) (.mul (.const 0x377976819e453ae102450606f91ba9fbb4993516e375be554c3d44016b7800b5) (.var ⟨291⟩))) (.mul (.const 0x0f68c3dfae2c9662429479a92ba45e42ae15db2468a7b71d8e0762fdadf1efe4) (.var ⟨300⟩))) (.mul (.const 0x338c078c712b6549e2a39c51762a71df6e0c3c94bc04b0497cfe0f8e1c3e879a) (.var ⟨309⟩))) (.mul (.const 0x09d0f74f1ab676ab36bd29aa7e758df556225f4b70f232c2690baecdfbb62a22) (.var ⟨318⟩))) (.mul (.const 0x0e3064c1d286ec7a98477999e5b2d73c61587d86e3b5873343677f426e69ab8a) (.var ⟨327⟩))) (.mul (.const 0x0b3c417e7017ab639803ed50d9453462170220fec40dd000856a170d8a0b7097) (.var ⟨336⟩))) (.mul (.const 0x3731bea9fa705a2ce7302fa102708f04896aafe81002e1d8b938c47653deb088) (.var ⟨345⟩))) (.mul (.const 0x21237faea4614f08abfbded581f173f2ddb51c66d3d1e4815ad71a15e5c04ae7) (.var ⟨354⟩))) (.mul (.const 0x1495f32856741cd1b56c6e91f75ee11c656266a61edd04fcf14048efde219aa5) (.var ⟨363⟩))) (.mul (.const 0x2022974df68cf9846be6366ac4336b6d6049cf711a16ae4a85c61821a43d08ce) (.var ⟨372⟩))) (.mul (.const 0x06d2706dfd993eeafb85ac7e94fb710333ed80ab0023a13a29bbfa6619c8656b) (.var ⟨381⟩))) (.mul (.const 0x3be35e510f0d1c184366b77bad8597efb517d50b3023e523e71cbd07c5c93080) (.var ⟨390⟩))) (.mul (.const 0x069532ea08f85464ac6310e9b5cfd1942707149f95f010605ed6a69d46eaf39b) (.var ⟨399⟩))) (.mul (.const 0x0aee22a6102999c4c5b998984776ff0dc23cfcf42f4ebf9ce880646c55a11d8c) (.var ⟨408⟩))) (.mul (.const 0x1440285950a112a59b6561be64f5b5f15315523c12fb6b17c03767eef6f1066b) (.var ⟨417⟩))) (.mul (.const 0x18072c2a18149455d074a24494eb1e10558e371327e8d416d389ec3d4e463e98) (.var ⟨426⟩))) (.mul (.const 0x21bd9e3b2d6b4c319f05d8d87fdad84a7058d8df4db0228046eb8c814dc2644e) (.var ⟨435⟩))) (.mul (.const 0x3a0bdddcc342fd3fc5247f4030f84c12fed7c055d0bf77d8dc32a843d2607339) (.var ⟨444⟩))) (.mul (.const 0x18f19d581bd5893d7a75b5b3a52b85426961fd23de66170b673c9584bb61533c) (.var ⟨453⟩))) (.mul (.const 0x13f814b31daf0b02f1d826df1b970cd6ed9cb1c56a830577b783ceab66e0e812) (.var ⟨462⟩))) (.mul (.const 0x188d0a5f7affbc06ce139b4046b2b1e0f73ec190197d2bb670dac456d508f3b0) (.var ⟨471⟩))) (.mul (.const 0x2d2ed707e6ac9bcdba6f20729340a20f7346ea0e2b4a6c2af4de3a390fa72d31) (.var ⟨480⟩))) (.mul (.const 0x249b89ca42274641af33a5cfdb2a3ea09877d166d259b473d70f64697b41a658) (.var ⟨489⟩))) (.mul (.const 0x3a278fe6deda28ebffe37011af1eb9d0e6848f7c09896385b7a373a242571de6) (.var ⟨498⟩))) (.mul (.const 0x26a5a9f1b9539d0372541a06b3328135ef7220991a3eaf0773969bc257599369) (.var ⟨507⟩))) (.mul (.const 0x2ab6b628674ba19be40ad2e15eddac7f2419db13cbca676609cdec6fbdc82af9) (.var ⟨516⟩))) (.mul (.const 0x08a4037fb096f9b50fe9e92e1a917f32d0e70d089cdcebc4aae35842740c6d97) (.var ⟨525⟩))) (.mul (.const 0x3292262d04e31a14230302401d731521e390bc7a56bb071a9321e261013d26ce) (.var ⟨534⟩))) (.mul (.const 0x13fee5101655e8c4e932e32a18444b9fb34d5e77329ab30d1f00b09d198761f4) (.var ⟨543⟩))) (.mul (.const 0x0b5df1cc69ac1d875c48572092dc089c8ef2cbe2be2c71e36f35ab02d56cd4bf) (.var ⟨552⟩))) (.mul (.const 0x2484060b20ee9722f098dcc9684dd641aa5619e0af75de197dfc0008aa13eab8) (.var ⟨561⟩))) (.mul (.const 0x2dc31f46b17ac6127392b35914ab9c5cf081728403091762565f3861a31aa8ad) (.var ⟨570⟩))) (.mul (.const 0x3bdcd4b88bcfab9be74447ea67f8fa75018f2b26e03768b66185ad08190a92b7) (.var ⟨579⟩))) (.mul (.const 0x12c2d6ac9c08c2f0c9d8d9e7f3ea156d4c6232db5efa680f5814d25f0ec936ce) (.var ⟨588⟩))) (.mul (.const 0x3352fb13e3fa414724f1cec42bf0229de8fad7977cdddf64fb8ba0eb8d5e0a9d) (.var ⟨597⟩))) (.mul (.const 0x1ea00fe71dd81ad6c87de825fea502d938489359241fc398463d8d27b90a8349) (.var ⟨606⟩))) (.mul (.const 0x26da55b8990747f0a0085c1929cd3ddb8561a09212ccfcddf56cdf270136a16e) (.var ⟨615⟩))) (.mul (.const 0x19aa8cdd169b326a251a4fccf96e885ce543d7eddb50d10ea7ee7067b4048bd6) (.var ⟨624⟩))) (.mul (.const 0x3d459624985835341c7eacdb7efec1e0a46a0837ba5c1189975183866fab73e7) (.var ⟨633⟩))) (.mul (.const 0x2f584e73aa1d6df66b758719fb0973576ba951d9ba22ebba1ea922bbad28c6fe) (.var ⟨642⟩))) (.mul (.const 0x0fdd3d22e1b8001fe5f1c63ec94e997ca2d021d1b78f636f21e42277d29049ec) (.var ⟨651⟩))) (.mul (.const 0x231ab9902a61d1c2a11cb579af2063a4a2c50f8b74925ff1bcad90ceb3d2e9f9) (.var ⟨660⟩))) (.mul (.const 0x20b109736ef611145db6c01df243049c0181b82ebed28c414ab8a15ecd5d81cd) (.var ⟨669⟩))) (.mul (.const 0x3b7f052192e5dbc89217019d8d84d25af29d8bfa253d72eaab82746596bf9766) (.var ⟨678⟩))) (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨687⟩))) (.mul (.const 0x0cbab33aa73976ddd0f06e17ef930a1fe62833dffc0098296c7e8779474342b8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨698⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3dadaba963bd2f573ec054de4f60ff93fdb6503364fecdfb700a9deaf95058ab) (.var ⟨147⟩)) (.mul (.const 0x1423a9a2e41a3d1a15d076bf9fe268c855aa343a1d368b2c3737c596fef1a079) (.var ⟨156⟩))) (.mul (.const 0x2afd4d4d722d30804731e87f472a33674b640772f13ffc73daedb844d3c08249) (.var ⟨165⟩))) (.mul (.const 0x0af5c4300f6dd0bcb7d47754cd4da28d5407cbb503a7e255fc76890b39f9ab47) (.var ⟨174⟩))) (.mul (.const 0x06778c97db8245e267369e4fbf4d3127b0d883172697f5bd8fe17d5f51ab388c) (.var ⟨183⟩))) (.mul (.const 0x114f7f650c5fed13fafd86cc54f5f1a4cdfb53d39ded66b1d8b7b62e98ad09a0) (.var ⟨192⟩))) (.mul (.const 0x36cf75429522da3105388847a407694be9594d1dc1231cdc9bf532fe21534a78) (.var ⟨201⟩))) (.mul (.const 0x15e44ed38d000c3c8363d84e3fdc1a61baf2cb18b767bd74cdf799488d0c975e) (.var ⟨210⟩))) (.mul (.const 0x192acd63791a030d0c787d556816126f9268d003d0e22a0796cd4bbceceb7293) (.var ⟨219⟩))) (.mul (.const 0x0fe7c710e496ad114b0aaa4cc60563c353161c84309f0cd664ff5a368617cc13) (.var ⟨228⟩))) (.mul (.const 0x2aade52909c97c676e710cf3a6ae3e008b0e6a4fd551f75b036856a1cc8888b2) (.var ⟨237⟩))) (.mul (.const 0x35c0f03c642a844b485716e5c518e57e1b13fcf007c5ea1d9a6ca0596bed3005) (.var ⟨246⟩))) (.mul (.const 0x2bef346b74c17bd2039e5a3bc30c09b027ad3b469ca34d0342aa20e7ea84cbfa) (.var ⟨255⟩))) (.mul (.const 0x2c4214a1ac2ad57bfe49fe0d4f3a163a3c248c5863e3c9b182cd09b42c8decc2) (.var ⟨264⟩))) (.mul (.const 0x01b285ce7d70c625c9cba09f87d3e5c7534eda0907bac45293c21490914797a0) (.var ⟨273⟩))) (.mul (.const 0x004f53925678dbb2c653ab179ae0ca6a54e39e8029eec54cf9983524d82aa0a3) (.var ⟨282⟩))) (.mul (.const 0x377976819e453ae102450606f91ba9fbb4993516e375be554c3d44016b7800b5) (.var ⟨291⟩))) (.mul (.const 0x0f68c3dfae2c9662429479a92ba45e42ae15db2468a7b71d8e0762fdadf1efe4) (.var ⟨300⟩))) (.mul (.const 0x338c078c712b6549e2a39c51762a71df6e0c3c94bc04b0497cfe0f8e1c3e879a) (.var ⟨309⟩))) (.mul (.const 0x09d0f74f1ab676ab36bd29aa7e758df556225f4b70f232c2690baecdfbb62a22) (.var ⟨318⟩))) (.mul (.const 0x0e3064c1d286ec7a98477999e5b2d73c61587d86e3b5873343677f426e69ab8a) (.var ⟨327⟩))) (.mul (.const 0x0b3c417e7017ab639803ed50d9453462170220fec40dd000856a170d8a0b7097) (.var ⟨336⟩))) (.mul (.const 0x3731bea9fa705a2ce7302fa102708f04896aafe81002e1d8b938c47653deb088) (.var ⟨345⟩))) (.mul (.const 0x21237faea4614f08abfbded581f173f2ddb51c66d3d1e4815ad71a15e5c04ae7) (.var ⟨354⟩))) (.mul (.const 0x1495f32856741cd1b56c6e91f75ee11c656266a61edd04fcf14048efde219aa5) (.var ⟨363⟩))) (.mul (.const 0x2022974df68cf9846be6366ac4336b6d6049cf711a16ae4a85c61821a43d08ce) (.var ⟨372⟩))) (.mul (.const 0x06d2706dfd993eeafb85ac7e94fb710333ed80ab0023a13a29bbfa6619c8656b) (.var ⟨381⟩))) (.mul (.const 0x3be35e510f0d1c184366b77bad8597efb517d50b3023e523e71cbd07c5c93080) (.var ⟨390⟩))) (.mul (.const 0x069532ea08f85464ac6310e9b5cfd1942707149f95f010605ed6a69d46eaf39b) (.var ⟨399⟩))) (.mul (.const 0x0aee22a6102999c4c5b998984776ff0dc23cfcf42f4ebf9ce880646c55a11d8c) (.var ⟨408⟩))) (.mul (.const 0x1440285950a112a59b6561be64f5b5f15315523c12fb6b17c03767eef6f1066b) (.var ⟨417⟩))) (.mul (.const 0x18072c2a18149455d074a24494eb1e10558e371327e8d416d389ec3d4e463e98) (.var ⟨426⟩))) (.mul (.const 0x21bd9e3b2d6b4c319f05d8d87fdad84a7058d8df4db0228046eb8c814dc2644e) (.var ⟨435⟩))) (.mul (.const 0x3a0bdddcc342fd3fc5247f4030f84c12fed7c055d0bf77d8dc32a843d2607339) (.var ⟨444⟩))) (.mul (.const 0x18f19d581bd5893d7a75b5b3a52b85426961fd23de66170b673c9584bb61533c) (.var ⟨453⟩))) (.mul (.const 0x13f814b31daf0b02f1d826df1b970cd6ed9cb1c56a830577b783ceab66e0e812) (.var ⟨462⟩))) (.mul (.const 0x188d0a5f7affbc06ce139b4046b2b1e0f73ec190197d2bb670dac456d508f3b0) (.var ⟨471⟩))) (.mul (.const 0x2d2ed707e6ac9bcdba6f20729340a20f7346ea0e2b4a6c2af4de3a390fa72d31) (.var ⟨480⟩))) (.mul (.const 0x249b89ca42274641af33a5cfdb2a3ea09877d166d259b473d70f64697b41a658) (.var ⟨489⟩))) (.mul (.const 0x3a278fe6deda28ebffe37011af1eb9d0e6848f7c09896385b7a373a242571de6) (.var ⟨498⟩))) (.mul (.const 0x26a5a9f1b9539d0372541a06b3328135ef7220991a3eaf0773969bc257599369) (.var ⟨507⟩))) (.mul (.const 0x2ab6b628674ba19be40ad2e15eddac7f2419db13cbca676609cdec6fbdc82af9) (.var ⟨516⟩))) (.mul (.const 0x08a4037fb096f9b50fe9e92e1a917f32d0e70d089cdcebc4aae35842740c6d97) (.var ⟨525⟩))) (.mul (.const 0x3292262d04e31a14230302401d731521e390bc7a56bb071a9321e261013d26ce) (.var ⟨534⟩))) (.mul (.const 0x13fee5101655e8c4e932e32a18444b9fb34d5e77329ab30d1f00b09d198761f4) (.var ⟨543⟩))) (.mul (.const 0x0b5df1cc69ac1d875c48572092dc089c8ef2cbe2be2c71e36f35ab02d56cd4bf) (.var ⟨552⟩))) (.mul (.const 0x2484060b20ee9722f098dcc9684dd641aa5619e0af75de197dfc0008aa13eab8) (.var ⟨561⟩))) (.mul (.const 0x2dc31f46b17ac6127392b35914ab9c5cf081728403091762565f3861a31aa8ad) (.var ⟨570⟩))) (.mul (.const 0x3bdcd4b88bcfab9be74447ea67f8fa75018f2b26e03768b66185ad08190a92b7) (.var ⟨579⟩))) (.mul (.const 0x12c2d6ac9c08c2f0c9d8d9e7f3ea156d4c6232db5efa680f5814d25f0ec936ce) (.var ⟨588⟩))) (.mul (.const 0x3352fb13e3fa414724f1cec42bf0229de8fad7977cdddf64fb8ba0eb8d5e0a9d) (.var ⟨597⟩))) (.mul (.const 0x1ea00fe71dd81ad6c87de825fea502d938489359241fc398463d8d27b90a8349) (.var ⟨606⟩))) (.mul (.const 0x26da55b8990747f0a0085c1929cd3ddb8561a09212ccfcddf56cdf270136a16e) (.var ⟨615⟩))) (.mul (.const 0x19aa8cdd169b326a251a4fccf96e885ce543d7eddb50d10ea7ee7067b4048bd6) (.var ⟨624⟩))) (.mul (.const 0x3d459624985835341c7eacdb7efec1e0a46a0837ba5c1189975183866fab73e7) (.var ⟨633⟩))) (.mul (.const 0x2f584e73aa1d6df66b758719fb0973576ba951d9ba22ebba1ea922bbad28c6fe) (.var ⟨642⟩))) (.mul (.const 0x0fdd3d22e1b8001fe5f1c63ec94e997ca2d021d1b78f636f21e42277d29049ec) (.var ⟨651⟩))) (.mul (.const 0x231ab9902a61d1c2a11cb579af2063a4a2c50f8b74925ff1bcad90ceb3d2e9f9) (.var ⟨660⟩))) (.mul (.const 0x20b109736ef611145db6c01df243049c0181b82ebed28c414ab8a15ecd5d81cd) (.var ⟨669⟩))) (.mul (.const 0x3b7f052192e5dbc89217019d8d84d25af29d8bfa253d72eaab82746596bf9766) (.var ⟨678⟩))) (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨687⟩))) (.mul (.const 0x0cbab33aa73976ddd0f06e17ef930a1fe62833dffc0098296c7e8779474342b8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨700⟩) (.var ⟨701⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨702⟩))),
+  .assert (.add (.var ⟨700⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩))),
+  .assert (.add (.var ⟨701⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨699⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨703⟩) (.var ⟨704⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨705⟩))),
+  .assert (.add (.var ⟨703⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨702⟩))),
+  .assert (.add (.var ⟨704⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x3dadaba963bd2f573ec054de4f60ff93fdb6503364fecdfb700a9deaf95058ab) (.var ⟨147⟩)) (.mul (.const 0x1423a9a2e41a3d1a15d076bf9fe268c855aa343a1d368b2c3737c596fef1a079) (.var ⟨156⟩))) (.mul (.const 0x2afd4d4d722d30804731e87f472a33674b640772f13ffc73daedb844d3c08249) (.var ⟨165⟩))) (.mul (.const 0x0af5c4300f6dd0bcb7d47754cd4da28d5407cbb503a7e255fc76890b39f9ab47) (.var ⟨174⟩))) (.mul (.const 0x06778c97db8245e267369e4fbf4d3127b0d883172697f5bd8fe17d5f51ab388c) (.var ⟨183⟩))) (.mul (.const 0x114f7f650c5fed13fafd86cc54f5f1a4cdfb53d39ded66b1d8b7b62e98ad09a0) (.var ⟨192⟩))) (.mul (.const 0x36cf75429522da3105388847a407694be9594d1dc1231cdc9bf532fe21534a78) (.var ⟨201⟩))) (.mul (.const 0x15e44ed38d000c3c8363d84e3fdc1a61baf2cb18b767bd74cdf799488d0c975e) (.var ⟨210⟩))) (.mul (.const 0x192acd63791a030d0c787d556816126f9268d003d0e22a0796cd4bbceceb7293) (.var ⟨219⟩))) (.mul (.const 0x0fe7c710e496ad114b0aaa4cc60563c353161c84309f0cd664ff5a368617cc13) (.var ⟨228⟩))) (.mul (.const 0x2aade52909c97c676e710cf3a6ae3e008b0e6a4fd551f75b036856a1cc8888b2) (.var ⟨237⟩))) (.mul (.const 0x35c0f03c642a844b485716e5c518e57e1b13fcf007c5ea1d9a6ca0596bed3005) (.var ⟨246⟩))) (.mul (.const 0x2bef346b74c17bd2039e5a3bc30c09b027ad3b469ca34d0342aa20e7ea84cbfa) (.var ⟨255⟩))) (.mul (.const 0x2c4214a1ac2ad57bfe49fe0d4f3a163a3c248c5863e3c9b182cd09b42c8decc2) (.var ⟨264⟩))) (.mul (.const 0x01b285ce7d70c625c9cba09f87d3e5c7534eda0907bac45293c21490914797a0) (.var ⟨273⟩))) (.mul (.const 0x004f53925678dbb2c653ab179ae0ca6a54e39e8029eec54cf9983524d82aa0a3) (.var ⟨282⟩))) (.mul (.const 0x377976819e453ae102450606f91ba9fbb4993516e375be554c3d44016b7800b5) (.var ⟨291⟩))) (.mul (.const 0x0f68c3dfae2c9662429479a92ba45e42ae15db2468a7b71d8e0762fdadf1efe4) (.var ⟨300⟩))) (.mul (.const 0x338c078c712b6549e2a39c51762a71df6e0c3c94bc04b0497cfe0f8e1c3e879a) (.var ⟨309⟩))) (.mul (.const 0x09d0f74f1ab676ab36bd29aa7e758df556225f4b70f232c2690baecdfbb62a22) (.var ⟨318⟩))) (.mul (.const 0x0e3064c1d286ec7a98477999e5b2d73c61587d86e3b5873343677f426e69ab8a) (.var ⟨327⟩))) (.mul (.const 0x0b3c417e7017ab639803ed50d9453462170220fec40dd000856a170d8a0b7097) (.var ⟨336⟩))) (.mul (.const 0x3731bea9fa705a2ce7302fa102708f04896aafe81002e1d8b938c47653deb088) (.var ⟨345⟩))) (.mul (.const 0x21237faea4614f08abfbded581f173f2ddb51c66d3d1e4815ad71a15e5c04ae7) (.var ⟨354⟩))) (.mul (.const 0x1495f32856741cd1b56c6e91f75ee11c656266a61edd04fcf14048efde219aa5) (.var ⟨363⟩))) (.mul (.const 0x2022974df68cf9846be6366ac4336b6d6049cf711a16ae4a85c61821a43d08ce) (.var ⟨372⟩))) (.mul (.const 0x06d2706dfd993eeafb85ac7e94fb710333ed80ab0023a13a29bbfa6619c8656b) (.var ⟨381⟩))) (.mul (.const 0x3be35e510f0d1c184366b77bad8597efb517d50b3023e523e71cbd07c5c93080) (.var ⟨390⟩))) (.mul (.const 0x069532ea08f85464ac6310e9b5cfd1942707149f95f010605ed6a69d46eaf39b) (.var ⟨399⟩))) (.mul (.const 0x0aee22a6102999c4c5b998984776ff0dc23cfcf42f4ebf9ce880646c55a11d8c) (.var ⟨408⟩))) (.mul (.const 0x1440285950a112a59b6561be64f5b5f15315523c12fb6b17c03767eef6f1066b) (.var ⟨417⟩))) (.mul (.const 0x18072c2a18149455d074a24494eb1e10558e371327e8d416d389ec3d4e463e98) (.var ⟨426⟩))) (.mul (.const 0x21bd9e3b2d6b4c319f05d8d87fdad84a7058d8df4db0228046eb8c814dc2644e) (.var ⟨435⟩))) (.mul (.const 0x3a0bdddcc342fd3fc5247f4030f84c12fed7c055d0bf77d8dc32a843d2607339) (.var ⟨444⟩))) (.mul (.const 0x18f19d581bd5893d7a75b5b3a52b85426961fd23de66170b673c9584bb61533c) (.var ⟨453⟩))) (.mul (.const 0x13f814b31daf0b02f1d826df1b970cd6ed9cb1c56a830577b783ceab66e0e812) (.var ⟨462⟩))) (.mul (.const 0x188d0a5f7affbc06ce139b4046b2b1e0f73ec190197d2bb670dac456d508f3b0) (.var ⟨471⟩))) (.mul (.const 0x2d2ed707e6ac9bcdba6f20729340a20f7346ea0e2b4a6c2af4de3a390fa72d31) (.var ⟨480⟩))) (.mul (.const 0x249b89ca42274641af33a5cfdb2a3ea09877d166d259b473d70f64697b41a658) (.var ⟨489⟩))) (.mul (.const 0x3a278fe6deda28ebffe37011af1eb9d0e6848f7c09896385b7a373a242571de6) (.var ⟨498⟩))) (.mul (.const 0x26a5a9f1b9539d0372541a06b3328135ef7220991a3eaf0773969bc257599369) (.var ⟨507⟩))) (.mul (.const 0x2ab6b628674ba19be40ad2e15eddac7f2419db13cbca676609cdec6fbdc82af9) (.var ⟨516⟩))) (.mul (.const 0x08a4037fb096f9b50fe9e92e1a917f32d0e70d089cdcebc4aae35842740c6d97) (.var ⟨525⟩))) (.mul (.const 0x3292262d04e31a14230302401d731521e390bc7a56bb071a9321e261013d26ce) (.var ⟨534⟩))) (.mul (.const 0x13fee5101655e8c4e932e32a18444b9fb34d5e77329ab30d1f00b09d198761f4) (.var ⟨543⟩))) (.mul (.const 0x0b5df1cc69ac1d875c48572092dc089c8ef2cbe2be2c71e36f35ab02d56cd4bf) (.var ⟨552⟩))) (.mul (.const 0x2484060b20ee9722f098dcc9684dd641aa5619e0af75de197dfc0008aa13eab8) (.var ⟨561⟩))) (.mul (.const 0x2dc31f46b17ac6127392b35914ab9c5cf081728403091762565f3861a31aa8ad) (.var ⟨570⟩))) (.mul (.const 0x3bdcd4b88bcfab9be74447ea67f8fa75018f2b26e03768b66185ad08190a92b7) (.var ⟨579⟩))) (.mul (.const 0x12c2d6ac9c08c2f0c9d8d9e7f3ea156d4c6232db5efa680f5814d25f0ec936ce) (.var ⟨588⟩))) (.mul (.const 0x3352fb13e3fa414724f1cec42bf0229de8fad7977cdddf64fb8ba0eb8d5e0a9d) (.var ⟨597⟩))) (.mul (.const 0x1ea00fe71dd81ad6c87de825fea502d938489359241fc398463d8d27b90a8349) (.var ⟨606⟩))) (.mul (.const 0x26da55b8990747f0a0085c1929cd3ddb8561a09212ccfcddf56cdf270136a16e) (.var ⟨615⟩))) (.mul (.const 0x19aa8cdd169b326a251a4fccf96e885ce543d7eddb50d10ea7ee7067b4048bd6) (.var ⟨624⟩))) (.mul (.const 0x3d459624985835341c7eacdb7efec1e0a46a0837ba5c1189975183866fab73e7) (.var ⟨633⟩))) (.mul (.const 0x2f584e73aa1d6df66b758719fb0973576ba951d9ba22ebba1ea922bbad28c6fe) (.var ⟨642⟩))) (.mul (.const 0x0fdd3d22e1b8001fe5f1c63ec94e997ca2d021d1b78f636f21e42277d29049ec) (.var ⟨651⟩))) (.mul (.const 0x231ab9902a61d1c2a11cb579af2063a4a2c50f8b74925ff1bcad90ceb3d2e9f9) (.var ⟨660⟩))) (.mul (.const 0x20b109736ef611145db6c01df243049c0181b82ebed28c414ab8a15ecd5d81cd) (.var ⟨669⟩))) (.mul (.const 0x3b7f052192e5dbc89217019d8d84d25af29d8bfa253d72eaab82746596bf9766) (.var ⟨678⟩))) (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨687⟩))) (.mul (.const 0x0cbab33aa73976ddd0f06e17ef930a1fe62833dffc0098296c7e8779474342b8) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨706⟩) (.var ⟨707⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨708⟩))),
+  .assert (.add (.var ⟨706⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0313ae86c248fc9beea2eb377d8408fc0e6bc70fb413165e11cfa479c267fe43) (.var ⟨147⟩)) (.mul (.const 0x35a1969d865b04b8f844f49ef3cf1b092c11cc9327d545e34b2cd2a0fb407c12) (.var ⟨156⟩))) (.mul (.const 0x3202bcc6951f578797dc8b78c98642b329f7884cffd9c4a65ab8b6bc47838824) (.var ⟨165⟩))) (.mul (.const 0x0f1066776c197fe84cfa70e49f703eb2021b2d2316ddacc90e638cbb5c511e08) (.var ⟨174⟩))) (.mul (.const 0x12cd33a01738a8f6b3c4e8d012e60686119ea4d4e40c9dd53e401d35816424f3) (.var ⟨183⟩))) (.mul (.const 0x12e399e293639f11be419360a4d4807420af8d566495e1623276c152b4d19445) (.var ⟨192⟩))) (.mul (.const 0x1517454a757d905baa7eb9b8ecb2031b3ec8ee76b3262a6bf53b280928b10883) (.var ⟨201⟩))) (.mul (.const 0x31219920a1c2d1148cce6a1e030027506062498384938bda13b5e43b290c0200) (.var ⟨210⟩))) (.mul (.const 0x35683b120e3193523d94b216b86a046ca8dd77ed6921500401376965c4c004d4) (.var ⟨219⟩))) (.mul (.const 0x3dd2cd646e3fc4b9a8878e66bd4a57e4f4fa0fbc6e1a994cad51b0fdff462960) (.var ⟨228⟩))) (.mul (.const 0x3eeacad2392db3491e1a59f4147ed9117b38f75725360932dd229c41a20e1e61) (.var ⟨237⟩))) (.mul (.const 0x1c9753e23537abbd7ed1224b442f2236ab5ed4d68627a04afe0f5da1253086e6) (.var ⟨246⟩))) (.mul (.const 0x1e760434201c677dcdace1990c6843dcd72f4f98bad6cdf46e81e4695a7eeaec) (.var ⟨255⟩))) (.mul (.const 0x3598287cd981b459c99762d2c5de45f2d2a4b865b0a3236007cbb35910a045ac) (.var ⟨264⟩))) (.mul (.const 0x1752864ce9eb5949bffd9675ed21e5bcc8036eefab0967150dd8f62af3fabf49) (.var ⟨273⟩))) (.mul (.const 0x0b19cdfe5541f32fee324072cdb00aaaa057d5cedccf17ba5080677e2a6d8234) (.var ⟨282⟩))) (.mul (.const 0x2cdf26d7178db87cd8d25f0bd6f5e20566dd3e1352d788450b3254c58c30fd65) (.var ⟨291⟩))) (.mul (.const 0x07bcb1659424cff49c7cc502873ddbab35c34d91a52d30a5060f1ecf4f124dde) (.var ⟨300⟩))) (.mul (.const 0x3e8e8e3f7f8c96d4525817df2c3652e6f0abe7566d61c0fe2802d6dea0882598) (.var ⟨309⟩))) (.mul (.const 0x3d63dd29830f56151d2c99720f6d0e4816c5d5ee9a5c2acff740bd6c1852d948) (.var ⟨318⟩))) (.mul (.const 0x3100fa0d3fd58f12df831650efb589d5e0e087c8ce281ce3a395fe9b4d8eea81) (.var ⟨327⟩))) (.mul (.const 0x239f946a8b70fe5b1f8be01f9375294a7288d31a3658faf1ccba32f38c336018) (.var ⟨336⟩))) (.mul (.const 0x2a0a0212a0e69873899360ecc726527a61fca433695242e44b49bc192e01369d) (.var ⟨345⟩))) (.mul (.const 0x1ddc1ffdcdbfcabad590842159c588c65415d35cf10c271c5c5a2ad5446fc835) (.var ⟨354⟩))) (.mul (.const 0x238789a498333bfb3d70ac970b98624e708c6e281ea51fa07a6400517c0dabfc) (.var ⟨363⟩))) (.mul (.const 0x1578b611a91b4a63273b5d573d904558d6c95491dc12053ae5c6d9306e9532b4) (.var ⟨372⟩))) (.mul (.const 0x004b74d61256e9a07dd4b9a6f052e6a64c559e55a2ac2d1dd0e50d6a7f9c8cee) (.var ⟨381⟩))) (.mul (.const 0x28dee9dc6d93194ac61a2b755935160e5abf81f4634c1e1709e292d38b755f70) (.var ⟨390⟩))) (.mul (.const 0x0c5b2ca27fe64a547e1161c0c3e32232d5e2f8655eede1305f8a33b7e58f9c04) (.var ⟨399⟩))) (.mul (.const 0x2ed75cb1b00dcab358b3210e5a52c4735f2b0c161cd24db37313b58158ec8c2f) (.var ⟨408⟩))) (.mul (.const 0x289a06ddc6e3833e2b1b3f31280cd54113448d146a2022f643e43c1195d3cd1d) (.var ⟨417⟩))) (.mul (.const 0x1b3498c3be2037db1cfeed037d4744df9a093ef7e47381f82207e4d71a1f960a) (.var ⟨426⟩))) (.mul (.const 0x2fc4f673ebd0ade84b963e04d16829f37029f20f54c9c8571555af8bff92860a) (.var ⟨435⟩))) (.mul (.const 0x0a0a6e6a58c2695d7fe17ee85cf535e04015083be846b5d9b3f363f66a12b6ab) (.var ⟨444⟩))) (.mul (.const 0x08b6f0cbd0955cd6e1a512f01f102309e933770764485346f94575e47a428c49) (.var ⟨453⟩))) (.mul (.const 0x3aac73d411eb63396a1afdceb4efc19a504f352a91e5f47e0c38a5bd9db90293) (.var ⟨462⟩))) (.mul (.const 0x2c8f92a12d6f9ee968498ff308e23cc5f9cfe543f9eb01571906d63a8f5d21ab) (.var ⟨471⟩))) (.mul (.const 0x375810c6329a2a61ebde31a740083342389c0d0777ab39dd61ea936dc4a56150) (.var ⟨480⟩))) (.mul (.const 0x0a0f0b5dabc48ab8a66a6e22a6191c70f6c9581f362630f8f82e36ca313db9e8) (.var ⟨489⟩))) (.mul (.const 0x32e19c60ac603fd86ecf079cd34f389a540babf69545bf6531a394042f8bd3e8) (.var ⟨498⟩))) (.mul (.const 0x23d1e66868702420ffe8f9f781f317f216f14e80ddabdb78c71718c5f321a7fa) (.var ⟨507⟩))) (.mul (.const 0x1d1f8313866f648178f881d799a4bed9017baded34bcbf47a7782a32cd310a95) (.var ⟨516⟩))) (.mul (.const 0x308d7e03b72c19ba0f34cac06df5c0346e55f22a8d9ffe2a08ad1c396cf79ebc) (.var ⟨525⟩))) (.mul (.const 0x124e6f5b5d26f44d9303b68ecd81467a14a52e911bb732ad1151a9fa4be970ed) (.var ⟨534⟩))) (.mul (.const 0x1af7163a44bb6e13f34c1b9bfc98603dfda81b91796fe7d4b24cf42677ea7936) (.var ⟨543⟩))) (.mul (.const 0x1dd3af7b1c1a00d48aca9bbb232799a3644957149c4188b95bc6da2693763af4) (.var ⟨552⟩))) (.mul (.const 0x39a9897389c1bcb55cd1c1d28ef7da2d44dbae4a9cdf10a8dd6c4e1fd5d77b38) (.var ⟨561⟩))) (.mul (.const 0x0d56f7349a1d8433056ffa7cdf42b17cf10bc1d8a57c5683590f67f26b6da5af) (.var ⟨570⟩))) (.mul (.const 0x0d3fea4d04ccda6c6a5ab183cbfd5f1b0ed86199ed03c48e6911448b41ac4f45) (.var ⟨579⟩))) (.mul (.const 0x349b56cfd0c39059516d052e991ff5bf238d0eab30f5ee4f891251b7eeeb461a) (.var ⟨588⟩))) (.mul (.const 0x055931cee07939557230848627382f0b11cafd2de4e5d0c04062e0f7782fc647) (.var ⟨597⟩))) (.mul (.const 0x103c2992e3ac0da058d368da6c339fe83cb63ac39807e64cb50cc6020044cc19) (.var ⟨606⟩))) (.mul (.const 0x0b67f8694f1b5af31be9eb51a41f5c1a2655f05d28b5ec860333e4ba72824e6c) (.var ⟨615⟩))) (.mul (.const 0x2be9443b82d8fda1459f30b463080bbf6718731963364be3e88fd35b728c0192) (.var ⟨624⟩))) (.mul (.const 0x39f6bb9280b61a3bcb37ed4d032770d337f815fb0338496f3c73ea1d14dd3a37) (.var ⟨633⟩))) (.mul (.const 0x0fe4bf9b60151d0176f2fad932ca85eea0304cf27aae44949b148837ca4a28b5) (.var ⟨642⟩))) (.mul (.const 0x235849675c60485ee81b31fa10332d9883f37c866c1793e640d330769a4a63dd) (.var ⟨651⟩))) (.mul (.const 0x2a80d31d06186b8185716dadf9c8c01dc10d8fec242bf49ea176d0535a822ca9) (.var ⟨660⟩))) (.mul (.const 0x118e9b5f617387c2cf883efe06cd1d25f6093dbbc6758117de8d0022136f0515) (.var ⟨669⟩))) (.mul (.const 0x3fcd8d32edc30abee3fac7db0d630befbff46e0d2f7d14178138641c1e8795de) (.var ⟨678⟩))) (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨687⟩))) (.mul (.const 0x1a0724422bfbbfcf3cb6131f9efe95e63fc872203a5cd1b6bf1fd6e2834a58fe) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨707⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0313ae86c248fc9beea2eb377d8408fc0e6bc70fb413165e11cfa479c267fe43) (.var ⟨147⟩)) (.mul (.const 0x35a1969d865b04b8f844f49ef3cf1b092c11cc9327d545e34b2cd2a0fb407c12) (.var ⟨156⟩))) (.mul (.const 0x3202bcc6951f578797dc8b78c98642b329f7884cffd9c4a65ab8b6bc47838824) (.var ⟨165⟩))) (.mul (.const 0x0f1066776c197fe84cfa70e49f703eb2021b2d2316ddacc90e638cbb5c511e08) (.var ⟨174⟩))) (.mul (.const 0x12cd33a01738a8f6b3c4e8d012e60686119ea4d4e40c9dd53e401d35816424f3) (.var ⟨183⟩))) (.mul (.const 0x12e399e293639f11be419360a4d4807420af8d566495e1623276c152b4d19445) (.var ⟨192⟩))) (.mul (.const 0x1517454a757d905baa7eb9b8ecb2031b3ec8ee76b3262a6bf53b280928b10883) (.var ⟨201⟩))) (.mul (.const 0x31219920a1c2d1148cce6a1e030027506062498384938bda13b5e43b290c0200) (.var ⟨210⟩))) (.mul (.const 0x35683b120e3193523d94b216b86a046ca8dd77ed6921500401376965c4c004d4) (.var ⟨219⟩))) (.mul (.const 0x3dd2cd646e3fc4b9a8878e66bd4a57e4f4fa0fbc6e1a994cad51b0fdff462960) (.var ⟨228⟩))) (.mul (.const 0x3eeacad2392db3491e1a59f4147ed9117b38f75725360932dd229c41a20e1e61) (.var ⟨237⟩))) (.mul (.const 0x1c9753e23537abbd7ed1224b442f2236ab5ed4d68627a04afe0f5da1253086e6) (.var ⟨246⟩))) (.mul (.const 0x1e760434201c677dcdace1990c6843dcd72f4f98bad6cdf46e81e4695a7eeaec) (.var ⟨255⟩))) (.mul (.const 0x3598287cd981b459c99762d2c5de45f2d2a4b865b0a3236007cbb35910a045ac) (.var ⟨264⟩))) (.mul (.const 0x1752864ce9eb5949bffd9675ed21e5bcc8036eefab0967150dd8f62af3fabf49) (.var ⟨273⟩))) (.mul (.const 0x0b19cdfe5541f32fee324072cdb00aaaa057d5cedccf17ba5080677e2a6d8234) (.var ⟨282⟩))) (.mul (.const 0x2cdf26d7178db87cd8d25f0bd6f5e20566dd3e1352d788450b3254c58c30fd65) (.var ⟨291⟩))) (.mul (.const 0x07bcb1659424cff49c7cc502873ddbab35c34d91a52d30a5060f1ecf4f124dde) (.var ⟨300⟩))) (.mul (.const 0x3e8e8e3f7f8c96d4525817df2c3652e6f0abe7566d61c0fe2802d6dea0882598) (.var ⟨309⟩))) (.mul (.const 0x3d63dd29830f56151d2c99720f6d0e4816c5d5ee9a5c2acff740bd6c1852d948) (.var ⟨318⟩))) (.mul (.const 0x3100fa0d3fd58f12df831650efb589d5e0e087c8ce281ce3a395fe9b4d8eea81) (.var ⟨327⟩))) (.mul (.const 0x239f946a8b70fe5b1f8be01f9375294a7288d31a3658faf1ccba32f38c336018) (.var ⟨336⟩))) (.mul (.const 0x2a0a0212a0e69873899360ecc726527a61fca433695242e44b49bc192e01369d) (.var ⟨345⟩))) (.mul (.const 0x1ddc1ffdcdbfcabad590842159c588c65415d35cf10c271c5c5a2ad5446fc835) (.var ⟨354⟩))) (.mul (.const 0x238789a498333bfb3d70ac970b98624e708c6e281ea51fa07a6400517c0dabfc) (.var ⟨363⟩))) (.mul (.const 0x1578b611a91b4a63273b5d573d904558d6c95491dc12053ae5c6d9306e9532b4) (.var ⟨372⟩))) (.mul (.const 0x004b74d61256e9a07dd4b9a6f052e6a64c559e55a2ac2d1dd0e50d6a7f9c8cee) (.var ⟨381⟩))) (.mul (.const 0x28dee9dc6d93194ac61a2b755935160e5abf81f4634c1e1709e292d38b755f70) (.var ⟨390⟩))) (.mul (.const 0x0c5b2ca27fe64a547e1161c0c3e32232d5e2f8655eede1305f8a33b7e58f9c04) (.var ⟨399⟩))) (.mul (.const 0x2ed75cb1b00dcab358b3210e5a52c4735f2b0c161cd24db37313b58158ec8c2f) (.var ⟨408⟩))) (.mul (.const 0x289a06ddc6e3833e2b1b3f31280cd54113448d146a2022f643e43c1195d3cd1d) (.var ⟨417⟩))) (.mul (.const 0x1b3498c3be2037db1cfeed037d4744df9a093ef7e47381f82207e4d71a1f960a) (.var ⟨426⟩))) (.mul (.const 0x2fc4f673ebd0ade84b963e04d16829f37029f20f54c9c8571555af8bff92860a) (.var ⟨435⟩))) (.mul (.const 0x0a0a6e6a58c2695d7fe17ee85cf535e04015083be846b5d9b3f363f66a12b6ab) (.var ⟨444⟩))) (.mul (.const 0x08b6f0cbd0955cd6e1a512f01f102309e933770764485346f94575e47a428c49) (.var ⟨453⟩))) (.mul (.const 0x3aac73d411eb63396a1afdceb4efc19a504f352a91e5f47e0c38a5bd9db90293) (.var ⟨462⟩))) (.mul (.const 0x2c8f92a12d6f9ee968498ff308e23cc5f9cfe543f9eb01571906d63a8f5d21ab) (.var ⟨471⟩))) (.mul (.const 0x375810c6329a2a61ebde31a740083342389c0d0777ab39dd61ea936dc4a56150) (.var ⟨480⟩))) (.mul (.const 0x0a0f0b5dabc48ab8a66a6e22a6191c70f6c9581f362630f8f82e36ca313db9e8) (.var ⟨489⟩))) (.mul (.const 0x32e19c60ac603fd86ecf079cd34f389a540babf69545bf6531a394042f8bd3e8) (.var ⟨498⟩))) (.mul (.const 0x23d1e66868702420ffe8f9f781f317f216f14e80ddabdb78c71718c5f321a7fa) (.var ⟨507⟩))) (.mul (.const 0x1d1f8313866f648178f881d799a4bed9017baded34bcbf47a7782a32cd310a95) (.var ⟨516⟩))) (.mul (.const 0x308d7e03b72c19ba0f34cac06df5c0346e55f22a8d9ffe2a08ad1c396cf79ebc) (.var ⟨525⟩))) (.mul (.const 0x124e6f5b5d26f44d9303b68ecd81467a14a52e911bb732ad1151a9fa4be970ed) (.var ⟨534⟩))) (.mul (.const 0x1af7163a44bb6e13f34c1b9bfc98603dfda81b91796fe7d4b24cf42677ea7936) (.var ⟨543⟩))) (.mul (.const 0x1dd3af7b1c1a00d48aca9bbb232799a3644957149c4188b95bc6da2693763af4) (.var ⟨552⟩))) (.mul (.const 0x39a9897389c1bcb55cd1c1d28ef7da2d44dbae4a9cdf10a8dd6c4e1fd5d77b38) (.var ⟨561⟩))) (.mul (.const 0x0d56f7349a1d8433056ffa7cdf42b17cf10bc1d8a57c5683590f67f26b6da5af) (.var ⟨570⟩))) (.mul (.const 0x0d3fea4d04ccda6c6a5ab183cbfd5f1b0ed86199ed03c48e6911448b41ac4f45) (.var ⟨579⟩))) (.mul (.const 0x349b56cfd0c39059516d052e991ff5bf238d0eab30f5ee4f891251b7eeeb461a) (.var ⟨588⟩))) (.mul (.const 0x055931cee07939557230848627382f0b11cafd2de4e5d0c04062e0f7782fc647) (.var ⟨597⟩))) (.mul (.const 0x103c2992e3ac0da058d368da6c339fe83cb63ac39807e64cb50cc6020044cc19) (.var ⟨606⟩))) (.mul (.const 0x0b67f8694f1b5af31be9eb51a41f5c1a2655f05d28b5ec860333e4ba72824e6c) (.var ⟨615⟩))) (.mul (.const 0x2be9443b82d8fda1459f30b463080bbf6718731963364be3e88fd35b728c0192) (.var ⟨624⟩))) (.mul (.const 0x39f6bb9280b61a3bcb37ed4d032770d337f815fb0338496f3c73ea1d14dd3a37) (.var ⟨633⟩))) (.mul (.const 0x0fe4bf9b60151d0176f2fad932ca85eea0304cf27aae44949b148837ca4a28b5) (.var ⟨642⟩))) (.mul (.const 0x235849675c60485ee81b31fa10332d9883f37c866c1793e640d330769a4a63dd) (.var ⟨651⟩))) (.mul (.const 0x2a80d31d06186b8185716dadf9c8c01dc10d8fec242bf49ea176d0535a822ca9) (.var ⟨660⟩))) (.mul (.const 0x118e9b5f617387c2cf883efe06cd1d25f6093dbbc6758117de8d0022136f0515) (.var ⟨669⟩))) (.mul (.const 0x3fcd8d32edc30abee3fac7db0d630befbff46e0d2f7d14178138641c1e8795de) (.var ⟨678⟩))) (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨687⟩))) (.mul (.const 0x1a0724422bfbbfcf3cb6131f9efe95e63fc872203a5cd1b6bf1fd6e2834a58fe) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨709⟩) (.var ⟨710⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨711⟩))),
+  .assert (.add (.var ⟨709⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨708⟩))),
+  .assert (.add (.var ⟨710⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨708⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨712⟩) (.var ⟨713⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨714⟩))),
+  .assert (.add (.var ⟨712⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨711⟩))),
+  .assert (.add (.var ⟨713⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x0313ae86c248fc9beea2eb377d8408fc0e6bc70fb413165e11cfa479c267fe43) (.var ⟨147⟩)) (.mul (.const 0x35a1969d865b04b8f844f49ef3cf1b092c11cc9327d545e34b2cd2a0fb407c12) (.var ⟨156⟩))) (.mul (.const 0x3202bcc6951f578797dc8b78c98642b329f7884cffd9c4a65ab8b6bc47838824) (.var ⟨165⟩))) (.mul (.const 0x0f1066776c197fe84cfa70e49f703eb2021b2d2316ddacc90e638cbb5c511e08) (.var ⟨174⟩))) (.mul (.const 0x12cd33a01738a8f6b3c4e8d012e60686119ea4d4e40c9dd53e401d35816424f3) (.var ⟨183⟩))) (.mul (.const 0x12e399e293639f11be419360a4d4807420af8d566495e1623276c152b4d19445) (.var ⟨192⟩))) (.mul (.const 0x1517454a757d905baa7eb9b8ecb2031b3ec8ee76b3262a6bf53b280928b10883) (.var ⟨201⟩))) (.mul (.const 0x31219920a1c2d1148cce6a1e030027506062498384938bda13b5e43b290c0200) (.var ⟨210⟩))) (.mul (.const 0x35683b120e3193523d94b216b86a046ca8dd77ed6921500401376965c4c004d4) (.var ⟨219⟩))) (.mul (.const 0x3dd2cd646e3fc4b9a8878e66bd4a57e4f4fa0fbc6e1a994cad51b0fdff462960) (.var ⟨228⟩))) (.mul (.const 0x3eeacad2392db3491e1a59f4147ed9117b38f75725360932dd229c41a20e1e61) (.var ⟨237⟩))) (.mul (.const 0x1c9753e23537abbd7ed1224b442f2236ab5ed4d68627a04afe0f5da1253086e6) (.var ⟨246⟩))) (.mul (.const 0x1e760434201c677dcdace1990c6843dcd72f4f98bad6cdf46e81e4695a7eeaec) (.var ⟨255⟩))) (.mul (.const 0x3598287cd981b459c99762d2c5de45f2d2a4b865b0a3236007cbb35910a045ac) (.var ⟨264⟩))) (.mul (.const 0x1752864ce9eb5949bffd9675ed21e5bcc8036eefab0967150dd8f62af3fabf49) (.var ⟨273⟩))) (.mul (.const 0x0b19cdfe5541f32fee324072cdb00aaaa057d5cedccf17ba5080677e2a6d8234) (.var ⟨282⟩))) (.mul (.const 0x2cdf26d7178db87cd8d25f0bd6f5e20566dd3e1352d788450b3254c58c30fd65) (.var ⟨291⟩))) (.mul (.const 0x07bcb1659424cff49c7cc502873ddbab35c34d91a52d30a5060f1ecf4f124dde) (.var ⟨300⟩))) (.mul (.const 0x3e8e8e3f7f8c96d4525817df2c3652e6f0abe7566d61c0fe2802d6dea0882598) (.var ⟨309⟩))) (.mul (.const 0x3d63dd29830f56151d2c99720f6d0e4816c5d5ee9a5c2acff740bd6c1852d948) (.var ⟨318⟩))) (.mul (.const 0x3100fa0d3fd58f12df831650efb589d5e0e087c8ce281ce3a395fe9b4d8eea81) (.var ⟨327⟩))) (.mul (.const 0x239f946a8b70fe5b1f8be01f9375294a7288d31a3658faf1ccba32f38c336018) (.var ⟨336⟩))) (.mul (.const 0x2a0a0212a0e69873899360ecc726527a61fca433695242e44b49bc192e01369d) (.var ⟨345⟩))) (.mul (.const 0x1ddc1ffdcdbfcabad590842159c588c65415d35cf10c271c5c5a2ad5446fc835) (.var ⟨354⟩))) (.mul (.const 0x238789a498333bfb3d70ac970b98624e708c6e281ea51fa07a6400517c0dabfc) (.var ⟨363⟩))) (.mul (.const 0x1578b611a91b4a63273b5d573d904558d6c95491dc12053ae5c6d9306e9532b4) (.var ⟨372⟩))) (.mul (.const 0x004b74d61256e9a07dd4b9a6f052e6a64c559e55a2ac2d1dd0e50d6a7f9c8cee) (.var ⟨381⟩))) (.mul (.const 0x28dee9dc6d93194ac61a2b755935160e5abf81f4634c1e1709e292d38b755f70) (.var ⟨390⟩))) (.mul (.const 0x0c5b2ca27fe64a547e1161c0c3e32232d5e2f8655eede1305f8a33b7e58f9c04) (.var ⟨399⟩))) (.mul (.const 0x2ed75cb1b00dcab358b3210e5a52c4735f2b0c161cd24db37313b58158ec8c2f) (.var ⟨408⟩))) (.mul (.const 0x289a06ddc6e3833e2b1b3f31280cd54113448d146a2022f643e43c1195d3cd1d) (.var ⟨417⟩))) (.mul (.const 0x1b3498c3be2037db1cfeed037d4744df9a093ef7e47381f82207e4d71a1f960a) (.var ⟨426⟩))) (.mul (.const 0x2fc4f673ebd0ade84b963e04d16829f37029f20f54c9c8571555af8bff92860a) (.var ⟨435⟩))) (.mul (.const 0x0a0a6e6a58c2695d7fe17ee85cf535e04015083be846b5d9b3f363f66a12b6ab) (.var ⟨444⟩))) (.mul (.const 0x08b6f0cbd0955cd6e1a512f01f102309e933770764485346f94575e47a428c49) (.var ⟨453⟩))) (.mul (.const 0x3aac73d411eb63396a1afdceb4efc19a504f352a91e5f47e0c38a5bd9db90293) (.var ⟨462⟩))) (.mul (.const 0x2c8f92a12d6f9ee968498ff308e23cc5f9cfe543f9eb01571906d63a8f5d21ab) (.var ⟨471⟩))) (.mul (.const 0x375810c6329a2a61ebde31a740083342389c0d0777ab39dd61ea936dc4a56150) (.var ⟨480⟩))) (.mul (.const 0x0a0f0b5dabc48ab8a66a6e22a6191c70f6c9581f362630f8f82e36ca313db9e8) (.var ⟨489⟩))) (.mul (.const 0x32e19c60ac603fd86ecf079cd34f389a540babf69545bf6531a394042f8bd3e8) (.var ⟨498⟩))) (.mul (.const 0x23d1e66868702420ffe8f9f781f317f216f14e80ddabdb78c71718c5f321a7fa) (.var ⟨507⟩))) (.mul (.const 0x1d1f8313866f648178f881d799a4bed9017baded34bcbf47a7782a32cd310a95) (.var ⟨516⟩))) (.mul (.const 0x308d7e03b72c19ba0f34cac06df5c0346e55f22a8d9ffe2a08ad1c396cf79ebc) (.var ⟨525⟩))) (.mul (.const 0x124e6f5b5d26f44d9303b68ecd81467a14a52e911bb732ad1151a9fa4be970ed) (.var ⟨534⟩))) (.mul (.const 0x1af7163a44bb6e13f34c1b9bfc98603dfda81b91796fe7d4b24cf42677ea7936) (.var ⟨543⟩))) (.mul (.const 0x1dd3af7b1c1a00d48aca9bbb232799a3644957149c4188b95bc6da2693763af4) (.var ⟨552⟩))) (.mul (.const 0x39a9897389c1bcb55cd1c1d28ef7da2d44dbae4a9cdf10a8dd6c4e1fd5d77b38) (.var ⟨561⟩))) (.mul (.const 0x0d56f7349a1d8433056ffa7cdf42b17cf10bc1d8a57c5683590f67f26b6da5af) (.var ⟨570⟩))) (.mul (.const 0x0d3fea4d04ccda6c6a5ab183cbfd5f1b0ed86199ed03c48e6911448b41ac4f45) (.var ⟨579⟩))) (.mul (.const 0x349b56cfd0c39059516d052e991ff5bf238d0eab30f5ee4f891251b7eeeb461a) (.var ⟨588⟩))) (.mul (.const 0x055931cee07939557230848627382f0b11cafd2de4e5d0c04062e0f7782fc647) (.var ⟨597⟩))) (.mul (.const 0x103c2992e3ac0da058d368da6c339fe83cb63ac39807e64cb50cc6020044cc19) (.var ⟨606⟩))) (.mul (.const 0x0b67f8694f1b5af31be9eb51a41f5c1a2655f05d28b5ec860333e4ba72824e6c) (.var ⟨615⟩))) (.mul (.const 0x2be9443b82d8fda1459f30b463080bbf6718731963364be3e88fd35b728c0192) (.var ⟨624⟩))) (.mul (.const 0x39f6bb9280b61a3bcb37ed4d032770d337f815fb0338496f3c73ea1d14dd3a37) (.var ⟨633⟩))) (.mul (.const 0x0fe4bf9b60151d0176f2fad932ca85eea0304cf27aae44949b148837ca4a28b5) (.var ⟨642⟩))) (.mul (.const 0x235849675c60485ee81b31fa10332d9883f37c866c1793e640d330769a4a63dd) (.var ⟨651⟩))) (.mul (.const 0x2a80d31d06186b8185716dadf9c8c01dc10d8fec242bf49ea176d0535a822ca9) (.var ⟨660⟩))) (.mul (.const 0x118e9b5f617387c2cf883efe06cd1d25f6093dbbc6758117de8d0022136f0515) (.var ⟨669⟩))) (.mul (.const 0x3fcd8d32edc30abee3fac7db0d630befbff46e0d2f7d14178138641c1e8795de) (.var ⟨678⟩))) (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨687⟩))) (.mul (.const 0x1a0724422bfbbfcf3cb6131f9efe95e63fc872203a5cd1b6bf1fd6e2834a58fe) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨715⟩) (.var ⟨716⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨717⟩))),
+  .assert (.add (.var ⟨715⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2e7c372897e14cbcd2204385660ab10b2e85f3de8eb466aa923eeeee31f4c070) (.var ⟨147⟩)) (.mul (.const 0x12e5fb69041e27eb8e9c141d6c62f613f93f0c8e746116f3d95cfb7bf0654274) (.var ⟨156⟩))) (.mul (.const 0x1e65ffdbd2eceb2b14c5586c35eff2e7b9a13817a8b8d661ebeb610a68975c08) (.var ⟨165⟩))) (.mul (.const 0x3bd7946b410142c9987cc9f7e1dc0f8c7544b041306904f30649265c66a47364) (.var ⟨174⟩))) (.mul (.const 0x161a4fc17d8c04ab5c763c08539988dbd012fb6c91ba9f26fd6c72d951b2f567) (.var ⟨183⟩))) (.mul (.const 0x31288e88a8416d53f99a03aeffd16f3364bd81c23dd3007772363af5def9f6a0) (.var ⟨192⟩))) (.mul (.const 0x0b4d74c5a930010e86c4e45caae8b412f49c5d5bd66b8ad48d9251e0b810d1aa) (.var ⟨201⟩))) (.mul (.const 0x27acc4b2079a06b1fb2e3739fc1956697356790e15258c0202c1f5fdb39ed18e) (.var ⟨210⟩))) (.mul (.const 0x2e3147af3083bc64b2036e554994971b6401275ee93b06ca89d4393e6c3512fb) (.var ⟨219⟩))) (.mul (.const 0x03ef2dfc2f8d34a08ac55ebe94ed5b2dda3b14cf88fc43dfed29c0d0b6a492be) (.var ⟨228⟩))) (.mul (.const 0x14628e1523e1ed3bf6da85c328ee718ebcfd4775be94c3483bcdb83905ca104a) (.var ⟨237⟩))) (.mul (.const 0x09c8a1c8a80918d3847adb0117fcc029711240b89cd9f7a89ccbe08148eff1e4) (.var ⟨246⟩))) (.mul (.const 0x09ac0e97e4c8751ba490551e8c465f6aec4fe321aa26448826cf76791aaf0de8) (.var ⟨255⟩))) (.mul (.const 0x388e81e61a49fca7c66ef43e236a5bc909acca534efbc231039690abc5db4ccb) (.var ⟨264⟩))) (.mul (.const 0x27d1f14aa52fb1187b7a9af5fabd0f6787a3c6574aa156dd09cdce376e17df08) (.var ⟨273⟩))) (.mul (.const 0x272220bd58584525cf207f15a725973c31a9f7489c4f1d4733298ab12553b6a0) (.var ⟨282⟩))) (.mul (.const 0x2dd43f9fd8a5b67d789e6f847f45783242ab308a39f0b69df50a3aaccbb14ffa) (.var ⟨291⟩))) (.mul (.const 0x14b300cbd0565285f1f78f21cc354a598033f2dff146711b446bb2eaf63f0e40) (.var ⟨300⟩))) (.mul (.const 0x078eb05cdb7ce5a997bd9b500afb2d62abcd649e453f83fa97712f9e70c71893) (.var ⟨309⟩))) (.mul (.const 0x2be45c24925fbee713295b43dd83c684019f6329c3ef9e71a7ac527e68df52de) (.var ⟨318⟩))) (.mul (.const 0x09ec4c5a1b4c91305e731f6c91234490f4ee60abc87137d5d18395b4b3e2cd1c) (.var ⟨327⟩))) (.mul (.const 0x3583a72b78341b0478e3a5db6b97a10e368fdb270902e00063608e30397513b7) (.var ⟨336⟩))) (.mul (.const 0x2d55876ee5446fe35d5feefc1767459eb74630f35b5f978add0c0035f13453c3) (.var ⟨345⟩))) (.mul (.const 0x19dc3e74a5c312c0c7eca573eb80e8d68794f3a063f60939d356a79448c9ef6e) (.var ⟨354⟩))) (.mul (.const 0x0ae689fd532eecc1adc579aa7733db4b75b3113ab119aaaa48c2a3b9257f7971) (.var ⟨363⟩))) (.mul (.const 0x3489f98cdf3f5de6dd3ba99a771b03a9866d04de2e50fb5b1b43d6f95a0e1236) (.var ⟨372⟩))) (.mul (.const 0x3d7dd7667318f921d35609ef869758d804b38dea5901d1af79ff5a244d155c27) (.var ⟨381⟩))) (.mul (.const 0x19b19249ed2e34fb6f616e589ac1d4e7917058eb285b973b6d57aeb425e5f5ec) (.var ⟨390⟩))) (.mul (.const 0x2fbb1c35612df98ea1ec921c1da37b221e41840d9c063ad9a69891d4cde871df) (.var ⟨399⟩))) (.mul (.const 0x2842864ea144baabd4278006dcdcafa4b104a2fb7fe766ef6482ff92ed3b9a8c) (.var ⟨408⟩))) (.mul (.const 0x0c4bb92d31f2083d11d3f83b0e07bbc8e4b727b5daac9c7605ff5110a84949b3) (.var ⟨417⟩))) (.mul (.const 0x14835158c4237a4b9af5cfab3ab34340df668b5556e44667773f635fb06ddf79) (.var ⟨426⟩))) (.mul (.const 0x108ff8e2962df8a69d2a23cb344bdc63a6613765dcfef892053d5bf507cfd2b7) (.var ⟨435⟩))) (.mul (.const 0x14aaae2bf68647200997aa076b43c4350a81995d01d016d3e6b803fe842bc68b) (.var ⟨444⟩))) (.mul (.const 0x2939baccc7b5c5c690f0c1083d50ad0a04bb2ae2ed0fb76faabe3e796a166adc) (.var ⟨453⟩))) (.mul (.const 0x25eb4964cfc221f665af4cdf8824edab0d7b18aa4c043ab56da17564d2023bad) (.var ⟨462⟩))) (.mul (.const 0x04696b3c8d8a85d4aa223fd92221761d34b1ab53d629e9b6bce9779ed4364275) (.var ⟨471⟩))) (.mul (.const 0x2275041325b48ef2911929c490e05e26255aeebc90b4aa652d3b6fad4e94edda) (.var ⟨480⟩))) (.mul (.const 0x2bd2dafc499d4ddd83a2dc14f5a1c8fd5e6a92e95e08965725a5f43c0c0f496a) (.var ⟨489⟩))) (.mul (.const 0x0e8c5084cb7f14851a4abed4f4f779fd03dec86721b6b73693fb75d9b0da502b) (.var ⟨498⟩))) (.mul (.const 0x1eafec582dcb64253f38170426d6eeecfbb23b8ea43da0831691a788ece625bf) (.var ⟨507⟩))) (.mul (.const 0x21776a79ad02cd3dbb5a00d50f6b30be3a1e051122f759629587efc0cc53c313) (.var ⟨516⟩))) (.mul (.const 0x190596aa4711db0c38e915de6a2c09d5bc234ab106fabd2768f86d25b44285f1) (.var ⟨525⟩))) (.mul (.const 0x2eb059c10e7c123fd686be07c93252c010c54230c101ea91e481ba4bac63f76f) (.var ⟨534⟩))) (.mul (.const 0x26ee158ef6f48a4c72de98fb10181881f94e1da5100c0852e13e0363b57cc63b) (.var ⟨543⟩))) (.mul (.const 0x09ed0f03d33f35e3dc38acbf033ecfe2cfc7c5155f7190bc5b5d04c4a75f5f27) (.var ⟨552⟩))) (.mul (.const 0x237f04f180f7f23d3384227cd7bb7dafac83f0235c43aae6aa4e84daa0ef4ebf) (.var ⟨561⟩))) (.mul (.const 0x1b5370a986dd001fffb7fdf8b6fa1aea1574399460379882956b8ea6f6d09b18) (.var ⟨570⟩))) (.mul (.const 0x1baa297d70cdcd48565e490912eb01312a801e76cd08f3b034d27b4e89454a39) (.var ⟨579⟩))) (.mul (.const 0x3cdc3500728d7c1f478719181e0df2fcf4947c54e61d320b1a2aad5e349a055f) (.var ⟨588⟩))) (.mul (.const 0x21dd23402d1e68cbb5d2442ffaf2c845052f3f46c330119f379d87bb14da1e42) (.var ⟨597⟩))) (.mul (.const 0x2eb016e376fdae6d7f9aa2854ccbc7f2b734ba056b5400e879970cea4d1ad719) (.var ⟨606⟩))) (.mul (.const 0x2ca989bdfafb73e61929b02943fd4dd8a3569cef0f3abd9a1b8cdb2c939ca2df) (.var ⟨615⟩))) (.mul (.const 0x23ad1e9b5fcc3fad6c35306ece28f5aec446ea45805b584702ac995a07ed6628) (.var ⟨624⟩))) (.mul (.const 0x12673aa271268afda169eda815c146e1ace1f64b8bc3c0e7a899f68149d796e3) (.var ⟨633⟩))) (.mul (.const 0x012c7105cec3ccea84a7e3cd37ae1477852015370343de83d46cce948f21c3ef) (.var ⟨642⟩))) (.mul (.const 0x31f6d5f84deea31145d1f2138b5833cc1ad9b1a43e5d54fe15b8e5244ff601db) (.var ⟨651⟩))) (.mul (.const 0x0de9222e6a9f43ce15de404ea9689926e14712aa7c05d2eda3f4270b038eb778) (.var ⟨660⟩))) (.mul (.const 0x0a078c3f1702f4331bab76d37b6aa23b63fc437b0e95ca1d4276bad2b91d67cd) (.var ⟨669⟩))) (.mul (.const 0x37b4159d8b092ec66cd765223eedb73353c804a817e1e376a1c5b7f44b9d4206) (.var ⟨678⟩))) (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨687⟩))) (.mul (.const 0x2b6c42a594a4e25eb1c98fdce4c1fad676d7791a632c4f2c3e957fb87f91cac4) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨716⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2e7c372897e14cbcd2204385660ab10b2e85f3de8eb466aa923eeeee31f4c070) (.var ⟨147⟩)) (.mul (.const 0x12e5fb69041e27eb8e9c141d6c62f613f93f0c8e746116f3d95cfb7bf0654274) (.var ⟨156⟩))) (.mul (.const 0x1e65ffdbd2eceb2b14c5586c35eff2e7b9a13817a8b8d661ebeb610a68975c08) (.var ⟨165⟩))) (.mul (.const 0x3bd7946b410142c9987cc9f7e1dc0f8c7544b041306904f30649265c66a47364) (.var ⟨174⟩))) (.mul (.const 0x161a4fc17d8c04ab5c763c08539988dbd012fb6c91ba9f26fd6c72d951b2f567) (.var ⟨183⟩))) (.mul (.const 0x31288e88a8416d53f99a03aeffd16f3364bd81c23dd3007772363af5def9f6a0) (.var ⟨192⟩))) (.mul (.const 0x0b4d74c5a930010e86c4e45caae8b412f49c5d5bd66b8ad48d9251e0b810d1aa) (.var ⟨201⟩))) (.mul (.const 0x27acc4b2079a06b1fb2e3739fc1956697356790e15258c0202c1f5fdb39ed18e) (.var ⟨210⟩))) (.mul (.const 0x2e3147af3083bc64b2036e554994971b6401275ee93b06ca89d4393e6c3512fb) (.var ⟨219⟩))) (.mul (.const 0x03ef2dfc2f8d34a08ac55ebe94ed5b2dda3b14cf88fc43dfed29c0d0b6a492be) (.var ⟨228⟩))) (.mul (.const 0x14628e1523e1ed3bf6da85c328ee718ebcfd4775be94c3483bcdb83905ca104a) (.var ⟨237⟩))) (.mul (.const 0x09c8a1c8a80918d3847adb0117fcc029711240b89cd9f7a89ccbe08148eff1e4) (.var ⟨246⟩))) (.mul (.const 0x09ac0e97e4c8751ba490551e8c465f6aec4fe321aa26448826cf76791aaf0de8) (.var ⟨255⟩))) (.mul (.const 0x388e81e61a49fca7c66ef43e236a5bc909acca534efbc231039690abc5db4ccb) (.var ⟨264⟩))) (.mul (.const 0x27d1f14aa52fb1187b7a9af5fabd0f6787a3c6574aa156dd09cdce376e17df08) (.var ⟨273⟩))) (.mul (.const 0x272220bd58584525cf207f15a725973c31a9f7489c4f1d4733298ab12553b6a0) (.var ⟨282⟩))) (.mul (.const 0x2dd43f9fd8a5b67d789e6f847f45783242ab308a39f0b69df50a3aaccbb14ffa) (.var ⟨291⟩))) (.mul (.const 0x14b300cbd0565285f1f78f21cc354a598033f2dff146711b446bb2eaf63f0e40) (.var ⟨300⟩))) (.mul (.const 0x078eb05cdb7ce5a997bd9b500afb2d62abcd649e453f83fa97712f9e70c71893) (.var ⟨309⟩))) (.mul (.const 0x2be45c24925fbee713295b43dd83c684019f6329c3ef9e71a7ac527e68df52de) (.var ⟨318⟩))) (.mul (.const 0x09ec4c5a1b4c91305e731f6c91234490f4ee60abc87137d5d18395b4b3e2cd1c) (.var ⟨327⟩))) (.mul (.const 0x3583a72b78341b0478e3a5db6b97a10e368fdb270902e00063608e30397513b7) (.var ⟨336⟩))) (.mul (.const 0x2d55876ee5446fe35d5feefc1767459eb74630f35b5f978add0c0035f13453c3) (.var ⟨345⟩))) (.mul (.const 0x19dc3e74a5c312c0c7eca573eb80e8d68794f3a063f60939d356a79448c9ef6e) (.var ⟨354⟩))) (.mul (.const 0x0ae689fd532eecc1adc579aa7733db4b75b3113ab119aaaa48c2a3b9257f7971) (.var ⟨363⟩))) (.mul (.const 0x3489f98cdf3f5de6dd3ba99a771b03a9866d04de2e50fb5b1b43d6f95a0e1236) (.var ⟨372⟩))) (.mul (.const 0x3d7dd7667318f921d35609ef869758d804b38dea5901d1af79ff5a244d155c27) (.var ⟨381⟩))) (.mul (.const 0x19b19249ed2e34fb6f616e589ac1d4e7917058eb285b973b6d57aeb425e5f5ec) (.var ⟨390⟩))) (.mul (.const 0x2fbb1c35612df98ea1ec921c1da37b221e41840d9c063ad9a69891d4cde871df) (.var ⟨399⟩))) (.mul (.const 0x2842864ea144baabd4278006dcdcafa4b104a2fb7fe766ef6482ff92ed3b9a8c) (.var ⟨408⟩))) (.mul (.const 0x0c4bb92d31f2083d11d3f83b0e07bbc8e4b727b5daac9c7605ff5110a84949b3) (.var ⟨417⟩))) (.mul (.const 0x14835158c4237a4b9af5cfab3ab34340df668b5556e44667773f635fb06ddf79) (.var ⟨426⟩))) (.mul (.const 0x108ff8e2962df8a69d2a23cb344bdc63a6613765dcfef892053d5bf507cfd2b7) (.var ⟨435⟩))) (.mul (.const 0x14aaae2bf68647200997aa076b43c4350a81995d01d016d3e6b803fe842bc68b) (.var ⟨444⟩))) (.mul (.const 0x2939baccc7b5c5c690f0c1083d50ad0a04bb2ae2ed0fb76faabe3e796a166adc) (.var ⟨453⟩))) (.mul (.const 0x25eb4964cfc221f665af4cdf8824edab0d7b18aa4c043ab56da17564d2023bad) (.var ⟨462⟩))) (.mul (.const 0x04696b3c8d8a85d4aa223fd92221761d34b1ab53d629e9b6bce9779ed4364275) (.var ⟨471⟩))) (.mul (.const 0x2275041325b48ef2911929c490e05e26255aeebc90b4aa652d3b6fad4e94edda) (.var ⟨480⟩))) (.mul (.const 0x2bd2dafc499d4ddd83a2dc14f5a1c8fd5e6a92e95e08965725a5f43c0c0f496a) (.var ⟨489⟩))) (.mul (.const 0x0e8c5084cb7f14851a4abed4f4f779fd03dec86721b6b73693fb75d9b0da502b) (.var ⟨498⟩))) (.mul (.const 0x1eafec582dcb64253f38170426d6eeecfbb23b8ea43da0831691a788ece625bf) (.var ⟨507⟩))) (.mul (.const 0x21776a79ad02cd3dbb5a00d50f6b30be3a1e051122f759629587efc0cc53c313) (.var ⟨516⟩))) (.mul (.const 0x190596aa4711db0c38e915de6a2c09d5bc234ab106fabd2768f86d25b44285f1) (.var ⟨525⟩))) (.mul (.const 0x2eb059c10e7c123fd686be07c93252c010c54230c101ea91e481ba4bac63f76f) (.var ⟨534⟩))) (.mul (.const 0x26ee158ef6f48a4c72de98fb10181881f94e1da5100c0852e13e0363b57cc63b) (.var ⟨543⟩))) (.mul (.const 0x09ed0f03d33f35e3dc38acbf033ecfe2cfc7c5155f7190bc5b5d04c4a75f5f27) (.var ⟨552⟩))) (.mul (.const 0x237f04f180f7f23d3384227cd7bb7dafac83f0235c43aae6aa4e84daa0ef4ebf) (.var ⟨561⟩))) (.mul (.const 0x1b5370a986dd001fffb7fdf8b6fa1aea1574399460379882956b8ea6f6d09b18) (.var ⟨570⟩))) (.mul (.const 0x1baa297d70cdcd48565e490912eb01312a801e76cd08f3b034d27b4e89454a39) (.var ⟨579⟩))) (.mul (.const 0x3cdc3500728d7c1f478719181e0df2fcf4947c54e61d320b1a2aad5e349a055f) (.var ⟨588⟩))) (.mul (.const 0x21dd23402d1e68cbb5d2442ffaf2c845052f3f46c330119f379d87bb14da1e42) (.var ⟨597⟩))) (.mul (.const 0x2eb016e376fdae6d7f9aa2854ccbc7f2b734ba056b5400e879970cea4d1ad719) (.var ⟨606⟩))) (.mul (.const 0x2ca989bdfafb73e61929b02943fd4dd8a3569cef0f3abd9a1b8cdb2c939ca2df) (.var ⟨615⟩))) (.mul (.const 0x23ad1e9b5fcc3fad6c35306ece28f5aec446ea45805b584702ac995a07ed6628) (.var ⟨624⟩))) (.mul (.const 0x12673aa271268afda169eda815c146e1ace1f64b8bc3c0e7a899f68149d796e3) (.var ⟨633⟩))) (.mul (.const 0x012c7105cec3ccea84a7e3cd37ae1477852015370343de83d46cce948f21c3ef) (.var ⟨642⟩))) (.mul (.const 0x31f6d5f84deea31145d1f2138b5833cc1ad9b1a43e5d54fe15b8e5244ff601db) (.var ⟨651⟩))) (.mul (.const 0x0de9222e6a9f43ce15de404ea9689926e14712aa7c05d2eda3f4270b038eb778) (.var ⟨660⟩))) (.mul (.const 0x0a078c3f1702f4331bab76d37b6aa23b63fc437b0e95ca1d4276bad2b91d67cd) (.var ⟨669⟩))) (.mul (.const 0x37b4159d8b092ec66cd765223eedb73353c804a817e1e376a1c5b7f44b9d4206) (.var ⟨678⟩))) (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨687⟩))) (.mul (.const 0x2b6c42a594a4e25eb1c98fdce4c1fad676d7791a632c4f2c3e957fb87f91cac4) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨718⟩) (.var ⟨719⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩))),
+  .assert (.add (.var ⟨718⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨717⟩))),
+  .assert (.add (.var ⟨719⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨717⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨721⟩) (.var ⟨722⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨723⟩))),
+  .assert (.add (.var ⟨721⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨720⟩))),
+  .assert (.add (.var ⟨722⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x2e7c372897e14cbcd2204385660ab10b2e85f3de8eb466aa923eeeee31f4c070) (.var ⟨147⟩)) (.mul (.const 0x12e5fb69041e27eb8e9c141d6c62f613f93f0c8e746116f3d95cfb7bf0654274) (.var ⟨156⟩))) (.mul (.const 0x1e65ffdbd2eceb2b14c5586c35eff2e7b9a13817a8b8d661ebeb610a68975c08) (.var ⟨165⟩))) (.mul (.const 0x3bd7946b410142c9987cc9f7e1dc0f8c7544b041306904f30649265c66a47364) (.var ⟨174⟩))) (.mul (.const 0x161a4fc17d8c04ab5c763c08539988dbd012fb6c91ba9f26fd6c72d951b2f567) (.var ⟨183⟩))) (.mul (.const 0x31288e88a8416d53f99a03aeffd16f3364bd81c23dd3007772363af5def9f6a0) (.var ⟨192⟩))) (.mul (.const 0x0b4d74c5a930010e86c4e45caae8b412f49c5d5bd66b8ad48d9251e0b810d1aa) (.var ⟨201⟩))) (.mul (.const 0x27acc4b2079a06b1fb2e3739fc1956697356790e15258c0202c1f5fdb39ed18e) (.var ⟨210⟩))) (.mul (.const 0x2e3147af3083bc64b2036e554994971b6401275ee93b06ca89d4393e6c3512fb) (.var ⟨219⟩))) (.mul (.const 0x03ef2dfc2f8d34a08ac55ebe94ed5b2dda3b14cf88fc43dfed29c0d0b6a492be) (.var ⟨228⟩))) (.mul (.const 0x14628e1523e1ed3bf6da85c328ee718ebcfd4775be94c3483bcdb83905ca104a) (.var ⟨237⟩))) (.mul (.const 0x09c8a1c8a80918d3847adb0117fcc029711240b89cd9f7a89ccbe08148eff1e4) (.var ⟨246⟩))) (.mul (.const 0x09ac0e97e4c8751ba490551e8c465f6aec4fe321aa26448826cf76791aaf0de8) (.var ⟨255⟩))) (.mul (.const 0x388e81e61a49fca7c66ef43e236a5bc909acca534efbc231039690abc5db4ccb) (.var ⟨264⟩))) (.mul (.const 0x27d1f14aa52fb1187b7a9af5fabd0f6787a3c6574aa156dd09cdce376e17df08) (.var ⟨273⟩))) (.mul (.const 0x272220bd58584525cf207f15a725973c31a9f7489c4f1d4733298ab12553b6a0) (.var ⟨282⟩))) (.mul (.const 0x2dd43f9fd8a5b67d789e6f847f45783242ab308a39f0b69df50a3aaccbb14ffa) (.var ⟨291⟩))) (.mul (.const 0x14b300cbd0565285f1f78f21cc354a598033f2dff146711b446bb2eaf63f0e40) (.var ⟨300⟩))) (.mul (.const 0x078eb05cdb7ce5a997bd9b500afb2d62abcd649e453f83fa97712f9e70c71893) (.var ⟨309⟩))) (.mul (.const 0x2be45c24925fbee713295b43dd83c684019f6329c3ef9e71a7ac527e68df52de) (.var ⟨318⟩))) (.mul (.const 0x09ec4c5a1b4c91305e731f6c91234490f4ee60abc87137d5d18395b4b3e2cd1c) (.var ⟨327⟩))) (.mul (.const 0x3583a72b78341b0478e3a5db6b97a10e368fdb270902e00063608e30397513b7) (.var ⟨336⟩))) (.mul (.const 0x2d55876ee5446fe35d5feefc1767459eb74630f35b5f978add0c0035f13453c3) (.var ⟨345⟩))) (.mul (.const 0x19dc3e74a5c312c0c7eca573eb80e8d68794f3a063f60939d356a79448c9ef6e) (.var ⟨354⟩))) (.mul (.const 0x0ae689fd532eecc1adc579aa7733db4b75b3113ab119aaaa48c2a3b9257f7971) (.var ⟨363⟩))) (.mul (.const 0x3489f98cdf3f5de6dd3ba99a771b03a9866d04de2e50fb5b1b43d6f95a0e1236) (.var ⟨372⟩))) (.mul (.const 0x3d7dd7667318f921d35609ef869758d804b38dea5901d1af79ff5a244d155c27) (.var ⟨381⟩))) (.mul (.const 0x19b19249ed2e34fb6f616e589ac1d4e7917058eb285b973b6d57aeb425e5f5ec) (.var ⟨390⟩))) (.mul (.const 0x2fbb1c35612df98ea1ec921c1da37b221e41840d9c063ad9a69891d4cde871df) (.var ⟨399⟩))) (.mul (.const 0x2842864ea144baabd4278006dcdcafa4b104a2fb7fe766ef6482ff92ed3b9a8c) (.var ⟨408⟩))) (.mul (.const 0x0c4bb92d31f2083d11d3f83b0e07bbc8e4b727b5daac9c7605ff5110a84949b3) (.var ⟨417⟩))) (.mul (.const 0x14835158c4237a4b9af5cfab3ab34340df668b5556e44667773f635fb06ddf79) (.var ⟨426⟩))) (.mul (.const 0x108ff8e2962df8a69d2a23cb344bdc63a6613765dcfef892053d5bf507cfd2b7) (.var ⟨435⟩))) (.mul (.const 0x14aaae2bf68647200997aa076b43c4350a81995d01d016d3e6b803fe842bc68b) (.var ⟨444⟩))) (.mul (.const 0x2939baccc7b5c5c690f0c1083d50ad0a04bb2ae2ed0fb76faabe3e796a166adc) (.var ⟨453⟩))) (.mul (.const 0x25eb4964cfc221f665af4cdf8824edab0d7b18aa4c043ab56da17564d2023bad) (.var ⟨462⟩))) (.mul (.const 0x04696b3c8d8a85d4aa223fd92221761d34b1ab53d629e9b6bce9779ed4364275) (.var ⟨471⟩))) (.mul (.const 0x2275041325b48ef2911929c490e05e26255aeebc90b4aa652d3b6fad4e94edda) (.var ⟨480⟩))) (.mul (.const 0x2bd2dafc499d4ddd83a2dc14f5a1c8fd5e6a92e95e08965725a5f43c0c0f496a) (.var ⟨489⟩))) (.mul (.const 0x0e8c5084cb7f14851a4abed4f4f779fd03dec86721b6b73693fb75d9b0da502b) (.var ⟨498⟩))) (.mul (.const 0x1eafec582dcb64253f38170426d6eeecfbb23b8ea43da0831691a788ece625bf) (.var ⟨507⟩))) (.mul (.const 0x21776a79ad02cd3dbb5a00d50f6b30be3a1e051122f759629587efc0cc53c313) (.var ⟨516⟩))) (.mul (.const 0x190596aa4711db0c38e915de6a2c09d5bc234ab106fabd2768f86d25b44285f1) (.var ⟨525⟩))) (.mul (.const 0x2eb059c10e7c123fd686be07c93252c010c54230c101ea91e481ba4bac63f76f) (.var ⟨534⟩))) (.mul (.const 0x26ee158ef6f48a4c72de98fb10181881f94e1da5100c0852e13e0363b57cc63b) (.var ⟨543⟩))) (.mul (.const 0x09ed0f03d33f35e3dc38acbf033ecfe2cfc7c5155f7190bc5b5d04c4a75f5f27) (.var ⟨552⟩))) (.mul (.const 0x237f04f180f7f23d3384227cd7bb7dafac83f0235c43aae6aa4e84daa0ef4ebf) (.var ⟨561⟩))) (.mul (.const 0x1b5370a986dd001fffb7fdf8b6fa1aea1574399460379882956b8ea6f6d09b18) (.var ⟨570⟩))) (.mul (.const 0x1baa297d70cdcd48565e490912eb01312a801e76cd08f3b034d27b4e89454a39) (.var ⟨579⟩))) (.mul (.const 0x3cdc3500728d7c1f478719181e0df2fcf4947c54e61d320b1a2aad5e349a055f) (.var ⟨588⟩))) (.mul (.const 0x21dd23402d1e68cbb5d2442ffaf2c845052f3f46c330119f379d87bb14da1e42) (.var ⟨597⟩))) (.mul (.const 0x2eb016e376fdae6d7f9aa2854ccbc7f2b734ba056b5400e879970cea4d1ad719) (.var ⟨606⟩))) (.mul (.const 0x2ca989bdfafb73e61929b02943fd4dd8a3569cef0f3abd9a1b8cdb2c939ca2df) (.var ⟨615⟩))) (.mul (.const 0x23ad1e9b5fcc3fad6c35306ece28f5aec446ea45805b584702ac995a07ed6628) (.var ⟨624⟩))) (.mul (.const 0x12673aa271268afda169eda815c146e1ace1f64b8bc3c0e7a899f68149d796e3) (.var ⟨633⟩))) (.mul (.const 0x012c7105cec3ccea84a7e3cd37ae1477852015370343de83d46cce948f21c3ef) (.var ⟨642⟩))) (.mul (.const 0x31f6d5f84deea31145d1f2138b5833cc1ad9b1a43e5d54fe15b8e5244ff601db) (.var ⟨651⟩))) (.mul (.const 0x0de9222e6a9f43ce15de404ea9689926e14712aa7c05d2eda3f4270b038eb778) (.var ⟨660⟩))) (.mul (.const 0x0a078c3f1702f4331bab76d37b6aa23b63fc437b0e95ca1d4276bad2b91d67cd) (.var ⟨669⟩))) (.mul (.const 0x37b4159d8b092ec66cd765223eedb73353c804a817e1e376a1c5b7f44b9d4206) (.var ⟨678⟩))) (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨687⟩))) (.mul (.const 0x2b6c42a594a4e25eb1c98fdce4c1fad676d7791a632c4f2c3e957fb87f91cac4) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨724⟩) (.var ⟨725⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨726⟩))),
+  .assert (.add (.var ⟨724⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x20cafc22e46186e3a9c406f2089b9fc961cdcabb2c07e0d6917f208391ca96ed) (.var ⟨147⟩)) (.mul (.const 0x37352983db712e3ad1d44fb92ba9b7bd665aa418a92b84c9a7fbe96034547fa4) (.var ⟨156⟩))) (.mul (.const 0x06031656f603c7b634efdbb0a63c62bfc60b04fabb722354d7cd9d9f4dcf5bfb) (.var ⟨165⟩))) (.mul (.const 0x36205a666b111d9808b1ea5de6c98cfb17e90c382cd6d7bb2f4fb2998eb049a1) (.var ⟨174⟩))) (.mul (.const 0x3414a1946cf8b38cb95185e958f98040a393bc3e7b4151c108d69cae8cca83f3) (.var ⟨183⟩))) (.mul (.const 0x136a63358a0eb1e2be8746a98be53e4b1b974b2fad07f4d116a6c93e92f01535) (.var ⟨192⟩))) (.mul (.const 0x04b61207d497de5ec85771a5eb46646d941a0dd19d469b452bcffefc7c0c091a) (.var ⟨201⟩))) (.mul (.const 0x2639aea7cce09cd498d315f8f214d53bcc6e98e2f1197421c9c02867b9f460a2) (.var ⟨210⟩))) (.mul (.const 0x3d801e6f6ac392950174e31f49f74137494f062d14f4baa2803425decb2c22b8) (.var ⟨219⟩))) (.mul (.const 0x25182def05538410818ddcb029aa49e148ed360c0920e40e9a31a8d77418cc8d) (.var ⟨228⟩))) (.mul (.const 0x0221d49c39eb4ad7be2c99be1b40b58a3c49a3983a8cd6c228d7d29f2d46f7f5) (.var ⟨237⟩))) (.mul (.const 0x0fee1b64f00f857ebadd5ae2661b7d85f4b369e39416f486185946ad228145b8) (.var ⟨246⟩))) (.mul (.const 0x1f7e981d4f90f39eac21eb7a3262a93c71fd4bff05edaf8d5c99f9234e00b55f) (.var ⟨255⟩))) (.mul (.const 0x0a98f6fd21a4256bd2824d39098e16af72ddf393b987240b0be4081197cdd3be) (.var ⟨264⟩))) (.mul (.const 0x1b79dc208274de51a5c2567c3b366c04a64b8897924d89d4d21a1073d4332517) (.var ⟨273⟩))) (.mul (.const 0x0469a9b9af643c78814489ce14576b5775b166abefd35801af26fdecae9bd24e) (.var ⟨282⟩))) (.mul (.const 0x0da2a706cc42fbbc70d13b3405b96fcfdfdcdbc40a09e7d601d1c9156e0a8182) (.var ⟨291⟩))) (.mul (.const 0x1ac42ae3172f7b006c3e40266d7464fe84440ae8eebcf188c2704993d57c4553) (.var ⟨300⟩))) (.mul (.const 0x079b77d222a3e2e524a9d14572eb53d9083804761cac60b254d9589e2d40df79) (.var ⟨309⟩))) (.mul (.const 0x3487609a4d60a0e94920b9aaeaf3cce9872a42715901c7e27a436f75d6e39fc4) (.var ⟨318⟩))) (.mul (.const 0x326f162d52bcbbe0c56c320dc4e062f9d394fd344f619597bc197160545330d8) (.var ⟨327⟩))) (.mul (.const 0x3fadb60c8ae5e1aee492c6f28884dff8dfc1232d37e4c7e010a45a6f9ee35d41) (.var ⟨336⟩))) (.mul (.const 0x2c691f402e8d467b09ac088e59af689e020504f328ae196784da8e2404370cd4) (.var ⟨345⟩))) (.mul (.const 0x0ddfa1589e5bdb0389c86bf62672febe9e7a6be479dc2ce9d14b799342b6c3a5) (.var ⟨354⟩))) (.mul (.const 0x388272a1bae21f7c1ae6bff3f883f6750bbd9e6e966de81fbc7e939faec54535) (.var ⟨363⟩))) (.mul (.const 0x112dc0eaf9714fe8fdf461a790b7c16aeea80eae8fcbda0cd27557b10f4ef8e4) (.var ⟨372⟩))) (.mul (.const 0x125370a5916ba0b361f21b2dcb4097a66efcfc76e2396689abfa2cf769753a9c) (.var ⟨381⟩))) (.mul (.const 0x12a4f1d7b1920179189cea34d9ea8a2022a22a45180ebec1f1817cbd6617baf4) (.var ⟨390⟩))) (.mul (.const 0x3caacf56db5ab03405abc0fa37372a1f2e9ca1ac59f8d83204aada2991ad00a5) (.var ⟨399⟩))) (.mul (.const 0x1718a66e5830fa410560997770fd13223838233c5f1e8566eae2eb4a2c6da920) (.var ⟨408⟩))) (.mul (.const 0x2eb7486cdedc6cd58100175179b13cae0f749fbdae3881424fa7c4d6a17d1407) (.var ⟨417⟩))) (.mul (.const 0x16eded19d951facff421b5509b1067c15e2450f7eacdeb5db0b337e335bf11e0) (.var ⟨426⟩))) (.mul (.const 0x33e7f4229662b7f00561ed475be6e124af7b008cf4cce0c6f667f0b70a19925e) (.var ⟨435⟩))) (.mul (.const 0x09ada4ca6c86caaf1a00cd43e463f1a0019dc5049a83298fe8d1bc5d3936b497) (.var ⟨444⟩))) (.mul (.const 0x000cd722f4b4b9af621b52d9a522d00d49aba0a8755dbf9093920919d483b593) (.var ⟨453⟩))) (.mul (.const 0x1f75ef2936b140642038a4ba0cdfedca6e3dabe45045125e74b9d7e8674c6b1c) (.var ⟨462⟩))) (.mul (.const 0x3674583fbcbb8cccaccd7108be0823544f712d11be4e0936e0a3ee8fbb5d3139) (.var ⟨471⟩))) (.mul (.const 0x0388f13ed18dbd94ab78d8794e90c7714459a6c9637d532fb0006eaa671fb990) (.var ⟨480⟩))) (.mul (.const 0x207502eb3a0b2d313d7cf3e62363796328809ef1d48c84565c1d380242524405) (.var ⟨489⟩))) (.mul (.const 0x2bdb56150264e07f705940baf2fc4a46cb7dac58f9d7e0c547398f51da33c765) (.var ⟨498⟩))) (.mul (.const 0x3ba3112ef24f0766cc19bbfa25cc684e4cca8e1c771c2c75df34006b0192c5f3) (.var ⟨507⟩))) (.mul (.const 0x3500cfb00ed700c175a40e153e1ba0a33a3498350ad313be18ea4d9441e763b3) (.var ⟨516⟩))) (.mul (.const 0x2a3076e76577b910d2ddbb79da8dfc7b8d3764d718cb3bb1f87988bd00ee36e0) (.var ⟨525⟩))) (.mul (.const 0x3483ead1e325e205bc7aa474377ce0b6e9eda86dd92187b97b2b69cd1dd9abfa) (.var ⟨534⟩))) (.mul (.const 0x1e6b4633392c86180fcc09126b6c251467be1369b333916b451cefcac9ab615e) (.var ⟨543⟩))) (.mul (.const 0x002b9071f9976cb10840e835d192fcccf7d7ed9854ab7330ea62e6d8618dd304) (.var ⟨552⟩))) (.mul (.const 0x3f6cd968e1c0463aefb18cff9dcdaeda3f0c691a9b2d9333022679e3c11aff8d) (.var ⟨561⟩))) (.mul (.const 0x10f86c9e514167df1f855b11b114204bcffec1b04fc8d77e9e53a85defcf28be) (.var ⟨570⟩))) (.mul (.const 0x00c2916976b900b695e60ca9395ef53c51bf9a8eb3ff4be3e839c42b7f7e628d) (.var ⟨579⟩))) (.mul (.const 0x26c0dcbb53e1e056ea26210b7a4c39d1190f5d41b3909673e6f98b62b4b8abc5) (.var ⟨588⟩))) (.mul (.const 0x103cf8d3b3d8e083b131adbfde3281ffc275dc67aa66e276fbdb3d18b40759f0) (.var ⟨597⟩))) (.mul (.const 0x17d79293c186b242b40607c81b98d5fe87b527c5ff3422cf45dede4f867707cc) (.var ⟨606⟩))) (.mul (.const 0x265640b03621e50b2cef3f61bfa15080fabf693a569219daa8d0473d9f23d9c2) (.var ⟨615⟩))) (.mul (.const 0x36a2e13e5bf2dd2e5b6bb88e2650f1bdfc6fddc0dc6ca52c8c57b378e126b553) (.var ⟨624⟩))) (.mul (.const 0x047163e2d3d0e48b042bc61451da9ea8a8338deb95830e3df5379ed52fefe528) (.var ⟨633⟩))) (.mul (.const 0x14b2062575a497ccd6df652b5d0a41d3f2543721e6f01dc29f7e6414ea7cc669) (.var ⟨642⟩))) (.mul (.const 0x08fe8076706fe67669ff228783a203a6e9620fe2c93e658cc37d70c299c181cc) (.var ⟨651⟩))) (.mul (.const 0x18ae2c43fb51ab9df799a2c237ff4dc3a4f2a82c69a758889087b3e05781f3e9) (.var ⟨660⟩))) (.mul (.const 0x1658e66633e672c8e30c71c4af6af46129821accbaa142e721cf3484f66559aa) (.var ⟨669⟩))) (.mul (.const 0x02b19059ca9a01083b0e660313de9e85a77c4822528e8fd815df9e7afc8e0e6b) (.var ⟨678⟩))) (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨687⟩))) (.mul (.const 0x0f59a9f4c589caf5e2a5f686429faf7be72a747facc05c70936641f374555a0c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨725⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x20cafc22e46186e3a9c406f2089b9fc961cdcabb2c07e0d6917f208391ca96ed) (.var ⟨147⟩)) (.mul (.const 0x37352983db712e3ad1d44fb92ba9b7bd665aa418a92b84c9a7fbe96034547fa4) (.var ⟨156⟩))) (.mul (.const 0x06031656f603c7b634efdbb0a63c62bfc60b04fabb722354d7cd9d9f4dcf5bfb) (.var ⟨165⟩))) (.mul (.const 0x36205a666b111d9808b1ea5de6c98cfb17e90c382cd6d7bb2f4fb2998eb049a1) (.var ⟨174⟩))) (.mul (.const 0x3414a1946cf8b38cb95185e958f98040a393bc3e7b4151c108d69cae8cca83f3) (.var ⟨183⟩))) (.mul (.const 0x136a63358a0eb1e2be8746a98be53e4b1b974b2fad07f4d116a6c93e92f01535) (.var ⟨192⟩))) (.mul (.const 0x04b61207d497de5ec85771a5eb46646d941a0dd19d469b452bcffefc7c0c091a) (.var ⟨201⟩))) (.mul (.const 0x2639aea7cce09cd498d315f8f214d53bcc6e98e2f1197421c9c02867b9f460a2) (.var ⟨210⟩))) (.mul (.const 0x3d801e6f6ac392950174e31f49f74137494f062d14f4baa2803425decb2c22b8) (.var ⟨219⟩))) (.mul (.const 0x25182def05538410818ddcb029aa49e148ed360c0920e40e9a31a8d77418cc8d) (.var ⟨228⟩))) (.mul (.const 0x0221d49c39eb4ad7be2c99be1b40b58a3c49a3983a8cd6c228d7d29f2d46f7f5) (.var ⟨237⟩))) (.mul (.const 0x0fee1b64f00f857ebadd5ae2661b7d85f4b369e39416f486185946ad228145b8) (.var ⟨246⟩))) (.mul (.const 0x1f7e981d4f90f39eac21eb7a3262a93c71fd4bff05edaf8d5c99f9234e00b55f) (.var ⟨255⟩))) (.mul (.const 0x0a98f6fd21a4256bd2824d39098e16af72ddf393b987240b0be4081197cdd3be) (.var ⟨264⟩))) (.mul (.const 0x1b79dc208274de51a5c2567c3b366c04a64b8897924d89d4d21a1073d4332517) (.var ⟨273⟩))) (.mul (.const 0x0469a9b9af643c78814489ce14576b5775b166abefd35801af26fdecae9bd24e) (.var ⟨282⟩))) (.mul (.const 0x0da2a706cc42fbbc70d13b3405b96fcfdfdcdbc40a09e7d601d1c9156e0a8182) (.var ⟨291⟩))) (.mul (.const 0x1ac42ae3172f7b006c3e40266d7464fe84440ae8eebcf188c2704993d57c4553) (.var ⟨300⟩))) (.mul (.const 0x079b77d222a3e2e524a9d14572eb53d9083804761cac60b254d9589e2d40df79) (.var ⟨309⟩))) (.mul (.const 0x3487609a4d60a0e94920b9aaeaf3cce9872a42715901c7e27a436f75d6e39fc4) (.var ⟨318⟩))) (.mul (.const 0x326f162d52bcbbe0c56c320dc4e062f9d394fd344f619597bc197160545330d8) (.var ⟨327⟩))) (.mul (.const 0x3fadb60c8ae5e1aee492c6f28884dff8dfc1232d37e4c7e010a45a6f9ee35d41) (.var ⟨336⟩))) (.mul (.const 0x2c691f402e8d467b09ac088e59af689e020504f328ae196784da8e2404370cd4) (.var ⟨345⟩))) (.mul (.const 0x0ddfa1589e5bdb0389c86bf62672febe9e7a6be479dc2ce9d14b799342b6c3a5) (.var ⟨354⟩))) (.mul (.const 0x388272a1bae21f7c1ae6bff3f883f6750bbd9e6e966de81fbc7e939faec54535) (.var ⟨363⟩))) (.mul (.const 0x112dc0eaf9714fe8fdf461a790b7c16aeea80eae8fcbda0cd27557b10f4ef8e4) (.var ⟨372⟩))) (.mul (.const 0x125370a5916ba0b361f21b2dcb4097a66efcfc76e2396689abfa2cf769753a9c) (.var ⟨381⟩))) (.mul (.const 0x12a4f1d7b1920179189cea34d9ea8a2022a22a45180ebec1f1817cbd6617baf4) (.var ⟨390⟩))) (.mul (.const 0x3caacf56db5ab03405abc0fa37372a1f2e9ca1ac59f8d83204aada2991ad00a5) (.var ⟨399⟩))) (.mul (.const 0x1718a66e5830fa410560997770fd13223838233c5f1e8566eae2eb4a2c6da920) (.var ⟨408⟩))) (.mul (.const 0x2eb7486cdedc6cd58100175179b13cae0f749fbdae3881424fa7c4d6a17d1407) (.var ⟨417⟩))) (.mul (.const 0x16eded19d951facff421b5509b1067c15e2450f7eacdeb5db0b337e335bf11e0) (.var ⟨426⟩))) (.mul (.const 0x33e7f4229662b7f00561ed475be6e124af7b008cf4cce0c6f667f0b70a19925e) (.var ⟨435⟩))) (.mul (.const 0x09ada4ca6c86caaf1a00cd43e463f1a0019dc5049a83298fe8d1bc5d3936b497) (.var ⟨444⟩))) (.mul (.const 0x000cd722f4b4b9af621b52d9a522d00d49aba0a8755dbf9093920919d483b593) (.var ⟨453⟩))) (.mul (.const 0x1f75ef2936b140642038a4ba0cdfedca6e3dabe45045125e74b9d7e8674c6b1c) (.var ⟨462⟩))) (.mul (.const 0x3674583fbcbb8cccaccd7108be0823544f712d11be4e0936e0a3ee8fbb5d3139) (.var ⟨471⟩))) (.mul (.const 0x0388f13ed18dbd94ab78d8794e90c7714459a6c9637d532fb0006eaa671fb990) (.var ⟨480⟩))) (.mul (.const 0x207502eb3a0b2d313d7cf3e62363796328809ef1d48c84565c1d380242524405) (.var ⟨489⟩))) (.mul (.const 0x2bdb56150264e07f705940baf2fc4a46cb7dac58f9d7e0c547398f51da33c765) (.var ⟨498⟩))) (.mul (.const 0x3ba3112ef24f0766cc19bbfa25cc684e4cca8e1c771c2c75df34006b0192c5f3) (.var ⟨507⟩))) (.mul (.const 0x3500cfb00ed700c175a40e153e1ba0a33a3498350ad313be18ea4d9441e763b3) (.var ⟨516⟩))) (.mul (.const 0x2a3076e76577b910d2ddbb79da8dfc7b8d3764d718cb3bb1f87988bd00ee36e0) (.var ⟨525⟩))) (.mul (.const 0x3483ead1e325e205bc7aa474377ce0b6e9eda86dd92187b97b2b69cd1dd9abfa) (.var ⟨534⟩))) (.mul (.const 0x1e6b4633392c86180fcc09126b6c251467be1369b333916b451cefcac9ab615e) (.var ⟨543⟩))) (.mul (.const 0x002b9071f9976cb10840e835d192fcccf7d7ed9854ab7330ea62e6d8618dd304) (.var ⟨552⟩))) (.mul (.const 0x3f6cd968e1c0463aefb18cff9dcdaeda3f0c691a9b2d9333022679e3c11aff8d) (.var ⟨561⟩))) (.mul (.const 0x10f86c9e514167df1f855b11b114204bcffec1b04fc8d77e9e53a85defcf28be) (.var ⟨570⟩))) (.mul (.const 0x00c2916976b900b695e60ca9395ef53c51bf9a8eb3ff4be3e839c42b7f7e628d) (.var ⟨579⟩))) (.mul (.const 0x26c0dcbb53e1e056ea26210b7a4c39d1190f5d41b3909673e6f98b62b4b8abc5) (.var ⟨588⟩))) (.mul (.const 0x103cf8d3b3d8e083b131adbfde3281ffc275dc67aa66e276fbdb3d18b40759f0) (.var ⟨597⟩))) (.mul (.const 0x17d79293c186b242b40607c81b98d5fe87b527c5ff3422cf45dede4f867707cc) (.var ⟨606⟩))) (.mul (.const 0x265640b03621e50b2cef3f61bfa15080fabf693a569219daa8d0473d9f23d9c2) (.var ⟨615⟩))) (.mul (.const 0x36a2e13e5bf2dd2e5b6bb88e2650f1bdfc6fddc0dc6ca52c8c57b378e126b553) (.var ⟨624⟩))) (.mul (.const 0x047163e2d3d0e48b042bc61451da9ea8a8338deb95830e3df5379ed52fefe528) (.var ⟨633⟩))) (.mul (.const 0x14b2062575a497ccd6df652b5d0a41d3f2543721e6f01dc29f7e6414ea7cc669) (.var ⟨642⟩))) (.mul (.const 0x08fe8076706fe67669ff228783a203a6e9620fe2c93e658cc37d70c299c181cc) (.var ⟨651⟩))) (.mul (.const 0x18ae2c43fb51ab9df799a2c237ff4dc3a4f2a82c69a758889087b3e05781f3e9) (.var ⟨660⟩))) (.mul (.const 0x1658e66633e672c8e30c71c4af6af46129821accbaa142e721cf3484f66559aa) (.var ⟨669⟩))) (.mul (.const 0x02b19059ca9a01083b0e660313de9e85a77c4822528e8fd815df9e7afc8e0e6b) (.var ⟨678⟩))) (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨687⟩))) (.mul (.const 0x0f59a9f4c589caf5e2a5f686429faf7be72a747facc05c70936641f374555a0c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨727⟩) (.var ⟨728⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨729⟩))),
+  .assert (.add (.var ⟨727⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨726⟩))),
+  .assert (.add (.var ⟨728⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨726⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨730⟩) (.var ⟨731⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨732⟩))),
+  .assert (.add (.var ⟨730⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨729⟩))),
+  .assert (.add (.var ⟨731⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.add (.mul (.const 0x20cafc22e46186e3a9c406f2089b9fc961cdcabb2c07e0d6917f208391ca96ed) (.var ⟨147⟩)) (.mul (.const 0x37352983db712e3ad1d44fb92ba9b7bd665aa418a92b84c9a7fbe96034547fa4) (.var ⟨156⟩))) (.mul (.const 0x06031656f603c7b634efdbb0a63c62bfc60b04fabb722354d7cd9d9f4dcf5bfb) (.var ⟨165⟩))) (.mul (.const 0x36205a666b111d9808b1ea5de6c98cfb17e90c382cd6d7bb2f4fb2998eb049a1) (.var ⟨174⟩))) (.mul (.const 0x3414a1946cf8b38cb95185e958f98040a393bc3e7b4151c108d69cae8cca83f3) (.var ⟨183⟩))) (.mul (.const 0x136a63358a0eb1e2be8746a98be53e4b1b974b2fad07f4d116a6c93e92f01535) (.var ⟨192⟩))) (.mul (.const 0x04b61207d497de5ec85771a5eb46646d941a0dd19d469b452bcffefc7c0c091a) (.var ⟨201⟩))) (.mul (.const 0x2639aea7cce09cd498d315f8f214d53bcc6e98e2f1197421c9c02867b9f460a2) (.var ⟨210⟩))) (.mul (.const 0x3d801e6f6ac392950174e31f49f74137494f062d14f4baa2803425decb2c22b8) (.var ⟨219⟩))) (.mul (.const 0x25182def05538410818ddcb029aa49e148ed360c0920e40e9a31a8d77418cc8d) (.var ⟨228⟩))) (.mul (.const 0x0221d49c39eb4ad7be2c99be1b40b58a3c49a3983a8cd6c228d7d29f2d46f7f5) (.var ⟨237⟩))) (.mul (.const 0x0fee1b64f00f857ebadd5ae2661b7d85f4b369e39416f486185946ad228145b8) (.var ⟨246⟩))) (.mul (.const 0x1f7e981d4f90f39eac21eb7a3262a93c71fd4bff05edaf8d5c99f9234e00b55f) (.var ⟨255⟩))) (.mul (.const 0x0a98f6fd21a4256bd2824d39098e16af72ddf393b987240b0be4081197cdd3be) (.var ⟨264⟩))) (.mul (.const 0x1b79dc208274de51a5c2567c3b366c04a64b8897924d89d4d21a1073d4332517) (.var ⟨273⟩))) (.mul (.const 0x0469a9b9af643c78814489ce14576b5775b166abefd35801af26fdecae9bd24e) (.var ⟨282⟩))) (.mul (.const 0x0da2a706cc42fbbc70d13b3405b96fcfdfdcdbc40a09e7d601d1c9156e0a8182) (.var ⟨291⟩))) (.mul (.const 0x1ac42ae3172f7b006c3e40266d7464fe84440ae8eebcf188c2704993d57c4553) (.var ⟨300⟩))) (.mul (.const 0x079b77d222a3e2e524a9d14572eb53d9083804761cac60b254d9589e2d40df79) (.var ⟨309⟩))) (.mul (.const 0x3487609a4d60a0e94920b9aaeaf3cce9872a42715901c7e27a436f75d6e39fc4) (.var ⟨318⟩))) (.mul (.const 0x326f162d52bcbbe0c56c320dc4e062f9d394fd344f619597bc197160545330d8) (.var ⟨327⟩))) (.mul (.const 0x3fadb60c8ae5e1aee492c6f28884dff8dfc1232d37e4c7e010a45a6f9ee35d41) (.var ⟨336⟩))) (.mul (.const 0x2c691f402e8d467b09ac088e59af689e020504f328ae196784da8e2404370cd4) (.var ⟨345⟩))) (.mul (.const 0x0ddfa1589e5bdb0389c86bf62672febe9e7a6be479dc2ce9d14b799342b6c3a5) (.var ⟨354⟩))) (.mul (.const 0x388272a1bae21f7c1ae6bff3f883f6750bbd9e6e966de81fbc7e939faec54535) (.var ⟨363⟩))) (.mul (.const 0x112dc0eaf9714fe8fdf461a790b7c16aeea80eae8fcbda0cd27557b10f4ef8e4) (.var ⟨372⟩))) (.mul (.const 0x125370a5916ba0b361f21b2dcb4097a66efcfc76e2396689abfa2cf769753a9c) (.var ⟨381⟩))) (.mul (.const 0x12a4f1d7b1920179189cea34d9ea8a2022a22a45180ebec1f1817cbd6617baf4) (.var ⟨390⟩))) (.mul (.const 0x3caacf56db5ab03405abc0fa37372a1f2e9ca1ac59f8d83204aada2991ad00a5) (.var ⟨399⟩))) (.mul (.const 0x1718a66e5830fa410560997770fd13223838233c5f1e8566eae2eb4a2c6da920) (.var ⟨408⟩))) (.mul (.const 0x2eb7486cdedc6cd58100175179b13cae0f749fbdae3881424fa7c4d6a17d1407) (.var ⟨417⟩))) (.mul (.const 0x16eded19d951facff421b5509b1067c15e2450f7eacdeb5db0b337e335bf11e0) (.var ⟨426⟩))) (.mul (.const 0x33e7f4229662b7f00561ed475be6e124af7b008cf4cce0c6f667f0b70a19925e) (.var ⟨435⟩))) (.mul (.const 0x09ada4ca6c86caaf1a00cd43e463f1a0019dc5049a83298fe8d1bc5d3936b497) (.var ⟨444⟩))) (.mul (.const 0x000cd722f4b4b9af621b52d9a522d00d49aba0a8755dbf9093920919d483b593) (.var ⟨453⟩))) (.mul (.const 0x1f75ef2936b140642038a4ba0cdfedca6e3dabe45045125e74b9d7e8674c6b1c) (.var ⟨462⟩))) (.mul (.const 0x3674583fbcbb8cccaccd7108be0823544f712d11be4e0936e0a3ee8fbb5d3139) (.var ⟨471⟩))) (.mul (.const 0x0388f13ed18dbd94ab78d8794e90c7714459a6c9637d532fb0006eaa671fb990) (.var ⟨480⟩))) (.mul (.const 0x207502eb3a0b2d313d7cf3e62363796328809ef1d48c84565c1d380242524405) (.var ⟨489⟩))) (.mul (.const 0x2bdb56150264e07f705940baf2fc4a46cb7dac58f9d7e0c547398f51da33c765) (.var ⟨498⟩))) (.mul (.const 0x3ba3112ef24f0766cc19bbfa25cc684e4cca8e1c771c2c75df34006b0192c5f3) (.var ⟨507⟩))) (.mul (.const 0x3500cfb00ed700c175a40e153e1ba0a33a3498350ad313be18ea4d9441e763b3) (.var ⟨516⟩))) (.mul (.const 0x2a3076e76577b910d2ddbb79da8dfc7b8d3764d718cb3bb1f87988bd00ee36e0) (.var ⟨525⟩))) (.mul (.const 0x3483ead1e325e205bc7aa474377ce0b6e9eda86dd92187b97b2b69cd1dd9abfa) (.var ⟨534⟩))) (.mul (.const 0x1e6b4633392c86180fcc09126b6c251467be1369b333916b451cefcac9ab615e) (.var ⟨543⟩))) (.mul (.const 0x002b9071f9976cb10840e835d192fcccf7d7ed9854ab7330ea62e6d8618dd304) (.var ⟨552⟩))) (.mul (.const 0x3f6cd968e1c0463aefb18cff9dcdaeda3f0c691a9b2d9333022679e3c11aff8d) (.var ⟨561⟩))) (.mul (.const 0x10f86c9e514167df1f855b11b114204bcffec1b04fc8d77e9e53a85defcf28be) (.var ⟨570⟩))) (.mul (.const 0x00c2916976b900b695e60ca9395ef53c51bf9a8eb3ff4be3e839c42b7f7e628d) (.var ⟨579⟩))) (.mul (.const 0x26c0dcbb53e1e056ea26210b7a4c39d1190f5d41b3909673e6f98b62b4b8abc5) (.var ⟨588⟩))) (.mul (.const 0x103cf8d3b3d8e083b131adbfde3281ffc275dc67aa66e276fbdb3d18b40759f0) (.var ⟨597⟩))) (.mul (.const 0x17d79293c186b242b40607c81b98d5fe87b527c5ff3422cf45dede4f867707cc) (.var ⟨606⟩))) (.mul (.const 0x265640b03621e50b2cef3f61bfa15080fabf693a569219daa8d0473d9f23d9c2) (.var ⟨615⟩))) (.mul (.const 0x36a2e13e5bf2dd2e5b6bb88e2650f1bdfc6fddc0dc6ca52c8c57b378e126b553) (.var ⟨624⟩))) (.mul (.const 0x047163e2d3d0e48b042bc61451da9ea8a8338deb95830e3df5379ed52fefe528) (.var ⟨633⟩))) (.mul (.const 0x14b2062575a497ccd6df652b5d0a41d3f2543721e6f01dc29f7e6414ea7cc669) (.var ⟨642⟩))) (.mul (.const 0x08fe8076706fe67669ff228783a203a6e9620fe2c93e658cc37d70c299c181cc) (.var ⟨651⟩))) (.mul (.const 0x18ae2c43fb51ab9df799a2c237ff4dc3a4f2a82c69a758889087b3e05781f3e9) (.var ⟨660⟩))) (.mul (.const 0x1658e66633e672c8e30c71c4af6af46129821accbaa142e721cf3484f66559aa) (.var ⟨669⟩))) (.mul (.const 0x02b19059ca9a01083b0e660313de9e85a77c4822528e8fd815df9e7afc8e0e6b) (.var ⟨678⟩))) (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨687⟩))) (.mul (.const 0x0f59a9f4c589caf5e2a5f686429faf7be72a747facc05c70936641f374555a0c) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨733⟩) (.var ⟨734⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨735⟩))),
+  .assert (.add (.var ⟨733⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨696⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨705⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨714⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨723⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨732⟩))) (.mul (.const 0x17c3300d1a39e83f4a578f92da409229ba071a29bba07ef597ed93970a1cf9b2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨734⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨696⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨705⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨714⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨723⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨732⟩))) (.mul (.const 0x17c3300d1a39e83f4a578f92da409229ba071a29bba07ef597ed93970a1cf9b2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨736⟩) (.var ⟨737⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨738⟩))),
+  .assert (.add (.var ⟨736⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨735⟩))),
+  .assert (.add (.var ⟨737⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨735⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨739⟩) (.var ⟨740⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨741⟩))),
+  .assert (.add (.var ⟨739⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨738⟩))),
+  .assert (.add (.var ⟨740⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨696⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨705⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨714⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨723⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨732⟩))) (.mul (.const 0x17c3300d1a39e83f4a578f92da409229ba071a29bba07ef597ed93970a1cf9b2) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨742⟩) (.var ⟨743⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨744⟩))),
+  .assert (.add (.var ⟨742⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨696⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨705⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨714⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨723⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨732⟩))) (.mul (.const 0x34dfe9de6ac6129d8549ee5cdb92395afc361a406184d2d1dbe84399923f6f69) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨743⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨696⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨705⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨714⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨723⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨732⟩))) (.mul (.const 0x34dfe9de6ac6129d8549ee5cdb92395afc361a406184d2d1dbe84399923f6f69) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨745⟩) (.var ⟨746⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨747⟩))),
+  .assert (.add (.var ⟨745⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨744⟩))),
+  .assert (.add (.var ⟨746⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨744⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨748⟩) (.var ⟨749⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨750⟩))),
+  .assert (.add (.var ⟨748⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨747⟩))),
+  .assert (.add (.var ⟨749⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨696⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨705⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨714⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨723⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨732⟩))) (.mul (.const 0x34dfe9de6ac6129d8549ee5cdb92395afc361a406184d2d1dbe84399923f6f69) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨751⟩) (.var ⟨752⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨753⟩))),
+  .assert (.add (.var ⟨751⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨696⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨705⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨714⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨723⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨732⟩))) (.mul (.const 0x2ebe3a38176369e82420fcd79faa116543e83d191a733ccdab75fea295d5e8ea) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨752⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨696⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨705⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨714⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨723⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨732⟩))) (.mul (.const 0x2ebe3a38176369e82420fcd79faa116543e83d191a733ccdab75fea295d5e8ea) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨754⟩) (.var ⟨755⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨756⟩))),
+  .assert (.add (.var ⟨754⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨753⟩))),
+  .assert (.add (.var ⟨755⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨753⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨757⟩) (.var ⟨758⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨759⟩))),
+  .assert (.add (.var ⟨757⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨756⟩))),
+  .assert (.add (.var ⟨758⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨696⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨705⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨714⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨723⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨732⟩))) (.mul (.const 0x2ebe3a38176369e82420fcd79faa116543e83d191a733ccdab75fea295d5e8ea) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨760⟩) (.var ⟨761⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩))),
+  .assert (.add (.var ⟨760⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨696⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨705⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨714⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨723⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨732⟩))) (.mul (.const 0x31a5617a0be95548532d636c9c9e4261cff82eb13629ec3519ce54200bbbdbc3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨761⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨696⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨705⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨714⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨723⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨732⟩))) (.mul (.const 0x31a5617a0be95548532d636c9c9e4261cff82eb13629ec3519ce54200bbbdbc3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨763⟩) (.var ⟨764⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨765⟩))),
+  .assert (.add (.var ⟨763⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩))),
+  .assert (.add (.var ⟨764⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨762⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨766⟩) (.var ⟨767⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨768⟩))),
+  .assert (.add (.var ⟨766⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨765⟩))),
+  .assert (.add (.var ⟨767⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨696⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨705⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨714⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨723⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨732⟩))) (.mul (.const 0x31a5617a0be95548532d636c9c9e4261cff82eb13629ec3519ce54200bbbdbc3) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨769⟩) (.var ⟨770⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨771⟩))),
+  .assert (.add (.var ⟨769⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨696⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨705⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨714⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨723⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨732⟩))) (.mul (.const 0x0dc4a92e0ed7b2257bacf5c88635a4e71b30a958189f39b8de0aa82e8420ed3b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨770⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨696⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨705⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨714⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨723⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨732⟩))) (.mul (.const 0x0dc4a92e0ed7b2257bacf5c88635a4e71b30a958189f39b8de0aa82e8420ed3b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨772⟩) (.var ⟨773⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨774⟩))),
+  .assert (.add (.var ⟨772⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨771⟩))),
+  .assert (.add (.var ⟨773⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨771⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨775⟩) (.var ⟨776⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨777⟩))),
+  .assert (.add (.var ⟨775⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨774⟩))),
+  .assert (.add (.var ⟨776⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨696⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨705⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨714⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨723⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨732⟩))) (.mul (.const 0x0dc4a92e0ed7b2257bacf5c88635a4e71b30a958189f39b8de0aa82e8420ed3b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨778⟩) (.var ⟨779⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨780⟩))),
+  .assert (.add (.var ⟨778⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨741⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨750⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨759⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨768⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨777⟩))) (.mul (.const 0x391aa9e950f9b0a7261e5174e32658265370689fb4ff0b4e68ae07f800d607f0) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨779⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨741⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨750⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨759⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨768⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨777⟩))) (.mul (.const 0x391aa9e950f9b0a7261e5174e32658265370689fb4ff0b4e68ae07f800d607f0) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨781⟩) (.var ⟨782⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩))),
+  .assert (.add (.var ⟨781⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨780⟩))),
+  .assert (.add (.var ⟨782⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨780⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨784⟩) (.var ⟨785⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨786⟩))),
+  .assert (.add (.var ⟨784⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨783⟩))),
+  .assert (.add (.var ⟨785⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨741⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨750⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨759⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨768⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨777⟩))) (.mul (.const 0x391aa9e950f9b0a7261e5174e32658265370689fb4ff0b4e68ae07f800d607f0) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨787⟩) (.var ⟨788⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨789⟩))),
+  .assert (.add (.var ⟨787⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨741⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨750⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨759⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨768⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨777⟩))) (.mul (.const 0x2cdde808356a14d219bb8710271c53b57199f252eabeddd3ba908ada1a6c6f20) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨788⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨741⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨750⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨759⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨768⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨777⟩))) (.mul (.const 0x2cdde808356a14d219bb8710271c53b57199f252eabeddd3ba908ada1a6c6f20) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨790⟩) (.var ⟨791⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨792⟩))),
+  .assert (.add (.var ⟨790⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨789⟩))),
+  .assert (.add (.var ⟨791⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨789⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨793⟩) (.var ⟨794⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨795⟩))),
+  .assert (.add (.var ⟨793⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨792⟩))),
+  .assert (.add (.var ⟨794⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨741⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨750⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨759⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨768⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨777⟩))) (.mul (.const 0x2cdde808356a14d219bb8710271c53b57199f252eabeddd3ba908ada1a6c6f20) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨796⟩) (.var ⟨797⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨798⟩))),
+  .assert (.add (.var ⟨796⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨741⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨750⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨759⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨768⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨777⟩))) (.mul (.const 0x3c455b1261288b16d6e2cbdb0e5dd23a0d3c11f3b2cbf0813ddd0bd85167723d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨797⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨741⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨750⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨759⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨768⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨777⟩))) (.mul (.const 0x3c455b1261288b16d6e2cbdb0e5dd23a0d3c11f3b2cbf0813ddd0bd85167723d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨799⟩) (.var ⟨800⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨801⟩))),
+  .assert (.add (.var ⟨799⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨798⟩))),
+  .assert (.add (.var ⟨800⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨798⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨802⟩) (.var ⟨803⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨804⟩))),
+  .assert (.add (.var ⟨802⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨801⟩))),
+  .assert (.add (.var ⟨803⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨741⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨750⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨759⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨768⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨777⟩))) (.mul (.const 0x3c455b1261288b16d6e2cbdb0e5dd23a0d3c11f3b2cbf0813ddd0bd85167723d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨805⟩) (.var ⟨806⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨807⟩))),
+  .assert (.add (.var ⟨805⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨741⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨750⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨759⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨768⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨777⟩))) (.mul (.const 0x08522989d97a572642169b6012ec5fddb1ee4d7323b0364cf8c26fc248d9d444) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨806⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨741⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨750⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨759⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨768⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨777⟩))) (.mul (.const 0x08522989d97a572642169b6012ec5fddb1ee4d7323b0364cf8c26fc248d9d444) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨808⟩) (.var ⟨809⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨810⟩))),
+  .assert (.add (.var ⟨808⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨807⟩))),
+  .assert (.add (.var ⟨809⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨807⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨811⟩) (.var ⟨812⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨813⟩))),
+  .assert (.add (.var ⟨811⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨810⟩))),
+  .assert (.add (.var ⟨812⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨741⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨750⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨759⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨768⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨777⟩))) (.mul (.const 0x08522989d97a572642169b6012ec5fddb1ee4d7323b0364cf8c26fc248d9d444) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨814⟩) (.var ⟨815⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨816⟩))),
+  .assert (.add (.var ⟨814⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨741⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨750⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨759⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨768⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨777⟩))) (.mul (.const 0x1ae81dcf9214d0ebee4318426888837b663706356d237c0505f474bcd325446d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨815⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨741⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨750⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨759⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨768⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨777⟩))) (.mul (.const 0x1ae81dcf9214d0ebee4318426888837b663706356d237c0505f474bcd325446d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨817⟩) (.var ⟨818⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨819⟩))),
+  .assert (.add (.var ⟨817⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨816⟩))),
+  .assert (.add (.var ⟨818⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨816⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨820⟩) (.var ⟨821⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨822⟩))),
+  .assert (.add (.var ⟨820⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨819⟩))),
+  .assert (.add (.var ⟨821⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨741⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨750⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨759⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨768⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨777⟩))) (.mul (.const 0x1ae81dcf9214d0ebee4318426888837b663706356d237c0505f474bcd325446d) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨823⟩) (.var ⟨824⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩))),
+  .assert (.add (.var ⟨823⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨786⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨795⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨804⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨813⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨822⟩))) (.mul (.const 0x3f0fc0913f94d0a66e190926bc394f333be5b89a8304a47f5c366ed86d4d44b6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨824⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨786⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨795⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨804⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨813⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨822⟩))) (.mul (.const 0x3f0fc0913f94d0a66e190926bc394f333be5b89a8304a47f5c366ed86d4d44b6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨826⟩) (.var ⟨827⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨828⟩))),
+  .assert (.add (.var ⟨826⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩))),
+  .assert (.add (.var ⟨827⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨825⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨829⟩) (.var ⟨830⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨831⟩))),
+  .assert (.add (.var ⟨829⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨828⟩))),
+  .assert (.add (.var ⟨830⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨786⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨795⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨804⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨813⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨822⟩))) (.mul (.const 0x3f0fc0913f94d0a66e190926bc394f333be5b89a8304a47f5c366ed86d4d44b6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨832⟩) (.var ⟨833⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨834⟩))),
+  .assert (.add (.var ⟨832⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨786⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨795⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨804⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨813⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨822⟩))) (.mul (.const 0x2a2f93868209f3ba48eb471cedb2f2cacca1a8f007a908095c5a51ec99f61d05) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨833⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨786⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨795⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨804⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨813⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨822⟩))) (.mul (.const 0x2a2f93868209f3ba48eb471cedb2f2cacca1a8f007a908095c5a51ec99f61d05) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨835⟩) (.var ⟨836⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨837⟩))),
+  .assert (.add (.var ⟨835⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨834⟩))),
+  .assert (.add (.var ⟨836⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨834⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨838⟩) (.var ⟨839⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨840⟩))),
+  .assert (.add (.var ⟨838⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨837⟩))),
+  .assert (.add (.var ⟨839⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x36e9c5b57d91e1099ae5945691c1140f3d243defade21a44cc86377bed814e0e) (.var ⟨786⟩)) (.mul (.const 0x34360a4516dabde9c2024e8e83a6218ba706fb105cf883be0be46694a6c02224) (.var ⟨795⟩))) (.mul (.const 0x089a2e44b84e120074565a78876fe8d5cb0759a57de3ccc0cc1213067667348b) (.var ⟨804⟩))) (.mul (.const 0x3f612bb351d01dcb9ddd4569901f76f11bd48466cd16e26b628d533f895db39c) (.var ⟨813⟩))) (.mul (.const 0x3036f6a9d84d21b3fef073f7a187030d1692d3aa50809c57403c9aeae5901bf7) (.var ⟨822⟩))) (.mul (.const 0x2a2f93868209f3ba48eb471cedb2f2cacca1a8f007a908095c5a51ec99f61d05) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨841⟩) (.var ⟨842⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨843⟩))),
+  .assert (.add (.var ⟨841⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨786⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨795⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨804⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨813⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨822⟩))) (.mul (.const 0x34a3bd354bbc7271cc161edeae2988a094bac24032123706bc10d035a3292ae6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨842⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨786⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨795⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨804⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨813⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨822⟩))) (.mul (.const 0x34a3bd354bbc7271cc161edeae2988a094bac24032123706bc10d035a3292ae6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨844⟩) (.var ⟨845⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩))),
+  .assert (.add (.var ⟨844⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨843⟩))),
+  .assert (.add (.var ⟨845⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨843⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨847⟩) (.var ⟨848⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨849⟩))),
+  .assert (.add (.var ⟨847⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨846⟩))),
+  .assert (.add (.var ⟨848⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x060704c074ec70c860001c721cb3d7ef106f1bb6fbb0030782284c88344835e5) (.var ⟨786⟩)) (.mul (.const 0x151de8e3e68f37e82a01290bf38ae29e9b5e809f540793e08e3898fd992e9df6) (.var ⟨795⟩))) (.mul (.const 0x288ab5e71486d5db03d20e916c2fa9327df595eb925e4c0c32c70cbc0706abee) (.var ⟨804⟩))) (.mul (.const 0x240077f7f8019bf67835fbcec8ca198a3fa6e67c0cee8d92f21e8706a91a466f) (.var ⟨813⟩))) (.mul (.const 0x3a3baec0378054ade39ebf2ed0c15a6ebd4e4f3b1d8281c4a375407716963dd9) (.var ⟨822⟩))) (.mul (.const 0x34a3bd354bbc7271cc161edeae2988a094bac24032123706bc10d035a3292ae6) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨850⟩) (.var ⟨851⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨852⟩))),
+  .assert (.add (.var ⟨850⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨786⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨795⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨804⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨813⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨822⟩))) (.mul (.const 0x08b4971d4224074a5820ab601b9203bb7b8414f3abb9df6dbe245a2eadb4183b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨851⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨786⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨795⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨804⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨813⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨822⟩))) (.mul (.const 0x08b4971d4224074a5820ab601b9203bb7b8414f3abb9df6dbe245a2eadb4183b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨853⟩) (.var ⟨854⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨855⟩))),
+  .assert (.add (.var ⟨853⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨852⟩))),
+  .assert (.add (.var ⟨854⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨852⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨856⟩) (.var ⟨857⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨858⟩))),
+  .assert (.add (.var ⟨856⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨855⟩))),
+  .assert (.add (.var ⟨857⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x2e1769ab643da4216ce8f0cc866c3e19315397b361955dd7250f9bd7d1c89d09) (.var ⟨786⟩)) (.mul (.const 0x2cd174268b7341924fee1d3ff9265edecf4b2fece596f2dee80a8ce37541d4a3) (.var ⟨795⟩))) (.mul (.const 0x32f8aadeaaa983881357e98128c7bff8b2330d073c44ba12f50ce55b5c0beac4) (.var ⟨804⟩))) (.mul (.const 0x14bbd67e39408bd0ef0c1ebe7790cd4ee3cd6d8e850f18dbc6a573c88adc633d) (.var ⟨813⟩))) (.mul (.const 0x379bb200eadfe1fa7f1d6281d128b575f0a955ec40df8d5a0e854d6fe4e251f7) (.var ⟨822⟩))) (.mul (.const 0x08b4971d4224074a5820ab601b9203bb7b8414f3abb9df6dbe245a2eadb4183b) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨859⟩) (.var ⟨860⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨861⟩))),
+  .assert (.add (.var ⟨859⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨786⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨795⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨804⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨813⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨822⟩))) (.mul (.const 0x03de34a7d5476efdedbadbc3888aaabe655e3a2ef1b9808d445abafb6b223a14) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .assert (.add (.var ⟨860⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨786⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨795⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨804⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨813⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨822⟩))) (.mul (.const 0x03de34a7d5476efdedbadbc3888aaabe655e3a2ef1b9808d445abafb6b223a14) (.const 0x0000000000000000000000000000000000000000000000000000000000000001))))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨862⟩) (.var ⟨863⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨864⟩))),
+  .assert (.add (.var ⟨862⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨861⟩))),
+  .assert (.add (.var ⟨863⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨861⟩))),
+  .witness 3 (fun _ => default),
+  .assert (.add (.mul (.var ⟨865⟩) (.var ⟨866⟩)) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨867⟩))),
+  .assert (.add (.var ⟨865⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.var ⟨864⟩))),
+  .assert (.add (.var ⟨866⟩) (.mul (.const 0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000) (.add (.add (.add (.add (.add (.mul (.const 0x3a2d10c729dd5d7548291dff7f4d60dfcad604b569f3c51ea74c26d36e49aa2b) (.var ⟨786⟩)) (.mul (.const 0x388230e3fdf524612c1dd64615516498c3caf75a7ead6d8ab6e1d8e98f2db8ae) (.var ⟨795⟩))) (.mul (.const 0x35965852fe36eb39c8813d8f07f3286c7e49b8d338137d7ba3a22fd47d91acbe) (.var ⟨804⟩))) (.mul (.const 0x0c06958f1f59ed5d58dacde68cba2ea0c91f3d8308328d8d386621317cd52035) (.var ⟨813⟩))) (.mul (.const 0x12ff6a42e907002302fcb9195191d21a59d960b63dbaf20742fcc35e3acf1b21) (.var ⟨822⟩))) (.mul (.const 0x03de34a7d5476efdedbadbc3888aaabe655e3a2ef1b9808d445abafb6b223a14) (.const 0x0000000000000000000000000000000000000000000000000000000000000001)))))
+]
+
+set_option maxRecDepth 100000 in
+/-- The extracted output expressions. -/
+def outputs : List (Expression (F p)) := [
+  .var ⟨0⟩,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000000,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000000,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000002,
+  .var ⟨3⟩,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000000,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000000,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000002,
+  .add (.add (.add (.add (.mul (.const 0x0e4b3540d4d73fa420f1851a6740eddbd600c9ab0ad8d5b4dcf64f04ffb374c6) (.var ⟨831⟩)) (.mul (.const 0x3fe7ecb7e78834870cdce4b908a2fb807b03af17cded80294c0c030880410ed4) (.var ⟨840⟩))) (.mul (.const 0x1e58e74d5d29b520e5fd248e6871693397c6de26fa449684f03b70a513fcbca0) (.var ⟨849⟩))) (.mul (.const 0x177c74dbeb98484b40187696c94630970970a35225a0c760651f0149b0ada929) (.var ⟨858⟩))) (.mul (.const 0x036dd2f32bb0f9f2424c29c0f5ca90562d91d016a08b76dbc6677647a1d2490f) (.var ⟨867⟩)),
+  .const 0x0000000000000000000000000000000000000000000000000000000000000000,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000000,
+  .const 0x0000000000000000000000000000000000000000000000000000000000000003
+]
+
+end Ragu.Extracted.Step.Hash2
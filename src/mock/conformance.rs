@@ -0,0 +1,503 @@
+//! Conformance kit checking the mock against the real `ragu_pcd`.
+//!
+//! The mock only earns the trust of downstream integrators if it accepts and
+//! rejects the same proof-carrying data as `ragu_pcd` does. This module lets
+//! an application be written once and run against both:
+//!
+//! - Headers implement [`SharedHeader`], which ties a mock [`Header`] and a
+//!   real [`ragu_pcd::header::Header`] to the same data. [`FieldHeader`]
+//!   covers the common case of a header carrying one field element.
+//! - Steps implement [`SharedStep`], whose logic is written once against a
+//!   generic [`Driver`]. Wrapping one in [`Shared`] yields both a mock
+//!   [`Step`] and a real [`ragu_pcd::step::Step`]; the mock side runs the
+//!   logic under a [`Simulator`] so that unsatisfied constraints fail as they
+//!   would in the real circuit.
+//! - Scenarios implement [`Scenario`] over any [`Backend`], which both
+//!   applications implement.
+//!
+//! [`Conformance::check`] runs a scenario against both applications, verifies
+//! and rerandomizes the result, and panics if the two disagree on whether it
+//! is accepted or on its header data.
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use ragu_arithmetic::{CryptoRngCore, Cycle, ff::PrimeField as _};
+use ragu_circuits::polynomials::Rank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    gadgets::{Bound, Kind},
+    maybe::{Always, Maybe as _, MaybeKind as _},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::step::Encoded;
+use ragu_primitives::{
+    Element, Simulator,
+    allocator::{Allocator, Standard},
+};
+
+use crate::{
+    application::{Application, ApplicationBuilder},
+    ctx::StepCtx,
+    header::{Header, Suffix},
+    proof::Pcd,
+    step::{Index, Step},
+};
+
+/// A header usable by both the mock and `ragu_pcd`, with the same data.
+pub trait SharedHeader:
+    Header<Data = <Self as SharedHeader>::Value>
+    + ragu_pcd::header::Header<Fp, Data = <Self as SharedHeader>::Value>
+{
+    /// The data carried by the header in both implementations.
+    type Value: Send + Clone + PartialEq + Debug;
+}
+
+impl SharedHeader for () {
+    type Value = ();
+}
+
+/// A header carrying a single field element, with the given application
+/// suffix.
+pub struct FieldHeader<const SUFFIX: usize>;
+
+impl<const SUFFIX: usize> Header for FieldHeader<SUFFIX> {
+    type Data = Fp;
+
+    const SUFFIX: Suffix = Suffix::new(SUFFIX);
+
+    fn encode(data: &Fp) -> Vec<u8> {
+        data.to_repr().as_ref().to_vec()
+    }
+}
+
+impl<const SUFFIX: usize> ragu_pcd::header::Header<Fp> for FieldHeader<SUFFIX> {
+    const SUFFIX: ragu_pcd::header::Suffix = ragu_pcd::header::Suffix::new(SUFFIX);
+    const ENCODED_LEN: Option<usize> = Some(1);
+
+    type Data = Fp;
+    type Output = Kind![Fp; Element<'_, _>];
+
+    fn encode<'dr, D: Driver<'dr, F = Fp>, A: Allocator<'dr, D>>(
+        dr: &mut D,
+        allocator: &mut A,
+        witness: DriverValue<D, Fp>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        Element::alloc(dr, allocator, witness)
+    }
+}
+
+impl<const SUFFIX: usize> SharedHeader for FieldHeader<SUFFIX> {
+    type Value = Fp;
+}
+
+/// A step written once against a generic driver, so that it can be run by
+/// both the mock and `ragu_pcd` through [`Shared`].
+pub trait SharedStep: Clone + Send + Sync + 'static {
+    /// The application step index; see [`Index::new`].
+    const INDEX: usize;
+
+    /// The witness data needed to run this step.
+    type Witness: Send;
+
+    /// The "left" header expected during this step.
+    type Left: SharedHeader;
+
+    /// The "right" header expected during this step.
+    type Right: SharedHeader;
+
+    /// The header produced during this step.
+    type Output: SharedHeader;
+
+    /// Computes the encoded output header and its data from the witness and
+    /// the encoded input headers, enforcing whatever the step checks along
+    /// the way.
+    fn apply<'dr, D: Driver<'dr, F = Fp>, A: Allocator<'dr, D>>(
+        &self,
+        dr: &mut D,
+        allocator: &mut A,
+        witness: DriverValue<D, Self::Witness>,
+        left: &Bound<'dr, D, <Self::Left as ragu_pcd::header::Header<Fp>>::Output>,
+        right: &Bound<'dr, D, <Self::Right as ragu_pcd::header::Header<Fp>>::Output>,
+    ) -> Result<(
+        Bound<'dr, D, <Self::Output as ragu_pcd::header::Header<Fp>>::Output>,
+        DriverValue<D, <Self::Output as SharedHeader>::Value>,
+    )>;
+}
+
+/// Adapts a [`SharedStep`] into both a mock [`Step`] and a real
+/// [`ragu_pcd::step::Step`].
+#[derive(Clone)]
+pub struct Shared<S>(pub S);
+
+impl<S: SharedStep> Step for Shared<S> {
+    type Aux<'source> = ();
+    type Left = S::Left;
+    type Output = S::Output;
+    type Right = S::Right;
+    type Witness<'source> = S::Witness;
+
+    const INDEX: Index = Index::new(S::INDEX);
+
+    fn witness<'source>(
+        &self,
+        _ctx: &mut StepCtx<'_>,
+        witness: Self::Witness<'source>,
+        left: <Self::Left as Header>::Data,
+        right: <Self::Right as Header>::Data,
+    ) -> Result<(<Self::Output as Header>::Data, Self::Aux<'source>)> {
+        let mut output = None;
+        Simulator::simulate((witness, left, right), |dr, inputs| {
+            let (witness, left, right) = inputs.take();
+            let left = <S::Left as ragu_pcd::header::Header<Fp>>::encode(
+                dr,
+                &mut (),
+                Always::maybe_just(|| left),
+            )?;
+            let right = <S::Right as ragu_pcd::header::Header<Fp>>::encode(
+                dr,
+                &mut (),
+                Always::maybe_just(|| right),
+            )?;
+            let (_, data) =
+                self.0
+                    .apply(dr, &mut (), Always::maybe_just(|| witness), &left, &right)?;
+            output = Some(data.take());
+            Ok(())
+        })?;
+
+        Ok((output.expect("the simulation ran the step"), ()))
+    }
+}
+
+impl<S: SharedStep> ragu_pcd::step::Step<Pasta> for Shared<S> {
+    const INDEX: ragu_pcd::step::Index = ragu_pcd::step::Index::new(S::INDEX);
+    type Witness<'source> = S::Witness;
+    type Aux<'source> = ();
+    type Left = S::Left;
+    type Right = S::Right;
+    type Output = S::Output;
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, <Self::Left as ragu_pcd::header::Header<Fp>>::Data>,
+        right: DriverValue<D, <Self::Right as ragu_pcd::header::Header<Fp>>::Data>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as ragu_pcd::header::Header<Fp>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )>
+    where
+        Self: 'dr,
+    {
+        let allocator = &mut Standard::new();
+        let left = Encoded::new(dr, allocator, left)?;
+        let right = Encoded::new(dr, allocator, right)?;
+        let (output, output_data) =
+            self.0
+                .apply(dr, allocator, witness, left.as_gadget(), right.as_gadget())?;
+
+        Ok((
+            (left, right, Encoded::from_gadget(output)),
+            output_data,
+            D::unit(),
+        ))
+    }
+}
+
+/// The operations a [`Scenario`] may perform, implemented by both the mock
+/// [`Application`] and [`ragu_pcd::Application`].
+pub trait Backend {
+    /// The name used to identify this backend in assertion messages.
+    const NAME: &'static str;
+
+    /// The proof-carrying data type of this backend.
+    type Pcd<H: SharedHeader>;
+
+    /// Seeds a new computation with `step`.
+    fn seed<RNG: CryptoRngCore, S: SharedStep<Left = (), Right = ()>>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness,
+    ) -> Result<Self::Pcd<S::Output>>;
+
+    /// Fuses `left` and `right` with `step`.
+    fn fuse<RNG: CryptoRngCore, S: SharedStep>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness,
+        left: Self::Pcd<S::Left>,
+        right: Self::Pcd<S::Right>,
+    ) -> Result<Self::Pcd<S::Output>>;
+
+    /// Rerandomizes `pcd`.
+    fn rerandomize<RNG: CryptoRngCore, H: SharedHeader>(
+        &self,
+        rng: &mut RNG,
+        pcd: Self::Pcd<H>,
+    ) -> Result<Self::Pcd<H>>;
+
+    /// Verifies `pcd`.
+    fn verify<RNG: CryptoRngCore, H: SharedHeader>(
+        &self,
+        rng: &mut RNG,
+        pcd: &Self::Pcd<H>,
+    ) -> Result<bool>;
+
+    /// Returns the data carried by `pcd`.
+    fn data<H: SharedHeader>(pcd: &Self::Pcd<H>) -> &H::Value;
+
+    /// Replaces the data carried by `pcd`, keeping its proof.
+    fn recarry<H: SharedHeader>(pcd: Self::Pcd<H>, data: H::Value) -> Self::Pcd<H>;
+}
+
+impl Backend for Application {
+    const NAME: &'static str = "mock";
+
+    type Pcd<H: SharedHeader> = Pcd<H>;
+
+    fn seed<RNG: CryptoRngCore, S: SharedStep<Left = (), Right = ()>>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness,
+    ) -> Result<Pcd<S::Output>> {
+        Application::seed(self, rng, Shared(step), witness).map(|(pcd, ())| pcd)
+    }
+
+    fn fuse<RNG: CryptoRngCore, S: SharedStep>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness,
+        left: Pcd<S::Left>,
+        right: Pcd<S::Right>,
+    ) -> Result<Pcd<S::Output>> {
+        Application::fuse(self, rng, Shared(step), witness, left, right).map(|(pcd, ())| pcd)
+    }
+
+    fn rerandomize<RNG: CryptoRngCore, H: SharedHeader>(
+        &self,
+        rng: &mut RNG,
+        pcd: Pcd<H>,
+    ) -> Result<Pcd<H>> {
+        Application::rerandomize(self, pcd, rng)
+    }
+
+    fn verify<RNG: CryptoRngCore, H: SharedHeader>(
+        &self,
+        rng: &mut RNG,
+        pcd: &Pcd<H>,
+    ) -> Result<bool> {
+        Application::verify(self, pcd, rng)
+    }
+
+    fn data<H: SharedHeader>(pcd: &Pcd<H>) -> &H::Value {
+        pcd.data()
+    }
+
+    fn recarry<H: SharedHeader>(pcd: Pcd<H>, data: H::Value) -> Pcd<H> {
+        pcd.into_parts().0.carry(data)
+    }
+}
+
+impl<R: Rank, const HEADER_SIZE: usize> Backend
+    for ragu_pcd::Application<'_, Pasta, R, HEADER_SIZE>
+{
+    const NAME: &'static str = "ragu_pcd";
+
+    type Pcd<H: SharedHeader> = ragu_pcd::Pcd<Pasta, R, H>;
+
+    fn seed<RNG: CryptoRngCore, S: SharedStep<Left = (), Right = ()>>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness,
+    ) -> Result<Self::Pcd<S::Output>> {
+        ragu_pcd::Application::seed(self, rng, Shared(step), witness).map(|(pcd, ())| pcd)
+    }
+
+    fn fuse<RNG: CryptoRngCore, S: SharedStep>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness,
+        left: Self::Pcd<S::Left>,
+        right: Self::Pcd<S::Right>,
+    ) -> Result<Self::Pcd<S::Output>> {
+        ragu_pcd::Application::fuse(self, rng, Shared(step), witness, left, right)
+            .map(|(pcd, ())| pcd)
+    }
+
+    fn rerandomize<RNG: CryptoRngCore, H: SharedHeader>(
+        &self,
+        rng: &mut RNG,
+        pcd: Self::Pcd<H>,
+    ) -> Result<Self::Pcd<H>> {
+        ragu_pcd::Application::rerandomize(self, pcd, rng)
+    }
+
+    fn verify<RNG: CryptoRngCore, H: SharedHeader>(
+        &self,
+        rng: &mut RNG,
+        pcd: &Self::Pcd<H>,
+    ) -> Result<bool> {
+        ragu_pcd::Application::verify(self, pcd, rng)
+    }
+
+    fn data<H: SharedHeader>(pcd: &Self::Pcd<H>) -> &H::Value {
+        pcd.data()
+    }
+
+    fn recarry<H: SharedHeader>(pcd: Self::Pcd<H>, data: H::Value) -> Self::Pcd<H> {
+        pcd.into_parts().0.carry(data)
+    }
+}
+
+/// A sequence of operations producing proof-carrying data, written once
+/// against any [`Backend`].
+pub trait Scenario {
+    /// The header of the proof-carrying data the scenario produces.
+    type Output: SharedHeader;
+
+    /// Runs the scenario. An error counts as a rejection.
+    fn run<B: Backend, RNG: CryptoRngCore>(
+        &self,
+        backend: &B,
+        rng: &mut RNG,
+    ) -> Result<B::Pcd<Self::Output>>;
+}
+
+/// Whether a [`Scenario`] produced proof-carrying data that verifies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// The scenario produced verifying proof-carrying data with this data.
+    Accepted(T),
+    /// The scenario failed, or its result did not verify.
+    Rejected,
+}
+
+/// Registers the same [`SharedStep`]s with a mock and a real application
+/// builder.
+pub struct ConformanceBuilder<'params, R: Rank, const HEADER_SIZE: usize> {
+    mock: ApplicationBuilder,
+    real: ragu_pcd::ApplicationBuilder<'params, Pasta, R, HEADER_SIZE>,
+}
+
+impl<R: Rank, const HEADER_SIZE: usize> Default for ConformanceBuilder<'_, R, HEADER_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'params, R: Rank, const HEADER_SIZE: usize> ConformanceBuilder<'params, R, HEADER_SIZE> {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            mock: ApplicationBuilder::new(),
+            real: ragu_pcd::ApplicationBuilder::new(),
+        }
+    }
+
+    /// Registers `step` with both applications.
+    pub fn register<S: SharedStep>(self, step: S) -> Result<Self> {
+        Ok(Self {
+            mock: self.mock.register(Shared(step.clone()))?,
+            real: self.real.register(Shared(step))?,
+        })
+    }
+
+    /// Finalizes both applications.
+    pub fn finalize(
+        self,
+        params: &'params <Pasta as Cycle>::Params,
+    ) -> Result<Conformance<'params, R, HEADER_SIZE>> {
+        Ok(Conformance {
+            mock: self.mock.finalize()?,
+            real: self.real.finalize(params)?,
+        })
+    }
+}
+
+/// A mock and a real application with the same steps registered.
+pub struct Conformance<'params, R: Rank, const HEADER_SIZE: usize> {
+    mock: Application,
+    real: ragu_pcd::Application<'params, Pasta, R, HEADER_SIZE>,
+}
+
+impl<'params, R: Rank, const HEADER_SIZE: usize> Conformance<'params, R, HEADER_SIZE> {
+    /// Returns the mock application.
+    #[must_use]
+    pub fn mock(&self) -> &Application {
+        &self.mock
+    }
+
+    /// Returns the real application.
+    #[must_use]
+    pub fn real(&self) -> &ragu_pcd::Application<'params, Pasta, R, HEADER_SIZE> {
+        &self.real
+    }
+
+    /// Runs `scenario` against both applications and returns the outcome
+    /// they agree on.
+    ///
+    /// Accepted proof-carrying data is also rerandomized, and must still
+    /// verify with the same data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the applications disagree on whether the scenario is
+    /// accepted or on its header data, or if either application stops
+    /// accepting a proof once it is rerandomized.
+    pub fn check<RNG: CryptoRngCore, S: Scenario>(
+        &self,
+        rng: &mut RNG,
+        scenario: &S,
+    ) -> Outcome<<S::Output as SharedHeader>::Value> {
+        let mock = outcome(&self.mock, rng, scenario);
+        let real = outcome(&self.real, rng, scenario);
+        assert_eq!(mock, real, "mock and ragu_pcd disagree");
+
+        real
+    }
+}
+
+fn outcome<B: Backend, RNG: CryptoRngCore, S: Scenario>(
+    backend: &B,
+    rng: &mut RNG,
+    scenario: &S,
+) -> Outcome<<S::Output as SharedHeader>::Value> {
+    let Ok(pcd) = scenario.run(backend, rng) else {
+        return Outcome::Rejected;
+    };
+    if !matches!(backend.verify(rng, &pcd), Ok(true)) {
+        return Outcome::Rejected;
+    }
+
+    let data = B::data(&pcd).clone();
+    let rerandomized = backend.rerandomize(rng, pcd);
+    assert!(
+        rerandomized
+            .as_ref()
+            .is_ok_and(|pcd| matches!(backend.verify(rng, pcd), Ok(true))),
+        "{} rejected a rerandomized proof that it accepted",
+        B::NAME
+    );
+    assert!(
+        rerandomized.is_ok_and(|pcd| *B::data(&pcd) == data),
+        "{} changed the data of a rerandomized proof",
+        B::NAME
+    );
+
+    Outcome::Accepted(data)
+}
@@ -2,7 +2,9 @@
 //!
 //! Enabled by the `mock` feature. Mirrors the shape of the real `ragu_pcd` API
 //! so downstream consumers (e.g. Zebra) can integrate against it ahead of the
-//! real implementation. The contents are re-exported at the crate root.
+//! real implementation. The contents are re-exported at the crate root. The
+//! [`conformance`] module checks that the mock and `ragu_pcd` agree on the
+//! proof-carrying data they accept.
 //!
 //! The mock builds against whichever crypto stack the `modern-deps` (default)
 //! or `legacy-deps` feature selects.
@@ -21,6 +23,7 @@ pub use sponge::{Sponge, SpongeState};
 pub use step::{Index, Step};
 
 pub mod application;
+pub mod conformance;
 pub mod constraint;
 pub mod ctx;
 pub mod domain;
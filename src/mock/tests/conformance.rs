@@ -0,0 +1,188 @@
+use ragu_arithmetic::{
+    CryptoRngCore,
+    rand::{SeedableRng as _, rngs::StdRng},
+};
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_primitives::{Element, allocator::Allocator};
+
+use crate::conformance::{
+    Backend, ConformanceBuilder, FieldHeader, Outcome, Scenario, SharedHeader, SharedStep,
+};
+
+type Leaf = FieldHeader<0>;
+type Node = FieldHeader<1>;
+
+#[derive(Clone)]
+struct Square;
+
+/// Returns `element` along with its value, as the output of a step.
+fn output<'dr, D: Driver<'dr, F = Fp>>(
+    element: Element<'dr, D>,
+) -> (Element<'dr, D>, DriverValue<D, Fp>) {
+    let value = element.value().map(|v| *v);
+    (element, value)
+}
+
+impl SharedStep for Square {
+    type Left = ();
+    type Output = Leaf;
+    type Right = ();
+    type Witness = u64;
+
+    const INDEX: usize = 0;
+
+    fn apply<'dr, D: Driver<'dr, F = Fp>, A: Allocator<'dr, D>>(
+        &self,
+        dr: &mut D,
+        allocator: &mut A,
+        witness: DriverValue<D, u64>,
+        _left: &(),
+        _right: &(),
+    ) -> Result<(Element<'dr, D>, DriverValue<D, Fp>)> {
+        let witness = Element::alloc(dr, allocator, witness.map(Fp::from))?;
+        Ok(output(witness.square(dr)?))
+    }
+}
+
+#[derive(Clone)]
+struct Sum;
+
+impl SharedStep for Sum {
+    type Left = Leaf;
+    type Output = Node;
+    type Right = Leaf;
+    type Witness = Fp;
+
+    const INDEX: usize = 1;
+
+    fn apply<'dr, D: Driver<'dr, F = Fp>, A: Allocator<'dr, D>>(
+        &self,
+        dr: &mut D,
+        allocator: &mut A,
+        witness: DriverValue<D, Fp>,
+        left: &Element<'dr, D>,
+        right: &Element<'dr, D>,
+    ) -> Result<(Element<'dr, D>, DriverValue<D, Fp>)> {
+        let witness = Element::alloc(dr, allocator, witness)?;
+        Ok(output(left.add(dr, right).add(dr, &witness)))
+    }
+}
+
+#[derive(Clone)]
+struct Equal;
+
+impl SharedStep for Equal {
+    type Left = Leaf;
+    type Output = Node;
+    type Right = Leaf;
+    type Witness = ();
+
+    const INDEX: usize = 2;
+
+    fn apply<'dr, D: Driver<'dr, F = Fp>, A: Allocator<'dr, D>>(
+        &self,
+        dr: &mut D,
+        _allocator: &mut A,
+        _witness: DriverValue<D, ()>,
+        left: &Element<'dr, D>,
+        right: &Element<'dr, D>,
+    ) -> Result<(Element<'dr, D>, DriverValue<D, Fp>)> {
+        left.sub(dr, right).enforce_zero(dr)?;
+        Ok(output(left.clone()))
+    }
+}
+
+/// Seeds a leaf from `witness`.
+struct Seed(u64);
+
+impl Scenario for Seed {
+    type Output = Leaf;
+
+    fn run<B: Backend, RNG: CryptoRngCore>(
+        &self,
+        backend: &B,
+        rng: &mut RNG,
+    ) -> Result<B::Pcd<Leaf>> {
+        backend.seed(rng, Square, self.0)
+    }
+}
+
+/// Seeds two leaves and fuses them with a step and its witness.
+struct Merge<S: SharedStep>(S, S::Witness, u64, u64);
+
+impl<S: SharedStep<Left = Leaf, Right = Leaf, Witness: Clone>> Scenario for Merge<S> {
+    type Output = S::Output;
+
+    fn run<B: Backend, RNG: CryptoRngCore>(
+        &self,
+        backend: &B,
+        rng: &mut RNG,
+    ) -> Result<B::Pcd<S::Output>> {
+        let left = Seed(self.2).run(backend, rng)?;
+        let right = Seed(self.3).run(backend, rng)?;
+        backend.fuse(rng, self.0.clone(), self.1.clone(), left, right)
+    }
+}
+
+/// Replaces the data carried by another scenario's result.
+struct Forge<S: Scenario>(S, <S::Output as SharedHeader>::Value);
+
+impl<S: Scenario> Scenario for Forge<S> {
+    type Output = S::Output;
+
+    fn run<B: Backend, RNG: CryptoRngCore>(
+        &self,
+        backend: &B,
+        rng: &mut RNG,
+    ) -> Result<B::Pcd<S::Output>> {
+        let pcd = self.0.run(backend, rng)?;
+        Ok(B::recarry(pcd, self.1.clone()))
+    }
+}
+
+#[test]
+fn mock_agrees_with_ragu_pcd() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let apps = ConformanceBuilder::<ProductionRank, 4>::new()
+        .register(Square)
+        .expect("register")
+        .register(Sum)
+        .expect("register")
+        .register(Equal)
+        .expect("register")
+        .finalize(Pasta::baked())
+        .expect("finalize");
+
+    assert_eq!(
+        apps.check(&mut rng, &Seed(3)),
+        Outcome::Accepted(Fp::from(9))
+    );
+    assert_eq!(
+        apps.check(&mut rng, &Merge(Sum, Fp::from(1), 2, 3)),
+        Outcome::Accepted(Fp::from(14))
+    );
+    assert_eq!(
+        apps.check(&mut rng, &Merge(Equal, (), 2, 2)),
+        Outcome::Accepted(Fp::from(4))
+    );
+    assert_eq!(
+        apps.check(&mut rng, &Merge(Equal, (), 2, 3)),
+        Outcome::Rejected
+    );
+    assert_eq!(
+        apps.check(&mut rng, &Forge(Seed(3), Fp::from(10))),
+        Outcome::Rejected
+    );
+    assert_eq!(
+        apps.check(
+            &mut rng,
+            &Forge(Merge(Sum, Fp::from(1), 2, 3), Fp::from(13))
+        ),
+        Outcome::Rejected
+    );
+}
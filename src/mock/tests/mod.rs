@@ -1,4 +1,5 @@
 mod application;
+mod conformance;
 mod constraint;
 mod proof;
 mod sponge;